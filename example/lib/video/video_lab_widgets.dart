@@ -78,6 +78,11 @@ class VideoMetadataWidget extends StatelessWidget {
             Text('Original Duration: ${videoInfo!.durationMs} ms'),
             Text('Original Resolution: ${videoInfo!.width}x${videoInfo!.height}'),
             Text('Original Size: $sizeStringBytes / $sizeStringKB / $sizeStringMB'),
+            Text('Frame Rate: ${videoInfo!.avgFrameRate?.toStringAsFixed(2) ?? '-'} fps${videoInfo!.isVfr ? ' (VFR)' : ''}'),
+            Text('Rotation: ${videoInfo!.rotation}° (stored ${videoInfo!.storedWidth}x${videoInfo!.storedHeight})'),
+            Text('Pixel Format: ${videoInfo!.pixelFormat ?? '-'}, HDR: ${videoInfo!.hdrFormat?.name ?? 'none'}'),
+            if (videoInfo!.audio != null)
+              Text('Audio: ${videoInfo!.audio!.codecName ?? '-'}, ${videoInfo!.audio!.channels} ch, ${videoInfo!.audio!.sampleRate} Hz'),
           ],
         ),
       ),
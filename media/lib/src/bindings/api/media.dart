@@ -8,9 +8,8 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'media.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `decode_heic_with_libheif`, `decode_image_with_ffmpeg_impl`, `decode_image_with_ffmpeg`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CompressProgress`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `image_thumbnail_options`, `decode_image`, `decode_heic_with_libheif`, `decode_image_with_ffmpeg_impl`, `panic_to_error`, `progress_reporter`, `decode_image_with_ffmpeg`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Exposed via FRB
 Future<VideoInfo> getVideoInfo({required String path}) =>
@@ -28,6 +27,19 @@ Future<String> generateVideoThumbnail({
   emptyImageFallback: emptyImageFallback,
 );
 
+/// Like `generate_video_thumbnail`, but returns the encoded image and the timestamp of the
+/// frame actually used instead of writing a file
+Future<ThumbnailData> generateVideoThumbnailData({
+  required String path,
+  required VideoThumbnailParams params,
+}) => RustLib.instance.api.crateApiMediaGenerateVideoThumbnailData(
+  path: path,
+  params: params,
+);
+
+/// Writes `num_thumbnails` frames evenly spaced from the start of the video, one per
+/// equal slice of its duration, streaming each path through `sink` as it is written.
+/// With `empty_image_fallback`, a blank image stands in for any frame that can't be decoded.
 Stream<String> generateVideoTimelineThumbnails({
   required String path,
   required String outputPath,
@@ -42,6 +54,18 @@ Stream<String> generateVideoTimelineThumbnails({
   emptyImageFallback: emptyImageFallback,
 );
 
+/// Like `generate_video_timeline_thumbnails`, but streams each encoded image with its
+/// timestamp through `sink` instead of writing files
+Stream<ThumbnailData> generateVideoTimelineThumbnailData({
+  required String path,
+  ImageThumbnailParams? params,
+  required int numThumbnails,
+}) => RustLib.instance.api.crateApiMediaGenerateVideoTimelineThumbnailData(
+  path: path,
+  params: params,
+  numThumbnails: numThumbnails,
+);
+
 Future<String> generateImageThumbnail({
   required String path,
   required String outputPath,
@@ -54,6 +78,15 @@ Future<String> generateImageThumbnail({
   suffix: suffix,
 );
 
+/// Like `generate_image_thumbnail`, but returns the encoded image instead of writing a file
+Future<ThumbnailData> generateImageThumbnailData({
+  required String path,
+  ImageThumbnailParams? params,
+}) => RustLib.instance.api.crateApiMediaGenerateImageThumbnailData(
+  path: path,
+  params: params,
+);
+
 Future<CompressionEstimate> estimateCompression({
   required String path,
   required String tempOutputPath,
@@ -74,6 +107,527 @@ Future<String> compressVideo({
   params: params,
 );
 
+/// Join several clips into one video at `output_path`.
+/// Clips with matching codecs and parameters are joined losslessly; otherwise they are
+/// normalised (resolution, frame rate, pixel format, rotation, stereo AAC audio with silence
+/// for clips that have none) and re-encoded. Progress is reported through `sink`.
+Stream<CompressProgress> concatVideos({
+  required List<String> inputs,
+  required String outputPath,
+  required ConcatParams params,
+}) => RustLib.instance.api.crateApiMediaConcatVideos(
+  inputs: inputs,
+  outputPath: outputPath,
+  params: params,
+);
+
+/// Render a clip (or `start_ms..end_ms` of it) backwards, or as a boomerang that plays
+/// forward then backward `boomerang_loops` times. Frames are decoded one GOP-aligned chunk
+/// at a time within `memory_budget_mb`. Progress is reported through `sink`.
+Stream<CompressProgress> reverseVideo({
+  required String path,
+  required String outputPath,
+  required ReverseParams params,
+}) => RustLib.instance.api.crateApiMediaReverseVideo(
+  path: path,
+  outputPath: outputPath,
+  params: params,
+);
+
+/// Export the audio track of `path` as `format`. The track is copied without re-encoding when
+/// the codec already matches the container and no channel/rate/bitrate change is requested;
+/// otherwise it is decoded, resampled and encoded. Returns the output file path.
+Future<String> extractAudio({
+  required String path,
+  required String outputPath,
+  required AudioFormat format,
+  required AudioExtractOptions options,
+}) => RustLib.instance.api.crateApiMediaExtractAudio(
+  path: path,
+  outputPath: outputPath,
+  format: format,
+  options: options,
+);
+
+/// Put the audio of `music_path` under the video `video_path`, replacing or mixing with the
+/// original track. The music is looped or cut to the video length. The video stream is copied
+/// when the output container supports its codec and re-encoded as H.264 otherwise.
+Stream<CompressProgress> addMusic({
+  required String videoPath,
+  required String musicPath,
+  required String outputPath,
+  required AudioMixParams params,
+}) => RustLib.instance.api.crateApiMediaAddMusic(
+  videoPath: videoPath,
+  musicPath: musicPath,
+  outputPath: outputPath,
+  params: params,
+);
+
+/// Measure the integrated loudness, loudness range and true peak (EBU R128) of the audio track
+Future<LoudnessInfo> analyzeLoudness({required String path}) =>
+    RustLib.instance.api.crateApiMediaAnalyzeLoudness(path: path);
+
+/// Export `[start_ms, start_ms + duration_ms)` of a video as an animated GIF, WebP or APNG
+/// at up to `fps` frames per second and `max_width` pixels wide. Returns the output file path.
+Future<String> exportAnimation({
+  required String path,
+  required String outputPath,
+  required BigInt startMs,
+  required BigInt durationMs,
+  required int fps,
+  required int maxWidth,
+  required AnimationFormat format,
+  required AnimationOptions options,
+}) => RustLib.instance.api.crateApiMediaExportAnimation(
+  path: path,
+  outputPath: outputPath,
+  startMs: startMs,
+  durationMs: durationMs,
+  fps: fps,
+  maxWidth: maxWidth,
+  format: format,
+  options: options,
+);
+
+/// Pick a poster frame: samples frames across the video, scores them on brightness,
+/// contrast, sharpness and colour, and returns the best one encoded with its timestamp
+Future<BestThumbnail> generateBestThumbnail({
+  required String path,
+  required BestThumbnailParams params,
+}) => RustLib.instance.api.crateApiMediaGenerateBestThumbnail(
+  path: path,
+  params: params,
+);
+
+/// Storyboard for scrubbing previews: a frame every `interval_ms` tiled `columns` x `rows`
+/// per JPEG sprite sheet, plus a WebVTT file mapping each time range to its tile.
+/// Only the JPEG fields of `encoder_options` apply.
+Future<Storyboard> generateStoryboard({
+  required String path,
+  required String outputDir,
+  required BigInt intervalMs,
+  required int tileWidth,
+  required int tileHeight,
+  required int columns,
+  required int rows,
+  EncoderOptions? encoderOptions,
+}) => RustLib.instance.api.crateApiMediaGenerateStoryboard(
+  path: path,
+  outputDir: outputDir,
+  intervalMs: intervalMs,
+  tileWidth: tileWidth,
+  tileHeight: tileHeight,
+  columns: columns,
+  rows: rows,
+  encoderOptions: encoderOptions,
+);
+
+/// Extract many frames in a single pass: the input is opened once and decoded forward,
+/// seeking only when a timestamp is past the next keyframe. Each image is written to
+/// `output_path` and streamed through `sink` as soon as it is ready.
+Stream<ExtractedFrame> extractFrames({
+  required String path,
+  required String outputPath,
+  required FrameExtractParams params,
+}) => RustLib.instance.api.crateApiMediaExtractFrames(
+  path: path,
+  outputPath: outputPath,
+  params: params,
+);
+
+/// Find scene cuts by comparing downscaled consecutive frames (colour histogram and pixel
+/// differences). `threshold` is 0-1, around 0.3 for hard cuts. Decodes the whole video;
+/// progress is reported through `sink`, followed by a single `Cuts` event with the result.
+Stream<SceneDetectionEvent> detectScenes({
+  required String path,
+  required double threshold,
+}) => RustLib.instance.api.crateApiMediaDetectScenes(
+  path: path,
+  threshold: threshold,
+);
+
+/// BlurHash placeholder for an image, or for the frame at `time_ms` of a video.
+/// `components_x` and `components_y` (1-9) set how much detail the hash keeps; 4x3 is typical.
+Future<String> computeBlurhash({
+  required String path,
+  required int componentsX,
+  required int componentsY,
+  BigInt? timeMs,
+}) => RustLib.instance.api.crateApiMediaComputeBlurhash(
+  path: path,
+  componentsX: componentsX,
+  componentsY: componentsY,
+  timeMs: timeMs,
+);
+
+/// ThumbHash placeholder (about 25 bytes, keeps the aspect ratio and transparency) for an
+/// image, or for the frame at `time_ms` of a video
+Future<Uint8List> computeThumbhash({required String path, BigInt? timeMs}) =>
+    RustLib.instance.api.crateApiMediaComputeThumbhash(
+      path: path,
+      timeMs: timeMs,
+    );
+
+/// Renders a BlurHash at `width` x `height` (at most 1024 each). `punch` scales the
+/// contrast, defaults to 1.
+Future<PlaceholderImage> decodeBlurhash({
+  required String hash,
+  required int width,
+  required int height,
+  double? punch,
+}) => RustLib.instance.api.crateApiMediaDecodeBlurhash(
+  hash: hash,
+  width: width,
+  height: height,
+  punch: punch,
+);
+
+/// Renders a ThumbHash at its encoded aspect ratio, at most 32 pixels on the longest side
+Future<PlaceholderImage> decodeThumbhash({required List<int> hash}) =>
+    RustLib.instance.api.crateApiMediaDecodeThumbhash(hash: hash);
+
+/// Perceptual hash of an image (HEIC, JPEG, PNG, ...) for duplicate detection. Defaults to pHash.
+Future<MediaHash> computeImageHash({
+  required String path,
+  HashAlgorithm? algorithm,
+}) => RustLib.instance.api.crateApiMediaComputeImageHash(
+  path: path,
+  algorithm: algorithm,
+);
+
+/// Perceptual hashes of `frame_count` frames (defaults to 16) spread evenly over a video.
+/// Defaults to pHash.
+Future<MediaHash> computeVideoHash({
+  required String path,
+  HashAlgorithm? algorithm,
+  int? frameCount,
+}) => RustLib.instance.api.crateApiMediaComputeVideoHash(
+  path: path,
+  algorithm: algorithm,
+  frameCount: frameCount,
+);
+
+/// Compares two hashes made with the same algorithm. An image against a video matches its
+/// closest frame. `threshold` is the largest distance still reported as similar, defaults to 10.
+Future<HashComparison> compareMediaHashes({
+  required MediaHash a,
+  required MediaHash b,
+  int? threshold,
+}) => RustLib.instance.api.crateApiMediaCompareMediaHashes(
+  a: a,
+  b: b,
+  threshold: threshold,
+);
+
+/// The `count` (1-64) dominant colours of an image, or of the frame at `time_ms` of a video,
+/// found by median cut on a downscaled copy, plus vibrant/muted swatches for theming
+Future<Palette> extractPalette({
+  required String path,
+  required int count,
+  BigInt? timeMs,
+}) => RustLib.instance.api.crateApiMediaExtractPalette(
+  path: path,
+  count: count,
+  timeMs: timeMs,
+);
+
+/// Container (format, duration, bitrate, tags, chapters) and every stream (codec, parameters,
+/// language, disposition, side data) of a media file. Unlike `get_video_info` this works for
+/// audio-only, image and subtitle files.
+Future<MediaProbe> probeMedia({required String path}) =>
+    RustLib.instance.api.crateApiMediaProbeMedia(path: path);
+
+/// `probe_media` as pretty-printed JSON, for logging or passing on to other tools
+Future<String> probeMediaJson({required String path}) =>
+    RustLib.instance.api.crateApiMediaProbeMediaJson(path: path);
+
+/// Dimensions, format, bit depth, alpha, EXIF (orientation, camera, capture date, GPS) and ICC
+/// profile name of an image, plus image/auxiliary/depth counts for HEIC. Reads headers and
+/// metadata only, so it is cheap enough to call before choosing a thumbnail size.
+Future<ImageInfo> getImageInfo({required String path}) =>
+    RustLib.instance.api.crateApiMediaGetImageInfo(path: path);
+
+/// Container of an animated export
+enum AnimationFormat {
+  gif,
+  webp,
+  apng;
+
+  Future<void> extension_() =>
+      RustLib.instance.api.crateApiMediaAnimationFormatExtension(that: this);
+}
+
+class AnimationOptions {
+  final BigInt? maxBytes;
+  final int? loopCount;
+  final bool? dither;
+
+  const AnimationOptions({this.maxBytes, this.loopCount, this.dither});
+
+  @override
+  int get hashCode => maxBytes.hashCode ^ loopCount.hashCode ^ dither.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnimationOptions &&
+          runtimeType == other.runtimeType &&
+          maxBytes == other.maxBytes &&
+          loopCount == other.loopCount &&
+          dither == other.dither;
+}
+
+/// How the source is mapped onto an output of a different aspect ratio
+enum AspectMode { fit, fill, blurredFit }
+
+enum AudioChannels { mono, stereo }
+
+class AudioExtractOptions {
+  final BigInt? startMs;
+  final BigInt? endMs;
+  final AudioChannels? channels;
+  final int? sampleRate;
+  final int? bitrateKbps;
+  final LoudnessNormalization? normalizeLoudness;
+
+  const AudioExtractOptions({
+    this.startMs,
+    this.endMs,
+    this.channels,
+    this.sampleRate,
+    this.bitrateKbps,
+    this.normalizeLoudness,
+  });
+
+  @override
+  int get hashCode =>
+      startMs.hashCode ^
+      endMs.hashCode ^
+      channels.hashCode ^
+      sampleRate.hashCode ^
+      bitrateKbps.hashCode ^
+      normalizeLoudness.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioExtractOptions &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          channels == other.channels &&
+          sampleRate == other.sampleRate &&
+          bitrateKbps == other.bitrateKbps &&
+          normalizeLoudness == other.normalizeLoudness;
+}
+
+/// Container/codec of an audio-only output. There is no MP3: the bundled FFmpeg is built
+/// without libmp3lame, so it has no MP3 encoder.
+enum AudioFormat {
+  m4A,
+  opus,
+  wav;
+
+  Future<void> extension_() =>
+      RustLib.instance.api.crateApiMediaAudioFormatExtension(that: this);
+}
+
+class AudioMixParams {
+  final AudioTrackMode? mode;
+  final double? musicGainDb;
+  final double? originalGainDb;
+  final DuckingParams? ducking;
+  final bool loopMusic;
+  final BigInt? musicStartMs;
+  final BigInt? fadeInMs;
+  final BigInt? fadeOutMs;
+
+  const AudioMixParams({
+    this.mode,
+    this.musicGainDb,
+    this.originalGainDb,
+    this.ducking,
+    required this.loopMusic,
+    this.musicStartMs,
+    this.fadeInMs,
+    this.fadeOutMs,
+  });
+
+  @override
+  int get hashCode =>
+      mode.hashCode ^
+      musicGainDb.hashCode ^
+      originalGainDb.hashCode ^
+      ducking.hashCode ^
+      loopMusic.hashCode ^
+      musicStartMs.hashCode ^
+      fadeInMs.hashCode ^
+      fadeOutMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioMixParams &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          musicGainDb == other.musicGainDb &&
+          originalGainDb == other.originalGainDb &&
+          ducking == other.ducking &&
+          loopMusic == other.loopMusic &&
+          musicStartMs == other.musicStartMs &&
+          fadeInMs == other.fadeInMs &&
+          fadeOutMs == other.fadeOutMs;
+}
+
+class AudioStreamInfo {
+  final String? codecName;
+  final int channels;
+  final int sampleRate;
+  final BigInt? bitrate;
+  final String? language;
+
+  const AudioStreamInfo({
+    this.codecName,
+    required this.channels,
+    required this.sampleRate,
+    this.bitrate,
+    this.language,
+  });
+
+  @override
+  int get hashCode =>
+      codecName.hashCode ^
+      channels.hashCode ^
+      sampleRate.hashCode ^
+      bitrate.hashCode ^
+      language.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioStreamInfo &&
+          runtimeType == other.runtimeType &&
+          codecName == other.codecName &&
+          channels == other.channels &&
+          sampleRate == other.sampleRate &&
+          bitrate == other.bitrate &&
+          language == other.language;
+}
+
+class AudioStreamProbe {
+  final int sampleRate;
+  final int channels;
+  final String? channelLayout;
+  final String? sampleFormat;
+  final int? bitsPerSample;
+
+  const AudioStreamProbe({
+    required this.sampleRate,
+    required this.channels,
+    this.channelLayout,
+    this.sampleFormat,
+    this.bitsPerSample,
+  });
+
+  @override
+  int get hashCode =>
+      sampleRate.hashCode ^
+      channels.hashCode ^
+      channelLayout.hashCode ^
+      sampleFormat.hashCode ^
+      bitsPerSample.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioStreamProbe &&
+          runtimeType == other.runtimeType &&
+          sampleRate == other.sampleRate &&
+          channels == other.channels &&
+          channelLayout == other.channelLayout &&
+          sampleFormat == other.sampleFormat &&
+          bitsPerSample == other.bitsPerSample;
+}
+
+/// What `add_music` does with the video's own audio track
+enum AudioTrackMode { replace, mix }
+
+/// Poster frame picked by `generate_best_thumbnail`
+class BestThumbnail {
+  final Uint8List data;
+  final int width;
+  final int height;
+  final BigInt timeMs;
+  final double score;
+
+  const BestThumbnail({
+    required this.data,
+    required this.width,
+    required this.height,
+    required this.timeMs,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      timeMs.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BestThumbnail &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          width == other.width &&
+          height == other.height &&
+          timeMs == other.timeMs &&
+          score == other.score;
+}
+
+class BestThumbnailParams {
+  final ThumbnailSizeType? sizeType;
+  final OutputFormat? format;
+  final int? candidates;
+  final ResizeMode? resizeMode;
+  final bool? allowUpscale;
+  final EncoderOptions? encoderOptions;
+
+  const BestThumbnailParams({
+    this.sizeType,
+    this.format,
+    this.candidates,
+    this.resizeMode,
+    this.allowUpscale,
+    this.encoderOptions,
+  });
+
+  @override
+  int get hashCode =>
+      sizeType.hashCode ^
+      format.hashCode ^
+      candidates.hashCode ^
+      resizeMode.hashCode ^
+      allowUpscale.hashCode ^
+      encoderOptions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BestThumbnailParams &&
+          runtimeType == other.runtimeType &&
+          sizeType == other.sizeType &&
+          format == other.format &&
+          candidates == other.candidates &&
+          resizeMode == other.resizeMode &&
+          allowUpscale == other.allowUpscale &&
+          encoderOptions == other.encoderOptions;
+}
+
 class CompressParams {
   final int targetBitrateKbps;
   final String? preset;
@@ -81,6 +635,16 @@ class CompressParams {
   final int? width;
   final int? height;
   final BigInt? sampleDurationMs;
+  final CropRect? crop;
+  final (int, int)? aspectRatio;
+  final AspectMode? aspectMode;
+  final int? padColor;
+  final WatermarkParams? watermark;
+  final SubtitleParams? subtitles;
+  final SpeedParams? speed;
+  final LoudnessNormalization? normalizeLoudness;
+  final String? videoFilters;
+  final String? audioFilters;
 
   const CompressParams({
     required this.targetBitrateKbps,
@@ -89,79 +653,797 @@ class CompressParams {
     this.width,
     this.height,
     this.sampleDurationMs,
+    this.crop,
+    this.aspectRatio,
+    this.aspectMode,
+    this.padColor,
+    this.watermark,
+    this.subtitles,
+    this.speed,
+    this.normalizeLoudness,
+    this.videoFilters,
+    this.audioFilters,
+  });
+
+  @override
+  int get hashCode =>
+      targetBitrateKbps.hashCode ^
+      preset.hashCode ^
+      crf.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      sampleDurationMs.hashCode ^
+      crop.hashCode ^
+      aspectRatio.hashCode ^
+      aspectMode.hashCode ^
+      padColor.hashCode ^
+      watermark.hashCode ^
+      subtitles.hashCode ^
+      speed.hashCode ^
+      normalizeLoudness.hashCode ^
+      videoFilters.hashCode ^
+      audioFilters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CompressParams &&
+          runtimeType == other.runtimeType &&
+          targetBitrateKbps == other.targetBitrateKbps &&
+          preset == other.preset &&
+          crf == other.crf &&
+          width == other.width &&
+          height == other.height &&
+          sampleDurationMs == other.sampleDurationMs &&
+          crop == other.crop &&
+          aspectRatio == other.aspectRatio &&
+          aspectMode == other.aspectMode &&
+          padColor == other.padColor &&
+          watermark == other.watermark &&
+          subtitles == other.subtitles &&
+          speed == other.speed &&
+          normalizeLoudness == other.normalizeLoudness &&
+          videoFilters == other.videoFilters &&
+          audioFilters == other.audioFilters;
+}
+
+class CompressProgress {
+  final BigInt processedMs;
+  final BigInt totalMs;
+  final double speedX;
+
+  const CompressProgress({
+    required this.processedMs,
+    required this.totalMs,
+    required this.speedX,
+  });
+
+  @override
+  int get hashCode => processedMs.hashCode ^ totalMs.hashCode ^ speedX.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CompressProgress &&
+          runtimeType == other.runtimeType &&
+          processedMs == other.processedMs &&
+          totalMs == other.totalMs &&
+          speedX == other.speedX;
+}
+
+class CompressionEstimate {
+  final BigInt estimatedSizeBytes;
+  final BigInt estimatedDurationMs;
+
+  const CompressionEstimate({
+    required this.estimatedSizeBytes,
+    required this.estimatedDurationMs,
   });
 
   @override
   int get hashCode =>
+      estimatedSizeBytes.hashCode ^ estimatedDurationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CompressionEstimate &&
+          runtimeType == other.runtimeType &&
+          estimatedSizeBytes == other.estimatedSizeBytes &&
+          estimatedDurationMs == other.estimatedDurationMs;
+}
+
+class ConcatParams {
+  final int? width;
+  final int? height;
+  final int? fps;
+  final int? targetBitrateKbps;
+  final String? preset;
+  final int? crf;
+  final bool? forceReencode;
+
+  const ConcatParams({
+    this.width,
+    this.height,
+    this.fps,
+    this.targetBitrateKbps,
+    this.preset,
+    this.crf,
+    this.forceReencode,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      fps.hashCode ^
       targetBitrateKbps.hashCode ^
       preset.hashCode ^
       crf.hashCode ^
+      forceReencode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConcatParams &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          fps == other.fps &&
+          targetBitrateKbps == other.targetBitrateKbps &&
+          preset == other.preset &&
+          crf == other.crf &&
+          forceReencode == other.forceReencode;
+}
+
+/// Crop rectangle in display orientation (as the video is shown, after rotation)
+class CropRect {
+  final int x;
+  final int y;
+  final int width;
+  final int height;
+
+  const CropRect({
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      x.hashCode ^ y.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CropRect &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// Lowers the music while the original track is loud (speech, effects)
+class DuckingParams {
+  final double? thresholdDb;
+  final double? reductionDb;
+  final int? attackMs;
+  final int? releaseMs;
+
+  const DuckingParams({
+    this.thresholdDb,
+    this.reductionDb,
+    this.attackMs,
+    this.releaseMs,
+  });
+
+  @override
+  int get hashCode =>
+      thresholdDb.hashCode ^
+      reductionDb.hashCode ^
+      attackMs.hashCode ^
+      releaseMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuckingParams &&
+          runtimeType == other.runtimeType &&
+          thresholdDb == other.thresholdDb &&
+          reductionDb == other.reductionDb &&
+          attackMs == other.attackMs &&
+          releaseMs == other.releaseMs;
+}
+
+/// Per-format encoder settings; each field only applies to its own format
+class EncoderOptions {
+  final int? jpegQuality;
+  final bool? jpegProgressive;
+  final double? webpQuality;
+  final int? pngCompression;
+  final int? avifQuality;
+  final int? heicQuality;
+
+  const EncoderOptions({
+    this.jpegQuality,
+    this.jpegProgressive,
+    this.webpQuality,
+    this.pngCompression,
+    this.avifQuality,
+    this.heicQuality,
+  });
+
+  @override
+  int get hashCode =>
+      jpegQuality.hashCode ^
+      jpegProgressive.hashCode ^
+      webpQuality.hashCode ^
+      pngCompression.hashCode ^
+      avifQuality.hashCode ^
+      heicQuality.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncoderOptions &&
+          runtimeType == other.runtimeType &&
+          jpegQuality == other.jpegQuality &&
+          jpegProgressive == other.jpegProgressive &&
+          webpQuality == other.webpQuality &&
+          pngCompression == other.pngCompression &&
+          avifQuality == other.avifQuality &&
+          heicQuality == other.heicQuality;
+}
+
+/// One image written by `extract_frames`
+class ExtractedFrame {
+  final String path;
+  final int index;
+  final BigInt requestedMs;
+  final BigInt timeMs;
+  final int width;
+  final int height;
+
+  const ExtractedFrame({
+    required this.path,
+    required this.index,
+    required this.requestedMs,
+    required this.timeMs,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      index.hashCode ^
+      requestedMs.hashCode ^
+      timeMs.hashCode ^
+      width.hashCode ^
+      height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExtractedFrame &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          index == other.index &&
+          requestedMs == other.requestedMs &&
+          timeMs == other.timeMs &&
+          width == other.width &&
+          height == other.height;
+}
+
+class FrameExtractParams {
+  final FrameSelection selection;
+  final ThumbnailSizeType? sizeType;
+  final OutputFormat? format;
+  final SeekMode? seekMode;
+  final ResizeMode? resizeMode;
+  final bool? allowUpscale;
+  final EncoderOptions? encoderOptions;
+
+  const FrameExtractParams({
+    required this.selection,
+    this.sizeType,
+    this.format,
+    this.seekMode,
+    this.resizeMode,
+    this.allowUpscale,
+    this.encoderOptions,
+  });
+
+  @override
+  int get hashCode =>
+      selection.hashCode ^
+      sizeType.hashCode ^
+      format.hashCode ^
+      seekMode.hashCode ^
+      resizeMode.hashCode ^
+      allowUpscale.hashCode ^
+      encoderOptions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameExtractParams &&
+          runtimeType == other.runtimeType &&
+          selection == other.selection &&
+          sizeType == other.sizeType &&
+          format == other.format &&
+          seekMode == other.seekMode &&
+          resizeMode == other.resizeMode &&
+          allowUpscale == other.allowUpscale &&
+          encoderOptions == other.encoderOptions;
+}
+
+@freezed
+sealed class FrameSelection with _$FrameSelection {
+  const FrameSelection._();
+
+  const factory FrameSelection.timestamps(Uint64List field0) =
+      FrameSelection_Timestamps;
+  const factory FrameSelection.interval(BigInt field0) =
+      FrameSelection_Interval;
+  const factory FrameSelection.count(int field0) = FrameSelection_Count;
+}
+
+/// GPS position from EXIF, in signed decimal degrees (south and west negative)
+class GpsLocation {
+  final double latitude;
+  final double longitude;
+  final double? altitude;
+
+  const GpsLocation({
+    required this.latitude,
+    required this.longitude,
+    this.altitude,
+  });
+
+  @override
+  int get hashCode =>
+      latitude.hashCode ^ longitude.hashCode ^ altitude.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GpsLocation &&
+          runtimeType == other.runtimeType &&
+          latitude == other.latitude &&
+          longitude == other.longitude &&
+          altitude == other.altitude;
+}
+
+/// Perceptual hash used for duplicate detection
+enum HashAlgorithm { dHash, pHash }
+
+/// Result of `compare_media_hashes`
+class HashComparison {
+  final int distance;
+  final double similarity;
+  final bool isSimilar;
+
+  const HashComparison({
+    required this.distance,
+    required this.similarity,
+    required this.isSimilar,
+  });
+
+  @override
+  int get hashCode =>
+      distance.hashCode ^ similarity.hashCode ^ isSimilar.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HashComparison &&
+          runtimeType == other.runtimeType &&
+          distance == other.distance &&
+          similarity == other.similarity &&
+          isSimilar == other.isSimilar;
+}
+
+enum HdrFormat { hdr10, hlg, dolbyVision }
+
+/// Header and EXIF facts about an image, from `get_image_info`; no pixels are decoded
+class ImageInfo {
+  final int width;
+  final int height;
+  final String format;
+  final int bitDepth;
+  final bool hasAlpha;
+  final int orientation;
+  final String? cameraMake;
+  final String? cameraModel;
+  final String? captureDate;
+  final GpsLocation? gps;
+  final String? iccProfileName;
+  final int imageCount;
+  final int auxiliaryImageCount;
+  final bool hasDepth;
+
+  const ImageInfo({
+    required this.width,
+    required this.height,
+    required this.format,
+    required this.bitDepth,
+    required this.hasAlpha,
+    required this.orientation,
+    this.cameraMake,
+    this.cameraModel,
+    this.captureDate,
+    this.gps,
+    this.iccProfileName,
+    required this.imageCount,
+    required this.auxiliaryImageCount,
+    required this.hasDepth,
+  });
+
+  @override
+  int get hashCode =>
       width.hashCode ^
       height.hashCode ^
-      sampleDurationMs.hashCode;
+      format.hashCode ^
+      bitDepth.hashCode ^
+      hasAlpha.hashCode ^
+      orientation.hashCode ^
+      cameraMake.hashCode ^
+      cameraModel.hashCode ^
+      captureDate.hashCode ^
+      gps.hashCode ^
+      iccProfileName.hashCode ^
+      imageCount.hashCode ^
+      auxiliaryImageCount.hashCode ^
+      hasDepth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageInfo &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          format == other.format &&
+          bitDepth == other.bitDepth &&
+          hasAlpha == other.hasAlpha &&
+          orientation == other.orientation &&
+          cameraMake == other.cameraMake &&
+          cameraModel == other.cameraModel &&
+          captureDate == other.captureDate &&
+          gps == other.gps &&
+          iccProfileName == other.iccProfileName &&
+          imageCount == other.imageCount &&
+          auxiliaryImageCount == other.auxiliaryImageCount &&
+          hasDepth == other.hasDepth;
+}
+
+class ImageThumbnailParams {
+  final ThumbnailSizeType? sizeType;
+  final OutputFormat? format;
+  final SeekMode? seekMode;
+  final ResizeMode? resizeMode;
+  final bool? allowUpscale;
+  final EncoderOptions? encoderOptions;
+
+  const ImageThumbnailParams({
+    this.sizeType,
+    this.format,
+    this.seekMode,
+    this.resizeMode,
+    this.allowUpscale,
+    this.encoderOptions,
+  });
+
+  @override
+  int get hashCode =>
+      sizeType.hashCode ^
+      format.hashCode ^
+      seekMode.hashCode ^
+      resizeMode.hashCode ^
+      allowUpscale.hashCode ^
+      encoderOptions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageThumbnailParams &&
+          runtimeType == other.runtimeType &&
+          sizeType == other.sizeType &&
+          format == other.format &&
+          seekMode == other.seekMode &&
+          resizeMode == other.resizeMode &&
+          allowUpscale == other.allowUpscale &&
+          encoderOptions == other.encoderOptions;
+}
+
+/// EBU R128 measurement of an audio track
+class LoudnessInfo {
+  final double integratedLufs;
+  final double loudnessRangeLu;
+  final double truePeakDbtp;
+
+  const LoudnessInfo({
+    required this.integratedLufs,
+    required this.loudnessRangeLu,
+    required this.truePeakDbtp,
+  });
+
+  @override
+  int get hashCode =>
+      integratedLufs.hashCode ^
+      loudnessRangeLu.hashCode ^
+      truePeakDbtp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoudnessInfo &&
+          runtimeType == other.runtimeType &&
+          integratedLufs == other.integratedLufs &&
+          loudnessRangeLu == other.loudnessRangeLu &&
+          truePeakDbtp == other.truePeakDbtp;
+}
+
+/// Single gain applied so the integrated loudness hits the target
+class LoudnessNormalization {
+  final double targetLufs;
+  final double? truePeakDbtp;
+
+  const LoudnessNormalization({required this.targetLufs, this.truePeakDbtp});
+
+  @override
+  int get hashCode => targetLufs.hashCode ^ truePeakDbtp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoudnessNormalization &&
+          runtimeType == other.runtimeType &&
+          targetLufs == other.targetLufs &&
+          truePeakDbtp == other.truePeakDbtp;
+}
+
+class MediaChapter {
+  final PlatformInt64 id;
+  final BigInt startMs;
+  final BigInt endMs;
+  final String? title;
+  final List<MediaTag> tags;
+
+  const MediaChapter({
+    required this.id,
+    required this.startMs,
+    required this.endMs,
+    this.title,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      startMs.hashCode ^
+      endMs.hashCode ^
+      title.hashCode ^
+      tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaChapter &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          title == other.title &&
+          tags == other.tags;
+}
+
+/// 64-bit perceptual hashes of an image or of frames sampled across a video
+class MediaHash {
+  final HashAlgorithm algorithm;
+  final Uint64List hashes;
+  final Uint64List timesMs;
+
+  const MediaHash({
+    required this.algorithm,
+    required this.hashes,
+    required this.timesMs,
+  });
+
+  @override
+  int get hashCode => algorithm.hashCode ^ hashes.hashCode ^ timesMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is CompressParams &&
+      other is MediaHash &&
           runtimeType == other.runtimeType &&
-          targetBitrateKbps == other.targetBitrateKbps &&
-          preset == other.preset &&
-          crf == other.crf &&
-          width == other.width &&
-          height == other.height &&
-          sampleDurationMs == other.sampleDurationMs;
+          algorithm == other.algorithm &&
+          hashes == other.hashes &&
+          timesMs == other.timesMs;
 }
 
-class CompressionEstimate {
-  final BigInt estimatedSizeBytes;
-  final BigInt estimatedDurationMs;
+/// Container and streams of a media file, from `probe_media`
+class MediaProbe {
+  final String formatName;
+  final String? formatLongName;
+  final BigInt? durationMs;
+  final PlatformInt64? startTimeMs;
+  final BigInt? bitrate;
+  final BigInt sizeBytes;
+  final List<MediaTag> tags;
+  final List<MediaChapter> chapters;
+  final List<StreamProbe> streams;
 
-  const CompressionEstimate({
-    required this.estimatedSizeBytes,
-    required this.estimatedDurationMs,
+  const MediaProbe({
+    required this.formatName,
+    this.formatLongName,
+    this.durationMs,
+    this.startTimeMs,
+    this.bitrate,
+    required this.sizeBytes,
+    required this.tags,
+    required this.chapters,
+    required this.streams,
   });
 
   @override
   int get hashCode =>
-      estimatedSizeBytes.hashCode ^ estimatedDurationMs.hashCode;
+      formatName.hashCode ^
+      formatLongName.hashCode ^
+      durationMs.hashCode ^
+      startTimeMs.hashCode ^
+      bitrate.hashCode ^
+      sizeBytes.hashCode ^
+      tags.hashCode ^
+      chapters.hashCode ^
+      streams.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is CompressionEstimate &&
+      other is MediaProbe &&
           runtimeType == other.runtimeType &&
-          estimatedSizeBytes == other.estimatedSizeBytes &&
-          estimatedDurationMs == other.estimatedDurationMs;
+          formatName == other.formatName &&
+          formatLongName == other.formatLongName &&
+          durationMs == other.durationMs &&
+          startTimeMs == other.startTimeMs &&
+          bitrate == other.bitrate &&
+          sizeBytes == other.sizeBytes &&
+          tags == other.tags &&
+          chapters == other.chapters &&
+          streams == other.streams;
 }
 
-class ImageThumbnailParams {
-  final ThumbnailSizeType? sizeType;
-  final OutputFormat? format;
+/// Container or stream metadata entry, in file order
+class MediaTag {
+  final String key;
+  final String value;
 
-  const ImageThumbnailParams({this.sizeType, this.format});
+  const MediaTag({required this.key, required this.value});
 
   @override
-  int get hashCode => sizeType.hashCode ^ format.hashCode;
+  int get hashCode => key.hashCode ^ value.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ImageThumbnailParams &&
+      other is MediaTag &&
           runtimeType == other.runtimeType &&
-          sizeType == other.sizeType &&
-          format == other.format;
+          key == other.key &&
+          value == other.value;
 }
 
 enum OutputFormat {
   webp,
   jpeg,
-  png;
+  png,
+  avif,
+  heic;
 
   Future<void> extension_() =>
       RustLib.instance.api.crateApiMediaOutputFormatExtension(that: this);
 }
 
+/// Dominant colours and Android-style swatches from `extract_palette`. A swatch is None
+/// when no colour falls in its saturation and lightness range.
+class Palette {
+  final List<PaletteColor> colors;
+  final PaletteColor average;
+  final PaletteColor? vibrant;
+  final PaletteColor? lightVibrant;
+  final PaletteColor? darkVibrant;
+  final PaletteColor? muted;
+  final PaletteColor? lightMuted;
+  final PaletteColor? darkMuted;
+
+  const Palette({
+    required this.colors,
+    required this.average,
+    this.vibrant,
+    this.lightVibrant,
+    this.darkVibrant,
+    this.muted,
+    this.lightMuted,
+    this.darkMuted,
+  });
+
+  @override
+  int get hashCode =>
+      colors.hashCode ^
+      average.hashCode ^
+      vibrant.hashCode ^
+      lightVibrant.hashCode ^
+      darkVibrant.hashCode ^
+      muted.hashCode ^
+      lightMuted.hashCode ^
+      darkMuted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Palette &&
+          runtimeType == other.runtimeType &&
+          colors == other.colors &&
+          average == other.average &&
+          vibrant == other.vibrant &&
+          lightVibrant == other.lightVibrant &&
+          darkVibrant == other.darkVibrant &&
+          muted == other.muted &&
+          lightMuted == other.lightMuted &&
+          darkMuted == other.darkMuted;
+}
+
+/// One colour of a `Palette`
+class PaletteColor {
+  final int color;
+  final double population;
+
+  const PaletteColor({required this.color, required this.population});
+
+  @override
+  int get hashCode => color.hashCode ^ population.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaletteColor &&
+          runtimeType == other.runtimeType &&
+          color == other.color &&
+          population == other.population;
+}
+
+/// Small RGBA image rendered from a BlurHash or ThumbHash
+class PlaceholderImage {
+  final Uint8List rgba;
+  final int width;
+  final int height;
+
+  const PlaceholderImage({
+    required this.rgba,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode => rgba.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaceholderImage &&
+          runtimeType == other.runtimeType &&
+          rgba == other.rgba &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// How a thumbnail is fitted into its `ThumbnailSizeType` box
+enum ResizeMode { fit, fill, exact, fitWidth, fitHeight }
+
 class ResolutionPreset {
   final String name;
   final int width;
@@ -197,6 +1479,393 @@ class ResolutionPreset {
           crf == other.crf;
 }
 
+/// What happens to the audio track of a reversed clip
+enum ReverseAudioMode { reverse, mute }
+
+class ReverseParams {
+  final BigInt? startMs;
+  final BigInt? endMs;
+  final int? boomerangLoops;
+  final ReverseAudioMode? audio;
+  final int? width;
+  final int? height;
+  final int? targetBitrateKbps;
+  final String? preset;
+  final int? crf;
+  final int? memoryBudgetMb;
+
+  const ReverseParams({
+    this.startMs,
+    this.endMs,
+    this.boomerangLoops,
+    this.audio,
+    this.width,
+    this.height,
+    this.targetBitrateKbps,
+    this.preset,
+    this.crf,
+    this.memoryBudgetMb,
+  });
+
+  @override
+  int get hashCode =>
+      startMs.hashCode ^
+      endMs.hashCode ^
+      boomerangLoops.hashCode ^
+      audio.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      targetBitrateKbps.hashCode ^
+      preset.hashCode ^
+      crf.hashCode ^
+      memoryBudgetMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReverseParams &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          boomerangLoops == other.boomerangLoops &&
+          audio == other.audio &&
+          width == other.width &&
+          height == other.height &&
+          targetBitrateKbps == other.targetBitrateKbps &&
+          preset == other.preset &&
+          crf == other.crf &&
+          memoryBudgetMb == other.memoryBudgetMb;
+}
+
+/// Start of a new scene found by `detect_scenes`
+class SceneCut {
+  final BigInt timeMs;
+  final double score;
+
+  const SceneCut({required this.timeMs, required this.score});
+
+  @override
+  int get hashCode => timeMs.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SceneCut &&
+          runtimeType == other.runtimeType &&
+          timeMs == other.timeMs &&
+          score == other.score;
+}
+
+@freezed
+sealed class SceneDetectionEvent with _$SceneDetectionEvent {
+  const SceneDetectionEvent._();
+
+  const factory SceneDetectionEvent.progress(CompressProgress field0) =
+      SceneDetectionEvent_Progress;
+  const factory SceneDetectionEvent.cuts(List<SceneCut> field0) =
+      SceneDetectionEvent_Cuts;
+}
+
+/// How a thumbnail position is located in the video
+enum SeekMode { accurate, keyframe }
+
+class SpeedParams {
+  final double factor;
+  final int? frameRate;
+  final double? muteAudioAbove;
+
+  const SpeedParams({
+    required this.factor,
+    this.frameRate,
+    this.muteAudioAbove,
+  });
+
+  @override
+  int get hashCode =>
+      factor.hashCode ^ frameRate.hashCode ^ muteAudioAbove.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpeedParams &&
+          runtimeType == other.runtimeType &&
+          factor == other.factor &&
+          frameRate == other.frameRate &&
+          muteAudioAbove == other.muteAudioAbove;
+}
+
+/// Files written by `generate_storyboard`
+class Storyboard {
+  final String vttPath;
+  final List<String> spritePaths;
+  final int tileCount;
+
+  const Storyboard({
+    required this.vttPath,
+    required this.spritePaths,
+    required this.tileCount,
+  });
+
+  @override
+  int get hashCode =>
+      vttPath.hashCode ^ spritePaths.hashCode ^ tileCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Storyboard &&
+          runtimeType == other.runtimeType &&
+          vttPath == other.vttPath &&
+          spritePaths == other.spritePaths &&
+          tileCount == other.tileCount;
+}
+
+/// Stream disposition flags, as ffprobe reports them
+class StreamDisposition {
+  final bool default_;
+  final bool dub;
+  final bool original;
+  final bool comment;
+  final bool lyrics;
+  final bool karaoke;
+  final bool forced;
+  final bool hearingImpaired;
+  final bool visualImpaired;
+  final bool cleanEffects;
+  final bool attachedPic;
+  final bool captions;
+  final bool descriptions;
+  final bool metadata;
+
+  const StreamDisposition({
+    required this.default_,
+    required this.dub,
+    required this.original,
+    required this.comment,
+    required this.lyrics,
+    required this.karaoke,
+    required this.forced,
+    required this.hearingImpaired,
+    required this.visualImpaired,
+    required this.cleanEffects,
+    required this.attachedPic,
+    required this.captions,
+    required this.descriptions,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode =>
+      default_.hashCode ^
+      dub.hashCode ^
+      original.hashCode ^
+      comment.hashCode ^
+      lyrics.hashCode ^
+      karaoke.hashCode ^
+      forced.hashCode ^
+      hearingImpaired.hashCode ^
+      visualImpaired.hashCode ^
+      cleanEffects.hashCode ^
+      attachedPic.hashCode ^
+      captions.hashCode ^
+      descriptions.hashCode ^
+      metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StreamDisposition &&
+          runtimeType == other.runtimeType &&
+          default_ == other.default_ &&
+          dub == other.dub &&
+          original == other.original &&
+          comment == other.comment &&
+          lyrics == other.lyrics &&
+          karaoke == other.karaoke &&
+          forced == other.forced &&
+          hearingImpaired == other.hearingImpaired &&
+          visualImpaired == other.visualImpaired &&
+          cleanEffects == other.cleanEffects &&
+          attachedPic == other.attachedPic &&
+          captions == other.captions &&
+          descriptions == other.descriptions &&
+          metadata == other.metadata;
+}
+
+/// One stream of `MediaProbe`. `video` or `audio` is set for streams of that type.
+class StreamProbe {
+  final int index;
+  final StreamType streamType;
+  final String? codecName;
+  final String? codecLongName;
+  final String? profile;
+  final BigInt? bitrate;
+  final PlatformInt64? startTimeMs;
+  final BigInt? durationMs;
+  final BigInt? frameCount;
+  final String? language;
+  final String? title;
+  final StreamDisposition disposition;
+  final List<MediaTag> tags;
+  final List<StreamSideData> sideData;
+  final VideoStreamProbe? video;
+  final AudioStreamProbe? audio;
+
+  const StreamProbe({
+    required this.index,
+    required this.streamType,
+    this.codecName,
+    this.codecLongName,
+    this.profile,
+    this.bitrate,
+    this.startTimeMs,
+    this.durationMs,
+    this.frameCount,
+    this.language,
+    this.title,
+    required this.disposition,
+    required this.tags,
+    required this.sideData,
+    this.video,
+    this.audio,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      streamType.hashCode ^
+      codecName.hashCode ^
+      codecLongName.hashCode ^
+      profile.hashCode ^
+      bitrate.hashCode ^
+      startTimeMs.hashCode ^
+      durationMs.hashCode ^
+      frameCount.hashCode ^
+      language.hashCode ^
+      title.hashCode ^
+      disposition.hashCode ^
+      tags.hashCode ^
+      sideData.hashCode ^
+      video.hashCode ^
+      audio.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StreamProbe &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          streamType == other.streamType &&
+          codecName == other.codecName &&
+          codecLongName == other.codecLongName &&
+          profile == other.profile &&
+          bitrate == other.bitrate &&
+          startTimeMs == other.startTimeMs &&
+          durationMs == other.durationMs &&
+          frameCount == other.frameCount &&
+          language == other.language &&
+          title == other.title &&
+          disposition == other.disposition &&
+          tags == other.tags &&
+          sideData == other.sideData &&
+          video == other.video &&
+          audio == other.audio;
+}
+
+/// Side data attached to a stream (display matrix, HDR metadata, Dolby Vision config, ...)
+class StreamSideData {
+  final String kind;
+  final int sizeBytes;
+
+  const StreamSideData({required this.kind, required this.sizeBytes});
+
+  @override
+  int get hashCode => kind.hashCode ^ sizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StreamSideData &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          sizeBytes == other.sizeBytes;
+}
+
+/// Kind of data a stream carries
+enum StreamType { video, audio, subtitle, data, attachment, unknown }
+
+class SubtitleParams {
+  final String subtitlePath;
+  final String? fontPath;
+  final int? fontSize;
+  final int? color;
+  final int? outlineColor;
+  final int? outlineWidth;
+  final int? bottomMargin;
+
+  const SubtitleParams({
+    required this.subtitlePath,
+    this.fontPath,
+    this.fontSize,
+    this.color,
+    this.outlineColor,
+    this.outlineWidth,
+    this.bottomMargin,
+  });
+
+  @override
+  int get hashCode =>
+      subtitlePath.hashCode ^
+      fontPath.hashCode ^
+      fontSize.hashCode ^
+      color.hashCode ^
+      outlineColor.hashCode ^
+      outlineWidth.hashCode ^
+      bottomMargin.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SubtitleParams &&
+          runtimeType == other.runtimeType &&
+          subtitlePath == other.subtitlePath &&
+          fontPath == other.fontPath &&
+          fontSize == other.fontSize &&
+          color == other.color &&
+          outlineColor == other.outlineColor &&
+          outlineWidth == other.outlineWidth &&
+          bottomMargin == other.bottomMargin;
+}
+
+/// Encoded thumbnail returned by the in-memory thumbnail functions
+class ThumbnailData {
+  final Uint8List data;
+  final int width;
+  final int height;
+  final BigInt? timeMs;
+
+  const ThumbnailData({
+    required this.data,
+    required this.width,
+    required this.height,
+    this.timeMs,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^ width.hashCode ^ height.hashCode ^ timeMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThumbnailData &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          width == other.width &&
+          height == other.height &&
+          timeMs == other.timeMs;
+}
+
 @freezed
 sealed class ThumbnailSizeType with _$ThumbnailSizeType {
   const ThumbnailSizeType._();
@@ -221,6 +1890,23 @@ class VideoInfo {
   final BigInt? bitrate;
   final String? codecName;
   final String? formatName;
+  final int storedWidth;
+  final int storedHeight;
+  final int rotation;
+  final double? avgFrameRate;
+  final double? realFrameRate;
+  final bool isVfr;
+  final String? pixelFormat;
+  final int? bitDepth;
+  final String? colorPrimaries;
+  final String? colorTransfer;
+  final String? colorMatrix;
+  final String? colorRange;
+  final HdrFormat? hdrFormat;
+  final String? profile;
+  final int? level;
+  final BigInt? frameCount;
+  final AudioStreamInfo? audio;
   final List<ResolutionPreset> suggestions;
 
   const VideoInfo({
@@ -231,6 +1917,23 @@ class VideoInfo {
     this.bitrate,
     this.codecName,
     this.formatName,
+    required this.storedWidth,
+    required this.storedHeight,
+    required this.rotation,
+    this.avgFrameRate,
+    this.realFrameRate,
+    required this.isVfr,
+    this.pixelFormat,
+    this.bitDepth,
+    this.colorPrimaries,
+    this.colorTransfer,
+    this.colorMatrix,
+    this.colorRange,
+    this.hdrFormat,
+    this.profile,
+    this.level,
+    this.frameCount,
+    this.audio,
     required this.suggestions,
   });
 
@@ -243,6 +1946,23 @@ class VideoInfo {
       bitrate.hashCode ^
       codecName.hashCode ^
       formatName.hashCode ^
+      storedWidth.hashCode ^
+      storedHeight.hashCode ^
+      rotation.hashCode ^
+      avgFrameRate.hashCode ^
+      realFrameRate.hashCode ^
+      isVfr.hashCode ^
+      pixelFormat.hashCode ^
+      bitDepth.hashCode ^
+      colorPrimaries.hashCode ^
+      colorTransfer.hashCode ^
+      colorMatrix.hashCode ^
+      colorRange.hashCode ^
+      hdrFormat.hashCode ^
+      profile.hashCode ^
+      level.hashCode ^
+      frameCount.hashCode ^
+      audio.hashCode ^
       suggestions.hashCode;
 
   @override
@@ -257,22 +1977,133 @@ class VideoInfo {
           bitrate == other.bitrate &&
           codecName == other.codecName &&
           formatName == other.formatName &&
+          storedWidth == other.storedWidth &&
+          storedHeight == other.storedHeight &&
+          rotation == other.rotation &&
+          avgFrameRate == other.avgFrameRate &&
+          realFrameRate == other.realFrameRate &&
+          isVfr == other.isVfr &&
+          pixelFormat == other.pixelFormat &&
+          bitDepth == other.bitDepth &&
+          colorPrimaries == other.colorPrimaries &&
+          colorTransfer == other.colorTransfer &&
+          colorMatrix == other.colorMatrix &&
+          colorRange == other.colorRange &&
+          hdrFormat == other.hdrFormat &&
+          profile == other.profile &&
+          level == other.level &&
+          frameCount == other.frameCount &&
+          audio == other.audio &&
           suggestions == other.suggestions;
 }
 
+class VideoStreamProbe {
+  final int width;
+  final int height;
+  final int displayWidth;
+  final int displayHeight;
+  final int rotation;
+  final double? avgFrameRate;
+  final double? realFrameRate;
+  final bool isVfr;
+  final String? pixelFormat;
+  final int? bitDepth;
+  final String? colorPrimaries;
+  final String? colorTransfer;
+  final String? colorMatrix;
+  final String? colorRange;
+  final HdrFormat? hdrFormat;
+  final int? level;
+
+  const VideoStreamProbe({
+    required this.width,
+    required this.height,
+    required this.displayWidth,
+    required this.displayHeight,
+    required this.rotation,
+    this.avgFrameRate,
+    this.realFrameRate,
+    required this.isVfr,
+    this.pixelFormat,
+    this.bitDepth,
+    this.colorPrimaries,
+    this.colorTransfer,
+    this.colorMatrix,
+    this.colorRange,
+    this.hdrFormat,
+    this.level,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      displayWidth.hashCode ^
+      displayHeight.hashCode ^
+      rotation.hashCode ^
+      avgFrameRate.hashCode ^
+      realFrameRate.hashCode ^
+      isVfr.hashCode ^
+      pixelFormat.hashCode ^
+      bitDepth.hashCode ^
+      colorPrimaries.hashCode ^
+      colorTransfer.hashCode ^
+      colorMatrix.hashCode ^
+      colorRange.hashCode ^
+      hdrFormat.hashCode ^
+      level.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VideoStreamProbe &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          displayWidth == other.displayWidth &&
+          displayHeight == other.displayHeight &&
+          rotation == other.rotation &&
+          avgFrameRate == other.avgFrameRate &&
+          realFrameRate == other.realFrameRate &&
+          isVfr == other.isVfr &&
+          pixelFormat == other.pixelFormat &&
+          bitDepth == other.bitDepth &&
+          colorPrimaries == other.colorPrimaries &&
+          colorTransfer == other.colorTransfer &&
+          colorMatrix == other.colorMatrix &&
+          colorRange == other.colorRange &&
+          hdrFormat == other.hdrFormat &&
+          level == other.level;
+}
+
 class VideoThumbnailParams {
   final BigInt timeMs;
   final ThumbnailSizeType? sizeType;
   final OutputFormat? format;
+  final SeekMode? seekMode;
+  final ResizeMode? resizeMode;
+  final bool? allowUpscale;
+  final EncoderOptions? encoderOptions;
 
   const VideoThumbnailParams({
     required this.timeMs,
     this.sizeType,
     this.format,
+    this.seekMode,
+    this.resizeMode,
+    this.allowUpscale,
+    this.encoderOptions,
   });
 
   @override
-  int get hashCode => timeMs.hashCode ^ sizeType.hashCode ^ format.hashCode;
+  int get hashCode =>
+      timeMs.hashCode ^
+      sizeType.hashCode ^
+      format.hashCode ^
+      seekMode.hashCode ^
+      resizeMode.hashCode ^
+      allowUpscale.hashCode ^
+      encoderOptions.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -281,5 +2112,55 @@ class VideoThumbnailParams {
           runtimeType == other.runtimeType &&
           timeMs == other.timeMs &&
           sizeType == other.sizeType &&
-          format == other.format;
+          format == other.format &&
+          seekMode == other.seekMode &&
+          resizeMode == other.resizeMode &&
+          allowUpscale == other.allowUpscale &&
+          encoderOptions == other.encoderOptions;
+}
+
+class WatermarkParams {
+  final String imagePath;
+  final WatermarkPosition? position;
+  final int? margin;
+  final double? scale;
+  final double? opacity;
+  final BigInt? startMs;
+  final BigInt? endMs;
+
+  const WatermarkParams({
+    required this.imagePath,
+    this.position,
+    this.margin,
+    this.scale,
+    this.opacity,
+    this.startMs,
+    this.endMs,
+  });
+
+  @override
+  int get hashCode =>
+      imagePath.hashCode ^
+      position.hashCode ^
+      margin.hashCode ^
+      scale.hashCode ^
+      opacity.hashCode ^
+      startMs.hashCode ^
+      endMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatermarkParams &&
+          runtimeType == other.runtimeType &&
+          imagePath == other.imagePath &&
+          position == other.position &&
+          margin == other.margin &&
+          scale == other.scale &&
+          opacity == other.opacity &&
+          startMs == other.startMs &&
+          endMs == other.endMs;
 }
+
+/// Corner (or centre) of the frame a watermark is anchored to
+enum WatermarkPosition { topLeft, topRight, bottomLeft, bottomRight, center }
@@ -15,6 +15,899 @@ final _privateConstructorUsedError = UnsupportedError(
   'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
 );
 
+/// @nodoc
+mixin _$FrameSelection {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint64List field0) timestamps,
+    required TResult Function(BigInt field0) interval,
+    required TResult Function(int field0) count,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint64List field0)? timestamps,
+    TResult? Function(BigInt field0)? interval,
+    TResult? Function(int field0)? count,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint64List field0)? timestamps,
+    TResult Function(BigInt field0)? interval,
+    TResult Function(int field0)? count,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FrameSelection_Timestamps value) timestamps,
+    required TResult Function(FrameSelection_Interval value) interval,
+    required TResult Function(FrameSelection_Count value) count,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FrameSelection_Timestamps value)? timestamps,
+    TResult? Function(FrameSelection_Interval value)? interval,
+    TResult? Function(FrameSelection_Count value)? count,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FrameSelection_Timestamps value)? timestamps,
+    TResult Function(FrameSelection_Interval value)? interval,
+    TResult Function(FrameSelection_Count value)? count,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FrameSelectionCopyWith<$Res> {
+  factory $FrameSelectionCopyWith(
+    FrameSelection value,
+    $Res Function(FrameSelection) then,
+  ) = _$FrameSelectionCopyWithImpl<$Res, FrameSelection>;
+}
+
+/// @nodoc
+class _$FrameSelectionCopyWithImpl<$Res, $Val extends FrameSelection>
+    implements $FrameSelectionCopyWith<$Res> {
+  _$FrameSelectionCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$FrameSelection_TimestampsImplCopyWith<$Res> {
+  factory _$$FrameSelection_TimestampsImplCopyWith(
+    _$FrameSelection_TimestampsImpl value,
+    $Res Function(_$FrameSelection_TimestampsImpl) then,
+  ) = __$$FrameSelection_TimestampsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint64List field0});
+}
+
+/// @nodoc
+class __$$FrameSelection_TimestampsImplCopyWithImpl<$Res>
+    extends _$FrameSelectionCopyWithImpl<$Res, _$FrameSelection_TimestampsImpl>
+    implements _$$FrameSelection_TimestampsImplCopyWith<$Res> {
+  __$$FrameSelection_TimestampsImplCopyWithImpl(
+    _$FrameSelection_TimestampsImpl _value,
+    $Res Function(_$FrameSelection_TimestampsImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$FrameSelection_TimestampsImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as Uint64List,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$FrameSelection_TimestampsImpl extends FrameSelection_Timestamps {
+  const _$FrameSelection_TimestampsImpl(this.field0) : super._();
+
+  @override
+  final Uint64List field0;
+
+  @override
+  String toString() {
+    return 'FrameSelection.timestamps(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FrameSelection_TimestampsImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FrameSelection_TimestampsImplCopyWith<_$FrameSelection_TimestampsImpl>
+  get copyWith =>
+      __$$FrameSelection_TimestampsImplCopyWithImpl<
+        _$FrameSelection_TimestampsImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint64List field0) timestamps,
+    required TResult Function(BigInt field0) interval,
+    required TResult Function(int field0) count,
+  }) {
+    return timestamps(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint64List field0)? timestamps,
+    TResult? Function(BigInt field0)? interval,
+    TResult? Function(int field0)? count,
+  }) {
+    return timestamps?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint64List field0)? timestamps,
+    TResult Function(BigInt field0)? interval,
+    TResult Function(int field0)? count,
+    required TResult orElse(),
+  }) {
+    if (timestamps != null) {
+      return timestamps(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FrameSelection_Timestamps value) timestamps,
+    required TResult Function(FrameSelection_Interval value) interval,
+    required TResult Function(FrameSelection_Count value) count,
+  }) {
+    return timestamps(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FrameSelection_Timestamps value)? timestamps,
+    TResult? Function(FrameSelection_Interval value)? interval,
+    TResult? Function(FrameSelection_Count value)? count,
+  }) {
+    return timestamps?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FrameSelection_Timestamps value)? timestamps,
+    TResult Function(FrameSelection_Interval value)? interval,
+    TResult Function(FrameSelection_Count value)? count,
+    required TResult orElse(),
+  }) {
+    if (timestamps != null) {
+      return timestamps(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FrameSelection_Timestamps extends FrameSelection {
+  const factory FrameSelection_Timestamps(final Uint64List field0) =
+      _$FrameSelection_TimestampsImpl;
+  const FrameSelection_Timestamps._() : super._();
+
+  Uint64List get field0;
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FrameSelection_TimestampsImplCopyWith<_$FrameSelection_TimestampsImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FrameSelection_IntervalImplCopyWith<$Res> {
+  factory _$$FrameSelection_IntervalImplCopyWith(
+    _$FrameSelection_IntervalImpl value,
+    $Res Function(_$FrameSelection_IntervalImpl) then,
+  ) = __$$FrameSelection_IntervalImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt field0});
+}
+
+/// @nodoc
+class __$$FrameSelection_IntervalImplCopyWithImpl<$Res>
+    extends _$FrameSelectionCopyWithImpl<$Res, _$FrameSelection_IntervalImpl>
+    implements _$$FrameSelection_IntervalImplCopyWith<$Res> {
+  __$$FrameSelection_IntervalImplCopyWithImpl(
+    _$FrameSelection_IntervalImpl _value,
+    $Res Function(_$FrameSelection_IntervalImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$FrameSelection_IntervalImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$FrameSelection_IntervalImpl extends FrameSelection_Interval {
+  const _$FrameSelection_IntervalImpl(this.field0) : super._();
+
+  @override
+  final BigInt field0;
+
+  @override
+  String toString() {
+    return 'FrameSelection.interval(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FrameSelection_IntervalImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FrameSelection_IntervalImplCopyWith<_$FrameSelection_IntervalImpl>
+  get copyWith =>
+      __$$FrameSelection_IntervalImplCopyWithImpl<
+        _$FrameSelection_IntervalImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint64List field0) timestamps,
+    required TResult Function(BigInt field0) interval,
+    required TResult Function(int field0) count,
+  }) {
+    return interval(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint64List field0)? timestamps,
+    TResult? Function(BigInt field0)? interval,
+    TResult? Function(int field0)? count,
+  }) {
+    return interval?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint64List field0)? timestamps,
+    TResult Function(BigInt field0)? interval,
+    TResult Function(int field0)? count,
+    required TResult orElse(),
+  }) {
+    if (interval != null) {
+      return interval(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FrameSelection_Timestamps value) timestamps,
+    required TResult Function(FrameSelection_Interval value) interval,
+    required TResult Function(FrameSelection_Count value) count,
+  }) {
+    return interval(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FrameSelection_Timestamps value)? timestamps,
+    TResult? Function(FrameSelection_Interval value)? interval,
+    TResult? Function(FrameSelection_Count value)? count,
+  }) {
+    return interval?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FrameSelection_Timestamps value)? timestamps,
+    TResult Function(FrameSelection_Interval value)? interval,
+    TResult Function(FrameSelection_Count value)? count,
+    required TResult orElse(),
+  }) {
+    if (interval != null) {
+      return interval(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FrameSelection_Interval extends FrameSelection {
+  const factory FrameSelection_Interval(final BigInt field0) =
+      _$FrameSelection_IntervalImpl;
+  const FrameSelection_Interval._() : super._();
+
+  BigInt get field0;
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FrameSelection_IntervalImplCopyWith<_$FrameSelection_IntervalImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$FrameSelection_CountImplCopyWith<$Res> {
+  factory _$$FrameSelection_CountImplCopyWith(
+    _$FrameSelection_CountImpl value,
+    $Res Function(_$FrameSelection_CountImpl) then,
+  ) = __$$FrameSelection_CountImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int field0});
+}
+
+/// @nodoc
+class __$$FrameSelection_CountImplCopyWithImpl<$Res>
+    extends _$FrameSelectionCopyWithImpl<$Res, _$FrameSelection_CountImpl>
+    implements _$$FrameSelection_CountImplCopyWith<$Res> {
+  __$$FrameSelection_CountImplCopyWithImpl(
+    _$FrameSelection_CountImpl _value,
+    $Res Function(_$FrameSelection_CountImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$FrameSelection_CountImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as int,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$FrameSelection_CountImpl extends FrameSelection_Count {
+  const _$FrameSelection_CountImpl(this.field0) : super._();
+
+  @override
+  final int field0;
+
+  @override
+  String toString() {
+    return 'FrameSelection.count(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FrameSelection_CountImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FrameSelection_CountImplCopyWith<_$FrameSelection_CountImpl>
+  get copyWith =>
+      __$$FrameSelection_CountImplCopyWithImpl<_$FrameSelection_CountImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint64List field0) timestamps,
+    required TResult Function(BigInt field0) interval,
+    required TResult Function(int field0) count,
+  }) {
+    return count(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint64List field0)? timestamps,
+    TResult? Function(BigInt field0)? interval,
+    TResult? Function(int field0)? count,
+  }) {
+    return count?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint64List field0)? timestamps,
+    TResult Function(BigInt field0)? interval,
+    TResult Function(int field0)? count,
+    required TResult orElse(),
+  }) {
+    if (count != null) {
+      return count(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FrameSelection_Timestamps value) timestamps,
+    required TResult Function(FrameSelection_Interval value) interval,
+    required TResult Function(FrameSelection_Count value) count,
+  }) {
+    return count(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FrameSelection_Timestamps value)? timestamps,
+    TResult? Function(FrameSelection_Interval value)? interval,
+    TResult? Function(FrameSelection_Count value)? count,
+  }) {
+    return count?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FrameSelection_Timestamps value)? timestamps,
+    TResult Function(FrameSelection_Interval value)? interval,
+    TResult Function(FrameSelection_Count value)? count,
+    required TResult orElse(),
+  }) {
+    if (count != null) {
+      return count(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FrameSelection_Count extends FrameSelection {
+  const factory FrameSelection_Count(final int field0) =
+      _$FrameSelection_CountImpl;
+  const FrameSelection_Count._() : super._();
+
+  int get field0;
+
+  /// Create a copy of FrameSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FrameSelection_CountImplCopyWith<_$FrameSelection_CountImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$SceneDetectionEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CompressProgress field0) progress,
+    required TResult Function(List<SceneCut> field0) cuts,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CompressProgress field0)? progress,
+    TResult? Function(List<SceneCut> field0)? cuts,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CompressProgress field0)? progress,
+    TResult Function(List<SceneCut> field0)? cuts,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SceneDetectionEvent_Progress value) progress,
+    required TResult Function(SceneDetectionEvent_Cuts value) cuts,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SceneDetectionEvent_Progress value)? progress,
+    TResult? Function(SceneDetectionEvent_Cuts value)? cuts,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SceneDetectionEvent_Progress value)? progress,
+    TResult Function(SceneDetectionEvent_Cuts value)? cuts,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SceneDetectionEventCopyWith<$Res> {
+  factory $SceneDetectionEventCopyWith(
+    SceneDetectionEvent value,
+    $Res Function(SceneDetectionEvent) then,
+  ) = _$SceneDetectionEventCopyWithImpl<$Res, SceneDetectionEvent>;
+}
+
+/// @nodoc
+class _$SceneDetectionEventCopyWithImpl<$Res, $Val extends SceneDetectionEvent>
+    implements $SceneDetectionEventCopyWith<$Res> {
+  _$SceneDetectionEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SceneDetectionEvent_ProgressImplCopyWith<$Res> {
+  factory _$$SceneDetectionEvent_ProgressImplCopyWith(
+    _$SceneDetectionEvent_ProgressImpl value,
+    $Res Function(_$SceneDetectionEvent_ProgressImpl) then,
+  ) = __$$SceneDetectionEvent_ProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({CompressProgress field0});
+}
+
+/// @nodoc
+class __$$SceneDetectionEvent_ProgressImplCopyWithImpl<$Res>
+    extends
+        _$SceneDetectionEventCopyWithImpl<
+          $Res,
+          _$SceneDetectionEvent_ProgressImpl
+        >
+    implements _$$SceneDetectionEvent_ProgressImplCopyWith<$Res> {
+  __$$SceneDetectionEvent_ProgressImplCopyWithImpl(
+    _$SceneDetectionEvent_ProgressImpl _value,
+    $Res Function(_$SceneDetectionEvent_ProgressImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$SceneDetectionEvent_ProgressImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as CompressProgress,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SceneDetectionEvent_ProgressImpl extends SceneDetectionEvent_Progress {
+  const _$SceneDetectionEvent_ProgressImpl(this.field0) : super._();
+
+  @override
+  final CompressProgress field0;
+
+  @override
+  String toString() {
+    return 'SceneDetectionEvent.progress(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SceneDetectionEvent_ProgressImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SceneDetectionEvent_ProgressImplCopyWith<
+    _$SceneDetectionEvent_ProgressImpl
+  >
+  get copyWith =>
+      __$$SceneDetectionEvent_ProgressImplCopyWithImpl<
+        _$SceneDetectionEvent_ProgressImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CompressProgress field0) progress,
+    required TResult Function(List<SceneCut> field0) cuts,
+  }) {
+    return progress(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CompressProgress field0)? progress,
+    TResult? Function(List<SceneCut> field0)? cuts,
+  }) {
+    return progress?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CompressProgress field0)? progress,
+    TResult Function(List<SceneCut> field0)? cuts,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SceneDetectionEvent_Progress value) progress,
+    required TResult Function(SceneDetectionEvent_Cuts value) cuts,
+  }) {
+    return progress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SceneDetectionEvent_Progress value)? progress,
+    TResult? Function(SceneDetectionEvent_Cuts value)? cuts,
+  }) {
+    return progress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SceneDetectionEvent_Progress value)? progress,
+    TResult Function(SceneDetectionEvent_Cuts value)? cuts,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SceneDetectionEvent_Progress extends SceneDetectionEvent {
+  const factory SceneDetectionEvent_Progress(final CompressProgress field0) =
+      _$SceneDetectionEvent_ProgressImpl;
+  const SceneDetectionEvent_Progress._() : super._();
+
+  CompressProgress get field0;
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SceneDetectionEvent_ProgressImplCopyWith<
+    _$SceneDetectionEvent_ProgressImpl
+  >
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SceneDetectionEvent_CutsImplCopyWith<$Res> {
+  factory _$$SceneDetectionEvent_CutsImplCopyWith(
+    _$SceneDetectionEvent_CutsImpl value,
+    $Res Function(_$SceneDetectionEvent_CutsImpl) then,
+  ) = __$$SceneDetectionEvent_CutsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<SceneCut> field0});
+}
+
+/// @nodoc
+class __$$SceneDetectionEvent_CutsImplCopyWithImpl<$Res>
+    extends
+        _$SceneDetectionEventCopyWithImpl<$Res, _$SceneDetectionEvent_CutsImpl>
+    implements _$$SceneDetectionEvent_CutsImplCopyWith<$Res> {
+  __$$SceneDetectionEvent_CutsImplCopyWithImpl(
+    _$SceneDetectionEvent_CutsImpl _value,
+    $Res Function(_$SceneDetectionEvent_CutsImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$SceneDetectionEvent_CutsImpl(
+        null == field0
+            ? _value._field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as List<SceneCut>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$SceneDetectionEvent_CutsImpl extends SceneDetectionEvent_Cuts {
+  const _$SceneDetectionEvent_CutsImpl(final List<SceneCut> field0)
+    : _field0 = field0,
+      super._();
+
+  final List<SceneCut> _field0;
+  @override
+  List<SceneCut> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'SceneDetectionEvent.cuts(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SceneDetectionEvent_CutsImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SceneDetectionEvent_CutsImplCopyWith<_$SceneDetectionEvent_CutsImpl>
+  get copyWith =>
+      __$$SceneDetectionEvent_CutsImplCopyWithImpl<
+        _$SceneDetectionEvent_CutsImpl
+      >(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(CompressProgress field0) progress,
+    required TResult Function(List<SceneCut> field0) cuts,
+  }) {
+    return cuts(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(CompressProgress field0)? progress,
+    TResult? Function(List<SceneCut> field0)? cuts,
+  }) {
+    return cuts?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(CompressProgress field0)? progress,
+    TResult Function(List<SceneCut> field0)? cuts,
+    required TResult orElse(),
+  }) {
+    if (cuts != null) {
+      return cuts(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SceneDetectionEvent_Progress value) progress,
+    required TResult Function(SceneDetectionEvent_Cuts value) cuts,
+  }) {
+    return cuts(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SceneDetectionEvent_Progress value)? progress,
+    TResult? Function(SceneDetectionEvent_Cuts value)? cuts,
+  }) {
+    return cuts?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SceneDetectionEvent_Progress value)? progress,
+    TResult Function(SceneDetectionEvent_Cuts value)? cuts,
+    required TResult orElse(),
+  }) {
+    if (cuts != null) {
+      return cuts(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SceneDetectionEvent_Cuts extends SceneDetectionEvent {
+  const factory SceneDetectionEvent_Cuts(final List<SceneCut> field0) =
+      _$SceneDetectionEvent_CutsImpl;
+  const SceneDetectionEvent_Cuts._() : super._();
+
+  List<SceneCut> get field0;
+
+  /// Create a copy of SceneDetectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SceneDetectionEvent_CutsImplCopyWith<_$SceneDetectionEvent_CutsImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$ThumbnailSizeType {
   @optionalTypeArgs
@@ -65,7 +65,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1535488819;
+  int get rustContentHash => -593138137;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,20 +76,121 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Stream<CompressProgress> crateApiMediaAddMusic({
+    required String videoPath,
+    required String musicPath,
+    required String outputPath,
+    required AudioMixParams params,
+  });
+
+  Future<LoudnessInfo> crateApiMediaAnalyzeLoudness({required String path});
+
+  Future<void> crateApiMediaAnimationFormatExtension({
+    required AnimationFormat that,
+  });
+
+  Future<void> crateApiMediaAudioFormatExtension({required AudioFormat that});
+
+  Future<HashComparison> crateApiMediaCompareMediaHashes({
+    required MediaHash a,
+    required MediaHash b,
+    int? threshold,
+  });
+
   Future<String> crateApiMediaCompressVideo({
     required String path,
     required String outputPath,
     required CompressParams params,
   });
 
+  Future<String> crateApiMediaComputeBlurhash({
+    required String path,
+    required int componentsX,
+    required int componentsY,
+    BigInt? timeMs,
+  });
+
+  Future<MediaHash> crateApiMediaComputeImageHash({
+    required String path,
+    HashAlgorithm? algorithm,
+  });
+
+  Future<Uint8List> crateApiMediaComputeThumbhash({
+    required String path,
+    BigInt? timeMs,
+  });
+
+  Future<MediaHash> crateApiMediaComputeVideoHash({
+    required String path,
+    HashAlgorithm? algorithm,
+    int? frameCount,
+  });
+
+  Stream<CompressProgress> crateApiMediaConcatVideos({
+    required List<String> inputs,
+    required String outputPath,
+    required ConcatParams params,
+  });
+
   Future<void> crateApiLoggerDebugThreads();
 
+  Future<PlaceholderImage> crateApiMediaDecodeBlurhash({
+    required String hash,
+    required int width,
+    required int height,
+    double? punch,
+  });
+
+  Future<PlaceholderImage> crateApiMediaDecodeThumbhash({
+    required List<int> hash,
+  });
+
+  Stream<SceneDetectionEvent> crateApiMediaDetectScenes({
+    required String path,
+    required double threshold,
+  });
+
   Future<CompressionEstimate> crateApiMediaEstimateCompression({
     required String path,
     required String tempOutputPath,
     required CompressParams params,
   });
 
+  Future<String> crateApiMediaExportAnimation({
+    required String path,
+    required String outputPath,
+    required BigInt startMs,
+    required BigInt durationMs,
+    required int fps,
+    required int maxWidth,
+    required AnimationFormat format,
+    required AnimationOptions options,
+  });
+
+  Future<String> crateApiMediaExtractAudio({
+    required String path,
+    required String outputPath,
+    required AudioFormat format,
+    required AudioExtractOptions options,
+  });
+
+  Stream<ExtractedFrame> crateApiMediaExtractFrames({
+    required String path,
+    required String outputPath,
+    required FrameExtractParams params,
+  });
+
+  Future<Palette> crateApiMediaExtractPalette({
+    required String path,
+    required int count,
+    BigInt? timeMs,
+  });
+
+  Future<BestThumbnail> crateApiMediaGenerateBestThumbnail({
+    required String path,
+    required BestThumbnailParams params,
+  });
+
   Future<String> crateApiMediaGenerateImageThumbnail({
     required String path,
     required String outputPath,
@@ -97,6 +198,22 @@ abstract class RustLibApi extends BaseApi {
     String? suffix,
   });
 
+  Future<ThumbnailData> crateApiMediaGenerateImageThumbnailData({
+    required String path,
+    ImageThumbnailParams? params,
+  });
+
+  Future<Storyboard> crateApiMediaGenerateStoryboard({
+    required String path,
+    required String outputDir,
+    required BigInt intervalMs,
+    required int tileWidth,
+    required int tileHeight,
+    required int columns,
+    required int rows,
+    EncoderOptions? encoderOptions,
+  });
+
   Future<String> crateApiMediaGenerateVideoThumbnail({
     required String path,
     required String outputPath,
@@ -104,6 +221,17 @@ abstract class RustLibApi extends BaseApi {
     bool? emptyImageFallback,
   });
 
+  Future<ThumbnailData> crateApiMediaGenerateVideoThumbnailData({
+    required String path,
+    required VideoThumbnailParams params,
+  });
+
+  Stream<ThumbnailData> crateApiMediaGenerateVideoTimelineThumbnailData({
+    required String path,
+    ImageThumbnailParams? params,
+    required int numThumbnails,
+  });
+
   Stream<String> crateApiMediaGenerateVideoTimelineThumbnails({
     required String path,
     required String outputPath,
@@ -112,6 +240,8 @@ abstract class RustLibApi extends BaseApi {
     bool? emptyImageFallback,
   });
 
+  Future<ImageInfo> crateApiMediaGetImageInfo({required String path});
+
   Future<VideoInfo> crateApiMediaGetVideoInfo({required String path});
 
   Future<void> crateApiLoggerInitLogger({
@@ -131,10 +261,20 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiMediaOutputFormatExtension({required OutputFormat that});
 
+  Future<MediaProbe> crateApiMediaProbeMedia({required String path});
+
+  Future<String> crateApiMediaProbeMediaJson({required String path});
+
   Future<void> crateApiLoggerReloadTracingFileWriter({
     required WriteToFiles writeToFiles,
   });
 
+  Stream<CompressProgress> crateApiMediaReverseVideo({
+    required String path,
+    required String outputPath,
+    required ReverseParams params,
+  });
+
   Future<(int, int)> crateApiMediaThumbnailSizeTypeDimensions({
     required ThumbnailSizeType that,
   });
@@ -148,6 +288,166 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Stream<CompressProgress> crateApiMediaAddMusic({
+    required String videoPath,
+    required String musicPath,
+    required String outputPath,
+    required AudioMixParams params,
+  }) {
+    final sink = RustStreamSink<CompressProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(videoPath);
+            var arg1 = cst_encode_String(musicPath);
+            var arg2 = cst_encode_String(outputPath);
+            var arg3 = cst_encode_box_autoadd_audio_mix_params(params);
+            var arg4 = cst_encode_StreamSink_compress_progress_Dco(sink);
+            return wire.wire__crate__api__media__add_music(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaAddMusicConstMeta,
+          argValues: [videoPath, musicPath, outputPath, params, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaAddMusicConstMeta => const TaskConstMeta(
+    debugName: "add_music",
+    argNames: ["videoPath", "musicPath", "outputPath", "params", "sink"],
+  );
+
+  @override
+  Future<LoudnessInfo> crateApiMediaAnalyzeLoudness({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__media__analyze_loudness(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_loudness_info,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaAnalyzeLoudnessConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaAnalyzeLoudnessConstMeta =>
+      const TaskConstMeta(debugName: "analyze_loudness", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiMediaAnimationFormatExtension({
+    required AnimationFormat that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_animation_format(that);
+          return wire.wire__crate__api__media__animation_format_extension(
+            port_,
+            arg0,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaAnimationFormatExtensionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaAnimationFormatExtensionConstMeta =>
+      const TaskConstMeta(
+        debugName: "animation_format_extension",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiMediaAudioFormatExtension({required AudioFormat that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_audio_format(that);
+          return wire.wire__crate__api__media__audio_format_extension(
+            port_,
+            arg0,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaAudioFormatExtensionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaAudioFormatExtensionConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_format_extension",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<HashComparison> crateApiMediaCompareMediaHashes({
+    required MediaHash a,
+    required MediaHash b,
+    int? threshold,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_box_autoadd_media_hash(a);
+          var arg1 = cst_encode_box_autoadd_media_hash(b);
+          var arg2 = cst_encode_opt_box_autoadd_u_32(threshold);
+          return wire.wire__crate__api__media__compare_media_hashes(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_hash_comparison,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaCompareMediaHashesConstMeta,
+        argValues: [a, b, threshold],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaCompareMediaHashesConstMeta =>
+      const TaskConstMeta(
+        debugName: "compare_media_hashes",
+        argNames: ["a", "b", "threshold"],
+      );
+
   @override
   Future<String> crateApiMediaCompressVideo({
     required String path,
@@ -184,185 +484,175 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<void> crateApiLoggerDebugThreads() {
+  Future<String> crateApiMediaComputeBlurhash({
+    required String path,
+    required int componentsX,
+    required int componentsY,
+    BigInt? timeMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          return wire.wire__crate__api__logger__debug_threads(port_);
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_u_32(componentsX);
+          var arg2 = cst_encode_u_32(componentsY);
+          var arg3 = cst_encode_opt_box_autoadd_u_64(timeMs);
+          return wire.wire__crate__api__media__compute_blurhash(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggerDebugThreadsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaComputeBlurhashConstMeta,
+        argValues: [path, componentsX, componentsY, timeMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerDebugThreadsConstMeta =>
-      const TaskConstMeta(debugName: "debug_threads", argNames: []);
+  TaskConstMeta get kCrateApiMediaComputeBlurhashConstMeta =>
+      const TaskConstMeta(
+        debugName: "compute_blurhash",
+        argNames: ["path", "componentsX", "componentsY", "timeMs"],
+      );
 
   @override
-  Future<CompressionEstimate> crateApiMediaEstimateCompression({
+  Future<MediaHash> crateApiMediaComputeImageHash({
     required String path,
-    required String tempOutputPath,
-    required CompressParams params,
+    HashAlgorithm? algorithm,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           var arg0 = cst_encode_String(path);
-          var arg1 = cst_encode_String(tempOutputPath);
-          var arg2 = cst_encode_box_autoadd_compress_params(params);
-          return wire.wire__crate__api__media__estimate_compression(
+          var arg1 = cst_encode_opt_box_autoadd_hash_algorithm(algorithm);
+          return wire.wire__crate__api__media__compute_image_hash(
             port_,
             arg0,
             arg1,
-            arg2,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_compression_estimate,
+          decodeSuccessData: dco_decode_media_hash,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaEstimateCompressionConstMeta,
-        argValues: [path, tempOutputPath, params],
+        constMeta: kCrateApiMediaComputeImageHashConstMeta,
+        argValues: [path, algorithm],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaEstimateCompressionConstMeta =>
+  TaskConstMeta get kCrateApiMediaComputeImageHashConstMeta =>
       const TaskConstMeta(
-        debugName: "estimate_compression",
-        argNames: ["path", "tempOutputPath", "params"],
+        debugName: "compute_image_hash",
+        argNames: ["path", "algorithm"],
       );
 
   @override
-  Future<String> crateApiMediaGenerateImageThumbnail({
+  Future<Uint8List> crateApiMediaComputeThumbhash({
     required String path,
-    required String outputPath,
-    ImageThumbnailParams? params,
-    String? suffix,
+    BigInt? timeMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           var arg0 = cst_encode_String(path);
-          var arg1 = cst_encode_String(outputPath);
-          var arg2 = cst_encode_opt_box_autoadd_image_thumbnail_params(params);
-          var arg3 = cst_encode_opt_String(suffix);
-          return wire.wire__crate__api__media__generate_image_thumbnail(
+          var arg1 = cst_encode_opt_box_autoadd_u_64(timeMs);
+          return wire.wire__crate__api__media__compute_thumbhash(
             port_,
             arg0,
             arg1,
-            arg2,
-            arg3,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGenerateImageThumbnailConstMeta,
-        argValues: [path, outputPath, params, suffix],
+        constMeta: kCrateApiMediaComputeThumbhashConstMeta,
+        argValues: [path, timeMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGenerateImageThumbnailConstMeta =>
+  TaskConstMeta get kCrateApiMediaComputeThumbhashConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_image_thumbnail",
-        argNames: ["path", "outputPath", "params", "suffix"],
+        debugName: "compute_thumbhash",
+        argNames: ["path", "timeMs"],
       );
 
   @override
-  Future<String> crateApiMediaGenerateVideoThumbnail({
+  Future<MediaHash> crateApiMediaComputeVideoHash({
     required String path,
-    required String outputPath,
-    required VideoThumbnailParams params,
-    bool? emptyImageFallback,
+    HashAlgorithm? algorithm,
+    int? frameCount,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           var arg0 = cst_encode_String(path);
-          var arg1 = cst_encode_String(outputPath);
-          var arg2 = cst_encode_box_autoadd_video_thumbnail_params(params);
-          var arg3 = cst_encode_opt_box_autoadd_bool(emptyImageFallback);
-          return wire.wire__crate__api__media__generate_video_thumbnail(
+          var arg1 = cst_encode_opt_box_autoadd_hash_algorithm(algorithm);
+          var arg2 = cst_encode_opt_box_autoadd_u_32(frameCount);
+          return wire.wire__crate__api__media__compute_video_hash(
             port_,
             arg0,
             arg1,
             arg2,
-            arg3,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_media_hash,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGenerateVideoThumbnailConstMeta,
-        argValues: [path, outputPath, params, emptyImageFallback],
+        constMeta: kCrateApiMediaComputeVideoHashConstMeta,
+        argValues: [path, algorithm, frameCount],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGenerateVideoThumbnailConstMeta =>
+  TaskConstMeta get kCrateApiMediaComputeVideoHashConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_video_thumbnail",
-        argNames: ["path", "outputPath", "params", "emptyImageFallback"],
+        debugName: "compute_video_hash",
+        argNames: ["path", "algorithm", "frameCount"],
       );
 
   @override
-  Stream<String> crateApiMediaGenerateVideoTimelineThumbnails({
-    required String path,
+  Stream<CompressProgress> crateApiMediaConcatVideos({
+    required List<String> inputs,
     required String outputPath,
-    ImageThumbnailParams? params,
-    required int numThumbnails,
-    bool? emptyImageFallback,
+    required ConcatParams params,
   }) {
-    final sink = RustStreamSink<String>();
+    final sink = RustStreamSink<CompressProgress>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
-            var arg0 = cst_encode_String(path);
+            var arg0 = cst_encode_list_String(inputs);
             var arg1 = cst_encode_String(outputPath);
-            var arg2 = cst_encode_opt_box_autoadd_image_thumbnail_params(
-              params,
+            var arg2 = cst_encode_box_autoadd_concat_params(params);
+            var arg3 = cst_encode_StreamSink_compress_progress_Dco(sink);
+            return wire.wire__crate__api__media__concat_videos(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
             );
-            var arg3 = cst_encode_u_32(numThumbnails);
-            var arg4 = cst_encode_opt_box_autoadd_bool(emptyImageFallback);
-            var arg5 = cst_encode_StreamSink_String_Dco(sink);
-            return wire
-                .wire__crate__api__media__generate_video_timeline_thumbnails(
-                  port_,
-                  arg0,
-                  arg1,
-                  arg2,
-                  arg3,
-                  arg4,
-                  arg5,
-                );
           },
           codec: DcoCodec(
             decodeSuccessData: dco_decode_unit,
             decodeErrorData: dco_decode_AnyhowException,
           ),
-          constMeta: kCrateApiMediaGenerateVideoTimelineThumbnailsConstMeta,
-          argValues: [
-            path,
-            outputPath,
-            params,
-            numThumbnails,
-            emptyImageFallback,
-            sink,
-          ],
+          constMeta: kCrateApiMediaConcatVideosConstMeta,
+          argValues: [inputs, outputPath, params, sink],
           apiImpl: this,
         ),
       ),
@@ -370,56 +660,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiMediaGenerateVideoTimelineThumbnailsConstMeta =>
-      const TaskConstMeta(
-        debugName: "generate_video_timeline_thumbnails",
-        argNames: [
-          "path",
-          "outputPath",
-          "params",
-          "numThumbnails",
-          "emptyImageFallback",
-          "sink",
-        ],
-      );
+  TaskConstMeta get kCrateApiMediaConcatVideosConstMeta => const TaskConstMeta(
+    debugName: "concat_videos",
+    argNames: ["inputs", "outputPath", "params", "sink"],
+  );
 
   @override
-  Future<VideoInfo> crateApiMediaGetVideoInfo({required String path}) {
+  Future<void> crateApiLoggerDebugThreads() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__media__get_video_info(port_, arg0);
+          return wire.wire__crate__api__logger__debug_threads(port_);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_video_info,
+          decodeSuccessData: dco_decode_unit,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetVideoInfoConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiLoggerDebugThreadsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetVideoInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_video_info", argNames: ["path"]);
+  TaskConstMeta get kCrateApiLoggerDebugThreadsConstMeta =>
+      const TaskConstMeta(debugName: "debug_threads", argNames: []);
 
   @override
-  Future<void> crateApiLoggerInitLogger({
-    required LogLevel logLevel,
-    required bool writeToStdoutOrSystem,
-    WriteToFiles? writeToFiles,
-    required bool useLightweightTokioRuntime,
+  Future<PlaceholderImage> crateApiMediaDecodeBlurhash({
+    required String hash,
+    required int width,
+    required int height,
+    double? punch,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_log_level(logLevel);
-          var arg1 = cst_encode_bool(writeToStdoutOrSystem);
-          var arg2 = cst_encode_opt_box_autoadd_write_to_files(writeToFiles);
-          var arg3 = cst_encode_bool(useLightweightTokioRuntime);
-          return wire.wire__crate__api__logger__init_logger(
+          var arg0 = cst_encode_String(hash);
+          var arg1 = cst_encode_u_32(width);
+          var arg2 = cst_encode_u_32(height);
+          var arg3 = cst_encode_opt_box_autoadd_f_32(punch);
+          return wire.wire__crate__api__media__decode_blurhash(
             port_,
             arg0,
             arg1,
@@ -428,1210 +709,6131 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_placeholder_image,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggerInitLoggerConstMeta,
-        argValues: [
-          logLevel,
-          writeToStdoutOrSystem,
-          writeToFiles,
-          useLightweightTokioRuntime,
-        ],
+        constMeta: kCrateApiMediaDecodeBlurhashConstMeta,
+        argValues: [hash, width, height, punch],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta => const TaskConstMeta(
-    debugName: "init_logger",
-    argNames: [
-      "logLevel",
-      "writeToStdoutOrSystem",
-      "writeToFiles",
-      "useLightweightTokioRuntime",
-    ],
+  TaskConstMeta get kCrateApiMediaDecodeBlurhashConstMeta =>
+      const TaskConstMeta(
+        debugName: "decode_blurhash",
+        argNames: ["hash", "width", "height", "punch"],
+      );
+
+  @override
+  Future<PlaceholderImage> crateApiMediaDecodeThumbhash({
+    required List<int> hash,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_list_prim_u_8_loose(hash);
+          return wire.wire__crate__api__media__decode_thumbhash(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_placeholder_image,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaDecodeThumbhashConstMeta,
+        argValues: [hash],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaDecodeThumbhashConstMeta =>
+      const TaskConstMeta(debugName: "decode_thumbhash", argNames: ["hash"]);
+
+  @override
+  Stream<SceneDetectionEvent> crateApiMediaDetectScenes({
+    required String path,
+    required double threshold,
+  }) {
+    final sink = RustStreamSink<SceneDetectionEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(path);
+            var arg1 = cst_encode_f_64(threshold);
+            var arg2 = cst_encode_StreamSink_scene_detection_event_Dco(sink);
+            return wire.wire__crate__api__media__detect_scenes(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaDetectScenesConstMeta,
+          argValues: [path, threshold, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaDetectScenesConstMeta => const TaskConstMeta(
+    debugName: "detect_scenes",
+    argNames: ["path", "threshold", "sink"],
   );
 
   @override
-  Future<void> crateApiLoggerLog({
-    required String file,
-    int? line,
-    required LogLevel level,
-    required String target,
-    required String message,
+  Future<CompressionEstimate> crateApiMediaEstimateCompression({
+    required String path,
+    required String tempOutputPath,
+    required CompressParams params,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_String(file);
-          var arg1 = cst_encode_opt_box_autoadd_u_32(line);
-          var arg2 = cst_encode_log_level(level);
-          var arg3 = cst_encode_String(target);
-          var arg4 = cst_encode_String(message);
-          return wire.wire__crate__api__logger__log(
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(tempOutputPath);
+          var arg2 = cst_encode_box_autoadd_compress_params(params);
+          return wire.wire__crate__api__media__estimate_compression(
             port_,
             arg0,
             arg1,
             arg2,
-            arg3,
-            arg4,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_compression_estimate,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggerLogConstMeta,
-        argValues: [file, line, level, target, message],
+        constMeta: kCrateApiMediaEstimateCompressionConstMeta,
+        argValues: [path, tempOutputPath, params],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerLogConstMeta => const TaskConstMeta(
-    debugName: "log",
-    argNames: ["file", "line", "level", "target", "message"],
-  );
+  TaskConstMeta get kCrateApiMediaEstimateCompressionConstMeta =>
+      const TaskConstMeta(
+        debugName: "estimate_compression",
+        argNames: ["path", "tempOutputPath", "params"],
+      );
 
   @override
-  Future<void> crateApiMediaOutputFormatExtension({
-    required OutputFormat that,
+  Future<String> crateApiMediaExportAnimation({
+    required String path,
+    required String outputPath,
+    required BigInt startMs,
+    required BigInt durationMs,
+    required int fps,
+    required int maxWidth,
+    required AnimationFormat format,
+    required AnimationOptions options,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_output_format(that);
-          return wire.wire__crate__api__media__output_format_extension(
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(outputPath);
+          var arg2 = cst_encode_u_64(startMs);
+          var arg3 = cst_encode_u_64(durationMs);
+          var arg4 = cst_encode_u_32(fps);
+          var arg5 = cst_encode_u_32(maxWidth);
+          var arg6 = cst_encode_animation_format(format);
+          var arg7 = cst_encode_box_autoadd_animation_options(options);
+          return wire.wire__crate__api__media__export_animation(
             port_,
             arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+            arg6,
+            arg7,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaOutputFormatExtensionConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMediaExportAnimationConstMeta,
+        argValues: [
+          path,
+          outputPath,
+          startMs,
+          durationMs,
+          fps,
+          maxWidth,
+          format,
+          options,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaOutputFormatExtensionConstMeta =>
+  TaskConstMeta get kCrateApiMediaExportAnimationConstMeta =>
       const TaskConstMeta(
-        debugName: "output_format_extension",
-        argNames: ["that"],
+        debugName: "export_animation",
+        argNames: [
+          "path",
+          "outputPath",
+          "startMs",
+          "durationMs",
+          "fps",
+          "maxWidth",
+          "format",
+          "options",
+        ],
       );
 
   @override
-  Future<void> crateApiLoggerReloadTracingFileWriter({
-    required WriteToFiles writeToFiles,
+  Future<String> crateApiMediaExtractAudio({
+    required String path,
+    required String outputPath,
+    required AudioFormat format,
+    required AudioExtractOptions options,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_box_autoadd_write_to_files(writeToFiles);
-          return wire.wire__crate__api__logger__reload_tracing_file_writer(
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(outputPath);
+          var arg2 = cst_encode_audio_format(format);
+          var arg3 = cst_encode_box_autoadd_audio_extract_options(options);
+          return wire.wire__crate__api__media__extract_audio(
             port_,
             arg0,
+            arg1,
+            arg2,
+            arg3,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggerReloadTracingFileWriterConstMeta,
-        argValues: [writeToFiles],
+        constMeta: kCrateApiMediaExtractAudioConstMeta,
+        argValues: [path, outputPath, format, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggerReloadTracingFileWriterConstMeta =>
+  TaskConstMeta get kCrateApiMediaExtractAudioConstMeta => const TaskConstMeta(
+    debugName: "extract_audio",
+    argNames: ["path", "outputPath", "format", "options"],
+  );
+
+  @override
+  Stream<ExtractedFrame> crateApiMediaExtractFrames({
+    required String path,
+    required String outputPath,
+    required FrameExtractParams params,
+  }) {
+    final sink = RustStreamSink<ExtractedFrame>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(path);
+            var arg1 = cst_encode_String(outputPath);
+            var arg2 = cst_encode_box_autoadd_frame_extract_params(params);
+            var arg3 = cst_encode_StreamSink_extracted_frame_Dco(sink);
+            return wire.wire__crate__api__media__extract_frames(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaExtractFramesConstMeta,
+          argValues: [path, outputPath, params, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaExtractFramesConstMeta => const TaskConstMeta(
+    debugName: "extract_frames",
+    argNames: ["path", "outputPath", "params", "sink"],
+  );
+
+  @override
+  Future<Palette> crateApiMediaExtractPalette({
+    required String path,
+    required int count,
+    BigInt? timeMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_u_32(count);
+          var arg2 = cst_encode_opt_box_autoadd_u_64(timeMs);
+          return wire.wire__crate__api__media__extract_palette(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_palette,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaExtractPaletteConstMeta,
+        argValues: [path, count, timeMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaExtractPaletteConstMeta =>
       const TaskConstMeta(
-        debugName: "reload_tracing_file_writer",
-        argNames: ["writeToFiles"],
+        debugName: "extract_palette",
+        argNames: ["path", "count", "timeMs"],
       );
 
   @override
-  Future<(int, int)> crateApiMediaThumbnailSizeTypeDimensions({
-    required ThumbnailSizeType that,
+  Future<BestThumbnail> crateApiMediaGenerateBestThumbnail({
+    required String path,
+    required BestThumbnailParams params,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          var arg0 = cst_encode_box_autoadd_thumbnail_size_type(that);
-          return wire.wire__crate__api__media__thumbnail_size_type_dimensions(
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_box_autoadd_best_thumbnail_params(params);
+          return wire.wire__crate__api__media__generate_best_thumbnail(
             port_,
             arg0,
+            arg1,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_record_u_32_u_32,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_best_thumbnail,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaThumbnailSizeTypeDimensionsConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiMediaGenerateBestThumbnailConstMeta,
+        argValues: [path, params],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaThumbnailSizeTypeDimensionsConstMeta =>
+  TaskConstMeta get kCrateApiMediaGenerateBestThumbnailConstMeta =>
       const TaskConstMeta(
-        debugName: "thumbnail_size_type_dimensions",
-        argNames: ["that"],
+        debugName: "generate_best_thumbnail",
+        argNames: ["path", "params"],
       );
 
-  @protected
+  @override
+  Future<String> crateApiMediaGenerateImageThumbnail({
+    required String path,
+    required String outputPath,
+    ImageThumbnailParams? params,
+    String? suffix,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(outputPath);
+          var arg2 = cst_encode_opt_box_autoadd_image_thumbnail_params(params);
+          var arg3 = cst_encode_opt_String(suffix);
+          return wire.wire__crate__api__media__generate_image_thumbnail(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateImageThumbnailConstMeta,
+        argValues: [path, outputPath, params, suffix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateImageThumbnailConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_image_thumbnail",
+        argNames: ["path", "outputPath", "params", "suffix"],
+      );
+
+  @override
+  Future<ThumbnailData> crateApiMediaGenerateImageThumbnailData({
+    required String path,
+    ImageThumbnailParams? params,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_opt_box_autoadd_image_thumbnail_params(params);
+          return wire.wire__crate__api__media__generate_image_thumbnail_data(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_thumbnail_data,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateImageThumbnailDataConstMeta,
+        argValues: [path, params],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateImageThumbnailDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_image_thumbnail_data",
+        argNames: ["path", "params"],
+      );
+
+  @override
+  Future<Storyboard> crateApiMediaGenerateStoryboard({
+    required String path,
+    required String outputDir,
+    required BigInt intervalMs,
+    required int tileWidth,
+    required int tileHeight,
+    required int columns,
+    required int rows,
+    EncoderOptions? encoderOptions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(outputDir);
+          var arg2 = cst_encode_u_64(intervalMs);
+          var arg3 = cst_encode_u_32(tileWidth);
+          var arg4 = cst_encode_u_32(tileHeight);
+          var arg5 = cst_encode_u_32(columns);
+          var arg6 = cst_encode_u_32(rows);
+          var arg7 = cst_encode_opt_box_autoadd_encoder_options(encoderOptions);
+          return wire.wire__crate__api__media__generate_storyboard(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+            arg6,
+            arg7,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_storyboard,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateStoryboardConstMeta,
+        argValues: [
+          path,
+          outputDir,
+          intervalMs,
+          tileWidth,
+          tileHeight,
+          columns,
+          rows,
+          encoderOptions,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateStoryboardConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_storyboard",
+        argNames: [
+          "path",
+          "outputDir",
+          "intervalMs",
+          "tileWidth",
+          "tileHeight",
+          "columns",
+          "rows",
+          "encoderOptions",
+        ],
+      );
+
+  @override
+  Future<String> crateApiMediaGenerateVideoThumbnail({
+    required String path,
+    required String outputPath,
+    required VideoThumbnailParams params,
+    bool? emptyImageFallback,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_String(outputPath);
+          var arg2 = cst_encode_box_autoadd_video_thumbnail_params(params);
+          var arg3 = cst_encode_opt_box_autoadd_bool(emptyImageFallback);
+          return wire.wire__crate__api__media__generate_video_thumbnail(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateVideoThumbnailConstMeta,
+        argValues: [path, outputPath, params, emptyImageFallback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateVideoThumbnailConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_video_thumbnail",
+        argNames: ["path", "outputPath", "params", "emptyImageFallback"],
+      );
+
+  @override
+  Future<ThumbnailData> crateApiMediaGenerateVideoThumbnailData({
+    required String path,
+    required VideoThumbnailParams params,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          var arg1 = cst_encode_box_autoadd_video_thumbnail_params(params);
+          return wire.wire__crate__api__media__generate_video_thumbnail_data(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_thumbnail_data,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateVideoThumbnailDataConstMeta,
+        argValues: [path, params],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateVideoThumbnailDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_video_thumbnail_data",
+        argNames: ["path", "params"],
+      );
+
+  @override
+  Stream<ThumbnailData> crateApiMediaGenerateVideoTimelineThumbnailData({
+    required String path,
+    ImageThumbnailParams? params,
+    required int numThumbnails,
+  }) {
+    final sink = RustStreamSink<ThumbnailData>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(path);
+            var arg1 = cst_encode_opt_box_autoadd_image_thumbnail_params(
+              params,
+            );
+            var arg2 = cst_encode_u_32(numThumbnails);
+            var arg3 = cst_encode_StreamSink_thumbnail_data_Dco(sink);
+            return wire
+                .wire__crate__api__media__generate_video_timeline_thumbnail_data(
+                  port_,
+                  arg0,
+                  arg1,
+                  arg2,
+                  arg3,
+                );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaGenerateVideoTimelineThumbnailDataConstMeta,
+          argValues: [path, params, numThumbnails, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateVideoTimelineThumbnailDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_video_timeline_thumbnail_data",
+        argNames: ["path", "params", "numThumbnails", "sink"],
+      );
+
+  @override
+  Stream<String> crateApiMediaGenerateVideoTimelineThumbnails({
+    required String path,
+    required String outputPath,
+    ImageThumbnailParams? params,
+    required int numThumbnails,
+    bool? emptyImageFallback,
+  }) {
+    final sink = RustStreamSink<String>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(path);
+            var arg1 = cst_encode_String(outputPath);
+            var arg2 = cst_encode_opt_box_autoadd_image_thumbnail_params(
+              params,
+            );
+            var arg3 = cst_encode_u_32(numThumbnails);
+            var arg4 = cst_encode_opt_box_autoadd_bool(emptyImageFallback);
+            var arg5 = cst_encode_StreamSink_String_Dco(sink);
+            return wire
+                .wire__crate__api__media__generate_video_timeline_thumbnails(
+                  port_,
+                  arg0,
+                  arg1,
+                  arg2,
+                  arg3,
+                  arg4,
+                  arg5,
+                );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaGenerateVideoTimelineThumbnailsConstMeta,
+          argValues: [
+            path,
+            outputPath,
+            params,
+            numThumbnails,
+            emptyImageFallback,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateVideoTimelineThumbnailsConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_video_timeline_thumbnails",
+        argNames: [
+          "path",
+          "outputPath",
+          "params",
+          "numThumbnails",
+          "emptyImageFallback",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<ImageInfo> crateApiMediaGetImageInfo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__media__get_image_info(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_image_info,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetImageInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetImageInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_image_info", argNames: ["path"]);
+
+  @override
+  Future<VideoInfo> crateApiMediaGetVideoInfo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__media__get_video_info(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_video_info,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetVideoInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetVideoInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_video_info", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiLoggerInitLogger({
+    required LogLevel logLevel,
+    required bool writeToStdoutOrSystem,
+    WriteToFiles? writeToFiles,
+    required bool useLightweightTokioRuntime,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_log_level(logLevel);
+          var arg1 = cst_encode_bool(writeToStdoutOrSystem);
+          var arg2 = cst_encode_opt_box_autoadd_write_to_files(writeToFiles);
+          var arg3 = cst_encode_bool(useLightweightTokioRuntime);
+          return wire.wire__crate__api__logger__init_logger(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoggerInitLoggerConstMeta,
+        argValues: [
+          logLevel,
+          writeToStdoutOrSystem,
+          writeToFiles,
+          useLightweightTokioRuntime,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerInitLoggerConstMeta => const TaskConstMeta(
+    debugName: "init_logger",
+    argNames: [
+      "logLevel",
+      "writeToStdoutOrSystem",
+      "writeToFiles",
+      "useLightweightTokioRuntime",
+    ],
+  );
+
+  @override
+  Future<void> crateApiLoggerLog({
+    required String file,
+    int? line,
+    required LogLevel level,
+    required String target,
+    required String message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(file);
+          var arg1 = cst_encode_opt_box_autoadd_u_32(line);
+          var arg2 = cst_encode_log_level(level);
+          var arg3 = cst_encode_String(target);
+          var arg4 = cst_encode_String(message);
+          return wire.wire__crate__api__logger__log(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggerLogConstMeta,
+        argValues: [file, line, level, target, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerLogConstMeta => const TaskConstMeta(
+    debugName: "log",
+    argNames: ["file", "line", "level", "target", "message"],
+  );
+
+  @override
+  Future<void> crateApiMediaOutputFormatExtension({
+    required OutputFormat that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_output_format(that);
+          return wire.wire__crate__api__media__output_format_extension(
+            port_,
+            arg0,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaOutputFormatExtensionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaOutputFormatExtensionConstMeta =>
+      const TaskConstMeta(
+        debugName: "output_format_extension",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<MediaProbe> crateApiMediaProbeMedia({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__media__probe_media(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_media_probe,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaProbeMediaConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaProbeMediaConstMeta =>
+      const TaskConstMeta(debugName: "probe_media", argNames: ["path"]);
+
+  @override
+  Future<String> crateApiMediaProbeMediaJson({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__media__probe_media_json(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaProbeMediaJsonConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaProbeMediaJsonConstMeta =>
+      const TaskConstMeta(debugName: "probe_media_json", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiLoggerReloadTracingFileWriter({
+    required WriteToFiles writeToFiles,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_box_autoadd_write_to_files(writeToFiles);
+          return wire.wire__crate__api__logger__reload_tracing_file_writer(
+            port_,
+            arg0,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoggerReloadTracingFileWriterConstMeta,
+        argValues: [writeToFiles],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggerReloadTracingFileWriterConstMeta =>
+      const TaskConstMeta(
+        debugName: "reload_tracing_file_writer",
+        argNames: ["writeToFiles"],
+      );
+
+  @override
+  Stream<CompressProgress> crateApiMediaReverseVideo({
+    required String path,
+    required String outputPath,
+    required ReverseParams params,
+  }) {
+    final sink = RustStreamSink<CompressProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_String(path);
+            var arg1 = cst_encode_String(outputPath);
+            var arg2 = cst_encode_box_autoadd_reverse_params(params);
+            var arg3 = cst_encode_StreamSink_compress_progress_Dco(sink);
+            return wire.wire__crate__api__media__reverse_video(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaReverseVideoConstMeta,
+          argValues: [path, outputPath, params, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaReverseVideoConstMeta => const TaskConstMeta(
+    debugName: "reverse_video",
+    argNames: ["path", "outputPath", "params", "sink"],
+  );
+
+  @override
+  Future<(int, int)> crateApiMediaThumbnailSizeTypeDimensions({
+    required ThumbnailSizeType that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_box_autoadd_thumbnail_size_type(that);
+          return wire.wire__crate__api__media__thumbnail_size_type_dimensions(
+            port_,
+            arg0,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_record_u_32_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaThumbnailSizeTypeDimensionsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaThumbnailSizeTypeDimensionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "thumbnail_size_type_dimensions",
+        argNames: ["that"],
+      );
+
+  @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<String> dco_decode_StreamSink_String_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<CompressProgress> dco_decode_StreamSink_compress_progress_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ExtractedFrame> dco_decode_StreamSink_extracted_frame_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SceneDetectionEvent>
+  dco_decode_StreamSink_scene_detection_event_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ThumbnailData> dco_decode_StreamSink_thumbnail_data_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AnimationFormat dco_decode_animation_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnimationFormat.values[raw as int];
+  }
+
+  @protected
+  AnimationOptions dco_decode_animation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AnimationOptions(
+      maxBytes: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      loopCount: dco_decode_opt_box_autoadd_u_16(arr[1]),
+      dither: dco_decode_opt_box_autoadd_bool(arr[2]),
+    );
+  }
+
+  @protected
+  AspectMode dco_decode_aspect_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AspectMode.values[raw as int];
+  }
+
+  @protected
+  AudioChannels dco_decode_audio_channels(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioChannels.values[raw as int];
+  }
+
+  @protected
+  AudioExtractOptions dco_decode_audio_extract_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AudioExtractOptions(
+      startMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      endMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      channels: dco_decode_opt_box_autoadd_audio_channels(arr[2]),
+      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      bitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      normalizeLoudness: dco_decode_opt_box_autoadd_loudness_normalization(
+        arr[5],
+      ),
+    );
+  }
+
+  @protected
+  AudioFormat dco_decode_audio_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioFormat.values[raw as int];
+  }
+
+  @protected
+  AudioMixParams dco_decode_audio_mix_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AudioMixParams(
+      mode: dco_decode_opt_box_autoadd_audio_track_mode(arr[0]),
+      musicGainDb: dco_decode_opt_box_autoadd_f_32(arr[1]),
+      originalGainDb: dco_decode_opt_box_autoadd_f_32(arr[2]),
+      ducking: dco_decode_opt_box_autoadd_ducking_params(arr[3]),
+      loopMusic: dco_decode_bool(arr[4]),
+      musicStartMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      fadeInMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      fadeOutMs: dco_decode_opt_box_autoadd_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  AudioStreamInfo dco_decode_audio_stream_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AudioStreamInfo(
+      codecName: dco_decode_opt_String(arr[0]),
+      channels: dco_decode_u_32(arr[1]),
+      sampleRate: dco_decode_u_32(arr[2]),
+      bitrate: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      language: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  AudioStreamProbe dco_decode_audio_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AudioStreamProbe(
+      sampleRate: dco_decode_u_32(arr[0]),
+      channels: dco_decode_u_32(arr[1]),
+      channelLayout: dco_decode_opt_String(arr[2]),
+      sampleFormat: dco_decode_opt_String(arr[3]),
+      bitsPerSample: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  AudioTrackMode dco_decode_audio_track_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioTrackMode.values[raw as int];
+  }
+
+  @protected
+  BestThumbnail dco_decode_best_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BestThumbnail(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      timeMs: dco_decode_u_64(arr[3]),
+      score: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  BestThumbnailParams dco_decode_best_thumbnail_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return BestThumbnailParams(
+      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[0]),
+      format: dco_decode_opt_box_autoadd_output_format(arr[1]),
+      candidates: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      resizeMode: dco_decode_opt_box_autoadd_resize_mode(arr[3]),
+      allowUpscale: dco_decode_opt_box_autoadd_bool(arr[4]),
+      encoderOptions: dco_decode_opt_box_autoadd_encoder_options(arr[5]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AnimationOptions dco_decode_box_autoadd_animation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_animation_options(raw);
+  }
+
+  @protected
+  AspectMode dco_decode_box_autoadd_aspect_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_aspect_mode(raw);
+  }
+
+  @protected
+  AudioChannels dco_decode_box_autoadd_audio_channels(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_channels(raw);
+  }
+
+  @protected
+  AudioExtractOptions dco_decode_box_autoadd_audio_extract_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_extract_options(raw);
+  }
+
+  @protected
+  AudioMixParams dco_decode_box_autoadd_audio_mix_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_mix_params(raw);
+  }
+
+  @protected
+  AudioStreamInfo dco_decode_box_autoadd_audio_stream_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_stream_info(raw);
+  }
+
+  @protected
+  AudioStreamProbe dco_decode_box_autoadd_audio_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_stream_probe(raw);
+  }
+
+  @protected
+  AudioTrackMode dco_decode_box_autoadd_audio_track_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_track_mode(raw);
+  }
+
+  @protected
+  BestThumbnailParams dco_decode_box_autoadd_best_thumbnail_params(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_best_thumbnail_params(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_compress_params(raw);
+  }
+
+  @protected
+  CompressProgress dco_decode_box_autoadd_compress_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_compress_progress(raw);
+  }
+
+  @protected
+  ConcatParams dco_decode_box_autoadd_concat_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_concat_params(raw);
+  }
+
+  @protected
+  CropRect dco_decode_box_autoadd_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_crop_rect(raw);
+  }
+
+  @protected
+  DuckingParams dco_decode_box_autoadd_ducking_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ducking_params(raw);
+  }
+
+  @protected
+  EncoderOptions dco_decode_box_autoadd_encoder_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_encoder_options(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FrameExtractParams dco_decode_box_autoadd_frame_extract_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frame_extract_params(raw);
+  }
+
+  @protected
+  GpsLocation dco_decode_box_autoadd_gps_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_gps_location(raw);
+  }
+
+  @protected
+  HashAlgorithm dco_decode_box_autoadd_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hash_algorithm(raw);
+  }
+
+  @protected
+  HdrFormat dco_decode_box_autoadd_hdr_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hdr_format(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_thumbnail_params(raw);
+  }
+
+  @protected
+  LoudnessNormalization dco_decode_box_autoadd_loudness_normalization(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_loudness_normalization(raw);
+  }
+
+  @protected
+  MediaHash dco_decode_box_autoadd_media_hash(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_hash(raw);
+  }
+
+  @protected
+  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_output_format(raw);
+  }
+
+  @protected
+  PaletteColor dco_decode_box_autoadd_palette_color(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_palette_color(raw);
+  }
+
+  @protected
+  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as (int, int);
+  }
+
+  @protected
+  ResizeMode dco_decode_box_autoadd_resize_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_resize_mode(raw);
+  }
+
+  @protected
+  ReverseAudioMode dco_decode_box_autoadd_reverse_audio_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reverse_audio_mode(raw);
+  }
+
+  @protected
+  ReverseParams dco_decode_box_autoadd_reverse_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reverse_params(raw);
+  }
+
+  @protected
+  SeekMode dco_decode_box_autoadd_seek_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_seek_mode(raw);
+  }
+
+  @protected
+  SpeedParams dco_decode_box_autoadd_speed_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_speed_params(raw);
+  }
+
+  @protected
+  SubtitleParams dco_decode_box_autoadd_subtitle_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_subtitle_params(raw);
+  }
+
+  @protected
+  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thumbnail_size_type(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  VideoStreamProbe dco_decode_box_autoadd_video_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_video_stream_probe(raw);
+  }
+
+  @protected
+  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_video_thumbnail_params(raw);
+  }
+
+  @protected
+  WatermarkParams dco_decode_box_autoadd_watermark_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_watermark_params(raw);
+  }
+
+  @protected
+  WatermarkPosition dco_decode_box_autoadd_watermark_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_watermark_position(raw);
+  }
+
+  @protected
+  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_write_to_files(raw);
+  }
+
+  @protected
+  CompressParams dco_decode_compress_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return CompressParams(
+      targetBitrateKbps: dco_decode_u_32(arr[0]),
+      preset: dco_decode_opt_String(arr[1]),
+      crf: dco_decode_opt_box_autoadd_u_8(arr[2]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      sampleDurationMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      crop: dco_decode_opt_box_autoadd_crop_rect(arr[6]),
+      aspectRatio: dco_decode_opt_box_autoadd_record_u_32_u_32(arr[7]),
+      aspectMode: dco_decode_opt_box_autoadd_aspect_mode(arr[8]),
+      padColor: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      watermark: dco_decode_opt_box_autoadd_watermark_params(arr[10]),
+      subtitles: dco_decode_opt_box_autoadd_subtitle_params(arr[11]),
+      speed: dco_decode_opt_box_autoadd_speed_params(arr[12]),
+      normalizeLoudness: dco_decode_opt_box_autoadd_loudness_normalization(
+        arr[13],
+      ),
+      videoFilters: dco_decode_opt_String(arr[14]),
+      audioFilters: dco_decode_opt_String(arr[15]),
+    );
+  }
+
+  @protected
+  CompressProgress dco_decode_compress_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CompressProgress(
+      processedMs: dco_decode_u_64(arr[0]),
+      totalMs: dco_decode_u_64(arr[1]),
+      speedX: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  CompressionEstimate dco_decode_compression_estimate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CompressionEstimate(
+      estimatedSizeBytes: dco_decode_u_64(arr[0]),
+      estimatedDurationMs: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ConcatParams dco_decode_concat_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ConcatParams(
+      width: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      fps: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      targetBitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      preset: dco_decode_opt_String(arr[4]),
+      crf: dco_decode_opt_box_autoadd_u_8(arr[5]),
+      forceReencode: dco_decode_opt_box_autoadd_bool(arr[6]),
+    );
+  }
+
+  @protected
+  CropRect dco_decode_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CropRect(
+      x: dco_decode_u_32(arr[0]),
+      y: dco_decode_u_32(arr[1]),
+      width: dco_decode_u_32(arr[2]),
+      height: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  DuckingParams dco_decode_ducking_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DuckingParams(
+      thresholdDb: dco_decode_opt_box_autoadd_f_32(arr[0]),
+      reductionDb: dco_decode_opt_box_autoadd_f_32(arr[1]),
+      attackMs: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      releaseMs: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  EncoderOptions dco_decode_encoder_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EncoderOptions(
+      jpegQuality: dco_decode_opt_box_autoadd_u_8(arr[0]),
+      jpegProgressive: dco_decode_opt_box_autoadd_bool(arr[1]),
+      webpQuality: dco_decode_opt_box_autoadd_f_32(arr[2]),
+      pngCompression: dco_decode_opt_box_autoadd_u_8(arr[3]),
+      avifQuality: dco_decode_opt_box_autoadd_u_8(arr[4]),
+      heicQuality: dco_decode_opt_box_autoadd_u_8(arr[5]),
+    );
+  }
+
+  @protected
+  ExtractedFrame dco_decode_extracted_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ExtractedFrame(
+      path: dco_decode_String(arr[0]),
+      index: dco_decode_u_32(arr[1]),
+      requestedMs: dco_decode_u_64(arr[2]),
+      timeMs: dco_decode_u_64(arr[3]),
+      width: dco_decode_u_32(arr[4]),
+      height: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FrameExtractParams dco_decode_frame_extract_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return FrameExtractParams(
+      selection: dco_decode_frame_selection(arr[0]),
+      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[1]),
+      format: dco_decode_opt_box_autoadd_output_format(arr[2]),
+      seekMode: dco_decode_opt_box_autoadd_seek_mode(arr[3]),
+      resizeMode: dco_decode_opt_box_autoadd_resize_mode(arr[4]),
+      allowUpscale: dco_decode_opt_box_autoadd_bool(arr[5]),
+      encoderOptions: dco_decode_opt_box_autoadd_encoder_options(arr[6]),
+    );
+  }
+
+  @protected
+  FrameSelection dco_decode_frame_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FrameSelection_Timestamps(
+          dco_decode_list_prim_u_64_strict(raw[1]),
+        );
+      case 1:
+        return FrameSelection_Interval(dco_decode_u_64(raw[1]));
+      case 2:
+        return FrameSelection_Count(dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GpsLocation dco_decode_gps_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GpsLocation(
+      latitude: dco_decode_f_64(arr[0]),
+      longitude: dco_decode_f_64(arr[1]),
+      altitude: dco_decode_opt_box_autoadd_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  HashAlgorithm dco_decode_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HashAlgorithm.values[raw as int];
+  }
+
+  @protected
+  HashComparison dco_decode_hash_comparison(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HashComparison(
+      distance: dco_decode_u_32(arr[0]),
+      similarity: dco_decode_f_64(arr[1]),
+      isSimilar: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  HdrFormat dco_decode_hdr_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HdrFormat.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImageInfo dco_decode_image_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return ImageInfo(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      format: dco_decode_String(arr[2]),
+      bitDepth: dco_decode_u_32(arr[3]),
+      hasAlpha: dco_decode_bool(arr[4]),
+      orientation: dco_decode_u_32(arr[5]),
+      cameraMake: dco_decode_opt_String(arr[6]),
+      cameraModel: dco_decode_opt_String(arr[7]),
+      captureDate: dco_decode_opt_String(arr[8]),
+      gps: dco_decode_opt_box_autoadd_gps_location(arr[9]),
+      iccProfileName: dco_decode_opt_String(arr[10]),
+      imageCount: dco_decode_u_32(arr[11]),
+      auxiliaryImageCount: dco_decode_u_32(arr[12]),
+      hasDepth: dco_decode_bool(arr[13]),
+    );
+  }
+
+  @protected
+  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ImageThumbnailParams(
+      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[0]),
+      format: dco_decode_opt_box_autoadd_output_format(arr[1]),
+      seekMode: dco_decode_opt_box_autoadd_seek_mode(arr[2]),
+      resizeMode: dco_decode_opt_box_autoadd_resize_mode(arr[3]),
+      allowUpscale: dco_decode_opt_box_autoadd_bool(arr[4]),
+      encoderOptions: dco_decode_opt_box_autoadd_encoder_options(arr[5]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<MediaChapter> dco_decode_list_media_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_chapter).toList();
+  }
+
+  @protected
+  List<MediaTag> dco_decode_list_media_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_tag).toList();
+  }
+
+  @protected
+  List<PaletteColor> dco_decode_list_palette_color(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_palette_color).toList();
+  }
+
+  @protected
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint64List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_resolution_preset).toList();
+  }
+
+  @protected
+  List<SceneCut> dco_decode_list_scene_cut(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scene_cut).toList();
+  }
+
+  @protected
+  List<StreamProbe> dco_decode_list_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stream_probe).toList();
+  }
+
+  @protected
+  List<StreamSideData> dco_decode_list_stream_side_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stream_side_data).toList();
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  LoudnessInfo dco_decode_loudness_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LoudnessInfo(
+      integratedLufs: dco_decode_f_64(arr[0]),
+      loudnessRangeLu: dco_decode_f_64(arr[1]),
+      truePeakDbtp: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  LoudnessNormalization dco_decode_loudness_normalization(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LoudnessNormalization(
+      targetLufs: dco_decode_f_32(arr[0]),
+      truePeakDbtp: dco_decode_opt_box_autoadd_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  MediaChapter dco_decode_media_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MediaChapter(
+      id: dco_decode_i_64(arr[0]),
+      startMs: dco_decode_u_64(arr[1]),
+      endMs: dco_decode_u_64(arr[2]),
+      title: dco_decode_opt_String(arr[3]),
+      tags: dco_decode_list_media_tag(arr[4]),
+    );
+  }
+
+  @protected
+  MediaHash dco_decode_media_hash(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MediaHash(
+      algorithm: dco_decode_hash_algorithm(arr[0]),
+      hashes: dco_decode_list_prim_u_64_strict(arr[1]),
+      timesMs: dco_decode_list_prim_u_64_strict(arr[2]),
+    );
+  }
+
+  @protected
+  MediaProbe dco_decode_media_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return MediaProbe(
+      formatName: dco_decode_String(arr[0]),
+      formatLongName: dco_decode_opt_String(arr[1]),
+      durationMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      startTimeMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      bitrate: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      sizeBytes: dco_decode_u_64(arr[5]),
+      tags: dco_decode_list_media_tag(arr[6]),
+      chapters: dco_decode_list_media_chapter(arr[7]),
+      streams: dco_decode_list_stream_probe(arr[8]),
+    );
+  }
+
+  @protected
+  MediaTag dco_decode_media_tag(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MediaTag(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AspectMode? dco_decode_opt_box_autoadd_aspect_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_aspect_mode(raw);
+  }
+
+  @protected
+  AudioChannels? dco_decode_opt_box_autoadd_audio_channels(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_channels(raw);
+  }
+
+  @protected
+  AudioStreamInfo? dco_decode_opt_box_autoadd_audio_stream_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_stream_info(raw);
+  }
+
+  @protected
+  AudioStreamProbe? dco_decode_opt_box_autoadd_audio_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_stream_probe(raw);
+  }
+
+  @protected
+  AudioTrackMode? dco_decode_opt_box_autoadd_audio_track_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audio_track_mode(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_crop_rect(raw);
+  }
+
+  @protected
+  DuckingParams? dco_decode_opt_box_autoadd_ducking_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ducking_params(raw);
+  }
+
+  @protected
+  EncoderOptions? dco_decode_opt_box_autoadd_encoder_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_encoder_options(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  GpsLocation? dco_decode_opt_box_autoadd_gps_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_gps_location(raw);
+  }
+
+  @protected
+  HashAlgorithm? dco_decode_opt_box_autoadd_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hash_algorithm(raw);
+  }
+
+  @protected
+  HdrFormat? dco_decode_opt_box_autoadd_hdr_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hdr_format(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_image_thumbnail_params(raw);
+  }
+
+  @protected
+  LoudnessNormalization? dco_decode_opt_box_autoadd_loudness_normalization(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_loudness_normalization(raw);
+  }
+
+  @protected
+  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_output_format(raw);
+  }
+
+  @protected
+  PaletteColor? dco_decode_opt_box_autoadd_palette_color(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_palette_color(raw);
+  }
+
+  @protected
+  (int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_record_u_32_u_32(raw);
+  }
+
+  @protected
+  ResizeMode? dco_decode_opt_box_autoadd_resize_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_resize_mode(raw);
+  }
+
+  @protected
+  ReverseAudioMode? dco_decode_opt_box_autoadd_reverse_audio_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_reverse_audio_mode(raw);
+  }
+
+  @protected
+  SeekMode? dco_decode_opt_box_autoadd_seek_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_seek_mode(raw);
+  }
+
+  @protected
+  SpeedParams? dco_decode_opt_box_autoadd_speed_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_speed_params(raw);
+  }
+
+  @protected
+  SubtitleParams? dco_decode_opt_box_autoadd_subtitle_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_subtitle_params(raw);
+  }
+
+  @protected
+  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_thumbnail_size_type(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  VideoStreamProbe? dco_decode_opt_box_autoadd_video_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_video_stream_probe(raw);
+  }
+
+  @protected
+  WatermarkParams? dco_decode_opt_box_autoadd_watermark_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_watermark_params(raw);
+  }
+
+  @protected
+  WatermarkPosition? dco_decode_opt_box_autoadd_watermark_position(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_watermark_position(raw);
+  }
+
+  @protected
+  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_write_to_files(raw);
+  }
+
+  @protected
+  OutputFormat dco_decode_output_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OutputFormat.values[raw as int];
+  }
+
+  @protected
+  Palette dco_decode_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return Palette(
+      colors: dco_decode_list_palette_color(arr[0]),
+      average: dco_decode_palette_color(arr[1]),
+      vibrant: dco_decode_opt_box_autoadd_palette_color(arr[2]),
+      lightVibrant: dco_decode_opt_box_autoadd_palette_color(arr[3]),
+      darkVibrant: dco_decode_opt_box_autoadd_palette_color(arr[4]),
+      muted: dco_decode_opt_box_autoadd_palette_color(arr[5]),
+      lightMuted: dco_decode_opt_box_autoadd_palette_color(arr[6]),
+      darkMuted: dco_decode_opt_box_autoadd_palette_color(arr[7]),
+    );
+  }
+
+  @protected
+  PaletteColor dco_decode_palette_color(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaletteColor(
+      color: dco_decode_u_32(arr[0]),
+      population: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  PlaceholderImage dco_decode_placeholder_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PlaceholderImage(
+      rgba: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  (int, int) dco_decode_record_u_32_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_u_32(arr[0]), dco_decode_u_32(arr[1]));
+  }
+
+  @protected
+  ResizeMode dco_decode_resize_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ResizeMode.values[raw as int];
+  }
+
+  @protected
+  ResolutionPreset dco_decode_resolution_preset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ResolutionPreset(
+      name: dco_decode_String(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      bitrate: dco_decode_u_64(arr[3]),
+      crf: dco_decode_u_8(arr[4]),
+    );
+  }
+
+  @protected
+  ReverseAudioMode dco_decode_reverse_audio_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReverseAudioMode.values[raw as int];
+  }
+
+  @protected
+  ReverseParams dco_decode_reverse_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ReverseParams(
+      startMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      endMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      boomerangLoops: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      audio: dco_decode_opt_box_autoadd_reverse_audio_mode(arr[3]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      targetBitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      preset: dco_decode_opt_String(arr[7]),
+      crf: dco_decode_opt_box_autoadd_u_8(arr[8]),
+      memoryBudgetMb: dco_decode_opt_box_autoadd_u_32(arr[9]),
+    );
+  }
+
+  @protected
+  SceneCut dco_decode_scene_cut(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SceneCut(
+      timeMs: dco_decode_u_64(arr[0]),
+      score: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  SceneDetectionEvent dco_decode_scene_detection_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SceneDetectionEvent_Progress(
+          dco_decode_box_autoadd_compress_progress(raw[1]),
+        );
+      case 1:
+        return SceneDetectionEvent_Cuts(dco_decode_list_scene_cut(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SeekMode dco_decode_seek_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SeekMode.values[raw as int];
+  }
+
+  @protected
+  SpeedParams dco_decode_speed_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SpeedParams(
+      factor: dco_decode_f_32(arr[0]),
+      frameRate: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      muteAudioAbove: dco_decode_opt_box_autoadd_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  Storyboard dco_decode_storyboard(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Storyboard(
+      vttPath: dco_decode_String(arr[0]),
+      spritePaths: dco_decode_list_String(arr[1]),
+      tileCount: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  StreamDisposition dco_decode_stream_disposition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return StreamDisposition(
+      default_: dco_decode_bool(arr[0]),
+      dub: dco_decode_bool(arr[1]),
+      original: dco_decode_bool(arr[2]),
+      comment: dco_decode_bool(arr[3]),
+      lyrics: dco_decode_bool(arr[4]),
+      karaoke: dco_decode_bool(arr[5]),
+      forced: dco_decode_bool(arr[6]),
+      hearingImpaired: dco_decode_bool(arr[7]),
+      visualImpaired: dco_decode_bool(arr[8]),
+      cleanEffects: dco_decode_bool(arr[9]),
+      attachedPic: dco_decode_bool(arr[10]),
+      captions: dco_decode_bool(arr[11]),
+      descriptions: dco_decode_bool(arr[12]),
+      metadata: dco_decode_bool(arr[13]),
+    );
+  }
+
+  @protected
+  StreamProbe dco_decode_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return StreamProbe(
+      index: dco_decode_u_32(arr[0]),
+      streamType: dco_decode_stream_type(arr[1]),
+      codecName: dco_decode_opt_String(arr[2]),
+      codecLongName: dco_decode_opt_String(arr[3]),
+      profile: dco_decode_opt_String(arr[4]),
+      bitrate: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      startTimeMs: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      durationMs: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      frameCount: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      language: dco_decode_opt_String(arr[9]),
+      title: dco_decode_opt_String(arr[10]),
+      disposition: dco_decode_stream_disposition(arr[11]),
+      tags: dco_decode_list_media_tag(arr[12]),
+      sideData: dco_decode_list_stream_side_data(arr[13]),
+      video: dco_decode_opt_box_autoadd_video_stream_probe(arr[14]),
+      audio: dco_decode_opt_box_autoadd_audio_stream_probe(arr[15]),
+    );
+  }
+
+  @protected
+  StreamSideData dco_decode_stream_side_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return StreamSideData(
+      kind: dco_decode_String(arr[0]),
+      sizeBytes: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  StreamType dco_decode_stream_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StreamType.values[raw as int];
+  }
+
+  @protected
+  SubtitleParams dco_decode_subtitle_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SubtitleParams(
+      subtitlePath: dco_decode_String(arr[0]),
+      fontPath: dco_decode_opt_String(arr[1]),
+      fontSize: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      color: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      outlineColor: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      outlineWidth: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      bottomMargin: dco_decode_opt_box_autoadd_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  ThumbnailData dco_decode_thumbnail_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ThumbnailData(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      timeMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ThumbnailSizeType_Icon();
+      case 1:
+        return ThumbnailSizeType_Small();
+      case 2:
+        return ThumbnailSizeType_Medium();
+      case 3:
+        return ThumbnailSizeType_Large();
+      case 4:
+        return ThumbnailSizeType_Larger();
+      case 5:
+        return ThumbnailSizeType_Custom(
+          dco_decode_box_autoadd_record_u_32_u_32(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  VideoInfo dco_decode_video_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 25)
+      throw Exception('unexpected arr length: expect 25 but see ${arr.length}');
+    return VideoInfo(
+      durationMs: dco_decode_u_64(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      sizeBytes: dco_decode_u_64(arr[3]),
+      bitrate: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      codecName: dco_decode_opt_String(arr[5]),
+      formatName: dco_decode_opt_String(arr[6]),
+      storedWidth: dco_decode_u_32(arr[7]),
+      storedHeight: dco_decode_u_32(arr[8]),
+      rotation: dco_decode_i_32(arr[9]),
+      avgFrameRate: dco_decode_opt_box_autoadd_f_64(arr[10]),
+      realFrameRate: dco_decode_opt_box_autoadd_f_64(arr[11]),
+      isVfr: dco_decode_bool(arr[12]),
+      pixelFormat: dco_decode_opt_String(arr[13]),
+      bitDepth: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      colorPrimaries: dco_decode_opt_String(arr[15]),
+      colorTransfer: dco_decode_opt_String(arr[16]),
+      colorMatrix: dco_decode_opt_String(arr[17]),
+      colorRange: dco_decode_opt_String(arr[18]),
+      hdrFormat: dco_decode_opt_box_autoadd_hdr_format(arr[19]),
+      profile: dco_decode_opt_String(arr[20]),
+      level: dco_decode_opt_box_autoadd_i_32(arr[21]),
+      frameCount: dco_decode_opt_box_autoadd_u_64(arr[22]),
+      audio: dco_decode_opt_box_autoadd_audio_stream_info(arr[23]),
+      suggestions: dco_decode_list_resolution_preset(arr[24]),
+    );
+  }
+
+  @protected
+  VideoStreamProbe dco_decode_video_stream_probe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return VideoStreamProbe(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      displayWidth: dco_decode_u_32(arr[2]),
+      displayHeight: dco_decode_u_32(arr[3]),
+      rotation: dco_decode_i_32(arr[4]),
+      avgFrameRate: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      realFrameRate: dco_decode_opt_box_autoadd_f_64(arr[6]),
+      isVfr: dco_decode_bool(arr[7]),
+      pixelFormat: dco_decode_opt_String(arr[8]),
+      bitDepth: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      colorPrimaries: dco_decode_opt_String(arr[10]),
+      colorTransfer: dco_decode_opt_String(arr[11]),
+      colorMatrix: dco_decode_opt_String(arr[12]),
+      colorRange: dco_decode_opt_String(arr[13]),
+      hdrFormat: dco_decode_opt_box_autoadd_hdr_format(arr[14]),
+      level: dco_decode_opt_box_autoadd_i_32(arr[15]),
+    );
+  }
+
+  @protected
+  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return VideoThumbnailParams(
+      timeMs: dco_decode_u_64(arr[0]),
+      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[1]),
+      format: dco_decode_opt_box_autoadd_output_format(arr[2]),
+      seekMode: dco_decode_opt_box_autoadd_seek_mode(arr[3]),
+      resizeMode: dco_decode_opt_box_autoadd_resize_mode(arr[4]),
+      allowUpscale: dco_decode_opt_box_autoadd_bool(arr[5]),
+      encoderOptions: dco_decode_opt_box_autoadd_encoder_options(arr[6]),
+    );
+  }
+
+  @protected
+  WatermarkParams dco_decode_watermark_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return WatermarkParams(
+      imagePath: dco_decode_String(arr[0]),
+      position: dco_decode_opt_box_autoadd_watermark_position(arr[1]),
+      margin: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      scale: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      opacity: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      startMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      endMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  WatermarkPosition dco_decode_watermark_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WatermarkPosition.values[raw as int];
+  }
+
+  @protected
+  WriteToFiles dco_decode_write_to_files(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WriteToFiles(
+      path: dco_decode_String(arr[0]),
+      filePrefix: dco_decode_String(arr[1]),
+      fileSuffix: dco_decode_opt_String(arr[2]),
+      maxFiles: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<CompressProgress> sse_decode_StreamSink_compress_progress_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ExtractedFrame> sse_decode_StreamSink_extracted_frame_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SceneDetectionEvent>
+  sse_decode_StreamSink_scene_detection_event_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ThumbnailData> sse_decode_StreamSink_thumbnail_data_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AnimationFormat sse_decode_animation_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AnimationFormat.values[inner];
+  }
+
+  @protected
+  AnimationOptions sse_decode_animation_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_loopCount = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_dither = sse_decode_opt_box_autoadd_bool(deserializer);
+    return AnimationOptions(
+      maxBytes: var_maxBytes,
+      loopCount: var_loopCount,
+      dither: var_dither,
+    );
+  }
+
+  @protected
+  AspectMode sse_decode_aspect_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AspectMode.values[inner];
+  }
+
+  @protected
+  AudioChannels sse_decode_audio_channels(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioChannels.values[inner];
+  }
+
+  @protected
+  AudioExtractOptions sse_decode_audio_extract_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_channels = sse_decode_opt_box_autoadd_audio_channels(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_normalizeLoudness =
+        sse_decode_opt_box_autoadd_loudness_normalization(deserializer);
+    return AudioExtractOptions(
+      startMs: var_startMs,
+      endMs: var_endMs,
+      channels: var_channels,
+      sampleRate: var_sampleRate,
+      bitrateKbps: var_bitrateKbps,
+      normalizeLoudness: var_normalizeLoudness,
+    );
+  }
+
+  @protected
+  AudioFormat sse_decode_audio_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioFormat.values[inner];
+  }
+
+  @protected
+  AudioMixParams sse_decode_audio_mix_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_opt_box_autoadd_audio_track_mode(deserializer);
+    var var_musicGainDb = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_originalGainDb = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_ducking = sse_decode_opt_box_autoadd_ducking_params(deserializer);
+    var var_loopMusic = sse_decode_bool(deserializer);
+    var var_musicStartMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_fadeInMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_fadeOutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return AudioMixParams(
+      mode: var_mode,
+      musicGainDb: var_musicGainDb,
+      originalGainDb: var_originalGainDb,
+      ducking: var_ducking,
+      loopMusic: var_loopMusic,
+      musicStartMs: var_musicStartMs,
+      fadeInMs: var_fadeInMs,
+      fadeOutMs: var_fadeOutMs,
+    );
+  }
+
+  @protected
+  AudioStreamInfo sse_decode_audio_stream_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codecName = sse_decode_opt_String(deserializer);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    return AudioStreamInfo(
+      codecName: var_codecName,
+      channels: var_channels,
+      sampleRate: var_sampleRate,
+      bitrate: var_bitrate,
+      language: var_language,
+    );
+  }
+
+  @protected
+  AudioStreamProbe sse_decode_audio_stream_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_channels = sse_decode_u_32(deserializer);
+    var var_channelLayout = sse_decode_opt_String(deserializer);
+    var var_sampleFormat = sse_decode_opt_String(deserializer);
+    var var_bitsPerSample = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return AudioStreamProbe(
+      sampleRate: var_sampleRate,
+      channels: var_channels,
+      channelLayout: var_channelLayout,
+      sampleFormat: var_sampleFormat,
+      bitsPerSample: var_bitsPerSample,
+    );
+  }
+
+  @protected
+  AudioTrackMode sse_decode_audio_track_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioTrackMode.values[inner];
+  }
+
+  @protected
+  BestThumbnail sse_decode_best_thumbnail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return BestThumbnail(
+      data: var_data,
+      width: var_width,
+      height: var_height,
+      timeMs: var_timeMs,
+      score: var_score,
+    );
+  }
+
+  @protected
+  BestThumbnailParams sse_decode_best_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
+      deserializer,
+    );
+    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
+    var var_candidates = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_resizeMode = sse_decode_opt_box_autoadd_resize_mode(deserializer);
+    var var_allowUpscale = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_encoderOptions = sse_decode_opt_box_autoadd_encoder_options(
+      deserializer,
+    );
+    return BestThumbnailParams(
+      sizeType: var_sizeType,
+      format: var_format,
+      candidates: var_candidates,
+      resizeMode: var_resizeMode,
+      allowUpscale: var_allowUpscale,
+      encoderOptions: var_encoderOptions,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AnimationOptions sse_decode_box_autoadd_animation_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_animation_options(deserializer));
+  }
+
+  @protected
+  AspectMode sse_decode_box_autoadd_aspect_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_aspect_mode(deserializer));
+  }
+
+  @protected
+  AudioChannels sse_decode_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_channels(deserializer));
+  }
+
+  @protected
+  AudioExtractOptions sse_decode_box_autoadd_audio_extract_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_extract_options(deserializer));
+  }
+
+  @protected
+  AudioMixParams sse_decode_box_autoadd_audio_mix_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_mix_params(deserializer));
+  }
+
+  @protected
+  AudioStreamInfo sse_decode_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_stream_info(deserializer));
+  }
+
+  @protected
+  AudioStreamProbe sse_decode_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_stream_probe(deserializer));
+  }
+
+  @protected
+  AudioTrackMode sse_decode_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_track_mode(deserializer));
+  }
+
+  @protected
+  BestThumbnailParams sse_decode_box_autoadd_best_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_best_thumbnail_params(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CompressParams sse_decode_box_autoadd_compress_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_compress_params(deserializer));
+  }
+
+  @protected
+  CompressProgress sse_decode_box_autoadd_compress_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_compress_progress(deserializer));
+  }
+
+  @protected
+  ConcatParams sse_decode_box_autoadd_concat_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_concat_params(deserializer));
+  }
+
+  @protected
+  CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_crop_rect(deserializer));
+  }
+
+  @protected
+  DuckingParams sse_decode_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ducking_params(deserializer));
+  }
+
+  @protected
+  EncoderOptions sse_decode_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_encoder_options(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FrameExtractParams sse_decode_box_autoadd_frame_extract_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frame_extract_params(deserializer));
+  }
+
+  @protected
+  GpsLocation sse_decode_box_autoadd_gps_location(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_gps_location(deserializer));
+  }
+
+  @protected
+  HashAlgorithm sse_decode_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hash_algorithm(deserializer));
+  }
+
+  @protected
+  HdrFormat sse_decode_box_autoadd_hdr_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hdr_format(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_thumbnail_params(deserializer));
+  }
+
+  @protected
+  LoudnessNormalization sse_decode_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_loudness_normalization(deserializer));
+  }
+
+  @protected
+  MediaHash sse_decode_box_autoadd_media_hash(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_hash(deserializer));
+  }
+
+  @protected
+  OutputFormat sse_decode_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_output_format(deserializer));
+  }
+
+  @protected
+  PaletteColor sse_decode_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_palette_color(deserializer));
+  }
+
+  @protected
+  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_record_u_32_u_32(deserializer));
+  }
+
+  @protected
+  ResizeMode sse_decode_box_autoadd_resize_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_resize_mode(deserializer));
+  }
+
+  @protected
+  ReverseAudioMode sse_decode_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reverse_audio_mode(deserializer));
+  }
+
+  @protected
+  ReverseParams sse_decode_box_autoadd_reverse_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reverse_params(deserializer));
+  }
+
+  @protected
+  SeekMode sse_decode_box_autoadd_seek_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_seek_mode(deserializer));
+  }
+
+  @protected
+  SpeedParams sse_decode_box_autoadd_speed_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_speed_params(deserializer));
+  }
+
+  @protected
+  SubtitleParams sse_decode_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_subtitle_params(deserializer));
+  }
+
+  @protected
+  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thumbnail_size_type(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  VideoStreamProbe sse_decode_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_video_stream_probe(deserializer));
+  }
+
+  @protected
+  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_video_thumbnail_params(deserializer));
+  }
+
+  @protected
+  WatermarkParams sse_decode_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_watermark_params(deserializer));
+  }
+
+  @protected
+  WatermarkPosition sse_decode_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_watermark_position(deserializer));
+  }
+
+  @protected
+  WriteToFiles sse_decode_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_write_to_files(deserializer));
+  }
+
+  @protected
+  CompressParams sse_decode_compress_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_targetBitrateKbps = sse_decode_u_32(deserializer);
+    var var_preset = sse_decode_opt_String(deserializer);
+    var var_crf = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sampleDurationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_crop = sse_decode_opt_box_autoadd_crop_rect(deserializer);
+    var var_aspectRatio = sse_decode_opt_box_autoadd_record_u_32_u_32(
+      deserializer,
+    );
+    var var_aspectMode = sse_decode_opt_box_autoadd_aspect_mode(deserializer);
+    var var_padColor = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_watermark = sse_decode_opt_box_autoadd_watermark_params(
+      deserializer,
+    );
+    var var_subtitles = sse_decode_opt_box_autoadd_subtitle_params(
+      deserializer,
+    );
+    var var_speed = sse_decode_opt_box_autoadd_speed_params(deserializer);
+    var var_normalizeLoudness =
+        sse_decode_opt_box_autoadd_loudness_normalization(deserializer);
+    var var_videoFilters = sse_decode_opt_String(deserializer);
+    var var_audioFilters = sse_decode_opt_String(deserializer);
+    return CompressParams(
+      targetBitrateKbps: var_targetBitrateKbps,
+      preset: var_preset,
+      crf: var_crf,
+      width: var_width,
+      height: var_height,
+      sampleDurationMs: var_sampleDurationMs,
+      crop: var_crop,
+      aspectRatio: var_aspectRatio,
+      aspectMode: var_aspectMode,
+      padColor: var_padColor,
+      watermark: var_watermark,
+      subtitles: var_subtitles,
+      speed: var_speed,
+      normalizeLoudness: var_normalizeLoudness,
+      videoFilters: var_videoFilters,
+      audioFilters: var_audioFilters,
+    );
+  }
+
+  @protected
+  CompressProgress sse_decode_compress_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_processedMs = sse_decode_u_64(deserializer);
+    var var_totalMs = sse_decode_u_64(deserializer);
+    var var_speedX = sse_decode_f_32(deserializer);
+    return CompressProgress(
+      processedMs: var_processedMs,
+      totalMs: var_totalMs,
+      speedX: var_speedX,
+    );
+  }
+
+  @protected
+  CompressionEstimate sse_decode_compression_estimate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_estimatedSizeBytes = sse_decode_u_64(deserializer);
+    var var_estimatedDurationMs = sse_decode_u_64(deserializer);
+    return CompressionEstimate(
+      estimatedSizeBytes: var_estimatedSizeBytes,
+      estimatedDurationMs: var_estimatedDurationMs,
+    );
+  }
+
+  @protected
+  ConcatParams sse_decode_concat_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_fps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_targetBitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_preset = sse_decode_opt_String(deserializer);
+    var var_crf = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_forceReencode = sse_decode_opt_box_autoadd_bool(deserializer);
+    return ConcatParams(
+      width: var_width,
+      height: var_height,
+      fps: var_fps,
+      targetBitrateKbps: var_targetBitrateKbps,
+      preset: var_preset,
+      crf: var_crf,
+      forceReencode: var_forceReencode,
+    );
+  }
+
+  @protected
+  CropRect sse_decode_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_u_32(deserializer);
+    var var_y = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return CropRect(x: var_x, y: var_y, width: var_width, height: var_height);
+  }
+
+  @protected
+  DuckingParams sse_decode_ducking_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_thresholdDb = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_reductionDb = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_attackMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_releaseMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return DuckingParams(
+      thresholdDb: var_thresholdDb,
+      reductionDb: var_reductionDb,
+      attackMs: var_attackMs,
+      releaseMs: var_releaseMs,
+    );
+  }
+
+  @protected
+  EncoderOptions sse_decode_encoder_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_jpegQuality = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_jpegProgressive = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_webpQuality = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_pngCompression = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_avifQuality = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_heicQuality = sse_decode_opt_box_autoadd_u_8(deserializer);
+    return EncoderOptions(
+      jpegQuality: var_jpegQuality,
+      jpegProgressive: var_jpegProgressive,
+      webpQuality: var_webpQuality,
+      pngCompression: var_pngCompression,
+      avifQuality: var_avifQuality,
+      heicQuality: var_heicQuality,
+    );
+  }
+
+  @protected
+  ExtractedFrame sse_decode_extracted_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_index = sse_decode_u_32(deserializer);
+    var var_requestedMs = sse_decode_u_64(deserializer);
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return ExtractedFrame(
+      path: var_path,
+      index: var_index,
+      requestedMs: var_requestedMs,
+      timeMs: var_timeMs,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FrameExtractParams sse_decode_frame_extract_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_selection = sse_decode_frame_selection(deserializer);
+    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
+      deserializer,
+    );
+    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
+    var var_seekMode = sse_decode_opt_box_autoadd_seek_mode(deserializer);
+    var var_resizeMode = sse_decode_opt_box_autoadd_resize_mode(deserializer);
+    var var_allowUpscale = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_encoderOptions = sse_decode_opt_box_autoadd_encoder_options(
+      deserializer,
+    );
+    return FrameExtractParams(
+      selection: var_selection,
+      sizeType: var_sizeType,
+      format: var_format,
+      seekMode: var_seekMode,
+      resizeMode: var_resizeMode,
+      allowUpscale: var_allowUpscale,
+      encoderOptions: var_encoderOptions,
+    );
+  }
+
+  @protected
+  FrameSelection sse_decode_frame_selection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_prim_u_64_strict(deserializer);
+        return FrameSelection_Timestamps(var_field0);
+      case 1:
+        var var_field0 = sse_decode_u_64(deserializer);
+        return FrameSelection_Interval(var_field0);
+      case 2:
+        var var_field0 = sse_decode_u_32(deserializer);
+        return FrameSelection_Count(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GpsLocation sse_decode_gps_location(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_latitude = sse_decode_f_64(deserializer);
+    var var_longitude = sse_decode_f_64(deserializer);
+    var var_altitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return GpsLocation(
+      latitude: var_latitude,
+      longitude: var_longitude,
+      altitude: var_altitude,
+    );
+  }
+
+  @protected
+  HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HashAlgorithm.values[inner];
+  }
+
+  @protected
+  HashComparison sse_decode_hash_comparison(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_distance = sse_decode_u_32(deserializer);
+    var var_similarity = sse_decode_f_64(deserializer);
+    var var_isSimilar = sse_decode_bool(deserializer);
+    return HashComparison(
+      distance: var_distance,
+      similarity: var_similarity,
+      isSimilar: var_isSimilar,
+    );
+  }
+
+  @protected
+  HdrFormat sse_decode_hdr_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HdrFormat.values[inner];
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImageInfo sse_decode_image_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_bitDepth = sse_decode_u_32(deserializer);
+    var var_hasAlpha = sse_decode_bool(deserializer);
+    var var_orientation = sse_decode_u_32(deserializer);
+    var var_cameraMake = sse_decode_opt_String(deserializer);
+    var var_cameraModel = sse_decode_opt_String(deserializer);
+    var var_captureDate = sse_decode_opt_String(deserializer);
+    var var_gps = sse_decode_opt_box_autoadd_gps_location(deserializer);
+    var var_iccProfileName = sse_decode_opt_String(deserializer);
+    var var_imageCount = sse_decode_u_32(deserializer);
+    var var_auxiliaryImageCount = sse_decode_u_32(deserializer);
+    var var_hasDepth = sse_decode_bool(deserializer);
+    return ImageInfo(
+      width: var_width,
+      height: var_height,
+      format: var_format,
+      bitDepth: var_bitDepth,
+      hasAlpha: var_hasAlpha,
+      orientation: var_orientation,
+      cameraMake: var_cameraMake,
+      cameraModel: var_cameraModel,
+      captureDate: var_captureDate,
+      gps: var_gps,
+      iccProfileName: var_iccProfileName,
+      imageCount: var_imageCount,
+      auxiliaryImageCount: var_auxiliaryImageCount,
+      hasDepth: var_hasDepth,
+    );
+  }
+
+  @protected
+  ImageThumbnailParams sse_decode_image_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
+      deserializer,
+    );
+    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
+    var var_seekMode = sse_decode_opt_box_autoadd_seek_mode(deserializer);
+    var var_resizeMode = sse_decode_opt_box_autoadd_resize_mode(deserializer);
+    var var_allowUpscale = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_encoderOptions = sse_decode_opt_box_autoadd_encoder_options(
+      deserializer,
+    );
+    return ImageThumbnailParams(
+      sizeType: var_sizeType,
+      format: var_format,
+      seekMode: var_seekMode,
+      resizeMode: var_resizeMode,
+      allowUpscale: var_allowUpscale,
+      encoderOptions: var_encoderOptions,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaChapter> sse_decode_list_media_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaTag> sse_decode_list_media_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaTag>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_tag(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaletteColor> sse_decode_list_palette_color(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaletteColor>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_palette_color(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ResolutionPreset> sse_decode_list_resolution_preset(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ResolutionPreset>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_resolution_preset(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SceneCut> sse_decode_list_scene_cut(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SceneCut>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scene_cut(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StreamProbe> sse_decode_list_stream_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StreamProbe>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stream_probe(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StreamSideData> sse_decode_list_stream_side_data(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StreamSideData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stream_side_data(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  LoudnessInfo sse_decode_loudness_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_integratedLufs = sse_decode_f_64(deserializer);
+    var var_loudnessRangeLu = sse_decode_f_64(deserializer);
+    var var_truePeakDbtp = sse_decode_f_64(deserializer);
+    return LoudnessInfo(
+      integratedLufs: var_integratedLufs,
+      loudnessRangeLu: var_loudnessRangeLu,
+      truePeakDbtp: var_truePeakDbtp,
+    );
+  }
+
+  @protected
+  LoudnessNormalization sse_decode_loudness_normalization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_targetLufs = sse_decode_f_32(deserializer);
+    var var_truePeakDbtp = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return LoudnessNormalization(
+      targetLufs: var_targetLufs,
+      truePeakDbtp: var_truePeakDbtp,
+    );
+  }
+
+  @protected
+  MediaChapter sse_decode_media_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_endMs = sse_decode_u_64(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_media_tag(deserializer);
+    return MediaChapter(
+      id: var_id,
+      startMs: var_startMs,
+      endMs: var_endMs,
+      title: var_title,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  MediaHash sse_decode_media_hash(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_algorithm = sse_decode_hash_algorithm(deserializer);
+    var var_hashes = sse_decode_list_prim_u_64_strict(deserializer);
+    var var_timesMs = sse_decode_list_prim_u_64_strict(deserializer);
+    return MediaHash(
+      algorithm: var_algorithm,
+      hashes: var_hashes,
+      timesMs: var_timesMs,
+    );
+  }
+
+  @protected
+  MediaProbe sse_decode_media_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_formatName = sse_decode_String(deserializer);
+    var var_formatLongName = sse_decode_opt_String(deserializer);
+    var var_durationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_startTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    var var_tags = sse_decode_list_media_tag(deserializer);
+    var var_chapters = sse_decode_list_media_chapter(deserializer);
+    var var_streams = sse_decode_list_stream_probe(deserializer);
+    return MediaProbe(
+      formatName: var_formatName,
+      formatLongName: var_formatLongName,
+      durationMs: var_durationMs,
+      startTimeMs: var_startTimeMs,
+      bitrate: var_bitrate,
+      sizeBytes: var_sizeBytes,
+      tags: var_tags,
+      chapters: var_chapters,
+      streams: var_streams,
+    );
+  }
+
+  @protected
+  MediaTag sse_decode_media_tag(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return MediaTag(key: var_key, value: var_value);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AspectMode? sse_decode_opt_box_autoadd_aspect_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_aspect_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioChannels? sse_decode_opt_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_channels(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioStreamInfo? sse_decode_opt_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_stream_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioStreamProbe? sse_decode_opt_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_stream_probe(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioTrackMode? sse_decode_opt_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_track_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_crop_rect(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DuckingParams? sse_decode_opt_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ducking_params(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EncoderOptions? sse_decode_opt_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_encoder_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GpsLocation? sse_decode_opt_box_autoadd_gps_location(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_gps_location(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HashAlgorithm? sse_decode_opt_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hash_algorithm(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HdrFormat? sse_decode_opt_box_autoadd_hdr_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hdr_format(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_image_thumbnail_params(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LoudnessNormalization? sse_decode_opt_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_loudness_normalization(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputFormat? sse_decode_opt_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_output_format(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PaletteColor? sse_decode_opt_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_palette_color(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  (int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_record_u_32_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ResizeMode? sse_decode_opt_box_autoadd_resize_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_resize_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReverseAudioMode? sse_decode_opt_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reverse_audio_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SeekMode? sse_decode_opt_box_autoadd_seek_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_seek_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SpeedParams? sse_decode_opt_box_autoadd_speed_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_speed_params(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SubtitleParams? sse_decode_opt_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_subtitle_params(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_thumbnail_size_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  VideoStreamProbe? sse_decode_opt_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_video_stream_probe(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WatermarkParams? sse_decode_opt_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_watermark_params(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WatermarkPosition? sse_decode_opt_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_watermark_position(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_write_to_files(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputFormat sse_decode_output_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OutputFormat.values[inner];
+  }
+
+  @protected
+  Palette sse_decode_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_colors = sse_decode_list_palette_color(deserializer);
+    var var_average = sse_decode_palette_color(deserializer);
+    var var_vibrant = sse_decode_opt_box_autoadd_palette_color(deserializer);
+    var var_lightVibrant = sse_decode_opt_box_autoadd_palette_color(
+      deserializer,
+    );
+    var var_darkVibrant = sse_decode_opt_box_autoadd_palette_color(
+      deserializer,
+    );
+    var var_muted = sse_decode_opt_box_autoadd_palette_color(deserializer);
+    var var_lightMuted = sse_decode_opt_box_autoadd_palette_color(deserializer);
+    var var_darkMuted = sse_decode_opt_box_autoadd_palette_color(deserializer);
+    return Palette(
+      colors: var_colors,
+      average: var_average,
+      vibrant: var_vibrant,
+      lightVibrant: var_lightVibrant,
+      darkVibrant: var_darkVibrant,
+      muted: var_muted,
+      lightMuted: var_lightMuted,
+      darkMuted: var_darkMuted,
+    );
+  }
+
+  @protected
+  PaletteColor sse_decode_palette_color(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_color = sse_decode_u_32(deserializer);
+    var var_population = sse_decode_f_64(deserializer);
+    return PaletteColor(color: var_color, population: var_population);
+  }
+
+  @protected
+  PlaceholderImage sse_decode_placeholder_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rgba = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return PlaceholderImage(
+      rgba: var_rgba,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_32(deserializer);
+    var var_field1 = sse_decode_u_32(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  ResizeMode sse_decode_resize_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ResizeMode.values[inner];
+  }
+
+  @protected
+  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_bitrate = sse_decode_u_64(deserializer);
+    var var_crf = sse_decode_u_8(deserializer);
+    return ResolutionPreset(
+      name: var_name,
+      width: var_width,
+      height: var_height,
+      bitrate: var_bitrate,
+      crf: var_crf,
+    );
+  }
+
+  @protected
+  ReverseAudioMode sse_decode_reverse_audio_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReverseAudioMode.values[inner];
+  }
+
+  @protected
+  ReverseParams sse_decode_reverse_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_boomerangLoops = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_audio = sse_decode_opt_box_autoadd_reverse_audio_mode(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_targetBitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_preset = sse_decode_opt_String(deserializer);
+    var var_crf = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_memoryBudgetMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ReverseParams(
+      startMs: var_startMs,
+      endMs: var_endMs,
+      boomerangLoops: var_boomerangLoops,
+      audio: var_audio,
+      width: var_width,
+      height: var_height,
+      targetBitrateKbps: var_targetBitrateKbps,
+      preset: var_preset,
+      crf: var_crf,
+      memoryBudgetMb: var_memoryBudgetMb,
+    );
+  }
+
+  @protected
+  SceneCut sse_decode_scene_cut(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return SceneCut(timeMs: var_timeMs, score: var_score);
+  }
+
+  @protected
+  SceneDetectionEvent sse_decode_scene_detection_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_compress_progress(deserializer);
+        return SceneDetectionEvent_Progress(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_scene_cut(deserializer);
+        return SceneDetectionEvent_Cuts(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SeekMode sse_decode_seek_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SeekMode.values[inner];
+  }
+
+  @protected
+  SpeedParams sse_decode_speed_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_factor = sse_decode_f_32(deserializer);
+    var var_frameRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_muteAudioAbove = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return SpeedParams(
+      factor: var_factor,
+      frameRate: var_frameRate,
+      muteAudioAbove: var_muteAudioAbove,
+    );
+  }
+
+  @protected
+  Storyboard sse_decode_storyboard(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_vttPath = sse_decode_String(deserializer);
+    var var_spritePaths = sse_decode_list_String(deserializer);
+    var var_tileCount = sse_decode_u_32(deserializer);
+    return Storyboard(
+      vttPath: var_vttPath,
+      spritePaths: var_spritePaths,
+      tileCount: var_tileCount,
+    );
+  }
+
+  @protected
+  StreamDisposition sse_decode_stream_disposition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_default_ = sse_decode_bool(deserializer);
+    var var_dub = sse_decode_bool(deserializer);
+    var var_original = sse_decode_bool(deserializer);
+    var var_comment = sse_decode_bool(deserializer);
+    var var_lyrics = sse_decode_bool(deserializer);
+    var var_karaoke = sse_decode_bool(deserializer);
+    var var_forced = sse_decode_bool(deserializer);
+    var var_hearingImpaired = sse_decode_bool(deserializer);
+    var var_visualImpaired = sse_decode_bool(deserializer);
+    var var_cleanEffects = sse_decode_bool(deserializer);
+    var var_attachedPic = sse_decode_bool(deserializer);
+    var var_captions = sse_decode_bool(deserializer);
+    var var_descriptions = sse_decode_bool(deserializer);
+    var var_metadata = sse_decode_bool(deserializer);
+    return StreamDisposition(
+      default_: var_default_,
+      dub: var_dub,
+      original: var_original,
+      comment: var_comment,
+      lyrics: var_lyrics,
+      karaoke: var_karaoke,
+      forced: var_forced,
+      hearingImpaired: var_hearingImpaired,
+      visualImpaired: var_visualImpaired,
+      cleanEffects: var_cleanEffects,
+      attachedPic: var_attachedPic,
+      captions: var_captions,
+      descriptions: var_descriptions,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  StreamProbe sse_decode_stream_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_streamType = sse_decode_stream_type(deserializer);
+    var var_codecName = sse_decode_opt_String(deserializer);
+    var var_codecLongName = sse_decode_opt_String(deserializer);
+    var var_profile = sse_decode_opt_String(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_startTimeMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_durationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_frameCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_disposition = sse_decode_stream_disposition(deserializer);
+    var var_tags = sse_decode_list_media_tag(deserializer);
+    var var_sideData = sse_decode_list_stream_side_data(deserializer);
+    var var_video = sse_decode_opt_box_autoadd_video_stream_probe(deserializer);
+    var var_audio = sse_decode_opt_box_autoadd_audio_stream_probe(deserializer);
+    return StreamProbe(
+      index: var_index,
+      streamType: var_streamType,
+      codecName: var_codecName,
+      codecLongName: var_codecLongName,
+      profile: var_profile,
+      bitrate: var_bitrate,
+      startTimeMs: var_startTimeMs,
+      durationMs: var_durationMs,
+      frameCount: var_frameCount,
+      language: var_language,
+      title: var_title,
+      disposition: var_disposition,
+      tags: var_tags,
+      sideData: var_sideData,
+      video: var_video,
+      audio: var_audio,
+    );
+  }
+
+  @protected
+  StreamSideData sse_decode_stream_side_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_u_32(deserializer);
+    return StreamSideData(kind: var_kind, sizeBytes: var_sizeBytes);
+  }
+
+  @protected
+  StreamType sse_decode_stream_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return StreamType.values[inner];
+  }
+
+  @protected
+  SubtitleParams sse_decode_subtitle_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subtitlePath = sse_decode_String(deserializer);
+    var var_fontPath = sse_decode_opt_String(deserializer);
+    var var_fontSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_outlineColor = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_outlineWidth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bottomMargin = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SubtitleParams(
+      subtitlePath: var_subtitlePath,
+      fontPath: var_fontPath,
+      fontSize: var_fontSize,
+      color: var_color,
+      outlineColor: var_outlineColor,
+      outlineWidth: var_outlineWidth,
+      bottomMargin: var_bottomMargin,
+    );
+  }
+
+  @protected
+  ThumbnailData sse_decode_thumbnail_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_timeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ThumbnailData(
+      data: var_data,
+      width: var_width,
+      height: var_height,
+      timeMs: var_timeMs,
+    );
+  }
+
+  @protected
+  ThumbnailSizeType sse_decode_thumbnail_size_type(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ThumbnailSizeType_Icon();
+      case 1:
+        return ThumbnailSizeType_Small();
+      case 2:
+        return ThumbnailSizeType_Medium();
+      case 3:
+        return ThumbnailSizeType_Large();
+      case 4:
+        return ThumbnailSizeType_Larger();
+      case 5:
+        var var_field0 = sse_decode_box_autoadd_record_u_32_u_32(deserializer);
+        return ThumbnailSizeType_Custom(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  VideoInfo sse_decode_video_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_codecName = sse_decode_opt_String(deserializer);
+    var var_formatName = sse_decode_opt_String(deserializer);
+    var var_storedWidth = sse_decode_u_32(deserializer);
+    var var_storedHeight = sse_decode_u_32(deserializer);
+    var var_rotation = sse_decode_i_32(deserializer);
+    var var_avgFrameRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_realFrameRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_isVfr = sse_decode_bool(deserializer);
+    var var_pixelFormat = sse_decode_opt_String(deserializer);
+    var var_bitDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_colorPrimaries = sse_decode_opt_String(deserializer);
+    var var_colorTransfer = sse_decode_opt_String(deserializer);
+    var var_colorMatrix = sse_decode_opt_String(deserializer);
+    var var_colorRange = sse_decode_opt_String(deserializer);
+    var var_hdrFormat = sse_decode_opt_box_autoadd_hdr_format(deserializer);
+    var var_profile = sse_decode_opt_String(deserializer);
+    var var_level = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_frameCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_audio = sse_decode_opt_box_autoadd_audio_stream_info(deserializer);
+    var var_suggestions = sse_decode_list_resolution_preset(deserializer);
+    return VideoInfo(
+      durationMs: var_durationMs,
+      width: var_width,
+      height: var_height,
+      sizeBytes: var_sizeBytes,
+      bitrate: var_bitrate,
+      codecName: var_codecName,
+      formatName: var_formatName,
+      storedWidth: var_storedWidth,
+      storedHeight: var_storedHeight,
+      rotation: var_rotation,
+      avgFrameRate: var_avgFrameRate,
+      realFrameRate: var_realFrameRate,
+      isVfr: var_isVfr,
+      pixelFormat: var_pixelFormat,
+      bitDepth: var_bitDepth,
+      colorPrimaries: var_colorPrimaries,
+      colorTransfer: var_colorTransfer,
+      colorMatrix: var_colorMatrix,
+      colorRange: var_colorRange,
+      hdrFormat: var_hdrFormat,
+      profile: var_profile,
+      level: var_level,
+      frameCount: var_frameCount,
+      audio: var_audio,
+      suggestions: var_suggestions,
+    );
+  }
+
+  @protected
+  VideoStreamProbe sse_decode_video_stream_probe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_displayWidth = sse_decode_u_32(deserializer);
+    var var_displayHeight = sse_decode_u_32(deserializer);
+    var var_rotation = sse_decode_i_32(deserializer);
+    var var_avgFrameRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_realFrameRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_isVfr = sse_decode_bool(deserializer);
+    var var_pixelFormat = sse_decode_opt_String(deserializer);
+    var var_bitDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_colorPrimaries = sse_decode_opt_String(deserializer);
+    var var_colorTransfer = sse_decode_opt_String(deserializer);
+    var var_colorMatrix = sse_decode_opt_String(deserializer);
+    var var_colorRange = sse_decode_opt_String(deserializer);
+    var var_hdrFormat = sse_decode_opt_box_autoadd_hdr_format(deserializer);
+    var var_level = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return VideoStreamProbe(
+      width: var_width,
+      height: var_height,
+      displayWidth: var_displayWidth,
+      displayHeight: var_displayHeight,
+      rotation: var_rotation,
+      avgFrameRate: var_avgFrameRate,
+      realFrameRate: var_realFrameRate,
+      isVfr: var_isVfr,
+      pixelFormat: var_pixelFormat,
+      bitDepth: var_bitDepth,
+      colorPrimaries: var_colorPrimaries,
+      colorTransfer: var_colorTransfer,
+      colorMatrix: var_colorMatrix,
+      colorRange: var_colorRange,
+      hdrFormat: var_hdrFormat,
+      level: var_level,
+    );
+  }
+
+  @protected
+  VideoThumbnailParams sse_decode_video_thumbnail_params(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
+      deserializer,
+    );
+    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
+    var var_seekMode = sse_decode_opt_box_autoadd_seek_mode(deserializer);
+    var var_resizeMode = sse_decode_opt_box_autoadd_resize_mode(deserializer);
+    var var_allowUpscale = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_encoderOptions = sse_decode_opt_box_autoadd_encoder_options(
+      deserializer,
+    );
+    return VideoThumbnailParams(
+      timeMs: var_timeMs,
+      sizeType: var_sizeType,
+      format: var_format,
+      seekMode: var_seekMode,
+      resizeMode: var_resizeMode,
+      allowUpscale: var_allowUpscale,
+      encoderOptions: var_encoderOptions,
+    );
+  }
+
+  @protected
+  WatermarkParams sse_decode_watermark_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imagePath = sse_decode_String(deserializer);
+    var var_position = sse_decode_opt_box_autoadd_watermark_position(
+      deserializer,
+    );
+    var var_margin = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_scale = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_opacity = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return WatermarkParams(
+      imagePath: var_imagePath,
+      position: var_position,
+      margin: var_margin,
+      scale: var_scale,
+      opacity: var_opacity,
+      startMs: var_startMs,
+      endMs: var_endMs,
+    );
+  }
+
+  @protected
+  WatermarkPosition sse_decode_watermark_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WatermarkPosition.values[inner];
+  }
+
+  @protected
+  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_filePrefix = sse_decode_String(deserializer);
+    var var_fileSuffix = sse_decode_opt_String(deserializer);
+    var var_maxFiles = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return WriteToFiles(
+      path: var_path,
+      filePrefix: var_filePrefix,
+      fileSuffix: var_fileSuffix,
+      maxFiles: var_maxFiles,
+    );
+  }
+
+  @protected
+  int cst_encode_animation_format(AnimationFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_aspect_mode(AspectMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_audio_channels(AudioChannels raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_audio_format(AudioFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_audio_track_mode(AudioTrackMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  bool cst_encode_bool(bool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  double cst_encode_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  double cst_encode_f_64(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  int cst_encode_hash_algorithm(HashAlgorithm raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_hdr_format(HdrFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  int cst_encode_log_level(LogLevel raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_output_format(OutputFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_resize_mode(ResizeMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_reverse_audio_mode(ReverseAudioMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_seek_mode(SeekMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_stream_type(StreamType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_u_16(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Dco(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  int cst_encode_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  int cst_encode_u_8(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void cst_encode_unit(void raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  int cst_encode_watermark_position(WatermarkPosition raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_compress_params(raw);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
-    dynamic raw,
+  void sse_encode_StreamSink_String_Dco(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_image_thumbnail_params(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_output_format(raw);
+  void sse_encode_StreamSink_compress_progress_Dco(
+    RustStreamSink<CompressProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_compress_progress,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as (int, int);
+  void sse_encode_StreamSink_extracted_frame_Dco(
+    RustStreamSink<ExtractedFrame> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_extracted_frame,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_thumbnail_size_type(raw);
+  void sse_encode_StreamSink_scene_detection_event_Dco(
+    RustStreamSink<SceneDetectionEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_scene_detection_event,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_thumbnail_data_Dco(
+    RustStreamSink<ThumbnailData> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_thumbnail_data,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_animation_format(
+    AnimationFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
-    dynamic raw,
+  void sse_encode_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_video_thumbnail_params(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.maxBytes, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.loopCount, serializer);
+    sse_encode_opt_box_autoadd_bool(self.dither, serializer);
   }
 
   @protected
-  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_write_to_files(raw);
+  void sse_encode_aspect_mode(AspectMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  CompressParams dco_decode_compress_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return CompressParams(
-      targetBitrateKbps: dco_decode_u_32(arr[0]),
-      preset: dco_decode_opt_String(arr[1]),
-      crf: dco_decode_opt_box_autoadd_u_8(arr[2]),
-      width: dco_decode_opt_box_autoadd_u_32(arr[3]),
-      height: dco_decode_opt_box_autoadd_u_32(arr[4]),
-      sampleDurationMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+  void sse_encode_audio_channels(AudioChannels self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+    sse_encode_opt_box_autoadd_audio_channels(self.channels, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitrateKbps, serializer);
+    sse_encode_opt_box_autoadd_loudness_normalization(
+      self.normalizeLoudness,
+      serializer,
     );
   }
 
   @protected
-  CompressionEstimate dco_decode_compression_estimate(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return CompressionEstimate(
-      estimatedSizeBytes: dco_decode_u_64(arr[0]),
-      estimatedDurationMs: dco_decode_u_64(arr[1]),
-    );
+  void sse_encode_audio_format(AudioFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_audio_track_mode(self.mode, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.musicGainDb, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.originalGainDb, serializer);
+    sse_encode_opt_box_autoadd_ducking_params(self.ducking, serializer);
+    sse_encode_bool(self.loopMusic, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.musicStartMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fadeInMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fadeOutMs, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.codecName, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.bitrate, serializer);
+    sse_encode_opt_String(self.language, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.channels, serializer);
+    sse_encode_opt_String(self.channelLayout, serializer);
+    sse_encode_opt_String(self.sampleFormat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitsPerSample, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_best_thumbnail(BestThumbnail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_f_64(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_thumbnail_size_type(self.sizeType, serializer);
+    sse_encode_opt_box_autoadd_output_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.candidates, serializer);
+    sse_encode_opt_box_autoadd_resize_mode(self.resizeMode, serializer);
+    sse_encode_opt_box_autoadd_bool(self.allowUpscale, serializer);
+    sse_encode_opt_box_autoadd_encoder_options(self.encoderOptions, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_animation_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_aspect_mode(
+    AspectMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_aspect_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_channels(
+    AudioChannels self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_channels(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_extract_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_mix_params(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_stream_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_stream_probe(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_track_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_best_thumbnail_params(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_compress_params(self, serializer);
   }
 
   @protected
-  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ImageThumbnailParams(
-      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[0]),
-      format: dco_decode_opt_box_autoadd_output_format(arr[1]),
-    );
+  void sse_encode_box_autoadd_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_compress_progress(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_box_autoadd_concat_params(
+    ConcatParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_concat_params(self, serializer);
   }
 
   @protected
-  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_resolution_preset).toList();
+  void sse_encode_box_autoadd_crop_rect(
+    CropRect self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_crop_rect(self, serializer);
   }
 
   @protected
-  LogLevel dco_decode_log_level(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LogLevel.values[raw as int];
+  void sse_encode_box_autoadd_ducking_params(
+    DuckingParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ducking_params(self, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_box_autoadd_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_encoder_options(self, serializer);
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
   }
 
   @protected
-  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_image_thumbnail_params(raw);
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_output_format(raw);
+  void sse_encode_box_autoadd_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frame_extract_params(self, serializer);
   }
 
   @protected
-  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
-    dynamic raw,
+  void sse_encode_box_autoadd_gps_location(
+    GpsLocation self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_thumbnail_size_type(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_gps_location(self, serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void sse_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hash_algorithm(self, serializer);
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void sse_encode_box_autoadd_hdr_format(
+    HdrFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hdr_format(self, serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_write_to_files(raw);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  OutputFormat dco_decode_output_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return OutputFormat.values[raw as int];
+  void sse_encode_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_thumbnail_params(self, serializer);
   }
 
   @protected
-  (int, int) dco_decode_record_u_32_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_u_32(arr[1]));
+  void sse_encode_box_autoadd_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_loudness_normalization(self, serializer);
   }
 
   @protected
-  ResolutionPreset dco_decode_resolution_preset(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ResolutionPreset(
-      name: dco_decode_String(arr[0]),
-      width: dco_decode_u_32(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-      bitrate: dco_decode_u_64(arr[3]),
-      crf: dco_decode_u_8(arr[4]),
-    );
+  void sse_encode_box_autoadd_media_hash(
+    MediaHash self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_hash(self, serializer);
   }
 
   @protected
-  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return ThumbnailSizeType_Icon();
-      case 1:
-        return ThumbnailSizeType_Small();
-      case 2:
-        return ThumbnailSizeType_Medium();
-      case 3:
-        return ThumbnailSizeType_Large();
-      case 4:
-        return ThumbnailSizeType_Larger();
-      case 5:
-        return ThumbnailSizeType_Custom(
-          dco_decode_box_autoadd_record_u_32_u_32(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_box_autoadd_output_format(
+    OutputFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_output_format(self, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_palette_color(
+    PaletteColor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_palette_color(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_record_u_32_u_32(
+    (int, int) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_record_u_32_u_32(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_resize_mode(
+    ResizeMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_resize_mode(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reverse_audio_mode(self, serializer);
   }
 
   @protected
-  VideoInfo dco_decode_video_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return VideoInfo(
-      durationMs: dco_decode_u_64(arr[0]),
-      width: dco_decode_u_32(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-      sizeBytes: dco_decode_u_64(arr[3]),
-      bitrate: dco_decode_opt_box_autoadd_u_64(arr[4]),
-      codecName: dco_decode_opt_String(arr[5]),
-      formatName: dco_decode_opt_String(arr[6]),
-      suggestions: dco_decode_list_resolution_preset(arr[7]),
-    );
+  void sse_encode_box_autoadd_reverse_params(
+    ReverseParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reverse_params(self, serializer);
   }
 
   @protected
-  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return VideoThumbnailParams(
-      timeMs: dco_decode_u_64(arr[0]),
-      sizeType: dco_decode_opt_box_autoadd_thumbnail_size_type(arr[1]),
-      format: dco_decode_opt_box_autoadd_output_format(arr[2]),
-    );
+  void sse_encode_box_autoadd_seek_mode(
+    SeekMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_seek_mode(self, serializer);
   }
 
   @protected
-  WriteToFiles dco_decode_write_to_files(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WriteToFiles(
-      path: dco_decode_String(arr[0]),
-      filePrefix: dco_decode_String(arr[1]),
-      fileSuffix: dco_decode_opt_String(arr[2]),
-      maxFiles: dco_decode_opt_box_autoadd_u_64(arr[3]),
-    );
+  void sse_encode_box_autoadd_speed_params(
+    SpeedParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_speed_params(self, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_subtitle_params(self, serializer);
   }
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_thumbnail_size_type(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_u_16(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
+    sse_encode_u_8(self, serializer);
   }
 
   @protected
-  CompressParams sse_decode_box_autoadd_compress_params(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_compress_params(deserializer));
+    sse_encode_video_stream_probe(self, serializer);
   }
 
   @protected
-  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_video_thumbnail_params(
+    VideoThumbnailParams self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_image_thumbnail_params(deserializer));
+    sse_encode_video_thumbnail_params(self, serializer);
   }
 
   @protected
-  OutputFormat sse_decode_box_autoadd_output_format(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_output_format(deserializer));
+    sse_encode_watermark_params(self, serializer);
   }
 
   @protected
-  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_record_u_32_u_32(deserializer));
+    sse_encode_watermark_position(self, serializer);
   }
 
   @protected
-  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_write_to_files(
+    WriteToFiles self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_thumbnail_size_type(deserializer));
+    sse_encode_write_to_files(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_u_32(self.targetBitrateKbps, serializer);
+    sse_encode_opt_String(self.preset, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.crf, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.sampleDurationMs, serializer);
+    sse_encode_opt_box_autoadd_crop_rect(self.crop, serializer);
+    sse_encode_opt_box_autoadd_record_u_32_u_32(self.aspectRatio, serializer);
+    sse_encode_opt_box_autoadd_aspect_mode(self.aspectMode, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.padColor, serializer);
+    sse_encode_opt_box_autoadd_watermark_params(self.watermark, serializer);
+    sse_encode_opt_box_autoadd_subtitle_params(self.subtitles, serializer);
+    sse_encode_opt_box_autoadd_speed_params(self.speed, serializer);
+    sse_encode_opt_box_autoadd_loudness_normalization(
+      self.normalizeLoudness,
+      serializer,
+    );
+    sse_encode_opt_String(self.videoFilters, serializer);
+    sse_encode_opt_String(self.audioFilters, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_u_64(self.processedMs, serializer);
+    sse_encode_u_64(self.totalMs, serializer);
+    sse_encode_f_32(self.speedX, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_compression_estimate(
+    CompressionEstimate self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_8(deserializer));
+    sse_encode_u_64(self.estimatedSizeBytes, serializer);
+    sse_encode_u_64(self.estimatedDurationMs, serializer);
   }
 
   @protected
-  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_concat_params(ConcatParams self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_video_thumbnail_params(deserializer));
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.fps, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.targetBitrateKbps, serializer);
+    sse_encode_opt_String(self.preset, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.crf, serializer);
+    sse_encode_opt_box_autoadd_bool(self.forceReencode, serializer);
   }
 
   @protected
-  WriteToFiles sse_decode_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_crop_rect(CropRect self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_write_to_files(deserializer));
+    sse_encode_u_32(self.x, serializer);
+    sse_encode_u_32(self.y, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
   }
 
   @protected
-  CompressParams sse_decode_compress_params(SseDeserializer deserializer) {
+  void sse_encode_ducking_params(DuckingParams self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_targetBitrateKbps = sse_decode_u_32(deserializer);
-    var var_preset = sse_decode_opt_String(deserializer);
-    var var_crf = sse_decode_opt_box_autoadd_u_8(deserializer);
-    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_sampleDurationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return CompressParams(
-      targetBitrateKbps: var_targetBitrateKbps,
-      preset: var_preset,
-      crf: var_crf,
-      width: var_width,
-      height: var_height,
-      sampleDurationMs: var_sampleDurationMs,
-    );
+    sse_encode_opt_box_autoadd_f_32(self.thresholdDb, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.reductionDb, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.attackMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.releaseMs, serializer);
   }
 
   @protected
-  CompressionEstimate sse_decode_compression_estimate(
-    SseDeserializer deserializer,
+  void sse_encode_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_estimatedSizeBytes = sse_decode_u_64(deserializer);
-    var var_estimatedDurationMs = sse_decode_u_64(deserializer);
-    return CompressionEstimate(
-      estimatedSizeBytes: var_estimatedSizeBytes,
-      estimatedDurationMs: var_estimatedDurationMs,
-    );
+    sse_encode_opt_box_autoadd_u_8(self.jpegQuality, serializer);
+    sse_encode_opt_box_autoadd_bool(self.jpegProgressive, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.webpQuality, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.pngCompression, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.avifQuality, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.heicQuality, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_extracted_frame(
+    ExtractedFrame self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_u_64(self.requestedMs, serializer);
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
   }
 
   @protected
-  ImageThumbnailParams sse_decode_image_thumbnail_params(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
-      deserializer,
-    );
-    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
-    return ImageThumbnailParams(sizeType: var_sizeType, format: var_format);
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  List<ResolutionPreset> sse_decode_list_resolution_preset(
-    SseDeserializer deserializer,
+  void sse_encode_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ResolutionPreset>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_resolution_preset(deserializer));
-    }
-    return ans_;
+    sse_encode_frame_selection(self.selection, serializer);
+    sse_encode_opt_box_autoadd_thumbnail_size_type(self.sizeType, serializer);
+    sse_encode_opt_box_autoadd_output_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_seek_mode(self.seekMode, serializer);
+    sse_encode_opt_box_autoadd_resize_mode(self.resizeMode, serializer);
+    sse_encode_opt_box_autoadd_bool(self.allowUpscale, serializer);
+    sse_encode_opt_box_autoadd_encoder_options(self.encoderOptions, serializer);
   }
 
   @protected
-  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+  void sse_encode_frame_selection(
+    FrameSelection self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LogLevel.values[inner];
+    switch (self) {
+      case FrameSelection_Timestamps(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_u_64_strict(field0, serializer);
+      case FrameSelection_Interval(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(field0, serializer);
+      case FrameSelection_Count(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_32(field0, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_gps_location(GpsLocation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_f_64(self.latitude, serializer);
+    sse_encode_f_64(self.longitude, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.altitude, serializer);
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
+  void sse_encode_hash_comparison(
+    HashComparison self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_image_thumbnail_params(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_u_32(self.distance, serializer);
+    sse_encode_f_64(self.similarity, serializer);
+    sse_encode_bool(self.isSimilar, serializer);
   }
 
   @protected
-  OutputFormat? sse_decode_opt_box_autoadd_output_format(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_hdr_format(HdrFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_output_format(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_thumbnail_size_type(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_image_info(ImageInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_u_32(self.bitDepth, serializer);
+    sse_encode_bool(self.hasAlpha, serializer);
+    sse_encode_u_32(self.orientation, serializer);
+    sse_encode_opt_String(self.cameraMake, serializer);
+    sse_encode_opt_String(self.cameraModel, serializer);
+    sse_encode_opt_String(self.captureDate, serializer);
+    sse_encode_opt_box_autoadd_gps_location(self.gps, serializer);
+    sse_encode_opt_String(self.iccProfileName, serializer);
+    sse_encode_u_32(self.imageCount, serializer);
+    sse_encode_u_32(self.auxiliaryImageCount, serializer);
+    sse_encode_bool(self.hasDepth, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_opt_box_autoadd_thumbnail_size_type(self.sizeType, serializer);
+    sse_encode_opt_box_autoadd_output_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_seek_mode(self.seekMode, serializer);
+    sse_encode_opt_box_autoadd_resize_mode(self.resizeMode, serializer);
+    sse_encode_opt_box_autoadd_bool(self.allowUpscale, serializer);
+    sse_encode_opt_box_autoadd_encoder_options(self.encoderOptions, serializer);
   }
 
   @protected
-  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_write_to_files(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
   }
 
   @protected
-  OutputFormat sse_decode_output_format(SseDeserializer deserializer) {
+  void sse_encode_list_media_chapter(
+    List<MediaChapter> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return OutputFormat.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_chapter(item, serializer);
+    }
   }
 
   @protected
-  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_media_tag(
+    List<MediaTag> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_u_32(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_tag(item, serializer);
+    }
   }
 
   @protected
-  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_bitrate = sse_decode_u_64(deserializer);
-    var var_crf = sse_decode_u_8(deserializer);
-    return ResolutionPreset(
-      name: var_name,
-      width: var_width,
-      height: var_height,
-      bitrate: var_bitrate,
-      crf: var_crf,
-    );
+  void sse_encode_list_palette_color(
+    List<PaletteColor> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_palette_color(item, serializer);
+    }
   }
 
   @protected
-  ThumbnailSizeType sse_decode_thumbnail_size_type(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return ThumbnailSizeType_Icon();
-      case 1:
-        return ThumbnailSizeType_Small();
-      case 2:
-        return ThumbnailSizeType_Medium();
-      case 3:
-        return ThumbnailSizeType_Large();
-      case 4:
-        return ThumbnailSizeType_Larger();
-      case 5:
-        var var_field0 = sse_decode_box_autoadd_record_u_32_u_32(deserializer);
-        return ThumbnailSizeType_Custom(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint64List(self);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_resolution_preset(
+    List<ResolutionPreset> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_resolution_preset(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_scene_cut(
+    List<SceneCut> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scene_cut(item, serializer);
+    }
   }
 
   @protected
-  VideoInfo sse_decode_video_info(SseDeserializer deserializer) {
+  void sse_encode_list_stream_probe(
+    List<StreamProbe> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_durationMs = sse_decode_u_64(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_sizeBytes = sse_decode_u_64(deserializer);
-    var var_bitrate = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_codecName = sse_decode_opt_String(deserializer);
-    var var_formatName = sse_decode_opt_String(deserializer);
-    var var_suggestions = sse_decode_list_resolution_preset(deserializer);
-    return VideoInfo(
-      durationMs: var_durationMs,
-      width: var_width,
-      height: var_height,
-      sizeBytes: var_sizeBytes,
-      bitrate: var_bitrate,
-      codecName: var_codecName,
-      formatName: var_formatName,
-      suggestions: var_suggestions,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stream_probe(item, serializer);
+    }
   }
 
   @protected
-  VideoThumbnailParams sse_decode_video_thumbnail_params(
-    SseDeserializer deserializer,
+  void sse_encode_list_stream_side_data(
+    List<StreamSideData> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timeMs = sse_decode_u_64(deserializer);
-    var var_sizeType = sse_decode_opt_box_autoadd_thumbnail_size_type(
-      deserializer,
-    );
-    var var_format = sse_decode_opt_box_autoadd_output_format(deserializer);
-    return VideoThumbnailParams(
-      timeMs: var_timeMs,
-      sizeType: var_sizeType,
-      format: var_format,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stream_side_data(item, serializer);
+    }
   }
 
   @protected
-  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer) {
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_filePrefix = sse_decode_String(deserializer);
-    var var_fileSuffix = sse_decode_opt_String(deserializer);
-    var var_maxFiles = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return WriteToFiles(
-      path: var_path,
-      filePrefix: var_filePrefix,
-      fileSuffix: var_fileSuffix,
-      maxFiles: var_maxFiles,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  bool cst_encode_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_loudness_info(LoudnessInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.integratedLufs, serializer);
+    sse_encode_f_64(self.loudnessRangeLu, serializer);
+    sse_encode_f_64(self.truePeakDbtp, serializer);
   }
 
   @protected
-  int cst_encode_i_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.targetLufs, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.truePeakDbtp, serializer);
   }
 
   @protected
-  int cst_encode_log_level(LogLevel raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_media_chapter(MediaChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_u_64(self.endMs, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_list_media_tag(self.tags, serializer);
   }
 
   @protected
-  int cst_encode_output_format(OutputFormat raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_media_hash(MediaHash self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hash_algorithm(self.algorithm, serializer);
+    sse_encode_list_prim_u_64_strict(self.hashes, serializer);
+    sse_encode_list_prim_u_64_strict(self.timesMs, serializer);
   }
 
   @protected
-  int cst_encode_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_media_probe(MediaProbe self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.formatName, serializer);
+    sse_encode_opt_String(self.formatLongName, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startTimeMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.bitrate, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+    sse_encode_list_media_tag(self.tags, serializer);
+    sse_encode_list_media_chapter(self.chapters, serializer);
+    sse_encode_list_stream_probe(self.streams, serializer);
   }
 
   @protected
-  int cst_encode_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_media_tag(MediaTag self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
-  void cst_encode_unit(void raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_aspect_mode(
+    AspectMode? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_aspect_mode(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_String_Dco(
-    RustStreamSink<String> self,
+  void sse_encode_opt_box_autoadd_audio_channels(
+    AudioChannels? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_channels(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_audio_stream_info(
+    AudioStreamInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_stream_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_audio_stream_probe(
+    AudioStreamProbe? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_stream_probe(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_audio_track_mode(
+    AudioTrackMode? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_track_mode(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_compress_params(
-    CompressParams self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_compress_params(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams self,
+  void sse_encode_opt_box_autoadd_crop_rect(
+    CropRect? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_image_thumbnail_params(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_crop_rect(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_output_format(
-    OutputFormat self,
+  void sse_encode_opt_box_autoadd_ducking_params(
+    DuckingParams? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_output_format(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_ducking_params(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_record_u_32_u_32(
-    (int, int) self,
+  void sse_encode_opt_box_autoadd_encoder_options(
+    EncoderOptions? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_u_32_u_32(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_encoder_options(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_thumbnail_size_type(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_gps_location(
+    GpsLocation? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_gps_location(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hash_algorithm(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_video_thumbnail_params(
-    VideoThumbnailParams self,
+  void sse_encode_opt_box_autoadd_hdr_format(
+    HdrFormat? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_video_thumbnail_params(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hdr_format(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_write_to_files(
-    WriteToFiles self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_write_to_files(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_compress_params(
-    CompressParams self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.targetBitrateKbps, serializer);
-    sse_encode_opt_String(self.preset, serializer);
-    sse_encode_opt_box_autoadd_u_8(self.crf, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.sampleDurationMs, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_compression_estimate(
-    CompressionEstimate self,
+  void sse_encode_opt_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.estimatedSizeBytes, serializer);
-    sse_encode_u_64(self.estimatedDurationMs, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_image_thumbnail_params(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_loudness_normalization(
+    LoudnessNormalization? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_loudness_normalization(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_image_thumbnail_params(
-    ImageThumbnailParams self,
+  void sse_encode_opt_box_autoadd_output_format(
+    OutputFormat? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_thumbnail_size_type(self.sizeType, serializer);
-    sse_encode_opt_box_autoadd_output_format(self.format, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_output_format(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_palette_color(
+    PaletteColor? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_palette_color(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_resolution_preset(
-    List<ResolutionPreset> self,
+  void sse_encode_opt_box_autoadd_record_u_32_u_32(
+    (int, int)? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_resolution_preset(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_record_u_32_u_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_resize_mode(
+    ResizeMode? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_resize_mode(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_reverse_audio_mode(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_seek_mode(
+    SeekMode? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
+      sse_encode_box_autoadd_seek_mode(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams? self,
+  void sse_encode_opt_box_autoadd_speed_params(
+    SpeedParams? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_image_thumbnail_params(self, serializer);
+      sse_encode_box_autoadd_speed_params(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_output_format(
-    OutputFormat? self,
+  void sse_encode_opt_box_autoadd_subtitle_params(
+    SubtitleParams? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_output_format(self, serializer);
+      sse_encode_box_autoadd_subtitle_params(self, serializer);
     }
   }
 
@@ -1648,6 +6850,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1678,6 +6890,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_video_stream_probe(
+    VideoStreamProbe? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_video_stream_probe(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_params(
+    WatermarkParams? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_watermark_params(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_position(
+    WatermarkPosition? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_watermark_position(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_write_to_files(
     WriteToFiles? self,
@@ -1697,6 +6948,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_palette(Palette self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_palette_color(self.colors, serializer);
+    sse_encode_palette_color(self.average, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.vibrant, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.lightVibrant, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.darkVibrant, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.muted, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.lightMuted, serializer);
+    sse_encode_opt_box_autoadd_palette_color(self.darkMuted, serializer);
+  }
+
+  @protected
+  void sse_encode_palette_color(PaletteColor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.color, serializer);
+    sse_encode_f_64(self.population, serializer);
+  }
+
+  @protected
+  void sse_encode_placeholder_image(
+    PlaceholderImage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.rgba, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+  }
+
   @protected
   void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1704,6 +6986,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_resize_mode(ResizeMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_resolution_preset(
     ResolutionPreset self,
@@ -1717,6 +7005,158 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_8(self.crf, serializer);
   }
 
+  @protected
+  void sse_encode_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_reverse_params(ReverseParams self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.boomerangLoops, serializer);
+    sse_encode_opt_box_autoadd_reverse_audio_mode(self.audio, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.targetBitrateKbps, serializer);
+    sse_encode_opt_String(self.preset, serializer);
+    sse_encode_opt_box_autoadd_u_8(self.crf, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.memoryBudgetMb, serializer);
+  }
+
+  @protected
+  void sse_encode_scene_cut(SceneCut self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_f_64(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_scene_detection_event(
+    SceneDetectionEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SceneDetectionEvent_Progress(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_compress_progress(field0, serializer);
+      case SceneDetectionEvent_Cuts(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_scene_cut(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_seek_mode(SeekMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_speed_params(SpeedParams self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.factor, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.frameRate, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.muteAudioAbove, serializer);
+  }
+
+  @protected
+  void sse_encode_storyboard(Storyboard self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.vttPath, serializer);
+    sse_encode_list_String(self.spritePaths, serializer);
+    sse_encode_u_32(self.tileCount, serializer);
+  }
+
+  @protected
+  void sse_encode_stream_disposition(
+    StreamDisposition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.default_, serializer);
+    sse_encode_bool(self.dub, serializer);
+    sse_encode_bool(self.original, serializer);
+    sse_encode_bool(self.comment, serializer);
+    sse_encode_bool(self.lyrics, serializer);
+    sse_encode_bool(self.karaoke, serializer);
+    sse_encode_bool(self.forced, serializer);
+    sse_encode_bool(self.hearingImpaired, serializer);
+    sse_encode_bool(self.visualImpaired, serializer);
+    sse_encode_bool(self.cleanEffects, serializer);
+    sse_encode_bool(self.attachedPic, serializer);
+    sse_encode_bool(self.captions, serializer);
+    sse_encode_bool(self.descriptions, serializer);
+    sse_encode_bool(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_stream_probe(StreamProbe self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_stream_type(self.streamType, serializer);
+    sse_encode_opt_String(self.codecName, serializer);
+    sse_encode_opt_String(self.codecLongName, serializer);
+    sse_encode_opt_String(self.profile, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.bitrate, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startTimeMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.frameCount, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_stream_disposition(self.disposition, serializer);
+    sse_encode_list_media_tag(self.tags, serializer);
+    sse_encode_list_stream_side_data(self.sideData, serializer);
+    sse_encode_opt_box_autoadd_video_stream_probe(self.video, serializer);
+    sse_encode_opt_box_autoadd_audio_stream_probe(self.audio, serializer);
+  }
+
+  @protected
+  void sse_encode_stream_side_data(
+    StreamSideData self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_u_32(self.sizeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_stream_type(StreamType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.subtitlePath, serializer);
+    sse_encode_opt_String(self.fontPath, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.fontSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.color, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.outlineColor, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.outlineWidth, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bottomMargin, serializer);
+  }
+
+  @protected
+  void sse_encode_thumbnail_data(ThumbnailData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timeMs, serializer);
+  }
+
   @protected
   void sse_encode_thumbnail_size_type(
     ThumbnailSizeType self,
@@ -1740,6 +7180,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1773,9 +7219,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.bitrate, serializer);
     sse_encode_opt_String(self.codecName, serializer);
     sse_encode_opt_String(self.formatName, serializer);
+    sse_encode_u_32(self.storedWidth, serializer);
+    sse_encode_u_32(self.storedHeight, serializer);
+    sse_encode_i_32(self.rotation, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.avgFrameRate, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.realFrameRate, serializer);
+    sse_encode_bool(self.isVfr, serializer);
+    sse_encode_opt_String(self.pixelFormat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitDepth, serializer);
+    sse_encode_opt_String(self.colorPrimaries, serializer);
+    sse_encode_opt_String(self.colorTransfer, serializer);
+    sse_encode_opt_String(self.colorMatrix, serializer);
+    sse_encode_opt_String(self.colorRange, serializer);
+    sse_encode_opt_box_autoadd_hdr_format(self.hdrFormat, serializer);
+    sse_encode_opt_String(self.profile, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.level, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.frameCount, serializer);
+    sse_encode_opt_box_autoadd_audio_stream_info(self.audio, serializer);
     sse_encode_list_resolution_preset(self.suggestions, serializer);
   }
 
+  @protected
+  void sse_encode_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.displayWidth, serializer);
+    sse_encode_u_32(self.displayHeight, serializer);
+    sse_encode_i_32(self.rotation, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.avgFrameRate, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.realFrameRate, serializer);
+    sse_encode_bool(self.isVfr, serializer);
+    sse_encode_opt_String(self.pixelFormat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitDepth, serializer);
+    sse_encode_opt_String(self.colorPrimaries, serializer);
+    sse_encode_opt_String(self.colorTransfer, serializer);
+    sse_encode_opt_String(self.colorMatrix, serializer);
+    sse_encode_opt_String(self.colorRange, serializer);
+    sse_encode_opt_box_autoadd_hdr_format(self.hdrFormat, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.level, serializer);
+  }
+
   @protected
   void sse_encode_video_thumbnail_params(
     VideoThumbnailParams self,
@@ -1785,6 +7272,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.timeMs, serializer);
     sse_encode_opt_box_autoadd_thumbnail_size_type(self.sizeType, serializer);
     sse_encode_opt_box_autoadd_output_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_seek_mode(self.seekMode, serializer);
+    sse_encode_opt_box_autoadd_resize_mode(self.resizeMode, serializer);
+    sse_encode_opt_box_autoadd_bool(self.allowUpscale, serializer);
+    sse_encode_opt_box_autoadd_encoder_options(self.encoderOptions, serializer);
+  }
+
+  @protected
+  void sse_encode_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.imagePath, serializer);
+    sse_encode_opt_box_autoadd_watermark_position(self.position, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.margin, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.scale, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.opacity, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+  }
+
+  @protected
+  void sse_encode_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
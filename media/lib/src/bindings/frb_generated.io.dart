@@ -26,1602 +26,6871 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<String> dco_decode_StreamSink_String_Dco(dynamic raw);
 
   @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
+  RustStreamSink<CompressProgress> dco_decode_StreamSink_compress_progress_Dco(
+    dynamic raw,
+  );
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
+  RustStreamSink<ExtractedFrame> dco_decode_StreamSink_extracted_frame_Dco(
+    dynamic raw,
+  );
 
   @protected
-  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw);
+  RustStreamSink<SceneDetectionEvent>
+  dco_decode_StreamSink_scene_detection_event_Dco(dynamic raw);
 
   @protected
-  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
+  RustStreamSink<ThumbnailData> dco_decode_StreamSink_thumbnail_data_Dco(
     dynamic raw,
   );
 
   @protected
-  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw);
+  String dco_decode_String(dynamic raw);
 
   @protected
-  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw);
+  AnimationFormat dco_decode_animation_format(dynamic raw);
 
   @protected
-  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw);
+  AnimationOptions dco_decode_animation_options(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+  AspectMode dco_decode_aspect_mode(dynamic raw);
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  AudioChannels dco_decode_audio_channels(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw);
+  AudioExtractOptions dco_decode_audio_extract_options(dynamic raw);
 
   @protected
-  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
-    dynamic raw,
-  );
+  AudioFormat dco_decode_audio_format(dynamic raw);
 
   @protected
-  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw);
+  AudioMixParams dco_decode_audio_mix_params(dynamic raw);
 
   @protected
-  CompressParams dco_decode_compress_params(dynamic raw);
+  AudioStreamInfo dco_decode_audio_stream_info(dynamic raw);
 
   @protected
-  CompressionEstimate dco_decode_compression_estimate(dynamic raw);
+  AudioStreamProbe dco_decode_audio_stream_probe(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  AudioTrackMode dco_decode_audio_track_mode(dynamic raw);
 
   @protected
-  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw);
+  BestThumbnail dco_decode_best_thumbnail(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  BestThumbnailParams dco_decode_best_thumbnail_params(dynamic raw);
 
   @protected
-  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw);
+  bool dco_decode_bool(dynamic raw);
 
   @protected
-  LogLevel dco_decode_log_level(dynamic raw);
+  AnimationOptions dco_decode_box_autoadd_animation_options(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  AspectMode dco_decode_box_autoadd_aspect_mode(dynamic raw);
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+  AudioChannels dco_decode_box_autoadd_audio_channels(dynamic raw);
 
   @protected
-  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
-    dynamic raw,
-  );
+  AudioExtractOptions dco_decode_box_autoadd_audio_extract_options(dynamic raw);
 
   @protected
-  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw);
+  AudioMixParams dco_decode_box_autoadd_audio_mix_params(dynamic raw);
 
   @protected
-  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
-    dynamic raw,
-  );
+  AudioStreamInfo dco_decode_box_autoadd_audio_stream_info(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  AudioStreamProbe dco_decode_box_autoadd_audio_stream_probe(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  AudioTrackMode dco_decode_box_autoadd_audio_track_mode(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+  BestThumbnailParams dco_decode_box_autoadd_best_thumbnail_params(dynamic raw);
 
   @protected
-  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw);
+  bool dco_decode_box_autoadd_bool(dynamic raw);
 
   @protected
-  OutputFormat dco_decode_output_format(dynamic raw);
+  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw);
 
   @protected
-  (int, int) dco_decode_record_u_32_u_32(dynamic raw);
+  CompressProgress dco_decode_box_autoadd_compress_progress(dynamic raw);
 
   @protected
-  ResolutionPreset dco_decode_resolution_preset(dynamic raw);
+  ConcatParams dco_decode_box_autoadd_concat_params(dynamic raw);
 
   @protected
-  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw);
+  CropRect dco_decode_box_autoadd_crop_rect(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  DuckingParams dco_decode_box_autoadd_ducking_params(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  EncoderOptions dco_decode_box_autoadd_encoder_options(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  double dco_decode_box_autoadd_f_32(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  double dco_decode_box_autoadd_f_64(dynamic raw);
 
   @protected
-  VideoInfo dco_decode_video_info(dynamic raw);
+  FrameExtractParams dco_decode_box_autoadd_frame_extract_params(dynamic raw);
 
   @protected
-  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw);
+  GpsLocation dco_decode_box_autoadd_gps_location(dynamic raw);
 
   @protected
-  WriteToFiles dco_decode_write_to_files(dynamic raw);
+  HashAlgorithm dco_decode_box_autoadd_hash_algorithm(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  HdrFormat dco_decode_box_autoadd_hdr_format(dynamic raw);
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
-    SseDeserializer deserializer,
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
+    dynamic raw,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  LoudnessNormalization dco_decode_box_autoadd_loudness_normalization(
+    dynamic raw,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  MediaHash dco_decode_box_autoadd_media_hash(dynamic raw);
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw);
 
   @protected
-  CompressParams sse_decode_box_autoadd_compress_params(
-    SseDeserializer deserializer,
-  );
+  PaletteColor dco_decode_box_autoadd_palette_color(dynamic raw);
 
   @protected
-  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw);
 
   @protected
-  OutputFormat sse_decode_box_autoadd_output_format(
-    SseDeserializer deserializer,
-  );
+  ResizeMode dco_decode_box_autoadd_resize_mode(dynamic raw);
 
   @protected
-  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
-    SseDeserializer deserializer,
-  );
+  ReverseAudioMode dco_decode_box_autoadd_reverse_audio_mode(dynamic raw);
 
   @protected
-  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  ReverseParams dco_decode_box_autoadd_reverse_params(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  SeekMode dco_decode_box_autoadd_seek_mode(dynamic raw);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  SpeedParams dco_decode_box_autoadd_speed_params(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+  SubtitleParams dco_decode_box_autoadd_subtitle_params(dynamic raw);
 
   @protected
-  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw);
 
   @protected
-  WriteToFiles sse_decode_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_box_autoadd_u_16(dynamic raw);
 
   @protected
-  CompressParams sse_decode_compress_params(SseDeserializer deserializer);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  CompressionEstimate sse_decode_compression_estimate(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  int dco_decode_box_autoadd_u_8(dynamic raw);
 
   @protected
-  ImageThumbnailParams sse_decode_image_thumbnail_params(
-    SseDeserializer deserializer,
+  VideoStreamProbe dco_decode_box_autoadd_video_stream_probe(dynamic raw);
+
+  @protected
+  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
+    dynamic raw,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  WatermarkParams dco_decode_box_autoadd_watermark_params(dynamic raw);
 
   @protected
-  List<ResolutionPreset> sse_decode_list_resolution_preset(
-    SseDeserializer deserializer,
-  );
+  WatermarkPosition dco_decode_box_autoadd_watermark_position(dynamic raw);
 
   @protected
-  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  CompressParams dco_decode_compress_params(dynamic raw);
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+  CompressProgress dco_decode_compress_progress(dynamic raw);
 
   @protected
-  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  CompressionEstimate dco_decode_compression_estimate(dynamic raw);
 
   @protected
-  OutputFormat? sse_decode_opt_box_autoadd_output_format(
-    SseDeserializer deserializer,
-  );
+  ConcatParams dco_decode_concat_params(dynamic raw);
 
   @protected
-  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  CropRect dco_decode_crop_rect(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  DuckingParams dco_decode_ducking_params(dynamic raw);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  EncoderOptions dco_decode_encoder_options(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+  ExtractedFrame dco_decode_extracted_frame(dynamic raw);
 
   @protected
-  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  );
+  double dco_decode_f_32(dynamic raw);
 
   @protected
-  OutputFormat sse_decode_output_format(SseDeserializer deserializer);
+  double dco_decode_f_64(dynamic raw);
 
   @protected
-  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer);
+  FrameExtractParams dco_decode_frame_extract_params(dynamic raw);
 
   @protected
-  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer);
+  FrameSelection dco_decode_frame_selection(dynamic raw);
 
   @protected
-  ThumbnailSizeType sse_decode_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  GpsLocation dco_decode_gps_location(dynamic raw);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  HashAlgorithm dco_decode_hash_algorithm(dynamic raw);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  HashComparison dco_decode_hash_comparison(dynamic raw);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  HdrFormat dco_decode_hdr_format(dynamic raw);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  VideoInfo sse_decode_video_info(SseDeserializer deserializer);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  VideoThumbnailParams sse_decode_video_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  ImageInfo dco_decode_image_info(dynamic raw);
 
   @protected
-  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer);
+  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(
-    AnyhowException raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_String_Dco(
-    RustStreamSink<String> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_String(
-      raw.setupAndSerialize(
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
-        ),
-      ),
-    );
-  }
+  List<MediaChapter> dco_decode_list_media_chapter(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
-  }
+  List<MediaTag> dco_decode_list_media_tag(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Bool> cst_encode_box_autoadd_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_bool(cst_encode_bool(raw));
-  }
+  List<PaletteColor> dco_decode_list_palette_color(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_compress_params> cst_encode_box_autoadd_compress_params(
-    CompressParams raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_compress_params();
-    cst_api_fill_to_wire_compress_params(raw, ptr.ref);
-    return ptr;
-  }
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_image_thumbnail_params>
-  cst_encode_box_autoadd_image_thumbnail_params(ImageThumbnailParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_image_thumbnail_params();
-    cst_api_fill_to_wire_image_thumbnail_params(raw, ptr.ref);
-    return ptr;
-  }
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_output_format(
-    OutputFormat raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_output_format(
-      cst_encode_output_format(raw),
-    );
-  }
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_record_u_32_u_32>
-  cst_encode_box_autoadd_record_u_32_u_32((int, int) raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_record_u_32_u_32();
-    cst_api_fill_to_wire_record_u_32_u_32(raw, ptr.ref);
-    return ptr;
-  }
+  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_thumbnail_size_type>
-  cst_encode_box_autoadd_thumbnail_size_type(ThumbnailSizeType raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_thumbnail_size_type();
-    cst_api_fill_to_wire_thumbnail_size_type(raw, ptr.ref);
-    return ptr;
-  }
+  List<SceneCut> dco_decode_list_scene_cut(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
-  }
+  List<StreamProbe> dco_decode_list_stream_probe(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
-  }
+  List<StreamSideData> dco_decode_list_stream_side_data(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint8> cst_encode_box_autoadd_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_8(cst_encode_u_8(raw));
-  }
+  LogLevel dco_decode_log_level(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_video_thumbnail_params>
-  cst_encode_box_autoadd_video_thumbnail_params(VideoThumbnailParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_video_thumbnail_params();
-    cst_api_fill_to_wire_video_thumbnail_params(raw, ptr.ref);
-    return ptr;
-  }
+  LoudnessInfo dco_decode_loudness_info(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_write_to_files> cst_encode_box_autoadd_write_to_files(
-    WriteToFiles raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_write_to_files();
-    cst_api_fill_to_wire_write_to_files(raw, ptr.ref);
-    return ptr;
-  }
+  LoudnessNormalization dco_decode_loudness_normalization(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
-    Uint8List raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  MediaChapter dco_decode_media_chapter(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_resolution_preset>
-  cst_encode_list_resolution_preset(List<ResolutionPreset> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_resolution_preset(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_resolution_preset(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  MediaHash dco_decode_media_hash(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
-    String? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
-  }
+  MediaProbe dco_decode_media_probe(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Bool> cst_encode_opt_box_autoadd_bool(bool? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bool(raw);
-  }
+  MediaTag dco_decode_media_tag(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_image_thumbnail_params>
-  cst_encode_opt_box_autoadd_image_thumbnail_params(ImageThumbnailParams? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_image_thumbnail_params(raw);
-  }
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_output_format(
-    OutputFormat? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_output_format(raw);
-  }
+  AspectMode? dco_decode_opt_box_autoadd_aspect_mode(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_thumbnail_size_type>
-  cst_encode_opt_box_autoadd_thumbnail_size_type(ThumbnailSizeType? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_thumbnail_size_type(raw);
-  }
+  AudioChannels? dco_decode_opt_box_autoadd_audio_channels(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
-  }
+  AudioStreamInfo? dco_decode_opt_box_autoadd_audio_stream_info(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
-  }
+  AudioStreamProbe? dco_decode_opt_box_autoadd_audio_stream_probe(dynamic raw);
 
   @protected
-  ffi.Pointer<ffi.Uint8> cst_encode_opt_box_autoadd_u_8(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_8(raw);
-  }
+  AudioTrackMode? dco_decode_opt_box_autoadd_audio_track_mode(dynamic raw);
 
   @protected
-  ffi.Pointer<wire_cst_write_to_files>
-  cst_encode_opt_box_autoadd_write_to_files(WriteToFiles? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_box_autoadd_write_to_files(raw);
-  }
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
   @protected
-  int cst_encode_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
-  }
+  CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_compress_params(
-    CompressParams apiObj,
-    ffi.Pointer<wire_cst_compress_params> wireObj,
-  ) {
-    cst_api_fill_to_wire_compress_params(apiObj, wireObj.ref);
-  }
+  DuckingParams? dco_decode_opt_box_autoadd_ducking_params(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams apiObj,
-    ffi.Pointer<wire_cst_image_thumbnail_params> wireObj,
-  ) {
-    cst_api_fill_to_wire_image_thumbnail_params(apiObj, wireObj.ref);
-  }
+  EncoderOptions? dco_decode_opt_box_autoadd_encoder_options(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_record_u_32_u_32(
-    (int, int) apiObj,
-    ffi.Pointer<wire_cst_record_u_32_u_32> wireObj,
-  ) {
-    cst_api_fill_to_wire_record_u_32_u_32(apiObj, wireObj.ref);
-  }
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType apiObj,
-    ffi.Pointer<wire_cst_thumbnail_size_type> wireObj,
-  ) {
-    cst_api_fill_to_wire_thumbnail_size_type(apiObj, wireObj.ref);
-  }
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_video_thumbnail_params(
-    VideoThumbnailParams apiObj,
-    ffi.Pointer<wire_cst_video_thumbnail_params> wireObj,
-  ) {
-    cst_api_fill_to_wire_video_thumbnail_params(apiObj, wireObj.ref);
-  }
+  GpsLocation? dco_decode_opt_box_autoadd_gps_location(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_write_to_files(
-    WriteToFiles apiObj,
-    ffi.Pointer<wire_cst_write_to_files> wireObj,
-  ) {
-    cst_api_fill_to_wire_write_to_files(apiObj, wireObj.ref);
-  }
+  HashAlgorithm? dco_decode_opt_box_autoadd_hash_algorithm(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_compress_params(
-    CompressParams apiObj,
-    wire_cst_compress_params wireObj,
-  ) {
-    wireObj.target_bitrate_kbps = cst_encode_u_32(apiObj.targetBitrateKbps);
-    wireObj.preset = cst_encode_opt_String(apiObj.preset);
-    wireObj.crf = cst_encode_opt_box_autoadd_u_8(apiObj.crf);
-    wireObj.width = cst_encode_opt_box_autoadd_u_32(apiObj.width);
-    wireObj.height = cst_encode_opt_box_autoadd_u_32(apiObj.height);
-    wireObj.sample_duration_ms = cst_encode_opt_box_autoadd_u_64(
-      apiObj.sampleDurationMs,
-    );
-  }
+  HdrFormat? dco_decode_opt_box_autoadd_hdr_format(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_compression_estimate(
-    CompressionEstimate apiObj,
-    wire_cst_compression_estimate wireObj,
-  ) {
-    wireObj.estimated_size_bytes = cst_encode_u_64(apiObj.estimatedSizeBytes);
-    wireObj.estimated_duration_ms = cst_encode_u_64(apiObj.estimatedDurationMs);
-  }
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_image_thumbnail_params(
-    ImageThumbnailParams apiObj,
-    wire_cst_image_thumbnail_params wireObj,
-  ) {
-    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
-      apiObj.sizeType,
-    );
-    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
-  }
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_record_u_32_u_32(
-    (int, int) apiObj,
-    wire_cst_record_u_32_u_32 wireObj,
-  ) {
-    wireObj.field0 = cst_encode_u_32(apiObj.$1);
-    wireObj.field1 = cst_encode_u_32(apiObj.$2);
-  }
+  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
+    dynamic raw,
+  );
 
   @protected
-  void cst_api_fill_to_wire_resolution_preset(
-    ResolutionPreset apiObj,
-    wire_cst_resolution_preset wireObj,
-  ) {
-    wireObj.name = cst_encode_String(apiObj.name);
-    wireObj.width = cst_encode_u_32(apiObj.width);
-    wireObj.height = cst_encode_u_32(apiObj.height);
-    wireObj.bitrate = cst_encode_u_64(apiObj.bitrate);
-    wireObj.crf = cst_encode_u_8(apiObj.crf);
-  }
+  LoudnessNormalization? dco_decode_opt_box_autoadd_loudness_normalization(
+    dynamic raw,
+  );
 
   @protected
-  void cst_api_fill_to_wire_thumbnail_size_type(
-    ThumbnailSizeType apiObj,
-    wire_cst_thumbnail_size_type wireObj,
-  ) {
-    if (apiObj is ThumbnailSizeType_Icon) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is ThumbnailSizeType_Small) {
-      wireObj.tag = 1;
-      return;
-    }
-    if (apiObj is ThumbnailSizeType_Medium) {
-      wireObj.tag = 2;
-      return;
-    }
-    if (apiObj is ThumbnailSizeType_Large) {
-      wireObj.tag = 3;
-      return;
-    }
-    if (apiObj is ThumbnailSizeType_Larger) {
-      wireObj.tag = 4;
-      return;
-    }
-    if (apiObj is ThumbnailSizeType_Custom) {
-      var pre_field0 = cst_encode_box_autoadd_record_u_32_u_32(apiObj.field0);
-      wireObj.tag = 5;
-      wireObj.kind.Custom.field0 = pre_field0;
-      return;
-    }
-  }
+  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_video_info(
-    VideoInfo apiObj,
-    wire_cst_video_info wireObj,
-  ) {
-    wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs);
-    wireObj.width = cst_encode_u_32(apiObj.width);
-    wireObj.height = cst_encode_u_32(apiObj.height);
-    wireObj.size_bytes = cst_encode_u_64(apiObj.sizeBytes);
-    wireObj.bitrate = cst_encode_opt_box_autoadd_u_64(apiObj.bitrate);
-    wireObj.codec_name = cst_encode_opt_String(apiObj.codecName);
-    wireObj.format_name = cst_encode_opt_String(apiObj.formatName);
-    wireObj.suggestions = cst_encode_list_resolution_preset(apiObj.suggestions);
-  }
+  PaletteColor? dco_decode_opt_box_autoadd_palette_color(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_video_thumbnail_params(
-    VideoThumbnailParams apiObj,
-    wire_cst_video_thumbnail_params wireObj,
-  ) {
-    wireObj.time_ms = cst_encode_u_64(apiObj.timeMs);
-    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
-      apiObj.sizeType,
-    );
-    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
-  }
+  (int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32(dynamic raw);
 
   @protected
-  void cst_api_fill_to_wire_write_to_files(
-    WriteToFiles apiObj,
-    wire_cst_write_to_files wireObj,
-  ) {
-    wireObj.path = cst_encode_String(apiObj.path);
-    wireObj.file_prefix = cst_encode_String(apiObj.filePrefix);
-    wireObj.file_suffix = cst_encode_opt_String(apiObj.fileSuffix);
-    wireObj.max_files = cst_encode_opt_box_autoadd_u_64(apiObj.maxFiles);
-  }
+  ResizeMode? dco_decode_opt_box_autoadd_resize_mode(dynamic raw);
 
   @protected
-  bool cst_encode_bool(bool raw);
+  ReverseAudioMode? dco_decode_opt_box_autoadd_reverse_audio_mode(dynamic raw);
 
   @protected
-  int cst_encode_i_32(int raw);
+  SeekMode? dco_decode_opt_box_autoadd_seek_mode(dynamic raw);
 
   @protected
-  int cst_encode_log_level(LogLevel raw);
+  SpeedParams? dco_decode_opt_box_autoadd_speed_params(dynamic raw);
 
   @protected
-  int cst_encode_output_format(OutputFormat raw);
+  SubtitleParams? dco_decode_opt_box_autoadd_subtitle_params(dynamic raw);
 
   @protected
-  int cst_encode_u_32(int raw);
+  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
+    dynamic raw,
+  );
 
   @protected
-  int cst_encode_u_8(int raw);
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
   @protected
-  void cst_encode_unit(void raw);
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  void sse_encode_StreamSink_String_Dco(
-    RustStreamSink<String> self,
-    SseSerializer serializer,
-  );
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  VideoStreamProbe? dco_decode_opt_box_autoadd_video_stream_probe(dynamic raw);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  WatermarkParams? dco_decode_opt_box_autoadd_watermark_params(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+  WatermarkPosition? dco_decode_opt_box_autoadd_watermark_position(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_compress_params(
-    CompressParams self,
-    SseSerializer serializer,
-  );
+  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams self,
-    SseSerializer serializer,
-  );
+  OutputFormat dco_decode_output_format(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_output_format(
-    OutputFormat self,
-    SseSerializer serializer,
-  );
+  Palette dco_decode_palette(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_record_u_32_u_32(
-    (int, int) self,
-    SseSerializer serializer,
-  );
+  PaletteColor dco_decode_palette_color(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType self,
-    SseSerializer serializer,
-  );
+  PlaceholderImage dco_decode_placeholder_image(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  (int, int) dco_decode_record_u_32_u_32(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  ResizeMode dco_decode_resize_mode(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+  ResolutionPreset dco_decode_resolution_preset(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_video_thumbnail_params(
-    VideoThumbnailParams self,
-    SseSerializer serializer,
-  );
+  ReverseAudioMode dco_decode_reverse_audio_mode(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_write_to_files(
-    WriteToFiles self,
-    SseSerializer serializer,
+  ReverseParams dco_decode_reverse_params(dynamic raw);
+
+  @protected
+  SceneCut dco_decode_scene_cut(dynamic raw);
+
+  @protected
+  SceneDetectionEvent dco_decode_scene_detection_event(dynamic raw);
+
+  @protected
+  SeekMode dco_decode_seek_mode(dynamic raw);
+
+  @protected
+  SpeedParams dco_decode_speed_params(dynamic raw);
+
+  @protected
+  Storyboard dco_decode_storyboard(dynamic raw);
+
+  @protected
+  StreamDisposition dco_decode_stream_disposition(dynamic raw);
+
+  @protected
+  StreamProbe dco_decode_stream_probe(dynamic raw);
+
+  @protected
+  StreamSideData dco_decode_stream_side_data(dynamic raw);
+
+  @protected
+  StreamType dco_decode_stream_type(dynamic raw);
+
+  @protected
+  SubtitleParams dco_decode_subtitle_params(dynamic raw);
+
+  @protected
+  ThumbnailData dco_decode_thumbnail_data(dynamic raw);
+
+  @protected
+  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  VideoInfo dco_decode_video_info(dynamic raw);
+
+  @protected
+  VideoStreamProbe dco_decode_video_stream_probe(dynamic raw);
+
+  @protected
+  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw);
+
+  @protected
+  WatermarkParams dco_decode_watermark_params(dynamic raw);
+
+  @protected
+  WatermarkPosition dco_decode_watermark_position(dynamic raw);
+
+  @protected
+  WriteToFiles dco_decode_write_to_files(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_compress_params(
-    CompressParams self,
-    SseSerializer serializer,
+  RustStreamSink<CompressProgress> sse_decode_StreamSink_compress_progress_Dco(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_compression_estimate(
-    CompressionEstimate self,
-    SseSerializer serializer,
+  RustStreamSink<ExtractedFrame> sse_decode_StreamSink_extracted_frame_Dco(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  RustStreamSink<SceneDetectionEvent>
+  sse_decode_StreamSink_scene_detection_event_Dco(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_image_thumbnail_params(
-    ImageThumbnailParams self,
-    SseSerializer serializer,
+  RustStreamSink<ThumbnailData> sse_decode_StreamSink_thumbnail_data_Dco(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AnimationFormat sse_decode_animation_format(SseDeserializer deserializer);
+
+  @protected
+  AnimationOptions sse_decode_animation_options(SseDeserializer deserializer);
+
+  @protected
+  AspectMode sse_decode_aspect_mode(SseDeserializer deserializer);
+
+  @protected
+  AudioChannels sse_decode_audio_channels(SseDeserializer deserializer);
+
+  @protected
+  AudioExtractOptions sse_decode_audio_extract_options(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_resolution_preset(
-    List<ResolutionPreset> self,
-    SseSerializer serializer,
+  AudioFormat sse_decode_audio_format(SseDeserializer deserializer);
+
+  @protected
+  AudioMixParams sse_decode_audio_mix_params(SseDeserializer deserializer);
+
+  @protected
+  AudioStreamInfo sse_decode_audio_stream_info(SseDeserializer deserializer);
+
+  @protected
+  AudioStreamProbe sse_decode_audio_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  AudioTrackMode sse_decode_audio_track_mode(SseDeserializer deserializer);
+
+  @protected
+  BestThumbnail sse_decode_best_thumbnail(SseDeserializer deserializer);
+
+  @protected
+  BestThumbnailParams sse_decode_best_thumbnail_params(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  AnimationOptions sse_decode_box_autoadd_animation_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+  AspectMode sse_decode_box_autoadd_aspect_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams? self,
-    SseSerializer serializer,
+  AudioChannels sse_decode_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_output_format(
-    OutputFormat? self,
-    SseSerializer serializer,
+  AudioExtractOptions sse_decode_box_autoadd_audio_extract_options(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType? self,
-    SseSerializer serializer,
+  AudioMixParams sse_decode_box_autoadd_audio_mix_params(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+  AudioStreamInfo sse_decode_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+  AudioStreamProbe sse_decode_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+  AudioTrackMode sse_decode_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_write_to_files(
-    WriteToFiles? self,
-    SseSerializer serializer,
+  BestThumbnailParams sse_decode_box_autoadd_best_thumbnail_params(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_output_format(OutputFormat self, SseSerializer serializer);
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer);
+  CompressParams sse_decode_box_autoadd_compress_params(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_resolution_preset(
-    ResolutionPreset self,
-    SseSerializer serializer,
+  CompressProgress sse_decode_box_autoadd_compress_progress(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_thumbnail_size_type(
-    ThumbnailSizeType self,
-    SseSerializer serializer,
+  ConcatParams sse_decode_box_autoadd_concat_params(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  DuckingParams sse_decode_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  EncoderOptions sse_decode_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_video_info(VideoInfo self, SseSerializer serializer);
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_video_thumbnail_params(
-    VideoThumbnailParams self,
-    SseSerializer serializer,
+  FrameExtractParams sse_decode_box_autoadd_frame_extract_params(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_write_to_files(WriteToFiles self, SseSerializer serializer);
-}
+  GpsLocation sse_decode_box_autoadd_gps_location(SseDeserializer deserializer);
 
-// Section: wire_class
+  @protected
+  HashAlgorithm sse_decode_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint, unused_import
+  @protected
+  HdrFormat sse_decode_box_autoadd_hdr_format(SseDeserializer deserializer);
 
-/// generated by flutter_rust_bridge
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
+  @protected
+  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
 
-  /// The symbols are looked up with [lookup].
-  RustLibWire.fromLookup(
-    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
-  ) : _lookup = lookup;
+  @protected
+  LoudnessNormalization sse_decode_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  );
 
-  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
-    return _store_dart_post_cobject(ptr);
-  }
+  @protected
+  MediaHash sse_decode_box_autoadd_media_hash(SseDeserializer deserializer);
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-        'store_dart_post_cobject',
-      );
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  @protected
+  OutputFormat sse_decode_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  );
 
-  void wire__crate__api__media__compress_video(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
-    ffi.Pointer<wire_cst_compress_params> params,
-  ) {
-    return _wire__crate__api__media__compress_video(
-      port_,
-      path,
-      output_path,
-      params,
-    );
-  }
+  @protected
+  PaletteColor sse_decode_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  );
 
-  late final _wire__crate__api__media__compress_videoPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_compress_params>,
-          )
-        >
+  @protected
+  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResizeMode sse_decode_box_autoadd_resize_mode(SseDeserializer deserializer);
+
+  @protected
+  ReverseAudioMode sse_decode_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReverseParams sse_decode_box_autoadd_reverse_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode sse_decode_box_autoadd_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams sse_decode_box_autoadd_speed_params(SseDeserializer deserializer);
+
+  @protected
+  SubtitleParams sse_decode_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe sse_decode_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams sse_decode_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkPosition sse_decode_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriteToFiles sse_decode_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CompressParams sse_decode_compress_params(SseDeserializer deserializer);
+
+  @protected
+  CompressProgress sse_decode_compress_progress(SseDeserializer deserializer);
+
+  @protected
+  CompressionEstimate sse_decode_compression_estimate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConcatParams sse_decode_concat_params(SseDeserializer deserializer);
+
+  @protected
+  CropRect sse_decode_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DuckingParams sse_decode_ducking_params(SseDeserializer deserializer);
+
+  @protected
+  EncoderOptions sse_decode_encoder_options(SseDeserializer deserializer);
+
+  @protected
+  ExtractedFrame sse_decode_extracted_frame(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrameExtractParams sse_decode_frame_extract_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrameSelection sse_decode_frame_selection(SseDeserializer deserializer);
+
+  @protected
+  GpsLocation sse_decode_gps_location(SseDeserializer deserializer);
+
+  @protected
+  HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer);
+
+  @protected
+  HashComparison sse_decode_hash_comparison(SseDeserializer deserializer);
+
+  @protected
+  HdrFormat sse_decode_hdr_format(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageInfo sse_decode_image_info(SseDeserializer deserializer);
+
+  @protected
+  ImageThumbnailParams sse_decode_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<MediaChapter> sse_decode_list_media_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MediaTag> sse_decode_list_media_tag(SseDeserializer deserializer);
+
+  @protected
+  List<PaletteColor> sse_decode_list_palette_color(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ResolutionPreset> sse_decode_list_resolution_preset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SceneCut> sse_decode_list_scene_cut(SseDeserializer deserializer);
+
+  @protected
+  List<StreamProbe> sse_decode_list_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  List<StreamSideData> sse_decode_list_stream_side_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  LoudnessInfo sse_decode_loudness_info(SseDeserializer deserializer);
+
+  @protected
+  LoudnessNormalization sse_decode_loudness_normalization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaChapter sse_decode_media_chapter(SseDeserializer deserializer);
+
+  @protected
+  MediaHash sse_decode_media_hash(SseDeserializer deserializer);
+
+  @protected
+  MediaProbe sse_decode_media_probe(SseDeserializer deserializer);
+
+  @protected
+  MediaTag sse_decode_media_tag(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AspectMode? sse_decode_opt_box_autoadd_aspect_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioChannels? sse_decode_opt_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamInfo? sse_decode_opt_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamProbe? sse_decode_opt_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTrackMode? sse_decode_opt_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DuckingParams? sse_decode_opt_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncoderOptions? sse_decode_opt_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GpsLocation? sse_decode_opt_box_autoadd_gps_location(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HashAlgorithm? sse_decode_opt_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HdrFormat? sse_decode_opt_box_autoadd_hdr_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoudnessNormalization? sse_decode_opt_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputFormat? sse_decode_opt_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaletteColor? sse_decode_opt_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResizeMode? sse_decode_opt_box_autoadd_resize_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReverseAudioMode? sse_decode_opt_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode? sse_decode_opt_box_autoadd_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams? sse_decode_opt_box_autoadd_speed_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SubtitleParams? sse_decode_opt_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe? sse_decode_opt_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams? sse_decode_opt_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkPosition? sse_decode_opt_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputFormat sse_decode_output_format(SseDeserializer deserializer);
+
+  @protected
+  Palette sse_decode_palette(SseDeserializer deserializer);
+
+  @protected
+  PaletteColor sse_decode_palette_color(SseDeserializer deserializer);
+
+  @protected
+  PlaceholderImage sse_decode_placeholder_image(SseDeserializer deserializer);
+
+  @protected
+  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer);
+
+  @protected
+  ResizeMode sse_decode_resize_mode(SseDeserializer deserializer);
+
+  @protected
+  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer);
+
+  @protected
+  ReverseAudioMode sse_decode_reverse_audio_mode(SseDeserializer deserializer);
+
+  @protected
+  ReverseParams sse_decode_reverse_params(SseDeserializer deserializer);
+
+  @protected
+  SceneCut sse_decode_scene_cut(SseDeserializer deserializer);
+
+  @protected
+  SceneDetectionEvent sse_decode_scene_detection_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode sse_decode_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams sse_decode_speed_params(SseDeserializer deserializer);
+
+  @protected
+  Storyboard sse_decode_storyboard(SseDeserializer deserializer);
+
+  @protected
+  StreamDisposition sse_decode_stream_disposition(SseDeserializer deserializer);
+
+  @protected
+  StreamProbe sse_decode_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  StreamSideData sse_decode_stream_side_data(SseDeserializer deserializer);
+
+  @protected
+  StreamType sse_decode_stream_type(SseDeserializer deserializer);
+
+  @protected
+  SubtitleParams sse_decode_subtitle_params(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailData sse_decode_thumbnail_data(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailSizeType sse_decode_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  VideoInfo sse_decode_video_info(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe sse_decode_video_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  VideoThumbnailParams sse_decode_video_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams sse_decode_watermark_params(SseDeserializer deserializer);
+
+  @protected
+  WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer);
+
+  @protected
+  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer);
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(
+    AnyhowException raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_String_Dco(
+    RustStreamSink<String> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_compress_progress_Dco(
+    RustStreamSink<CompressProgress> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_compress_progress,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_extracted_frame_Dco(
+    RustStreamSink<ExtractedFrame> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_extracted_frame,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_scene_detection_event_Dco(
+    RustStreamSink<SceneDetectionEvent> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_scene_detection_event,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_thumbnail_data_Dco(RustStreamSink<ThumbnailData> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_thumbnail_data,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_animation_options>
+  cst_encode_box_autoadd_animation_options(AnimationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_animation_options();
+    cst_api_fill_to_wire_animation_options(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_aspect_mode(AspectMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_aspect_mode(cst_encode_aspect_mode(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_audio_channels(
+    AudioChannels raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_audio_channels(
+      cst_encode_audio_channels(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_extract_options>
+  cst_encode_box_autoadd_audio_extract_options(AudioExtractOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_audio_extract_options();
+    cst_api_fill_to_wire_audio_extract_options(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_mix_params>
+  cst_encode_box_autoadd_audio_mix_params(AudioMixParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_audio_mix_params();
+    cst_api_fill_to_wire_audio_mix_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_stream_info>
+  cst_encode_box_autoadd_audio_stream_info(AudioStreamInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_audio_stream_info();
+    cst_api_fill_to_wire_audio_stream_info(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_stream_probe>
+  cst_encode_box_autoadd_audio_stream_probe(AudioStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_audio_stream_probe();
+    cst_api_fill_to_wire_audio_stream_probe(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_audio_track_mode(
+    AudioTrackMode raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_audio_track_mode(
+      cst_encode_audio_track_mode(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_best_thumbnail_params>
+  cst_encode_box_autoadd_best_thumbnail_params(BestThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_best_thumbnail_params();
+    cst_api_fill_to_wire_best_thumbnail_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> cst_encode_box_autoadd_bool(bool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_bool(cst_encode_bool(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_compress_params> cst_encode_box_autoadd_compress_params(
+    CompressParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_compress_params();
+    cst_api_fill_to_wire_compress_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_compress_progress>
+  cst_encode_box_autoadd_compress_progress(CompressProgress raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_compress_progress();
+    cst_api_fill_to_wire_compress_progress(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_concat_params> cst_encode_box_autoadd_concat_params(
+    ConcatParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_concat_params();
+    cst_api_fill_to_wire_concat_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_crop_rect> cst_encode_box_autoadd_crop_rect(
+    CropRect raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_crop_rect();
+    cst_api_fill_to_wire_crop_rect(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_ducking_params> cst_encode_box_autoadd_ducking_params(
+    DuckingParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_ducking_params();
+    cst_api_fill_to_wire_ducking_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_encoder_options> cst_encode_box_autoadd_encoder_options(
+    EncoderOptions raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_encoder_options();
+    cst_api_fill_to_wire_encoder_options(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_box_autoadd_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_f_32(cst_encode_f_32(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Double> cst_encode_box_autoadd_f_64(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_f_64(cst_encode_f_64(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_frame_extract_params>
+  cst_encode_box_autoadd_frame_extract_params(FrameExtractParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_frame_extract_params();
+    cst_api_fill_to_wire_frame_extract_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_gps_location> cst_encode_box_autoadd_gps_location(
+    GpsLocation raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_gps_location();
+    cst_api_fill_to_wire_gps_location(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_hash_algorithm(
+      cst_encode_hash_algorithm(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_hdr_format(HdrFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_hdr_format(cst_encode_hdr_format(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_i_32(cst_encode_i_32(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_i_64(cst_encode_i_64(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_image_thumbnail_params>
+  cst_encode_box_autoadd_image_thumbnail_params(ImageThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_image_thumbnail_params();
+    cst_api_fill_to_wire_image_thumbnail_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_loudness_normalization>
+  cst_encode_box_autoadd_loudness_normalization(LoudnessNormalization raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_loudness_normalization();
+    cst_api_fill_to_wire_loudness_normalization(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_media_hash> cst_encode_box_autoadd_media_hash(
+    MediaHash raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_media_hash();
+    cst_api_fill_to_wire_media_hash(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_output_format(
+    OutputFormat raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_output_format(
+      cst_encode_output_format(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_palette_color> cst_encode_box_autoadd_palette_color(
+    PaletteColor raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_palette_color();
+    cst_api_fill_to_wire_palette_color(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_record_u_32_u_32>
+  cst_encode_box_autoadd_record_u_32_u_32((int, int) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_record_u_32_u_32();
+    cst_api_fill_to_wire_record_u_32_u_32(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_resize_mode(ResizeMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_resize_mode(cst_encode_resize_mode(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_reverse_audio_mode(
+      cst_encode_reverse_audio_mode(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_reverse_params> cst_encode_box_autoadd_reverse_params(
+    ReverseParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_reverse_params();
+    cst_api_fill_to_wire_reverse_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_seek_mode(SeekMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_seek_mode(cst_encode_seek_mode(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_speed_params> cst_encode_box_autoadd_speed_params(
+    SpeedParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_speed_params();
+    cst_api_fill_to_wire_speed_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_subtitle_params> cst_encode_box_autoadd_subtitle_params(
+    SubtitleParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_subtitle_params();
+    cst_api_fill_to_wire_subtitle_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_thumbnail_size_type>
+  cst_encode_box_autoadd_thumbnail_size_type(ThumbnailSizeType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_thumbnail_size_type();
+    cst_api_fill_to_wire_thumbnail_size_type(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> cst_encode_box_autoadd_u_16(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_16(cst_encode_u_16(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint8> cst_encode_box_autoadd_u_8(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_8(cst_encode_u_8(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_video_stream_probe>
+  cst_encode_box_autoadd_video_stream_probe(VideoStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_video_stream_probe();
+    cst_api_fill_to_wire_video_stream_probe(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_video_thumbnail_params>
+  cst_encode_box_autoadd_video_thumbnail_params(VideoThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_video_thumbnail_params();
+    cst_api_fill_to_wire_video_thumbnail_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_watermark_params>
+  cst_encode_box_autoadd_watermark_params(WatermarkParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_watermark_params();
+    cst_api_fill_to_wire_watermark_params(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_watermark_position(
+    WatermarkPosition raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_watermark_position(
+      cst_encode_watermark_position(raw),
+    );
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_write_to_files> cst_encode_box_autoadd_write_to_files(
+    WriteToFiles raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_write_to_files();
+    cst_api_fill_to_wire_write_to_files(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  int cst_encode_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toInt();
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_String(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_media_chapter> cst_encode_list_media_chapter(
+    List<MediaChapter> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_media_chapter(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_media_chapter(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_media_tag> cst_encode_list_media_tag(
+    List<MediaTag> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_media_tag(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_media_tag(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_palette_color> cst_encode_list_palette_color(
+    List<PaletteColor> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_palette_color(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_palette_color(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_64_strict> cst_encode_list_prim_u_64_strict(
+    Uint64List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_64_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw.inner);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_encode_list_prim_u_8_loose(
+    List<int> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_8_loose(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
+    Uint8List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_resolution_preset>
+  cst_encode_list_resolution_preset(List<ResolutionPreset> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_resolution_preset(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_resolution_preset(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_scene_cut> cst_encode_list_scene_cut(
+    List<SceneCut> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_scene_cut(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_scene_cut(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_stream_probe> cst_encode_list_stream_probe(
+    List<StreamProbe> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_stream_probe(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_stream_probe(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_stream_side_data> cst_encode_list_stream_side_data(
+    List<StreamSideData> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_stream_side_data(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_stream_side_data(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
+    String? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_aspect_mode(
+    AspectMode? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_aspect_mode(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_audio_channels(
+    AudioChannels? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_audio_channels(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_stream_info>
+  cst_encode_opt_box_autoadd_audio_stream_info(AudioStreamInfo? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_audio_stream_info(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_audio_stream_probe>
+  cst_encode_opt_box_autoadd_audio_stream_probe(AudioStreamProbe? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_audio_stream_probe(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_audio_track_mode(
+    AudioTrackMode? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_audio_track_mode(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Bool> cst_encode_opt_box_autoadd_bool(bool? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_crop_rect> cst_encode_opt_box_autoadd_crop_rect(
+    CropRect? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_crop_rect(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_ducking_params>
+  cst_encode_opt_box_autoadd_ducking_params(DuckingParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_ducking_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_encoder_options>
+  cst_encode_opt_box_autoadd_encoder_options(EncoderOptions? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_encoder_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_opt_box_autoadd_f_32(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Double> cst_encode_opt_box_autoadd_f_64(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_gps_location> cst_encode_opt_box_autoadd_gps_location(
+    GpsLocation? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_gps_location(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_hash_algorithm(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_hdr_format(HdrFormat? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_hdr_format(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_i_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_image_thumbnail_params>
+  cst_encode_opt_box_autoadd_image_thumbnail_params(ImageThumbnailParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_image_thumbnail_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_loudness_normalization>
+  cst_encode_opt_box_autoadd_loudness_normalization(
+    LoudnessNormalization? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_loudness_normalization(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_output_format(
+    OutputFormat? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_output_format(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_palette_color> cst_encode_opt_box_autoadd_palette_color(
+    PaletteColor? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_palette_color(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_record_u_32_u_32>
+  cst_encode_opt_box_autoadd_record_u_32_u_32((int, int)? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_record_u_32_u_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_resize_mode(
+    ResizeMode? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_resize_mode(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_reverse_audio_mode(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_seek_mode(SeekMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_seek_mode(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_speed_params> cst_encode_opt_box_autoadd_speed_params(
+    SpeedParams? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_speed_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_subtitle_params>
+  cst_encode_opt_box_autoadd_subtitle_params(SubtitleParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_subtitle_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_thumbnail_size_type>
+  cst_encode_opt_box_autoadd_thumbnail_size_type(ThumbnailSizeType? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_thumbnail_size_type(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> cst_encode_opt_box_autoadd_u_16(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint8> cst_encode_opt_box_autoadd_u_8(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_video_stream_probe>
+  cst_encode_opt_box_autoadd_video_stream_probe(VideoStreamProbe? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_video_stream_probe(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_watermark_params>
+  cst_encode_opt_box_autoadd_watermark_params(WatermarkParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_watermark_params(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_watermark_position(
+    WatermarkPosition? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_watermark_position(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_write_to_files>
+  cst_encode_opt_box_autoadd_write_to_files(WriteToFiles? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_write_to_files(raw);
+  }
+
+  @protected
+  int cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_animation_options(
+    AnimationOptions apiObj,
+    ffi.Pointer<wire_cst_animation_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_animation_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_audio_extract_options(
+    AudioExtractOptions apiObj,
+    ffi.Pointer<wire_cst_audio_extract_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_audio_extract_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_audio_mix_params(
+    AudioMixParams apiObj,
+    ffi.Pointer<wire_cst_audio_mix_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_audio_mix_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_audio_stream_info(
+    AudioStreamInfo apiObj,
+    ffi.Pointer<wire_cst_audio_stream_info> wireObj,
+  ) {
+    cst_api_fill_to_wire_audio_stream_info(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_audio_stream_probe(
+    AudioStreamProbe apiObj,
+    ffi.Pointer<wire_cst_audio_stream_probe> wireObj,
+  ) {
+    cst_api_fill_to_wire_audio_stream_probe(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_best_thumbnail_params(
+    BestThumbnailParams apiObj,
+    ffi.Pointer<wire_cst_best_thumbnail_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_best_thumbnail_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_compress_params(
+    CompressParams apiObj,
+    ffi.Pointer<wire_cst_compress_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_compress_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_compress_progress(
+    CompressProgress apiObj,
+    ffi.Pointer<wire_cst_compress_progress> wireObj,
+  ) {
+    cst_api_fill_to_wire_compress_progress(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_concat_params(
+    ConcatParams apiObj,
+    ffi.Pointer<wire_cst_concat_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_concat_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_crop_rect(
+    CropRect apiObj,
+    ffi.Pointer<wire_cst_crop_rect> wireObj,
+  ) {
+    cst_api_fill_to_wire_crop_rect(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_ducking_params(
+    DuckingParams apiObj,
+    ffi.Pointer<wire_cst_ducking_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_ducking_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_encoder_options(
+    EncoderOptions apiObj,
+    ffi.Pointer<wire_cst_encoder_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_encoder_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_frame_extract_params(
+    FrameExtractParams apiObj,
+    ffi.Pointer<wire_cst_frame_extract_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_frame_extract_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_gps_location(
+    GpsLocation apiObj,
+    ffi.Pointer<wire_cst_gps_location> wireObj,
+  ) {
+    cst_api_fill_to_wire_gps_location(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams apiObj,
+    ffi.Pointer<wire_cst_image_thumbnail_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_image_thumbnail_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_loudness_normalization(
+    LoudnessNormalization apiObj,
+    ffi.Pointer<wire_cst_loudness_normalization> wireObj,
+  ) {
+    cst_api_fill_to_wire_loudness_normalization(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_media_hash(
+    MediaHash apiObj,
+    ffi.Pointer<wire_cst_media_hash> wireObj,
+  ) {
+    cst_api_fill_to_wire_media_hash(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_palette_color(
+    PaletteColor apiObj,
+    ffi.Pointer<wire_cst_palette_color> wireObj,
+  ) {
+    cst_api_fill_to_wire_palette_color(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_record_u_32_u_32(
+    (int, int) apiObj,
+    ffi.Pointer<wire_cst_record_u_32_u_32> wireObj,
+  ) {
+    cst_api_fill_to_wire_record_u_32_u_32(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_reverse_params(
+    ReverseParams apiObj,
+    ffi.Pointer<wire_cst_reverse_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_reverse_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_speed_params(
+    SpeedParams apiObj,
+    ffi.Pointer<wire_cst_speed_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_speed_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_subtitle_params(
+    SubtitleParams apiObj,
+    ffi.Pointer<wire_cst_subtitle_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_subtitle_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType apiObj,
+    ffi.Pointer<wire_cst_thumbnail_size_type> wireObj,
+  ) {
+    cst_api_fill_to_wire_thumbnail_size_type(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_video_stream_probe(
+    VideoStreamProbe apiObj,
+    ffi.Pointer<wire_cst_video_stream_probe> wireObj,
+  ) {
+    cst_api_fill_to_wire_video_stream_probe(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_video_thumbnail_params(
+    VideoThumbnailParams apiObj,
+    ffi.Pointer<wire_cst_video_thumbnail_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_video_thumbnail_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_watermark_params(
+    WatermarkParams apiObj,
+    ffi.Pointer<wire_cst_watermark_params> wireObj,
+  ) {
+    cst_api_fill_to_wire_watermark_params(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_write_to_files(
+    WriteToFiles apiObj,
+    ffi.Pointer<wire_cst_write_to_files> wireObj,
+  ) {
+    cst_api_fill_to_wire_write_to_files(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_animation_options(
+    AnimationOptions apiObj,
+    wire_cst_animation_options wireObj,
+  ) {
+    wireObj.max_bytes = cst_encode_opt_box_autoadd_u_64(apiObj.maxBytes);
+    wireObj.loop_count = cst_encode_opt_box_autoadd_u_16(apiObj.loopCount);
+    wireObj.dither = cst_encode_opt_box_autoadd_bool(apiObj.dither);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_audio_extract_options(
+    AudioExtractOptions apiObj,
+    wire_cst_audio_extract_options wireObj,
+  ) {
+    wireObj.start_ms = cst_encode_opt_box_autoadd_u_64(apiObj.startMs);
+    wireObj.end_ms = cst_encode_opt_box_autoadd_u_64(apiObj.endMs);
+    wireObj.channels = cst_encode_opt_box_autoadd_audio_channels(
+      apiObj.channels,
+    );
+    wireObj.sample_rate = cst_encode_opt_box_autoadd_u_32(apiObj.sampleRate);
+    wireObj.bitrate_kbps = cst_encode_opt_box_autoadd_u_32(apiObj.bitrateKbps);
+    wireObj.normalize_loudness =
+        cst_encode_opt_box_autoadd_loudness_normalization(
+          apiObj.normalizeLoudness,
+        );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_audio_mix_params(
+    AudioMixParams apiObj,
+    wire_cst_audio_mix_params wireObj,
+  ) {
+    wireObj.mode = cst_encode_opt_box_autoadd_audio_track_mode(apiObj.mode);
+    wireObj.music_gain_db = cst_encode_opt_box_autoadd_f_32(apiObj.musicGainDb);
+    wireObj.original_gain_db = cst_encode_opt_box_autoadd_f_32(
+      apiObj.originalGainDb,
+    );
+    wireObj.ducking = cst_encode_opt_box_autoadd_ducking_params(apiObj.ducking);
+    wireObj.loop_music = cst_encode_bool(apiObj.loopMusic);
+    wireObj.music_start_ms = cst_encode_opt_box_autoadd_u_64(
+      apiObj.musicStartMs,
+    );
+    wireObj.fade_in_ms = cst_encode_opt_box_autoadd_u_64(apiObj.fadeInMs);
+    wireObj.fade_out_ms = cst_encode_opt_box_autoadd_u_64(apiObj.fadeOutMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_audio_stream_info(
+    AudioStreamInfo apiObj,
+    wire_cst_audio_stream_info wireObj,
+  ) {
+    wireObj.codec_name = cst_encode_opt_String(apiObj.codecName);
+    wireObj.channels = cst_encode_u_32(apiObj.channels);
+    wireObj.sample_rate = cst_encode_u_32(apiObj.sampleRate);
+    wireObj.bitrate = cst_encode_opt_box_autoadd_u_64(apiObj.bitrate);
+    wireObj.language = cst_encode_opt_String(apiObj.language);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_audio_stream_probe(
+    AudioStreamProbe apiObj,
+    wire_cst_audio_stream_probe wireObj,
+  ) {
+    wireObj.sample_rate = cst_encode_u_32(apiObj.sampleRate);
+    wireObj.channels = cst_encode_u_32(apiObj.channels);
+    wireObj.channel_layout = cst_encode_opt_String(apiObj.channelLayout);
+    wireObj.sample_format = cst_encode_opt_String(apiObj.sampleFormat);
+    wireObj.bits_per_sample = cst_encode_opt_box_autoadd_u_32(
+      apiObj.bitsPerSample,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_best_thumbnail(
+    BestThumbnail apiObj,
+    wire_cst_best_thumbnail wireObj,
+  ) {
+    wireObj.data = cst_encode_list_prim_u_8_strict(apiObj.data);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.time_ms = cst_encode_u_64(apiObj.timeMs);
+    wireObj.score = cst_encode_f_64(apiObj.score);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_best_thumbnail_params(
+    BestThumbnailParams apiObj,
+    wire_cst_best_thumbnail_params wireObj,
+  ) {
+    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
+      apiObj.sizeType,
+    );
+    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
+    wireObj.candidates = cst_encode_opt_box_autoadd_u_32(apiObj.candidates);
+    wireObj.resize_mode = cst_encode_opt_box_autoadd_resize_mode(
+      apiObj.resizeMode,
+    );
+    wireObj.allow_upscale = cst_encode_opt_box_autoadd_bool(
+      apiObj.allowUpscale,
+    );
+    wireObj.encoder_options = cst_encode_opt_box_autoadd_encoder_options(
+      apiObj.encoderOptions,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_compress_params(
+    CompressParams apiObj,
+    wire_cst_compress_params wireObj,
+  ) {
+    wireObj.target_bitrate_kbps = cst_encode_u_32(apiObj.targetBitrateKbps);
+    wireObj.preset = cst_encode_opt_String(apiObj.preset);
+    wireObj.crf = cst_encode_opt_box_autoadd_u_8(apiObj.crf);
+    wireObj.width = cst_encode_opt_box_autoadd_u_32(apiObj.width);
+    wireObj.height = cst_encode_opt_box_autoadd_u_32(apiObj.height);
+    wireObj.sample_duration_ms = cst_encode_opt_box_autoadd_u_64(
+      apiObj.sampleDurationMs,
+    );
+    wireObj.crop = cst_encode_opt_box_autoadd_crop_rect(apiObj.crop);
+    wireObj.aspect_ratio = cst_encode_opt_box_autoadd_record_u_32_u_32(
+      apiObj.aspectRatio,
+    );
+    wireObj.aspect_mode = cst_encode_opt_box_autoadd_aspect_mode(
+      apiObj.aspectMode,
+    );
+    wireObj.pad_color = cst_encode_opt_box_autoadd_u_32(apiObj.padColor);
+    wireObj.watermark = cst_encode_opt_box_autoadd_watermark_params(
+      apiObj.watermark,
+    );
+    wireObj.subtitles = cst_encode_opt_box_autoadd_subtitle_params(
+      apiObj.subtitles,
+    );
+    wireObj.speed = cst_encode_opt_box_autoadd_speed_params(apiObj.speed);
+    wireObj.normalize_loudness =
+        cst_encode_opt_box_autoadd_loudness_normalization(
+          apiObj.normalizeLoudness,
+        );
+    wireObj.video_filters = cst_encode_opt_String(apiObj.videoFilters);
+    wireObj.audio_filters = cst_encode_opt_String(apiObj.audioFilters);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_compress_progress(
+    CompressProgress apiObj,
+    wire_cst_compress_progress wireObj,
+  ) {
+    wireObj.processed_ms = cst_encode_u_64(apiObj.processedMs);
+    wireObj.total_ms = cst_encode_u_64(apiObj.totalMs);
+    wireObj.speed_x = cst_encode_f_32(apiObj.speedX);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_compression_estimate(
+    CompressionEstimate apiObj,
+    wire_cst_compression_estimate wireObj,
+  ) {
+    wireObj.estimated_size_bytes = cst_encode_u_64(apiObj.estimatedSizeBytes);
+    wireObj.estimated_duration_ms = cst_encode_u_64(apiObj.estimatedDurationMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_concat_params(
+    ConcatParams apiObj,
+    wire_cst_concat_params wireObj,
+  ) {
+    wireObj.width = cst_encode_opt_box_autoadd_u_32(apiObj.width);
+    wireObj.height = cst_encode_opt_box_autoadd_u_32(apiObj.height);
+    wireObj.fps = cst_encode_opt_box_autoadd_u_32(apiObj.fps);
+    wireObj.target_bitrate_kbps = cst_encode_opt_box_autoadd_u_32(
+      apiObj.targetBitrateKbps,
+    );
+    wireObj.preset = cst_encode_opt_String(apiObj.preset);
+    wireObj.crf = cst_encode_opt_box_autoadd_u_8(apiObj.crf);
+    wireObj.force_reencode = cst_encode_opt_box_autoadd_bool(
+      apiObj.forceReencode,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_crop_rect(
+    CropRect apiObj,
+    wire_cst_crop_rect wireObj,
+  ) {
+    wireObj.x = cst_encode_u_32(apiObj.x);
+    wireObj.y = cst_encode_u_32(apiObj.y);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ducking_params(
+    DuckingParams apiObj,
+    wire_cst_ducking_params wireObj,
+  ) {
+    wireObj.threshold_db = cst_encode_opt_box_autoadd_f_32(apiObj.thresholdDb);
+    wireObj.reduction_db = cst_encode_opt_box_autoadd_f_32(apiObj.reductionDb);
+    wireObj.attack_ms = cst_encode_opt_box_autoadd_u_32(apiObj.attackMs);
+    wireObj.release_ms = cst_encode_opt_box_autoadd_u_32(apiObj.releaseMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_encoder_options(
+    EncoderOptions apiObj,
+    wire_cst_encoder_options wireObj,
+  ) {
+    wireObj.jpeg_quality = cst_encode_opt_box_autoadd_u_8(apiObj.jpegQuality);
+    wireObj.jpeg_progressive = cst_encode_opt_box_autoadd_bool(
+      apiObj.jpegProgressive,
+    );
+    wireObj.webp_quality = cst_encode_opt_box_autoadd_f_32(apiObj.webpQuality);
+    wireObj.png_compression = cst_encode_opt_box_autoadd_u_8(
+      apiObj.pngCompression,
+    );
+    wireObj.avif_quality = cst_encode_opt_box_autoadd_u_8(apiObj.avifQuality);
+    wireObj.heic_quality = cst_encode_opt_box_autoadd_u_8(apiObj.heicQuality);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_extracted_frame(
+    ExtractedFrame apiObj,
+    wire_cst_extracted_frame wireObj,
+  ) {
+    wireObj.path = cst_encode_String(apiObj.path);
+    wireObj.index = cst_encode_u_32(apiObj.index);
+    wireObj.requested_ms = cst_encode_u_64(apiObj.requestedMs);
+    wireObj.time_ms = cst_encode_u_64(apiObj.timeMs);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_frame_extract_params(
+    FrameExtractParams apiObj,
+    wire_cst_frame_extract_params wireObj,
+  ) {
+    cst_api_fill_to_wire_frame_selection(apiObj.selection, wireObj.selection);
+    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
+      apiObj.sizeType,
+    );
+    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
+    wireObj.seek_mode = cst_encode_opt_box_autoadd_seek_mode(apiObj.seekMode);
+    wireObj.resize_mode = cst_encode_opt_box_autoadd_resize_mode(
+      apiObj.resizeMode,
+    );
+    wireObj.allow_upscale = cst_encode_opt_box_autoadd_bool(
+      apiObj.allowUpscale,
+    );
+    wireObj.encoder_options = cst_encode_opt_box_autoadd_encoder_options(
+      apiObj.encoderOptions,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_frame_selection(
+    FrameSelection apiObj,
+    wire_cst_frame_selection wireObj,
+  ) {
+    if (apiObj is FrameSelection_Timestamps) {
+      var pre_field0 = cst_encode_list_prim_u_64_strict(apiObj.field0);
+      wireObj.tag = 0;
+      wireObj.kind.Timestamps.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is FrameSelection_Interval) {
+      var pre_field0 = cst_encode_u_64(apiObj.field0);
+      wireObj.tag = 1;
+      wireObj.kind.Interval.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is FrameSelection_Count) {
+      var pre_field0 = cst_encode_u_32(apiObj.field0);
+      wireObj.tag = 2;
+      wireObj.kind.Count.field0 = pre_field0;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_gps_location(
+    GpsLocation apiObj,
+    wire_cst_gps_location wireObj,
+  ) {
+    wireObj.latitude = cst_encode_f_64(apiObj.latitude);
+    wireObj.longitude = cst_encode_f_64(apiObj.longitude);
+    wireObj.altitude = cst_encode_opt_box_autoadd_f_64(apiObj.altitude);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_hash_comparison(
+    HashComparison apiObj,
+    wire_cst_hash_comparison wireObj,
+  ) {
+    wireObj.distance = cst_encode_u_32(apiObj.distance);
+    wireObj.similarity = cst_encode_f_64(apiObj.similarity);
+    wireObj.is_similar = cst_encode_bool(apiObj.isSimilar);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_image_info(
+    ImageInfo apiObj,
+    wire_cst_image_info wireObj,
+  ) {
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.format = cst_encode_String(apiObj.format);
+    wireObj.bit_depth = cst_encode_u_32(apiObj.bitDepth);
+    wireObj.has_alpha = cst_encode_bool(apiObj.hasAlpha);
+    wireObj.orientation = cst_encode_u_32(apiObj.orientation);
+    wireObj.camera_make = cst_encode_opt_String(apiObj.cameraMake);
+    wireObj.camera_model = cst_encode_opt_String(apiObj.cameraModel);
+    wireObj.capture_date = cst_encode_opt_String(apiObj.captureDate);
+    wireObj.gps = cst_encode_opt_box_autoadd_gps_location(apiObj.gps);
+    wireObj.icc_profile_name = cst_encode_opt_String(apiObj.iccProfileName);
+    wireObj.image_count = cst_encode_u_32(apiObj.imageCount);
+    wireObj.auxiliary_image_count = cst_encode_u_32(apiObj.auxiliaryImageCount);
+    wireObj.has_depth = cst_encode_bool(apiObj.hasDepth);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_image_thumbnail_params(
+    ImageThumbnailParams apiObj,
+    wire_cst_image_thumbnail_params wireObj,
+  ) {
+    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
+      apiObj.sizeType,
+    );
+    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
+    wireObj.seek_mode = cst_encode_opt_box_autoadd_seek_mode(apiObj.seekMode);
+    wireObj.resize_mode = cst_encode_opt_box_autoadd_resize_mode(
+      apiObj.resizeMode,
+    );
+    wireObj.allow_upscale = cst_encode_opt_box_autoadd_bool(
+      apiObj.allowUpscale,
+    );
+    wireObj.encoder_options = cst_encode_opt_box_autoadd_encoder_options(
+      apiObj.encoderOptions,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_loudness_info(
+    LoudnessInfo apiObj,
+    wire_cst_loudness_info wireObj,
+  ) {
+    wireObj.integrated_lufs = cst_encode_f_64(apiObj.integratedLufs);
+    wireObj.loudness_range_lu = cst_encode_f_64(apiObj.loudnessRangeLu);
+    wireObj.true_peak_dbtp = cst_encode_f_64(apiObj.truePeakDbtp);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_loudness_normalization(
+    LoudnessNormalization apiObj,
+    wire_cst_loudness_normalization wireObj,
+  ) {
+    wireObj.target_lufs = cst_encode_f_32(apiObj.targetLufs);
+    wireObj.true_peak_dbtp = cst_encode_opt_box_autoadd_f_32(
+      apiObj.truePeakDbtp,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_media_chapter(
+    MediaChapter apiObj,
+    wire_cst_media_chapter wireObj,
+  ) {
+    wireObj.id = cst_encode_i_64(apiObj.id);
+    wireObj.start_ms = cst_encode_u_64(apiObj.startMs);
+    wireObj.end_ms = cst_encode_u_64(apiObj.endMs);
+    wireObj.title = cst_encode_opt_String(apiObj.title);
+    wireObj.tags = cst_encode_list_media_tag(apiObj.tags);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_media_hash(
+    MediaHash apiObj,
+    wire_cst_media_hash wireObj,
+  ) {
+    wireObj.algorithm = cst_encode_hash_algorithm(apiObj.algorithm);
+    wireObj.hashes = cst_encode_list_prim_u_64_strict(apiObj.hashes);
+    wireObj.times_ms = cst_encode_list_prim_u_64_strict(apiObj.timesMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_media_probe(
+    MediaProbe apiObj,
+    wire_cst_media_probe wireObj,
+  ) {
+    wireObj.format_name = cst_encode_String(apiObj.formatName);
+    wireObj.format_long_name = cst_encode_opt_String(apiObj.formatLongName);
+    wireObj.duration_ms = cst_encode_opt_box_autoadd_u_64(apiObj.durationMs);
+    wireObj.start_time_ms = cst_encode_opt_box_autoadd_i_64(apiObj.startTimeMs);
+    wireObj.bitrate = cst_encode_opt_box_autoadd_u_64(apiObj.bitrate);
+    wireObj.size_bytes = cst_encode_u_64(apiObj.sizeBytes);
+    wireObj.tags = cst_encode_list_media_tag(apiObj.tags);
+    wireObj.chapters = cst_encode_list_media_chapter(apiObj.chapters);
+    wireObj.streams = cst_encode_list_stream_probe(apiObj.streams);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_media_tag(
+    MediaTag apiObj,
+    wire_cst_media_tag wireObj,
+  ) {
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_String(apiObj.value);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_palette(Palette apiObj, wire_cst_palette wireObj) {
+    wireObj.colors = cst_encode_list_palette_color(apiObj.colors);
+    cst_api_fill_to_wire_palette_color(apiObj.average, wireObj.average);
+    wireObj.vibrant = cst_encode_opt_box_autoadd_palette_color(apiObj.vibrant);
+    wireObj.light_vibrant = cst_encode_opt_box_autoadd_palette_color(
+      apiObj.lightVibrant,
+    );
+    wireObj.dark_vibrant = cst_encode_opt_box_autoadd_palette_color(
+      apiObj.darkVibrant,
+    );
+    wireObj.muted = cst_encode_opt_box_autoadd_palette_color(apiObj.muted);
+    wireObj.light_muted = cst_encode_opt_box_autoadd_palette_color(
+      apiObj.lightMuted,
+    );
+    wireObj.dark_muted = cst_encode_opt_box_autoadd_palette_color(
+      apiObj.darkMuted,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_palette_color(
+    PaletteColor apiObj,
+    wire_cst_palette_color wireObj,
+  ) {
+    wireObj.color = cst_encode_u_32(apiObj.color);
+    wireObj.population = cst_encode_f_64(apiObj.population);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_placeholder_image(
+    PlaceholderImage apiObj,
+    wire_cst_placeholder_image wireObj,
+  ) {
+    wireObj.rgba = cst_encode_list_prim_u_8_strict(apiObj.rgba);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_record_u_32_u_32(
+    (int, int) apiObj,
+    wire_cst_record_u_32_u_32 wireObj,
+  ) {
+    wireObj.field0 = cst_encode_u_32(apiObj.$1);
+    wireObj.field1 = cst_encode_u_32(apiObj.$2);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_resolution_preset(
+    ResolutionPreset apiObj,
+    wire_cst_resolution_preset wireObj,
+  ) {
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.bitrate = cst_encode_u_64(apiObj.bitrate);
+    wireObj.crf = cst_encode_u_8(apiObj.crf);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_reverse_params(
+    ReverseParams apiObj,
+    wire_cst_reverse_params wireObj,
+  ) {
+    wireObj.start_ms = cst_encode_opt_box_autoadd_u_64(apiObj.startMs);
+    wireObj.end_ms = cst_encode_opt_box_autoadd_u_64(apiObj.endMs);
+    wireObj.boomerang_loops = cst_encode_opt_box_autoadd_u_32(
+      apiObj.boomerangLoops,
+    );
+    wireObj.audio = cst_encode_opt_box_autoadd_reverse_audio_mode(apiObj.audio);
+    wireObj.width = cst_encode_opt_box_autoadd_u_32(apiObj.width);
+    wireObj.height = cst_encode_opt_box_autoadd_u_32(apiObj.height);
+    wireObj.target_bitrate_kbps = cst_encode_opt_box_autoadd_u_32(
+      apiObj.targetBitrateKbps,
+    );
+    wireObj.preset = cst_encode_opt_String(apiObj.preset);
+    wireObj.crf = cst_encode_opt_box_autoadd_u_8(apiObj.crf);
+    wireObj.memory_budget_mb = cst_encode_opt_box_autoadd_u_32(
+      apiObj.memoryBudgetMb,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_scene_cut(
+    SceneCut apiObj,
+    wire_cst_scene_cut wireObj,
+  ) {
+    wireObj.time_ms = cst_encode_u_64(apiObj.timeMs);
+    wireObj.score = cst_encode_f_64(apiObj.score);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_scene_detection_event(
+    SceneDetectionEvent apiObj,
+    wire_cst_scene_detection_event wireObj,
+  ) {
+    if (apiObj is SceneDetectionEvent_Progress) {
+      var pre_field0 = cst_encode_box_autoadd_compress_progress(apiObj.field0);
+      wireObj.tag = 0;
+      wireObj.kind.Progress.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is SceneDetectionEvent_Cuts) {
+      var pre_field0 = cst_encode_list_scene_cut(apiObj.field0);
+      wireObj.tag = 1;
+      wireObj.kind.Cuts.field0 = pre_field0;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_speed_params(
+    SpeedParams apiObj,
+    wire_cst_speed_params wireObj,
+  ) {
+    wireObj.factor = cst_encode_f_32(apiObj.factor);
+    wireObj.frame_rate = cst_encode_opt_box_autoadd_u_32(apiObj.frameRate);
+    wireObj.mute_audio_above = cst_encode_opt_box_autoadd_f_32(
+      apiObj.muteAudioAbove,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_storyboard(
+    Storyboard apiObj,
+    wire_cst_storyboard wireObj,
+  ) {
+    wireObj.vtt_path = cst_encode_String(apiObj.vttPath);
+    wireObj.sprite_paths = cst_encode_list_String(apiObj.spritePaths);
+    wireObj.tile_count = cst_encode_u_32(apiObj.tileCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_stream_disposition(
+    StreamDisposition apiObj,
+    wire_cst_stream_disposition wireObj,
+  ) {
+    wireObj.default1 = cst_encode_bool(apiObj.default_);
+    wireObj.dub = cst_encode_bool(apiObj.dub);
+    wireObj.original = cst_encode_bool(apiObj.original);
+    wireObj.comment = cst_encode_bool(apiObj.comment);
+    wireObj.lyrics = cst_encode_bool(apiObj.lyrics);
+    wireObj.karaoke = cst_encode_bool(apiObj.karaoke);
+    wireObj.forced = cst_encode_bool(apiObj.forced);
+    wireObj.hearing_impaired = cst_encode_bool(apiObj.hearingImpaired);
+    wireObj.visual_impaired = cst_encode_bool(apiObj.visualImpaired);
+    wireObj.clean_effects = cst_encode_bool(apiObj.cleanEffects);
+    wireObj.attached_pic = cst_encode_bool(apiObj.attachedPic);
+    wireObj.captions = cst_encode_bool(apiObj.captions);
+    wireObj.descriptions = cst_encode_bool(apiObj.descriptions);
+    wireObj.metadata = cst_encode_bool(apiObj.metadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_stream_probe(
+    StreamProbe apiObj,
+    wire_cst_stream_probe wireObj,
+  ) {
+    wireObj.index = cst_encode_u_32(apiObj.index);
+    wireObj.stream_type = cst_encode_stream_type(apiObj.streamType);
+    wireObj.codec_name = cst_encode_opt_String(apiObj.codecName);
+    wireObj.codec_long_name = cst_encode_opt_String(apiObj.codecLongName);
+    wireObj.profile = cst_encode_opt_String(apiObj.profile);
+    wireObj.bitrate = cst_encode_opt_box_autoadd_u_64(apiObj.bitrate);
+    wireObj.start_time_ms = cst_encode_opt_box_autoadd_i_64(apiObj.startTimeMs);
+    wireObj.duration_ms = cst_encode_opt_box_autoadd_u_64(apiObj.durationMs);
+    wireObj.frame_count = cst_encode_opt_box_autoadd_u_64(apiObj.frameCount);
+    wireObj.language = cst_encode_opt_String(apiObj.language);
+    wireObj.title = cst_encode_opt_String(apiObj.title);
+    cst_api_fill_to_wire_stream_disposition(
+      apiObj.disposition,
+      wireObj.disposition,
+    );
+    wireObj.tags = cst_encode_list_media_tag(apiObj.tags);
+    wireObj.side_data = cst_encode_list_stream_side_data(apiObj.sideData);
+    wireObj.video = cst_encode_opt_box_autoadd_video_stream_probe(apiObj.video);
+    wireObj.audio = cst_encode_opt_box_autoadd_audio_stream_probe(apiObj.audio);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_stream_side_data(
+    StreamSideData apiObj,
+    wire_cst_stream_side_data wireObj,
+  ) {
+    wireObj.kind = cst_encode_String(apiObj.kind);
+    wireObj.size_bytes = cst_encode_u_32(apiObj.sizeBytes);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_subtitle_params(
+    SubtitleParams apiObj,
+    wire_cst_subtitle_params wireObj,
+  ) {
+    wireObj.subtitle_path = cst_encode_String(apiObj.subtitlePath);
+    wireObj.font_path = cst_encode_opt_String(apiObj.fontPath);
+    wireObj.font_size = cst_encode_opt_box_autoadd_u_32(apiObj.fontSize);
+    wireObj.color = cst_encode_opt_box_autoadd_u_32(apiObj.color);
+    wireObj.outline_color = cst_encode_opt_box_autoadd_u_32(
+      apiObj.outlineColor,
+    );
+    wireObj.outline_width = cst_encode_opt_box_autoadd_u_32(
+      apiObj.outlineWidth,
+    );
+    wireObj.bottom_margin = cst_encode_opt_box_autoadd_u_32(
+      apiObj.bottomMargin,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_thumbnail_data(
+    ThumbnailData apiObj,
+    wire_cst_thumbnail_data wireObj,
+  ) {
+    wireObj.data = cst_encode_list_prim_u_8_strict(apiObj.data);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.time_ms = cst_encode_opt_box_autoadd_u_64(apiObj.timeMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_thumbnail_size_type(
+    ThumbnailSizeType apiObj,
+    wire_cst_thumbnail_size_type wireObj,
+  ) {
+    if (apiObj is ThumbnailSizeType_Icon) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is ThumbnailSizeType_Small) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is ThumbnailSizeType_Medium) {
+      wireObj.tag = 2;
+      return;
+    }
+    if (apiObj is ThumbnailSizeType_Large) {
+      wireObj.tag = 3;
+      return;
+    }
+    if (apiObj is ThumbnailSizeType_Larger) {
+      wireObj.tag = 4;
+      return;
+    }
+    if (apiObj is ThumbnailSizeType_Custom) {
+      var pre_field0 = cst_encode_box_autoadd_record_u_32_u_32(apiObj.field0);
+      wireObj.tag = 5;
+      wireObj.kind.Custom.field0 = pre_field0;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_video_info(
+    VideoInfo apiObj,
+    wire_cst_video_info wireObj,
+  ) {
+    wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs);
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.size_bytes = cst_encode_u_64(apiObj.sizeBytes);
+    wireObj.bitrate = cst_encode_opt_box_autoadd_u_64(apiObj.bitrate);
+    wireObj.codec_name = cst_encode_opt_String(apiObj.codecName);
+    wireObj.format_name = cst_encode_opt_String(apiObj.formatName);
+    wireObj.stored_width = cst_encode_u_32(apiObj.storedWidth);
+    wireObj.stored_height = cst_encode_u_32(apiObj.storedHeight);
+    wireObj.rotation = cst_encode_i_32(apiObj.rotation);
+    wireObj.avg_frame_rate = cst_encode_opt_box_autoadd_f_64(
+      apiObj.avgFrameRate,
+    );
+    wireObj.real_frame_rate = cst_encode_opt_box_autoadd_f_64(
+      apiObj.realFrameRate,
+    );
+    wireObj.is_vfr = cst_encode_bool(apiObj.isVfr);
+    wireObj.pixel_format = cst_encode_opt_String(apiObj.pixelFormat);
+    wireObj.bit_depth = cst_encode_opt_box_autoadd_u_32(apiObj.bitDepth);
+    wireObj.color_primaries = cst_encode_opt_String(apiObj.colorPrimaries);
+    wireObj.color_transfer = cst_encode_opt_String(apiObj.colorTransfer);
+    wireObj.color_matrix = cst_encode_opt_String(apiObj.colorMatrix);
+    wireObj.color_range = cst_encode_opt_String(apiObj.colorRange);
+    wireObj.hdr_format = cst_encode_opt_box_autoadd_hdr_format(
+      apiObj.hdrFormat,
+    );
+    wireObj.profile = cst_encode_opt_String(apiObj.profile);
+    wireObj.level = cst_encode_opt_box_autoadd_i_32(apiObj.level);
+    wireObj.frame_count = cst_encode_opt_box_autoadd_u_64(apiObj.frameCount);
+    wireObj.audio = cst_encode_opt_box_autoadd_audio_stream_info(apiObj.audio);
+    wireObj.suggestions = cst_encode_list_resolution_preset(apiObj.suggestions);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_video_stream_probe(
+    VideoStreamProbe apiObj,
+    wire_cst_video_stream_probe wireObj,
+  ) {
+    wireObj.width = cst_encode_u_32(apiObj.width);
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.display_width = cst_encode_u_32(apiObj.displayWidth);
+    wireObj.display_height = cst_encode_u_32(apiObj.displayHeight);
+    wireObj.rotation = cst_encode_i_32(apiObj.rotation);
+    wireObj.avg_frame_rate = cst_encode_opt_box_autoadd_f_64(
+      apiObj.avgFrameRate,
+    );
+    wireObj.real_frame_rate = cst_encode_opt_box_autoadd_f_64(
+      apiObj.realFrameRate,
+    );
+    wireObj.is_vfr = cst_encode_bool(apiObj.isVfr);
+    wireObj.pixel_format = cst_encode_opt_String(apiObj.pixelFormat);
+    wireObj.bit_depth = cst_encode_opt_box_autoadd_u_32(apiObj.bitDepth);
+    wireObj.color_primaries = cst_encode_opt_String(apiObj.colorPrimaries);
+    wireObj.color_transfer = cst_encode_opt_String(apiObj.colorTransfer);
+    wireObj.color_matrix = cst_encode_opt_String(apiObj.colorMatrix);
+    wireObj.color_range = cst_encode_opt_String(apiObj.colorRange);
+    wireObj.hdr_format = cst_encode_opt_box_autoadd_hdr_format(
+      apiObj.hdrFormat,
+    );
+    wireObj.level = cst_encode_opt_box_autoadd_i_32(apiObj.level);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_video_thumbnail_params(
+    VideoThumbnailParams apiObj,
+    wire_cst_video_thumbnail_params wireObj,
+  ) {
+    wireObj.time_ms = cst_encode_u_64(apiObj.timeMs);
+    wireObj.size_type = cst_encode_opt_box_autoadd_thumbnail_size_type(
+      apiObj.sizeType,
+    );
+    wireObj.format = cst_encode_opt_box_autoadd_output_format(apiObj.format);
+    wireObj.seek_mode = cst_encode_opt_box_autoadd_seek_mode(apiObj.seekMode);
+    wireObj.resize_mode = cst_encode_opt_box_autoadd_resize_mode(
+      apiObj.resizeMode,
+    );
+    wireObj.allow_upscale = cst_encode_opt_box_autoadd_bool(
+      apiObj.allowUpscale,
+    );
+    wireObj.encoder_options = cst_encode_opt_box_autoadd_encoder_options(
+      apiObj.encoderOptions,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_watermark_params(
+    WatermarkParams apiObj,
+    wire_cst_watermark_params wireObj,
+  ) {
+    wireObj.image_path = cst_encode_String(apiObj.imagePath);
+    wireObj.position = cst_encode_opt_box_autoadd_watermark_position(
+      apiObj.position,
+    );
+    wireObj.margin = cst_encode_opt_box_autoadd_u_32(apiObj.margin);
+    wireObj.scale = cst_encode_opt_box_autoadd_f_32(apiObj.scale);
+    wireObj.opacity = cst_encode_opt_box_autoadd_f_32(apiObj.opacity);
+    wireObj.start_ms = cst_encode_opt_box_autoadd_u_64(apiObj.startMs);
+    wireObj.end_ms = cst_encode_opt_box_autoadd_u_64(apiObj.endMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_write_to_files(
+    WriteToFiles apiObj,
+    wire_cst_write_to_files wireObj,
+  ) {
+    wireObj.path = cst_encode_String(apiObj.path);
+    wireObj.file_prefix = cst_encode_String(apiObj.filePrefix);
+    wireObj.file_suffix = cst_encode_opt_String(apiObj.fileSuffix);
+    wireObj.max_files = cst_encode_opt_box_autoadd_u_64(apiObj.maxFiles);
+  }
+
+  @protected
+  int cst_encode_animation_format(AnimationFormat raw);
+
+  @protected
+  int cst_encode_aspect_mode(AspectMode raw);
+
+  @protected
+  int cst_encode_audio_channels(AudioChannels raw);
+
+  @protected
+  int cst_encode_audio_format(AudioFormat raw);
+
+  @protected
+  int cst_encode_audio_track_mode(AudioTrackMode raw);
+
+  @protected
+  bool cst_encode_bool(bool raw);
+
+  @protected
+  double cst_encode_f_32(double raw);
+
+  @protected
+  double cst_encode_f_64(double raw);
+
+  @protected
+  int cst_encode_hash_algorithm(HashAlgorithm raw);
+
+  @protected
+  int cst_encode_hdr_format(HdrFormat raw);
+
+  @protected
+  int cst_encode_i_32(int raw);
+
+  @protected
+  int cst_encode_log_level(LogLevel raw);
+
+  @protected
+  int cst_encode_output_format(OutputFormat raw);
+
+  @protected
+  int cst_encode_resize_mode(ResizeMode raw);
+
+  @protected
+  int cst_encode_reverse_audio_mode(ReverseAudioMode raw);
+
+  @protected
+  int cst_encode_seek_mode(SeekMode raw);
+
+  @protected
+  int cst_encode_stream_type(StreamType raw);
+
+  @protected
+  int cst_encode_u_16(int raw);
+
+  @protected
+  int cst_encode_u_32(int raw);
+
+  @protected
+  int cst_encode_u_8(int raw);
+
+  @protected
+  void cst_encode_unit(void raw);
+
+  @protected
+  int cst_encode_watermark_position(WatermarkPosition raw);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_String_Dco(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_compress_progress_Dco(
+    RustStreamSink<CompressProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_extracted_frame_Dco(
+    RustStreamSink<ExtractedFrame> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_scene_detection_event_Dco(
+    RustStreamSink<SceneDetectionEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_thumbnail_data_Dco(
+    RustStreamSink<ThumbnailData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_animation_format(
+    AnimationFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_aspect_mode(AspectMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_channels(AudioChannels self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_format(AudioFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_best_thumbnail(BestThumbnail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_aspect_mode(
+    AspectMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_channels(
+    AudioChannels self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_concat_params(
+    ConcatParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_crop_rect(
+    CropRect self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_ducking_params(
+    DuckingParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_gps_location(
+    GpsLocation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hdr_format(
+    HdrFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_media_hash(
+    MediaHash self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_output_format(
+    OutputFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_palette_color(
+    PaletteColor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_record_u_32_u_32(
+    (int, int) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_resize_mode(
+    ResizeMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reverse_params(
+    ReverseParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_seek_mode(
+    SeekMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_speed_params(
+    SpeedParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_video_thumbnail_params(
+    VideoThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_write_to_files(
+    WriteToFiles self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compression_estimate(
+    CompressionEstimate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_concat_params(ConcatParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_crop_rect(CropRect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ducking_params(DuckingParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_extracted_frame(
+    ExtractedFrame self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frame_selection(
+    FrameSelection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_gps_location(GpsLocation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hash_comparison(
+    HashComparison self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hdr_format(HdrFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_info(ImageInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_media_chapter(
+    List<MediaChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_tag(List<MediaTag> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_palette_color(
+    List<PaletteColor> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_resolution_preset(
+    List<ResolutionPreset> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_scene_cut(List<SceneCut> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_stream_probe(
+    List<StreamProbe> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_stream_side_data(
+    List<StreamSideData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_loudness_info(LoudnessInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_media_chapter(MediaChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_hash(MediaHash self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_probe(MediaProbe self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_tag(MediaTag self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_aspect_mode(
+    AspectMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_audio_channels(
+    AudioChannels? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_audio_stream_info(
+    AudioStreamInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_audio_stream_probe(
+    AudioStreamProbe? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_audio_track_mode(
+    AudioTrackMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_crop_rect(
+    CropRect? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_ducking_params(
+    DuckingParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_encoder_options(
+    EncoderOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_gps_location(
+    GpsLocation? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hdr_format(
+    HdrFormat? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_loudness_normalization(
+    LoudnessNormalization? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_output_format(
+    OutputFormat? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_palette_color(
+    PaletteColor? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_record_u_32_u_32(
+    (int, int)? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_resize_mode(
+    ResizeMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_seek_mode(
+    SeekMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_speed_params(
+    SpeedParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_subtitle_params(
+    SubtitleParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_video_stream_probe(
+    VideoStreamProbe? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_params(
+    WatermarkParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_position(
+    WatermarkPosition? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_write_to_files(
+    WriteToFiles? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_output_format(OutputFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_palette(Palette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_palette_color(PaletteColor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_placeholder_image(
+    PlaceholderImage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resize_mode(ResizeMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resolution_preset(
+    ResolutionPreset self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reverse_params(ReverseParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scene_cut(SceneCut self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scene_detection_event(
+    SceneDetectionEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_seek_mode(SeekMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_speed_params(SpeedParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_storyboard(Storyboard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_disposition(
+    StreamDisposition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stream_probe(StreamProbe self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_side_data(
+    StreamSideData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stream_type(StreamType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_data(ThumbnailData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_thumbnail_size_type(
+    ThumbnailSizeType self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_info(VideoInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_video_thumbnail_params(
+    VideoThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_write_to_files(WriteToFiles self, SseSerializer serializer);
+}
+
+// Section: wire_class
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint, unused_import
+
+/// generated by flutter_rust_bridge
+class RustLibWire implements BaseWire {
+  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+      RustLibWire(lib.ffiDynamicLibrary);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+  _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+    : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustLibWire.fromLookup(
+    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
+  ) : _lookup = lookup;
+
+  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
+    return _store_dart_post_cobject(ptr);
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+        'store_dart_post_cobject',
+      );
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  void wire__crate__api__media__add_music(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> video_path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> music_path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_audio_mix_params> params,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__add_music(
+      port_,
+      video_path,
+      music_path,
+      output_path,
+      params,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__add_musicPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_audio_mix_params>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__add_music');
+  late final _wire__crate__api__media__add_music =
+      _wire__crate__api__media__add_musicPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_audio_mix_params>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__analyze_loudness(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__media__analyze_loudness(port_, path);
+  }
+
+  late final _wire__crate__api__media__analyze_loudnessPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__analyze_loudness');
+  late final _wire__crate__api__media__analyze_loudness =
+      _wire__crate__api__media__analyze_loudnessPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__media__animation_format_extension(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__api__media__animation_format_extension(port_, that);
+  }
+
+  late final _wire__crate__api__media__animation_format_extensionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+        'frbgen_media_wire__crate__api__media__animation_format_extension',
+      );
+  late final _wire__crate__api__media__animation_format_extension =
+      _wire__crate__api__media__animation_format_extensionPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__api__media__audio_format_extension(int port_, int that) {
+    return _wire__crate__api__media__audio_format_extension(port_, that);
+  }
+
+  late final _wire__crate__api__media__audio_format_extensionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+        'frbgen_media_wire__crate__api__media__audio_format_extension',
+      );
+  late final _wire__crate__api__media__audio_format_extension =
+      _wire__crate__api__media__audio_format_extensionPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__api__media__compare_media_hashes(
+    int port_,
+    ffi.Pointer<wire_cst_media_hash> a,
+    ffi.Pointer<wire_cst_media_hash> b,
+    ffi.Pointer<ffi.Uint32> threshold,
+  ) {
+    return _wire__crate__api__media__compare_media_hashes(
+      port_,
+      a,
+      b,
+      threshold,
+    );
+  }
+
+  late final _wire__crate__api__media__compare_media_hashesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_media_hash>,
+            ffi.Pointer<wire_cst_media_hash>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__compare_media_hashes');
+  late final _wire__crate__api__media__compare_media_hashes =
+      _wire__crate__api__media__compare_media_hashesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_media_hash>,
+              ffi.Pointer<wire_cst_media_hash>,
+              ffi.Pointer<ffi.Uint32>,
+            )
+          >();
+
+  void wire__crate__api__media__compress_video(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_compress_params> params,
+  ) {
+    return _wire__crate__api__media__compress_video(
+      port_,
+      path,
+      output_path,
+      params,
+    );
+  }
+
+  late final _wire__crate__api__media__compress_videoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_compress_params>,
+          )
+        >
       >('frbgen_media_wire__crate__api__media__compress_video');
   late final _wire__crate__api__media__compress_video =
       _wire__crate__api__media__compress_videoPtr
           .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_compress_params>,
-            )
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_compress_params>,
+            )
+          >();
+
+  void wire__crate__api__media__compute_blurhash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    int components_x,
+    int components_y,
+    ffi.Pointer<ffi.Uint64> time_ms,
+  ) {
+    return _wire__crate__api__media__compute_blurhash(
+      port_,
+      path,
+      components_x,
+      components_y,
+      time_ms,
+    );
+  }
+
+  late final _wire__crate__api__media__compute_blurhashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Pointer<ffi.Uint64>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__compute_blurhash');
+  late final _wire__crate__api__media__compute_blurhash =
+      _wire__crate__api__media__compute_blurhashPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              int,
+              ffi.Pointer<ffi.Uint64>,
+            )
+          >();
+
+  void wire__crate__api__media__compute_image_hash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<ffi.Int32> algorithm,
+  ) {
+    return _wire__crate__api__media__compute_image_hash(port_, path, algorithm);
+  }
+
+  late final _wire__crate__api__media__compute_image_hashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Int32>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__compute_image_hash');
+  late final _wire__crate__api__media__compute_image_hash =
+      _wire__crate__api__media__compute_image_hashPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Int32>,
+            )
+          >();
+
+  void wire__crate__api__media__compute_thumbhash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<ffi.Uint64> time_ms,
+  ) {
+    return _wire__crate__api__media__compute_thumbhash(port_, path, time_ms);
+  }
+
+  late final _wire__crate__api__media__compute_thumbhashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint64>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__compute_thumbhash');
+  late final _wire__crate__api__media__compute_thumbhash =
+      _wire__crate__api__media__compute_thumbhashPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint64>,
+            )
+          >();
+
+  void wire__crate__api__media__compute_video_hash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<ffi.Int32> algorithm,
+    ffi.Pointer<ffi.Uint32> frame_count,
+  ) {
+    return _wire__crate__api__media__compute_video_hash(
+      port_,
+      path,
+      algorithm,
+      frame_count,
+    );
+  }
+
+  late final _wire__crate__api__media__compute_video_hashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Int32>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__compute_video_hash');
+  late final _wire__crate__api__media__compute_video_hash =
+      _wire__crate__api__media__compute_video_hashPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Int32>,
+              ffi.Pointer<ffi.Uint32>,
+            )
+          >();
+
+  void wire__crate__api__media__concat_videos(
+    int port_,
+    ffi.Pointer<wire_cst_list_String> inputs,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_concat_params> params,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__concat_videos(
+      port_,
+      inputs,
+      output_path,
+      params,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__concat_videosPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_concat_params>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__concat_videos');
+  late final _wire__crate__api__media__concat_videos =
+      _wire__crate__api__media__concat_videosPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_concat_params>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__logger__debug_threads(int port_) {
+    return _wire__crate__api__logger__debug_threads(port_);
+  }
+
+  late final _wire__crate__api__logger__debug_threadsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_media_wire__crate__api__logger__debug_threads',
+      );
+  late final _wire__crate__api__logger__debug_threads =
+      _wire__crate__api__logger__debug_threadsPtr
+          .asFunction<void Function(int)>();
+
+  void wire__crate__api__media__decode_blurhash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> hash,
+    int width,
+    int height,
+    ffi.Pointer<ffi.Float> punch,
+  ) {
+    return _wire__crate__api__media__decode_blurhash(
+      port_,
+      hash,
+      width,
+      height,
+      punch,
+    );
+  }
+
+  late final _wire__crate__api__media__decode_blurhashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Pointer<ffi.Float>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__decode_blurhash');
+  late final _wire__crate__api__media__decode_blurhash =
+      _wire__crate__api__media__decode_blurhashPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              int,
+              ffi.Pointer<ffi.Float>,
+            )
+          >();
+
+  void wire__crate__api__media__decode_thumbhash(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> hash,
+  ) {
+    return _wire__crate__api__media__decode_thumbhash(port_, hash);
+  }
+
+  late final _wire__crate__api__media__decode_thumbhashPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__decode_thumbhash');
+  late final _wire__crate__api__media__decode_thumbhash =
+      _wire__crate__api__media__decode_thumbhashPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_loose>)
+          >();
+
+  void wire__crate__api__media__detect_scenes(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    double threshold,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__detect_scenes(
+      port_,
+      path,
+      threshold,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__detect_scenesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Double,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__detect_scenes');
+  late final _wire__crate__api__media__detect_scenes =
+      _wire__crate__api__media__detect_scenesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__estimate_compression(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> temp_output_path,
+    ffi.Pointer<wire_cst_compress_params> params,
+  ) {
+    return _wire__crate__api__media__estimate_compression(
+      port_,
+      path,
+      temp_output_path,
+      params,
+    );
+  }
+
+  late final _wire__crate__api__media__estimate_compressionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_compress_params>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__estimate_compression');
+  late final _wire__crate__api__media__estimate_compression =
+      _wire__crate__api__media__estimate_compressionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_compress_params>,
+            )
+          >();
+
+  void wire__crate__api__media__export_animation(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    int start_ms,
+    int duration_ms,
+    int fps,
+    int max_width,
+    int format,
+    ffi.Pointer<wire_cst_animation_options> options,
+  ) {
+    return _wire__crate__api__media__export_animation(
+      port_,
+      path,
+      output_path,
+      start_ms,
+      duration_ms,
+      fps,
+      max_width,
+      format,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__media__export_animationPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint64,
+            ffi.Uint64,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Int32,
+            ffi.Pointer<wire_cst_animation_options>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__export_animation');
+  late final _wire__crate__api__media__export_animation =
+      _wire__crate__api__media__export_animationPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              int,
+              int,
+              int,
+              int,
+              ffi.Pointer<wire_cst_animation_options>,
+            )
+          >();
+
+  void wire__crate__api__media__extract_audio(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    int format,
+    ffi.Pointer<wire_cst_audio_extract_options> options,
+  ) {
+    return _wire__crate__api__media__extract_audio(
+      port_,
+      path,
+      output_path,
+      format,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__media__extract_audioPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Int32,
+            ffi.Pointer<wire_cst_audio_extract_options>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__extract_audio');
+  late final _wire__crate__api__media__extract_audio =
+      _wire__crate__api__media__extract_audioPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              ffi.Pointer<wire_cst_audio_extract_options>,
+            )
+          >();
+
+  void wire__crate__api__media__extract_frames(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_frame_extract_params> params,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__extract_frames(
+      port_,
+      path,
+      output_path,
+      params,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__extract_framesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_frame_extract_params>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__extract_frames');
+  late final _wire__crate__api__media__extract_frames =
+      _wire__crate__api__media__extract_framesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_frame_extract_params>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__extract_palette(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    int count,
+    ffi.Pointer<ffi.Uint64> time_ms,
+  ) {
+    return _wire__crate__api__media__extract_palette(
+      port_,
+      path,
+      count,
+      time_ms,
+    );
+  }
+
+  late final _wire__crate__api__media__extract_palettePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+            ffi.Pointer<ffi.Uint64>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__extract_palette');
+  late final _wire__crate__api__media__extract_palette =
+      _wire__crate__api__media__extract_palettePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              ffi.Pointer<ffi.Uint64>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_best_thumbnail(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_best_thumbnail_params> params,
+  ) {
+    return _wire__crate__api__media__generate_best_thumbnail(
+      port_,
+      path,
+      params,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_best_thumbnailPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_best_thumbnail_params>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_best_thumbnail');
+  late final _wire__crate__api__media__generate_best_thumbnail =
+      _wire__crate__api__media__generate_best_thumbnailPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_best_thumbnail_params>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_image_thumbnail(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_image_thumbnail_params> params,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> suffix,
+  ) {
+    return _wire__crate__api__media__generate_image_thumbnail(
+      port_,
+      path,
+      output_path,
+      params,
+      suffix,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_image_thumbnailPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_image_thumbnail_params>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_image_thumbnail');
+  late final _wire__crate__api__media__generate_image_thumbnail =
+      _wire__crate__api__media__generate_image_thumbnailPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_image_thumbnail_params>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_image_thumbnail_data(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_image_thumbnail_params> params,
+  ) {
+    return _wire__crate__api__media__generate_image_thumbnail_data(
+      port_,
+      path,
+      params,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_image_thumbnail_dataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_image_thumbnail_params>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_image_thumbnail_data');
+  late final _wire__crate__api__media__generate_image_thumbnail_data =
+      _wire__crate__api__media__generate_image_thumbnail_dataPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_image_thumbnail_params>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_storyboard(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_dir,
+    int interval_ms,
+    int tile_width,
+    int tile_height,
+    int columns,
+    int rows,
+    ffi.Pointer<wire_cst_encoder_options> encoder_options,
+  ) {
+    return _wire__crate__api__media__generate_storyboard(
+      port_,
+      path,
+      output_dir,
+      interval_ms,
+      tile_width,
+      tile_height,
+      columns,
+      rows,
+      encoder_options,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_storyboardPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint64,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Pointer<wire_cst_encoder_options>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_storyboard');
+  late final _wire__crate__api__media__generate_storyboard =
+      _wire__crate__api__media__generate_storyboardPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              int,
+              int,
+              int,
+              int,
+              ffi.Pointer<wire_cst_encoder_options>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_video_thumbnail(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_video_thumbnail_params> params,
+    ffi.Pointer<ffi.Bool> empty_image_fallback,
+  ) {
+    return _wire__crate__api__media__generate_video_thumbnail(
+      port_,
+      path,
+      output_path,
+      params,
+      empty_image_fallback,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_video_thumbnailPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_video_thumbnail_params>,
+            ffi.Pointer<ffi.Bool>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_video_thumbnail');
+  late final _wire__crate__api__media__generate_video_thumbnail =
+      _wire__crate__api__media__generate_video_thumbnailPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_video_thumbnail_params>,
+              ffi.Pointer<ffi.Bool>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_video_thumbnail_data(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_video_thumbnail_params> params,
+  ) {
+    return _wire__crate__api__media__generate_video_thumbnail_data(
+      port_,
+      path,
+      params,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_video_thumbnail_dataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_video_thumbnail_params>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__generate_video_thumbnail_data');
+  late final _wire__crate__api__media__generate_video_thumbnail_data =
+      _wire__crate__api__media__generate_video_thumbnail_dataPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_video_thumbnail_params>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_video_timeline_thumbnail_data(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_image_thumbnail_params> params,
+    int num_thumbnails,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__generate_video_timeline_thumbnail_data(
+      port_,
+      path,
+      params,
+      num_thumbnails,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_video_timeline_thumbnail_dataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_image_thumbnail_params>,
+            ffi.Uint32,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >(
+        'frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnail_data',
+      );
+  late final _wire__crate__api__media__generate_video_timeline_thumbnail_data =
+      _wire__crate__api__media__generate_video_timeline_thumbnail_dataPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_image_thumbnail_params>,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__generate_video_timeline_thumbnails(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_image_thumbnail_params> params,
+    int num_thumbnails,
+    ffi.Pointer<ffi.Bool> empty_image_fallback,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__generate_video_timeline_thumbnails(
+      port_,
+      path,
+      output_path,
+      params,
+      num_thumbnails,
+      empty_image_fallback,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__generate_video_timeline_thumbnailsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_image_thumbnail_params>,
+            ffi.Uint32,
+            ffi.Pointer<ffi.Bool>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >(
+        'frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails',
+      );
+  late final _wire__crate__api__media__generate_video_timeline_thumbnails =
+      _wire__crate__api__media__generate_video_timeline_thumbnailsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_image_thumbnail_params>,
+              int,
+              ffi.Pointer<ffi.Bool>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__get_image_info(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__media__get_image_info(port_, path);
+  }
+
+  late final _wire__crate__api__media__get_image_infoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__get_image_info');
+  late final _wire__crate__api__media__get_image_info =
+      _wire__crate__api__media__get_image_infoPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__media__get_video_info(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__media__get_video_info(port_, path);
+  }
+
+  late final _wire__crate__api__media__get_video_infoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__get_video_info');
+  late final _wire__crate__api__media__get_video_info =
+      _wire__crate__api__media__get_video_infoPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__logger__init_logger(
+    int port_,
+    int log_level,
+    bool write_to_stdout_or_system,
+    ffi.Pointer<wire_cst_write_to_files> write_to_files,
+    bool use_lightweight_tokio_runtime,
+  ) {
+    return _wire__crate__api__logger__init_logger(
+      port_,
+      log_level,
+      write_to_stdout_or_system,
+      write_to_files,
+      use_lightweight_tokio_runtime,
+    );
+  }
+
+  late final _wire__crate__api__logger__init_loggerPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Int32,
+            ffi.Bool,
+            ffi.Pointer<wire_cst_write_to_files>,
+            ffi.Bool,
+          )
+        >
+      >('frbgen_media_wire__crate__api__logger__init_logger');
+  late final _wire__crate__api__logger__init_logger =
+      _wire__crate__api__logger__init_loggerPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              bool,
+              ffi.Pointer<wire_cst_write_to_files>,
+              bool,
+            )
+          >();
+
+  void wire__crate__api__logger__log(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> file,
+    ffi.Pointer<ffi.Uint32> line,
+    int level,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> target,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  ) {
+    return _wire__crate__api__logger__log(
+      port_,
+      file,
+      line,
+      level,
+      target,
+      message,
+    );
+  }
+
+  late final _wire__crate__api__logger__logPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Int32,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__logger__log');
+  late final _wire__crate__api__logger__log = _wire__crate__api__logger__logPtr
+      .asFunction<
+        void Function(
+          int,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<ffi.Uint32>,
+          int,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+        )
+      >();
+
+  void wire__crate__api__media__output_format_extension(int port_, int that) {
+    return _wire__crate__api__media__output_format_extension(port_, that);
+  }
+
+  late final _wire__crate__api__media__output_format_extensionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
+        'frbgen_media_wire__crate__api__media__output_format_extension',
+      );
+  late final _wire__crate__api__media__output_format_extension =
+      _wire__crate__api__media__output_format_extensionPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__api__media__probe_media(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__media__probe_media(port_, path);
+  }
+
+  late final _wire__crate__api__media__probe_mediaPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__probe_media');
+  late final _wire__crate__api__media__probe_media =
+      _wire__crate__api__media__probe_mediaPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__media__probe_media_json(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__media__probe_media_json(port_, path);
+  }
+
+  late final _wire__crate__api__media__probe_media_jsonPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__probe_media_json');
+  late final _wire__crate__api__media__probe_media_json =
+      _wire__crate__api__media__probe_media_jsonPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__logger__reload_tracing_file_writer(
+    int port_,
+    ffi.Pointer<wire_cst_write_to_files> write_to_files,
+  ) {
+    return _wire__crate__api__logger__reload_tracing_file_writer(
+      port_,
+      write_to_files,
+    );
+  }
+
+  late final _wire__crate__api__logger__reload_tracing_file_writerPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_write_to_files>)
+        >
+      >('frbgen_media_wire__crate__api__logger__reload_tracing_file_writer');
+  late final _wire__crate__api__logger__reload_tracing_file_writer =
+      _wire__crate__api__logger__reload_tracing_file_writerPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_write_to_files>)
+          >();
+
+  void wire__crate__api__media__reverse_video(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
+    ffi.Pointer<wire_cst_reverse_params> params,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__media__reverse_video(
+      port_,
+      path,
+      output_path,
+      params,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__media__reverse_videoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_reverse_params>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__reverse_video');
+  late final _wire__crate__api__media__reverse_video =
+      _wire__crate__api__media__reverse_videoPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_reverse_params>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__media__thumbnail_size_type_dimensions(
+    int port_,
+    ffi.Pointer<wire_cst_thumbnail_size_type> that,
+  ) {
+    return _wire__crate__api__media__thumbnail_size_type_dimensions(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__api__media__thumbnail_size_type_dimensionsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_thumbnail_size_type>,
+          )
+        >
+      >('frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions');
+  late final _wire__crate__api__media__thumbnail_size_type_dimensions =
+      _wire__crate__api__media__thumbnail_size_type_dimensionsPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_thumbnail_size_type>)
+          >();
+
+  ffi.Pointer<wire_cst_animation_options>
+  cst_new_box_autoadd_animation_options() {
+    return _cst_new_box_autoadd_animation_options();
+  }
+
+  late final _cst_new_box_autoadd_animation_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_animation_options> Function()>
+      >('frbgen_media_cst_new_box_autoadd_animation_options');
+  late final _cst_new_box_autoadd_animation_options =
+      _cst_new_box_autoadd_animation_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_animation_options> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_aspect_mode(int value) {
+    return _cst_new_box_autoadd_aspect_mode(value);
+  }
+
+  late final _cst_new_box_autoadd_aspect_modePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_aspect_mode',
+      );
+  late final _cst_new_box_autoadd_aspect_mode =
+      _cst_new_box_autoadd_aspect_modePtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_audio_channels(int value) {
+    return _cst_new_box_autoadd_audio_channels(value);
+  }
+
+  late final _cst_new_box_autoadd_audio_channelsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_audio_channels',
+      );
+  late final _cst_new_box_autoadd_audio_channels =
+      _cst_new_box_autoadd_audio_channelsPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_audio_extract_options>
+  cst_new_box_autoadd_audio_extract_options() {
+    return _cst_new_box_autoadd_audio_extract_options();
+  }
+
+  late final _cst_new_box_autoadd_audio_extract_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_audio_extract_options> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_audio_extract_options');
+  late final _cst_new_box_autoadd_audio_extract_options =
+      _cst_new_box_autoadd_audio_extract_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_audio_extract_options> Function()>();
+
+  ffi.Pointer<wire_cst_audio_mix_params>
+  cst_new_box_autoadd_audio_mix_params() {
+    return _cst_new_box_autoadd_audio_mix_params();
+  }
+
+  late final _cst_new_box_autoadd_audio_mix_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_audio_mix_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_audio_mix_params');
+  late final _cst_new_box_autoadd_audio_mix_params =
+      _cst_new_box_autoadd_audio_mix_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_audio_mix_params> Function()>();
+
+  ffi.Pointer<wire_cst_audio_stream_info>
+  cst_new_box_autoadd_audio_stream_info() {
+    return _cst_new_box_autoadd_audio_stream_info();
+  }
+
+  late final _cst_new_box_autoadd_audio_stream_infoPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_audio_stream_info> Function()>
+      >('frbgen_media_cst_new_box_autoadd_audio_stream_info');
+  late final _cst_new_box_autoadd_audio_stream_info =
+      _cst_new_box_autoadd_audio_stream_infoPtr
+          .asFunction<ffi.Pointer<wire_cst_audio_stream_info> Function()>();
+
+  ffi.Pointer<wire_cst_audio_stream_probe>
+  cst_new_box_autoadd_audio_stream_probe() {
+    return _cst_new_box_autoadd_audio_stream_probe();
+  }
+
+  late final _cst_new_box_autoadd_audio_stream_probePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_audio_stream_probe> Function()>
+      >('frbgen_media_cst_new_box_autoadd_audio_stream_probe');
+  late final _cst_new_box_autoadd_audio_stream_probe =
+      _cst_new_box_autoadd_audio_stream_probePtr
+          .asFunction<ffi.Pointer<wire_cst_audio_stream_probe> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_audio_track_mode(int value) {
+    return _cst_new_box_autoadd_audio_track_mode(value);
+  }
+
+  late final _cst_new_box_autoadd_audio_track_modePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_audio_track_mode',
+      );
+  late final _cst_new_box_autoadd_audio_track_mode =
+      _cst_new_box_autoadd_audio_track_modePtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_best_thumbnail_params>
+  cst_new_box_autoadd_best_thumbnail_params() {
+    return _cst_new_box_autoadd_best_thumbnail_params();
+  }
+
+  late final _cst_new_box_autoadd_best_thumbnail_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_best_thumbnail_params> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_best_thumbnail_params');
+  late final _cst_new_box_autoadd_best_thumbnail_params =
+      _cst_new_box_autoadd_best_thumbnail_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_best_thumbnail_params> Function()>();
+
+  ffi.Pointer<ffi.Bool> cst_new_box_autoadd_bool(bool value) {
+    return _cst_new_box_autoadd_bool(value);
+  }
+
+  late final _cst_new_box_autoadd_boolPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>(
+        'frbgen_media_cst_new_box_autoadd_bool',
+      );
+  late final _cst_new_box_autoadd_bool = _cst_new_box_autoadd_boolPtr
+      .asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
+
+  ffi.Pointer<wire_cst_compress_params> cst_new_box_autoadd_compress_params() {
+    return _cst_new_box_autoadd_compress_params();
+  }
+
+  late final _cst_new_box_autoadd_compress_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_compress_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_compress_params');
+  late final _cst_new_box_autoadd_compress_params =
+      _cst_new_box_autoadd_compress_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_compress_params> Function()>();
+
+  ffi.Pointer<wire_cst_compress_progress>
+  cst_new_box_autoadd_compress_progress() {
+    return _cst_new_box_autoadd_compress_progress();
+  }
+
+  late final _cst_new_box_autoadd_compress_progressPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_compress_progress> Function()>
+      >('frbgen_media_cst_new_box_autoadd_compress_progress');
+  late final _cst_new_box_autoadd_compress_progress =
+      _cst_new_box_autoadd_compress_progressPtr
+          .asFunction<ffi.Pointer<wire_cst_compress_progress> Function()>();
+
+  ffi.Pointer<wire_cst_concat_params> cst_new_box_autoadd_concat_params() {
+    return _cst_new_box_autoadd_concat_params();
+  }
+
+  late final _cst_new_box_autoadd_concat_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_concat_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_concat_params');
+  late final _cst_new_box_autoadd_concat_params =
+      _cst_new_box_autoadd_concat_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_concat_params> Function()>();
+
+  ffi.Pointer<wire_cst_crop_rect> cst_new_box_autoadd_crop_rect() {
+    return _cst_new_box_autoadd_crop_rect();
+  }
+
+  late final _cst_new_box_autoadd_crop_rectPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_crop_rect> Function()>>(
+        'frbgen_media_cst_new_box_autoadd_crop_rect',
+      );
+  late final _cst_new_box_autoadd_crop_rect = _cst_new_box_autoadd_crop_rectPtr
+      .asFunction<ffi.Pointer<wire_cst_crop_rect> Function()>();
+
+  ffi.Pointer<wire_cst_ducking_params> cst_new_box_autoadd_ducking_params() {
+    return _cst_new_box_autoadd_ducking_params();
+  }
+
+  late final _cst_new_box_autoadd_ducking_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_ducking_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_ducking_params');
+  late final _cst_new_box_autoadd_ducking_params =
+      _cst_new_box_autoadd_ducking_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_ducking_params> Function()>();
+
+  ffi.Pointer<wire_cst_encoder_options> cst_new_box_autoadd_encoder_options() {
+    return _cst_new_box_autoadd_encoder_options();
+  }
+
+  late final _cst_new_box_autoadd_encoder_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_encoder_options> Function()>
+      >('frbgen_media_cst_new_box_autoadd_encoder_options');
+  late final _cst_new_box_autoadd_encoder_options =
+      _cst_new_box_autoadd_encoder_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_encoder_options> Function()>();
+
+  ffi.Pointer<ffi.Float> cst_new_box_autoadd_f_32(double value) {
+    return _cst_new_box_autoadd_f_32(value);
+  }
+
+  late final _cst_new_box_autoadd_f_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Float> Function(ffi.Float)>>(
+        'frbgen_media_cst_new_box_autoadd_f_32',
+      );
+  late final _cst_new_box_autoadd_f_32 = _cst_new_box_autoadd_f_32Ptr
+      .asFunction<ffi.Pointer<ffi.Float> Function(double)>();
+
+  ffi.Pointer<ffi.Double> cst_new_box_autoadd_f_64(double value) {
+    return _cst_new_box_autoadd_f_64(value);
+  }
+
+  late final _cst_new_box_autoadd_f_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>(
+        'frbgen_media_cst_new_box_autoadd_f_64',
+      );
+  late final _cst_new_box_autoadd_f_64 = _cst_new_box_autoadd_f_64Ptr
+      .asFunction<ffi.Pointer<ffi.Double> Function(double)>();
+
+  ffi.Pointer<wire_cst_frame_extract_params>
+  cst_new_box_autoadd_frame_extract_params() {
+    return _cst_new_box_autoadd_frame_extract_params();
+  }
+
+  late final _cst_new_box_autoadd_frame_extract_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_frame_extract_params> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_frame_extract_params');
+  late final _cst_new_box_autoadd_frame_extract_params =
+      _cst_new_box_autoadd_frame_extract_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_frame_extract_params> Function()>();
+
+  ffi.Pointer<wire_cst_gps_location> cst_new_box_autoadd_gps_location() {
+    return _cst_new_box_autoadd_gps_location();
+  }
+
+  late final _cst_new_box_autoadd_gps_locationPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_gps_location> Function()>
+      >('frbgen_media_cst_new_box_autoadd_gps_location');
+  late final _cst_new_box_autoadd_gps_location =
+      _cst_new_box_autoadd_gps_locationPtr
+          .asFunction<ffi.Pointer<wire_cst_gps_location> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_hash_algorithm(int value) {
+    return _cst_new_box_autoadd_hash_algorithm(value);
+  }
+
+  late final _cst_new_box_autoadd_hash_algorithmPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_hash_algorithm',
+      );
+  late final _cst_new_box_autoadd_hash_algorithm =
+      _cst_new_box_autoadd_hash_algorithmPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_hdr_format(int value) {
+    return _cst_new_box_autoadd_hdr_format(value);
+  }
+
+  late final _cst_new_box_autoadd_hdr_formatPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_hdr_format',
+      );
+  late final _cst_new_box_autoadd_hdr_format =
+      _cst_new_box_autoadd_hdr_formatPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_i_32(int value) {
+    return _cst_new_box_autoadd_i_32(value);
+  }
+
+  late final _cst_new_box_autoadd_i_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_i_32',
+      );
+  late final _cst_new_box_autoadd_i_32 = _cst_new_box_autoadd_i_32Ptr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(int value) {
+    return _cst_new_box_autoadd_i_64(value);
+  }
+
+  late final _cst_new_box_autoadd_i_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int64> Function(ffi.Int64)>>(
+        'frbgen_media_cst_new_box_autoadd_i_64',
+      );
+  late final _cst_new_box_autoadd_i_64 = _cst_new_box_autoadd_i_64Ptr
+      .asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
+
+  ffi.Pointer<wire_cst_image_thumbnail_params>
+  cst_new_box_autoadd_image_thumbnail_params() {
+    return _cst_new_box_autoadd_image_thumbnail_params();
+  }
+
+  late final _cst_new_box_autoadd_image_thumbnail_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_image_thumbnail_params> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_image_thumbnail_params');
+  late final _cst_new_box_autoadd_image_thumbnail_params =
+      _cst_new_box_autoadd_image_thumbnail_paramsPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_image_thumbnail_params> Function()
+          >();
+
+  ffi.Pointer<wire_cst_loudness_normalization>
+  cst_new_box_autoadd_loudness_normalization() {
+    return _cst_new_box_autoadd_loudness_normalization();
+  }
+
+  late final _cst_new_box_autoadd_loudness_normalizationPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_loudness_normalization> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_loudness_normalization');
+  late final _cst_new_box_autoadd_loudness_normalization =
+      _cst_new_box_autoadd_loudness_normalizationPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_loudness_normalization> Function()
+          >();
+
+  ffi.Pointer<wire_cst_media_hash> cst_new_box_autoadd_media_hash() {
+    return _cst_new_box_autoadd_media_hash();
+  }
+
+  late final _cst_new_box_autoadd_media_hashPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_media_hash> Function()>>(
+        'frbgen_media_cst_new_box_autoadd_media_hash',
+      );
+  late final _cst_new_box_autoadd_media_hash =
+      _cst_new_box_autoadd_media_hashPtr
+          .asFunction<ffi.Pointer<wire_cst_media_hash> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_output_format(int value) {
+    return _cst_new_box_autoadd_output_format(value);
+  }
+
+  late final _cst_new_box_autoadd_output_formatPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_output_format',
+      );
+  late final _cst_new_box_autoadd_output_format =
+      _cst_new_box_autoadd_output_formatPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_palette_color> cst_new_box_autoadd_palette_color() {
+    return _cst_new_box_autoadd_palette_color();
+  }
+
+  late final _cst_new_box_autoadd_palette_colorPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_palette_color> Function()>
+      >('frbgen_media_cst_new_box_autoadd_palette_color');
+  late final _cst_new_box_autoadd_palette_color =
+      _cst_new_box_autoadd_palette_colorPtr
+          .asFunction<ffi.Pointer<wire_cst_palette_color> Function()>();
+
+  ffi.Pointer<wire_cst_record_u_32_u_32>
+  cst_new_box_autoadd_record_u_32_u_32() {
+    return _cst_new_box_autoadd_record_u_32_u_32();
+  }
+
+  late final _cst_new_box_autoadd_record_u_32_u_32Ptr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_record_u_32_u_32> Function()>
+      >('frbgen_media_cst_new_box_autoadd_record_u_32_u_32');
+  late final _cst_new_box_autoadd_record_u_32_u_32 =
+      _cst_new_box_autoadd_record_u_32_u_32Ptr
+          .asFunction<ffi.Pointer<wire_cst_record_u_32_u_32> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_resize_mode(int value) {
+    return _cst_new_box_autoadd_resize_mode(value);
+  }
+
+  late final _cst_new_box_autoadd_resize_modePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_resize_mode',
+      );
+  late final _cst_new_box_autoadd_resize_mode =
+      _cst_new_box_autoadd_resize_modePtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_reverse_audio_mode(int value) {
+    return _cst_new_box_autoadd_reverse_audio_mode(value);
+  }
+
+  late final _cst_new_box_autoadd_reverse_audio_modePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_reverse_audio_mode',
+      );
+  late final _cst_new_box_autoadd_reverse_audio_mode =
+      _cst_new_box_autoadd_reverse_audio_modePtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_reverse_params> cst_new_box_autoadd_reverse_params() {
+    return _cst_new_box_autoadd_reverse_params();
+  }
+
+  late final _cst_new_box_autoadd_reverse_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_reverse_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_reverse_params');
+  late final _cst_new_box_autoadd_reverse_params =
+      _cst_new_box_autoadd_reverse_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_reverse_params> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_seek_mode(int value) {
+    return _cst_new_box_autoadd_seek_mode(value);
+  }
+
+  late final _cst_new_box_autoadd_seek_modePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_seek_mode',
+      );
+  late final _cst_new_box_autoadd_seek_mode = _cst_new_box_autoadd_seek_modePtr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_speed_params> cst_new_box_autoadd_speed_params() {
+    return _cst_new_box_autoadd_speed_params();
+  }
+
+  late final _cst_new_box_autoadd_speed_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_speed_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_speed_params');
+  late final _cst_new_box_autoadd_speed_params =
+      _cst_new_box_autoadd_speed_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_speed_params> Function()>();
+
+  ffi.Pointer<wire_cst_subtitle_params> cst_new_box_autoadd_subtitle_params() {
+    return _cst_new_box_autoadd_subtitle_params();
+  }
+
+  late final _cst_new_box_autoadd_subtitle_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_subtitle_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_subtitle_params');
+  late final _cst_new_box_autoadd_subtitle_params =
+      _cst_new_box_autoadd_subtitle_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_subtitle_params> Function()>();
+
+  ffi.Pointer<wire_cst_thumbnail_size_type>
+  cst_new_box_autoadd_thumbnail_size_type() {
+    return _cst_new_box_autoadd_thumbnail_size_type();
+  }
+
+  late final _cst_new_box_autoadd_thumbnail_size_typePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_thumbnail_size_type> Function()>
+      >('frbgen_media_cst_new_box_autoadd_thumbnail_size_type');
+  late final _cst_new_box_autoadd_thumbnail_size_type =
+      _cst_new_box_autoadd_thumbnail_size_typePtr
+          .asFunction<ffi.Pointer<wire_cst_thumbnail_size_type> Function()>();
+
+  ffi.Pointer<ffi.Uint16> cst_new_box_autoadd_u_16(int value) {
+    return _cst_new_box_autoadd_u_16(value);
+  }
+
+  late final _cst_new_box_autoadd_u_16Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>(
+        'frbgen_media_cst_new_box_autoadd_u_16',
+      );
+  late final _cst_new_box_autoadd_u_16 = _cst_new_box_autoadd_u_16Ptr
+      .asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
+  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
+    return _cst_new_box_autoadd_u_32(value);
+  }
+
+  late final _cst_new_box_autoadd_u_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+        'frbgen_media_cst_new_box_autoadd_u_32',
+      );
+  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr
+      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
+    return _cst_new_box_autoadd_u_64(value);
+  }
+
+  late final _cst_new_box_autoadd_u_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
+        'frbgen_media_cst_new_box_autoadd_u_64',
+      );
+  late final _cst_new_box_autoadd_u_64 = _cst_new_box_autoadd_u_64Ptr
+      .asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
+  ffi.Pointer<ffi.Uint8> cst_new_box_autoadd_u_8(int value) {
+    return _cst_new_box_autoadd_u_8(value);
+  }
+
+  late final _cst_new_box_autoadd_u_8Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>(
+        'frbgen_media_cst_new_box_autoadd_u_8',
+      );
+  late final _cst_new_box_autoadd_u_8 = _cst_new_box_autoadd_u_8Ptr
+      .asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
+
+  ffi.Pointer<wire_cst_video_stream_probe>
+  cst_new_box_autoadd_video_stream_probe() {
+    return _cst_new_box_autoadd_video_stream_probe();
+  }
+
+  late final _cst_new_box_autoadd_video_stream_probePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_video_stream_probe> Function()>
+      >('frbgen_media_cst_new_box_autoadd_video_stream_probe');
+  late final _cst_new_box_autoadd_video_stream_probe =
+      _cst_new_box_autoadd_video_stream_probePtr
+          .asFunction<ffi.Pointer<wire_cst_video_stream_probe> Function()>();
+
+  ffi.Pointer<wire_cst_video_thumbnail_params>
+  cst_new_box_autoadd_video_thumbnail_params() {
+    return _cst_new_box_autoadd_video_thumbnail_params();
+  }
+
+  late final _cst_new_box_autoadd_video_thumbnail_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_video_thumbnail_params> Function()
+        >
+      >('frbgen_media_cst_new_box_autoadd_video_thumbnail_params');
+  late final _cst_new_box_autoadd_video_thumbnail_params =
+      _cst_new_box_autoadd_video_thumbnail_paramsPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_video_thumbnail_params> Function()
+          >();
+
+  ffi.Pointer<wire_cst_watermark_params>
+  cst_new_box_autoadd_watermark_params() {
+    return _cst_new_box_autoadd_watermark_params();
+  }
+
+  late final _cst_new_box_autoadd_watermark_paramsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_watermark_params> Function()>
+      >('frbgen_media_cst_new_box_autoadd_watermark_params');
+  late final _cst_new_box_autoadd_watermark_params =
+      _cst_new_box_autoadd_watermark_paramsPtr
+          .asFunction<ffi.Pointer<wire_cst_watermark_params> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_watermark_position(int value) {
+    return _cst_new_box_autoadd_watermark_position(value);
+  }
+
+  late final _cst_new_box_autoadd_watermark_positionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_media_cst_new_box_autoadd_watermark_position',
+      );
+  late final _cst_new_box_autoadd_watermark_position =
+      _cst_new_box_autoadd_watermark_positionPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_write_to_files> cst_new_box_autoadd_write_to_files() {
+    return _cst_new_box_autoadd_write_to_files();
+  }
+
+  late final _cst_new_box_autoadd_write_to_filesPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_write_to_files> Function()>
+      >('frbgen_media_cst_new_box_autoadd_write_to_files');
+  late final _cst_new_box_autoadd_write_to_files =
+      _cst_new_box_autoadd_write_to_filesPtr
+          .asFunction<ffi.Pointer<wire_cst_write_to_files> Function()>();
+
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
+    return _cst_new_list_String(len);
+  }
+
+  late final _cst_new_list_StringPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_String');
+  late final _cst_new_list_String = _cst_new_list_StringPtr
+      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_media_chapter> cst_new_list_media_chapter(int len) {
+    return _cst_new_list_media_chapter(len);
+  }
+
+  late final _cst_new_list_media_chapterPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_media_chapter> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_media_chapter');
+  late final _cst_new_list_media_chapter = _cst_new_list_media_chapterPtr
+      .asFunction<ffi.Pointer<wire_cst_list_media_chapter> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_media_tag> cst_new_list_media_tag(int len) {
+    return _cst_new_list_media_tag(len);
+  }
+
+  late final _cst_new_list_media_tagPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_media_tag> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_media_tag');
+  late final _cst_new_list_media_tag = _cst_new_list_media_tagPtr
+      .asFunction<ffi.Pointer<wire_cst_list_media_tag> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_palette_color> cst_new_list_palette_color(int len) {
+    return _cst_new_list_palette_color(len);
+  }
+
+  late final _cst_new_list_palette_colorPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_palette_color> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_palette_color');
+  late final _cst_new_list_palette_color = _cst_new_list_palette_colorPtr
+      .asFunction<ffi.Pointer<wire_cst_list_palette_color> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_64_strict> cst_new_list_prim_u_64_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_u_64_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_64_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_u_64_strict> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_prim_u_64_strict');
+  late final _cst_new_list_prim_u_64_strict = _cst_new_list_prim_u_64_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_u_64_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_new_list_prim_u_8_loose(
+    int len,
+  ) {
+    return _cst_new_list_prim_u_8_loose(len);
+  }
+
+  late final _cst_new_list_prim_u_8_loosePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_prim_u_8_loose');
+  late final _cst_new_list_prim_u_8_loose = _cst_new_list_prim_u_8_loosePtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_u_8_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_8_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_prim_u_8_strict');
+  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_resolution_preset> cst_new_list_resolution_preset(
+    int len,
+  ) {
+    return _cst_new_list_resolution_preset(len);
+  }
+
+  late final _cst_new_list_resolution_presetPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_resolution_preset> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_resolution_preset');
+  late final _cst_new_list_resolution_preset =
+      _cst_new_list_resolution_presetPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_resolution_preset> Function(int)
           >();
 
-  void wire__crate__api__logger__debug_threads(int port_) {
-    return _wire__crate__api__logger__debug_threads(port_);
-  }
+  ffi.Pointer<wire_cst_list_scene_cut> cst_new_list_scene_cut(int len) {
+    return _cst_new_list_scene_cut(len);
+  }
+
+  late final _cst_new_list_scene_cutPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_scene_cut> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_scene_cut');
+  late final _cst_new_list_scene_cut = _cst_new_list_scene_cutPtr
+      .asFunction<ffi.Pointer<wire_cst_list_scene_cut> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_stream_probe> cst_new_list_stream_probe(int len) {
+    return _cst_new_list_stream_probe(len);
+  }
+
+  late final _cst_new_list_stream_probePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_stream_probe> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_stream_probe');
+  late final _cst_new_list_stream_probe = _cst_new_list_stream_probePtr
+      .asFunction<ffi.Pointer<wire_cst_list_stream_probe> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_stream_side_data> cst_new_list_stream_side_data(
+    int len,
+  ) {
+    return _cst_new_list_stream_side_data(len);
+  }
+
+  late final _cst_new_list_stream_side_dataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_stream_side_data> Function(ffi.Int32)
+        >
+      >('frbgen_media_cst_new_list_stream_side_data');
+  late final _cst_new_list_stream_side_data = _cst_new_list_stream_side_dataPtr
+      .asFunction<ffi.Pointer<wire_cst_list_stream_side_data> Function(int)>();
+
+  int dummy_method_to_enforce_bundling() {
+    return _dummy_method_to_enforce_bundling();
+  }
+
+  late final _dummy_method_to_enforce_bundlingPtr =
+      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>(
+        'dummy_method_to_enforce_bundling',
+      );
+  late final _dummy_method_to_enforce_bundling =
+      _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+}
+
+typedef DartPort = ffi.Int64;
+typedef DartDartPort = int;
+typedef DartPostCObjectFnTypeFunction =
+    ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartDartPostCObjectFnTypeFunction =
+    bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartPostCObjectFnType =
+    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+
+final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_ducking_params extends ffi.Struct {
+  external ffi.Pointer<ffi.Float> threshold_db;
+
+  external ffi.Pointer<ffi.Float> reduction_db;
+
+  external ffi.Pointer<ffi.Uint32> attack_ms;
+
+  external ffi.Pointer<ffi.Uint32> release_ms;
+}
+
+final class wire_cst_audio_mix_params extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> mode;
+
+  external ffi.Pointer<ffi.Float> music_gain_db;
+
+  external ffi.Pointer<ffi.Float> original_gain_db;
+
+  external ffi.Pointer<wire_cst_ducking_params> ducking;
+
+  @ffi.Bool()
+  external bool loop_music;
+
+  external ffi.Pointer<ffi.Uint64> music_start_ms;
+
+  external ffi.Pointer<ffi.Uint64> fade_in_ms;
+
+  external ffi.Pointer<ffi.Uint64> fade_out_ms;
+}
+
+final class wire_cst_list_prim_u_64_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_media_hash extends ffi.Struct {
+  @ffi.Int32()
+  external int algorithm;
+
+  external ffi.Pointer<wire_cst_list_prim_u_64_strict> hashes;
+
+  external ffi.Pointer<wire_cst_list_prim_u_64_strict> times_ms;
+}
+
+final class wire_cst_crop_rect extends ffi.Struct {
+  @ffi.Uint32()
+  external int x;
+
+  @ffi.Uint32()
+  external int y;
+
+  @ffi.Uint32()
+  external int width;
+
+  @ffi.Uint32()
+  external int height;
+}
+
+final class wire_cst_record_u_32_u_32 extends ffi.Struct {
+  @ffi.Uint32()
+  external int field0;
+
+  @ffi.Uint32()
+  external int field1;
+}
+
+final class wire_cst_watermark_params extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> image_path;
+
+  external ffi.Pointer<ffi.Int32> position;
+
+  external ffi.Pointer<ffi.Uint32> margin;
+
+  external ffi.Pointer<ffi.Float> scale;
+
+  external ffi.Pointer<ffi.Float> opacity;
+
+  external ffi.Pointer<ffi.Uint64> start_ms;
+
+  external ffi.Pointer<ffi.Uint64> end_ms;
+}
+
+final class wire_cst_subtitle_params extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> subtitle_path;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> font_path;
+
+  external ffi.Pointer<ffi.Uint32> font_size;
+
+  external ffi.Pointer<ffi.Uint32> color;
+
+  external ffi.Pointer<ffi.Uint32> outline_color;
+
+  external ffi.Pointer<ffi.Uint32> outline_width;
+
+  external ffi.Pointer<ffi.Uint32> bottom_margin;
+}
+
+final class wire_cst_speed_params extends ffi.Struct {
+  @ffi.Float()
+  external double factor;
+
+  external ffi.Pointer<ffi.Uint32> frame_rate;
+
+  external ffi.Pointer<ffi.Float> mute_audio_above;
+}
+
+final class wire_cst_loudness_normalization extends ffi.Struct {
+  @ffi.Float()
+  external double target_lufs;
+
+  external ffi.Pointer<ffi.Float> true_peak_dbtp;
+}
+
+final class wire_cst_compress_params extends ffi.Struct {
+  @ffi.Uint32()
+  external int target_bitrate_kbps;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preset;
+
+  external ffi.Pointer<ffi.Uint8> crf;
+
+  external ffi.Pointer<ffi.Uint32> width;
+
+  external ffi.Pointer<ffi.Uint32> height;
+
+  external ffi.Pointer<ffi.Uint64> sample_duration_ms;
+
+  external ffi.Pointer<wire_cst_crop_rect> crop;
+
+  external ffi.Pointer<wire_cst_record_u_32_u_32> aspect_ratio;
+
+  external ffi.Pointer<ffi.Int32> aspect_mode;
+
+  external ffi.Pointer<ffi.Uint32> pad_color;
+
+  external ffi.Pointer<wire_cst_watermark_params> watermark;
+
+  external ffi.Pointer<wire_cst_subtitle_params> subtitles;
+
+  external ffi.Pointer<wire_cst_speed_params> speed;
+
+  external ffi.Pointer<wire_cst_loudness_normalization> normalize_loudness;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> video_filters;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> audio_filters;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_concat_params extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> width;
+
+  external ffi.Pointer<ffi.Uint32> height;
+
+  external ffi.Pointer<ffi.Uint32> fps;
+
+  external ffi.Pointer<ffi.Uint32> target_bitrate_kbps;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preset;
+
+  external ffi.Pointer<ffi.Uint8> crf;
+
+  external ffi.Pointer<ffi.Bool> force_reencode;
+}
+
+final class wire_cst_list_prim_u_8_loose extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_animation_options extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> max_bytes;
+
+  external ffi.Pointer<ffi.Uint16> loop_count;
+
+  external ffi.Pointer<ffi.Bool> dither;
+}
+
+final class wire_cst_audio_extract_options extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> start_ms;
+
+  external ffi.Pointer<ffi.Uint64> end_ms;
+
+  external ffi.Pointer<ffi.Int32> channels;
+
+  external ffi.Pointer<ffi.Uint32> sample_rate;
+
+  external ffi.Pointer<ffi.Uint32> bitrate_kbps;
+
+  external ffi.Pointer<wire_cst_loudness_normalization> normalize_loudness;
+}
+
+final class wire_cst_FrameSelection_Timestamps extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_64_strict> field0;
+}
+
+final class wire_cst_FrameSelection_Interval extends ffi.Struct {
+  @ffi.Uint64()
+  external int field0;
+}
+
+final class wire_cst_FrameSelection_Count extends ffi.Struct {
+  @ffi.Uint32()
+  external int field0;
+}
+
+final class FrameSelectionKind extends ffi.Union {
+  external wire_cst_FrameSelection_Timestamps Timestamps;
+
+  external wire_cst_FrameSelection_Interval Interval;
+
+  external wire_cst_FrameSelection_Count Count;
+}
+
+final class wire_cst_frame_selection extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external FrameSelectionKind kind;
+}
+
+final class wire_cst_ThumbnailSizeType_Custom extends ffi.Struct {
+  external ffi.Pointer<wire_cst_record_u_32_u_32> field0;
+}
+
+final class ThumbnailSizeTypeKind extends ffi.Union {
+  external wire_cst_ThumbnailSizeType_Custom Custom;
+}
+
+final class wire_cst_thumbnail_size_type extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ThumbnailSizeTypeKind kind;
+}
+
+final class wire_cst_encoder_options extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> jpeg_quality;
+
+  external ffi.Pointer<ffi.Bool> jpeg_progressive;
+
+  external ffi.Pointer<ffi.Float> webp_quality;
+
+  external ffi.Pointer<ffi.Uint8> png_compression;
+
+  external ffi.Pointer<ffi.Uint8> avif_quality;
+
+  external ffi.Pointer<ffi.Uint8> heic_quality;
+}
+
+final class wire_cst_frame_extract_params extends ffi.Struct {
+  external wire_cst_frame_selection selection;
+
+  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+
+  external ffi.Pointer<ffi.Int32> format;
+
+  external ffi.Pointer<ffi.Int32> seek_mode;
+
+  external ffi.Pointer<ffi.Int32> resize_mode;
+
+  external ffi.Pointer<ffi.Bool> allow_upscale;
+
+  external ffi.Pointer<wire_cst_encoder_options> encoder_options;
+}
+
+final class wire_cst_best_thumbnail_params extends ffi.Struct {
+  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+
+  external ffi.Pointer<ffi.Int32> format;
+
+  external ffi.Pointer<ffi.Uint32> candidates;
+
+  external ffi.Pointer<ffi.Int32> resize_mode;
+
+  external ffi.Pointer<ffi.Bool> allow_upscale;
+
+  external ffi.Pointer<wire_cst_encoder_options> encoder_options;
+}
+
+final class wire_cst_image_thumbnail_params extends ffi.Struct {
+  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+
+  external ffi.Pointer<ffi.Int32> format;
+
+  external ffi.Pointer<ffi.Int32> seek_mode;
+
+  external ffi.Pointer<ffi.Int32> resize_mode;
+
+  external ffi.Pointer<ffi.Bool> allow_upscale;
+
+  external ffi.Pointer<wire_cst_encoder_options> encoder_options;
+}
+
+final class wire_cst_video_thumbnail_params extends ffi.Struct {
+  @ffi.Uint64()
+  external int time_ms;
+
+  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+
+  external ffi.Pointer<ffi.Int32> format;
+
+  external ffi.Pointer<ffi.Int32> seek_mode;
 
-  late final _wire__crate__api__logger__debug_threadsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_media_wire__crate__api__logger__debug_threads',
-      );
-  late final _wire__crate__api__logger__debug_threads =
-      _wire__crate__api__logger__debug_threadsPtr
-          .asFunction<void Function(int)>();
+  external ffi.Pointer<ffi.Int32> resize_mode;
 
-  void wire__crate__api__media__estimate_compression(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> temp_output_path,
-    ffi.Pointer<wire_cst_compress_params> params,
-  ) {
-    return _wire__crate__api__media__estimate_compression(
-      port_,
-      path,
-      temp_output_path,
-      params,
-    );
-  }
+  external ffi.Pointer<ffi.Bool> allow_upscale;
 
-  late final _wire__crate__api__media__estimate_compressionPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_compress_params>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__media__estimate_compression');
-  late final _wire__crate__api__media__estimate_compression =
-      _wire__crate__api__media__estimate_compressionPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_compress_params>,
-            )
-          >();
+  external ffi.Pointer<wire_cst_encoder_options> encoder_options;
+}
 
-  void wire__crate__api__media__generate_image_thumbnail(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
-    ffi.Pointer<wire_cst_image_thumbnail_params> params,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> suffix,
-  ) {
-    return _wire__crate__api__media__generate_image_thumbnail(
-      port_,
-      path,
-      output_path,
-      params,
-      suffix,
-    );
-  }
+final class wire_cst_write_to_files extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
 
-  late final _wire__crate__api__media__generate_image_thumbnailPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_image_thumbnail_params>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__media__generate_image_thumbnail');
-  late final _wire__crate__api__media__generate_image_thumbnail =
-      _wire__crate__api__media__generate_image_thumbnailPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_image_thumbnail_params>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file_prefix;
 
-  void wire__crate__api__media__generate_video_thumbnail(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
-    ffi.Pointer<wire_cst_video_thumbnail_params> params,
-    ffi.Pointer<ffi.Bool> empty_image_fallback,
-  ) {
-    return _wire__crate__api__media__generate_video_thumbnail(
-      port_,
-      path,
-      output_path,
-      params,
-      empty_image_fallback,
-    );
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file_suffix;
 
-  late final _wire__crate__api__media__generate_video_thumbnailPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_video_thumbnail_params>,
-            ffi.Pointer<ffi.Bool>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__media__generate_video_thumbnail');
-  late final _wire__crate__api__media__generate_video_thumbnail =
-      _wire__crate__api__media__generate_video_thumbnailPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_video_thumbnail_params>,
-              ffi.Pointer<ffi.Bool>,
-            )
-          >();
+  external ffi.Pointer<ffi.Uint64> max_files;
+}
 
-  void wire__crate__api__media__generate_video_timeline_thumbnails(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> output_path,
-    ffi.Pointer<wire_cst_image_thumbnail_params> params,
-    int num_thumbnails,
-    ffi.Pointer<ffi.Bool> empty_image_fallback,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
-  ) {
-    return _wire__crate__api__media__generate_video_timeline_thumbnails(
-      port_,
-      path,
-      output_path,
-      params,
-      num_thumbnails,
-      empty_image_fallback,
-      sink,
-    );
-  }
+final class wire_cst_reverse_params extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> start_ms;
 
-  late final _wire__crate__api__media__generate_video_timeline_thumbnailsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_image_thumbnail_params>,
-            ffi.Uint32,
-            ffi.Pointer<ffi.Bool>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >(
-        'frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails',
-      );
-  late final _wire__crate__api__media__generate_video_timeline_thumbnails =
-      _wire__crate__api__media__generate_video_timeline_thumbnailsPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_image_thumbnail_params>,
-              int,
-              ffi.Pointer<ffi.Bool>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+  external ffi.Pointer<ffi.Uint64> end_ms;
+
+  external ffi.Pointer<ffi.Uint32> boomerang_loops;
+
+  external ffi.Pointer<ffi.Int32> audio;
+
+  external ffi.Pointer<ffi.Uint32> width;
+
+  external ffi.Pointer<ffi.Uint32> height;
+
+  external ffi.Pointer<ffi.Uint32> target_bitrate_kbps;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preset;
+
+  external ffi.Pointer<ffi.Uint8> crf;
+
+  external ffi.Pointer<ffi.Uint32> memory_budget_mb;
+}
+
+final class wire_cst_audio_stream_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> codec_name;
+
+  @ffi.Uint32()
+  external int channels;
+
+  @ffi.Uint32()
+  external int sample_rate;
+
+  external ffi.Pointer<ffi.Uint64> bitrate;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> language;
+}
+
+final class wire_cst_audio_stream_probe extends ffi.Struct {
+  @ffi.Uint32()
+  external int sample_rate;
+
+  @ffi.Uint32()
+  external int channels;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> channel_layout;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sample_format;
+
+  external ffi.Pointer<ffi.Uint32> bits_per_sample;
+}
+
+final class wire_cst_compress_progress extends ffi.Struct {
+  @ffi.Uint64()
+  external int processed_ms;
+
+  @ffi.Uint64()
+  external int total_ms;
+
+  @ffi.Float()
+  external double speed_x;
+}
+
+final class wire_cst_gps_location extends ffi.Struct {
+  @ffi.Double()
+  external double latitude;
+
+  @ffi.Double()
+  external double longitude;
+
+  external ffi.Pointer<ffi.Double> altitude;
+}
+
+final class wire_cst_palette_color extends ffi.Struct {
+  @ffi.Uint32()
+  external int color;
+
+  @ffi.Double()
+  external double population;
+}
+
+final class wire_cst_video_stream_probe extends ffi.Struct {
+  @ffi.Uint32()
+  external int width;
+
+  @ffi.Uint32()
+  external int height;
+
+  @ffi.Uint32()
+  external int display_width;
+
+  @ffi.Uint32()
+  external int display_height;
+
+  @ffi.Int32()
+  external int rotation;
+
+  external ffi.Pointer<ffi.Double> avg_frame_rate;
+
+  external ffi.Pointer<ffi.Double> real_frame_rate;
+
+  @ffi.Bool()
+  external bool is_vfr;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pixel_format;
+
+  external ffi.Pointer<ffi.Uint32> bit_depth;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_primaries;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_transfer;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_matrix;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_range;
+
+  external ffi.Pointer<ffi.Int32> hdr_format;
+
+  external ffi.Pointer<ffi.Int32> level;
+}
+
+final class wire_cst_media_tag extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_media_tag extends ffi.Struct {
+  external ffi.Pointer<wire_cst_media_tag> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_media_chapter extends ffi.Struct {
+  @ffi.Int64()
+  external int id;
+
+  @ffi.Uint64()
+  external int start_ms;
+
+  @ffi.Uint64()
+  external int end_ms;
 
-  void wire__crate__api__media__get_video_info(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-  ) {
-    return _wire__crate__api__media__get_video_info(port_, path);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> title;
 
-  late final _wire__crate__api__media__get_video_infoPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__media__get_video_info');
-  late final _wire__crate__api__media__get_video_info =
-      _wire__crate__api__media__get_video_infoPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  external ffi.Pointer<wire_cst_list_media_tag> tags;
+}
 
-  void wire__crate__api__logger__init_logger(
-    int port_,
-    int log_level,
-    bool write_to_stdout_or_system,
-    ffi.Pointer<wire_cst_write_to_files> write_to_files,
-    bool use_lightweight_tokio_runtime,
-  ) {
-    return _wire__crate__api__logger__init_logger(
-      port_,
-      log_level,
-      write_to_stdout_or_system,
-      write_to_files,
-      use_lightweight_tokio_runtime,
-    );
-  }
+final class wire_cst_list_media_chapter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_media_chapter> ptr;
 
-  late final _wire__crate__api__logger__init_loggerPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Int32,
-            ffi.Bool,
-            ffi.Pointer<wire_cst_write_to_files>,
-            ffi.Bool,
-          )
-        >
-      >('frbgen_media_wire__crate__api__logger__init_logger');
-  late final _wire__crate__api__logger__init_logger =
-      _wire__crate__api__logger__init_loggerPtr
-          .asFunction<
-            void Function(
-              int,
-              int,
-              bool,
-              ffi.Pointer<wire_cst_write_to_files>,
-              bool,
-            )
-          >();
+  @ffi.Int32()
+  external int len;
+}
 
-  void wire__crate__api__logger__log(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> file,
-    ffi.Pointer<ffi.Uint32> line,
-    int level,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> target,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-  ) {
-    return _wire__crate__api__logger__log(
-      port_,
-      file,
-      line,
-      level,
-      target,
-      message,
-    );
-  }
+final class wire_cst_list_palette_color extends ffi.Struct {
+  external ffi.Pointer<wire_cst_palette_color> ptr;
 
-  late final _wire__crate__api__logger__logPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<ffi.Uint32>,
-            ffi.Int32,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__logger__log');
-  late final _wire__crate__api__logger__log = _wire__crate__api__logger__logPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<ffi.Uint32>,
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
+  @ffi.Int32()
+  external int len;
+}
 
-  void wire__crate__api__media__output_format_extension(int port_, int that) {
-    return _wire__crate__api__media__output_format_extension(port_, that);
-  }
+final class wire_cst_resolution_preset extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
 
-  late final _wire__crate__api__media__output_format_extensionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>(
-        'frbgen_media_wire__crate__api__media__output_format_extension',
-      );
-  late final _wire__crate__api__media__output_format_extension =
-      _wire__crate__api__media__output_format_extensionPtr
-          .asFunction<void Function(int, int)>();
+  @ffi.Uint32()
+  external int width;
 
-  void wire__crate__api__logger__reload_tracing_file_writer(
-    int port_,
-    ffi.Pointer<wire_cst_write_to_files> write_to_files,
-  ) {
-    return _wire__crate__api__logger__reload_tracing_file_writer(
-      port_,
-      write_to_files,
-    );
-  }
+  @ffi.Uint32()
+  external int height;
 
-  late final _wire__crate__api__logger__reload_tracing_file_writerPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_write_to_files>)
-        >
-      >('frbgen_media_wire__crate__api__logger__reload_tracing_file_writer');
-  late final _wire__crate__api__logger__reload_tracing_file_writer =
-      _wire__crate__api__logger__reload_tracing_file_writerPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_write_to_files>)
-          >();
+  @ffi.Uint64()
+  external int bitrate;
 
-  void wire__crate__api__media__thumbnail_size_type_dimensions(
-    int port_,
-    ffi.Pointer<wire_cst_thumbnail_size_type> that,
-  ) {
-    return _wire__crate__api__media__thumbnail_size_type_dimensions(
-      port_,
-      that,
-    );
-  }
+  @ffi.Uint8()
+  external int crf;
+}
 
-  late final _wire__crate__api__media__thumbnail_size_type_dimensionsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_thumbnail_size_type>,
-          )
-        >
-      >('frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions');
-  late final _wire__crate__api__media__thumbnail_size_type_dimensions =
-      _wire__crate__api__media__thumbnail_size_type_dimensionsPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_thumbnail_size_type>)
-          >();
+final class wire_cst_list_resolution_preset extends ffi.Struct {
+  external ffi.Pointer<wire_cst_resolution_preset> ptr;
 
-  ffi.Pointer<ffi.Bool> cst_new_box_autoadd_bool(bool value) {
-    return _cst_new_box_autoadd_bool(value);
-  }
+  @ffi.Int32()
+  external int len;
+}
 
-  late final _cst_new_box_autoadd_boolPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>(
-        'frbgen_media_cst_new_box_autoadd_bool',
-      );
-  late final _cst_new_box_autoadd_bool = _cst_new_box_autoadd_boolPtr
-      .asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
+final class wire_cst_scene_cut extends ffi.Struct {
+  @ffi.Uint64()
+  external int time_ms;
 
-  ffi.Pointer<wire_cst_compress_params> cst_new_box_autoadd_compress_params() {
-    return _cst_new_box_autoadd_compress_params();
-  }
+  @ffi.Double()
+  external double score;
+}
 
-  late final _cst_new_box_autoadd_compress_paramsPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<wire_cst_compress_params> Function()>
-      >('frbgen_media_cst_new_box_autoadd_compress_params');
-  late final _cst_new_box_autoadd_compress_params =
-      _cst_new_box_autoadd_compress_paramsPtr
-          .asFunction<ffi.Pointer<wire_cst_compress_params> Function()>();
+final class wire_cst_list_scene_cut extends ffi.Struct {
+  external ffi.Pointer<wire_cst_scene_cut> ptr;
 
-  ffi.Pointer<wire_cst_image_thumbnail_params>
-  cst_new_box_autoadd_image_thumbnail_params() {
-    return _cst_new_box_autoadd_image_thumbnail_params();
-  }
+  @ffi.Int32()
+  external int len;
+}
 
-  late final _cst_new_box_autoadd_image_thumbnail_paramsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_image_thumbnail_params> Function()
-        >
-      >('frbgen_media_cst_new_box_autoadd_image_thumbnail_params');
-  late final _cst_new_box_autoadd_image_thumbnail_params =
-      _cst_new_box_autoadd_image_thumbnail_paramsPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_image_thumbnail_params> Function()
-          >();
+final class wire_cst_stream_disposition extends ffi.Struct {
+  @ffi.Bool()
+  external bool default1;
 
-  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_output_format(int value) {
-    return _cst_new_box_autoadd_output_format(value);
-  }
+  @ffi.Bool()
+  external bool dub;
 
-  late final _cst_new_box_autoadd_output_formatPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
-        'frbgen_media_cst_new_box_autoadd_output_format',
-      );
-  late final _cst_new_box_autoadd_output_format =
-      _cst_new_box_autoadd_output_formatPtr
-          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+  @ffi.Bool()
+  external bool original;
 
-  ffi.Pointer<wire_cst_record_u_32_u_32>
-  cst_new_box_autoadd_record_u_32_u_32() {
-    return _cst_new_box_autoadd_record_u_32_u_32();
-  }
+  @ffi.Bool()
+  external bool comment;
 
-  late final _cst_new_box_autoadd_record_u_32_u_32Ptr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<wire_cst_record_u_32_u_32> Function()>
-      >('frbgen_media_cst_new_box_autoadd_record_u_32_u_32');
-  late final _cst_new_box_autoadd_record_u_32_u_32 =
-      _cst_new_box_autoadd_record_u_32_u_32Ptr
-          .asFunction<ffi.Pointer<wire_cst_record_u_32_u_32> Function()>();
+  @ffi.Bool()
+  external bool lyrics;
 
-  ffi.Pointer<wire_cst_thumbnail_size_type>
-  cst_new_box_autoadd_thumbnail_size_type() {
-    return _cst_new_box_autoadd_thumbnail_size_type();
-  }
+  @ffi.Bool()
+  external bool karaoke;
+
+  @ffi.Bool()
+  external bool forced;
+
+  @ffi.Bool()
+  external bool hearing_impaired;
+
+  @ffi.Bool()
+  external bool visual_impaired;
+
+  @ffi.Bool()
+  external bool clean_effects;
+
+  @ffi.Bool()
+  external bool attached_pic;
+
+  @ffi.Bool()
+  external bool captions;
+
+  @ffi.Bool()
+  external bool descriptions;
+
+  @ffi.Bool()
+  external bool metadata;
+}
+
+final class wire_cst_stream_side_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> kind;
+
+  @ffi.Uint32()
+  external int size_bytes;
+}
+
+final class wire_cst_list_stream_side_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_stream_side_data> ptr;
 
-  late final _cst_new_box_autoadd_thumbnail_size_typePtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<wire_cst_thumbnail_size_type> Function()>
-      >('frbgen_media_cst_new_box_autoadd_thumbnail_size_type');
-  late final _cst_new_box_autoadd_thumbnail_size_type =
-      _cst_new_box_autoadd_thumbnail_size_typePtr
-          .asFunction<ffi.Pointer<wire_cst_thumbnail_size_type> Function()>();
+  @ffi.Int32()
+  external int len;
+}
 
-  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
-    return _cst_new_box_autoadd_u_32(value);
-  }
+final class wire_cst_stream_probe extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
 
-  late final _cst_new_box_autoadd_u_32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
-        'frbgen_media_cst_new_box_autoadd_u_32',
-      );
-  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr
-      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+  @ffi.Int32()
+  external int stream_type;
 
-  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
-    return _cst_new_box_autoadd_u_64(value);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> codec_name;
 
-  late final _cst_new_box_autoadd_u_64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
-        'frbgen_media_cst_new_box_autoadd_u_64',
-      );
-  late final _cst_new_box_autoadd_u_64 = _cst_new_box_autoadd_u_64Ptr
-      .asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> codec_long_name;
 
-  ffi.Pointer<ffi.Uint8> cst_new_box_autoadd_u_8(int value) {
-    return _cst_new_box_autoadd_u_8(value);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> profile;
 
-  late final _cst_new_box_autoadd_u_8Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>(
-        'frbgen_media_cst_new_box_autoadd_u_8',
-      );
-  late final _cst_new_box_autoadd_u_8 = _cst_new_box_autoadd_u_8Ptr
-      .asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
+  external ffi.Pointer<ffi.Uint64> bitrate;
 
-  ffi.Pointer<wire_cst_video_thumbnail_params>
-  cst_new_box_autoadd_video_thumbnail_params() {
-    return _cst_new_box_autoadd_video_thumbnail_params();
-  }
+  external ffi.Pointer<ffi.Int64> start_time_ms;
 
-  late final _cst_new_box_autoadd_video_thumbnail_paramsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_video_thumbnail_params> Function()
-        >
-      >('frbgen_media_cst_new_box_autoadd_video_thumbnail_params');
-  late final _cst_new_box_autoadd_video_thumbnail_params =
-      _cst_new_box_autoadd_video_thumbnail_paramsPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_video_thumbnail_params> Function()
-          >();
+  external ffi.Pointer<ffi.Uint64> duration_ms;
 
-  ffi.Pointer<wire_cst_write_to_files> cst_new_box_autoadd_write_to_files() {
-    return _cst_new_box_autoadd_write_to_files();
-  }
+  external ffi.Pointer<ffi.Uint64> frame_count;
 
-  late final _cst_new_box_autoadd_write_to_filesPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<wire_cst_write_to_files> Function()>
-      >('frbgen_media_cst_new_box_autoadd_write_to_files');
-  late final _cst_new_box_autoadd_write_to_files =
-      _cst_new_box_autoadd_write_to_filesPtr
-          .asFunction<ffi.Pointer<wire_cst_write_to_files> Function()>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> language;
 
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
-    int len,
-  ) {
-    return _cst_new_list_prim_u_8_strict(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> title;
 
-  late final _cst_new_list_prim_u_8_strictPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)
-        >
-      >('frbgen_media_cst_new_list_prim_u_8_strict');
-  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
+  external wire_cst_stream_disposition disposition;
 
-  ffi.Pointer<wire_cst_list_resolution_preset> cst_new_list_resolution_preset(
-    int len,
-  ) {
-    return _cst_new_list_resolution_preset(len);
-  }
+  external ffi.Pointer<wire_cst_list_media_tag> tags;
 
-  late final _cst_new_list_resolution_presetPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_resolution_preset> Function(ffi.Int32)
-        >
-      >('frbgen_media_cst_new_list_resolution_preset');
-  late final _cst_new_list_resolution_preset =
-      _cst_new_list_resolution_presetPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_list_resolution_preset> Function(int)
-          >();
+  external ffi.Pointer<wire_cst_list_stream_side_data> side_data;
 
-  int dummy_method_to_enforce_bundling() {
-    return _dummy_method_to_enforce_bundling();
-  }
+  external ffi.Pointer<wire_cst_video_stream_probe> video;
 
-  late final _dummy_method_to_enforce_bundlingPtr =
-      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>(
-        'dummy_method_to_enforce_bundling',
-      );
-  late final _dummy_method_to_enforce_bundling =
-      _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+  external ffi.Pointer<wire_cst_audio_stream_probe> audio;
 }
 
-typedef DartPort = ffi.Int64;
-typedef DartDartPort = int;
-typedef DartPostCObjectFnTypeFunction =
-    ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartDartPostCObjectFnTypeFunction =
-    bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartPostCObjectFnType =
-    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
-
-final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+final class wire_cst_list_stream_probe extends ffi.Struct {
+  external ffi.Pointer<wire_cst_stream_probe> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_compress_params extends ffi.Struct {
+final class wire_cst_best_thumbnail extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> data;
+
   @ffi.Uint32()
-  external int target_bitrate_kbps;
+  external int width;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preset;
+  @ffi.Uint32()
+  external int height;
 
-  external ffi.Pointer<ffi.Uint8> crf;
+  @ffi.Uint64()
+  external int time_ms;
 
-  external ffi.Pointer<ffi.Uint32> width;
+  @ffi.Double()
+  external double score;
+}
 
-  external ffi.Pointer<ffi.Uint32> height;
+final class wire_cst_compression_estimate extends ffi.Struct {
+  @ffi.Uint64()
+  external int estimated_size_bytes;
 
-  external ffi.Pointer<ffi.Uint64> sample_duration_ms;
+  @ffi.Uint64()
+  external int estimated_duration_ms;
 }
 
-final class wire_cst_record_u_32_u_32 extends ffi.Struct {
+final class wire_cst_extracted_frame extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+
   @ffi.Uint32()
-  external int field0;
+  external int index;
+
+  @ffi.Uint64()
+  external int requested_ms;
+
+  @ffi.Uint64()
+  external int time_ms;
 
   @ffi.Uint32()
-  external int field1;
-}
+  external int width;
 
-final class wire_cst_ThumbnailSizeType_Custom extends ffi.Struct {
-  external ffi.Pointer<wire_cst_record_u_32_u_32> field0;
+  @ffi.Uint32()
+  external int height;
 }
 
-final class ThumbnailSizeTypeKind extends ffi.Union {
-  external wire_cst_ThumbnailSizeType_Custom Custom;
+final class wire_cst_hash_comparison extends ffi.Struct {
+  @ffi.Uint32()
+  external int distance;
+
+  @ffi.Double()
+  external double similarity;
+
+  @ffi.Bool()
+  external bool is_similar;
 }
 
-final class wire_cst_thumbnail_size_type extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+final class wire_cst_image_info extends ffi.Struct {
+  @ffi.Uint32()
+  external int width;
 
-  external ThumbnailSizeTypeKind kind;
+  @ffi.Uint32()
+  external int height;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> format;
+
+  @ffi.Uint32()
+  external int bit_depth;
+
+  @ffi.Bool()
+  external bool has_alpha;
+
+  @ffi.Uint32()
+  external int orientation;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> camera_make;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> camera_model;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> capture_date;
+
+  external ffi.Pointer<wire_cst_gps_location> gps;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> icc_profile_name;
+
+  @ffi.Uint32()
+  external int image_count;
+
+  @ffi.Uint32()
+  external int auxiliary_image_count;
+
+  @ffi.Bool()
+  external bool has_depth;
 }
 
-final class wire_cst_image_thumbnail_params extends ffi.Struct {
-  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+final class wire_cst_loudness_info extends ffi.Struct {
+  @ffi.Double()
+  external double integrated_lufs;
 
-  external ffi.Pointer<ffi.Int32> format;
+  @ffi.Double()
+  external double loudness_range_lu;
+
+  @ffi.Double()
+  external double true_peak_dbtp;
 }
 
-final class wire_cst_video_thumbnail_params extends ffi.Struct {
+final class wire_cst_media_probe extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> format_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> format_long_name;
+
+  external ffi.Pointer<ffi.Uint64> duration_ms;
+
+  external ffi.Pointer<ffi.Int64> start_time_ms;
+
+  external ffi.Pointer<ffi.Uint64> bitrate;
+
   @ffi.Uint64()
-  external int time_ms;
+  external int size_bytes;
 
-  external ffi.Pointer<wire_cst_thumbnail_size_type> size_type;
+  external ffi.Pointer<wire_cst_list_media_tag> tags;
 
-  external ffi.Pointer<ffi.Int32> format;
+  external ffi.Pointer<wire_cst_list_media_chapter> chapters;
+
+  external ffi.Pointer<wire_cst_list_stream_probe> streams;
 }
 
-final class wire_cst_write_to_files extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+final class wire_cst_palette extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_palette_color> colors;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file_prefix;
+  external wire_cst_palette_color average;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file_suffix;
+  external ffi.Pointer<wire_cst_palette_color> vibrant;
 
-  external ffi.Pointer<ffi.Uint64> max_files;
+  external ffi.Pointer<wire_cst_palette_color> light_vibrant;
+
+  external ffi.Pointer<wire_cst_palette_color> dark_vibrant;
+
+  external ffi.Pointer<wire_cst_palette_color> muted;
+
+  external ffi.Pointer<wire_cst_palette_color> light_muted;
+
+  external ffi.Pointer<wire_cst_palette_color> dark_muted;
 }
 
-final class wire_cst_resolution_preset extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+final class wire_cst_placeholder_image extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> rgba;
 
   @ffi.Uint32()
   external int width;
 
   @ffi.Uint32()
   external int height;
+}
 
-  @ffi.Uint64()
-  external int bitrate;
+final class wire_cst_SceneDetectionEvent_Progress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_compress_progress> field0;
+}
 
-  @ffi.Uint8()
-  external int crf;
+final class wire_cst_SceneDetectionEvent_Cuts extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_scene_cut> field0;
 }
 
-final class wire_cst_list_resolution_preset extends ffi.Struct {
-  external ffi.Pointer<wire_cst_resolution_preset> ptr;
+final class SceneDetectionEventKind extends ffi.Union {
+  external wire_cst_SceneDetectionEvent_Progress Progress;
+
+  external wire_cst_SceneDetectionEvent_Cuts Cuts;
+}
 
+final class wire_cst_scene_detection_event extends ffi.Struct {
   @ffi.Int32()
-  external int len;
+  external int tag;
+
+  external SceneDetectionEventKind kind;
 }
 
-final class wire_cst_compression_estimate extends ffi.Struct {
-  @ffi.Uint64()
-  external int estimated_size_bytes;
+final class wire_cst_storyboard extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> vtt_path;
 
-  @ffi.Uint64()
-  external int estimated_duration_ms;
+  external ffi.Pointer<wire_cst_list_String> sprite_paths;
+
+  @ffi.Uint32()
+  external int tile_count;
+}
+
+final class wire_cst_thumbnail_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> data;
+
+  @ffi.Uint32()
+  external int width;
+
+  @ffi.Uint32()
+  external int height;
+
+  external ffi.Pointer<ffi.Uint64> time_ms;
 }
 
 final class wire_cst_video_info extends ffi.Struct {
@@ -1643,5 +6912,43 @@ final class wire_cst_video_info extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> format_name;
 
+  @ffi.Uint32()
+  external int stored_width;
+
+  @ffi.Uint32()
+  external int stored_height;
+
+  @ffi.Int32()
+  external int rotation;
+
+  external ffi.Pointer<ffi.Double> avg_frame_rate;
+
+  external ffi.Pointer<ffi.Double> real_frame_rate;
+
+  @ffi.Bool()
+  external bool is_vfr;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pixel_format;
+
+  external ffi.Pointer<ffi.Uint32> bit_depth;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_primaries;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_transfer;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_matrix;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color_range;
+
+  external ffi.Pointer<ffi.Int32> hdr_format;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> profile;
+
+  external ffi.Pointer<ffi.Int32> level;
+
+  external ffi.Pointer<ffi.Uint64> frame_count;
+
+  external ffi.Pointer<wire_cst_audio_stream_info> audio;
+
   external ffi.Pointer<wire_cst_list_resolution_preset> suggestions;
 }
@@ -28,720 +28,3234 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<String> dco_decode_StreamSink_String_Dco(dynamic raw);
 
   @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
+  RustStreamSink<CompressProgress> dco_decode_StreamSink_compress_progress_Dco(
+    dynamic raw,
+  );
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
+  RustStreamSink<ExtractedFrame> dco_decode_StreamSink_extracted_frame_Dco(
+    dynamic raw,
+  );
 
   @protected
-  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw);
+  RustStreamSink<SceneDetectionEvent>
+  dco_decode_StreamSink_scene_detection_event_Dco(dynamic raw);
 
   @protected
-  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
+  RustStreamSink<ThumbnailData> dco_decode_StreamSink_thumbnail_data_Dco(
     dynamic raw,
   );
 
   @protected
-  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw);
+  String dco_decode_String(dynamic raw);
 
   @protected
-  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw);
+  AnimationFormat dco_decode_animation_format(dynamic raw);
 
   @protected
-  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw);
+  AnimationOptions dco_decode_animation_options(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+  AspectMode dco_decode_aspect_mode(dynamic raw);
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+  AudioChannels dco_decode_audio_channels(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw);
+  AudioExtractOptions dco_decode_audio_extract_options(dynamic raw);
 
   @protected
-  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
-    dynamic raw,
-  );
+  AudioFormat dco_decode_audio_format(dynamic raw);
 
   @protected
-  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw);
+  AudioMixParams dco_decode_audio_mix_params(dynamic raw);
 
   @protected
-  CompressParams dco_decode_compress_params(dynamic raw);
+  AudioStreamInfo dco_decode_audio_stream_info(dynamic raw);
 
   @protected
-  CompressionEstimate dco_decode_compression_estimate(dynamic raw);
+  AudioStreamProbe dco_decode_audio_stream_probe(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  AudioTrackMode dco_decode_audio_track_mode(dynamic raw);
 
   @protected
-  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw);
+  BestThumbnail dco_decode_best_thumbnail(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  BestThumbnailParams dco_decode_best_thumbnail_params(dynamic raw);
 
   @protected
-  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw);
+  bool dco_decode_bool(dynamic raw);
 
   @protected
-  LogLevel dco_decode_log_level(dynamic raw);
+  AnimationOptions dco_decode_box_autoadd_animation_options(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  AspectMode dco_decode_box_autoadd_aspect_mode(dynamic raw);
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+  AudioChannels dco_decode_box_autoadd_audio_channels(dynamic raw);
 
   @protected
-  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
-    dynamic raw,
-  );
+  AudioExtractOptions dco_decode_box_autoadd_audio_extract_options(dynamic raw);
 
   @protected
-  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw);
+  AudioMixParams dco_decode_box_autoadd_audio_mix_params(dynamic raw);
 
   @protected
-  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
-    dynamic raw,
-  );
+  AudioStreamInfo dco_decode_box_autoadd_audio_stream_info(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  AudioStreamProbe dco_decode_box_autoadd_audio_stream_probe(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  AudioTrackMode dco_decode_box_autoadd_audio_track_mode(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+  BestThumbnailParams dco_decode_box_autoadd_best_thumbnail_params(dynamic raw);
 
   @protected
-  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw);
+  bool dco_decode_box_autoadd_bool(dynamic raw);
 
   @protected
-  OutputFormat dco_decode_output_format(dynamic raw);
+  CompressParams dco_decode_box_autoadd_compress_params(dynamic raw);
 
   @protected
-  (int, int) dco_decode_record_u_32_u_32(dynamic raw);
+  CompressProgress dco_decode_box_autoadd_compress_progress(dynamic raw);
 
   @protected
-  ResolutionPreset dco_decode_resolution_preset(dynamic raw);
+  ConcatParams dco_decode_box_autoadd_concat_params(dynamic raw);
 
   @protected
-  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw);
+  CropRect dco_decode_box_autoadd_crop_rect(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  DuckingParams dco_decode_box_autoadd_ducking_params(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  EncoderOptions dco_decode_box_autoadd_encoder_options(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  double dco_decode_box_autoadd_f_32(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  double dco_decode_box_autoadd_f_64(dynamic raw);
 
   @protected
-  VideoInfo dco_decode_video_info(dynamic raw);
+  FrameExtractParams dco_decode_box_autoadd_frame_extract_params(dynamic raw);
 
   @protected
-  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw);
+  GpsLocation dco_decode_box_autoadd_gps_location(dynamic raw);
 
   @protected
-  WriteToFiles dco_decode_write_to_files(dynamic raw);
+  HashAlgorithm dco_decode_box_autoadd_hash_algorithm(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  HdrFormat dco_decode_box_autoadd_hdr_format(dynamic raw);
 
   @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_box_autoadd_i_32(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  ImageThumbnailParams dco_decode_box_autoadd_image_thumbnail_params(
+    dynamic raw,
+  );
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+  LoudnessNormalization dco_decode_box_autoadd_loudness_normalization(
+    dynamic raw,
+  );
 
   @protected
-  CompressParams sse_decode_box_autoadd_compress_params(
-    SseDeserializer deserializer,
-  );
+  MediaHash dco_decode_box_autoadd_media_hash(dynamic raw);
 
   @protected
-  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  OutputFormat dco_decode_box_autoadd_output_format(dynamic raw);
 
   @protected
-  OutputFormat sse_decode_box_autoadd_output_format(
-    SseDeserializer deserializer,
-  );
+  PaletteColor dco_decode_box_autoadd_palette_color(dynamic raw);
 
   @protected
-  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
-    SseDeserializer deserializer,
-  );
+  (int, int) dco_decode_box_autoadd_record_u_32_u_32(dynamic raw);
 
   @protected
-  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  ResizeMode dco_decode_box_autoadd_resize_mode(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  ReverseAudioMode dco_decode_box_autoadd_reverse_audio_mode(dynamic raw);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  ReverseParams dco_decode_box_autoadd_reverse_params(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+  SeekMode dco_decode_box_autoadd_seek_mode(dynamic raw);
 
   @protected
-  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  SpeedParams dco_decode_box_autoadd_speed_params(dynamic raw);
 
   @protected
-  WriteToFiles sse_decode_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  );
+  SubtitleParams dco_decode_box_autoadd_subtitle_params(dynamic raw);
 
   @protected
-  CompressParams sse_decode_compress_params(SseDeserializer deserializer);
+  ThumbnailSizeType dco_decode_box_autoadd_thumbnail_size_type(dynamic raw);
 
   @protected
-  CompressionEstimate sse_decode_compression_estimate(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_box_autoadd_u_16(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  ImageThumbnailParams sse_decode_image_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  int dco_decode_box_autoadd_u_8(dynamic raw);
 
   @protected
-  List<ResolutionPreset> sse_decode_list_resolution_preset(
-    SseDeserializer deserializer,
+  VideoStreamProbe dco_decode_box_autoadd_video_stream_probe(dynamic raw);
+
+  @protected
+  VideoThumbnailParams dco_decode_box_autoadd_video_thumbnail_params(
+    dynamic raw,
   );
 
   @protected
-  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+  WatermarkParams dco_decode_box_autoadd_watermark_params(dynamic raw);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  WatermarkPosition dco_decode_box_autoadd_watermark_position(dynamic raw);
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+  WriteToFiles dco_decode_box_autoadd_write_to_files(dynamic raw);
 
   @protected
-  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  CompressParams dco_decode_compress_params(dynamic raw);
 
   @protected
-  OutputFormat? sse_decode_opt_box_autoadd_output_format(
-    SseDeserializer deserializer,
-  );
+  CompressProgress dco_decode_compress_progress(dynamic raw);
 
   @protected
-  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  CompressionEstimate dco_decode_compression_estimate(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  ConcatParams dco_decode_concat_params(dynamic raw);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  CropRect dco_decode_crop_rect(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+  DuckingParams dco_decode_ducking_params(dynamic raw);
 
   @protected
-  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
-    SseDeserializer deserializer,
-  );
+  EncoderOptions dco_decode_encoder_options(dynamic raw);
 
   @protected
-  OutputFormat sse_decode_output_format(SseDeserializer deserializer);
+  ExtractedFrame dco_decode_extracted_frame(dynamic raw);
 
   @protected
-  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer);
+  double dco_decode_f_32(dynamic raw);
 
   @protected
-  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer);
+  double dco_decode_f_64(dynamic raw);
 
   @protected
-  ThumbnailSizeType sse_decode_thumbnail_size_type(
-    SseDeserializer deserializer,
-  );
+  FrameExtractParams dco_decode_frame_extract_params(dynamic raw);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  FrameSelection dco_decode_frame_selection(dynamic raw);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  GpsLocation dco_decode_gps_location(dynamic raw);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  HashAlgorithm dco_decode_hash_algorithm(dynamic raw);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  HashComparison dco_decode_hash_comparison(dynamic raw);
 
   @protected
-  VideoInfo sse_decode_video_info(SseDeserializer deserializer);
+  HdrFormat dco_decode_hdr_format(dynamic raw);
 
   @protected
-  VideoThumbnailParams sse_decode_video_thumbnail_params(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  String cst_encode_AnyhowException(AnyhowException raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  ImageInfo dco_decode_image_info(dynamic raw);
 
   @protected
-  String cst_encode_StreamSink_String_Dco(RustStreamSink<String> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_String(
-      raw.setupAndSerialize(
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
-        ),
-      ),
-    );
-  }
+  ImageThumbnailParams dco_decode_image_thumbnail_params(dynamic raw);
 
   @protected
-  String cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  bool cst_encode_box_autoadd_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_bool(raw);
-  }
+  List<MediaChapter> dco_decode_list_media_chapter(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_compress_params(CompressParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_compress_params(raw);
-  }
+  List<MediaTag> dco_decode_list_media_tag(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_image_thumbnail_params(raw);
-  }
+  List<PaletteColor> dco_decode_list_palette_color(dynamic raw);
 
   @protected
-  int cst_encode_box_autoadd_output_format(OutputFormat raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_output_format(raw);
-  }
+  Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_record_u_32_u_32((int, int) raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_record_u_32_u_32(raw);
-  }
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_thumbnail_size_type(ThumbnailSizeType raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_thumbnail_size_type(raw);
-  }
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  int cst_encode_box_autoadd_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_32(raw);
-  }
+  List<ResolutionPreset> dco_decode_list_resolution_preset(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_64(raw);
-  }
+  List<SceneCut> dco_decode_list_scene_cut(dynamic raw);
 
   @protected
-  int cst_encode_box_autoadd_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_8(raw);
-  }
+  List<StreamProbe> dco_decode_list_stream_probe(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_video_thumbnail_params(
-    VideoThumbnailParams raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_video_thumbnail_params(raw);
-  }
+  List<StreamSideData> dco_decode_list_stream_side_data(dynamic raw);
 
   @protected
-  JSAny cst_encode_box_autoadd_write_to_files(WriteToFiles raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_write_to_files(raw);
-  }
+  LogLevel dco_decode_log_level(dynamic raw);
 
   @protected
-  JSAny cst_encode_compress_params(CompressParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_u_32(raw.targetBitrateKbps),
-      cst_encode_opt_String(raw.preset),
-      cst_encode_opt_box_autoadd_u_8(raw.crf),
-      cst_encode_opt_box_autoadd_u_32(raw.width),
-      cst_encode_opt_box_autoadd_u_32(raw.height),
-      cst_encode_opt_box_autoadd_u_64(raw.sampleDurationMs),
-    ].jsify()!;
-  }
+  LoudnessInfo dco_decode_loudness_info(dynamic raw);
 
   @protected
-  JSAny cst_encode_compression_estimate(CompressionEstimate raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_u_64(raw.estimatedSizeBytes),
-      cst_encode_u_64(raw.estimatedDurationMs),
-    ].jsify()!;
-  }
+  LoudnessNormalization dco_decode_loudness_normalization(dynamic raw);
 
   @protected
-  JSAny cst_encode_image_thumbnail_params(ImageThumbnailParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
-      cst_encode_opt_box_autoadd_output_format(raw.format),
-    ].jsify()!;
-  }
+  MediaChapter dco_decode_media_chapter(dynamic raw);
 
   @protected
-  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
-  }
+  MediaHash dco_decode_media_hash(dynamic raw);
 
   @protected
-  JSAny cst_encode_list_resolution_preset(List<ResolutionPreset> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_resolution_preset).toList().jsify()!;
-  }
+  MediaProbe dco_decode_media_probe(dynamic raw);
 
   @protected
-  String? cst_encode_opt_String(String? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+  MediaTag dco_decode_media_tag(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AspectMode? dco_decode_opt_box_autoadd_aspect_mode(dynamic raw);
+
+  @protected
+  AudioChannels? dco_decode_opt_box_autoadd_audio_channels(dynamic raw);
+
+  @protected
+  AudioStreamInfo? dco_decode_opt_box_autoadd_audio_stream_info(dynamic raw);
+
+  @protected
+  AudioStreamProbe? dco_decode_opt_box_autoadd_audio_stream_probe(dynamic raw);
+
+  @protected
+  AudioTrackMode? dco_decode_opt_box_autoadd_audio_track_mode(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CropRect? dco_decode_opt_box_autoadd_crop_rect(dynamic raw);
+
+  @protected
+  DuckingParams? dco_decode_opt_box_autoadd_ducking_params(dynamic raw);
+
+  @protected
+  EncoderOptions? dco_decode_opt_box_autoadd_encoder_options(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  GpsLocation? dco_decode_opt_box_autoadd_gps_location(dynamic raw);
+
+  @protected
+  HashAlgorithm? dco_decode_opt_box_autoadd_hash_algorithm(dynamic raw);
+
+  @protected
+  HdrFormat? dco_decode_opt_box_autoadd_hdr_format(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImageThumbnailParams? dco_decode_opt_box_autoadd_image_thumbnail_params(
+    dynamic raw,
+  );
+
+  @protected
+  LoudnessNormalization? dco_decode_opt_box_autoadd_loudness_normalization(
+    dynamic raw,
+  );
+
+  @protected
+  OutputFormat? dco_decode_opt_box_autoadd_output_format(dynamic raw);
+
+  @protected
+  PaletteColor? dco_decode_opt_box_autoadd_palette_color(dynamic raw);
+
+  @protected
+  (int, int)? dco_decode_opt_box_autoadd_record_u_32_u_32(dynamic raw);
+
+  @protected
+  ResizeMode? dco_decode_opt_box_autoadd_resize_mode(dynamic raw);
+
+  @protected
+  ReverseAudioMode? dco_decode_opt_box_autoadd_reverse_audio_mode(dynamic raw);
+
+  @protected
+  SeekMode? dco_decode_opt_box_autoadd_seek_mode(dynamic raw);
+
+  @protected
+  SpeedParams? dco_decode_opt_box_autoadd_speed_params(dynamic raw);
+
+  @protected
+  SubtitleParams? dco_decode_opt_box_autoadd_subtitle_params(dynamic raw);
+
+  @protected
+  ThumbnailSizeType? dco_decode_opt_box_autoadd_thumbnail_size_type(
+    dynamic raw,
+  );
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+  @protected
+  VideoStreamProbe? dco_decode_opt_box_autoadd_video_stream_probe(dynamic raw);
+
+  @protected
+  WatermarkParams? dco_decode_opt_box_autoadd_watermark_params(dynamic raw);
+
+  @protected
+  WatermarkPosition? dco_decode_opt_box_autoadd_watermark_position(dynamic raw);
+
+  @protected
+  WriteToFiles? dco_decode_opt_box_autoadd_write_to_files(dynamic raw);
+
+  @protected
+  OutputFormat dco_decode_output_format(dynamic raw);
+
+  @protected
+  Palette dco_decode_palette(dynamic raw);
+
+  @protected
+  PaletteColor dco_decode_palette_color(dynamic raw);
+
+  @protected
+  PlaceholderImage dco_decode_placeholder_image(dynamic raw);
+
+  @protected
+  (int, int) dco_decode_record_u_32_u_32(dynamic raw);
+
+  @protected
+  ResizeMode dco_decode_resize_mode(dynamic raw);
+
+  @protected
+  ResolutionPreset dco_decode_resolution_preset(dynamic raw);
+
+  @protected
+  ReverseAudioMode dco_decode_reverse_audio_mode(dynamic raw);
+
+  @protected
+  ReverseParams dco_decode_reverse_params(dynamic raw);
+
+  @protected
+  SceneCut dco_decode_scene_cut(dynamic raw);
+
+  @protected
+  SceneDetectionEvent dco_decode_scene_detection_event(dynamic raw);
+
+  @protected
+  SeekMode dco_decode_seek_mode(dynamic raw);
+
+  @protected
+  SpeedParams dco_decode_speed_params(dynamic raw);
+
+  @protected
+  Storyboard dco_decode_storyboard(dynamic raw);
+
+  @protected
+  StreamDisposition dco_decode_stream_disposition(dynamic raw);
+
+  @protected
+  StreamProbe dco_decode_stream_probe(dynamic raw);
+
+  @protected
+  StreamSideData dco_decode_stream_side_data(dynamic raw);
+
+  @protected
+  StreamType dco_decode_stream_type(dynamic raw);
+
+  @protected
+  SubtitleParams dco_decode_subtitle_params(dynamic raw);
+
+  @protected
+  ThumbnailData dco_decode_thumbnail_data(dynamic raw);
+
+  @protected
+  ThumbnailSizeType dco_decode_thumbnail_size_type(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  VideoInfo dco_decode_video_info(dynamic raw);
+
+  @protected
+  VideoStreamProbe dco_decode_video_stream_probe(dynamic raw);
+
+  @protected
+  VideoThumbnailParams dco_decode_video_thumbnail_params(dynamic raw);
+
+  @protected
+  WatermarkParams dco_decode_watermark_params(dynamic raw);
+
+  @protected
+  WatermarkPosition dco_decode_watermark_position(dynamic raw);
+
+  @protected
+  WriteToFiles dco_decode_write_to_files(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<String> sse_decode_StreamSink_String_Dco(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<CompressProgress> sse_decode_StreamSink_compress_progress_Dco(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ExtractedFrame> sse_decode_StreamSink_extracted_frame_Dco(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<SceneDetectionEvent>
+  sse_decode_StreamSink_scene_detection_event_Dco(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ThumbnailData> sse_decode_StreamSink_thumbnail_data_Dco(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AnimationFormat sse_decode_animation_format(SseDeserializer deserializer);
+
+  @protected
+  AnimationOptions sse_decode_animation_options(SseDeserializer deserializer);
+
+  @protected
+  AspectMode sse_decode_aspect_mode(SseDeserializer deserializer);
+
+  @protected
+  AudioChannels sse_decode_audio_channels(SseDeserializer deserializer);
+
+  @protected
+  AudioExtractOptions sse_decode_audio_extract_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioFormat sse_decode_audio_format(SseDeserializer deserializer);
+
+  @protected
+  AudioMixParams sse_decode_audio_mix_params(SseDeserializer deserializer);
+
+  @protected
+  AudioStreamInfo sse_decode_audio_stream_info(SseDeserializer deserializer);
+
+  @protected
+  AudioStreamProbe sse_decode_audio_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  AudioTrackMode sse_decode_audio_track_mode(SseDeserializer deserializer);
+
+  @protected
+  BestThumbnail sse_decode_best_thumbnail(SseDeserializer deserializer);
+
+  @protected
+  BestThumbnailParams sse_decode_best_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AnimationOptions sse_decode_box_autoadd_animation_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AspectMode sse_decode_box_autoadd_aspect_mode(SseDeserializer deserializer);
+
+  @protected
+  AudioChannels sse_decode_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioExtractOptions sse_decode_box_autoadd_audio_extract_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioMixParams sse_decode_box_autoadd_audio_mix_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamInfo sse_decode_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamProbe sse_decode_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTrackMode sse_decode_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BestThumbnailParams sse_decode_box_autoadd_best_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CompressParams sse_decode_box_autoadd_compress_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CompressProgress sse_decode_box_autoadd_compress_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConcatParams sse_decode_box_autoadd_concat_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CropRect sse_decode_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DuckingParams sse_decode_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncoderOptions sse_decode_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrameExtractParams sse_decode_box_autoadd_frame_extract_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GpsLocation sse_decode_box_autoadd_gps_location(SseDeserializer deserializer);
+
+  @protected
+  HashAlgorithm sse_decode_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HdrFormat sse_decode_box_autoadd_hdr_format(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageThumbnailParams sse_decode_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoudnessNormalization sse_decode_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaHash sse_decode_box_autoadd_media_hash(SseDeserializer deserializer);
+
+  @protected
+  OutputFormat sse_decode_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaletteColor sse_decode_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (int, int) sse_decode_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResizeMode sse_decode_box_autoadd_resize_mode(SseDeserializer deserializer);
+
+  @protected
+  ReverseAudioMode sse_decode_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReverseParams sse_decode_box_autoadd_reverse_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode sse_decode_box_autoadd_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams sse_decode_box_autoadd_speed_params(SseDeserializer deserializer);
+
+  @protected
+  SubtitleParams sse_decode_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailSizeType sse_decode_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe sse_decode_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VideoThumbnailParams sse_decode_box_autoadd_video_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams sse_decode_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkPosition sse_decode_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriteToFiles sse_decode_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CompressParams sse_decode_compress_params(SseDeserializer deserializer);
+
+  @protected
+  CompressProgress sse_decode_compress_progress(SseDeserializer deserializer);
+
+  @protected
+  CompressionEstimate sse_decode_compression_estimate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConcatParams sse_decode_concat_params(SseDeserializer deserializer);
+
+  @protected
+  CropRect sse_decode_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DuckingParams sse_decode_ducking_params(SseDeserializer deserializer);
+
+  @protected
+  EncoderOptions sse_decode_encoder_options(SseDeserializer deserializer);
+
+  @protected
+  ExtractedFrame sse_decode_extracted_frame(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrameExtractParams sse_decode_frame_extract_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrameSelection sse_decode_frame_selection(SseDeserializer deserializer);
+
+  @protected
+  GpsLocation sse_decode_gps_location(SseDeserializer deserializer);
+
+  @protected
+  HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer);
+
+  @protected
+  HashComparison sse_decode_hash_comparison(SseDeserializer deserializer);
+
+  @protected
+  HdrFormat sse_decode_hdr_format(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageInfo sse_decode_image_info(SseDeserializer deserializer);
+
+  @protected
+  ImageThumbnailParams sse_decode_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<MediaChapter> sse_decode_list_media_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MediaTag> sse_decode_list_media_tag(SseDeserializer deserializer);
+
+  @protected
+  List<PaletteColor> sse_decode_list_palette_color(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ResolutionPreset> sse_decode_list_resolution_preset(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SceneCut> sse_decode_list_scene_cut(SseDeserializer deserializer);
+
+  @protected
+  List<StreamProbe> sse_decode_list_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  List<StreamSideData> sse_decode_list_stream_side_data(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  LoudnessInfo sse_decode_loudness_info(SseDeserializer deserializer);
+
+  @protected
+  LoudnessNormalization sse_decode_loudness_normalization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaChapter sse_decode_media_chapter(SseDeserializer deserializer);
+
+  @protected
+  MediaHash sse_decode_media_hash(SseDeserializer deserializer);
+
+  @protected
+  MediaProbe sse_decode_media_probe(SseDeserializer deserializer);
+
+  @protected
+  MediaTag sse_decode_media_tag(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AspectMode? sse_decode_opt_box_autoadd_aspect_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioChannels? sse_decode_opt_box_autoadd_audio_channels(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamInfo? sse_decode_opt_box_autoadd_audio_stream_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioStreamProbe? sse_decode_opt_box_autoadd_audio_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioTrackMode? sse_decode_opt_box_autoadd_audio_track_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CropRect? sse_decode_opt_box_autoadd_crop_rect(SseDeserializer deserializer);
+
+  @protected
+  DuckingParams? sse_decode_opt_box_autoadd_ducking_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncoderOptions? sse_decode_opt_box_autoadd_encoder_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  GpsLocation? sse_decode_opt_box_autoadd_gps_location(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HashAlgorithm? sse_decode_opt_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HdrFormat? sse_decode_opt_box_autoadd_hdr_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImageThumbnailParams? sse_decode_opt_box_autoadd_image_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoudnessNormalization? sse_decode_opt_box_autoadd_loudness_normalization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputFormat? sse_decode_opt_box_autoadd_output_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PaletteColor? sse_decode_opt_box_autoadd_palette_color(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (int, int)? sse_decode_opt_box_autoadd_record_u_32_u_32(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResizeMode? sse_decode_opt_box_autoadd_resize_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReverseAudioMode? sse_decode_opt_box_autoadd_reverse_audio_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode? sse_decode_opt_box_autoadd_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams? sse_decode_opt_box_autoadd_speed_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SubtitleParams? sse_decode_opt_box_autoadd_subtitle_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThumbnailSizeType? sse_decode_opt_box_autoadd_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe? sse_decode_opt_box_autoadd_video_stream_probe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams? sse_decode_opt_box_autoadd_watermark_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkPosition? sse_decode_opt_box_autoadd_watermark_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WriteToFiles? sse_decode_opt_box_autoadd_write_to_files(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutputFormat sse_decode_output_format(SseDeserializer deserializer);
+
+  @protected
+  Palette sse_decode_palette(SseDeserializer deserializer);
+
+  @protected
+  PaletteColor sse_decode_palette_color(SseDeserializer deserializer);
+
+  @protected
+  PlaceholderImage sse_decode_placeholder_image(SseDeserializer deserializer);
+
+  @protected
+  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer);
+
+  @protected
+  ResizeMode sse_decode_resize_mode(SseDeserializer deserializer);
+
+  @protected
+  ResolutionPreset sse_decode_resolution_preset(SseDeserializer deserializer);
+
+  @protected
+  ReverseAudioMode sse_decode_reverse_audio_mode(SseDeserializer deserializer);
+
+  @protected
+  ReverseParams sse_decode_reverse_params(SseDeserializer deserializer);
+
+  @protected
+  SceneCut sse_decode_scene_cut(SseDeserializer deserializer);
+
+  @protected
+  SceneDetectionEvent sse_decode_scene_detection_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SeekMode sse_decode_seek_mode(SseDeserializer deserializer);
+
+  @protected
+  SpeedParams sse_decode_speed_params(SseDeserializer deserializer);
+
+  @protected
+  Storyboard sse_decode_storyboard(SseDeserializer deserializer);
+
+  @protected
+  StreamDisposition sse_decode_stream_disposition(SseDeserializer deserializer);
+
+  @protected
+  StreamProbe sse_decode_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  StreamSideData sse_decode_stream_side_data(SseDeserializer deserializer);
+
+  @protected
+  StreamType sse_decode_stream_type(SseDeserializer deserializer);
+
+  @protected
+  SubtitleParams sse_decode_subtitle_params(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailData sse_decode_thumbnail_data(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailSizeType sse_decode_thumbnail_size_type(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  VideoInfo sse_decode_video_info(SseDeserializer deserializer);
+
+  @protected
+  VideoStreamProbe sse_decode_video_stream_probe(SseDeserializer deserializer);
+
+  @protected
+  VideoThumbnailParams sse_decode_video_thumbnail_params(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatermarkParams sse_decode_watermark_params(SseDeserializer deserializer);
+
+  @protected
+  WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer);
+
+  @protected
+  WriteToFiles sse_decode_write_to_files(SseDeserializer deserializer);
+
+  @protected
+  String cst_encode_AnyhowException(AnyhowException raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String cst_encode_StreamSink_String_Dco(RustStreamSink<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_compress_progress_Dco(
+    RustStreamSink<CompressProgress> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_compress_progress,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_extracted_frame_Dco(
+    RustStreamSink<ExtractedFrame> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_extracted_frame,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_scene_detection_event_Dco(
+    RustStreamSink<SceneDetectionEvent> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_scene_detection_event,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_thumbnail_data_Dco(
+    RustStreamSink<ThumbnailData> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_thumbnail_data,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_String(String raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  JSAny cst_encode_animation_options(AnimationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_64(raw.maxBytes),
+      cst_encode_opt_box_autoadd_u_16(raw.loopCount),
+      cst_encode_opt_box_autoadd_bool(raw.dither),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_audio_extract_options(AudioExtractOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_64(raw.startMs),
+      cst_encode_opt_box_autoadd_u_64(raw.endMs),
+      cst_encode_opt_box_autoadd_audio_channels(raw.channels),
+      cst_encode_opt_box_autoadd_u_32(raw.sampleRate),
+      cst_encode_opt_box_autoadd_u_32(raw.bitrateKbps),
+      cst_encode_opt_box_autoadd_loudness_normalization(raw.normalizeLoudness),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_audio_mix_params(AudioMixParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_audio_track_mode(raw.mode),
+      cst_encode_opt_box_autoadd_f_32(raw.musicGainDb),
+      cst_encode_opt_box_autoadd_f_32(raw.originalGainDb),
+      cst_encode_opt_box_autoadd_ducking_params(raw.ducking),
+      cst_encode_bool(raw.loopMusic),
+      cst_encode_opt_box_autoadd_u_64(raw.musicStartMs),
+      cst_encode_opt_box_autoadd_u_64(raw.fadeInMs),
+      cst_encode_opt_box_autoadd_u_64(raw.fadeOutMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_audio_stream_info(AudioStreamInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_String(raw.codecName),
+      cst_encode_u_32(raw.channels),
+      cst_encode_u_32(raw.sampleRate),
+      cst_encode_opt_box_autoadd_u_64(raw.bitrate),
+      cst_encode_opt_String(raw.language),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_audio_stream_probe(AudioStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.sampleRate),
+      cst_encode_u_32(raw.channels),
+      cst_encode_opt_String(raw.channelLayout),
+      cst_encode_opt_String(raw.sampleFormat),
+      cst_encode_opt_box_autoadd_u_32(raw.bitsPerSample),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_best_thumbnail(BestThumbnail raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_prim_u_8_strict(raw.data),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_u_64(raw.timeMs),
+      cst_encode_f_64(raw.score),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_best_thumbnail_params(BestThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
+      cst_encode_opt_box_autoadd_output_format(raw.format),
+      cst_encode_opt_box_autoadd_u_32(raw.candidates),
+      cst_encode_opt_box_autoadd_resize_mode(raw.resizeMode),
+      cst_encode_opt_box_autoadd_bool(raw.allowUpscale),
+      cst_encode_opt_box_autoadd_encoder_options(raw.encoderOptions),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_animation_options(AnimationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_animation_options(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_aspect_mode(AspectMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_aspect_mode(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_audio_channels(AudioChannels raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_channels(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_audio_extract_options(AudioExtractOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_extract_options(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_audio_mix_params(AudioMixParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_mix_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_audio_stream_info(AudioStreamInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_stream_info(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_audio_stream_probe(AudioStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_stream_probe(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_audio_track_mode(AudioTrackMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_audio_track_mode(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_best_thumbnail_params(BestThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_best_thumbnail_params(raw);
+  }
+
+  @protected
+  bool cst_encode_box_autoadd_bool(bool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_bool(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_compress_params(CompressParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_compress_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_compress_progress(CompressProgress raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_compress_progress(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_concat_params(ConcatParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_concat_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_crop_rect(CropRect raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_crop_rect(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_ducking_params(DuckingParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_ducking_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_encoder_options(EncoderOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_encoder_options(raw);
+  }
+
+  @protected
+  double cst_encode_box_autoadd_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_f_32(raw);
+  }
+
+  @protected
+  double cst_encode_box_autoadd_f_64(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_f_64(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_frame_extract_params(FrameExtractParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_frame_extract_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_gps_location(GpsLocation raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_gps_location(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_hash_algorithm(HashAlgorithm raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_hash_algorithm(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_hdr_format(HdrFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_hdr_format(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_image_thumbnail_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_loudness_normalization(
+    LoudnessNormalization raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_loudness_normalization(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_media_hash(MediaHash raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_media_hash(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_output_format(OutputFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_output_format(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_palette_color(PaletteColor raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_palette_color(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_record_u_32_u_32((int, int) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_record_u_32_u_32(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_resize_mode(ResizeMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_resize_mode(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_reverse_audio_mode(ReverseAudioMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_reverse_audio_mode(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_reverse_params(ReverseParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_reverse_params(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_seek_mode(SeekMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_seek_mode(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_speed_params(SpeedParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_speed_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_subtitle_params(SubtitleParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_subtitle_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_thumbnail_size_type(ThumbnailSizeType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_thumbnail_size_type(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_u_16(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_16(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_32(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_64(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_u_8(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_8(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_video_stream_probe(VideoStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_video_stream_probe(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_video_thumbnail_params(
+    VideoThumbnailParams raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_video_thumbnail_params(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_watermark_params(WatermarkParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_watermark_params(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_watermark_position(WatermarkPosition raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_watermark_position(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_write_to_files(WriteToFiles raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_write_to_files(raw);
+  }
+
+  @protected
+  JSAny cst_encode_compress_params(CompressParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.targetBitrateKbps),
+      cst_encode_opt_String(raw.preset),
+      cst_encode_opt_box_autoadd_u_8(raw.crf),
+      cst_encode_opt_box_autoadd_u_32(raw.width),
+      cst_encode_opt_box_autoadd_u_32(raw.height),
+      cst_encode_opt_box_autoadd_u_64(raw.sampleDurationMs),
+      cst_encode_opt_box_autoadd_crop_rect(raw.crop),
+      cst_encode_opt_box_autoadd_record_u_32_u_32(raw.aspectRatio),
+      cst_encode_opt_box_autoadd_aspect_mode(raw.aspectMode),
+      cst_encode_opt_box_autoadd_u_32(raw.padColor),
+      cst_encode_opt_box_autoadd_watermark_params(raw.watermark),
+      cst_encode_opt_box_autoadd_subtitle_params(raw.subtitles),
+      cst_encode_opt_box_autoadd_speed_params(raw.speed),
+      cst_encode_opt_box_autoadd_loudness_normalization(raw.normalizeLoudness),
+      cst_encode_opt_String(raw.videoFilters),
+      cst_encode_opt_String(raw.audioFilters),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_compress_progress(CompressProgress raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_64(raw.processedMs),
+      cst_encode_u_64(raw.totalMs),
+      cst_encode_f_32(raw.speedX),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_compression_estimate(CompressionEstimate raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_64(raw.estimatedSizeBytes),
+      cst_encode_u_64(raw.estimatedDurationMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_concat_params(ConcatParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_32(raw.width),
+      cst_encode_opt_box_autoadd_u_32(raw.height),
+      cst_encode_opt_box_autoadd_u_32(raw.fps),
+      cst_encode_opt_box_autoadd_u_32(raw.targetBitrateKbps),
+      cst_encode_opt_String(raw.preset),
+      cst_encode_opt_box_autoadd_u_8(raw.crf),
+      cst_encode_opt_box_autoadd_bool(raw.forceReencode),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_crop_rect(CropRect raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.x),
+      cst_encode_u_32(raw.y),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_ducking_params(DuckingParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_f_32(raw.thresholdDb),
+      cst_encode_opt_box_autoadd_f_32(raw.reductionDb),
+      cst_encode_opt_box_autoadd_u_32(raw.attackMs),
+      cst_encode_opt_box_autoadd_u_32(raw.releaseMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_encoder_options(EncoderOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_8(raw.jpegQuality),
+      cst_encode_opt_box_autoadd_bool(raw.jpegProgressive),
+      cst_encode_opt_box_autoadd_f_32(raw.webpQuality),
+      cst_encode_opt_box_autoadd_u_8(raw.pngCompression),
+      cst_encode_opt_box_autoadd_u_8(raw.avifQuality),
+      cst_encode_opt_box_autoadd_u_8(raw.heicQuality),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_extracted_frame(ExtractedFrame raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.path),
+      cst_encode_u_32(raw.index),
+      cst_encode_u_64(raw.requestedMs),
+      cst_encode_u_64(raw.timeMs),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_frame_extract_params(FrameExtractParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_frame_selection(raw.selection),
+      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
+      cst_encode_opt_box_autoadd_output_format(raw.format),
+      cst_encode_opt_box_autoadd_seek_mode(raw.seekMode),
+      cst_encode_opt_box_autoadd_resize_mode(raw.resizeMode),
+      cst_encode_opt_box_autoadd_bool(raw.allowUpscale),
+      cst_encode_opt_box_autoadd_encoder_options(raw.encoderOptions),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_frame_selection(FrameSelection raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is FrameSelection_Timestamps) {
+      return [0, cst_encode_list_prim_u_64_strict(raw.field0)].jsify()!;
+    }
+    if (raw is FrameSelection_Interval) {
+      return [1, cst_encode_u_64(raw.field0)].jsify()!;
+    }
+    if (raw is FrameSelection_Count) {
+      return [2, cst_encode_u_32(raw.field0)].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
+  @protected
+  JSAny cst_encode_gps_location(GpsLocation raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_f_64(raw.latitude),
+      cst_encode_f_64(raw.longitude),
+      cst_encode_opt_box_autoadd_f_64(raw.altitude),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_hash_comparison(HashComparison raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.distance),
+      cst_encode_f_64(raw.similarity),
+      cst_encode_bool(raw.isSimilar),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return castNativeBigInt(raw);
+  }
+
+  @protected
+  JSAny cst_encode_image_info(ImageInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_String(raw.format),
+      cst_encode_u_32(raw.bitDepth),
+      cst_encode_bool(raw.hasAlpha),
+      cst_encode_u_32(raw.orientation),
+      cst_encode_opt_String(raw.cameraMake),
+      cst_encode_opt_String(raw.cameraModel),
+      cst_encode_opt_String(raw.captureDate),
+      cst_encode_opt_box_autoadd_gps_location(raw.gps),
+      cst_encode_opt_String(raw.iccProfileName),
+      cst_encode_u_32(raw.imageCount),
+      cst_encode_u_32(raw.auxiliaryImageCount),
+      cst_encode_bool(raw.hasDepth),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_image_thumbnail_params(ImageThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
+      cst_encode_opt_box_autoadd_output_format(raw.format),
+      cst_encode_opt_box_autoadd_seek_mode(raw.seekMode),
+      cst_encode_opt_box_autoadd_resize_mode(raw.resizeMode),
+      cst_encode_opt_box_autoadd_bool(raw.allowUpscale),
+      cst_encode_opt_box_autoadd_encoder_options(raw.encoderOptions),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_String).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_media_chapter(List<MediaChapter> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_media_chapter).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_media_tag(List<MediaTag> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_media_tag).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_palette_color(List<PaletteColor> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_palette_color).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_u_64_strict(Uint64List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_u_8_loose(List<int> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_resolution_preset(List<ResolutionPreset> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_resolution_preset).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_scene_cut(List<SceneCut> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_scene_cut).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_stream_probe(List<StreamProbe> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_stream_probe).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_stream_side_data(List<StreamSideData> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_stream_side_data).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_loudness_info(LoudnessInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_f_64(raw.integratedLufs),
+      cst_encode_f_64(raw.loudnessRangeLu),
+      cst_encode_f_64(raw.truePeakDbtp),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_loudness_normalization(LoudnessNormalization raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_f_32(raw.targetLufs),
+      cst_encode_opt_box_autoadd_f_32(raw.truePeakDbtp),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_media_chapter(MediaChapter raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_i_64(raw.id),
+      cst_encode_u_64(raw.startMs),
+      cst_encode_u_64(raw.endMs),
+      cst_encode_opt_String(raw.title),
+      cst_encode_list_media_tag(raw.tags),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_media_hash(MediaHash raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_hash_algorithm(raw.algorithm),
+      cst_encode_list_prim_u_64_strict(raw.hashes),
+      cst_encode_list_prim_u_64_strict(raw.timesMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_media_probe(MediaProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.formatName),
+      cst_encode_opt_String(raw.formatLongName),
+      cst_encode_opt_box_autoadd_u_64(raw.durationMs),
+      cst_encode_opt_box_autoadd_i_64(raw.startTimeMs),
+      cst_encode_opt_box_autoadd_u_64(raw.bitrate),
+      cst_encode_u_64(raw.sizeBytes),
+      cst_encode_list_media_tag(raw.tags),
+      cst_encode_list_media_chapter(raw.chapters),
+      cst_encode_list_stream_probe(raw.streams),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_media_tag(MediaTag raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [cst_encode_String(raw.key), cst_encode_String(raw.value)].jsify()!;
+  }
+
+  @protected
+  String? cst_encode_opt_String(String? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? null : cst_encode_String(raw);
   }
 
   @protected
-  bool? cst_encode_opt_box_autoadd_bool(bool? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_bool(raw);
-  }
+  int? cst_encode_opt_box_autoadd_aspect_mode(AspectMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_aspect_mode(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_audio_channels(AudioChannels? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_audio_channels(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_audio_stream_info(AudioStreamInfo? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_audio_stream_info(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_audio_stream_probe(AudioStreamProbe? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_audio_stream_probe(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_audio_track_mode(AudioTrackMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_audio_track_mode(raw);
+  }
+
+  @protected
+  bool? cst_encode_opt_box_autoadd_bool(bool? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_crop_rect(CropRect? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_crop_rect(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_ducking_params(DuckingParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_ducking_params(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_encoder_options(EncoderOptions? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_encoder_options(raw);
+  }
+
+  @protected
+  double? cst_encode_opt_box_autoadd_f_32(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? cst_encode_opt_box_autoadd_f_64(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_gps_location(GpsLocation? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_gps_location(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_hash_algorithm(HashAlgorithm? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_hash_algorithm(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_hdr_format(HdrFormat? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_hdr_format(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_i_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? null
+        : cst_encode_box_autoadd_image_thumbnail_params(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_loudness_normalization(
+    LoudnessNormalization? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? null
+        : cst_encode_box_autoadd_loudness_normalization(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_output_format(OutputFormat? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_output_format(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_palette_color(PaletteColor? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_palette_color(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_record_u_32_u_32((int, int)? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_record_u_32_u_32(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_resize_mode(ResizeMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_resize_mode(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_reverse_audio_mode(ReverseAudioMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_reverse_audio_mode(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_seek_mode(SeekMode? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_seek_mode(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_speed_params(SpeedParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_speed_params(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_subtitle_params(SubtitleParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_subtitle_params(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_thumbnail_size_type(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_u_16(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_u_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_u_8(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_video_stream_probe(VideoStreamProbe? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_video_stream_probe(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_watermark_params(WatermarkParams? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_watermark_params(raw);
+  }
+
+  @protected
+  int? cst_encode_opt_box_autoadd_watermark_position(WatermarkPosition? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_watermark_position(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_write_to_files(WriteToFiles? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_write_to_files(raw);
+  }
+
+  @protected
+  JSAny cst_encode_palette(Palette raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_palette_color(raw.colors),
+      cst_encode_palette_color(raw.average),
+      cst_encode_opt_box_autoadd_palette_color(raw.vibrant),
+      cst_encode_opt_box_autoadd_palette_color(raw.lightVibrant),
+      cst_encode_opt_box_autoadd_palette_color(raw.darkVibrant),
+      cst_encode_opt_box_autoadd_palette_color(raw.muted),
+      cst_encode_opt_box_autoadd_palette_color(raw.lightMuted),
+      cst_encode_opt_box_autoadd_palette_color(raw.darkMuted),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_palette_color(PaletteColor raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.color),
+      cst_encode_f_64(raw.population),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_placeholder_image(PlaceholderImage raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_prim_u_8_strict(raw.rgba),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_record_u_32_u_32((int, int) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [cst_encode_u_32(raw.$1), cst_encode_u_32(raw.$2)].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_resolution_preset(ResolutionPreset raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.name),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_u_64(raw.bitrate),
+      cst_encode_u_8(raw.crf),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_reverse_params(ReverseParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_64(raw.startMs),
+      cst_encode_opt_box_autoadd_u_64(raw.endMs),
+      cst_encode_opt_box_autoadd_u_32(raw.boomerangLoops),
+      cst_encode_opt_box_autoadd_reverse_audio_mode(raw.audio),
+      cst_encode_opt_box_autoadd_u_32(raw.width),
+      cst_encode_opt_box_autoadd_u_32(raw.height),
+      cst_encode_opt_box_autoadd_u_32(raw.targetBitrateKbps),
+      cst_encode_opt_String(raw.preset),
+      cst_encode_opt_box_autoadd_u_8(raw.crf),
+      cst_encode_opt_box_autoadd_u_32(raw.memoryBudgetMb),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_scene_cut(SceneCut raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [cst_encode_u_64(raw.timeMs), cst_encode_f_64(raw.score)].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_scene_detection_event(SceneDetectionEvent raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is SceneDetectionEvent_Progress) {
+      return [0, cst_encode_box_autoadd_compress_progress(raw.field0)].jsify()!;
+    }
+    if (raw is SceneDetectionEvent_Cuts) {
+      return [1, cst_encode_list_scene_cut(raw.field0)].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
+  @protected
+  JSAny cst_encode_speed_params(SpeedParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_f_32(raw.factor),
+      cst_encode_opt_box_autoadd_u_32(raw.frameRate),
+      cst_encode_opt_box_autoadd_f_32(raw.muteAudioAbove),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_storyboard(Storyboard raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.vttPath),
+      cst_encode_list_String(raw.spritePaths),
+      cst_encode_u_32(raw.tileCount),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_stream_disposition(StreamDisposition raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_bool(raw.default_),
+      cst_encode_bool(raw.dub),
+      cst_encode_bool(raw.original),
+      cst_encode_bool(raw.comment),
+      cst_encode_bool(raw.lyrics),
+      cst_encode_bool(raw.karaoke),
+      cst_encode_bool(raw.forced),
+      cst_encode_bool(raw.hearingImpaired),
+      cst_encode_bool(raw.visualImpaired),
+      cst_encode_bool(raw.cleanEffects),
+      cst_encode_bool(raw.attachedPic),
+      cst_encode_bool(raw.captions),
+      cst_encode_bool(raw.descriptions),
+      cst_encode_bool(raw.metadata),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_stream_probe(StreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.index),
+      cst_encode_stream_type(raw.streamType),
+      cst_encode_opt_String(raw.codecName),
+      cst_encode_opt_String(raw.codecLongName),
+      cst_encode_opt_String(raw.profile),
+      cst_encode_opt_box_autoadd_u_64(raw.bitrate),
+      cst_encode_opt_box_autoadd_i_64(raw.startTimeMs),
+      cst_encode_opt_box_autoadd_u_64(raw.durationMs),
+      cst_encode_opt_box_autoadd_u_64(raw.frameCount),
+      cst_encode_opt_String(raw.language),
+      cst_encode_opt_String(raw.title),
+      cst_encode_stream_disposition(raw.disposition),
+      cst_encode_list_media_tag(raw.tags),
+      cst_encode_list_stream_side_data(raw.sideData),
+      cst_encode_opt_box_autoadd_video_stream_probe(raw.video),
+      cst_encode_opt_box_autoadd_audio_stream_probe(raw.audio),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_stream_side_data(StreamSideData raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.kind),
+      cst_encode_u_32(raw.sizeBytes),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_subtitle_params(SubtitleParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.subtitlePath),
+      cst_encode_opt_String(raw.fontPath),
+      cst_encode_opt_box_autoadd_u_32(raw.fontSize),
+      cst_encode_opt_box_autoadd_u_32(raw.color),
+      cst_encode_opt_box_autoadd_u_32(raw.outlineColor),
+      cst_encode_opt_box_autoadd_u_32(raw.outlineWidth),
+      cst_encode_opt_box_autoadd_u_32(raw.bottomMargin),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_thumbnail_data(ThumbnailData raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_prim_u_8_strict(raw.data),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_opt_box_autoadd_u_64(raw.timeMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_thumbnail_size_type(ThumbnailSizeType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is ThumbnailSizeType_Icon) {
+      return [0].jsify()!;
+    }
+    if (raw is ThumbnailSizeType_Small) {
+      return [1].jsify()!;
+    }
+    if (raw is ThumbnailSizeType_Medium) {
+      return [2].jsify()!;
+    }
+    if (raw is ThumbnailSizeType_Large) {
+      return [3].jsify()!;
+    }
+    if (raw is ThumbnailSizeType_Larger) {
+      return [4].jsify()!;
+    }
+    if (raw is ThumbnailSizeType_Custom) {
+      return [5, cst_encode_box_autoadd_record_u_32_u_32(raw.field0)].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
+  @protected
+  JSAny cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return castNativeBigInt(raw);
+  }
+
+  @protected
+  JSAny cst_encode_video_info(VideoInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_64(raw.durationMs),
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_u_64(raw.sizeBytes),
+      cst_encode_opt_box_autoadd_u_64(raw.bitrate),
+      cst_encode_opt_String(raw.codecName),
+      cst_encode_opt_String(raw.formatName),
+      cst_encode_u_32(raw.storedWidth),
+      cst_encode_u_32(raw.storedHeight),
+      cst_encode_i_32(raw.rotation),
+      cst_encode_opt_box_autoadd_f_64(raw.avgFrameRate),
+      cst_encode_opt_box_autoadd_f_64(raw.realFrameRate),
+      cst_encode_bool(raw.isVfr),
+      cst_encode_opt_String(raw.pixelFormat),
+      cst_encode_opt_box_autoadd_u_32(raw.bitDepth),
+      cst_encode_opt_String(raw.colorPrimaries),
+      cst_encode_opt_String(raw.colorTransfer),
+      cst_encode_opt_String(raw.colorMatrix),
+      cst_encode_opt_String(raw.colorRange),
+      cst_encode_opt_box_autoadd_hdr_format(raw.hdrFormat),
+      cst_encode_opt_String(raw.profile),
+      cst_encode_opt_box_autoadd_i_32(raw.level),
+      cst_encode_opt_box_autoadd_u_64(raw.frameCount),
+      cst_encode_opt_box_autoadd_audio_stream_info(raw.audio),
+      cst_encode_list_resolution_preset(raw.suggestions),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_video_stream_probe(VideoStreamProbe raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.width),
+      cst_encode_u_32(raw.height),
+      cst_encode_u_32(raw.displayWidth),
+      cst_encode_u_32(raw.displayHeight),
+      cst_encode_i_32(raw.rotation),
+      cst_encode_opt_box_autoadd_f_64(raw.avgFrameRate),
+      cst_encode_opt_box_autoadd_f_64(raw.realFrameRate),
+      cst_encode_bool(raw.isVfr),
+      cst_encode_opt_String(raw.pixelFormat),
+      cst_encode_opt_box_autoadd_u_32(raw.bitDepth),
+      cst_encode_opt_String(raw.colorPrimaries),
+      cst_encode_opt_String(raw.colorTransfer),
+      cst_encode_opt_String(raw.colorMatrix),
+      cst_encode_opt_String(raw.colorRange),
+      cst_encode_opt_box_autoadd_hdr_format(raw.hdrFormat),
+      cst_encode_opt_box_autoadd_i_32(raw.level),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_video_thumbnail_params(VideoThumbnailParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_64(raw.timeMs),
+      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
+      cst_encode_opt_box_autoadd_output_format(raw.format),
+      cst_encode_opt_box_autoadd_seek_mode(raw.seekMode),
+      cst_encode_opt_box_autoadd_resize_mode(raw.resizeMode),
+      cst_encode_opt_box_autoadd_bool(raw.allowUpscale),
+      cst_encode_opt_box_autoadd_encoder_options(raw.encoderOptions),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_watermark_params(WatermarkParams raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.imagePath),
+      cst_encode_opt_box_autoadd_watermark_position(raw.position),
+      cst_encode_opt_box_autoadd_u_32(raw.margin),
+      cst_encode_opt_box_autoadd_f_32(raw.scale),
+      cst_encode_opt_box_autoadd_f_32(raw.opacity),
+      cst_encode_opt_box_autoadd_u_64(raw.startMs),
+      cst_encode_opt_box_autoadd_u_64(raw.endMs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_write_to_files(WriteToFiles raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.path),
+      cst_encode_String(raw.filePrefix),
+      cst_encode_opt_String(raw.fileSuffix),
+      cst_encode_opt_box_autoadd_u_64(raw.maxFiles),
+    ].jsify()!;
+  }
+
+  @protected
+  int cst_encode_animation_format(AnimationFormat raw);
+
+  @protected
+  int cst_encode_aspect_mode(AspectMode raw);
+
+  @protected
+  int cst_encode_audio_channels(AudioChannels raw);
+
+  @protected
+  int cst_encode_audio_format(AudioFormat raw);
+
+  @protected
+  int cst_encode_audio_track_mode(AudioTrackMode raw);
+
+  @protected
+  bool cst_encode_bool(bool raw);
+
+  @protected
+  double cst_encode_f_32(double raw);
+
+  @protected
+  double cst_encode_f_64(double raw);
+
+  @protected
+  int cst_encode_hash_algorithm(HashAlgorithm raw);
+
+  @protected
+  int cst_encode_hdr_format(HdrFormat raw);
+
+  @protected
+  int cst_encode_i_32(int raw);
+
+  @protected
+  int cst_encode_log_level(LogLevel raw);
+
+  @protected
+  int cst_encode_output_format(OutputFormat raw);
+
+  @protected
+  int cst_encode_resize_mode(ResizeMode raw);
+
+  @protected
+  int cst_encode_reverse_audio_mode(ReverseAudioMode raw);
+
+  @protected
+  int cst_encode_seek_mode(SeekMode raw);
+
+  @protected
+  int cst_encode_stream_type(StreamType raw);
+
+  @protected
+  int cst_encode_u_16(int raw);
+
+  @protected
+  int cst_encode_u_32(int raw);
+
+  @protected
+  int cst_encode_u_8(int raw);
+
+  @protected
+  void cst_encode_unit(void raw);
+
+  @protected
+  int cst_encode_watermark_position(WatermarkPosition raw);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_String_Dco(
+    RustStreamSink<String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_compress_progress_Dco(
+    RustStreamSink<CompressProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_extracted_frame_Dco(
+    RustStreamSink<ExtractedFrame> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_scene_detection_event_Dco(
+    RustStreamSink<SceneDetectionEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_thumbnail_data_Dco(
+    RustStreamSink<ThumbnailData> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_animation_format(
+    AnimationFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_aspect_mode(AspectMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_channels(AudioChannels self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_format(AudioFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_best_thumbnail(BestThumbnail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_animation_options(
+    AnimationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_aspect_mode(
+    AspectMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_channels(
+    AudioChannels self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_extract_options(
+    AudioExtractOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_mix_params(
+    AudioMixParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_info(
+    AudioStreamInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_stream_probe(
+    AudioStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audio_track_mode(
+    AudioTrackMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_best_thumbnail_params(
+    BestThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_concat_params(
+    ConcatParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_crop_rect(
+    CropRect self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_ducking_params(
+    DuckingParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_gps_location(
+    GpsLocation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hdr_format(
+    HdrFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny? cst_encode_opt_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? null
-        : cst_encode_box_autoadd_image_thumbnail_params(raw);
-  }
+  void sse_encode_box_autoadd_media_hash(
+    MediaHash self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? cst_encode_opt_box_autoadd_output_format(OutputFormat? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_output_format(raw);
-  }
+  void sse_encode_box_autoadd_output_format(
+    OutputFormat self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny? cst_encode_opt_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_thumbnail_size_type(raw);
-  }
+  void sse_encode_box_autoadd_palette_color(
+    PaletteColor self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? cst_encode_opt_box_autoadd_u_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_32(raw);
-  }
+  void sse_encode_box_autoadd_record_u_32_u_32(
+    (int, int) self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_64(raw);
-  }
+  void sse_encode_box_autoadd_resize_mode(
+    ResizeMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? cst_encode_opt_box_autoadd_u_8(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_8(raw);
-  }
+  void sse_encode_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny? cst_encode_opt_box_autoadd_write_to_files(WriteToFiles? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_write_to_files(raw);
-  }
+  void sse_encode_box_autoadd_reverse_params(
+    ReverseParams self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_record_u_32_u_32((int, int) raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_u_32(raw.$1), cst_encode_u_32(raw.$2)].jsify()!;
-  }
+  void sse_encode_box_autoadd_seek_mode(
+    SeekMode self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_resolution_preset(ResolutionPreset raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.name),
-      cst_encode_u_32(raw.width),
-      cst_encode_u_32(raw.height),
-      cst_encode_u_64(raw.bitrate),
-      cst_encode_u_8(raw.crf),
-    ].jsify()!;
-  }
+  void sse_encode_box_autoadd_speed_params(
+    SpeedParams self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_thumbnail_size_type(ThumbnailSizeType raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    if (raw is ThumbnailSizeType_Icon) {
-      return [0].jsify()!;
-    }
-    if (raw is ThumbnailSizeType_Small) {
-      return [1].jsify()!;
-    }
-    if (raw is ThumbnailSizeType_Medium) {
-      return [2].jsify()!;
-    }
-    if (raw is ThumbnailSizeType_Large) {
-      return [3].jsify()!;
-    }
-    if (raw is ThumbnailSizeType_Larger) {
-      return [4].jsify()!;
-    }
-    if (raw is ThumbnailSizeType_Custom) {
-      return [5, cst_encode_box_autoadd_record_u_32_u_32(raw.field0)].jsify()!;
-    }
+  void sse_encode_box_autoadd_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  );
 
-    throw Exception('unreachable');
-  }
+  @protected
+  void sse_encode_box_autoadd_thumbnail_size_type(
+    ThumbnailSizeType self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return castNativeBigInt(raw);
-  }
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
 
   @protected
-  JSAny cst_encode_video_info(VideoInfo raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_u_64(raw.durationMs),
-      cst_encode_u_32(raw.width),
-      cst_encode_u_32(raw.height),
-      cst_encode_u_64(raw.sizeBytes),
-      cst_encode_opt_box_autoadd_u_64(raw.bitrate),
-      cst_encode_opt_String(raw.codecName),
-      cst_encode_opt_String(raw.formatName),
-      cst_encode_list_resolution_preset(raw.suggestions),
-    ].jsify()!;
-  }
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_video_thumbnail_params(
+    VideoThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_write_to_files(
+    WriteToFiles self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compress_params(
+    CompressParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compress_progress(
+    CompressProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_compression_estimate(
+    CompressionEstimate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_concat_params(ConcatParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_crop_rect(CropRect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ducking_params(DuckingParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_encoder_options(
+    EncoderOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_extracted_frame(
+    ExtractedFrame self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_extract_params(
+    FrameExtractParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frame_selection(
+    FrameSelection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_gps_location(GpsLocation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hash_comparison(
+    HashComparison self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hdr_format(HdrFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_info(ImageInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_thumbnail_params(
+    ImageThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_media_chapter(
+    List<MediaChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_tag(List<MediaTag> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_palette_color(
+    List<PaletteColor> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_64_strict(
+    Uint64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_resolution_preset(
+    List<ResolutionPreset> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_scene_cut(List<SceneCut> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_stream_probe(
+    List<StreamProbe> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_video_thumbnail_params(VideoThumbnailParams raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_u_64(raw.timeMs),
-      cst_encode_opt_box_autoadd_thumbnail_size_type(raw.sizeType),
-      cst_encode_opt_box_autoadd_output_format(raw.format),
-    ].jsify()!;
-  }
+  void sse_encode_list_stream_side_data(
+    List<StreamSideData> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  JSAny cst_encode_write_to_files(WriteToFiles raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.path),
-      cst_encode_String(raw.filePrefix),
-      cst_encode_opt_String(raw.fileSuffix),
-      cst_encode_opt_box_autoadd_u_64(raw.maxFiles),
-    ].jsify()!;
-  }
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
 
   @protected
-  bool cst_encode_bool(bool raw);
+  void sse_encode_loudness_info(LoudnessInfo self, SseSerializer serializer);
 
   @protected
-  int cst_encode_i_32(int raw);
+  void sse_encode_loudness_normalization(
+    LoudnessNormalization self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int cst_encode_log_level(LogLevel raw);
+  void sse_encode_media_chapter(MediaChapter self, SseSerializer serializer);
 
   @protected
-  int cst_encode_output_format(OutputFormat raw);
+  void sse_encode_media_hash(MediaHash self, SseSerializer serializer);
 
   @protected
-  int cst_encode_u_32(int raw);
+  void sse_encode_media_probe(MediaProbe self, SseSerializer serializer);
 
   @protected
-  int cst_encode_u_8(int raw);
+  void sse_encode_media_tag(MediaTag self, SseSerializer serializer);
 
   @protected
-  void cst_encode_unit(void raw);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_aspect_mode(
+    AspectMode? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_String_Dco(
-    RustStreamSink<String> self,
+  void sse_encode_opt_box_autoadd_audio_channels(
+    AudioChannels? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_audio_stream_info(
+    AudioStreamInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_compress_params(
-    CompressParams self,
+  void sse_encode_opt_box_autoadd_audio_stream_probe(
+    AudioStreamProbe? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams self,
+  void sse_encode_opt_box_autoadd_audio_track_mode(
+    AudioTrackMode? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_output_format(
-    OutputFormat self,
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_crop_rect(
+    CropRect? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_record_u_32_u_32(
-    (int, int) self,
+  void sse_encode_opt_box_autoadd_ducking_params(
+    DuckingParams? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_thumbnail_size_type(
-    ThumbnailSizeType self,
+  void sse_encode_opt_box_autoadd_encoder_options(
+    EncoderOptions? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_gps_location(
+    GpsLocation? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_video_thumbnail_params(
-    VideoThumbnailParams self,
+  void sse_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_write_to_files(
-    WriteToFiles self,
+  void sse_encode_opt_box_autoadd_hdr_format(
+    HdrFormat? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_compress_params(
-    CompressParams self,
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_compression_estimate(
-    CompressionEstimate self,
+  void sse_encode_opt_box_autoadd_image_thumbnail_params(
+    ImageThumbnailParams? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_loudness_normalization(
+    LoudnessNormalization? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_image_thumbnail_params(
-    ImageThumbnailParams self,
+  void sse_encode_opt_box_autoadd_output_format(
+    OutputFormat? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_palette_color(
+    PaletteColor? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_resolution_preset(
-    List<ResolutionPreset> self,
+  void sse_encode_opt_box_autoadd_record_u_32_u_32(
+    (int, int)? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_resize_mode(
+    ResizeMode? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_reverse_audio_mode(
+    ReverseAudioMode? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_seek_mode(
+    SeekMode? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_image_thumbnail_params(
-    ImageThumbnailParams? self,
+  void sse_encode_opt_box_autoadd_speed_params(
+    SpeedParams? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_output_format(
-    OutputFormat? self,
+  void sse_encode_opt_box_autoadd_subtitle_params(
+    SubtitleParams? self,
     SseSerializer serializer,
   );
 
@@ -751,6 +3265,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -760,6 +3277,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_video_stream_probe(
+    VideoStreamProbe? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_params(
+    WatermarkParams? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watermark_position(
+    WatermarkPosition? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_write_to_files(
     WriteToFiles? self,
@@ -769,75 +3304,374 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_output_format(OutputFormat self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_palette(Palette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_palette_color(PaletteColor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_placeholder_image(
+    PlaceholderImage self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_resize_mode(ResizeMode self, SseSerializer serializer);
+
   @protected
   void sse_encode_resolution_preset(
     ResolutionPreset self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_reverse_audio_mode(
+    ReverseAudioMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reverse_params(ReverseParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scene_cut(SceneCut self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scene_detection_event(
+    SceneDetectionEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_seek_mode(SeekMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_speed_params(SpeedParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_storyboard(Storyboard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_disposition(
+    StreamDisposition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stream_probe(StreamProbe self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_side_data(
+    StreamSideData self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stream_type(StreamType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_subtitle_params(
+    SubtitleParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_thumbnail_data(ThumbnailData self, SseSerializer serializer);
+
   @protected
   void sse_encode_thumbnail_size_type(
     ThumbnailSizeType self,
     SseSerializer serializer,
   );
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_info(VideoInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_stream_probe(
+    VideoStreamProbe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_video_thumbnail_params(
+    VideoThumbnailParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watermark_params(
+    WatermarkParams self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watermark_position(
+    WatermarkPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_write_to_files(WriteToFiles self, SseSerializer serializer);
+}
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void wire__crate__api__media__add_music(
+    NativePortType port_,
+    String video_path,
+    String music_path,
+    String output_path,
+    JSAny params,
+    String sink,
+  ) => wasmModule.wire__crate__api__media__add_music(
+    port_,
+    video_path,
+    music_path,
+    output_path,
+    params,
+    sink,
+  );
+
+  void wire__crate__api__media__analyze_loudness(
+    NativePortType port_,
+    String path,
+  ) => wasmModule.wire__crate__api__media__analyze_loudness(port_, path);
+
+  void wire__crate__api__media__animation_format_extension(
+    NativePortType port_,
+    int that,
+  ) => wasmModule.wire__crate__api__media__animation_format_extension(
+    port_,
+    that,
+  );
+
+  void wire__crate__api__media__audio_format_extension(
+    NativePortType port_,
+    int that,
+  ) => wasmModule.wire__crate__api__media__audio_format_extension(port_, that);
+
+  void wire__crate__api__media__compare_media_hashes(
+    NativePortType port_,
+    JSAny a,
+    JSAny b,
+    int? threshold,
+  ) => wasmModule.wire__crate__api__media__compare_media_hashes(
+    port_,
+    a,
+    b,
+    threshold,
+  );
+
+  void wire__crate__api__media__compress_video(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny params,
+  ) => wasmModule.wire__crate__api__media__compress_video(
+    port_,
+    path,
+    output_path,
+    params,
+  );
+
+  void wire__crate__api__media__compute_blurhash(
+    NativePortType port_,
+    String path,
+    int components_x,
+    int components_y,
+    JSAny? time_ms,
+  ) => wasmModule.wire__crate__api__media__compute_blurhash(
+    port_,
+    path,
+    components_x,
+    components_y,
+    time_ms,
+  );
+
+  void wire__crate__api__media__compute_image_hash(
+    NativePortType port_,
+    String path,
+    int? algorithm,
+  ) => wasmModule.wire__crate__api__media__compute_image_hash(
+    port_,
+    path,
+    algorithm,
+  );
+
+  void wire__crate__api__media__compute_thumbhash(
+    NativePortType port_,
+    String path,
+    JSAny? time_ms,
+  ) => wasmModule.wire__crate__api__media__compute_thumbhash(
+    port_,
+    path,
+    time_ms,
+  );
+
+  void wire__crate__api__media__compute_video_hash(
+    NativePortType port_,
+    String path,
+    int? algorithm,
+    int? frame_count,
+  ) => wasmModule.wire__crate__api__media__compute_video_hash(
+    port_,
+    path,
+    algorithm,
+    frame_count,
+  );
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  void wire__crate__api__media__concat_videos(
+    NativePortType port_,
+    JSAny inputs,
+    String output_path,
+    JSAny params,
+    String sink,
+  ) => wasmModule.wire__crate__api__media__concat_videos(
+    port_,
+    inputs,
+    output_path,
+    params,
+    sink,
+  );
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  void wire__crate__api__logger__debug_threads(NativePortType port_) =>
+      wasmModule.wire__crate__api__logger__debug_threads(port_);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  void wire__crate__api__media__decode_blurhash(
+    NativePortType port_,
+    String hash,
+    int width,
+    int height,
+    double? punch,
+  ) => wasmModule.wire__crate__api__media__decode_blurhash(
+    port_,
+    hash,
+    width,
+    height,
+    punch,
+  );
 
-  @protected
-  void sse_encode_video_info(VideoInfo self, SseSerializer serializer);
+  void wire__crate__api__media__decode_thumbhash(
+    NativePortType port_,
+    JSAny hash,
+  ) => wasmModule.wire__crate__api__media__decode_thumbhash(port_, hash);
 
-  @protected
-  void sse_encode_video_thumbnail_params(
-    VideoThumbnailParams self,
-    SseSerializer serializer,
+  void wire__crate__api__media__detect_scenes(
+    NativePortType port_,
+    String path,
+    double threshold,
+    String sink,
+  ) => wasmModule.wire__crate__api__media__detect_scenes(
+    port_,
+    path,
+    threshold,
+    sink,
   );
 
-  @protected
-  void sse_encode_write_to_files(WriteToFiles self, SseSerializer serializer);
-}
+  void wire__crate__api__media__estimate_compression(
+    NativePortType port_,
+    String path,
+    String temp_output_path,
+    JSAny params,
+  ) => wasmModule.wire__crate__api__media__estimate_compression(
+    port_,
+    path,
+    temp_output_path,
+    params,
+  );
 
-// Section: wire_class
+  void wire__crate__api__media__export_animation(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny start_ms,
+    JSAny duration_ms,
+    int fps,
+    int max_width,
+    int format,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__media__export_animation(
+    port_,
+    path,
+    output_path,
+    start_ms,
+    duration_ms,
+    fps,
+    max_width,
+    format,
+    options,
+  );
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+  void wire__crate__api__media__extract_audio(
+    NativePortType port_,
+    String path,
+    String output_path,
+    int format,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__media__extract_audio(
+    port_,
+    path,
+    output_path,
+    format,
+    options,
+  );
 
-  void wire__crate__api__media__compress_video(
+  void wire__crate__api__media__extract_frames(
     NativePortType port_,
     String path,
     String output_path,
     JSAny params,
-  ) => wasmModule.wire__crate__api__media__compress_video(
+    String sink,
+  ) => wasmModule.wire__crate__api__media__extract_frames(
     port_,
     path,
     output_path,
     params,
+    sink,
   );
 
-  void wire__crate__api__logger__debug_threads(NativePortType port_) =>
-      wasmModule.wire__crate__api__logger__debug_threads(port_);
+  void wire__crate__api__media__extract_palette(
+    NativePortType port_,
+    String path,
+    int count,
+    JSAny? time_ms,
+  ) => wasmModule.wire__crate__api__media__extract_palette(
+    port_,
+    path,
+    count,
+    time_ms,
+  );
 
-  void wire__crate__api__media__estimate_compression(
+  void wire__crate__api__media__generate_best_thumbnail(
     NativePortType port_,
     String path,
-    String temp_output_path,
     JSAny params,
-  ) => wasmModule.wire__crate__api__media__estimate_compression(
+  ) => wasmModule.wire__crate__api__media__generate_best_thumbnail(
     port_,
     path,
-    temp_output_path,
     params,
   );
 
@@ -855,6 +3689,38 @@ class RustLibWire implements BaseWire {
     suffix,
   );
 
+  void wire__crate__api__media__generate_image_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny? params,
+  ) => wasmModule.wire__crate__api__media__generate_image_thumbnail_data(
+    port_,
+    path,
+    params,
+  );
+
+  void wire__crate__api__media__generate_storyboard(
+    NativePortType port_,
+    String path,
+    String output_dir,
+    JSAny interval_ms,
+    int tile_width,
+    int tile_height,
+    int columns,
+    int rows,
+    JSAny? encoder_options,
+  ) => wasmModule.wire__crate__api__media__generate_storyboard(
+    port_,
+    path,
+    output_dir,
+    interval_ms,
+    tile_width,
+    tile_height,
+    columns,
+    rows,
+    encoder_options,
+  );
+
   void wire__crate__api__media__generate_video_thumbnail(
     NativePortType port_,
     String path,
@@ -869,6 +3735,32 @@ class RustLibWire implements BaseWire {
     empty_image_fallback,
   );
 
+  void wire__crate__api__media__generate_video_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny params,
+  ) => wasmModule.wire__crate__api__media__generate_video_thumbnail_data(
+    port_,
+    path,
+    params,
+  );
+
+  void wire__crate__api__media__generate_video_timeline_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny? params,
+    int num_thumbnails,
+    String sink,
+  ) =>
+      wasmModule
+          .wire__crate__api__media__generate_video_timeline_thumbnail_data(
+            port_,
+            path,
+            params,
+            num_thumbnails,
+            sink,
+          );
+
   void wire__crate__api__media__generate_video_timeline_thumbnails(
     NativePortType port_,
     String path,
@@ -887,6 +3779,11 @@ class RustLibWire implements BaseWire {
     sink,
   );
 
+  void wire__crate__api__media__get_image_info(
+    NativePortType port_,
+    String path,
+  ) => wasmModule.wire__crate__api__media__get_image_info(port_, path);
+
   void wire__crate__api__media__get_video_info(
     NativePortType port_,
     String path,
@@ -927,6 +3824,16 @@ class RustLibWire implements BaseWire {
     int that,
   ) => wasmModule.wire__crate__api__media__output_format_extension(port_, that);
 
+  void wire__crate__api__media__probe_media(
+    NativePortType port_,
+    String path,
+  ) => wasmModule.wire__crate__api__media__probe_media(port_, path);
+
+  void wire__crate__api__media__probe_media_json(
+    NativePortType port_,
+    String path,
+  ) => wasmModule.wire__crate__api__media__probe_media_json(port_, path);
+
   void wire__crate__api__logger__reload_tracing_file_writer(
     NativePortType port_,
     JSAny write_to_files,
@@ -935,6 +3842,20 @@ class RustLibWire implements BaseWire {
     write_to_files,
   );
 
+  void wire__crate__api__media__reverse_video(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny params,
+    String sink,
+  ) => wasmModule.wire__crate__api__media__reverse_video(
+    port_,
+    path,
+    output_path,
+    params,
+    sink,
+  );
+
   void wire__crate__api__media__thumbnail_size_type_dimensions(
     NativePortType port_,
     JSAny that,
@@ -950,6 +3871,37 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void wire__crate__api__media__add_music(
+    NativePortType port_,
+    String video_path,
+    String music_path,
+    String output_path,
+    JSAny params,
+    String sink,
+  );
+
+  external void wire__crate__api__media__analyze_loudness(
+    NativePortType port_,
+    String path,
+  );
+
+  external void wire__crate__api__media__animation_format_extension(
+    NativePortType port_,
+    int that,
+  );
+
+  external void wire__crate__api__media__audio_format_extension(
+    NativePortType port_,
+    int that,
+  );
+
+  external void wire__crate__api__media__compare_media_hashes(
+    NativePortType port_,
+    JSAny a,
+    JSAny b,
+    int? threshold,
+  );
+
   external void wire__crate__api__media__compress_video(
     NativePortType port_,
     String path,
@@ -957,8 +3909,63 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     JSAny params,
   );
 
+  external void wire__crate__api__media__compute_blurhash(
+    NativePortType port_,
+    String path,
+    int components_x,
+    int components_y,
+    JSAny? time_ms,
+  );
+
+  external void wire__crate__api__media__compute_image_hash(
+    NativePortType port_,
+    String path,
+    int? algorithm,
+  );
+
+  external void wire__crate__api__media__compute_thumbhash(
+    NativePortType port_,
+    String path,
+    JSAny? time_ms,
+  );
+
+  external void wire__crate__api__media__compute_video_hash(
+    NativePortType port_,
+    String path,
+    int? algorithm,
+    int? frame_count,
+  );
+
+  external void wire__crate__api__media__concat_videos(
+    NativePortType port_,
+    JSAny inputs,
+    String output_path,
+    JSAny params,
+    String sink,
+  );
+
   external void wire__crate__api__logger__debug_threads(NativePortType port_);
 
+  external void wire__crate__api__media__decode_blurhash(
+    NativePortType port_,
+    String hash,
+    int width,
+    int height,
+    double? punch,
+  );
+
+  external void wire__crate__api__media__decode_thumbhash(
+    NativePortType port_,
+    JSAny hash,
+  );
+
+  external void wire__crate__api__media__detect_scenes(
+    NativePortType port_,
+    String path,
+    double threshold,
+    String sink,
+  );
+
   external void wire__crate__api__media__estimate_compression(
     NativePortType port_,
     String path,
@@ -966,6 +3973,47 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     JSAny params,
   );
 
+  external void wire__crate__api__media__export_animation(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny start_ms,
+    JSAny duration_ms,
+    int fps,
+    int max_width,
+    int format,
+    JSAny options,
+  );
+
+  external void wire__crate__api__media__extract_audio(
+    NativePortType port_,
+    String path,
+    String output_path,
+    int format,
+    JSAny options,
+  );
+
+  external void wire__crate__api__media__extract_frames(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny params,
+    String sink,
+  );
+
+  external void wire__crate__api__media__extract_palette(
+    NativePortType port_,
+    String path,
+    int count,
+    JSAny? time_ms,
+  );
+
+  external void wire__crate__api__media__generate_best_thumbnail(
+    NativePortType port_,
+    String path,
+    JSAny params,
+  );
+
   external void wire__crate__api__media__generate_image_thumbnail(
     NativePortType port_,
     String path,
@@ -974,6 +4022,24 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String? suffix,
   );
 
+  external void wire__crate__api__media__generate_image_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny? params,
+  );
+
+  external void wire__crate__api__media__generate_storyboard(
+    NativePortType port_,
+    String path,
+    String output_dir,
+    JSAny interval_ms,
+    int tile_width,
+    int tile_height,
+    int columns,
+    int rows,
+    JSAny? encoder_options,
+  );
+
   external void wire__crate__api__media__generate_video_thumbnail(
     NativePortType port_,
     String path,
@@ -982,6 +4048,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     bool? empty_image_fallback,
   );
 
+  external void wire__crate__api__media__generate_video_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny params,
+  );
+
+  external void wire__crate__api__media__generate_video_timeline_thumbnail_data(
+    NativePortType port_,
+    String path,
+    JSAny? params,
+    int num_thumbnails,
+    String sink,
+  );
+
   external void wire__crate__api__media__generate_video_timeline_thumbnails(
     NativePortType port_,
     String path,
@@ -992,6 +4072,11 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String sink,
   );
 
+  external void wire__crate__api__media__get_image_info(
+    NativePortType port_,
+    String path,
+  );
+
   external void wire__crate__api__media__get_video_info(
     NativePortType port_,
     String path,
@@ -1019,11 +4104,29 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int that,
   );
 
+  external void wire__crate__api__media__probe_media(
+    NativePortType port_,
+    String path,
+  );
+
+  external void wire__crate__api__media__probe_media_json(
+    NativePortType port_,
+    String path,
+  );
+
   external void wire__crate__api__logger__reload_tracing_file_writer(
     NativePortType port_,
     JSAny write_to_files,
   );
 
+  external void wire__crate__api__media__reverse_video(
+    NativePortType port_,
+    String path,
+    String output_path,
+    JSAny params,
+    String sink,
+  );
+
   external void wire__crate__api__media__thumbnail_size_type_dimensions(
     NativePortType port_,
     JSAny that,
@@ -0,0 +1,555 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:media/media.dart';
+import 'package:test/test.dart';
+
+// Smoke tests for the generated bindings. Each call sends the API's parameter types
+// across the bridge and checks the Rust side decodes them and answers; a missing
+// input file makes the call fail fast with an error instead of doing any work.
+const missing = 'does_not_exist.mp4';
+final throwsRustError = throwsA(isA<AnyhowException>());
+
+/// First error of a stream call, whether it arrives on the stream or escapes the
+/// unawaited Rust task that feeds it.
+Future<Object> streamError(Stream<Object?> Function() call) {
+  final error = Completer<Object>();
+  void complete(Object e) {
+    if (!error.isCompleted) error.complete(e);
+  }
+
+  runZonedGuarded(
+    () => call().listen(null, onError: (Object e) => complete(e)),
+    (e, _) => complete(e),
+  );
+  return error.future;
+}
+
+void main() {
+  late Directory out;
+
+  setUpAll(() async {
+    await Media.init();
+    out = Directory.systemTemp.createTempSync('media_bindings_test');
+  });
+
+  tearDownAll(() => out.deleteSync(recursive: true));
+
+  group('compressVideo', () {
+    test('accepts crop, aspect ratio and padding', () async {
+      const params = CompressParams(
+        targetBitrateKbps: 1000,
+        crop: CropRect(x: 0, y: 0, width: 640, height: 360),
+        aspectRatio: (1, 1),
+        aspectMode: AspectMode.blurredFit,
+        padColor: 0x000000FF,
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+      await expectLater(
+        estimateCompression(
+          path: missing,
+          tempOutputPath: 'sample.mp4',
+          params: params,
+        ),
+        throwsRustError,
+      );
+    });
+
+    test('accepts a watermark', () async {
+      final params = CompressParams(
+        targetBitrateKbps: 1000,
+        watermark: WatermarkParams(
+          imagePath: 'logo.png',
+          position: WatermarkPosition.bottomRight,
+          margin: 16,
+          scale: 0.2,
+          opacity: 0.8,
+          startMs: BigInt.from(1000),
+          endMs: BigInt.from(5000),
+        ),
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+    });
+
+    test('accepts burned-in subtitles', () async {
+      const params = CompressParams(
+        targetBitrateKbps: 1000,
+        subtitles: SubtitleParams(
+          subtitlePath: 'captions.srt',
+          fontSize: 32,
+          color: 0xFFFFFFFF,
+          outlineColor: 0x000000FF,
+          outlineWidth: 2,
+          bottomMargin: 24,
+        ),
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+    });
+
+    test('accepts a speed change', () async {
+      const params = CompressParams(
+        targetBitrateKbps: 1000,
+        speed: SpeedParams(factor: 4, frameRate: 30, muteAudioAbove: 2),
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+    });
+
+    test('accepts loudness normalisation', () async {
+      const params = CompressParams(
+        targetBitrateKbps: 1000,
+        normalizeLoudness: LoudnessNormalization(
+          targetLufs: -16,
+          truePeakDbtp: -1.5,
+        ),
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+    });
+
+    test('accepts video and audio filter chains', () async {
+      const params = CompressParams(
+        targetBitrateKbps: 1000,
+        videoFilters: 'hflip,eq=contrast=1.1',
+        audioFilters: 'highpass=f=80',
+      );
+      await expectLater(
+        compressVideo(path: missing, outputPath: 'out.mp4', params: params),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('concatVideos', () {
+    test('accepts the clip list and parameters', () async {
+      final error = await streamError(
+        () => concatVideos(
+          inputs: [missing, missing],
+          outputPath: 'joined.mp4',
+          params: const ConcatParams(
+            width: 1280,
+            height: 720,
+            fps: 30,
+            forceReencode: true,
+          ),
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('reverseVideo', () {
+    test('accepts the reverse parameters', () async {
+      final error = await streamError(
+        () => reverseVideo(
+          path: missing,
+          outputPath: 'reversed.mp4',
+          params: ReverseParams(
+            startMs: BigInt.zero,
+            endMs: BigInt.from(3000),
+            boomerangLoops: 2,
+            audio: ReverseAudioMode.mute,
+            memoryBudgetMb: 128,
+          ),
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('extractAudio', () {
+    test('accepts every format and the extract options', () async {
+      for (final format in AudioFormat.values) {
+        await format.extension_();
+        await expectLater(
+          extractAudio(
+            path: missing,
+            outputPath: 'audio',
+            format: format,
+            options: AudioExtractOptions(
+              startMs: BigInt.from(500),
+              channels: AudioChannels.mono,
+              sampleRate: 44100,
+              bitrateKbps: 128,
+            ),
+          ),
+          throwsRustError,
+        );
+      }
+    });
+
+    test('accepts loudness normalisation', () async {
+      await expectLater(
+        extractAudio(
+          path: missing,
+          outputPath: 'audio',
+          format: AudioFormat.m4A,
+          options: const AudioExtractOptions(
+            normalizeLoudness: LoudnessNormalization(targetLufs: -23),
+          ),
+        ),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('addMusic', () {
+    test('accepts the mix and ducking parameters', () async {
+      final error = await streamError(
+        () => addMusic(
+          videoPath: missing,
+          musicPath: 'music.mp3',
+          outputPath: 'with_music.mp4',
+          params: AudioMixParams(
+            mode: AudioTrackMode.mix,
+            musicGainDb: -6,
+            ducking: const DuckingParams(thresholdDb: -30, reductionDb: 12),
+            loopMusic: true,
+            fadeOutMs: BigInt.from(2000),
+          ),
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('analyzeLoudness', () {
+    test('reports a missing file', () async {
+      await expectLater(analyzeLoudness(path: missing), throwsRustError);
+    });
+  });
+
+  group('exportAnimation', () {
+    test('accepts every format and the animation options', () async {
+      for (final format in AnimationFormat.values) {
+        await format.extension_();
+        await expectLater(
+          exportAnimation(
+            path: missing,
+            outputPath: 'clip',
+            startMs: BigInt.from(1000),
+            durationMs: BigInt.from(2000),
+            fps: 12,
+            maxWidth: 320,
+            format: format,
+            options: AnimationOptions(
+              maxBytes: BigInt.from(1 << 20),
+              loopCount: 0,
+              dither: true,
+            ),
+          ),
+          throwsRustError,
+        );
+      }
+    });
+  });
+
+  group('generateVideoThumbnail', () {
+    test('accepts every seek mode', () async {
+      for (final seekMode in SeekMode.values) {
+        await expectLater(
+          generateVideoThumbnail(
+            path: missing,
+            outputPath: out.path,
+            params: VideoThumbnailParams(
+              timeMs: BigInt.from(1000),
+              seekMode: seekMode,
+            ),
+          ),
+          throwsRustError,
+        );
+      }
+    });
+
+    test('accepts every resize mode', () async {
+      for (final resizeMode in ResizeMode.values) {
+        await expectLater(
+          generateVideoThumbnail(
+            path: missing,
+            outputPath: out.path,
+            params: VideoThumbnailParams(
+              timeMs: BigInt.from(1000),
+              sizeType: const ThumbnailSizeType.custom((320, 320)),
+              resizeMode: resizeMode,
+              allowUpscale: true,
+            ),
+          ),
+          throwsRustError,
+        );
+      }
+    });
+  });
+
+  group('generateVideoTimelineThumbnails', () {
+    test('accepts a seek mode', () async {
+      final error = await streamError(
+        () => generateVideoTimelineThumbnails(
+          path: missing,
+          outputPath: out.path,
+          params: const ImageThumbnailParams(seekMode: SeekMode.keyframe),
+          numThumbnails: 4,
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('generateBestThumbnail', () {
+    test('accepts the candidate parameters', () async {
+      await expectLater(
+        generateBestThumbnail(
+          path: missing,
+          params: const BestThumbnailParams(
+            sizeType: ThumbnailSizeType.custom((320, 180)),
+            format: OutputFormat.jpeg,
+            candidates: 8,
+          ),
+        ),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('generateStoryboard', () {
+    test('accepts the sheet layout', () async {
+      await expectLater(
+        generateStoryboard(
+          path: missing,
+          outputDir: out.path,
+          intervalMs: BigInt.from(1000),
+          tileWidth: 160,
+          tileHeight: 90,
+          columns: 5,
+          rows: 5,
+          encoderOptions: const EncoderOptions(jpegQuality: 70),
+        ),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('extractFrames', () {
+    test('accepts every frame selection', () async {
+      final selections = [
+        FrameSelection.timestamps(
+          Uint64List.fromList([BigInt.from(500), BigInt.from(2500)]),
+        ),
+        FrameSelection.interval(BigInt.from(1000)),
+        const FrameSelection.count(4),
+      ];
+      for (final selection in selections) {
+        final error = await streamError(
+          () => extractFrames(
+            path: missing,
+            outputPath: out.path,
+            params: FrameExtractParams(
+              selection: selection,
+              format: OutputFormat.jpeg,
+              seekMode: SeekMode.keyframe,
+            ),
+          ),
+        );
+        expect(error, isA<AnyhowException>());
+      }
+    });
+
+    test('accepts a resize mode', () async {
+      final error = await streamError(
+        () => extractFrames(
+          path: missing,
+          outputPath: out.path,
+          params: const FrameExtractParams(
+            selection: FrameSelection.count(4),
+            resizeMode: ResizeMode.fill,
+            allowUpscale: false,
+          ),
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('detectScenes', () {
+    test('reports a missing file on the stream', () async {
+      final error = await streamError(
+        () => detectScenes(path: missing, threshold: 0.3),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+  });
+
+  group('thumbnail data', () {
+    test('generateVideoThumbnailData reports a missing file', () async {
+      await expectLater(
+        generateVideoThumbnailData(
+          path: missing,
+          params: VideoThumbnailParams(timeMs: BigInt.from(1000)),
+        ),
+        throwsRustError,
+      );
+    });
+
+    test('generateVideoTimelineThumbnailData reports a missing file', () async {
+      final error = await streamError(
+        () => generateVideoTimelineThumbnailData(
+          path: missing,
+          params: const ImageThumbnailParams(format: OutputFormat.jpeg),
+          numThumbnails: 4,
+        ),
+      );
+      expect(error, isA<AnyhowException>());
+    });
+
+    test('generateImageThumbnailData reports a missing file', () async {
+      await expectLater(
+        generateImageThumbnailData(path: 'does_not_exist.png'),
+        throwsRustError,
+      );
+    });
+
+    test('accepts every output format and the encoder options', () async {
+      for (final format in OutputFormat.values) {
+        await format.extension_();
+        await expectLater(
+          generateImageThumbnailData(
+            path: 'does_not_exist.png',
+            params: ImageThumbnailParams(
+              format: format,
+              encoderOptions: const EncoderOptions(
+                jpegQuality: 85,
+                jpegProgressive: true,
+                webpQuality: 80,
+                pngCompression: 9,
+                avifQuality: 60,
+                heicQuality: 60,
+              ),
+            ),
+          ),
+          throwsRustError,
+        );
+      }
+    });
+  });
+
+  group('placeholders', () {
+    test('compute functions report a missing file', () async {
+      await expectLater(
+        computeBlurhash(
+          path: missing,
+          componentsX: 4,
+          componentsY: 3,
+          timeMs: BigInt.from(1000),
+        ),
+        throwsRustError,
+      );
+      await expectLater(computeThumbhash(path: missing), throwsRustError);
+    });
+
+    test('decodeBlurhash renders the hash as RGBA', () async {
+      // Single-component hash of a white image
+      final image = await decodeBlurhash(hash: '00TSUA', width: 4, height: 4);
+      expect(image.width, 4);
+      expect(image.height, 4);
+      expect(image.rgba, hasLength(4 * 4 * 4));
+      expect(image.rgba.sublist(0, 4), [255, 255, 255, 255]);
+    });
+
+    test('decodeThumbhash rejects a truncated hash', () async {
+      await expectLater(decodeThumbhash(hash: [1, 2, 3]), throwsRustError);
+    });
+  });
+
+  group('perceptual hashes', () {
+    test('compute functions report a missing file', () async {
+      for (final algorithm in HashAlgorithm.values) {
+        await expectLater(
+          computeImageHash(path: 'does_not_exist.png', algorithm: algorithm),
+          throwsRustError,
+        );
+        await expectLater(
+          computeVideoHash(path: missing, algorithm: algorithm, frameCount: 8),
+          throwsRustError,
+        );
+      }
+    });
+
+    test('compareMediaHashes returns the Hamming distance', () async {
+      MediaHash hash(int value) => MediaHash(
+        algorithm: HashAlgorithm.pHash,
+        hashes: Uint64List.fromList([BigInt.from(value)]),
+        timesMs: Uint64List(0),
+      );
+      final comparison = await compareMediaHashes(
+        a: hash(0xF0F0),
+        b: hash(0xF0F3),
+        threshold: 1,
+      );
+      expect(comparison.distance, 2);
+      expect(comparison.similarity, 1 - 2 / 64);
+      expect(comparison.isSimilar, isFalse);
+    });
+
+    test('compareMediaHashes rejects mixed algorithms', () async {
+      await expectLater(
+        compareMediaHashes(
+          a: MediaHash(
+            algorithm: HashAlgorithm.dHash,
+            hashes: Uint64List.fromList([BigInt.one]),
+            timesMs: Uint64List(0),
+          ),
+          b: MediaHash(
+            algorithm: HashAlgorithm.pHash,
+            hashes: Uint64List.fromList([BigInt.one]),
+            timesMs: Uint64List(0),
+          ),
+        ),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('extractPalette', () {
+    test('reports a missing file', () async {
+      await expectLater(
+        extractPalette(path: missing, count: 6, timeMs: BigInt.from(1000)),
+        throwsRustError,
+      );
+    });
+  });
+
+  group('getVideoInfo', () {
+    test('reports a missing file', () async {
+      await expectLater(getVideoInfo(path: missing), throwsRustError);
+    });
+  });
+
+  group('probeMedia', () {
+    test('reports a missing file', () async {
+      await expectLater(probeMedia(path: missing), throwsRustError);
+      await expectLater(probeMediaJson(path: missing), throwsRustError);
+    });
+  });
+
+  group('getImageInfo', () {
+    test('reports a missing file', () async {
+      await expectLater(
+        getImageInfo(path: 'does_not_exist.heic'),
+        throwsRustError,
+      );
+    });
+  });
+}
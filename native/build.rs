@@ -92,6 +92,10 @@ fn main() {
                 // to avoid __imp_ prefix issues. This is handled in build_libheif_msvc.bat
                 println!("cargo:rustc-link-lib=static=de265");
                 println!("cargo:rustc-link-lib=static=libde265");
+                // libaom is libheif's AV1 (AVIF) encoder
+                if std::path::Path::new(&format!("{}/aom.lib", lib_dir)).exists() {
+                    println!("cargo:rustc-link-lib=static=aom");
+                }
                 println!("cargo:rustc-link-lib=static=heif");
                 println!("cargo:rustc-link-lib=static=libheif");
             } else {
@@ -139,6 +143,10 @@ fn main() {
             // Use .a files (MinGW/Unix format)
             println!("cargo:rustc-link-lib=static=heif");
             println!("cargo:rustc-link-lib=static=de265");
+            // libaom is libheif's AV1 (AVIF) encoder
+            if std::path::Path::new(&format!("{}/libaom.a", lib_dir)).exists() {
+                println!("cargo:rustc-link-lib=static=aom");
+            }
         }
 
         // libheif is a C++ library, so we need to link the C++ standard library
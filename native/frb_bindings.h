@@ -19,6 +19,78 @@ typedef struct wire_cst_list_prim_u_8_strict {
   int32_t len;
 } wire_cst_list_prim_u_8_strict;
 
+typedef struct wire_cst_ducking_params {
+  float *threshold_db;
+  float *reduction_db;
+  uint32_t *attack_ms;
+  uint32_t *release_ms;
+} wire_cst_ducking_params;
+
+typedef struct wire_cst_audio_mix_params {
+  int32_t *mode;
+  float *music_gain_db;
+  float *original_gain_db;
+  struct wire_cst_ducking_params *ducking;
+  bool loop_music;
+  uint64_t *music_start_ms;
+  uint64_t *fade_in_ms;
+  uint64_t *fade_out_ms;
+} wire_cst_audio_mix_params;
+
+typedef struct wire_cst_list_prim_u_64_strict {
+  uint64_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_64_strict;
+
+typedef struct wire_cst_media_hash {
+  int32_t algorithm;
+  struct wire_cst_list_prim_u_64_strict *hashes;
+  struct wire_cst_list_prim_u_64_strict *times_ms;
+} wire_cst_media_hash;
+
+typedef struct wire_cst_crop_rect {
+  uint32_t x;
+  uint32_t y;
+  uint32_t width;
+  uint32_t height;
+} wire_cst_crop_rect;
+
+typedef struct wire_cst_record_u_32_u_32 {
+  uint32_t field0;
+  uint32_t field1;
+} wire_cst_record_u_32_u_32;
+
+typedef struct wire_cst_watermark_params {
+  struct wire_cst_list_prim_u_8_strict *image_path;
+  int32_t *position;
+  uint32_t *margin;
+  float *scale;
+  float *opacity;
+  uint64_t *start_ms;
+  uint64_t *end_ms;
+} wire_cst_watermark_params;
+
+typedef struct wire_cst_subtitle_params {
+  struct wire_cst_list_prim_u_8_strict *subtitle_path;
+  struct wire_cst_list_prim_u_8_strict *font_path;
+  uint32_t *font_size;
+  uint32_t *color;
+  uint32_t *outline_color;
+  uint32_t *outline_width;
+  uint32_t *bottom_margin;
+} wire_cst_subtitle_params;
+
+typedef struct wire_cst_speed_params {
+  float factor;
+  uint32_t *frame_rate;
+  float *mute_audio_above;
+} wire_cst_speed_params;
+
+typedef struct wire_cst_loudness_normalization {
+  float target_lufs;
+  float *true_peak_dbtp;
+} wire_cst_loudness_normalization;
+
 typedef struct wire_cst_compress_params {
   uint32_t target_bitrate_kbps;
   struct wire_cst_list_prim_u_8_strict *preset;
@@ -26,12 +98,75 @@ typedef struct wire_cst_compress_params {
   uint32_t *width;
   uint32_t *height;
   uint64_t *sample_duration_ms;
+  struct wire_cst_crop_rect *crop;
+  struct wire_cst_record_u_32_u_32 *aspect_ratio;
+  int32_t *aspect_mode;
+  uint32_t *pad_color;
+  struct wire_cst_watermark_params *watermark;
+  struct wire_cst_subtitle_params *subtitles;
+  struct wire_cst_speed_params *speed;
+  struct wire_cst_loudness_normalization *normalize_loudness;
+  struct wire_cst_list_prim_u_8_strict *video_filters;
+  struct wire_cst_list_prim_u_8_strict *audio_filters;
 } wire_cst_compress_params;
 
-typedef struct wire_cst_record_u_32_u_32 {
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
+typedef struct wire_cst_concat_params {
+  uint32_t *width;
+  uint32_t *height;
+  uint32_t *fps;
+  uint32_t *target_bitrate_kbps;
+  struct wire_cst_list_prim_u_8_strict *preset;
+  uint8_t *crf;
+  bool *force_reencode;
+} wire_cst_concat_params;
+
+typedef struct wire_cst_list_prim_u_8_loose {
+  uint8_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_8_loose;
+
+typedef struct wire_cst_animation_options {
+  uint64_t *max_bytes;
+  uint16_t *loop_count;
+  bool *dither;
+} wire_cst_animation_options;
+
+typedef struct wire_cst_audio_extract_options {
+  uint64_t *start_ms;
+  uint64_t *end_ms;
+  int32_t *channels;
+  uint32_t *sample_rate;
+  uint32_t *bitrate_kbps;
+  struct wire_cst_loudness_normalization *normalize_loudness;
+} wire_cst_audio_extract_options;
+
+typedef struct wire_cst_FrameSelection_Timestamps {
+  struct wire_cst_list_prim_u_64_strict *field0;
+} wire_cst_FrameSelection_Timestamps;
+
+typedef struct wire_cst_FrameSelection_Interval {
+  uint64_t field0;
+} wire_cst_FrameSelection_Interval;
+
+typedef struct wire_cst_FrameSelection_Count {
   uint32_t field0;
-  uint32_t field1;
-} wire_cst_record_u_32_u_32;
+} wire_cst_FrameSelection_Count;
+
+typedef union FrameSelectionKind {
+  struct wire_cst_FrameSelection_Timestamps Timestamps;
+  struct wire_cst_FrameSelection_Interval Interval;
+  struct wire_cst_FrameSelection_Count Count;
+} FrameSelectionKind;
+
+typedef struct wire_cst_frame_selection {
+  int32_t tag;
+  union FrameSelectionKind kind;
+} wire_cst_frame_selection;
 
 typedef struct wire_cst_ThumbnailSizeType_Custom {
   struct wire_cst_record_u_32_u_32 *field0;
@@ -46,15 +181,51 @@ typedef struct wire_cst_thumbnail_size_type {
   union ThumbnailSizeTypeKind kind;
 } wire_cst_thumbnail_size_type;
 
+typedef struct wire_cst_encoder_options {
+  uint8_t *jpeg_quality;
+  bool *jpeg_progressive;
+  float *webp_quality;
+  uint8_t *png_compression;
+  uint8_t *avif_quality;
+  uint8_t *heic_quality;
+} wire_cst_encoder_options;
+
+typedef struct wire_cst_frame_extract_params {
+  struct wire_cst_frame_selection selection;
+  struct wire_cst_thumbnail_size_type *size_type;
+  int32_t *format;
+  int32_t *seek_mode;
+  int32_t *resize_mode;
+  bool *allow_upscale;
+  struct wire_cst_encoder_options *encoder_options;
+} wire_cst_frame_extract_params;
+
+typedef struct wire_cst_best_thumbnail_params {
+  struct wire_cst_thumbnail_size_type *size_type;
+  int32_t *format;
+  uint32_t *candidates;
+  int32_t *resize_mode;
+  bool *allow_upscale;
+  struct wire_cst_encoder_options *encoder_options;
+} wire_cst_best_thumbnail_params;
+
 typedef struct wire_cst_image_thumbnail_params {
   struct wire_cst_thumbnail_size_type *size_type;
   int32_t *format;
+  int32_t *seek_mode;
+  int32_t *resize_mode;
+  bool *allow_upscale;
+  struct wire_cst_encoder_options *encoder_options;
 } wire_cst_image_thumbnail_params;
 
 typedef struct wire_cst_video_thumbnail_params {
   uint64_t time_ms;
   struct wire_cst_thumbnail_size_type *size_type;
   int32_t *format;
+  int32_t *seek_mode;
+  int32_t *resize_mode;
+  bool *allow_upscale;
+  struct wire_cst_encoder_options *encoder_options;
 } wire_cst_video_thumbnail_params;
 
 typedef struct wire_cst_write_to_files {
@@ -64,6 +235,99 @@ typedef struct wire_cst_write_to_files {
   uint64_t *max_files;
 } wire_cst_write_to_files;
 
+typedef struct wire_cst_reverse_params {
+  uint64_t *start_ms;
+  uint64_t *end_ms;
+  uint32_t *boomerang_loops;
+  int32_t *audio;
+  uint32_t *width;
+  uint32_t *height;
+  uint32_t *target_bitrate_kbps;
+  struct wire_cst_list_prim_u_8_strict *preset;
+  uint8_t *crf;
+  uint32_t *memory_budget_mb;
+} wire_cst_reverse_params;
+
+typedef struct wire_cst_audio_stream_info {
+  struct wire_cst_list_prim_u_8_strict *codec_name;
+  uint32_t channels;
+  uint32_t sample_rate;
+  uint64_t *bitrate;
+  struct wire_cst_list_prim_u_8_strict *language;
+} wire_cst_audio_stream_info;
+
+typedef struct wire_cst_audio_stream_probe {
+  uint32_t sample_rate;
+  uint32_t channels;
+  struct wire_cst_list_prim_u_8_strict *channel_layout;
+  struct wire_cst_list_prim_u_8_strict *sample_format;
+  uint32_t *bits_per_sample;
+} wire_cst_audio_stream_probe;
+
+typedef struct wire_cst_compress_progress {
+  uint64_t processed_ms;
+  uint64_t total_ms;
+  float speed_x;
+} wire_cst_compress_progress;
+
+typedef struct wire_cst_gps_location {
+  double latitude;
+  double longitude;
+  double *altitude;
+} wire_cst_gps_location;
+
+typedef struct wire_cst_palette_color {
+  uint32_t color;
+  double population;
+} wire_cst_palette_color;
+
+typedef struct wire_cst_video_stream_probe {
+  uint32_t width;
+  uint32_t height;
+  uint32_t display_width;
+  uint32_t display_height;
+  int32_t rotation;
+  double *avg_frame_rate;
+  double *real_frame_rate;
+  bool is_vfr;
+  struct wire_cst_list_prim_u_8_strict *pixel_format;
+  uint32_t *bit_depth;
+  struct wire_cst_list_prim_u_8_strict *color_primaries;
+  struct wire_cst_list_prim_u_8_strict *color_transfer;
+  struct wire_cst_list_prim_u_8_strict *color_matrix;
+  struct wire_cst_list_prim_u_8_strict *color_range;
+  int32_t *hdr_format;
+  int32_t *level;
+} wire_cst_video_stream_probe;
+
+typedef struct wire_cst_media_tag {
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_media_tag;
+
+typedef struct wire_cst_list_media_tag {
+  struct wire_cst_media_tag *ptr;
+  int32_t len;
+} wire_cst_list_media_tag;
+
+typedef struct wire_cst_media_chapter {
+  int64_t id;
+  uint64_t start_ms;
+  uint64_t end_ms;
+  struct wire_cst_list_prim_u_8_strict *title;
+  struct wire_cst_list_media_tag *tags;
+} wire_cst_media_chapter;
+
+typedef struct wire_cst_list_media_chapter {
+  struct wire_cst_media_chapter *ptr;
+  int32_t len;
+} wire_cst_list_media_chapter;
+
+typedef struct wire_cst_list_palette_color {
+  struct wire_cst_palette_color *ptr;
+  int32_t len;
+} wire_cst_list_palette_color;
+
 typedef struct wire_cst_resolution_preset {
   struct wire_cst_list_prim_u_8_strict *name;
   uint32_t width;
@@ -77,11 +341,178 @@ typedef struct wire_cst_list_resolution_preset {
   int32_t len;
 } wire_cst_list_resolution_preset;
 
+typedef struct wire_cst_scene_cut {
+  uint64_t time_ms;
+  double score;
+} wire_cst_scene_cut;
+
+typedef struct wire_cst_list_scene_cut {
+  struct wire_cst_scene_cut *ptr;
+  int32_t len;
+} wire_cst_list_scene_cut;
+
+typedef struct wire_cst_stream_disposition {
+  bool default;
+  bool dub;
+  bool original;
+  bool comment;
+  bool lyrics;
+  bool karaoke;
+  bool forced;
+  bool hearing_impaired;
+  bool visual_impaired;
+  bool clean_effects;
+  bool attached_pic;
+  bool captions;
+  bool descriptions;
+  bool metadata;
+} wire_cst_stream_disposition;
+
+typedef struct wire_cst_stream_side_data {
+  struct wire_cst_list_prim_u_8_strict *kind;
+  uint32_t size_bytes;
+} wire_cst_stream_side_data;
+
+typedef struct wire_cst_list_stream_side_data {
+  struct wire_cst_stream_side_data *ptr;
+  int32_t len;
+} wire_cst_list_stream_side_data;
+
+typedef struct wire_cst_stream_probe {
+  uint32_t index;
+  int32_t stream_type;
+  struct wire_cst_list_prim_u_8_strict *codec_name;
+  struct wire_cst_list_prim_u_8_strict *codec_long_name;
+  struct wire_cst_list_prim_u_8_strict *profile;
+  uint64_t *bitrate;
+  int64_t *start_time_ms;
+  uint64_t *duration_ms;
+  uint64_t *frame_count;
+  struct wire_cst_list_prim_u_8_strict *language;
+  struct wire_cst_list_prim_u_8_strict *title;
+  struct wire_cst_stream_disposition disposition;
+  struct wire_cst_list_media_tag *tags;
+  struct wire_cst_list_stream_side_data *side_data;
+  struct wire_cst_video_stream_probe *video;
+  struct wire_cst_audio_stream_probe *audio;
+} wire_cst_stream_probe;
+
+typedef struct wire_cst_list_stream_probe {
+  struct wire_cst_stream_probe *ptr;
+  int32_t len;
+} wire_cst_list_stream_probe;
+
+typedef struct wire_cst_best_thumbnail {
+  struct wire_cst_list_prim_u_8_strict *data;
+  uint32_t width;
+  uint32_t height;
+  uint64_t time_ms;
+  double score;
+} wire_cst_best_thumbnail;
+
 typedef struct wire_cst_compression_estimate {
   uint64_t estimated_size_bytes;
   uint64_t estimated_duration_ms;
 } wire_cst_compression_estimate;
 
+typedef struct wire_cst_extracted_frame {
+  struct wire_cst_list_prim_u_8_strict *path;
+  uint32_t index;
+  uint64_t requested_ms;
+  uint64_t time_ms;
+  uint32_t width;
+  uint32_t height;
+} wire_cst_extracted_frame;
+
+typedef struct wire_cst_hash_comparison {
+  uint32_t distance;
+  double similarity;
+  bool is_similar;
+} wire_cst_hash_comparison;
+
+typedef struct wire_cst_image_info {
+  uint32_t width;
+  uint32_t height;
+  struct wire_cst_list_prim_u_8_strict *format;
+  uint32_t bit_depth;
+  bool has_alpha;
+  uint32_t orientation;
+  struct wire_cst_list_prim_u_8_strict *camera_make;
+  struct wire_cst_list_prim_u_8_strict *camera_model;
+  struct wire_cst_list_prim_u_8_strict *capture_date;
+  struct wire_cst_gps_location *gps;
+  struct wire_cst_list_prim_u_8_strict *icc_profile_name;
+  uint32_t image_count;
+  uint32_t auxiliary_image_count;
+  bool has_depth;
+} wire_cst_image_info;
+
+typedef struct wire_cst_loudness_info {
+  double integrated_lufs;
+  double loudness_range_lu;
+  double true_peak_dbtp;
+} wire_cst_loudness_info;
+
+typedef struct wire_cst_media_probe {
+  struct wire_cst_list_prim_u_8_strict *format_name;
+  struct wire_cst_list_prim_u_8_strict *format_long_name;
+  uint64_t *duration_ms;
+  int64_t *start_time_ms;
+  uint64_t *bitrate;
+  uint64_t size_bytes;
+  struct wire_cst_list_media_tag *tags;
+  struct wire_cst_list_media_chapter *chapters;
+  struct wire_cst_list_stream_probe *streams;
+} wire_cst_media_probe;
+
+typedef struct wire_cst_palette {
+  struct wire_cst_list_palette_color *colors;
+  struct wire_cst_palette_color average;
+  struct wire_cst_palette_color *vibrant;
+  struct wire_cst_palette_color *light_vibrant;
+  struct wire_cst_palette_color *dark_vibrant;
+  struct wire_cst_palette_color *muted;
+  struct wire_cst_palette_color *light_muted;
+  struct wire_cst_palette_color *dark_muted;
+} wire_cst_palette;
+
+typedef struct wire_cst_placeholder_image {
+  struct wire_cst_list_prim_u_8_strict *rgba;
+  uint32_t width;
+  uint32_t height;
+} wire_cst_placeholder_image;
+
+typedef struct wire_cst_SceneDetectionEvent_Progress {
+  struct wire_cst_compress_progress *field0;
+} wire_cst_SceneDetectionEvent_Progress;
+
+typedef struct wire_cst_SceneDetectionEvent_Cuts {
+  struct wire_cst_list_scene_cut *field0;
+} wire_cst_SceneDetectionEvent_Cuts;
+
+typedef union SceneDetectionEventKind {
+  struct wire_cst_SceneDetectionEvent_Progress Progress;
+  struct wire_cst_SceneDetectionEvent_Cuts Cuts;
+} SceneDetectionEventKind;
+
+typedef struct wire_cst_scene_detection_event {
+  int32_t tag;
+  union SceneDetectionEventKind kind;
+} wire_cst_scene_detection_event;
+
+typedef struct wire_cst_storyboard {
+  struct wire_cst_list_prim_u_8_strict *vtt_path;
+  struct wire_cst_list_String *sprite_paths;
+  uint32_t tile_count;
+} wire_cst_storyboard;
+
+typedef struct wire_cst_thumbnail_data {
+  struct wire_cst_list_prim_u_8_strict *data;
+  uint32_t width;
+  uint32_t height;
+  uint64_t *time_ms;
+} wire_cst_thumbnail_data;
+
 typedef struct wire_cst_video_info {
   uint64_t duration_ms;
   uint32_t width;
@@ -90,33 +521,163 @@ typedef struct wire_cst_video_info {
   uint64_t *bitrate;
   struct wire_cst_list_prim_u_8_strict *codec_name;
   struct wire_cst_list_prim_u_8_strict *format_name;
+  uint32_t stored_width;
+  uint32_t stored_height;
+  int32_t rotation;
+  double *avg_frame_rate;
+  double *real_frame_rate;
+  bool is_vfr;
+  struct wire_cst_list_prim_u_8_strict *pixel_format;
+  uint32_t *bit_depth;
+  struct wire_cst_list_prim_u_8_strict *color_primaries;
+  struct wire_cst_list_prim_u_8_strict *color_transfer;
+  struct wire_cst_list_prim_u_8_strict *color_matrix;
+  struct wire_cst_list_prim_u_8_strict *color_range;
+  int32_t *hdr_format;
+  struct wire_cst_list_prim_u_8_strict *profile;
+  int32_t *level;
+  uint64_t *frame_count;
+  struct wire_cst_audio_stream_info *audio;
   struct wire_cst_list_resolution_preset *suggestions;
 } wire_cst_video_info;
 
+void frbgen_media_wire__crate__api__media__add_music(int64_t port_,
+                                                     struct wire_cst_list_prim_u_8_strict *video_path,
+                                                     struct wire_cst_list_prim_u_8_strict *music_path,
+                                                     struct wire_cst_list_prim_u_8_strict *output_path,
+                                                     struct wire_cst_audio_mix_params *params,
+                                                     struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_media_wire__crate__api__media__analyze_loudness(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *path);
+
+void frbgen_media_wire__crate__api__media__animation_format_extension(int64_t port_, int32_t that);
+
+void frbgen_media_wire__crate__api__media__audio_format_extension(int64_t port_, int32_t that);
+
+void frbgen_media_wire__crate__api__media__compare_media_hashes(int64_t port_,
+                                                                struct wire_cst_media_hash *a,
+                                                                struct wire_cst_media_hash *b,
+                                                                uint32_t *threshold);
+
 void frbgen_media_wire__crate__api__media__compress_video(int64_t port_,
                                                           struct wire_cst_list_prim_u_8_strict *path,
                                                           struct wire_cst_list_prim_u_8_strict *output_path,
                                                           struct wire_cst_compress_params *params);
 
+void frbgen_media_wire__crate__api__media__compute_blurhash(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *path,
+                                                            uint32_t components_x,
+                                                            uint32_t components_y,
+                                                            uint64_t *time_ms);
+
+void frbgen_media_wire__crate__api__media__compute_image_hash(int64_t port_,
+                                                              struct wire_cst_list_prim_u_8_strict *path,
+                                                              int32_t *algorithm);
+
+void frbgen_media_wire__crate__api__media__compute_thumbhash(int64_t port_,
+                                                             struct wire_cst_list_prim_u_8_strict *path,
+                                                             uint64_t *time_ms);
+
+void frbgen_media_wire__crate__api__media__compute_video_hash(int64_t port_,
+                                                              struct wire_cst_list_prim_u_8_strict *path,
+                                                              int32_t *algorithm,
+                                                              uint32_t *frame_count);
+
+void frbgen_media_wire__crate__api__media__concat_videos(int64_t port_,
+                                                         struct wire_cst_list_String *inputs,
+                                                         struct wire_cst_list_prim_u_8_strict *output_path,
+                                                         struct wire_cst_concat_params *params,
+                                                         struct wire_cst_list_prim_u_8_strict *sink);
+
 void frbgen_media_wire__crate__api__logger__debug_threads(int64_t port_);
 
+void frbgen_media_wire__crate__api__media__decode_blurhash(int64_t port_,
+                                                           struct wire_cst_list_prim_u_8_strict *hash,
+                                                           uint32_t width,
+                                                           uint32_t height,
+                                                           float *punch);
+
+void frbgen_media_wire__crate__api__media__decode_thumbhash(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_loose *hash);
+
+void frbgen_media_wire__crate__api__media__detect_scenes(int64_t port_,
+                                                         struct wire_cst_list_prim_u_8_strict *path,
+                                                         double threshold,
+                                                         struct wire_cst_list_prim_u_8_strict *sink);
+
 void frbgen_media_wire__crate__api__media__estimate_compression(int64_t port_,
                                                                 struct wire_cst_list_prim_u_8_strict *path,
                                                                 struct wire_cst_list_prim_u_8_strict *temp_output_path,
                                                                 struct wire_cst_compress_params *params);
 
+void frbgen_media_wire__crate__api__media__export_animation(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *path,
+                                                            struct wire_cst_list_prim_u_8_strict *output_path,
+                                                            uint64_t start_ms,
+                                                            uint64_t duration_ms,
+                                                            uint32_t fps,
+                                                            uint32_t max_width,
+                                                            int32_t format,
+                                                            struct wire_cst_animation_options *options);
+
+void frbgen_media_wire__crate__api__media__extract_audio(int64_t port_,
+                                                         struct wire_cst_list_prim_u_8_strict *path,
+                                                         struct wire_cst_list_prim_u_8_strict *output_path,
+                                                         int32_t format,
+                                                         struct wire_cst_audio_extract_options *options);
+
+void frbgen_media_wire__crate__api__media__extract_frames(int64_t port_,
+                                                          struct wire_cst_list_prim_u_8_strict *path,
+                                                          struct wire_cst_list_prim_u_8_strict *output_path,
+                                                          struct wire_cst_frame_extract_params *params,
+                                                          struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_media_wire__crate__api__media__extract_palette(int64_t port_,
+                                                           struct wire_cst_list_prim_u_8_strict *path,
+                                                           uint32_t count,
+                                                           uint64_t *time_ms);
+
+void frbgen_media_wire__crate__api__media__generate_best_thumbnail(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *path,
+                                                                   struct wire_cst_best_thumbnail_params *params);
+
 void frbgen_media_wire__crate__api__media__generate_image_thumbnail(int64_t port_,
                                                                     struct wire_cst_list_prim_u_8_strict *path,
                                                                     struct wire_cst_list_prim_u_8_strict *output_path,
                                                                     struct wire_cst_image_thumbnail_params *params,
                                                                     struct wire_cst_list_prim_u_8_strict *suffix);
 
+void frbgen_media_wire__crate__api__media__generate_image_thumbnail_data(int64_t port_,
+                                                                         struct wire_cst_list_prim_u_8_strict *path,
+                                                                         struct wire_cst_image_thumbnail_params *params);
+
+void frbgen_media_wire__crate__api__media__generate_storyboard(int64_t port_,
+                                                               struct wire_cst_list_prim_u_8_strict *path,
+                                                               struct wire_cst_list_prim_u_8_strict *output_dir,
+                                                               uint64_t interval_ms,
+                                                               uint32_t tile_width,
+                                                               uint32_t tile_height,
+                                                               uint32_t columns,
+                                                               uint32_t rows,
+                                                               struct wire_cst_encoder_options *encoder_options);
+
 void frbgen_media_wire__crate__api__media__generate_video_thumbnail(int64_t port_,
                                                                     struct wire_cst_list_prim_u_8_strict *path,
                                                                     struct wire_cst_list_prim_u_8_strict *output_path,
                                                                     struct wire_cst_video_thumbnail_params *params,
                                                                     bool *empty_image_fallback);
 
+void frbgen_media_wire__crate__api__media__generate_video_thumbnail_data(int64_t port_,
+                                                                         struct wire_cst_list_prim_u_8_strict *path,
+                                                                         struct wire_cst_video_thumbnail_params *params);
+
+void frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnail_data(int64_t port_,
+                                                                                  struct wire_cst_list_prim_u_8_strict *path,
+                                                                                  struct wire_cst_image_thumbnail_params *params,
+                                                                                  uint32_t num_thumbnails,
+                                                                                  struct wire_cst_list_prim_u_8_strict *sink);
+
 void frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails(int64_t port_,
                                                                               struct wire_cst_list_prim_u_8_strict *path,
                                                                               struct wire_cst_list_prim_u_8_strict *output_path,
@@ -125,6 +686,9 @@ void frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails(in
                                                                               bool *empty_image_fallback,
                                                                               struct wire_cst_list_prim_u_8_strict *sink);
 
+void frbgen_media_wire__crate__api__media__get_image_info(int64_t port_,
+                                                          struct wire_cst_list_prim_u_8_strict *path);
+
 void frbgen_media_wire__crate__api__media__get_video_info(int64_t port_,
                                                           struct wire_cst_list_prim_u_8_strict *path);
 
@@ -143,63 +707,233 @@ void frbgen_media_wire__crate__api__logger__log(int64_t port_,
 
 void frbgen_media_wire__crate__api__media__output_format_extension(int64_t port_, int32_t that);
 
+void frbgen_media_wire__crate__api__media__probe_media(int64_t port_,
+                                                       struct wire_cst_list_prim_u_8_strict *path);
+
+void frbgen_media_wire__crate__api__media__probe_media_json(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *path);
+
 void frbgen_media_wire__crate__api__logger__reload_tracing_file_writer(int64_t port_,
                                                                        struct wire_cst_write_to_files *write_to_files);
 
+void frbgen_media_wire__crate__api__media__reverse_video(int64_t port_,
+                                                         struct wire_cst_list_prim_u_8_strict *path,
+                                                         struct wire_cst_list_prim_u_8_strict *output_path,
+                                                         struct wire_cst_reverse_params *params,
+                                                         struct wire_cst_list_prim_u_8_strict *sink);
+
 void frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions(int64_t port_,
                                                                           struct wire_cst_thumbnail_size_type *that);
 
+struct wire_cst_animation_options *frbgen_media_cst_new_box_autoadd_animation_options(void);
+
+int32_t *frbgen_media_cst_new_box_autoadd_aspect_mode(int32_t value);
+
+int32_t *frbgen_media_cst_new_box_autoadd_audio_channels(int32_t value);
+
+struct wire_cst_audio_extract_options *frbgen_media_cst_new_box_autoadd_audio_extract_options(void);
+
+struct wire_cst_audio_mix_params *frbgen_media_cst_new_box_autoadd_audio_mix_params(void);
+
+struct wire_cst_audio_stream_info *frbgen_media_cst_new_box_autoadd_audio_stream_info(void);
+
+struct wire_cst_audio_stream_probe *frbgen_media_cst_new_box_autoadd_audio_stream_probe(void);
+
+int32_t *frbgen_media_cst_new_box_autoadd_audio_track_mode(int32_t value);
+
+struct wire_cst_best_thumbnail_params *frbgen_media_cst_new_box_autoadd_best_thumbnail_params(void);
+
 bool *frbgen_media_cst_new_box_autoadd_bool(bool value);
 
 struct wire_cst_compress_params *frbgen_media_cst_new_box_autoadd_compress_params(void);
 
+struct wire_cst_compress_progress *frbgen_media_cst_new_box_autoadd_compress_progress(void);
+
+struct wire_cst_concat_params *frbgen_media_cst_new_box_autoadd_concat_params(void);
+
+struct wire_cst_crop_rect *frbgen_media_cst_new_box_autoadd_crop_rect(void);
+
+struct wire_cst_ducking_params *frbgen_media_cst_new_box_autoadd_ducking_params(void);
+
+struct wire_cst_encoder_options *frbgen_media_cst_new_box_autoadd_encoder_options(void);
+
+float *frbgen_media_cst_new_box_autoadd_f_32(float value);
+
+double *frbgen_media_cst_new_box_autoadd_f_64(double value);
+
+struct wire_cst_frame_extract_params *frbgen_media_cst_new_box_autoadd_frame_extract_params(void);
+
+struct wire_cst_gps_location *frbgen_media_cst_new_box_autoadd_gps_location(void);
+
+int32_t *frbgen_media_cst_new_box_autoadd_hash_algorithm(int32_t value);
+
+int32_t *frbgen_media_cst_new_box_autoadd_hdr_format(int32_t value);
+
+int32_t *frbgen_media_cst_new_box_autoadd_i_32(int32_t value);
+
+int64_t *frbgen_media_cst_new_box_autoadd_i_64(int64_t value);
+
 struct wire_cst_image_thumbnail_params *frbgen_media_cst_new_box_autoadd_image_thumbnail_params(void);
 
+struct wire_cst_loudness_normalization *frbgen_media_cst_new_box_autoadd_loudness_normalization(void);
+
+struct wire_cst_media_hash *frbgen_media_cst_new_box_autoadd_media_hash(void);
+
 int32_t *frbgen_media_cst_new_box_autoadd_output_format(int32_t value);
 
+struct wire_cst_palette_color *frbgen_media_cst_new_box_autoadd_palette_color(void);
+
 struct wire_cst_record_u_32_u_32 *frbgen_media_cst_new_box_autoadd_record_u_32_u_32(void);
 
+int32_t *frbgen_media_cst_new_box_autoadd_resize_mode(int32_t value);
+
+int32_t *frbgen_media_cst_new_box_autoadd_reverse_audio_mode(int32_t value);
+
+struct wire_cst_reverse_params *frbgen_media_cst_new_box_autoadd_reverse_params(void);
+
+int32_t *frbgen_media_cst_new_box_autoadd_seek_mode(int32_t value);
+
+struct wire_cst_speed_params *frbgen_media_cst_new_box_autoadd_speed_params(void);
+
+struct wire_cst_subtitle_params *frbgen_media_cst_new_box_autoadd_subtitle_params(void);
+
 struct wire_cst_thumbnail_size_type *frbgen_media_cst_new_box_autoadd_thumbnail_size_type(void);
 
+uint16_t *frbgen_media_cst_new_box_autoadd_u_16(uint16_t value);
+
 uint32_t *frbgen_media_cst_new_box_autoadd_u_32(uint32_t value);
 
 uint64_t *frbgen_media_cst_new_box_autoadd_u_64(uint64_t value);
 
 uint8_t *frbgen_media_cst_new_box_autoadd_u_8(uint8_t value);
 
+struct wire_cst_video_stream_probe *frbgen_media_cst_new_box_autoadd_video_stream_probe(void);
+
 struct wire_cst_video_thumbnail_params *frbgen_media_cst_new_box_autoadd_video_thumbnail_params(void);
 
+struct wire_cst_watermark_params *frbgen_media_cst_new_box_autoadd_watermark_params(void);
+
+int32_t *frbgen_media_cst_new_box_autoadd_watermark_position(int32_t value);
+
 struct wire_cst_write_to_files *frbgen_media_cst_new_box_autoadd_write_to_files(void);
 
+struct wire_cst_list_String *frbgen_media_cst_new_list_String(int32_t len);
+
+struct wire_cst_list_media_chapter *frbgen_media_cst_new_list_media_chapter(int32_t len);
+
+struct wire_cst_list_media_tag *frbgen_media_cst_new_list_media_tag(int32_t len);
+
+struct wire_cst_list_palette_color *frbgen_media_cst_new_list_palette_color(int32_t len);
+
+struct wire_cst_list_prim_u_64_strict *frbgen_media_cst_new_list_prim_u_64_strict(int32_t len);
+
+struct wire_cst_list_prim_u_8_loose *frbgen_media_cst_new_list_prim_u_8_loose(int32_t len);
+
 struct wire_cst_list_prim_u_8_strict *frbgen_media_cst_new_list_prim_u_8_strict(int32_t len);
 
 struct wire_cst_list_resolution_preset *frbgen_media_cst_new_list_resolution_preset(int32_t len);
+
+struct wire_cst_list_scene_cut *frbgen_media_cst_new_list_scene_cut(int32_t len);
+
+struct wire_cst_list_stream_probe *frbgen_media_cst_new_list_stream_probe(int32_t len);
+
+struct wire_cst_list_stream_side_data *frbgen_media_cst_new_list_stream_side_data(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_animation_options);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_aspect_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_channels);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_extract_options);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_mix_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_stream_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_stream_probe);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_audio_track_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_best_thumbnail_params);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_bool);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_compress_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_compress_progress);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_concat_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_crop_rect);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_ducking_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_encoder_options);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_f_32);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_f_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_frame_extract_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_gps_location);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_hash_algorithm);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_hdr_format);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_i_32);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_i_64);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_image_thumbnail_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_loudness_normalization);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_media_hash);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_output_format);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_palette_color);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_record_u_32_u_32);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_resize_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_reverse_audio_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_reverse_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_seek_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_speed_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_subtitle_params);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_thumbnail_size_type);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_u_16);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_u_64);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_u_8);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_video_stream_probe);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_video_thumbnail_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_watermark_params);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_watermark_position);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_box_autoadd_write_to_files);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_media_chapter);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_media_tag);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_palette_color);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_prim_u_64_strict);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_prim_u_8_loose);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_prim_u_8_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_resolution_preset);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_scene_cut);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_stream_probe);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_cst_new_list_stream_side_data);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__logger__debug_threads);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__logger__init_logger);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__logger__log);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__logger__reload_tracing_file_writer);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__add_music);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__analyze_loudness);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__animation_format_extension);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__audio_format_extension);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compare_media_hashes);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compress_video);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compute_blurhash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compute_image_hash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compute_thumbhash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__compute_video_hash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__concat_videos);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__decode_blurhash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__decode_thumbhash);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__detect_scenes);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__estimate_compression);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__export_animation);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__extract_audio);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__extract_frames);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__extract_palette);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_best_thumbnail);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_image_thumbnail);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_image_thumbnail_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_storyboard);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_video_thumbnail);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_video_thumbnail_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnail_data);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__get_image_info);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__get_video_info);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__output_format_extension);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__probe_media);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__probe_media_json);
+    dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__reverse_video);
     dummy_var ^= ((int64_t) (void*) frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
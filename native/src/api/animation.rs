@@ -0,0 +1,742 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use image::{ImageEncoder, RgbImage, RgbaImage};
+use tracing::{debug, info};
+
+use crate::api::extract::resolve_output_path;
+use crate::api::media::{AnimationFormat, AnimationOptions};
+use crate::api::pipeline::{rescale, MICROSECONDS};
+use crate::api::video::{self, rgb_frame_to_image, scale_to_fit};
+
+/// The size target lowers the frame rate down to this before it starts shrinking frames
+const MIN_FPS: u32 = 5;
+
+/// Frames are not shrunk below this width
+const MIN_WIDTH: u32 = 64;
+
+/// GIF delays are in centiseconds and viewers slow down anything faster than 2 cs
+const MAX_GIF_FPS: u32 = 50;
+
+/// Colors in the shared GIF palette
+const GIF_COLORS: usize = 256;
+
+/// Pixels sampled across all frames to build the GIF palette
+const PALETTE_SAMPLES: usize = 200_000;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Frame rate and width of the next, smaller attempt at a size target: first fewer
+/// frames, then smaller ones. None once both are at their minimum.
+fn next_attempt(fps: u32, width: u32) -> Option<(u32, u32)> {
+    if fps > MIN_FPS {
+        Some(((fps * 3 / 4).max(MIN_FPS), width))
+    } else if width > MIN_WIDTH {
+        Some((fps, (width * 4 / 5).max(MIN_WIDTH)))
+    } else {
+        None
+    }
+}
+
+/// Indexes into frames sampled at `base_fps` for playback at `fps`
+fn select_frames(count: usize, base_fps: u32, fps: u32) -> Vec<usize> {
+    let duration = count as f64 / base_fps as f64;
+    let output = ((duration * fps as f64).round() as usize).max(1);
+    (0..output)
+        .map(|i| ((i as f64 * base_fps as f64 / fps as f64) as usize).min(count - 1))
+        .collect()
+}
+
+/// Per-frame delays in ms, rounded to `unit_ms` without drifting from the frame rate
+fn frame_delays(count: usize, fps: u32, unit_ms: u32) -> Vec<u32> {
+    let units_per_second = 1000.0 / unit_ms as f64;
+    let at = |i: usize| (i as f64 * units_per_second / fps as f64).round() as u32;
+    (0..count)
+        .map(|i| (at(i + 1) - at(i)).max(1) * unit_ms)
+        .collect()
+}
+
+/// Shared palette for every GIF frame (median cut), with a nearest-color table
+/// indexed by 15-bit RGB
+struct Palette {
+    colors: Vec<[u8; 3]>,
+    lookup: Vec<u8>,
+}
+
+impl Palette {
+    fn new(frames: &[RgbImage]) -> Self {
+        let total: usize = frames
+            .iter()
+            .map(|f| (f.width() * f.height()) as usize)
+            .sum();
+        let step = (total / PALETTE_SAMPLES).max(1);
+        let mut samples: Vec<[u8; 3]> = frames
+            .iter()
+            .flat_map(|frame| frame.pixels().map(|p| p.0))
+            .step_by(step)
+            .collect();
+        if samples.is_empty() {
+            samples.push([0, 0, 0]);
+        }
+
+        // Split the box with the widest channel range at its median until there are enough
+        let mut boxes: Vec<Vec<[u8; 3]>> = vec![samples];
+        while boxes.len() < GIF_COLORS {
+            let widest = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, pixels)| pixels.len() > 1)
+                .map(|(i, pixels)| {
+                    let (channel, range) = channel_range(pixels);
+                    (i, channel, range)
+                })
+                .filter(|&(_, _, range)| range > 0)
+                .max_by_key(|&(_, _, range)| range);
+            let Some((index, channel, _)) = widest else {
+                break;
+            };
+            let mut pixels = boxes.swap_remove(index);
+            pixels.sort_unstable_by_key(|p| p[channel]);
+            let upper = pixels.split_off(pixels.len() / 2);
+            boxes.push(pixels);
+            boxes.push(upper);
+        }
+
+        let colors: Vec<[u8; 3]> = boxes
+            .iter()
+            .map(|pixels| {
+                let mut sum = [0u64; 3];
+                for pixel in pixels {
+                    for c in 0..3 {
+                        sum[c] += pixel[c] as u64;
+                    }
+                }
+                let n = pixels.len() as u64;
+                [(sum[0] / n) as u8, (sum[1] / n) as u8, (sum[2] / n) as u8]
+            })
+            .collect();
+
+        let lookup = (0..1usize << 15)
+            .map(|key| {
+                let center = [
+                    (((key >> 10) as u8) << 3) | 4,
+                    ((((key >> 5) & 31) as u8) << 3) | 4,
+                    (((key & 31) as u8) << 3) | 4,
+                ];
+                nearest(&colors, center) as u8
+            })
+            .collect();
+        Palette { colors, lookup }
+    }
+
+    fn index_of(&self, rgb: [u8; 3]) -> usize {
+        let key =
+            ((rgb[0] as usize >> 3) << 10) | ((rgb[1] as usize >> 3) << 5) | (rgb[2] as usize >> 3);
+        self.lookup[key] as usize
+    }
+
+    /// Map a frame onto the palette, with Floyd-Steinberg error diffusion when `dither` is set
+    fn apply(&self, frame: &RgbImage, dither: bool) -> RgbaImage {
+        let (width, height) = frame.dimensions();
+        let mut out = RgbaImage::new(width, height);
+        // Accumulated error (x16) for this row and the next, offset by one column
+        let mut current = vec![[0i16; 3]; width as usize + 2];
+        let mut next = current.clone();
+        for y in 0..height {
+            for x in 0..width as usize {
+                let pixel = frame.get_pixel(x as u32, y).0;
+                let wanted = if dither {
+                    [0, 1, 2].map(|c| (pixel[c] as i16 + current[x + 1][c] / 16).clamp(0, 255))
+                } else {
+                    pixel.map(|v| v as i16)
+                };
+                let color = self.colors[self.index_of(wanted.map(|v| v as u8))];
+                out.put_pixel(
+                    x as u32,
+                    y,
+                    image::Rgba([color[0], color[1], color[2], 255]),
+                );
+                if dither {
+                    for c in 0..3 {
+                        let e = wanted[c] - color[c] as i16;
+                        current[x + 2][c] += e * 7;
+                        next[x][c] += e * 3;
+                        next[x + 1][c] += e * 5;
+                        next[x + 2][c] += e;
+                    }
+                }
+            }
+            std::mem::swap(&mut current, &mut next);
+            next.fill([0; 3]);
+        }
+        out
+    }
+}
+
+/// Channel with the largest spread in `pixels`, and that spread
+fn channel_range(pixels: &[[u8; 3]]) -> (usize, u8) {
+    let mut min = [255u8; 3];
+    let mut max = [0u8; 3];
+    for pixel in pixels {
+        for c in 0..3 {
+            min[c] = min[c].min(pixel[c]);
+            max[c] = max[c].max(pixel[c]);
+        }
+    }
+    (0..3)
+        .map(|c| (c, max[c] - min[c]))
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+fn nearest(colors: &[[u8; 3]], rgb: [u8; 3]) -> usize {
+    colors
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, color)| {
+            (0..3)
+                .map(|c| {
+                    let d = color[c] as i32 - rgb[c] as i32;
+                    d * d
+                })
+                .sum::<i32>()
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+fn encode_gif(
+    frames: &[RgbImage],
+    delays: &[u32],
+    loop_count: u16,
+    dither: bool,
+) -> Result<Vec<u8>> {
+    use image::codecs::gif::{GifEncoder, Repeat};
+
+    let palette = Palette::new(frames);
+    let mut out = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut out);
+        encoder.set_repeat(match loop_count {
+            0 => Repeat::Infinite,
+            plays => Repeat::Finite(plays - 1),
+        })?;
+        for (frame, &delay) in frames.iter().zip(delays) {
+            // At most 256 colors per frame, so the encoder keeps the palette as-is
+            let indexed = palette.apply(frame, dither);
+            encoder.encode_frame(image::Frame::from_parts(
+                indexed,
+                0,
+                0,
+                image::Delay::from_numer_denom_ms(delay, 1),
+            ))?;
+        }
+    }
+    Ok(out)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn write_png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// (type, data) of each chunk in a PNG file
+fn png_chunks(png: &[u8]) -> Result<Vec<([u8; 4], &[u8])>> {
+    if !png.starts_with(&PNG_SIGNATURE) {
+        return Err(anyhow::anyhow!("Not a PNG stream"));
+    }
+    let mut chunks = Vec::new();
+    let mut offset = PNG_SIGNATURE.len();
+    while offset + 12 <= png.len() {
+        let length = u32::from_be_bytes(png[offset..offset + 4].try_into()?) as usize;
+        let kind: [u8; 4] = png[offset + 4..offset + 8].try_into()?;
+        let data = png
+            .get(offset + 8..offset + 8 + length)
+            .ok_or_else(|| anyhow::anyhow!("Truncated PNG chunk"))?;
+        chunks.push((kind, data));
+        offset += 12 + length;
+    }
+    Ok(chunks)
+}
+
+/// Encode each frame as a PNG and repackage the image data as APNG frames
+fn encode_apng(frames: &[RgbImage], delays: &[u32], loop_count: u16) -> Result<Vec<u8>> {
+    use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+
+    let mut out = PNG_SIGNATURE.to_vec();
+    let mut sequence = 0u32;
+    for (i, (frame, &delay)) in frames.iter().zip(delays).enumerate() {
+        let mut png = Vec::new();
+        PngEncoder::new_with_quality(&mut png, CompressionType::Best, FilterType::Adaptive)
+            .write_image(
+                frame.as_raw(),
+                frame.width(),
+                frame.height(),
+                image::ExtendedColorType::Rgb8,
+            )?;
+        let chunks = png_chunks(&png)?;
+
+        if i == 0 {
+            let (_, header) = chunks
+                .iter()
+                .find(|(kind, _)| kind == b"IHDR")
+                .ok_or_else(|| anyhow::anyhow!("PNG encoder wrote no IHDR"))?;
+            write_png_chunk(&mut out, b"IHDR", header);
+            let mut actl = (frames.len() as u32).to_be_bytes().to_vec();
+            actl.extend_from_slice(&(loop_count as u32).to_be_bytes());
+            write_png_chunk(&mut out, b"acTL", &actl);
+        }
+
+        let mut fctl = Vec::with_capacity(26);
+        fctl.extend_from_slice(&sequence.to_be_bytes());
+        fctl.extend_from_slice(&frame.width().to_be_bytes());
+        fctl.extend_from_slice(&frame.height().to_be_bytes());
+        fctl.extend_from_slice(&[0; 8]); // x/y offset
+        fctl.extend_from_slice(&(delay.min(u16::MAX as u32) as u16).to_be_bytes());
+        fctl.extend_from_slice(&1000u16.to_be_bytes());
+        fctl.extend_from_slice(&[0, 0]); // dispose none, blend source
+        write_png_chunk(&mut out, b"fcTL", &fctl);
+        sequence += 1;
+
+        for (_, data) in chunks.iter().filter(|(kind, _)| kind == b"IDAT") {
+            if i == 0 {
+                write_png_chunk(&mut out, b"IDAT", data);
+            } else {
+                let mut fdat = sequence.to_be_bytes().to_vec();
+                fdat.extend_from_slice(data);
+                write_png_chunk(&mut out, b"fdAT", &fdat);
+                sequence += 1;
+            }
+        }
+    }
+    write_png_chunk(&mut out, b"IEND", &[]);
+    Ok(out)
+}
+
+fn write_riff_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(kind);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        out.push(0);
+    }
+}
+
+fn push_u24(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes()[..3]);
+}
+
+/// The VP8L chunk (header and padding included) of a still lossless WebP file
+fn webp_bitstream_chunk(webp: &[u8]) -> Result<&[u8]> {
+    if webp.len() < 12 || &webp[0..4] != b"RIFF" || &webp[8..12] != b"WEBP" {
+        return Err(anyhow::anyhow!("Not a WebP stream"));
+    }
+    let mut offset = 12;
+    while offset + 8 <= webp.len() {
+        let kind = &webp[offset..offset + 4];
+        let length = u32::from_le_bytes(webp[offset + 4..offset + 8].try_into()?) as usize;
+        let end = offset + 8 + length + length % 2;
+        if kind == b"VP8L" {
+            return webp
+                .get(offset..end)
+                .ok_or_else(|| anyhow::anyhow!("Truncated WebP chunk"));
+        }
+        offset = end;
+    }
+    Err(anyhow::anyhow!("WebP encoder wrote no VP8L chunk"))
+}
+
+/// Encode each frame as a lossless WebP and repackage the bitstreams as animation frames
+fn encode_webp(frames: &[RgbImage], delays: &[u32], loop_count: u16) -> Result<Vec<u8>> {
+    use image::codecs::webp::WebPEncoder;
+
+    let (width, height) = frames
+        .first()
+        .map(|frame| frame.dimensions())
+        .ok_or_else(|| anyhow::anyhow!("No frames to encode"))?;
+
+    let mut body = b"WEBP".to_vec();
+    let mut vp8x = vec![0x02, 0, 0, 0]; // animation flag
+    push_u24(&mut vp8x, width - 1);
+    push_u24(&mut vp8x, height - 1);
+    write_riff_chunk(&mut body, b"VP8X", &vp8x);
+    let mut anim = vec![0, 0, 0, 0]; // background color
+    anim.extend_from_slice(&loop_count.to_le_bytes());
+    write_riff_chunk(&mut body, b"ANIM", &anim);
+
+    for (frame, &delay) in frames.iter().zip(delays) {
+        let mut webp = Vec::new();
+        WebPEncoder::new_lossless(&mut webp).write_image(
+            frame.as_raw(),
+            frame.width(),
+            frame.height(),
+            image::ExtendedColorType::Rgb8,
+        )?;
+        let mut anmf = Vec::new();
+        push_u24(&mut anmf, 0); // x / 2
+        push_u24(&mut anmf, 0); // y / 2
+        push_u24(&mut anmf, frame.width() - 1);
+        push_u24(&mut anmf, frame.height() - 1);
+        push_u24(&mut anmf, delay.min(0xFF_FFFF));
+        anmf.push(0x02); // no blending, no disposal
+        anmf.extend_from_slice(webp_bitstream_chunk(&webp)?);
+        write_riff_chunk(&mut body, b"ANMF", &anmf);
+    }
+
+    let mut out = b"RIFF".to_vec();
+    out.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    out.extend_from_slice(&body);
+    Ok(out)
+}
+
+fn encode(
+    format: AnimationFormat,
+    frames: &[RgbImage],
+    fps: u32,
+    options: &AnimationOptions,
+) -> Result<Vec<u8>> {
+    let loop_count = options.loop_count.unwrap_or(0);
+    match format {
+        AnimationFormat::GIF => encode_gif(
+            frames,
+            &frame_delays(frames.len(), fps, 10),
+            loop_count,
+            options.dither.unwrap_or(true),
+        ),
+        AnimationFormat::WEBP => {
+            encode_webp(frames, &frame_delays(frames.len(), fps, 1), loop_count)
+        }
+        AnimationFormat::APNG => {
+            encode_apng(frames, &frame_delays(frames.len(), fps, 1), loop_count)
+        }
+    }
+}
+
+/// Decode `[start_ms, start_ms + duration_ms)` sampled at `fps`, each frame upright and at
+/// most `max_width` wide. Every output slot gets the last frame shown at its time.
+fn decode_frames(
+    path: &str,
+    start_ms: u64,
+    duration_ms: u64,
+    fps: u32,
+    max_width: u32,
+) -> Result<Vec<RgbImage>> {
+    let mut ictx = video::open_input(path)?;
+    let stream = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", path))?;
+    let stream_index = stream.index();
+    let time_base = stream.time_base();
+    let stream_start_us = match stream.start_time() {
+        ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+        start => rescale(start, time_base, MICROSECONDS),
+    };
+    let mut decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+        .decoder()
+        .video()
+        .context("Failed to open video decoder")?;
+    if decoder.width() == 0 || decoder.height() == 0 {
+        return Err(anyhow::anyhow!("Invalid video dimensions (0x0)"));
+    }
+    let (display_width, display_height, rotation) = video::get_display_dimensions_with_format(
+        &ictx,
+        &stream,
+        decoder.width(),
+        decoder.height(),
+    );
+    let (width, height) = scale_to_fit(display_width, display_height, max_width, display_height);
+    let (scaled_width, scaled_height) = if rotation == 90 || rotation == 270 {
+        (height.max(1), width.max(1))
+    } else {
+        (width.max(1), height.max(1))
+    };
+
+    let start_us = start_ms as i64 * 1000;
+    let end_us = start_us + duration_ms as i64 * 1000;
+    let slots = ((duration_ms * fps as u64).div_ceil(1000)).max(1) as usize;
+    let slot_us = |slot: usize| start_us + (slot as i64 * 1_000_000) / fps as i64;
+
+    let target = stream_start_us + start_us;
+    if ictx.seek(target, ..target).is_err() {
+        ictx.seek(target, ..).context("Seek failed")?;
+    }
+
+    let mut scaler: Option<ffmpeg::software::scaling::Context> = None;
+    let mut rgb = ffmpeg::util::frame::video::Video::new(
+        ffmpeg::format::Pixel::RGB24,
+        scaled_width,
+        scaled_height,
+    );
+    let mut decoded = ffmpeg::util::frame::video::Video::empty();
+    let mut previous = ffmpeg::util::frame::video::Video::empty();
+    let mut has_previous = false;
+    let mut frames: Vec<RgbImage> = Vec::with_capacity(slots);
+
+    // Fill the slots before `until_us` with the previous frame
+    let mut fill = |frames: &mut Vec<RgbImage>,
+                    previous: &ffmpeg::util::frame::video::Video,
+                    until_us: i64|
+     -> Result<()> {
+        if frames.len() >= slots || slot_us(frames.len()) >= until_us {
+            return Ok(());
+        }
+        if scaler.is_none() {
+            scaler = Some(ffmpeg::software::scaling::Context::get(
+                previous.format(),
+                previous.width(),
+                previous.height(),
+                ffmpeg::format::Pixel::RGB24,
+                scaled_width,
+                scaled_height,
+                ffmpeg::software::scaling::flag::Flags::BILINEAR,
+            )?);
+        }
+        if let Some(ref mut scaler) = scaler {
+            scaler.run(previous, &mut rgb)?;
+        }
+        let image = rgb_frame_to_image(&rgb, rotation)?;
+        while frames.len() < slots && slot_us(frames.len()) < until_us {
+            frames.push(image.clone());
+        }
+        Ok(())
+    };
+
+    let mut receive =
+        |decoder: &mut ffmpeg::codec::decoder::Video, frames: &mut Vec<RgbImage>| -> Result<bool> {
+            while decoder.receive_frame(&mut decoded).is_ok() {
+                let Some(pts) = decoded.timestamp().or(decoded.pts()) else {
+                    continue;
+                };
+                let time_us = rescale(pts, time_base, MICROSECONDS) - stream_start_us;
+                if has_previous {
+                    fill(frames, &previous, time_us.min(end_us))?;
+                }
+                if time_us >= end_us {
+                    return Ok(true);
+                }
+                std::mem::swap(&mut decoded, &mut previous);
+                has_previous = true;
+            }
+            Ok(false)
+        };
+
+    let mut done = false;
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        if decoder.send_packet(&packet).is_err() {
+            continue;
+        }
+        if receive(&mut decoder, &mut frames)? {
+            done = true;
+            break;
+        }
+    }
+    if !done {
+        decoder.send_eof().ok();
+        receive(&mut decoder, &mut frames)?;
+    }
+    // The last decoded frame stays on screen until the end of the range (or the clip)
+    if has_previous {
+        fill(&mut frames, &previous, end_us)?;
+    }
+
+    if frames.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No frames decoded between {} ms and {} ms",
+            start_ms,
+            start_ms + duration_ms
+        ));
+    }
+    Ok(frames)
+}
+
+/// Export `[start_ms, start_ms + duration_ms)` of `path` as an animated GIF, WebP or APNG.
+/// With `max_bytes` the frame rate and then the width are lowered until the file fits.
+#[allow(clippy::too_many_arguments)]
+pub fn export_animation(
+    path: &str,
+    output_path: &str,
+    start_ms: u64,
+    duration_ms: u64,
+    fps: u32,
+    max_width: u32,
+    format: AnimationFormat,
+    options: &AnimationOptions,
+) -> Result<String> {
+    if duration_ms == 0 || fps == 0 || max_width == 0 {
+        return Err(anyhow::anyhow!(
+            "duration_ms, fps and max_width must be greater than 0"
+        ));
+    }
+    let fps = match format {
+        AnimationFormat::GIF => fps.min(MAX_GIF_FPS),
+        _ => fps,
+    };
+
+    video::init_ffmpeg()?;
+    let base_frames = {
+        #[cfg(target_os = "windows")]
+        let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+            .lock()
+            .expect("Failed to acquire serialization mutex");
+        decode_frames(path, start_ms, duration_ms, fps, max_width)?
+    };
+    let base_width = base_frames[0].width();
+    let base_height = base_frames[0].height();
+    info!(
+        "export_animation - {} frames at {} fps, {}x{}, {:?}",
+        base_frames.len(),
+        fps,
+        base_width,
+        base_height,
+        format
+    );
+
+    let output = resolve_output_path(path, output_path, format.extension())?;
+    let (mut attempt_fps, mut attempt_width) = (fps, base_width);
+    loop {
+        let selected: Vec<RgbImage> = select_frames(base_frames.len(), fps, attempt_fps)
+            .into_iter()
+            .map(|i| {
+                if attempt_width == base_width {
+                    base_frames[i].clone()
+                } else {
+                    let height = ((base_height as u64 * attempt_width as u64) / base_width as u64)
+                        .max(1) as u32;
+                    image::imageops::resize(
+                        &base_frames[i],
+                        attempt_width,
+                        height,
+                        image::imageops::FilterType::Triangle,
+                    )
+                }
+            })
+            .collect();
+        let bytes = encode(format, &selected, attempt_fps, options)?;
+        debug!(
+            "export_animation - {} fps, {} px wide: {} bytes",
+            attempt_fps,
+            attempt_width,
+            bytes.len()
+        );
+
+        let fits = options
+            .max_bytes
+            .is_none_or(|max_bytes| bytes.len() as u64 <= max_bytes);
+        if fits {
+            std::fs::write(&output, &bytes)
+                .with_context(|| format!("Failed to write animation to: {}", output.display()))?;
+            return Ok(output.to_string_lossy().to_string());
+        }
+        match next_attempt(attempt_fps, attempt_width) {
+            Some((next_fps, next_width)) => {
+                attempt_fps = next_fps.min(fps);
+                attempt_width = next_width.min(base_width);
+            }
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Could not fit the animation in {} bytes (smallest attempt: {} bytes at {} fps, {} px wide)",
+                    options.max_bytes.unwrap_or(0),
+                    bytes.len(),
+                    attempt_fps,
+                    attempt_width
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(width: u32, height: u32, shift: u8) -> RgbImage {
+        RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([
+                (x * 255 / width) as u8,
+                (y * 255 / height) as u8,
+                shift.wrapping_mul(40),
+            ])
+        })
+    }
+
+    #[test]
+    fn test_attempts_and_timing() {
+        assert_eq!(next_attempt(20, 480), Some((15, 480)));
+        assert_eq!(next_attempt(6, 480), Some((5, 480)));
+        assert_eq!(next_attempt(5, 480), Some((5, 384)));
+        assert_eq!(next_attempt(5, 70), Some((5, 64)));
+        assert_eq!(next_attempt(5, 64), None);
+
+        assert_eq!(
+            select_frames(20, 20, 10),
+            vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18]
+        );
+        assert_eq!(select_frames(3, 15, 10), vec![0, 1]);
+
+        // 15 fps in centiseconds alternates 7/6/7 without drifting
+        let delays = frame_delays(3, 15, 10);
+        assert_eq!(delays, vec![70, 60, 70]);
+        assert_eq!(frame_delays(30, 15, 10).iter().sum::<u32>(), 2000);
+    }
+
+    #[test]
+    fn test_palette_keeps_gif_colors() {
+        let frames = vec![gradient(64, 32, 0), gradient(64, 32, 1)];
+        let palette = Palette::new(&frames);
+        assert!(palette.colors.len() <= GIF_COLORS);
+        let mapped = palette.apply(&frames[0], true);
+        let distinct: std::collections::BTreeSet<[u8; 4]> = mapped.pixels().map(|p| p.0).collect();
+        assert!(distinct.len() <= GIF_COLORS);
+
+        let gif = encode_gif(&frames, &[100, 100], 0, true).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+
+    #[test]
+    fn test_animated_containers() {
+        let frames = vec![gradient(16, 8, 0), gradient(16, 8, 3), gradient(16, 8, 5)];
+        let delays = frame_delays(frames.len(), 10, 1);
+
+        let apng = encode_apng(&frames, &delays, 0).unwrap();
+        let chunks = png_chunks(&apng).unwrap();
+        let kinds: Vec<&[u8; 4]> = chunks.iter().map(|(kind, _)| kind).collect();
+        assert_eq!(kinds[0], b"IHDR");
+        assert_eq!(kinds[1], b"acTL");
+        assert_eq!(kinds.iter().filter(|k| **k == b"fcTL").count(), 3);
+        assert_eq!(kinds.last().copied(), Some(b"IEND"));
+        // Still a valid PNG (first frame) for viewers without APNG support
+        let first = image::load_from_memory(&apng).unwrap();
+        assert_eq!((first.width(), first.height()), (16, 8));
+
+        let webp = encode_webp(&frames, &delays, 2).unwrap();
+        assert_eq!(&webp[0..4], b"RIFF");
+        assert_eq!(
+            u32::from_le_bytes(webp[4..8].try_into().unwrap()) as usize,
+            webp.len() - 8
+        );
+        let anmf = webp.windows(4).filter(|w| *w == b"ANMF").count();
+        assert_eq!(anmf, 3);
+    }
+}
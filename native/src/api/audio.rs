@@ -0,0 +1,476 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+
+use crate::api::filters::FilterChain;
+use crate::api::speed::TimeStretch;
+
+/// Output sample rate for audio the in-process pipelines re-encode
+pub const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+
+/// AAC bitrate used when transcoding (matches `perform_compression`)
+pub const AAC_BIT_RATE: usize = 192_000;
+
+/// Planar stereo f32 samples waiting to be encoded
+#[derive(Debug, Default, Clone)]
+pub struct StereoBuffer {
+    pub left: Vec<f32>,
+    pub right: Vec<f32>,
+}
+
+impl StereoBuffer {
+    pub fn len(&self) -> usize {
+        self.left.len().min(self.right.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append a planar float frame; mono is duplicated to both channels
+    pub fn push_frame(&mut self, frame: &ffmpeg::util::frame::audio::Audio) {
+        let samples = frame.samples();
+        if samples == 0 {
+            return;
+        }
+        if frame.planes() >= 2 {
+            self.left.extend_from_slice(&frame.plane::<f32>(0)[..samples]);
+            self.right.extend_from_slice(&frame.plane::<f32>(1)[..samples]);
+        } else {
+            let mono = &frame.plane::<f32>(0)[..samples];
+            self.left.extend_from_slice(mono);
+            self.right.extend_from_slice(mono);
+        }
+    }
+
+    pub fn push_silence(&mut self, samples: usize) {
+        self.left.extend(std::iter::repeat_n(0.0, samples));
+        self.right.extend(std::iter::repeat_n(0.0, samples));
+    }
+
+    /// Drop up to `samples` from the end of the buffer
+    pub fn truncate_back(&mut self, samples: usize) {
+        let keep = self.len().saturating_sub(samples);
+        self.left.truncate(keep);
+        self.right.truncate(keep);
+    }
+
+    pub fn drain_front(&mut self, samples: usize) -> (Vec<f32>, Vec<f32>) {
+        let samples = samples.min(self.len());
+        (
+            self.left.drain(..samples).collect(),
+            self.right.drain(..samples).collect(),
+        )
+    }
+}
+
+/// Copy `source` into an encoder plane, scaled by `gain`
+fn copy_with_gain(destination: &mut [f32], source: &[f32], gain: f32) {
+    if gain == 1.0 {
+        destination.copy_from_slice(source);
+    } else {
+        for (out, sample) in destination.iter_mut().zip(source) {
+            *out = sample * gain;
+        }
+    }
+}
+
+/// Decodes one input audio stream and resamples it to stereo FLTP at a fixed rate, optionally
+/// running an `audio_filters` chain before and a time stretch after resampling.
+/// The resampler is created from the first decoded frame, so streams whose decoder
+/// context lacks a channel layout still work.
+pub struct AudioResampler {
+    decoder: ffmpeg::codec::decoder::Audio,
+    filter: Option<FilterChain>,
+    resampler: Option<ffmpeg::software::resampling::Context>,
+    stretch: Option<TimeStretch>,
+    out_rate: u32,
+    decoded: ffmpeg::util::frame::audio::Audio,
+}
+
+impl AudioResampler {
+    pub fn new(parameters: ffmpeg::codec::Parameters, out_rate: u32) -> Result<Self> {
+        let mut decoder = ffmpeg::codec::context::Context::from_parameters(parameters)?
+            .decoder()
+            .audio()
+            .context("Failed to open audio decoder")?;
+        // Set channel layout if missing (common in some containers)
+        if decoder.channel_layout().is_empty() {
+            let channels = decoder.channels().max(1) as i32;
+            decoder.set_channel_layout(ffmpeg::util::channel_layout::ChannelLayout::default(
+                channels,
+            ));
+        }
+        Ok(AudioResampler {
+            decoder,
+            filter: None,
+            resampler: None,
+            stretch: None,
+            out_rate,
+            decoded: ffmpeg::util::frame::audio::Audio::empty(),
+        })
+    }
+
+    /// Run decoded frames through the `audio_filters` chain `spec` before resampling
+    pub fn filtered(mut self, spec: &str, time_base: ffmpeg::Rational) -> Result<Self> {
+        self.filter = Some(FilterChain::audio(spec, &self.decoder, time_base)?);
+        Ok(self)
+    }
+
+    /// Change the tempo of the resampled audio by `tempo` (pitch is kept)
+    pub fn stretched(mut self, tempo: f64) -> Self {
+        self.stretch = Some(TimeStretch::new(tempo, self.out_rate));
+        self
+    }
+
+    pub fn decoder_rate(&self) -> u32 {
+        self.decoder.rate()
+    }
+
+    pub fn decoder_channels(&self) -> u16 {
+        self.decoder.channels()
+    }
+
+    pub fn send_packet(&mut self, packet: &ffmpeg::Packet, out: &mut StereoBuffer) -> Result<()> {
+        self.decoder.send_packet(packet)?;
+        self.receive_frames(out)
+    }
+
+    /// Drain the decoder, the filters and the resampler delay at end of stream
+    pub fn flush(&mut self, out: &mut StereoBuffer) -> Result<()> {
+        self.decoder.send_eof().ok();
+        self.receive_frames(out)?;
+        if let Some(ref mut filter) = self.filter {
+            filter.flush()?;
+            self.receive_frames(out)?;
+        }
+        let mut flushed = ffmpeg::util::frame::audio::Audio::new(
+            ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar),
+            4096,
+            ffmpeg::util::channel_layout::ChannelLayout::STEREO,
+        );
+        let resampler_flushed = match self.resampler {
+            Some(ref mut resampler) => resampler.flush(&mut flushed).is_ok(),
+            None => false,
+        };
+        if resampler_flushed {
+            self.append(&flushed, out);
+        }
+        if let Some(ref mut stretch) = self.stretch {
+            stretch.finish(&mut out.left, &mut out.right);
+        }
+        Ok(())
+    }
+
+    /// Hand resampled samples to the time stretch, or straight to `out`
+    fn append(&mut self, resampled: &ffmpeg::util::frame::audio::Audio, out: &mut StereoBuffer) {
+        let samples = resampled.samples();
+        if samples == 0 {
+            return;
+        }
+        match self.stretch {
+            Some(ref mut stretch) => stretch.process(
+                &resampled.plane::<f32>(0)[..samples],
+                &resampled.plane::<f32>(1)[..samples],
+                &mut out.left,
+                &mut out.right,
+            ),
+            None => out.push_frame(resampled),
+        }
+    }
+
+    fn receive_frames(&mut self, out: &mut StereoBuffer) -> Result<()> {
+        loop {
+            let received = match self.filter {
+                Some(ref mut filter) => filter.receive(&mut self.decoder, &mut self.decoded)?,
+                None => self.decoder.receive_frame(&mut self.decoded).is_ok(),
+            };
+            if !received {
+                break;
+            }
+            if self.decoded.samples() == 0 {
+                continue;
+            }
+            if self.decoded.channel_layout().is_empty() {
+                let channels = self.decoded.channels().max(1) as i32;
+                self.decoded.set_channel_layout(
+                    ffmpeg::util::channel_layout::ChannelLayout::default(channels),
+                );
+            }
+            let in_rate = self.decoded.rate().max(1);
+            if self.resampler.is_none() {
+                self.resampler = Some(ffmpeg::software::resampling::Context::get(
+                    self.decoded.format(),
+                    self.decoded.channel_layout(),
+                    in_rate,
+                    ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar),
+                    ffmpeg::util::channel_layout::ChannelLayout::STEREO,
+                    self.out_rate,
+                )?);
+            }
+
+            // ceil(in_samples * out_rate / in_rate) plus headroom for the resampler delay
+            let out_samples =
+                (self.decoded.samples() as u64 * self.out_rate as u64).div_ceil(in_rate as u64)
+                    as usize
+                    + 64;
+            let mut resampled = ffmpeg::util::frame::audio::Audio::new(
+                ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar),
+                out_samples,
+                ffmpeg::util::channel_layout::ChannelLayout::STEREO,
+            );
+            resampled.set_rate(self.out_rate);
+            if let Some(ref mut resampler) = self.resampler {
+                resampler
+                    .run(&self.decoded, &mut resampled)
+                    .context("Audio resample failed")?;
+            }
+            self.append(&resampled, out);
+        }
+        Ok(())
+    }
+}
+
+/// Sample format handed to the encoder: planar float when supported (no conversion needed),
+/// otherwise the codec's first supported format
+fn encoder_sample_format(codec: ffmpeg::Codec) -> ffmpeg::format::Sample {
+    let planar_float = ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar);
+    let formats: Vec<ffmpeg::format::Sample> = codec
+        .audio()
+        .ok()
+        .and_then(|audio| audio.formats())
+        .map(|formats| formats.collect())
+        .unwrap_or_default();
+    if formats.is_empty() || formats.contains(&planar_float) {
+        planar_float
+    } else {
+        formats[0]
+    }
+}
+
+/// Audio encoder fed from a `StereoBuffer` in `frame_size` chunks, writing to one output stream.
+/// Chunks are built as planar float (downmixed for mono) and converted when the encoder
+/// wants another sample format.
+pub struct AudioWriter {
+    encoder: ffmpeg::codec::encoder::Audio,
+    stream_index: usize,
+    channels: u16,
+    converter: Option<ffmpeg::software::resampling::Context>,
+    next_pts: i64,
+    gain: f32,
+    pub encoded_size_bytes: u64,
+}
+
+impl AudioWriter {
+    /// Stereo AAC at `sample_rate`, as produced by the in-process compression path
+    pub fn aac(octx: &mut ffmpeg::format::context::Output, sample_rate: u32) -> Result<Self> {
+        let codec = ffmpeg::encoder::find(ffmpeg::codec::Id::AAC)
+            .ok_or(anyhow::anyhow!("AAC codec not found"))?;
+        Self::add_to(octx, codec, sample_rate, 2, AAC_BIT_RATE)
+    }
+
+    /// Open `codec` with 1 or 2 `channels` and add its stream to `octx` (before `write_header`).
+    /// `bit_rate` is ignored by lossless codecs.
+    pub fn add_to(
+        octx: &mut ffmpeg::format::context::Output,
+        codec: ffmpeg::Codec,
+        sample_rate: u32,
+        channels: u16,
+        bit_rate: usize,
+    ) -> Result<Self> {
+        let global_header = octx
+            .format()
+            .flags()
+            .contains(ffmpeg::format::flag::Flags::GLOBAL_HEADER);
+        let layout = if channels == 1 {
+            ffmpeg::util::channel_layout::ChannelLayout::MONO
+        } else {
+            ffmpeg::util::channel_layout::ChannelLayout::STEREO
+        };
+        let format = encoder_sample_format(codec);
+
+        let mut encoder = ffmpeg::codec::context::Context::new_with_codec(codec)
+            .encoder()
+            .audio()?;
+        encoder.set_rate(sample_rate as i32);
+        encoder.set_channel_layout(layout);
+        encoder.set_format(format);
+        if bit_rate > 0 {
+            encoder.set_bit_rate(bit_rate);
+        }
+        encoder.set_time_base(ffmpeg::util::rational::Rational(1, sample_rate as i32));
+        if global_header {
+            encoder.set_flags(ffmpeg::codec::flag::Flags::GLOBAL_HEADER);
+        }
+        // FFmpeg's native Opus encoder is still flagged experimental
+        if codec
+            .capabilities()
+            .contains(ffmpeg::codec::capabilities::Capabilities::EXPERIMENTAL)
+        {
+            encoder.compliance(ffmpeg::codec::Compliance::Experimental);
+        }
+        let encoder = encoder
+            .open_as(codec)
+            .map_err(|e| anyhow::anyhow!("Failed to open {} encoder: {:?}", codec.name(), e))?;
+
+        let planar_float = ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar);
+        let converter = if format != planar_float {
+            Some(ffmpeg::software::resampling::Context::get(
+                planar_float,
+                layout,
+                sample_rate,
+                format,
+                layout,
+                sample_rate,
+            )?)
+        } else {
+            None
+        };
+
+        let mut ost = octx.add_stream(codec)?;
+        ost.set_parameters(&encoder);
+        Ok(AudioWriter {
+            encoder,
+            stream_index: ost.index(),
+            channels: if channels == 1 { 1 } else { 2 },
+            converter,
+            next_pts: 0,
+            gain: 1.0,
+            encoded_size_bytes: 0,
+        })
+    }
+
+    /// Linear gain applied to everything written from now on (e.g. loudness normalisation)
+    pub fn set_gain(&mut self, gain: f32) {
+        self.gain = gain;
+    }
+
+    pub fn rate(&self) -> u32 {
+        self.encoder.rate()
+    }
+
+    pub fn stream_index(&self) -> usize {
+        self.stream_index
+    }
+
+    /// Samples already handed to the encoder
+    pub fn samples_written(&self) -> i64 {
+        self.next_pts
+    }
+
+    /// Samples per encoded frame; codecs without a fixed size (PCM) take 1024-sample chunks
+    fn chunk_size(&self) -> usize {
+        match self.encoder.frame_size() {
+            0 => 1024,
+            size => size as usize,
+        }
+    }
+
+    /// Encode every complete frame in `buffer`
+    pub fn write(
+        &mut self,
+        buffer: &mut StereoBuffer,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        let chunk_size = self.chunk_size();
+        while buffer.len() >= chunk_size {
+            let (left, right) = buffer.drain_front(chunk_size);
+            self.encode_chunk(&left, &right, octx)?;
+        }
+        Ok(())
+    }
+
+    /// Encode what is left and flush the encoder. Fixed-frame-size codecs get the last
+    /// frame padded with silence.
+    pub fn finish(
+        &mut self,
+        buffer: &mut StereoBuffer,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        self.write(buffer, octx)?;
+        if !buffer.is_empty() {
+            if self.encoder.frame_size() > 0 {
+                buffer.push_silence(self.chunk_size() - buffer.len());
+            }
+            let (left, right) = buffer.drain_front(buffer.len());
+            self.encode_chunk(&left, &right, octx)?;
+        }
+        self.encoder.send_eof().ok();
+        self.drain_packets(octx)
+    }
+
+    fn encode_chunk(
+        &mut self,
+        left: &[f32],
+        right: &[f32],
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        let mut frame = ffmpeg::util::frame::audio::Audio::new(
+            ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar),
+            left.len(),
+            self.encoder.channel_layout(),
+        );
+        frame.set_rate(self.encoder.rate());
+        if self.channels == 1 {
+            for (mono, (l, r)) in frame.plane_mut::<f32>(0).iter_mut().zip(left.iter().zip(right)) {
+                *mono = (l + r) * 0.5 * self.gain;
+            }
+        } else {
+            copy_with_gain(frame.plane_mut::<f32>(0), left, self.gain);
+            copy_with_gain(frame.plane_mut::<f32>(1), right, self.gain);
+        }
+
+        let mut frame = match self.converter {
+            Some(ref mut converter) => {
+                let mut converted = ffmpeg::util::frame::audio::Audio::empty();
+                converter
+                    .run(&frame, &mut converted)
+                    .context("Audio sample format conversion failed")?;
+                converted
+            }
+            None => frame,
+        };
+        frame.set_pts(Some(self.next_pts));
+        self.next_pts += left.len() as i64;
+
+        self.encoder
+            .send_frame(&frame)
+            .context("Audio encoder send_frame failed")?;
+        self.drain_packets(octx)
+    }
+
+    fn drain_packets(&mut self, octx: &mut ffmpeg::format::context::Output) -> Result<()> {
+        let out_time_base = octx
+            .stream(self.stream_index)
+            .ok_or_else(|| anyhow::anyhow!("Audio output stream not found"))?
+            .time_base();
+        let mut packet = ffmpeg::Packet::empty();
+        while self.encoder.receive_packet(&mut packet).is_ok() {
+            packet.set_stream(self.stream_index);
+            packet.rescale_ts(self.encoder.time_base(), out_time_base);
+            self.encoded_size_bytes += packet.size() as u64;
+            packet
+                .write_interleaved(octx)
+                .context("Failed to write audio packet")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stereo_buffer_silence_and_truncate() {
+        let mut buffer = StereoBuffer::default();
+        buffer.push_silence(10);
+        assert_eq!(buffer.len(), 10);
+        buffer.truncate_back(4);
+        assert_eq!(buffer.len(), 6);
+        let (left, right) = buffer.drain_front(4);
+        assert_eq!((left.len(), right.len()), (4, 4));
+        assert_eq!(buffer.len(), 2);
+        buffer.truncate_back(10);
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,745 @@
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use tracing::{debug, info};
+
+use crate::api::audio::{AudioResampler, AudioWriter, StereoBuffer, DEFAULT_SAMPLE_RATE};
+use crate::api::layout::{compute_layout, rotate_yuv420p, LayoutRenderer};
+use crate::api::media::{AspectMode, CompressParams, ConcatParams};
+use crate::api::pipeline::{
+    rescale, ConstantRateEncoder, Progress, DEFAULT_BITRATE_KBPS, DEFAULT_FPS, MAX_FPS,
+    MICROSECONDS,
+};
+use crate::api::video::{self, CompressionStats};
+
+#[derive(Debug, Clone)]
+struct ClipVideo {
+    codec_id: ffmpeg::codec::Id,
+    width: u32,
+    height: u32,
+    display_width: u32,
+    display_height: u32,
+    rotation: i32,
+    format: ffmpeg::format::Pixel,
+    fps: f64,
+    bit_rate: usize,
+    extradata: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct ClipAudio {
+    codec_id: ffmpeg::codec::Id,
+    rate: u32,
+    channels: u16,
+    extradata: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct ClipInfo {
+    path: String,
+    duration_us: i64,
+    video: ClipVideo,
+    audio: Option<ClipAudio>,
+}
+
+fn extradata(parameters: &ffmpeg::codec::Parameters) -> Vec<u8> {
+    unsafe {
+        let ptr = parameters.as_ptr();
+        if ptr.is_null() || (*ptr).extradata.is_null() || (*ptr).extradata_size <= 0 {
+            return Vec::new();
+        }
+        std::slice::from_raw_parts((*ptr).extradata, (*ptr).extradata_size as usize).to_vec()
+    }
+}
+
+fn probe_clip(path: &str) -> Result<ClipInfo> {
+    let ictx = video::open_input(path)?;
+    let stream = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Video)
+        .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", path))?;
+    let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+        .decoder()
+        .video()?;
+    let (display_width, display_height, rotation) = video::get_display_dimensions_with_format(
+        &ictx,
+        &stream,
+        decoder.width(),
+        decoder.height(),
+    );
+
+    let avg_rate = stream.avg_frame_rate();
+    let rate = if avg_rate.numerator() > 0 && avg_rate.denominator() > 0 {
+        avg_rate
+    } else {
+        stream.rate()
+    };
+    let fps = if rate.numerator() > 0 && rate.denominator() > 0 {
+        f64::from(rate)
+    } else {
+        0.0
+    };
+
+    let duration_us = if ictx.duration() > 0 {
+        ictx.duration()
+    } else if stream.duration() > 0 {
+        rescale(stream.duration(), stream.time_base(), MICROSECONDS)
+    } else {
+        0
+    };
+
+    let video = ClipVideo {
+        codec_id: stream.parameters().id(),
+        width: decoder.width(),
+        height: decoder.height(),
+        display_width,
+        display_height,
+        rotation,
+        format: decoder.format(),
+        fps,
+        bit_rate: decoder.bit_rate(),
+        extradata: extradata(&stream.parameters()),
+    };
+
+    let audio = ictx
+        .streams()
+        .best(ffmpeg::media::Type::Audio)
+        .and_then(|stream| {
+            let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())
+                .ok()?
+                .decoder()
+                .audio()
+                .ok()?;
+            Some(ClipAudio {
+                codec_id: stream.parameters().id(),
+                rate: decoder.rate(),
+                channels: decoder.channels(),
+                extradata: extradata(&stream.parameters()),
+            })
+        });
+
+    Ok(ClipInfo {
+        path: path.to_string(),
+        duration_us,
+        video,
+        audio,
+    })
+}
+
+/// Whether the clips can be joined by copying packets: same codecs and codec parameters
+/// (including the SPS/PPS in extradata), same orientation, and nothing to resize.
+fn can_stream_copy(clips: &[ClipInfo], params: &ConcatParams) -> bool {
+    if params.force_reencode.unwrap_or(false) {
+        return false;
+    }
+    let first = &clips[0];
+    if params.width.is_some_and(|w| w != first.video.display_width)
+        || params
+            .height
+            .is_some_and(|h| h != first.video.display_height)
+        || params
+            .fps
+            .is_some_and(|fps| (fps as f64 - first.video.fps).abs() > 0.01)
+    {
+        return false;
+    }
+    if !matches!(
+        first.video.codec_id,
+        ffmpeg::codec::Id::H264 | ffmpeg::codec::Id::HEVC | ffmpeg::codec::Id::MPEG4
+    ) {
+        return false;
+    }
+    if let Some(ref audio) = first.audio {
+        if !matches!(
+            audio.codec_id,
+            ffmpeg::codec::Id::AAC | ffmpeg::codec::Id::MP3
+        ) {
+            return false;
+        }
+    }
+
+    clips.iter().all(|clip| {
+        let video_matches = clip.video.codec_id == first.video.codec_id
+            && clip.video.width == first.video.width
+            && clip.video.height == first.video.height
+            && clip.video.format == first.video.format
+            && clip.video.rotation == first.video.rotation
+            && clip.video.extradata == first.video.extradata;
+        let audio_matches = match (&clip.audio, &first.audio) {
+            (None, None) => true,
+            (Some(a), Some(b)) => {
+                a.codec_id == b.codec_id
+                    && a.rate == b.rate
+                    && a.channels == b.channels
+                    && a.extradata == b.extradata
+            }
+            _ => false,
+        };
+        video_matches && audio_matches
+    })
+}
+
+/// Output display size for re-encoding: explicit params, else the first clip's display size
+fn output_size(first: &ClipVideo, params: &ConcatParams) -> (u32, u32) {
+    let (src_w, src_h) = (first.display_width.max(2), first.display_height.max(2));
+    let (w, h) = match (
+        params.width.filter(|w| *w > 0),
+        params.height.filter(|h| *h > 0),
+    ) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, (src_h as f64 * w as f64 / src_w as f64).round() as u32),
+        (None, Some(h)) => ((src_w as f64 * h as f64 / src_h as f64).round() as u32, h),
+        (None, None) => (src_w, src_h),
+    };
+    ((w & !1).max(2), (h & !1).max(2))
+}
+
+fn output_fps(first: &ClipVideo, params: &ConcatParams) -> u32 {
+    params
+        .fps
+        .filter(|fps| *fps > 0)
+        .unwrap_or_else(|| {
+            let fps = first.fps.round() as u32;
+            if fps > 0 {
+                fps
+            } else {
+                DEFAULT_FPS
+            }
+        })
+        .min(MAX_FPS)
+}
+
+/// Join clips into `output_path`. `on_progress` receives (processed_ms, total_ms).
+pub fn concat_videos(
+    inputs: &[String],
+    output_path: &str,
+    params: &ConcatParams,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<CompressionStats> {
+    if inputs.is_empty() {
+        return Err(anyhow::anyhow!("concat_videos needs at least one input"));
+    }
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    if let Some(parent) = Path::new(output_path).parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create output directory: {}", parent.display())
+            })?;
+        }
+    }
+
+    let clips = inputs
+        .iter()
+        .map(|path| probe_clip(path))
+        .collect::<Result<Vec<_>>>()?;
+    let total_ms = (clips.iter().map(|c| c.duration_us.max(0)).sum::<i64>() / 1000) as u64;
+    let mut progress = Progress::new(total_ms, &mut on_progress);
+
+    let stats = if can_stream_copy(&clips, params) {
+        info!(
+            "concat_videos - clips match, joining {} clips losslessly",
+            clips.len()
+        );
+        concat_stream_copy(&clips, output_path, &mut progress)?
+    } else {
+        info!(
+            "concat_videos - clips differ, re-encoding {} clips",
+            clips.len()
+        );
+        concat_reencode(&clips, output_path, params, &mut progress)?
+    };
+    progress.finish();
+    Ok(stats)
+}
+
+/// Lossless join: packets are copied and their timestamps shifted by the running duration
+fn concat_stream_copy(
+    clips: &[ClipInfo],
+    output_path: &str,
+    progress: &mut Progress,
+) -> Result<CompressionStats> {
+    let started = Instant::now();
+    let mut octx = ffmpeg::format::output(&output_path)
+        .with_context(|| format!("Failed to create output video file: {}", output_path))?;
+
+    // Output streams mirror the first clip; every clip has the same parameters
+    let (video_out, audio_out) = {
+        let first = video::open_input(&clips[0].path)?;
+        let video_in = first
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", clips[0].path))?;
+        let add_copy_stream = |octx: &mut ffmpeg::format::context::Output,
+                               stream: &ffmpeg::format::stream::Stream|
+         -> Result<usize> {
+            let mut ost = octx.add_stream(ffmpeg::encoder::find(stream.parameters().id()))?;
+            ost.set_parameters(stream.parameters());
+            ost.set_time_base(stream.time_base());
+            unsafe {
+                // Let the muxer pick the tag for its container (e.g. MOV -> MP4)
+                let codecpar = (*ost.as_mut_ptr()).codecpar;
+                if !codecpar.is_null() {
+                    (*codecpar).codec_tag = 0;
+                }
+            }
+            Ok(ost.index())
+        };
+        let video_out = add_copy_stream(&mut octx, &video_in)?;
+        let audio_out = match first.streams().best(ffmpeg::media::Type::Audio) {
+            Some(audio_in) if clips[0].audio.is_some() => {
+                Some(add_copy_stream(&mut octx, &audio_in)?)
+            }
+            _ => None,
+        };
+        (video_out, audio_out)
+    };
+
+    octx.write_header()
+        .context("Failed to write output header")?;
+
+    let out_time_bases = [
+        octx.stream(video_out).unwrap().time_base(),
+        audio_out
+            .map(|i| octx.stream(i).unwrap().time_base())
+            .unwrap_or(MICROSECONDS),
+    ];
+    let mut last_dts: [Option<i64>; 2] = [None, None];
+    let mut offset_us: i64 = 0;
+    let mut encoded_size_bytes = 0u64;
+
+    for clip in clips {
+        debug!(
+            "concat_stream_copy - appending {} at {} us",
+            clip.path, offset_us
+        );
+        let mut ictx = video::open_input(&clip.path)?;
+        let video_in = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .map(|s| s.index());
+        let audio_in = if audio_out.is_some() {
+            ictx.streams()
+                .best(ffmpeg::media::Type::Audio)
+                .map(|s| s.index())
+        } else {
+            None
+        };
+
+        // One shift per stream for the whole clip, moving its first DTS to the running end
+        // of the output. PTS and DTS move together, so B-frame reordering is kept intact.
+        let mut shift: [Option<i64>; 2] = [None, None];
+        let mut clip_end_us = offset_us;
+        for (stream, mut packet) in ictx.packets() {
+            let (slot, out_index) = if Some(stream.index()) == video_in {
+                (0, video_out)
+            } else if Some(stream.index()) == audio_in {
+                (1, audio_out.unwrap_or(video_out))
+            } else {
+                continue;
+            };
+
+            let in_tb = stream.time_base();
+            let out_tb = out_time_bases[slot];
+            let Some(packet_dts) = packet.dts().or(packet.pts()) else {
+                continue;
+            };
+            let shift = *shift[slot].get_or_insert_with(|| {
+                // First packet of this stream in the clip
+                let first_dts = rescale(packet_dts, in_tb, out_tb);
+                let shift = rescale(offset_us, MICROSECONDS, out_tb) - first_dts;
+                // Never step back behind what the previous clip already wrote
+                match last_dts[slot] {
+                    Some(last) if first_dts + shift <= last => last + 1 - first_dts,
+                    _ => shift,
+                }
+            });
+
+            let dts = rescale(packet_dts, in_tb, out_tb) + shift;
+            let pts = packet.pts().map(|pts| rescale(pts, in_tb, out_tb) + shift);
+            let duration = rescale(packet.duration().max(0), in_tb, out_tb);
+            last_dts[slot] = Some(dts);
+
+            if let Some(pts) = pts {
+                let end_us = rescale(pts + duration, out_tb, MICROSECONDS);
+                clip_end_us = clip_end_us.max(end_us);
+                if slot == 0 {
+                    progress.report((end_us / 1000).max(0) as u64);
+                }
+            }
+
+            packet.set_pts(pts);
+            packet.set_dts(Some(dts));
+            packet.set_duration(duration);
+            packet.set_position(-1);
+            packet.set_stream(out_index);
+            encoded_size_bytes += packet.size() as u64;
+            packet
+                .write_interleaved(&mut octx)
+                .context("Failed to write packet")?;
+        }
+
+        offset_us = if clip_end_us > offset_us {
+            clip_end_us
+        } else {
+            offset_us + clip.duration_us
+        };
+    }
+
+    octx.write_trailer().context("Write trailer failed")?;
+
+    Ok(CompressionStats {
+        processed_duration_ms: (offset_us / 1000) as u64,
+        elapsed_ms: started.elapsed().as_millis(),
+        encoded_size_bytes,
+        output_file_path: output_path.to_string(),
+    })
+}
+
+/// Per-clip video state for the re-encode path
+struct ClipRenderer {
+    renderer: LayoutRenderer,
+    canvas: ffmpeg::util::frame::video::Video,
+    rotation: i32,
+    time_base: ffmpeg::Rational,
+    frame_duration_us: i64,
+    offset_us: i64,
+    first_pts: Option<i64>,
+    end_us: i64,
+}
+
+impl ClipRenderer {
+    /// Render a decoded frame onto the normalised canvas and hand it to the encoder
+    fn process(
+        &mut self,
+        decoded: &mut ffmpeg::util::frame::video::Video,
+        encoder: &mut ConstantRateEncoder,
+        octx: &mut ffmpeg::format::context::Output,
+        progress: &mut Progress,
+    ) -> Result<()> {
+        let pts = decoded.timestamp().or(decoded.pts()).unwrap_or(0);
+        let first_pts = *self.first_pts.get_or_insert(pts);
+        let time_us = rescale(pts - first_pts, self.time_base, MICROSECONDS).max(0);
+        self.end_us = self.end_us.max(time_us + self.frame_duration_us);
+
+        // The encoder may still reference the previous buffer
+        unsafe {
+            ffmpeg::ffi::av_frame_make_writable(self.canvas.as_mut_ptr());
+        }
+        self.renderer.render(decoded, &mut self.canvas)?;
+
+        let global_us = self.offset_us + time_us;
+        if self.rotation != 0 {
+            let mut upright = rotate_yuv420p(&self.canvas, self.rotation);
+            encoder.push(&mut upright, global_us, octx)?;
+        } else {
+            encoder.push(&mut self.canvas, global_us, octx)?;
+        }
+        progress.report((global_us / 1000) as u64);
+        Ok(())
+    }
+
+    /// Hold the last frame until the clip's end so the next clip starts on time
+    fn finish(
+        &mut self,
+        encoder: &mut ConstantRateEncoder,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        if self.first_pts.is_none() {
+            return Ok(());
+        }
+        let end_us = self.offset_us + self.end_us;
+        if self.rotation != 0 {
+            let mut upright = rotate_yuv420p(&self.canvas, self.rotation);
+            encoder.fill_until(&mut upright, end_us, octx)
+        } else {
+            encoder.fill_until(&mut self.canvas, end_us, octx)
+        }
+    }
+}
+
+/// Normalising join: every clip is decoded, letterboxed onto a common upright canvas at a
+/// common frame rate and re-encoded as H.264; audio becomes stereo AAC, with silence for
+/// clips that have no audio track.
+fn concat_reencode(
+    clips: &[ClipInfo],
+    output_path: &str,
+    params: &ConcatParams,
+    progress: &mut Progress,
+) -> Result<CompressionStats> {
+    let started = Instant::now();
+    let (out_width, out_height) = output_size(&clips[0].video, params);
+    let fps = output_fps(&clips[0].video, params);
+    let bitrate_kbps = params
+        .target_bitrate_kbps
+        .filter(|b| *b > 0)
+        .unwrap_or_else(|| {
+            let max_input = clips.iter().map(|c| c.video.bit_rate).max().unwrap_or(0) / 1000;
+            if max_input > 0 {
+                max_input as u32
+            } else {
+                DEFAULT_BITRATE_KBPS
+            }
+        });
+    info!(
+        "concat_reencode - output {}x{} @ {} fps, {} kbps",
+        out_width, out_height, fps, bitrate_kbps
+    );
+
+    let mut octx = ffmpeg::format::output(&output_path)
+        .with_context(|| format!("Failed to create output video file: {}", output_path))?;
+    let global_header = octx
+        .format()
+        .flags()
+        .contains(ffmpeg::format::flag::Flags::GLOBAL_HEADER);
+
+    let codec = video::find_h264_encoder()?;
+    let encoder = video::open_h264_encoder(
+        codec,
+        out_width,
+        out_height,
+        fps,
+        bitrate_kbps,
+        params.crf,
+        params.preset.as_deref(),
+        global_header,
+    )?;
+    let video_out = {
+        let mut ost = octx.add_stream(codec)?;
+        ost.set_parameters(&encoder);
+        ost.index()
+    };
+    let mut audio_writer = if clips.iter().any(|c| c.audio.is_some()) {
+        Some(AudioWriter::aac(&mut octx, DEFAULT_SAMPLE_RATE)?)
+    } else {
+        None
+    };
+
+    octx.write_header()
+        .context("Failed to write output header")?;
+
+    let mut video_encoder = ConstantRateEncoder::new(encoder, video_out, fps);
+    let mut audio_buffer = StereoBuffer::default();
+    let mut offset_us: i64 = 0;
+
+    for clip in clips {
+        debug!(
+            "concat_reencode - appending {} at {} us",
+            clip.path, offset_us
+        );
+        let mut ictx = video::open_input(&clip.path)?;
+        let (video_in, time_base, mut decoder) = {
+            let stream = ictx
+                .streams()
+                .best(ffmpeg::media::Type::Video)
+                .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", clip.path))?;
+            let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+                .decoder()
+                .video()?;
+            (stream.index(), stream.time_base(), decoder)
+        };
+        let (audio_in, mut audio_decoder) = match ictx.streams().best(ffmpeg::media::Type::Audio) {
+            Some(stream) if audio_writer.is_some() => (
+                Some(stream.index()),
+                Some(AudioResampler::new(
+                    stream.parameters(),
+                    DEFAULT_SAMPLE_RATE,
+                )?),
+            ),
+            _ => (None, None),
+        };
+
+        // Letterbox the clip (in its display orientation) onto the output canvas
+        let layout_params = CompressParams {
+            width: Some(out_width),
+            height: Some(out_height),
+            aspect_ratio: Some((out_width, out_height)),
+            aspect_mode: Some(AspectMode::Fit),
+            ..Default::default()
+        };
+        let layout = compute_layout(
+            clip.video.display_width,
+            clip.video.display_height,
+            &layout_params,
+        )
+        .ok_or_else(|| anyhow::anyhow!("Invalid video dimensions in {}", clip.path))?;
+        let renderer = LayoutRenderer::new(
+            &layout,
+            clip.video.rotation,
+            clip.video.display_width,
+            clip.video.display_height,
+        );
+        let (canvas_w, canvas_h) = renderer.output_size();
+        let frame_duration_us = if clip.video.fps > 0.0 {
+            (1_000_000.0 / clip.video.fps) as i64
+        } else {
+            1_000_000 / DEFAULT_FPS as i64
+        };
+        let mut clip_renderer = ClipRenderer {
+            renderer,
+            canvas: ffmpeg::util::frame::video::Video::new(
+                ffmpeg::format::Pixel::YUV420P,
+                canvas_w,
+                canvas_h,
+            ),
+            rotation: clip.video.rotation,
+            time_base,
+            frame_duration_us,
+            offset_us,
+            first_pts: None,
+            end_us: 0,
+        };
+
+        let mut decoded = ffmpeg::util::frame::video::Video::empty();
+        for (stream, packet) in ictx.packets() {
+            if stream.index() == video_in {
+                decoder.send_packet(&packet)?;
+                while decoder.receive_frame(&mut decoded).is_ok() {
+                    clip_renderer.process(&mut decoded, &mut video_encoder, &mut octx, progress)?;
+                }
+            } else if Some(stream.index()) == audio_in {
+                if let (Some(audio_decoder), Some(writer)) =
+                    (audio_decoder.as_mut(), audio_writer.as_mut())
+                {
+                    audio_decoder.send_packet(&packet, &mut audio_buffer)?;
+                    writer.write(&mut audio_buffer, &mut octx)?;
+                }
+            }
+        }
+
+        decoder.send_eof().ok();
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            clip_renderer.process(&mut decoded, &mut video_encoder, &mut octx, progress)?;
+        }
+        clip_renderer.finish(&mut video_encoder, &mut octx)?;
+
+        let clip_duration_us = if clip_renderer.end_us > 0 {
+            clip_renderer.end_us
+        } else {
+            clip.duration_us
+        };
+        offset_us += clip_duration_us;
+
+        if let Some(writer) = audio_writer.as_mut() {
+            if let Some(audio_decoder) = audio_decoder.as_mut() {
+                audio_decoder.flush(&mut audio_buffer)?;
+            }
+            // Pad with silence (clips without audio) or trim so audio stays aligned to video
+            let target_samples = (offset_us as i128 * writer.rate() as i128 / 1_000_000) as i64;
+            let queued = writer.samples_written() + audio_buffer.len() as i64;
+            if queued < target_samples {
+                audio_buffer.push_silence((target_samples - queued) as usize);
+            } else {
+                audio_buffer.truncate_back((queued - target_samples) as usize);
+            }
+            writer.write(&mut audio_buffer, &mut octx)?;
+        }
+    }
+
+    video_encoder.finish(&mut octx)?;
+    let mut encoded_size_bytes = video_encoder.encoded_size_bytes();
+    if let Some(writer) = audio_writer.as_mut() {
+        writer.finish(&mut audio_buffer, &mut octx)?;
+        encoded_size_bytes += writer.encoded_size_bytes;
+    }
+    octx.write_trailer().context("Write trailer failed")?;
+
+    Ok(CompressionStats {
+        processed_duration_ms: (offset_us / 1000) as u64,
+        elapsed_ms: started.elapsed().as_millis(),
+        encoded_size_bytes,
+        output_file_path: output_path.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip(width: u32, height: u32, rotation: i32, audio: bool) -> ClipInfo {
+        let (display_width, display_height) = match rotation {
+            90 | 270 => (height, width),
+            _ => (width, height),
+        };
+        ClipInfo {
+            path: "clip.mp4".to_string(),
+            duration_us: 1_000_000,
+            video: ClipVideo {
+                codec_id: ffmpeg::codec::Id::H264,
+                width,
+                height,
+                display_width,
+                display_height,
+                rotation,
+                format: ffmpeg::format::Pixel::YUV420P,
+                fps: 30.0,
+                bit_rate: 2_000_000,
+                extradata: vec![1, 2, 3],
+            },
+            audio: audio.then(|| ClipAudio {
+                codec_id: ffmpeg::codec::Id::AAC,
+                rate: 44100,
+                channels: 2,
+                extradata: vec![4, 5],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_matching_clips_are_copied() {
+        let clips = vec![clip(1280, 720, 0, true), clip(1280, 720, 0, true)];
+        assert!(can_stream_copy(&clips, &ConcatParams::default()));
+        let forced = ConcatParams {
+            force_reencode: Some(true),
+            ..Default::default()
+        };
+        assert!(!can_stream_copy(&clips, &forced));
+    }
+
+    #[test]
+    fn test_mismatched_clips_are_reencoded() {
+        let base = clip(1280, 720, 0, true);
+        let params = ConcatParams::default();
+        assert!(!can_stream_copy(
+            &[base.clone(), clip(1920, 1080, 0, true)],
+            &params
+        ));
+        assert!(!can_stream_copy(
+            &[base.clone(), clip(1280, 720, 90, true)],
+            &params
+        ));
+        assert!(!can_stream_copy(
+            &[base.clone(), clip(1280, 720, 0, false)],
+            &params
+        ));
+        let resized = ConcatParams {
+            width: Some(640),
+            ..Default::default()
+        };
+        assert!(!can_stream_copy(&[base.clone(), base], &resized));
+    }
+
+    #[test]
+    fn test_output_size_and_fps() {
+        let portrait = clip(1920, 1080, 90, false).video;
+        assert_eq!(
+            output_size(&portrait, &ConcatParams::default()),
+            (1080, 1920)
+        );
+        let params = ConcatParams {
+            width: Some(541),
+            fps: Some(120),
+            ..Default::default()
+        };
+        assert_eq!(output_size(&portrait, &params), (540, 962));
+        assert_eq!(output_fps(&portrait, &params), MAX_FPS);
+        assert_eq!(output_fps(&portrait, &ConcatParams::default()), 30);
+    }
+}
@@ -0,0 +1,263 @@
+use anyhow::{Context, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::codecs::webp::WebPEncoder;
+use image::{DynamicImage, ExtendedColorType, ImageEncoder, RgbImage};
+use libheif_rs::CompressionFormat;
+
+use crate::api::media::{EncoderOptions, OutputFormat};
+
+/// Same as the image crate's JPEG default
+const DEFAULT_JPEG_QUALITY: u8 = 75;
+const DEFAULT_AVIF_QUALITY: u8 = 70;
+const DEFAULT_HEIC_QUALITY: u8 = 70;
+
+/// Tightly packed 8-bit RGB or RGBA pixels
+struct Pixels<'a> {
+    data: &'a [u8],
+    width: u32,
+    height: u32,
+    alpha: bool,
+}
+
+impl Pixels<'_> {
+    fn color_type(&self) -> ExtendedColorType {
+        if self.alpha {
+            ExtendedColorType::Rgba8
+        } else {
+            ExtendedColorType::Rgb8
+        }
+    }
+}
+
+/// Encodes an RGB image as `format` with the matching settings from `options`
+pub(crate) fn encode_rgb_image(
+    image: &RgbImage,
+    format: OutputFormat,
+    options: &EncoderOptions,
+) -> Result<Vec<u8>> {
+    encode_pixels(
+        &Pixels {
+            data: image.as_raw(),
+            width: image.width(),
+            height: image.height(),
+            alpha: false,
+        },
+        format,
+        options,
+    )
+}
+
+/// Encodes a still image, keeping transparency for every format but JPEG
+pub(crate) fn encode_image(
+    image: &DynamicImage,
+    format: OutputFormat,
+    options: &EncoderOptions,
+) -> Result<Vec<u8>> {
+    if !image.color().has_alpha() || matches!(format, OutputFormat::JPEG) {
+        return encode_rgb_image(&image.to_rgb8(), format, options);
+    }
+    let rgba = image.to_rgba8();
+    encode_pixels(
+        &Pixels {
+            data: rgba.as_raw(),
+            width: rgba.width(),
+            height: rgba.height(),
+            alpha: true,
+        },
+        format,
+        options,
+    )
+}
+
+fn encode_pixels(
+    pixels: &Pixels,
+    format: OutputFormat,
+    options: &EncoderOptions,
+) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    match format {
+        OutputFormat::PNG => {
+            let compression = match options.png_compression {
+                None => CompressionType::default(),
+                Some(0) => CompressionType::Uncompressed,
+                Some(level) => CompressionType::Level(level.min(9)),
+            };
+            PngEncoder::new_with_quality(&mut out, compression, FilterType::Adaptive).write_image(
+                pixels.data,
+                pixels.width,
+                pixels.height,
+                pixels.color_type(),
+            )?;
+        }
+        OutputFormat::JPEG => {
+            let quality = options
+                .jpeg_quality
+                .unwrap_or(DEFAULT_JPEG_QUALITY)
+                .clamp(1, 100);
+            if options.jpeg_progressive.unwrap_or(false) {
+                // The image crate only writes baseline JPEG
+                let (width, height) = (u16::try_from(pixels.width), u16::try_from(pixels.height));
+                let (Ok(width), Ok(height)) = (width, height) else {
+                    return Err(anyhow::anyhow!(
+                        "JPEG is limited to 65535x65535, got {}x{}",
+                        pixels.width,
+                        pixels.height
+                    ));
+                };
+                let mut encoder = jpeg_encoder::Encoder::new(&mut out, quality);
+                encoder.set_progressive(true);
+                let color = if pixels.alpha {
+                    jpeg_encoder::ColorType::Rgba
+                } else {
+                    jpeg_encoder::ColorType::Rgb
+                };
+                encoder
+                    .encode(pixels.data, width, height, color)
+                    .context("Failed to encode progressive JPEG")?;
+            } else {
+                JpegEncoder::new_with_quality(&mut out, quality).write_image(
+                    pixels.data,
+                    pixels.width,
+                    pixels.height,
+                    pixels.color_type(),
+                )?;
+            }
+        }
+        OutputFormat::WEBP => match options.webp_quality {
+            Some(quality) => {
+                let encoder = if pixels.alpha {
+                    webp::Encoder::from_rgba(pixels.data, pixels.width, pixels.height)
+                } else {
+                    webp::Encoder::from_rgb(pixels.data, pixels.width, pixels.height)
+                };
+                let memory = encoder
+                    .encode_simple(false, quality.clamp(0.0, 100.0))
+                    .map_err(|e| anyhow::anyhow!("Failed to encode lossy WebP: {:?}", e))?;
+                out.extend_from_slice(&memory);
+            }
+            None => {
+                WebPEncoder::new_lossless(&mut out).write_image(
+                    pixels.data,
+                    pixels.width,
+                    pixels.height,
+                    pixels.color_type(),
+                )?;
+            }
+        },
+        OutputFormat::AVIF => {
+            let quality = options
+                .avif_quality
+                .unwrap_or(DEFAULT_AVIF_QUALITY)
+                .clamp(1, 100);
+            out = encode_heif(pixels, CompressionFormat::Av1, quality)
+                .context("AVIF output needs libheif built with an AV1 encoder (libaom)")?;
+        }
+        OutputFormat::HEIC => {
+            let quality = options
+                .heic_quality
+                .unwrap_or(DEFAULT_HEIC_QUALITY)
+                .clamp(1, 100);
+            out = encode_heif(pixels, CompressionFormat::Hevc, quality)
+                .context("HEIC output needs libheif built with an HEVC encoder (x265)")?;
+        }
+    }
+    Ok(out)
+}
+
+/// Encodes through libheif, which picks the best encoder plugin it was built with
+fn encode_heif(pixels: &Pixels, format: CompressionFormat, quality: u8) -> Result<Vec<u8>> {
+    use libheif_rs::{Channel, ColorSpace, EncoderQuality, HeifContext, Image, LibHeif, RgbChroma};
+
+    let lib_heif = LibHeif::new();
+    let mut encoder = lib_heif
+        .encoder_for_format(format)
+        .with_context(|| format!("libheif has no {:?} encoder", format))?;
+    encoder.set_quality(EncoderQuality::Lossy(quality))?;
+
+    let chroma = if pixels.alpha {
+        RgbChroma::Rgba
+    } else {
+        RgbChroma::Rgb
+    };
+    let mut image = Image::new(pixels.width, pixels.height, ColorSpace::Rgb(chroma))?;
+    image.create_plane(Channel::Interleaved, pixels.width, pixels.height, 8)?;
+
+    // libheif rows are padded to its stride
+    let planes = image.planes_mut();
+    let plane = planes
+        .interleaved
+        .ok_or_else(|| anyhow::anyhow!("libheif image has no interleaved plane"))?;
+    let row_len = pixels.width as usize * if pixels.alpha { 4 } else { 3 };
+    for (y, row) in pixels.data.chunks_exact(row_len).enumerate() {
+        let start = y * plane.stride;
+        plane.data[start..start + row_len].copy_from_slice(row);
+    }
+
+    let mut context = HeifContext::new()?;
+    context.encode_image(&image, &mut encoder, None)?;
+    Ok(context.write_to_bytes()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient() -> RgbImage {
+        RgbImage::from_fn(64, 48, |x, y| {
+            image::Rgb([(x * 4) as u8, (y * 5) as u8, 128])
+        })
+    }
+
+    #[test]
+    fn test_png_compression_level() {
+        let image = gradient();
+        let encode = |level| {
+            let options = EncoderOptions {
+                png_compression: Some(level),
+                ..Default::default()
+            };
+            encode_rgb_image(&image, OutputFormat::PNG, &options).unwrap()
+        };
+        let (stored, best) = (encode(0), encode(9));
+        assert!(best.len() < stored.len());
+        let decoded = image::load_from_memory(&best).unwrap().to_rgb8();
+        assert_eq!(decoded, image);
+    }
+
+    #[test]
+    fn test_jpeg_quality_and_progressive() {
+        let image = gradient();
+        let encode = |quality, progressive| {
+            let options = EncoderOptions {
+                jpeg_quality: Some(quality),
+                jpeg_progressive: Some(progressive),
+                ..Default::default()
+            };
+            encode_rgb_image(&image, OutputFormat::JPEG, &options).unwrap()
+        };
+        assert!(encode(95, false).len() > encode(20, false).len());
+
+        let progressive = encode(80, true);
+        // SOF2 marks a progressive frame
+        assert!(progressive.windows(2).any(|marker| marker == [0xFF, 0xC2]));
+        let decoded = image::load_from_memory(&progressive).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (64, 48));
+    }
+
+    #[test]
+    fn test_alpha_is_kept_except_for_jpeg() {
+        let image = DynamicImage::ImageRgba8(image::RgbaImage::from_pixel(
+            8,
+            8,
+            image::Rgba([10, 20, 30, 100]),
+        ));
+        let options = EncoderOptions::default();
+
+        let png = encode_image(&image, OutputFormat::PNG, &options).unwrap();
+        assert!(image::load_from_memory(&png).unwrap().color().has_alpha());
+
+        let jpeg = encode_image(&image, OutputFormat::JPEG, &options).unwrap();
+        assert!(!image::load_from_memory(&jpeg).unwrap().color().has_alpha());
+    }
+}
@@ -0,0 +1,465 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use tracing::{debug, info};
+
+use crate::api::audio::{AudioResampler, AudioWriter, StereoBuffer};
+use crate::api::loudness::{self, LoudnessMeter};
+use crate::api::media::{AudioChannels, AudioExtractOptions, AudioFormat};
+use crate::api::pipeline::{rescale, MICROSECONDS};
+use crate::api::video::{self, check_output_path, get_file_name_without_extension};
+
+/// Opus is only encoded at 48 kHz
+const OPUS_SAMPLE_RATE: u32 = 48_000;
+
+/// Source audio stream as far as the copy/transcode decision is concerned
+struct SourceAudio {
+    codec: ffmpeg::codec::Id,
+    channels: u16,
+    sample_rate: u32,
+}
+
+/// Extracted part of the audio stream, in the stream time base
+struct TrimRange {
+    time_base: ffmpeg::Rational,
+    stream_start: i64,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+}
+
+impl TrimRange {
+    /// Seek `ictx` to the last keyframe before the range
+    fn seek(&self, ictx: &mut ffmpeg::format::context::Input) -> Result<()> {
+        if let Some(start_ts) = self.start_ts {
+            let target = rescale(start_ts, self.time_base, MICROSECONDS);
+            if ictx.seek(target, ..target).is_err() {
+                ictx.seek(target, ..).context("Seek failed")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Position of `ts` in samples at `rate`
+    fn sample_at(&self, ts: i64, rate: u32) -> i64 {
+        rescale(
+            ts - self.stream_start,
+            self.time_base,
+            ffmpeg::util::rational::Rational(1, rate as i32),
+        )
+    }
+}
+
+/// Decode the range from `ictx` (already seeked) and hand the trimmed samples to `sink`
+/// as they arrive. `sink` takes what it can use; whatever is left is returned at the end.
+fn decode_range(
+    ictx: &mut ffmpeg::format::context::Input,
+    audio_index: usize,
+    range: &TrimRange,
+    rate: u32,
+    mut resampler: AudioResampler,
+    mut sink: impl FnMut(&mut StereoBuffer) -> Result<()>,
+) -> Result<StereoBuffer> {
+    let start_sample = range
+        .start_ts
+        .map(|ts| range.sample_at(ts, rate))
+        .unwrap_or(0);
+    let end_sample = range.end_ts.map(|ts| range.sample_at(ts, rate));
+
+    let trim = |buffer: &mut StereoBuffer, position: &mut i64| -> bool {
+        if *position < start_sample {
+            let skip = ((start_sample - *position) as usize).min(buffer.len());
+            buffer.drain_front(skip);
+            *position += skip as i64;
+        }
+        match end_sample {
+            Some(end) if *position + buffer.len() as i64 >= end => {
+                let excess = (*position + buffer.len() as i64 - end).max(0) as usize;
+                buffer.truncate_back(excess.min(buffer.len()));
+                true
+            }
+            _ => false,
+        }
+    };
+
+    let mut buffer = StereoBuffer::default();
+    let mut position: Option<i64> = None; // sample position of buffer[0]
+    let mut done = false;
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != audio_index {
+            continue;
+        }
+        let front = position.get_or_insert_with(|| {
+            packet
+                .pts()
+                .map(|pts| range.sample_at(pts, rate))
+                .unwrap_or(0)
+        });
+        resampler.send_packet(&packet, &mut buffer)?;
+        done = trim(&mut buffer, front);
+        let before = buffer.len();
+        sink(&mut buffer)?;
+        *front += (before - buffer.len()) as i64;
+        if done {
+            break;
+        }
+    }
+    if !done {
+        resampler.flush(&mut buffer)?;
+        if let Some(ref mut front) = position {
+            trim(&mut buffer, front);
+        }
+    }
+    Ok(buffer)
+}
+
+/// Muxer used for each format
+fn muxer(format: AudioFormat) -> &'static str {
+    match format {
+        AudioFormat::M4A => "ipod",
+        AudioFormat::OPUS => "opus",
+        AudioFormat::WAV => "wav",
+    }
+}
+
+fn encoder_id(format: AudioFormat) -> ffmpeg::codec::Id {
+    match format {
+        AudioFormat::M4A => ffmpeg::codec::Id::AAC,
+        AudioFormat::OPUS => ffmpeg::codec::Id::OPUS,
+        AudioFormat::WAV => ffmpeg::codec::Id::PCM_S16LE,
+    }
+}
+
+fn default_bitrate_kbps(format: AudioFormat) -> u32 {
+    match format {
+        AudioFormat::M4A => 192,
+        AudioFormat::OPUS => 128,
+        AudioFormat::WAV => 0,
+    }
+}
+
+/// Channel count of the output: the requested one, else mono stays mono and
+/// everything else is downmixed to stereo
+fn output_channels(options: &AudioExtractOptions, source_channels: u16) -> u16 {
+    match options.channels {
+        Some(AudioChannels::Mono) => 1,
+        Some(AudioChannels::Stereo) => 2,
+        None if source_channels == 1 => 1,
+        None => 2,
+    }
+}
+
+fn output_rate(format: AudioFormat, options: &AudioExtractOptions, source_rate: u32) -> u32 {
+    match format {
+        AudioFormat::OPUS => OPUS_SAMPLE_RATE,
+        _ => options.sample_rate.unwrap_or(source_rate),
+    }
+}
+
+/// Packets can be copied as-is when the codec already fits the container and nothing
+/// about the encoding is asked to change. Trimming works on packet boundaries.
+fn can_stream_copy(
+    format: AudioFormat,
+    source: &SourceAudio,
+    options: &AudioExtractOptions,
+) -> bool {
+    source.codec == encoder_id(format)
+        && source.channels <= 2
+        && output_channels(options, source.channels) == source.channels
+        && output_rate(format, options, source.sample_rate) == source.sample_rate
+        && options.bitrate_kbps.is_none()
+        && options.normalize_loudness.is_none()
+}
+
+/// A path with an extension is used as the output file; anything else is treated as a
+/// directory that receives `<input name>.<extension>`
+pub(crate) fn resolve_output_path(
+    path: &str,
+    output_path: &str,
+    extension: &str,
+) -> Result<PathBuf> {
+    let output = PathBuf::from(output_path);
+    if output.extension().is_some() && !output.is_dir() {
+        if let Some(parent) = output.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).with_context(|| {
+                    format!("Failed to create output directory: {}", parent.display())
+                })?;
+            }
+        }
+        return Ok(output);
+    }
+    let dir = check_output_path(output_path)?;
+    Ok(dir.join(get_file_name_without_extension(path).with_extension(extension)))
+}
+
+pub fn extract_audio(
+    path: &str,
+    output_path: &str,
+    format: AudioFormat,
+    options: &AudioExtractOptions,
+) -> Result<String> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    if let (Some(start), Some(end)) = (options.start_ms, options.end_ms) {
+        if end <= start {
+            return Err(anyhow::anyhow!(
+                "end_ms ({}) must be greater than start_ms ({})",
+                end,
+                start
+            ));
+        }
+    }
+    if options.sample_rate == Some(0) {
+        return Err(anyhow::anyhow!("sample_rate must be greater than 0"));
+    }
+
+    let output = resolve_output_path(path, output_path, format.extension())?;
+    let output_str = output.to_string_lossy().to_string();
+
+    let mut ictx = video::open_input(path)?;
+    let (audio_index, time_base, stream_start, source) = {
+        let stream = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Audio)
+            .ok_or_else(|| anyhow::anyhow!("No audio stream found in {}", path))?;
+        let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+            .decoder()
+            .audio()
+            .context("Failed to open audio decoder")?;
+        let start = match stream.start_time() {
+            ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+            start => start,
+        };
+        (
+            stream.index(),
+            stream.time_base(),
+            start,
+            SourceAudio {
+                codec: stream.parameters().id(),
+                channels: decoder.channels(),
+                sample_rate: decoder.rate(),
+            },
+        )
+    };
+
+    // Trim bounds in the stream time base
+    let range = TrimRange {
+        time_base,
+        stream_start,
+        start_ts: options
+            .start_ms
+            .map(|ms| stream_start + rescale(ms as i64 * 1000, MICROSECONDS, time_base)),
+        end_ts: options
+            .end_ms
+            .map(|ms| stream_start + rescale(ms as i64 * 1000, MICROSECONDS, time_base)),
+    };
+    range.seek(&mut ictx)?;
+
+    let mut octx = ffmpeg::format::output_as(&output, muxer(format))
+        .with_context(|| format!("Failed to create output audio file: {}", output_str))?;
+
+    let copy = can_stream_copy(format, &source, options);
+    info!(
+        "extract_audio - {:?} -> {:?} ({}), stream copy: {}",
+        source.codec, format, output_str, copy
+    );
+
+    if copy {
+        let mut ost = octx.add_stream(ffmpeg::encoder::find(source.codec))?;
+        ost.set_parameters(ictx.stream(audio_index).unwrap().parameters());
+        ost.set_time_base(time_base);
+        unsafe {
+            // Let the muxer pick the tag for its container
+            let codecpar = (*ost.as_mut_ptr()).codecpar;
+            if !codecpar.is_null() {
+                (*codecpar).codec_tag = 0;
+            }
+        }
+        octx.write_header()
+            .context("Failed to write output header")?;
+        let out_time_base = octx.stream(0).unwrap().time_base();
+
+        let mut origin: Option<i64> = None;
+        for (stream, mut packet) in ictx.packets() {
+            if stream.index() != audio_index {
+                continue;
+            }
+            let Some(pts) = packet.pts() else {
+                continue;
+            };
+            if range.end_ts.is_some_and(|end| pts >= end) {
+                break;
+            }
+            // Skip packets that finish before the trim start
+            if range
+                .start_ts
+                .is_some_and(|start| pts + packet.duration().max(0) <= start)
+            {
+                continue;
+            }
+            let origin = *origin.get_or_insert(pts);
+            packet.set_pts(Some(pts - origin));
+            packet.set_dts(packet.dts().map(|dts| dts - origin));
+            packet.set_stream(0);
+            packet.set_position(-1);
+            packet.rescale_ts(time_base, out_time_base);
+            packet
+                .write_interleaved(&mut octx)
+                .context("Failed to write audio packet")?;
+        }
+    } else {
+        let rate = output_rate(format, options, source.sample_rate);
+        let codec = ffmpeg::encoder::find(encoder_id(format)).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No {:?} encoder in this FFmpeg build; the source ({:?}) cannot be converted to {}",
+                encoder_id(format),
+                source.codec,
+                format.extension()
+            )
+        })?;
+        let bit_rate = options
+            .bitrate_kbps
+            .unwrap_or_else(|| default_bitrate_kbps(format)) as usize
+            * 1000;
+        let channels = output_channels(options, source.channels);
+        let mut writer = AudioWriter::add_to(&mut octx, codec, rate, channels, bit_rate)?;
+        let parameters = ictx.stream(audio_index).unwrap().parameters();
+        if let Some(ref target) = options.normalize_loudness {
+            // First pass over the same trimmed range that gets encoded
+            let mut first_pass = video::open_input(path)?;
+            range.seek(&mut first_pass)?;
+            let mut meter = LoudnessMeter::new(rate, channels as usize);
+            let mut rest = decode_range(
+                &mut first_pass,
+                audio_index,
+                &range,
+                rate,
+                AudioResampler::new(parameters.clone(), rate)?,
+                |buffer| {
+                    meter.process_buffer(buffer);
+                    Ok(())
+                },
+            )?;
+            meter.process_buffer(&mut rest);
+            let info = meter.info();
+            let gain = loudness::normalization_gain(&info, target);
+            debug!(
+                "extract_audio - {:.1} LUFS -> {:.1} LUFS, gain {:.2}",
+                info.integrated_lufs, target.target_lufs, gain
+            );
+            writer.set_gain(gain);
+        }
+        octx.write_header()
+            .context("Failed to write output header")?;
+
+        let mut buffer = decode_range(
+            &mut ictx,
+            audio_index,
+            &range,
+            rate,
+            AudioResampler::new(parameters, rate)?,
+            |buffer| writer.write(buffer, &mut octx),
+        )?;
+        writer.finish(&mut buffer, &mut octx)?;
+        debug!(
+            "extract_audio - encoded {} samples, {} bytes",
+            writer.samples_written(),
+            writer.encoded_size_bytes
+        );
+    }
+
+    octx.write_trailer()
+        .context("Failed to write output trailer")?;
+
+    if !Path::new(&output).exists() {
+        return Err(anyhow::anyhow!(
+            "Output file was not created: {}",
+            output_str
+        ));
+    }
+    Ok(output_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::media::LoudnessNormalization;
+
+    fn source(codec: ffmpeg::codec::Id, channels: u16, sample_rate: u32) -> SourceAudio {
+        SourceAudio {
+            codec,
+            channels,
+            sample_rate,
+        }
+    }
+
+    #[test]
+    fn test_stream_copy_decision() {
+        let aac = source(ffmpeg::codec::Id::AAC, 2, 44_100);
+        let defaults = AudioExtractOptions::default();
+        assert!(can_stream_copy(AudioFormat::M4A, &aac, &defaults));
+        assert!(!can_stream_copy(AudioFormat::OPUS, &aac, &defaults));
+        assert!(!can_stream_copy(AudioFormat::WAV, &aac, &defaults));
+
+        let mono = AudioExtractOptions {
+            channels: Some(AudioChannels::Mono),
+            ..Default::default()
+        };
+        assert!(!can_stream_copy(AudioFormat::M4A, &aac, &mono));
+        let bitrate = AudioExtractOptions {
+            bitrate_kbps: Some(96),
+            ..Default::default()
+        };
+        assert!(!can_stream_copy(AudioFormat::M4A, &aac, &bitrate));
+        let normalized = AudioExtractOptions {
+            normalize_loudness: Some(LoudnessNormalization {
+                target_lufs: -16.0,
+                true_peak_dbtp: None,
+            }),
+            ..Default::default()
+        };
+        assert!(!can_stream_copy(AudioFormat::M4A, &aac, &normalized));
+
+        // 5.1 is downmixed, Opus at anything but 48 kHz is resampled
+        assert!(!can_stream_copy(
+            AudioFormat::M4A,
+            &source(ffmpeg::codec::Id::AAC, 6, 48_000),
+            &defaults
+        ));
+        assert!(can_stream_copy(
+            AudioFormat::OPUS,
+            &source(ffmpeg::codec::Id::OPUS, 2, 48_000),
+            &defaults
+        ));
+        assert!(!can_stream_copy(
+            AudioFormat::OPUS,
+            &source(ffmpeg::codec::Id::OPUS, 2, 24_000),
+            &defaults
+        ));
+    }
+
+    #[test]
+    fn test_output_channels_and_rate() {
+        let defaults = AudioExtractOptions::default();
+        assert_eq!(output_channels(&defaults, 1), 1);
+        assert_eq!(output_channels(&defaults, 6), 2);
+        let stereo = AudioExtractOptions {
+            channels: Some(AudioChannels::Stereo),
+            sample_rate: Some(22_050),
+            ..Default::default()
+        };
+        assert_eq!(output_channels(&stereo, 1), 2);
+        assert_eq!(output_rate(AudioFormat::WAV, &stereo, 44_100), 22_050);
+        assert_eq!(
+            output_rate(AudioFormat::OPUS, &stereo, 44_100),
+            OPUS_SAMPLE_RATE
+        );
+        assert_eq!(output_rate(AudioFormat::M4A, &defaults, 44_100), 44_100);
+    }
+}
@@ -232,7 +232,13 @@ impl FFmpegProcess {
         }
         
         // Parse output to get statistics
-        let stats = self.parse_output(&output.stderr, elapsed_ms, duration_ms)?;
+        let mut stats = self.parse_output(&output.stderr, elapsed_ms, duration_ms)?;
+        
+        // FFmpeg reports output time; report source time like the in-process path
+        if let Some(ref speed) = params.speed {
+            stats.processed_duration_ms =
+                (stats.processed_duration_ms as f64 * speed.factor as f64).round() as u64;
+        }
         
         debug!("compress_segment completed: {:?}", stats);
         Ok(stats)
@@ -253,16 +259,28 @@ impl FFmpegProcess {
         args.push("-i".to_string());
         args.push(input_path.to_string());
         
+        // Watermark image as second input (must precede the output options below)
+        if let Some(ref watermark) = params.watermark {
+            args.push("-i".to_string());
+            args.push(watermark.image_path.clone());
+        }
+        
+        // -ss/-t are output options here, so with a speed change they are in retimed output time
+        let to_output_ms = |ms: u64| match params.speed {
+            Some(ref speed) => (ms as f64 / speed.factor as f64).round() as u64,
+            None => ms,
+        };
+        
         // Start time (seek)
         if let Some(start) = start_ms {
             args.push("-ss".to_string());
-            args.push(Self::format_timestamp(start));
+            args.push(Self::format_timestamp(to_output_ms(start)));
         }
         
         // Duration
         if let Some(duration) = duration_ms {
             args.push("-t".to_string());
-            args.push(Self::format_timestamp(duration));
+            args.push(Self::format_timestamp(to_output_ms(duration)));
         }
         
         // Video codec
@@ -281,10 +299,62 @@ impl FFmpegProcess {
             args.push(crf.to_string());
         }
         
-        // Scale (resolution)
-        if let (Some(width), Some(height)) = (params.width, params.height) {
+        // Crop / aspect conversion and watermark sizing need the display dimensions
+        let info = if params.crop.is_some() || params.aspect_ratio.is_some() || params.watermark.is_some() {
+            Some(
+                crate::api::video::get_video_info(input_path)
+                    .context("Failed to read video dimensions for filter setup")?,
+            )
+        } else {
+            None
+        };
+        let layout = info
+            .as_ref()
+            .and_then(|info| crate::api::layout::compute_layout(info.width, info.height, params));
+        
+        // Scale (resolution), or the crop/pad/aspect graph
+        let video_filter = if let Some(ref layout) = layout {
+            Some(layout.to_filter_graph())
+        } else if let (Some(width), Some(height)) = (params.width, params.height) {
+            Some(format!("scale={}:{}", width, height))
+        } else {
+            None
+        };
+        
+        // Speed change retimes whatever the graph above produces (output is video-only, see -an)
+        let video_filter = match (video_filter, &params.speed) {
+            (Some(filter), Some(speed)) => Some(format!(
+                "{},{}",
+                filter,
+                crate::api::speed::video_filter(speed)
+            )),
+            (None, Some(speed)) => Some(crate::api::speed::video_filter(speed)),
+            (filter, None) => filter,
+        };
+        
+        if let (Some(watermark), Some(info)) = (&params.watermark, &info) {
+            // Display width of the video the watermark lands on
+            let output_width = match (&layout, params.width, params.height) {
+                (Some(layout), _, _) => layout.canvas_width,
+                (None, Some(width), Some(_)) => width,
+                _ => info.width,
+            };
+            let base = video_filter.unwrap_or_else(|| "null".to_string());
+            args.push("-filter_complex".to_string());
+            args.push(format!(
+                "[0:v]{}[base];{}",
+                base,
+                crate::api::overlay::watermark_filter(
+                    watermark,
+                    output_width,
+                    params.speed.as_ref()
+                )?
+            ));
+            args.push("-map".to_string());
+            args.push("[vout]".to_string());
+        } else if let Some(filter) = video_filter {
             args.push("-vf".to_string());
-            args.push(format!("scale={}:{}", width, height));
+            args.push(filter);
         }
         
         // No audio (faster encoding)
@@ -299,68 +369,124 @@ impl FFmpegProcess {
         Ok(args)
     }
 
-    /// Generate a thumbnail for a video using FFmpeg process
-    pub fn generate_thumbnail(
-        &self,
+    /// Whether the CLI can produce the image `params` asks for. AVIF, HEIC and progressive
+    /// JPEG are left to the in-process encoders, which don't depend on how FFmpeg was built.
+    pub fn can_encode(params: &crate::api::media::VideoThumbnailParams) -> bool {
+        use crate::api::media::OutputFormat;
+
+        let progressive = params
+            .encoder_options
+            .and_then(|options| options.jpeg_progressive)
+            .unwrap_or(false);
+        match params.format.unwrap_or(OutputFormat::PNG) {
+            OutputFormat::AVIF | OutputFormat::HEIC => false,
+            OutputFormat::JPEG => !progressive,
+            OutputFormat::PNG | OutputFormat::WEBP => true,
+        }
+    }
+
+    /// Arguments writing the frame at `time_ms` to stdout as a single image
+    fn thumbnail_args(
         input_path: &str,
         time_ms: u64,
         params: &crate::api::media::VideoThumbnailParams,
-    ) -> Result<(Vec<u8>, u32, u32)> {
-        use crate::api::media::{OutputFormat, ThumbnailSizeType};
-        
-        debug!("generate_thumbnail: input={}, time={}ms", input_path, time_ms);
+    ) -> Vec<String> {
+        use crate::api::media::{OutputFormat, SeekMode};
         
         let mut args = Vec::new();
         
+        // Seek on the input: FFmpeg jumps to the preceding keyframe, then (unless only the
+        // keyframe is wanted) decodes forward and drops the frames before the timestamp
+        if params.seek_mode == Some(SeekMode::Keyframe) {
+            args.push("-noaccurate_seek".to_string());
+        }
+        args.push("-ss".to_string());
+        args.push(Self::format_timestamp(time_ms));
+        
         // Input file
         args.push("-i".to_string());
         args.push(input_path.to_string());
         
-        // Seek to timestamp
-        args.push("-ss".to_string());
-        args.push(Self::format_timestamp(time_ms));
-        
         // Only 1 frame
         args.push("-frames:v".to_string());
         args.push("1".to_string());
         
-        // Scale if needed
+        // Scale if needed, with the same geometry as the in-process path
         if let Some(size_type) = &params.size_type {
-            let (target_w, target_h) = size_type.dimensions();
-            if target_w > 0 && target_h > 0 {
+            let resize = crate::api::resize::Resize::new(
+                size_type.dimensions(),
+                params.resize_mode,
+                params.allow_upscale,
+            );
+            if let Some(filter) = resize.ffmpeg_filter() {
                 args.push("-vf".to_string());
-                // Force scale to specific dimensions (or aspect ratio logic could be applied here)
-                // For now, consistent with existing logic:
-                args.push(format!("scale={}:{}", target_w, target_h));
+                args.push(filter);
             }
         }
         
         // Output format
         let format = params.format.unwrap_or(OutputFormat::PNG);
+        let options = params.encoder_options.unwrap_or_default();
+        args.push("-f".to_string());
+        args.push("image2pipe".to_string());
         match format {
             OutputFormat::JPEG => {
-                args.push("-f".to_string());
-                args.push("image2pipe".to_string());
                 args.push("-vcodec".to_string());
                 args.push("mjpeg".to_string());
+                if let Some(quality) = options.jpeg_quality {
+                    // mjpeg takes a qscale, 2 (best) to 31 (worst)
+                    let qscale = 2 + (100 - quality.clamp(1, 100) as u32) * 29 / 99;
+                    args.push("-q:v".to_string());
+                    args.push(qscale.to_string());
+                }
             },
             OutputFormat::PNG => {
-                args.push("-f".to_string());
-                args.push("image2pipe".to_string());
                 args.push("-vcodec".to_string());
                 args.push("png".to_string());
+                if let Some(level) = options.png_compression {
+                    args.push("-compression_level".to_string());
+                    args.push(level.min(9).to_string());
+                }
             },
             OutputFormat::WEBP => {
-                args.push("-f".to_string());
-                args.push("image2pipe".to_string());
                 args.push("-vcodec".to_string());
                 args.push("libwebp".to_string());
+                // Lossless unless a quality is given, like the in-process encoder
+                match options.webp_quality {
+                    Some(quality) => {
+                        args.push("-quality".to_string());
+                        args.push(quality.clamp(0.0, 100.0).to_string());
+                    }
+                    None => {
+                        args.push("-lossless".to_string());
+                        args.push("1".to_string());
+                    }
+                }
+            },
+            // Not reachable: `can_encode` keeps these on the in-process path
+            OutputFormat::AVIF | OutputFormat::HEIC => {
+                args.push("-vcodec".to_string());
+                args.push("png".to_string());
             },
         }
         
         // Output to stdout
         args.push("-".to_string());
         
+        args
+    }
+
+    /// Generate a thumbnail for a video using FFmpeg process
+    pub fn generate_thumbnail(
+        &self,
+        input_path: &str,
+        time_ms: u64,
+        params: &crate::api::media::VideoThumbnailParams,
+    ) -> Result<(Vec<u8>, u32, u32)> {
+        debug!("generate_thumbnail: input={}, time={}ms", input_path, time_ms);
+        
+        let args = Self::thumbnail_args(input_path, time_ms, params);
+        
         debug!("FFmpeg thumbnail command: {} {}", self.ffmpeg_path.display(), args.join(" "));
         
         let mut cmd = Command::new(&self.ffmpeg_path);
@@ -538,6 +664,80 @@ mod tests {
         assert_eq!(FFmpegProcess::parse_size("1024"), Some(1024));
     }
 
+    #[test]
+    fn test_thumbnail_args_seek_before_input() {
+        use crate::api::media::{SeekMode, VideoThumbnailParams};
+
+        let mut params = VideoThumbnailParams {
+            time_ms: 1500,
+            size_type: None,
+            format: None,
+            seek_mode: None,
+            resize_mode: None,
+            allow_upscale: None,
+            encoder_options: None,
+        };
+        let args = FFmpegProcess::thumbnail_args("in.mp4", 1500, &params);
+        assert_eq!(&args[0..4], &["-ss", "00:00:01.500", "-i", "in.mp4"]);
+
+        params.seek_mode = Some(SeekMode::Keyframe);
+        let args = FFmpegProcess::thumbnail_args("in.mp4", 1500, &params);
+        assert_eq!(&args[0..5], &["-noaccurate_seek", "-ss", "00:00:01.500", "-i", "in.mp4"]);
+    }
+
+    #[test]
+    fn test_thumbnail_args_resize_mode() {
+        use crate::api::media::{ResizeMode, ThumbnailSizeType, VideoThumbnailParams};
+
+        let params = VideoThumbnailParams {
+            time_ms: 0,
+            size_type: Some(ThumbnailSizeType::Custom((320, 180))),
+            format: None,
+            seek_mode: None,
+            resize_mode: Some(ResizeMode::Fill),
+            allow_upscale: Some(true),
+            encoder_options: None,
+        };
+        let args = FFmpegProcess::thumbnail_args("in.mp4", 0, &params);
+        let vf = args.iter().position(|arg| arg == "-vf").unwrap();
+        assert_eq!(
+            args[vf + 1],
+            "scale=320:180:force_original_aspect_ratio=increase,crop=320:180"
+        );
+    }
+
+    #[test]
+    fn test_thumbnail_args_encoder_options() {
+        use crate::api::media::{EncoderOptions, OutputFormat, VideoThumbnailParams};
+
+        let mut params = VideoThumbnailParams {
+            time_ms: 0,
+            size_type: None,
+            format: Some(OutputFormat::JPEG),
+            seek_mode: None,
+            resize_mode: None,
+            allow_upscale: None,
+            encoder_options: Some(EncoderOptions {
+                jpeg_quality: Some(100),
+                ..Default::default()
+            }),
+        };
+        let args = FFmpegProcess::thumbnail_args("in.mp4", 0, &params);
+        let q = args.iter().position(|arg| arg == "-q:v").unwrap();
+        assert_eq!(args[q + 1], "2");
+        assert!(FFmpegProcess::can_encode(&params));
+
+        params.encoder_options = Some(EncoderOptions {
+            jpeg_progressive: Some(true),
+            ..Default::default()
+        });
+        assert!(!FFmpegProcess::can_encode(&params));
+
+        params.format = Some(OutputFormat::AVIF);
+        params.encoder_options = None;
+        assert!(!FFmpegProcess::can_encode(&params));
+    }
+
     #[test]
     fn test_parse_timestamp() {
         assert_eq!(FFmpegProcess::parse_timestamp("00:00:01.500"), Some(1500));
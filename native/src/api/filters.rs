@@ -0,0 +1,436 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+
+use crate::api::media::CompressParams;
+use crate::api::pipeline::rescale;
+
+/// Size of the placeholder source used to check a chain before the input is opened
+const PROBE_WIDTH: u32 = 64;
+const PROBE_HEIGHT: u32 = 64;
+const PROBE_SAMPLE_RATE: u32 = 48_000;
+
+/// Filter names in a filtergraph description, skipping link labels, options and quoted text
+fn filter_names(spec: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut name = String::new();
+    let mut in_options = false;
+    let mut in_label = false;
+    let mut in_quote = false;
+    let mut escaped = false;
+
+    for c in spec.chars() {
+        if escaped {
+            escaped = false;
+        } else if in_quote {
+            in_quote = c != '\'';
+        } else if in_label {
+            in_label = c != ']';
+        } else {
+            match c {
+                '\\' => escaped = true,
+                '\'' => in_quote = true,
+                '[' => in_label = true,
+                ',' | ';' => {
+                    if !name.is_empty() {
+                        names.push(std::mem::take(&mut name));
+                    }
+                    in_options = false;
+                }
+                '=' => in_options = true,
+                c if !in_options && !c.is_whitespace() => name.push(c),
+                _ => {}
+            }
+        }
+    }
+    if !name.is_empty() {
+        names.push(name);
+    }
+
+    // "name@instance" gives a filter instance its own id
+    names
+        .into_iter()
+        .map(|name| match name.split_once('@') {
+            Some((filter, _)) => filter.to_string(),
+            None => name,
+        })
+        .collect()
+}
+
+/// Checks every filter in the chain exists and takes the right kind of input
+fn check_filters(spec: &str, medium: ffmpeg::media::Type, field: &str) -> Result<()> {
+    let names = filter_names(spec);
+    if names.is_empty() {
+        return Err(anyhow::anyhow!("{} is empty", field));
+    }
+    for name in names {
+        let filter = ffmpeg::filter::find(&name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown filter '{}' in {}", name, field))?;
+        let wrong_medium = filter.inputs().and_then(|mut pads| {
+            pads.find(|pad| pad.medium() != medium)
+                .map(|pad| pad.medium())
+        });
+        if let Some(other) = wrong_medium {
+            return Err(anyhow::anyhow!(
+                "Filter '{}' takes {:?} input and can't be used in {}",
+                name,
+                other,
+                field
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn video_source_args(
+    width: u32,
+    height: u32,
+    format: ffmpeg::format::Pixel,
+    time_base: ffmpeg::Rational,
+    aspect_ratio: ffmpeg::Rational,
+) -> Result<String> {
+    let pix_fmt = format
+        .descriptor()
+        .map(|descriptor| descriptor.name())
+        .ok_or_else(|| anyhow::anyhow!("Unsupported pixel format {:?}", format))?;
+    let aspect_ratio = if aspect_ratio.numerator() > 0 && aspect_ratio.denominator() > 0 {
+        aspect_ratio
+    } else {
+        ffmpeg::Rational(1, 1)
+    };
+    Ok(format!(
+        "video_size={}x{}:pix_fmt={}:time_base={}/{}:pixel_aspect={}/{}",
+        width,
+        height,
+        pix_fmt,
+        time_base.numerator(),
+        time_base.denominator().max(1),
+        aspect_ratio.numerator(),
+        aspect_ratio.denominator()
+    ))
+}
+
+fn audio_source_args(
+    rate: u32,
+    format: ffmpeg::format::Sample,
+    layout: &ffmpeg::ChannelLayout,
+    time_base: ffmpeg::Rational,
+) -> String {
+    format!(
+        "time_base={}/{}:sample_rate={}:sample_fmt={}:channel_layout={}",
+        time_base.numerator(),
+        time_base.denominator().max(1),
+        rate,
+        format.name(),
+        describe_layout(layout)
+    )
+}
+
+fn describe_layout(layout: &ffmpeg::ChannelLayout) -> String {
+    let mut buffer = [0u8; 64];
+    let written = unsafe {
+        ffmpeg::ffi::av_channel_layout_describe(
+            &layout.0,
+            buffer.as_mut_ptr() as *mut _,
+            buffer.len(),
+        )
+    };
+    if written <= 0 {
+        return format!("{}c", layout.channels().max(1));
+    }
+    let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+    String::from_utf8_lossy(&buffer[..end]).into_owned()
+}
+
+/// Builds `source -> spec -> buffersink`, the chain labelled "in" and "out"
+fn parse_graph(
+    spec: &str,
+    source: &str,
+    source_args: &str,
+    sink: &str,
+) -> Result<ffmpeg::filter::Graph> {
+    let mut graph = ffmpeg::filter::Graph::new();
+    let source_filter = ffmpeg::filter::find(source)
+        .ok_or_else(|| anyhow::anyhow!("FFmpeg was built without the {} filter", source))?;
+    let sink_filter = ffmpeg::filter::find(sink)
+        .ok_or_else(|| anyhow::anyhow!("FFmpeg was built without the {} filter", sink))?;
+    graph.add(&source_filter, "in", source_args)?;
+    graph.add(&sink_filter, "out", "")?;
+    graph.output("in", 0)?.input("out", 0)?.parse(spec)?;
+    Ok(graph)
+}
+
+/// Checks the caller-supplied filter chains before anything is opened or written.
+/// The chains are parsed against a placeholder source, which catches unknown filters
+/// and bad options; format negotiation is checked again against the real input.
+pub fn validate(params: &CompressParams) -> Result<()> {
+    if let Some(ref spec) = params.video_filters {
+        check_filters(spec, ffmpeg::media::Type::Video, "video_filters")?;
+        let args = video_source_args(
+            PROBE_WIDTH,
+            PROBE_HEIGHT,
+            ffmpeg::format::Pixel::YUV420P,
+            ffmpeg::Rational(1, 30),
+            ffmpeg::Rational(1, 1),
+        )?;
+        parse_graph(spec, "buffer", &args, "buffersink")
+            .with_context(|| format!("Invalid video_filters '{}'", spec))?;
+    }
+    if let Some(ref spec) = params.audio_filters {
+        check_filters(spec, ffmpeg::media::Type::Audio, "audio_filters")?;
+        let args = audio_source_args(
+            PROBE_SAMPLE_RATE,
+            ffmpeg::format::Sample::F32(ffmpeg::format::sample::Type::Planar),
+            &ffmpeg::ChannelLayout::STEREO,
+            ffmpeg::Rational(1, PROBE_SAMPLE_RATE as i32),
+        );
+        parse_graph(spec, "abuffer", &args, "abuffersink")
+            .with_context(|| format!("Invalid audio_filters '{}'", spec))?;
+    }
+    Ok(())
+}
+
+/// Filters that turn stored frames upright for a clockwise display rotation
+fn upright_filters(rotation: i32) -> Option<&'static str> {
+    match rotation {
+        90 => Some("transpose=clock"),
+        180 => Some("hflip,vflip"),
+        270 => Some("transpose=cclock"),
+        _ => None,
+    }
+}
+
+/// Caller-supplied filter chain between a decoder and the rest of the pipeline.
+/// The output is converted back to the decoder's sample or pixel format (and for
+/// audio its rate and layout), so only the video frame size can change.
+pub(crate) struct FilterChain {
+    graph: ffmpeg::filter::Graph,
+    time_base: ffmpeg::Rational,
+    input: ffmpeg::util::frame::Frame,
+    output_size: (u32, u32),
+}
+
+impl FilterChain {
+    /// Frames are turned upright for `rotation` before `spec` runs, so the chain sees the
+    /// video as displayed and its output carries no rotation.
+    pub fn video(
+        spec: &str,
+        decoder: &ffmpeg::codec::decoder::Video,
+        time_base: ffmpeg::Rational,
+        rotation: i32,
+    ) -> Result<Self> {
+        let args = video_source_args(
+            decoder.width(),
+            decoder.height(),
+            decoder.format(),
+            time_base,
+            decoder.aspect_ratio(),
+        )?;
+        let pix_fmt = decoder
+            .format()
+            .descriptor()
+            .map(|descriptor| descriptor.name())
+            .unwrap_or("yuv420p");
+        let chain = match upright_filters(rotation) {
+            Some(upright) => format!("{},{},format=pix_fmts={}", upright, spec, pix_fmt),
+            None => format!("{},format=pix_fmts={}", spec, pix_fmt),
+        };
+        let mut graph = parse_graph(&chain, "buffer", &args, "buffersink")
+            .with_context(|| format!("Invalid video_filters '{}'", spec))?;
+        graph
+            .validate()
+            .with_context(|| format!("video_filters '{}' can't process this video", spec))?;
+
+        let output_size = {
+            let out = graph
+                .get("out")
+                .ok_or_else(|| anyhow::anyhow!("Filter graph has no output"))?;
+            unsafe {
+                (
+                    ffmpeg::ffi::av_buffersink_get_w(out.as_ptr()) as u32,
+                    ffmpeg::ffi::av_buffersink_get_h(out.as_ptr()) as u32,
+                )
+            }
+        };
+        if output_size.0 == 0 || output_size.1 == 0 {
+            return Err(anyhow::anyhow!(
+                "video_filters '{}' produce an empty frame",
+                spec
+            ));
+        }
+
+        Ok(Self {
+            graph,
+            time_base,
+            input: unsafe { ffmpeg::util::frame::Frame::empty() },
+            output_size,
+        })
+    }
+
+    pub fn audio(
+        spec: &str,
+        decoder: &ffmpeg::codec::decoder::Audio,
+        time_base: ffmpeg::Rational,
+    ) -> Result<Self> {
+        let layout = decoder.channel_layout();
+        let args = audio_source_args(decoder.rate(), decoder.format(), &layout, time_base);
+        let chain = format!(
+            "{},aformat=sample_fmts={}:sample_rates={}:channel_layouts={}",
+            spec,
+            decoder.format().name(),
+            decoder.rate(),
+            describe_layout(&layout)
+        );
+        let mut graph = parse_graph(&chain, "abuffer", &args, "abuffersink")
+            .with_context(|| format!("Invalid audio_filters '{}'", spec))?;
+        graph
+            .validate()
+            .with_context(|| format!("audio_filters '{}' can't process this audio", spec))?;
+
+        Ok(Self {
+            graph,
+            time_base,
+            input: unsafe { ffmpeg::util::frame::Frame::empty() },
+            output_size: (0, 0),
+        })
+    }
+
+    /// Filtered video frame size
+    pub fn output_size(&self) -> (u32, u32) {
+        self.output_size
+    }
+
+    /// Next filtered frame, pulling decoded frames into the graph as needed.
+    /// Returns false once the decoder needs another packet. Timestamps are
+    /// rescaled back to the time base the chain was created with.
+    pub fn receive(
+        &mut self,
+        decoder: &mut ffmpeg::codec::decoder::Opened,
+        frame: &mut ffmpeg::util::frame::Frame,
+    ) -> Result<bool> {
+        let mut out = self
+            .graph
+            .get("out")
+            .ok_or_else(|| anyhow::anyhow!("Filter graph has no output"))?;
+        let mut input = self
+            .graph
+            .get("in")
+            .ok_or_else(|| anyhow::anyhow!("Filter graph has no input"))?;
+
+        loop {
+            unsafe { ffmpeg::ffi::av_frame_unref(frame.as_mut_ptr()) };
+            match out.sink().frame(frame) {
+                Ok(()) => {
+                    let sink_time_base = out.sink().time_base();
+                    if let Some(pts) = frame.pts() {
+                        frame.set_pts(Some(rescale(pts, sink_time_base, self.time_base)));
+                    }
+                    return Ok(true);
+                }
+                Err(ffmpeg::Error::Other {
+                    errno: ffmpeg::util::error::EAGAIN,
+                }) => {}
+                Err(ffmpeg::Error::Eof) => return Ok(false),
+                Err(e) => return Err(e).context("Filter graph failed"),
+            }
+
+            if decoder.receive_frame(&mut self.input).is_err() {
+                return Ok(false);
+            }
+            input
+                .source()
+                .add(&self.input)
+                .context("Failed to feed the filter graph")?;
+        }
+    }
+
+    /// Signal end of stream once the decoder is drained. Filters that hold frames back
+    /// (`reverse`, `tpad`, `fps`, `areverse`, ...) then release them through `receive`.
+    pub fn flush(&mut self) -> Result<()> {
+        let mut input = self
+            .graph
+            .get("in")
+            .ok_or_else(|| anyhow::anyhow!("Filter graph has no input"))?;
+        input
+            .source()
+            .flush()
+            .context("Failed to flush the filter graph")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_names() {
+        assert_eq!(filter_names("hflip"), vec!["hflip"]);
+        assert_eq!(
+            filter_names("scale=w=iw/2:h=-2, hqdn3d ,transpose=1"),
+            vec!["scale", "hqdn3d", "transpose"]
+        );
+        assert_eq!(
+            filter_names("split[a][b];[a]crop=100:100:0:0[c];[b][c]overlay"),
+            vec!["split", "crop", "overlay"]
+        );
+        assert_eq!(
+            filter_names("drawtext=text='a, b; [c]':x=10,eq@grade=contrast=1.1"),
+            vec!["drawtext", "eq"]
+        );
+        assert_eq!(
+            filter_names(r"drawtext=text=a\,b,vflip"),
+            vec!["drawtext", "vflip"]
+        );
+        assert!(filter_names(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_chains() {
+        let params = |video: Option<&str>, audio: Option<&str>| CompressParams {
+            video_filters: video.map(str::to_string),
+            audio_filters: audio.map(str::to_string),
+            ..Default::default()
+        };
+        assert!(validate(&params(None, None)).is_ok());
+        assert!(validate(&params(Some("hflip,scale=32:-2"), Some("volume=0.5"))).is_ok());
+
+        let unknown = validate(&params(Some("hflip,notafilter"), None)).unwrap_err();
+        assert!(unknown.to_string().contains("Unknown filter 'notafilter'"));
+        let wrong_medium = validate(&params(None, Some("hflip"))).unwrap_err();
+        assert!(wrong_medium
+            .to_string()
+            .contains("can't be used in audio_filters"));
+        assert!(validate(&params(Some(""), None)).is_err());
+        assert!(validate(&params(Some("scale=nosuchoption=1"), None)).is_err());
+    }
+
+    #[test]
+    fn test_upright_filters_turn_the_frame() {
+        let output_size = |rotation: i32| {
+            let args = video_source_args(
+                PROBE_WIDTH,
+                PROBE_HEIGHT / 2,
+                ffmpeg::format::Pixel::YUV420P,
+                ffmpeg::Rational(1, 30),
+                ffmpeg::Rational(1, 1),
+            )
+            .unwrap();
+            let chain = match upright_filters(rotation) {
+                Some(upright) => format!("{},null", upright),
+                None => "null".to_string(),
+            };
+            let mut graph = parse_graph(&chain, "buffer", &args, "buffersink").unwrap();
+            graph.validate().unwrap();
+            let out = graph.get("out").unwrap();
+            unsafe {
+                (
+                    ffmpeg::ffi::av_buffersink_get_w(out.as_ptr()) as u32,
+                    ffmpeg::ffi::av_buffersink_get_h(out.as_ptr()) as u32,
+                )
+            }
+        };
+        assert_eq!(output_size(0), (PROBE_WIDTH, PROBE_HEIGHT / 2));
+        assert_eq!(output_size(90), (PROBE_HEIGHT / 2, PROBE_WIDTH));
+        assert_eq!(output_size(180), (PROBE_WIDTH, PROBE_HEIGHT / 2));
+        assert_eq!(output_size(270), (PROBE_HEIGHT / 2, PROBE_WIDTH));
+    }
+}
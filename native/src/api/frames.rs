@@ -0,0 +1,285 @@
+use anyhow::{Context, Result};
+use image::RgbImage;
+use tracing::debug;
+
+use crate::api::encode::encode_rgb_image;
+use crate::api::media::{
+    ExtractedFrame, FrameExtractParams, FrameSelection, OutputFormat, SeekMode, ThumbnailData,
+    ThumbnailSizeType, VideoThumbnailParams,
+};
+use crate::api::resize::Resize;
+use crate::api::sampler::FrameSampler;
+use crate::api::video::{self, check_output_path, get_file_name_without_extension};
+
+/// Upper bound on frames per call, so a tiny interval on a long video can't run for hours
+const MAX_FRAMES: usize = 10_000;
+
+/// Count mode leaves out the end of the video, where frames often fail to decode
+const END_BUFFER_MS: u64 = 1500;
+
+/// Request times for `selection`, in the order they are decoded
+fn frame_times(selection: &FrameSelection, duration_ms: u64) -> Result<Vec<u64>> {
+    let times = match selection {
+        FrameSelection::Timestamps(timestamps) => {
+            if timestamps.is_empty() {
+                return Err(anyhow::anyhow!("No timestamps given"));
+            }
+            // Decoding only walks forward
+            let mut times = timestamps.clone();
+            times.sort_unstable();
+            times.dedup();
+            times
+        }
+        FrameSelection::Interval(interval_ms) => {
+            if *interval_ms == 0 {
+                return Err(anyhow::anyhow!("Frame interval must be greater than 0"));
+            }
+            let mut times: Vec<u64> = (0u64..)
+                .map_while(|i| i.checked_mul(*interval_ms))
+                .take_while(|time| *time < duration_ms)
+                .take(MAX_FRAMES + 1)
+                .collect();
+            if times.is_empty() {
+                times.push(0);
+            }
+            times
+        }
+        FrameSelection::Count(count) => {
+            if *count == 0 {
+                return Err(anyhow::anyhow!("Number of frames must be greater than 0"));
+            }
+            if *count as usize > MAX_FRAMES {
+                return Err(anyhow::anyhow!(
+                    "Too many frames requested ({}, max {})",
+                    count,
+                    MAX_FRAMES
+                ));
+            }
+            // The start of each of `count` equal slices of all but the last 1.5 s,
+            // so the last frame isn't the very end
+            let span_ms = if duration_ms > END_BUFFER_MS {
+                duration_ms - END_BUFFER_MS
+            } else {
+                duration_ms
+            };
+            (0..*count as u64)
+                .map(|i| span_ms * i / *count as u64)
+                .collect()
+        }
+    };
+    if times.len() > MAX_FRAMES {
+        return Err(anyhow::anyhow!(
+            "Too many frames requested (max {}); use a longer interval",
+            MAX_FRAMES
+        ));
+    }
+    Ok(times)
+}
+
+/// Decodes the frames for `selection` in one pass over the input, reusing the demuxer,
+/// decoder and scaler. `on_frame` is called in time order with the frame index, the
+/// requested time and the upright image scaled into the `resize` box with its actual timestamp,
+/// or `None` if nothing could be decoded for it.
+pub(crate) fn sample_frames<F>(
+    path: &str,
+    selection: &FrameSelection,
+    resize: Resize,
+    mode: SeekMode,
+    mut on_frame: F,
+) -> Result<()>
+where
+    F: FnMut(u32, u64, Option<(RgbImage, u64)>) -> Result<()>,
+{
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let mut serialization_guard = Some(
+        video::FFMPEG_SERIALIZATION_MUTEX
+            .lock()
+            .expect("Failed to acquire serialization mutex"),
+    );
+
+    let mut sampler = FrameSampler::open(path)?;
+    let times = frame_times(selection, sampler.duration_ms())?;
+    debug!(
+        "sample_frames - {} frames from {} ({}ms) in {:?} mode",
+        times.len(),
+        path,
+        sampler.duration_ms(),
+        mode
+    );
+
+    for (index, &requested_ms) in times.iter().enumerate() {
+        let frame = match sampler.frame_at(requested_ms, mode)? {
+            Some(time_ms) => Some((sampler.image(&resize)?, time_ms)),
+            None => None,
+        };
+
+        // The callback writes files and talks to Dart; other FFmpeg work may run meanwhile
+        #[cfg(target_os = "windows")]
+        drop(serialization_guard.take());
+        let result = on_frame(index as u32, requested_ms, frame);
+        #[cfg(target_os = "windows")]
+        {
+            serialization_guard = Some(
+                video::FFMPEG_SERIALIZATION_MUTEX
+                    .lock()
+                    .expect("Failed to acquire serialization mutex"),
+            );
+        }
+        result?;
+    }
+    Ok(())
+}
+
+/// Writes the frames for `params.selection` to `output_dir` as
+/// `frame_<name>_<index>.<ext>` and hands each one to `on_frame` as soon as it is written.
+/// Returns the number of frames written.
+pub fn extract_frames<F>(
+    path: &str,
+    output_dir: &str,
+    params: &FrameExtractParams,
+    mut on_frame: F,
+) -> Result<u32>
+where
+    F: FnMut(ExtractedFrame) -> Result<()>,
+{
+    let base_output_dir = check_output_path(output_dir)?;
+    let name = get_file_name_without_extension(path);
+    let format = params.format.unwrap_or(OutputFormat::PNG);
+    let encoder_options = params.encoder_options.unwrap_or_default();
+    let resize = Resize::new(
+        params
+            .size_type
+            .unwrap_or(ThumbnailSizeType::Medium)
+            .dimensions(),
+        params.resize_mode,
+        params.allow_upscale,
+    );
+    let mut written = 0;
+
+    sample_frames(
+        path,
+        &params.selection,
+        resize,
+        params.seek_mode.unwrap_or(SeekMode::Accurate),
+        |index, requested_ms, frame| {
+            let Some((image, time_ms)) = frame else {
+                debug!("extract_frames - no frame for {}ms", requested_ms);
+                return Ok(());
+            };
+            let output_path = base_output_dir.join(format!(
+                "frame_{}_{}.{}",
+                name.display(),
+                index,
+                format.extension()
+            ));
+            let data = encode_rgb_image(&image, format, &encoder_options)?;
+            std::fs::write(&output_path, data)
+                .with_context(|| format!("Failed to write frame {}", output_path.display()))?;
+            written += 1;
+            on_frame(ExtractedFrame {
+                path: output_path.to_string_lossy().to_string(),
+                index,
+                requested_ms,
+                time_ms,
+                width: image.width(),
+                height: image.height(),
+            })
+        },
+    )?;
+
+    if written == 0 {
+        return Err(anyhow::anyhow!("Could not decode any frame from {}", path));
+    }
+    Ok(written)
+}
+
+/// The frame for `params.time_ms`, encoded in memory with its actual timestamp
+pub fn thumbnail_data(path: &str, params: &VideoThumbnailParams) -> Result<ThumbnailData> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let mut sampler = FrameSampler::open(path)?;
+    let time_ms = sampler
+        .frame_at(
+            params.time_ms,
+            params.seek_mode.unwrap_or(SeekMode::Accurate),
+        )?
+        .ok_or_else(|| anyhow::anyhow!("Could not decode any frame from {}", path))?;
+
+    // Without a size the frame keeps its display size
+    let resize = Resize::new(
+        params
+            .size_type
+            .map(|size| size.dimensions())
+            .unwrap_or((0, 0)),
+        params.resize_mode,
+        params.allow_upscale,
+    );
+    let image = sampler.image(&resize)?;
+
+    Ok(ThumbnailData {
+        data: encode_rgb_image(
+            &image,
+            params.format.unwrap_or(OutputFormat::PNG),
+            &params.encoder_options.unwrap_or_default(),
+        )?,
+        width: image.width(),
+        height: image.height(),
+        time_ms: Some(time_ms),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamps_are_sorted_and_deduplicated() {
+        let selection = FrameSelection::Timestamps(vec![5_000, 1_000, 5_000, 0]);
+        assert_eq!(
+            frame_times(&selection, 10_000).unwrap(),
+            vec![0, 1_000, 5_000]
+        );
+        assert!(frame_times(&FrameSelection::Timestamps(vec![]), 10_000).is_err());
+    }
+
+    #[test]
+    fn test_interval_times() {
+        let selection = FrameSelection::Interval(3_000);
+        assert_eq!(
+            frame_times(&selection, 10_000).unwrap(),
+            vec![0, 3_000, 6_000, 9_000]
+        );
+        // Shorter than one interval, or of unknown duration: still one frame
+        assert_eq!(frame_times(&selection, 0).unwrap(), vec![0]);
+        assert!(frame_times(&FrameSelection::Interval(0), 10_000).is_err());
+        assert!(frame_times(&FrameSelection::Interval(1), 3_600_000).is_err());
+        // Intervals past u32 don't wrap on 32-bit targets
+        assert_eq!(
+            frame_times(&FrameSelection::Interval(1 << 32), 10_000).unwrap(),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn test_count_times() {
+        let selection = FrameSelection::Count(4);
+        assert_eq!(
+            frame_times(&selection, 10_000).unwrap(),
+            vec![0, 2_125, 4_250, 6_375]
+        );
+        // Videos shorter than the end buffer are spread over their whole length, and more
+        // frames than milliseconds is fine, neighbours just repeat
+        assert_eq!(
+            frame_times(&FrameSelection::Count(3), 2).unwrap(),
+            vec![0, 0, 1]
+        );
+        assert!(frame_times(&FrameSelection::Count(0), 10_000).is_err());
+    }
+}
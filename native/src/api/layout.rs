@@ -0,0 +1,762 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+
+use crate::api::media::{AspectMode, CompressParams, CropRect};
+
+/// Default pad colour (black) when `CompressParams::pad_color` is not set
+const DEFAULT_PAD_COLOR: u32 = 0x000000;
+
+/// Long edge of the downscaled frame used to build the blurred background.
+/// Upscaling such a small frame with a bicubic filter gives a cheap, smooth blur.
+const BLUR_TINY_EDGE: u32 = 32;
+
+/// Rectangle in pixel coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn full(width: u32, height: u32) -> Self {
+        Rect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+}
+
+impl From<CropRect> for Rect {
+    fn from(crop: CropRect) -> Self {
+        Rect {
+            x: crop.x,
+            y: crop.y,
+            width: crop.width,
+            height: crop.height,
+        }
+    }
+}
+
+/// What fills the canvas area not covered by the scaled source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    /// Source covers the whole canvas
+    None,
+    /// Solid colour, 0xRRGGBB
+    Color(u32),
+    /// Blurred copy of the source, scaled to cover the canvas
+    Blur,
+}
+
+/// Output geometry for crop, pad and aspect-ratio conversion.
+///
+/// All rectangles are in display orientation (after rotation is applied), which is what
+/// the user sees and what crop rectangles / aspect ratios are expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameLayout {
+    /// Region of the source frame to keep
+    pub crop: Option<Rect>,
+    /// Output frame size
+    pub canvas_width: u32,
+    pub canvas_height: u32,
+    /// Where the scaled source lands on the canvas
+    pub content: Rect,
+    pub background: Background,
+}
+
+/// Compute the output layout for the given display dimensions.
+/// Returns None when no crop or aspect conversion is requested (plain scaling applies).
+pub fn compute_layout(src_w: u32, src_h: u32, params: &CompressParams) -> Option<FrameLayout> {
+    let aspect = params.aspect_ratio.filter(|(num, den)| *num > 0 && *den > 0);
+    if params.crop.is_none() && aspect.is_none() {
+        return None;
+    }
+    if src_w == 0 || src_h == 0 {
+        return None;
+    }
+
+    let mode = params.aspect_mode.unwrap_or(AspectMode::Fit);
+    let crop = params.crop.map(|c| clamp_rect(c.into(), src_w, src_h));
+    let region = crop.unwrap_or(Rect::full(src_w, src_h));
+
+    let Some((num, den)) = aspect else {
+        // Crop only: scale the cropped region like a regular compression
+        let (w, h) = scale_request(region.width, region.height, params.width, params.height);
+        return Some(FrameLayout {
+            crop,
+            canvas_width: w,
+            canvas_height: h,
+            content: Rect::full(w, h),
+            background: Background::None,
+        });
+    };
+
+    let (box_w, box_h) = aspect_box(region.width, region.height, num, den, mode);
+    let (canvas_w, canvas_h) = match (params.width, params.height) {
+        // Explicit output size wins over the aspect-derived box
+        (Some(w), Some(h)) if w > 0 && h > 0 => (even(w), even(h)),
+        (w, h) => scale_request(box_w, box_h, w, h),
+    };
+
+    match mode {
+        AspectMode::Fill => {
+            // Narrow the kept region to the canvas aspect around its centre
+            let inner = center_crop(region.width, region.height, canvas_w, canvas_h);
+            Some(FrameLayout {
+                crop: Some(Rect {
+                    x: region.x + inner.x,
+                    y: region.y + inner.y,
+                    width: inner.width,
+                    height: inner.height,
+                }),
+                canvas_width: canvas_w,
+                canvas_height: canvas_h,
+                content: Rect::full(canvas_w, canvas_h),
+                background: Background::None,
+            })
+        }
+        AspectMode::Fit | AspectMode::BlurredFit => {
+            let scale = (canvas_w as f64 / region.width as f64)
+                .min(canvas_h as f64 / region.height as f64);
+            let content_w = even(((region.width as f64 * scale).round() as u32).min(canvas_w));
+            let content_h = even(((region.height as f64 * scale).round() as u32).min(canvas_h));
+            let content = Rect {
+                x: ((canvas_w - content_w) / 2) & !1,
+                y: ((canvas_h - content_h) / 2) & !1,
+                width: content_w,
+                height: content_h,
+            };
+            let background = if content_w == canvas_w && content_h == canvas_h {
+                Background::None
+            } else if mode == AspectMode::BlurredFit {
+                Background::Blur
+            } else {
+                Background::Color(params.pad_color.unwrap_or(DEFAULT_PAD_COLOR) & 0xFF_FF_FF)
+            };
+            Some(FrameLayout {
+                crop,
+                canvas_width: canvas_w,
+                canvas_height: canvas_h,
+                content,
+                background,
+            })
+        }
+    }
+}
+
+impl FrameLayout {
+    /// Canvas size in stored orientation (swapped for 90/270° rotation)
+    pub fn stored_canvas_size(&self, rotation: i32) -> (u32, u32) {
+        match rotation {
+            90 | 270 => (self.canvas_height, self.canvas_width),
+            _ => (self.canvas_width, self.canvas_height),
+        }
+    }
+
+    /// Build an FFmpeg `-vf` graph for the CLI path.
+    /// The ffmpeg CLI auto-rotates its input, so display coordinates are used as-is.
+    pub fn to_filter_graph(&self) -> String {
+        let mut prefix = String::new();
+        if let Some(crop) = self.crop {
+            prefix = format!(
+                "crop={}:{}:{}:{},",
+                crop.width, crop.height, crop.x, crop.y
+            );
+        }
+
+        match self.background {
+            Background::None => format!(
+                "{}scale={}:{},setsar=1",
+                prefix, self.content.width, self.content.height
+            ),
+            Background::Color(color) => format!(
+                "{}scale={}:{},pad={}:{}:{}:{}:color=0x{:06X},setsar=1",
+                prefix,
+                self.content.width,
+                self.content.height,
+                self.canvas_width,
+                self.canvas_height,
+                self.content.x,
+                self.content.y,
+                color
+            ),
+            Background::Blur => {
+                let region = self.crop.unwrap_or(Rect::full(
+                    self.content.width,
+                    self.content.height,
+                ));
+                let (cover_w, cover_h) = cover_size(
+                    region.width,
+                    region.height,
+                    self.canvas_width,
+                    self.canvas_height,
+                );
+                let radius = (self.canvas_width.min(self.canvas_height) / 20).max(2);
+                format!(
+                    "{}split=2[bg][fg];\
+                     [bg]scale={}:{},crop={}:{},boxblur={}:2[bgb];\
+                     [fg]scale={}:{}[fgs];\
+                     [bgb][fgs]overlay={}:{},setsar=1",
+                    prefix,
+                    cover_w,
+                    cover_h,
+                    self.canvas_width,
+                    self.canvas_height,
+                    radius,
+                    self.content.width,
+                    self.content.height,
+                    self.content.x,
+                    self.content.y
+                )
+            }
+        }
+    }
+}
+
+/// Map a rectangle from display orientation back to stored orientation.
+/// `display_w`/`display_h` are the dimensions of the display-space frame the rect lives in.
+///
+/// Rotation follows `get_video_rotation`: 90 means the stored frame is turned 90° clockwise
+/// for display, 270 means 90° counter-clockwise.
+pub fn display_rect_to_stored(rect: Rect, rotation: i32, display_w: u32, display_h: u32) -> Rect {
+    match rotation {
+        90 => Rect {
+            x: rect.y,
+            y: display_w.saturating_sub(rect.x + rect.width),
+            width: rect.height,
+            height: rect.width,
+        },
+        180 => Rect {
+            x: display_w.saturating_sub(rect.x + rect.width),
+            y: display_h.saturating_sub(rect.y + rect.height),
+            width: rect.width,
+            height: rect.height,
+        },
+        270 => Rect {
+            x: display_h.saturating_sub(rect.y + rect.height),
+            y: rect.x,
+            width: rect.height,
+            height: rect.width,
+        },
+        _ => rect,
+    }
+}
+
+pub(crate) fn even(v: u32) -> u32 {
+    (v & !1).max(2)
+}
+
+fn clamp_rect(rect: Rect, max_w: u32, max_h: u32) -> Rect {
+    let x = rect.x.min(max_w.saturating_sub(2));
+    let y = rect.y.min(max_h.saturating_sub(2));
+    let width = rect.width.min(max_w - x).max(2);
+    let height = rect.height.min(max_h - y).max(2);
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Scale a size to the requested width/height without upscaling, preserving the aspect
+/// ratio of the request (or of the source when only one side is given).
+fn scale_request(src_w: u32, src_h: u32, width: Option<u32>, height: Option<u32>) -> (u32, u32) {
+    let (mut w, mut h) = match (width.filter(|w| *w > 0), height.filter(|h| *h > 0)) {
+        (Some(w), Some(h)) => (w as f64, h as f64),
+        (Some(w), None) => (w as f64, src_h as f64 * w as f64 / src_w as f64),
+        (None, Some(h)) => (src_w as f64 * h as f64 / src_h as f64, h as f64),
+        (None, None) => (src_w as f64, src_h as f64),
+    };
+    if w > src_w as f64 || h > src_h as f64 {
+        let factor = (src_w as f64 / w).min(src_h as f64 / h);
+        w *= factor;
+        h *= factor;
+    }
+    (even(w.round() as u32), even(h.round() as u32))
+}
+
+/// Canvas box of the requested aspect ratio for a source region.
+/// Fill keeps the largest box inside the region; Fit keeps the region's long edge.
+fn aspect_box(region_w: u32, region_h: u32, num: u32, den: u32, mode: AspectMode) -> (u32, u32) {
+    let ratio = num as f64 / den as f64;
+    let (w, h) = match mode {
+        AspectMode::Fill => {
+            if region_w as f64 / region_h as f64 > ratio {
+                (region_h as f64 * ratio, region_h as f64)
+            } else {
+                (region_w as f64, region_w as f64 / ratio)
+            }
+        }
+        AspectMode::Fit | AspectMode::BlurredFit => {
+            let long_edge = region_w.max(region_h) as f64;
+            if ratio >= 1.0 {
+                (long_edge, long_edge / ratio)
+            } else {
+                (long_edge * ratio, long_edge)
+            }
+        }
+    };
+    (even(w.round() as u32), even(h.round() as u32))
+}
+
+/// Centre rectangle of `src` with the aspect ratio of `target`
+fn center_crop(src_w: u32, src_h: u32, target_w: u32, target_h: u32) -> Rect {
+    let target_ratio = target_w as f64 / target_h as f64;
+    let (w, h) = if src_w as f64 / src_h as f64 > target_ratio {
+        ((src_h as f64 * target_ratio).round() as u32, src_h)
+    } else {
+        (src_w, (src_w as f64 / target_ratio).round() as u32)
+    };
+    let (w, h) = (w.clamp(1, src_w), h.clamp(1, src_h));
+    Rect {
+        x: (src_w - w) / 2,
+        y: (src_h - h) / 2,
+        width: w,
+        height: h,
+    }
+}
+
+/// Smallest size with the source aspect ratio that covers the target
+fn cover_size(src_w: u32, src_h: u32, target_w: u32, target_h: u32) -> (u32, u32) {
+    let scale = (target_w as f64 / src_w as f64).max(target_h as f64 / src_h as f64);
+    let w = ((src_w as f64 * scale).ceil() as u32).max(target_w);
+    let h = ((src_h as f64 * scale).ceil() as u32).max(target_h);
+    ((w + 1) & !1, (h + 1) & !1)
+}
+
+/// Convert 0xRRGGBB to BT.709 Y'CbCr
+pub(crate) fn rgb_to_yuv(color: u32, full_range: bool) -> (u8, u8, u8) {
+    let r = ((color >> 16) & 0xFF) as f64 / 255.0;
+    let g = ((color >> 8) & 0xFF) as f64 / 255.0;
+    let b = (color & 0xFF) as f64 / 255.0;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let cb = (b - y) / 1.8556;
+    let cr = (r - y) / 1.5748;
+    if full_range {
+        (
+            (y * 255.0).round().clamp(0.0, 255.0) as u8,
+            (128.0 + cb * 255.0).round().clamp(0.0, 255.0) as u8,
+            (128.0 + cr * 255.0).round().clamp(0.0, 255.0) as u8,
+        )
+    } else {
+        (
+            (16.0 + y * 219.0).round().clamp(0.0, 255.0) as u8,
+            (128.0 + cb * 224.0).round().clamp(0.0, 255.0) as u8,
+            (128.0 + cr * 224.0).round().clamp(0.0, 255.0) as u8,
+        )
+    }
+}
+
+/// Crop a frame in place by moving its data pointers (no copy)
+pub fn apply_crop(frame: &mut ffmpeg::util::frame::video::Video, rect: Rect) -> Result<()> {
+    let width = frame.width();
+    let height = frame.height();
+    let rect = clamp_rect(rect, width, height);
+    unsafe {
+        let ptr = frame.as_mut_ptr();
+        (*ptr).crop_left = rect.x as usize;
+        (*ptr).crop_top = rect.y as usize;
+        (*ptr).crop_right = (width - rect.x - rect.width) as usize;
+        (*ptr).crop_bottom = (height - rect.y - rect.height) as usize;
+        // AV_FRAME_CROP_UNALIGNED: keep the exact rectangle even if the pointers end up unaligned
+        let ret = ffmpeg::ffi::av_frame_apply_cropping(ptr, 1);
+        if ret < 0 {
+            return Err(anyhow::anyhow!(
+                "Failed to crop frame to {}x{}+{}+{}: {}",
+                rect.width,
+                rect.height,
+                rect.x,
+                rect.y,
+                ffmpeg::Error::from(ret)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Fill every plane of a YUV420P frame with a solid colour
+fn fill_yuv420p(frame: &mut ffmpeg::util::frame::video::Video, yuv: (u8, u8, u8)) {
+    let values = [yuv.0, yuv.1, yuv.2];
+    for (plane, value) in values.iter().enumerate() {
+        let stride = frame.stride(plane);
+        let width = frame.plane_width(plane) as usize;
+        let rows = frame.plane_height(plane) as usize;
+        let data = frame.data_mut(plane);
+        for row in 0..rows {
+            data[row * stride..row * stride + width].fill(*value);
+        }
+    }
+}
+
+/// Copy a YUV420P frame onto a larger YUV420P frame at an even offset
+pub fn blit_yuv420p(
+    src: &ffmpeg::util::frame::video::Video,
+    dst: &mut ffmpeg::util::frame::video::Video,
+    x: u32,
+    y: u32,
+) {
+    for plane in 0..3 {
+        let shift = if plane == 0 { 0 } else { 1 };
+        let src_stride = src.stride(plane);
+        let dst_stride = dst.stride(plane);
+        let dst_w = dst.plane_width(plane) as usize;
+        let dst_h = dst.plane_height(plane) as usize;
+        let off_x = (x >> shift) as usize;
+        let off_y = (y >> shift) as usize;
+        let width = (src.plane_width(plane) as usize).min(dst_w.saturating_sub(off_x));
+        let rows = (src.plane_height(plane) as usize).min(dst_h.saturating_sub(off_y));
+        let src_data = src.data(plane);
+        let dst_data = dst.data_mut(plane);
+        for row in 0..rows {
+            let s = row * src_stride;
+            let d = (off_y + row) * dst_stride + off_x;
+            dst_data[d..d + width].copy_from_slice(&src_data[s..s + width]);
+        }
+    }
+}
+
+/// Rotate a YUV420P frame clockwise by `rotation` degrees (0/90/180/270) into a new frame.
+/// Used where frames must be physically upright, e.g. when clips with different
+/// rotations are joined into one stream.
+pub fn rotate_yuv420p(
+    src: &ffmpeg::util::frame::video::Video,
+    rotation: i32,
+) -> ffmpeg::util::frame::video::Video {
+    let (width, height) = match rotation {
+        90 | 270 => (src.height(), src.width()),
+        _ => (src.width(), src.height()),
+    };
+    let mut dst =
+        ffmpeg::util::frame::video::Video::new(ffmpeg::format::Pixel::YUV420P, width, height);
+    for plane in 0..3 {
+        let src_stride = src.stride(plane);
+        let dst_stride = dst.stride(plane);
+        let sw = src.plane_width(plane) as usize;
+        let sh = src.plane_height(plane) as usize;
+        let dw = dst.plane_width(plane) as usize;
+        let dh = dst.plane_height(plane) as usize;
+        let src_data = src.data(plane);
+        let dst_data = dst.data_mut(plane);
+        for dy in 0..dh {
+            for dx in 0..dw {
+                let (sx, sy) = match rotation {
+                    90 => (dy, sh - 1 - dx),
+                    180 => (sw - 1 - dx, sh - 1 - dy),
+                    270 => (sw - 1 - dy, dx),
+                    _ => (dx, dy),
+                };
+                if sx < sw && sy < sh {
+                    dst_data[dy * dst_stride + dx] = src_data[sy * src_stride + sx];
+                }
+            }
+        }
+    }
+    dst
+}
+
+/// Renders decoded frames onto the output canvas for the in-process pipeline.
+///
+/// Works in stored orientation: the layout is mapped through the rotation once, and the
+/// encoder keeps the source display matrix so players rotate the result as before.
+pub struct LayoutRenderer {
+    crop: Option<Rect>,
+    content: Rect,
+    canvas_width: u32,
+    canvas_height: u32,
+    background: Background,
+    content_scaler: Option<ffmpeg::software::scaling::Context>,
+    content_frame: ffmpeg::util::frame::video::Video,
+    blur_down: Option<ffmpeg::software::scaling::Context>,
+    blur_up: Option<ffmpeg::software::scaling::Context>,
+    blur_tiny: ffmpeg::util::frame::video::Video,
+}
+
+impl LayoutRenderer {
+    /// `src_display_w`/`src_display_h` are the display dimensions of the source video
+    pub fn new(layout: &FrameLayout, rotation: i32, src_display_w: u32, src_display_h: u32) -> Self {
+        let crop = layout
+            .crop
+            .map(|c| display_rect_to_stored(c, rotation, src_display_w, src_display_h));
+        let content = display_rect_to_stored(
+            layout.content,
+            rotation,
+            layout.canvas_width,
+            layout.canvas_height,
+        );
+        let (canvas_width, canvas_height) = layout.stored_canvas_size(rotation);
+        LayoutRenderer {
+            crop,
+            content: Rect {
+                x: content.x & !1,
+                y: content.y & !1,
+                width: content.width,
+                height: content.height,
+            },
+            canvas_width,
+            canvas_height,
+            background: layout.background,
+            content_scaler: None,
+            content_frame: ffmpeg::util::frame::video::Video::empty(),
+            blur_down: None,
+            blur_up: None,
+            blur_tiny: ffmpeg::util::frame::video::Video::empty(),
+        }
+    }
+
+    /// Encoder dimensions (stored orientation)
+    pub fn output_size(&self) -> (u32, u32) {
+        (self.canvas_width, self.canvas_height)
+    }
+
+    /// Crop, scale and composite `decoded` onto `canvas` (YUV420P, `output_size()`).
+    /// `decoded` is cropped in place.
+    pub fn render(
+        &mut self,
+        decoded: &mut ffmpeg::util::frame::video::Video,
+        canvas: &mut ffmpeg::util::frame::video::Video,
+    ) -> Result<()> {
+        if let Some(crop) = self.crop {
+            apply_crop(decoded, crop)?;
+        }
+
+        if self.content_scaler.is_none() {
+            self.content_scaler = Some(ffmpeg::software::scaling::Context::get(
+                decoded.format(),
+                decoded.width(),
+                decoded.height(),
+                ffmpeg::format::Pixel::YUV420P,
+                self.content.width,
+                self.content.height,
+                ffmpeg::software::scaling::flag::Flags::BILINEAR,
+            )?);
+        }
+
+        // Full-canvas content can be scaled straight into the output frame
+        if self.background == Background::None
+            && self.content.width == self.canvas_width
+            && self.content.height == self.canvas_height
+        {
+            if let Some(ref mut scaler) = self.content_scaler {
+                scaler.run(decoded, canvas).context("Scaler run failed")?;
+            }
+            return Ok(());
+        }
+
+        if let Some(ref mut scaler) = self.content_scaler {
+            scaler
+                .run(decoded, &mut self.content_frame)
+                .context("Scaler run failed")?;
+        }
+
+        match self.background {
+            Background::Color(color) => {
+                let full_range = decoded.color_range() == ffmpeg::util::color::Range::JPEG;
+                fill_yuv420p(canvas, rgb_to_yuv(color, full_range));
+            }
+            Background::Blur => self.render_blur(decoded, canvas)?,
+            Background::None => fill_yuv420p(canvas, rgb_to_yuv(DEFAULT_PAD_COLOR, false)),
+        }
+
+        blit_yuv420p(&self.content_frame, canvas, self.content.x, self.content.y);
+        Ok(())
+    }
+
+    /// Downscale the source to a tiny frame, crop it to the canvas aspect and upscale it
+    /// back over the canvas with a bicubic filter, which reads as a soft blur.
+    fn render_blur(
+        &mut self,
+        decoded: &ffmpeg::util::frame::video::Video,
+        canvas: &mut ffmpeg::util::frame::video::Video,
+    ) -> Result<()> {
+        if self.blur_down.is_none() {
+            let (src_w, src_h) = (decoded.width().max(1), decoded.height().max(1));
+            let (tiny_w, tiny_h) = if src_w >= src_h {
+                (BLUR_TINY_EDGE, even(BLUR_TINY_EDGE * src_h / src_w))
+            } else {
+                (even(BLUR_TINY_EDGE * src_w / src_h), BLUR_TINY_EDGE)
+            };
+            self.blur_down = Some(ffmpeg::software::scaling::Context::get(
+                decoded.format(),
+                decoded.width(),
+                decoded.height(),
+                ffmpeg::format::Pixel::YUV420P,
+                tiny_w,
+                tiny_h,
+                ffmpeg::software::scaling::flag::Flags::AREA,
+            )?);
+        }
+
+        if let Some(ref mut down) = self.blur_down {
+            down.run(decoded, &mut self.blur_tiny)
+                .context("Blur downscale failed")?;
+        }
+
+        // Work on a copy so the tiny frame keeps its size for the next run
+        let mut view = self.blur_tiny.clone();
+        let cover = center_crop(
+            view.width(),
+            view.height(),
+            self.canvas_width,
+            self.canvas_height,
+        );
+        apply_crop(&mut view, cover)?;
+
+        if self.blur_up.is_none() {
+            self.blur_up = Some(ffmpeg::software::scaling::Context::get(
+                ffmpeg::format::Pixel::YUV420P,
+                view.width(),
+                view.height(),
+                ffmpeg::format::Pixel::YUV420P,
+                self.canvas_width,
+                self.canvas_height,
+                ffmpeg::software::scaling::flag::Flags::BICUBIC,
+            )?);
+        }
+
+        if let Some(ref mut up) = self.blur_up {
+            up.run(&view, canvas).context("Blur upscale failed")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> CompressParams {
+        CompressParams {
+            target_bitrate_kbps: 1000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_layout_without_crop_or_aspect() {
+        assert_eq!(compute_layout(1920, 1080, &params()), None);
+    }
+
+    #[test]
+    fn test_fit_landscape_to_portrait() {
+        let p = CompressParams {
+            aspect_ratio: Some((9, 16)),
+            aspect_mode: Some(AspectMode::Fit),
+            pad_color: Some(0xFF0000),
+            ..params()
+        };
+        let layout = compute_layout(1920, 1080, &p).unwrap();
+        assert_eq!((layout.canvas_width, layout.canvas_height), (1080, 1920));
+        assert_eq!(layout.content.width, 1080);
+        assert_eq!(layout.content.height, 608);
+        assert_eq!(layout.content.y, 656);
+        assert_eq!(layout.background, Background::Color(0xFF0000));
+        assert_eq!(
+            layout.to_filter_graph(),
+            "scale=1080:608,pad=1080:1920:0:656:color=0xFF0000,setsar=1"
+        );
+    }
+
+    #[test]
+    fn test_fill_center_crops_to_square() {
+        let p = CompressParams {
+            aspect_ratio: Some((1, 1)),
+            aspect_mode: Some(AspectMode::Fill),
+            ..params()
+        };
+        let layout = compute_layout(1920, 1080, &p).unwrap();
+        assert_eq!((layout.canvas_width, layout.canvas_height), (1080, 1080));
+        assert_eq!(
+            layout.crop,
+            Some(Rect {
+                x: 420,
+                y: 0,
+                width: 1080,
+                height: 1080
+            })
+        );
+        assert_eq!(layout.background, Background::None);
+    }
+
+    #[test]
+    fn test_blurred_fit_uses_blur_background() {
+        let p = CompressParams {
+            aspect_ratio: Some((9, 16)),
+            aspect_mode: Some(AspectMode::BlurredFit),
+            width: Some(720),
+            ..params()
+        };
+        let layout = compute_layout(1920, 1080, &p).unwrap();
+        assert_eq!((layout.canvas_width, layout.canvas_height), (720, 1280));
+        assert_eq!(layout.background, Background::Blur);
+        assert!(layout.to_filter_graph().contains("boxblur"));
+    }
+
+    #[test]
+    fn test_crop_only_keeps_region_size() {
+        let p = CompressParams {
+            crop: Some(CropRect {
+                x: 100,
+                y: 50,
+                width: 640,
+                height: 480,
+            }),
+            ..params()
+        };
+        let layout = compute_layout(1920, 1080, &p).unwrap();
+        assert_eq!((layout.canvas_width, layout.canvas_height), (640, 480));
+        assert_eq!(
+            layout.to_filter_graph(),
+            "crop=640:480:100:50,scale=640:480,setsar=1"
+        );
+    }
+
+    #[test]
+    fn test_scale_request_does_not_reset_aspect() {
+        // Requesting more than the source keeps the requested aspect instead of snapping back
+        assert_eq!(scale_request(1280, 720, Some(1920), Some(1920)), (720, 720));
+        assert_eq!(scale_request(1280, 720, Some(640), None), (640, 360));
+    }
+
+    #[test]
+    fn test_display_rect_to_stored() {
+        // Stored 1920x1080 shown as 1080x1920 (rotation 90)
+        let rect = Rect {
+            x: 0,
+            y: 0,
+            width: 1080,
+            height: 100,
+        };
+        assert_eq!(
+            display_rect_to_stored(rect, 90, 1080, 1920),
+            Rect {
+                x: 0,
+                y: 0,
+                width: 100,
+                height: 1080
+            }
+        );
+        assert_eq!(
+            display_rect_to_stored(rect, 270, 1080, 1920),
+            Rect {
+                x: 1820,
+                y: 0,
+                width: 100,
+                height: 1080
+            }
+        );
+        assert_eq!(
+            display_rect_to_stored(rect, 180, 1080, 1920),
+            Rect {
+                x: 0,
+                y: 1820,
+                width: 1080,
+                height: 100
+            }
+        );
+    }
+}
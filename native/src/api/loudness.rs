@@ -0,0 +1,438 @@
+use anyhow::Result;
+use ffmpeg_next::{self as ffmpeg};
+use tracing::debug;
+
+use crate::api::audio::{AudioResampler, StereoBuffer, DEFAULT_SAMPLE_RATE};
+use crate::api::media::{LoudnessInfo, LoudnessNormalization};
+use crate::api::video;
+
+/// Gating thresholds from EBU R128 / ITU-R BS.1770
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+const RELATIVE_GATE_LU: f64 = -10.0;
+const LRA_RELATIVE_GATE_LU: f64 = -20.0;
+
+/// Measurement steps of 100 ms; momentary blocks span 4 steps, short-term windows 30
+const STEPS_PER_SECOND: u32 = 10;
+const MOMENTARY_STEPS: usize = 4;
+const SHORT_TERM_STEPS: usize = 30;
+
+/// True peak is measured on a 4x oversampled signal
+const OVERSAMPLING: usize = 4;
+const TAPS_PER_PHASE: usize = 12;
+
+/// Default ceiling for the normalised true peak
+const DEFAULT_TRUE_PEAK_DBTP: f32 = -1.0;
+
+/// Loudness of a mean-square energy
+fn energy_to_lufs(energy: f64) -> f64 {
+    if energy <= 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        -0.691 + 10.0 * energy.log10()
+    }
+}
+
+fn lufs_to_energy(lufs: f64) -> f64 {
+    10f64.powf((lufs + 0.691) / 10.0)
+}
+
+/// Direct form I biquad
+#[derive(Clone, Copy)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl Biquad {
+    fn new(b: [f64; 3], a: [f64; 2]) -> Self {
+        Biquad {
+            b,
+            a,
+            x: [0.0; 2],
+            y: [0.0; 2],
+        }
+    }
+
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.b[1] * self.x[0] + self.b[2] * self.x[1]
+            - self.a[0] * self.y[0]
+            - self.a[1] * self.y[1];
+        self.x = [input, self.x[0]];
+        self.y = [output, self.y[0]];
+        output
+    }
+}
+
+/// BS.1770 K-weighting (high shelf + high pass) designed for `rate`
+fn k_weighting(rate: u32) -> [Biquad; 2] {
+    let rate = rate as f64;
+
+    let f0 = 1681.974450955533;
+    let gain_db = 3.999843853973347;
+    let q = 0.7071752369554196;
+    let k = (std::f64::consts::PI * f0 / rate).tan();
+    let vh = 10f64.powf(gain_db / 20.0);
+    let vb = vh.powf(0.4996667741545416);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad::new(
+        [
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+        ],
+        [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+    );
+
+    let f0 = 38.13547087602444;
+    let q = 0.5003270373238773;
+    let k = (std::f64::consts::PI * f0 / rate).tan();
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad::new(
+        [1.0, -2.0, 1.0],
+        [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+    );
+    [shelf, high_pass]
+}
+
+/// Polyphase interpolator estimating inter-sample peaks
+struct TruePeak {
+    phases: Vec<[f64; TAPS_PER_PHASE]>,
+    history: [[f64; TAPS_PER_PHASE]; 2],
+    peak: f64,
+}
+
+impl TruePeak {
+    fn new() -> Self {
+        // Hann-windowed sinc low pass at the original Nyquist frequency
+        let length = OVERSAMPLING * TAPS_PER_PHASE;
+        let center = (length - 1) as f64 / 2.0;
+        let taps: Vec<f64> = (0..length)
+            .map(|i| {
+                let t = (i as f64 - center) / OVERSAMPLING as f64;
+                let sinc = if t == 0.0 {
+                    1.0
+                } else {
+                    (std::f64::consts::PI * t).sin() / (std::f64::consts::PI * t)
+                };
+                let window =
+                    0.5 - 0.5 * (2.0 * std::f64::consts::PI * i as f64 / (length - 1) as f64).cos();
+                sinc * window
+            })
+            .collect();
+        let phases = (0..OVERSAMPLING)
+            .map(|phase| {
+                let mut coefficients = [0.0; TAPS_PER_PHASE];
+                for (tap, coefficient) in coefficients.iter_mut().enumerate() {
+                    *coefficient = taps[tap * OVERSAMPLING + phase];
+                }
+                coefficients
+            })
+            .collect();
+        TruePeak {
+            phases,
+            history: [[0.0; TAPS_PER_PHASE]; 2],
+            peak: 0.0,
+        }
+    }
+
+    fn process(&mut self, channel: usize, sample: f64) {
+        let history = &mut self.history[channel];
+        history.copy_within(0..TAPS_PER_PHASE - 1, 1);
+        history[0] = sample;
+        self.peak = self.peak.max(sample.abs());
+        for phase in &self.phases {
+            let value: f64 = phase.iter().zip(history.iter()).map(|(c, x)| c * x).sum();
+            self.peak = self.peak.max(value.abs());
+        }
+    }
+}
+
+/// EBU R128 meter: integrated loudness, loudness range and true peak
+pub struct LoudnessMeter {
+    channels: usize,
+    filters: [[Biquad; 2]; 2],
+    step_samples: usize,
+    step_energy: f64,
+    step_count: usize,
+    steps: Vec<f64>, // mean-square energy of each complete 100 ms step
+    true_peak: TruePeak,
+}
+
+impl LoudnessMeter {
+    /// `channels` is 1 for mono sources (only the left plane is measured) or 2
+    pub fn new(rate: u32, channels: usize) -> Self {
+        LoudnessMeter {
+            channels: channels.clamp(1, 2),
+            filters: [k_weighting(rate), k_weighting(rate)],
+            step_samples: (rate / STEPS_PER_SECOND).max(1) as usize,
+            step_energy: 0.0,
+            step_count: 0,
+            steps: Vec::new(),
+            true_peak: TruePeak::new(),
+        }
+    }
+
+    pub fn process(&mut self, left: &[f32], right: &[f32]) {
+        for i in 0..left.len().min(right.len()) {
+            for channel in 0..self.channels {
+                let sample = if channel == 0 { left[i] } else { right[i] } as f64;
+                self.true_peak.process(channel, sample);
+                let [shelf, high_pass] = &mut self.filters[channel];
+                let weighted = high_pass.process(shelf.process(sample));
+                self.step_energy += weighted * weighted;
+            }
+            self.step_count += 1;
+            if self.step_count == self.step_samples {
+                self.steps.push(self.step_energy / self.step_samples as f64);
+                self.step_energy = 0.0;
+                self.step_count = 0;
+            }
+        }
+    }
+
+    /// Measure and empty `buffer`. Mono meters get the downmix, as written by `AudioWriter`.
+    pub fn process_buffer(&mut self, buffer: &mut StereoBuffer) {
+        let (left, right) = buffer.drain_front(buffer.len());
+        if self.channels == 1 {
+            let mono: Vec<f32> = left
+                .iter()
+                .zip(&right)
+                .map(|(l, r)| (l + r) * 0.5)
+                .collect();
+            self.process(&mono, &mono);
+        } else {
+            self.process(&left, &right);
+        }
+    }
+
+    /// Mean energies of every `window`-step block, advancing one step at a time
+    fn windows(&self, window: usize) -> Vec<f64> {
+        if self.steps.len() < window {
+            return Vec::new();
+        }
+        self.steps
+            .windows(window)
+            .map(|steps| steps.iter().sum::<f64>() / window as f64)
+            .collect()
+    }
+
+    pub fn integrated_lufs(&self) -> f64 {
+        // Momentary blocks overlap by 75%, i.e. one per step
+        let blocks: Vec<f64> = self
+            .windows(MOMENTARY_STEPS)
+            .into_iter()
+            .filter(|&energy| energy_to_lufs(energy) > ABSOLUTE_GATE_LUFS)
+            .collect();
+        if blocks.is_empty() {
+            return f64::NEG_INFINITY;
+        }
+        let mean = blocks.iter().sum::<f64>() / blocks.len() as f64;
+        let gate = lufs_to_energy(energy_to_lufs(mean) + RELATIVE_GATE_LU);
+        let gated: Vec<f64> = blocks.into_iter().filter(|&energy| energy > gate).collect();
+        if gated.is_empty() {
+            return f64::NEG_INFINITY;
+        }
+        energy_to_lufs(gated.iter().sum::<f64>() / gated.len() as f64)
+    }
+
+    /// Spread between the 10th and 95th percentile of gated short-term loudness (EBU Tech 3342)
+    pub fn loudness_range_lu(&self) -> f64 {
+        let windows: Vec<f64> = self
+            .windows(SHORT_TERM_STEPS)
+            .into_iter()
+            .filter(|&energy| energy_to_lufs(energy) > ABSOLUTE_GATE_LUFS)
+            .collect();
+        if windows.is_empty() {
+            return 0.0;
+        }
+        let mean = windows.iter().sum::<f64>() / windows.len() as f64;
+        let gate = energy_to_lufs(mean) + LRA_RELATIVE_GATE_LU;
+        let mut loudness: Vec<f64> = windows
+            .into_iter()
+            .map(energy_to_lufs)
+            .filter(|&lufs| lufs > gate)
+            .collect();
+        if loudness.len() < 2 {
+            return 0.0;
+        }
+        loudness.sort_by(|a, b| a.total_cmp(b));
+        let percentile = |p: f64| loudness[((loudness.len() - 1) as f64 * p).round() as usize];
+        percentile(0.95) - percentile(0.10)
+    }
+
+    pub fn true_peak_dbtp(&self) -> f64 {
+        if self.true_peak.peak > 0.0 {
+            20.0 * self.true_peak.peak.log10()
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+
+    pub fn info(&self) -> LoudnessInfo {
+        LoudnessInfo {
+            integrated_lufs: self.integrated_lufs(),
+            loudness_range_lu: self.loudness_range_lu(),
+            true_peak_dbtp: self.true_peak_dbtp(),
+        }
+    }
+}
+
+/// Linear gain bringing `info` to the target loudness without pushing the true peak over
+/// the ceiling. Silence is left alone.
+pub fn normalization_gain(info: &LoudnessInfo, target: &LoudnessNormalization) -> f32 {
+    if !info.integrated_lufs.is_finite() {
+        return 1.0;
+    }
+    let mut gain_db = target.target_lufs as f64 - info.integrated_lufs;
+    if info.true_peak_dbtp.is_finite() {
+        let ceiling = target.true_peak_dbtp.unwrap_or(DEFAULT_TRUE_PEAK_DBTP) as f64;
+        gain_db = gain_db.min(ceiling - info.true_peak_dbtp);
+    }
+    10f64.powf(gain_db / 20.0) as f32
+}
+
+/// Measure the best audio stream of `path`
+pub fn analyze(path: &str) -> Result<LoudnessInfo> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    measure(path)
+}
+
+/// `analyze` for callers that already hold the FFmpeg serialization lock
+pub(crate) fn measure(path: &str) -> Result<LoudnessInfo> {
+    let mut ictx = video::open_input(path)?;
+    let (index, parameters) = {
+        let stream = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Audio)
+            .ok_or_else(|| anyhow::anyhow!("No audio stream found in {}", path))?;
+        (stream.index(), stream.parameters())
+    };
+    let resampler = AudioResampler::new(parameters, DEFAULT_SAMPLE_RATE)?;
+    let channels = if resampler.decoder_channels() == 1 {
+        1
+    } else {
+        2
+    };
+    let info = measure_processed(&mut ictx, index, resampler, DEFAULT_SAMPLE_RATE, channels)?;
+    debug!(
+        "loudness - {}: {:.1} LUFS, LRA {:.1} LU, true peak {:.1} dBTP",
+        path, info.integrated_lufs, info.loudness_range_lu, info.true_peak_dbtp
+    );
+    Ok(info)
+}
+
+/// Measure stream `index` of `ictx` as `resampler` (running at `rate`) delivers it, so
+/// filters and retiming count the same way they do in the encoded output.
+/// `channels` is 1 when the output is downmixed to mono.
+pub(crate) fn measure_processed(
+    ictx: &mut ffmpeg::format::context::Input,
+    index: usize,
+    mut resampler: AudioResampler,
+    rate: u32,
+    channels: usize,
+) -> Result<LoudnessInfo> {
+    let mut meter = LoudnessMeter::new(rate, channels);
+    let mut buffer = StereoBuffer::default();
+
+    for (stream, packet) in ictx.packets() {
+        if stream.index() != index {
+            continue;
+        }
+        resampler.send_packet(&packet, &mut buffer)?;
+        meter.process_buffer(&mut buffer);
+    }
+    resampler.flush(&mut buffer)?;
+    meter.process_buffer(&mut buffer);
+    Ok(meter.info())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(rate: u32, seconds: f32, frequency: f32, amplitude: f32) -> Vec<f32> {
+        (0..(rate as f32 * seconds) as usize)
+            .map(|i| amplitude * (i as f32 * frequency * std::f32::consts::TAU / rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_stereo_sine_reference_level() {
+        // EBU Tech 3341 case 1: 1 kHz stereo sine at -23 dBFS reads -23 LUFS
+        let rate = 48_000;
+        let signal = sine(rate, 20.0, 1000.0, 10f32.powf(-23.0 / 20.0));
+        let mut meter = LoudnessMeter::new(rate, 2);
+        for chunk in signal.chunks(1024) {
+            meter.process(chunk, chunk);
+        }
+        assert!(
+            (meter.integrated_lufs() + 23.0).abs() < 0.1,
+            "{}",
+            meter.integrated_lufs()
+        );
+        assert!(meter.loudness_range_lu() < 0.1);
+        assert!(
+            (meter.true_peak_dbtp() + 23.0).abs() < 0.2,
+            "{}",
+            meter.true_peak_dbtp()
+        );
+    }
+
+    #[test]
+    fn test_gating_and_range() {
+        // 10 s at -20 LUFS and 10 s at -30: a range of about 10 LU
+        let rate = 48_000;
+        let mut signal = sine(rate, 10.0, 1000.0, 10f32.powf(-20.0 / 20.0));
+        signal.extend(sine(rate, 10.0, 1000.0, 10f32.powf(-30.0 / 20.0)));
+        let mut meter = LoudnessMeter::new(rate, 2);
+        meter.process(&signal, &signal);
+        let lra = meter.loudness_range_lu();
+        assert!((lra - 10.0).abs() < 0.5, "{}", lra);
+
+        // Silence is below the absolute gate: it neither counts towards the integrated level...
+        let integrated = meter.integrated_lufs();
+        let zeros = vec![0.0; rate as usize * 10];
+        meter.process(&zeros, &zeros);
+        assert!((meter.integrated_lufs() - integrated).abs() < 0.1);
+
+        // ...nor reads as a level of its own
+        let mut silent = LoudnessMeter::new(rate, 2);
+        silent.process(&[0.0; 48_000], &[0.0; 48_000]);
+        assert_eq!(silent.integrated_lufs(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_normalization_gain() {
+        let target = LoudnessNormalization {
+            target_lufs: -16.0,
+            true_peak_dbtp: None,
+        };
+        let quiet = LoudnessInfo {
+            integrated_lufs: -26.0,
+            loudness_range_lu: 5.0,
+            true_peak_dbtp: -20.0,
+        };
+        assert!((normalization_gain(&quiet, &target) - 10f32.powf(10.0 / 20.0)).abs() < 1e-4);
+
+        // Limited by the -1 dBTP ceiling
+        let peaky = LoudnessInfo {
+            true_peak_dbtp: -4.0,
+            ..quiet.clone()
+        };
+        assert!((normalization_gain(&peaky, &target) - 10f32.powf(3.0 / 20.0)).abs() < 1e-4);
+
+        let silent = LoudnessInfo {
+            integrated_lufs: f64::NEG_INFINITY,
+            ..quiet
+        };
+        assert_eq!(normalization_gain(&silent, &target), 1.0);
+    }
+}
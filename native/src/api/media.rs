@@ -1,9 +1,26 @@
+use crate::api::animation;
+use crate::api::concat;
+use crate::api::encode;
+use crate::api::extract;
+use crate::api::frames;
+use crate::api::loudness;
+use crate::api::metadata;
+use crate::api::music;
+use crate::api::palette;
+use crate::api::perceptual;
+use crate::api::placeholder;
+use crate::api::poster;
+use crate::api::probe;
+use crate::api::resize::Resize;
+use crate::api::reverse;
+use crate::api::scenes;
+use crate::api::storyboard;
 use crate::api::video::{self, check_output_path, get_file_name_without_extension};
 use crate::frb_generated::StreamSink;
 use anyhow::{Context, Error};
 use image::{DynamicImage, ImageBuffer, Rgb};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolutionPreset {
@@ -23,14 +40,49 @@ pub struct VideoInfo {
     pub bitrate: Option<u64>,
     pub codec_name: Option<String>,
     pub format_name: Option<String>,
+    pub stored_width: u32,               // coded size, before rotation
+    pub stored_height: u32,
+    pub rotation: i32,                   // degrees clockwise: 0, 90, 180 or 270
+    pub avg_frame_rate: Option<f64>,     // frames / duration
+    pub real_frame_rate: Option<f64>,    // lowest rate all timestamps fit (r_frame_rate)
+    pub is_vfr: bool,                    // average and real rates disagree
+    pub pixel_format: Option<String>,    // e.g. "yuv420p10le"
+    pub bit_depth: Option<u32>,          // bits per luma sample
+    pub color_primaries: Option<String>, // e.g. "bt709", "bt2020"
+    pub color_transfer: Option<String>,  // e.g. "bt709", "smpte2084", "arib-std-b67"
+    pub color_matrix: Option<String>,    // e.g. "bt709", "bt2020nc"
+    pub color_range: Option<String>,     // "tv" (limited) or "pc" (full)
+    pub hdr_format: Option<HdrFormat>,   // None for SDR
+    pub profile: Option<String>,         // e.g. "High", "Main 10"
+    pub level: Option<i32>,              // codec-specific, e.g. 41 for H.264 level 4.1
+    pub frame_count: Option<u64>,        // from the container, when it records it
+    pub audio: Option<AudioStreamInfo>,  // best audio stream, if any
     pub suggestions: Vec<ResolutionPreset>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HdrFormat {
+    Hdr10,       // PQ (SMPTE ST 2084) transfer
+    Hlg,         // Hybrid Log-Gamma (ARIB STD-B67) transfer
+    DolbyVision, // Dolby Vision configuration record present
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioStreamInfo {
+    pub codec_name: Option<String>,
+    pub channels: u32,
+    pub sample_rate: u32,        // Hz
+    pub bitrate: Option<u64>,    // bits per second
+    pub language: Option<String>, // ISO 639, None when missing or "und"
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum OutputFormat {
     WEBP,
     JPEG,
     PNG,
+    AVIF, // libheif with its libaom AV1 encoder
+    HEIC, // needs libheif built with an HEVC encoder (x265)
 }
 
 impl OutputFormat {
@@ -39,10 +91,67 @@ impl OutputFormat {
             OutputFormat::WEBP => "webp",
             OutputFormat::JPEG => "jpeg",
             OutputFormat::PNG => "png",
+            OutputFormat::AVIF => "avif",
+            OutputFormat::HEIC => "heic",
+        }
+    }
+}
+
+/// Per-format encoder settings; each field only applies to its own format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EncoderOptions {
+    pub jpeg_quality: Option<u8>,       // 1-100, defaults to 75
+    pub jpeg_progressive: Option<bool>, // defaults to false (baseline)
+    pub webp_quality: Option<f32>,      // 0-100 for lossy WebP; None keeps lossless
+    pub png_compression: Option<u8>,    // 0 (none) to 9 (smallest), defaults to fast
+    pub avif_quality: Option<u8>,       // 1-100, defaults to 70
+    pub heic_quality: Option<u8>,       // 1-100, defaults to 70
+}
+
+/// Container/codec of an audio-only output. There is no MP3: the bundled FFmpeg is built
+/// without libmp3lame, so it has no MP3 encoder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioFormat {
+    M4A,  // AAC in MP4
+    OPUS, // Opus in Ogg
+    WAV,  // 16-bit PCM
+}
+
+impl AudioFormat {
+    pub fn extension(&self) -> &str {
+        match self {
+            AudioFormat::M4A => "m4a",
+            AudioFormat::OPUS => "opus",
+            AudioFormat::WAV => "wav",
         }
     }
 }
 
+/// Container of an animated export
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnimationFormat {
+    GIF,  // shared 256-color palette, optionally dithered
+    WEBP, // lossless frames
+    APNG, // lossless frames; shows the first frame where APNG is unsupported
+}
+
+impl AnimationFormat {
+    pub fn extension(&self) -> &str {
+        match self {
+            AnimationFormat::GIF => "gif",
+            AnimationFormat::WEBP => "webp",
+            AnimationFormat::APNG => "png",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnimationOptions {
+    pub max_bytes: Option<u64>,  // lower the fps, then the width, until the file fits
+    pub loop_count: Option<u16>, // number of plays; None or 0 loops forever
+    pub dither: Option<bool>,    // GIF only, defaults to true
+}
+
 /// Represents fixed sizes of a thumbnail
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum ThumbnailSizeType {
@@ -67,20 +176,378 @@ impl ThumbnailSizeType {
     }
 }
 
+/// How a thumbnail position is located in the video
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeekMode {
+    Accurate, // first frame at or after the requested time; decodes forward from the preceding keyframe
+    Keyframe, // keyframe at or before the requested time; fast, but can be seconds early
+}
+
+/// How a thumbnail is fitted into its `ThumbnailSizeType` box
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResizeMode {
+    Fit,       // whole frame inside the box, keeping the aspect ratio
+    Fill,      // cover the box, keeping the aspect ratio, and crop the centre
+    Exact,     // stretch to the box
+    FitWidth,  // match the box width; height follows the aspect ratio
+    FitHeight, // match the box height; width follows the aspect ratio
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VideoThumbnailParams {
     pub time_ms: u64,                         // position to grab framen
     pub size_type: Option<ThumbnailSizeType>, // if None, use default size as per the videos aspect ratio
     pub format: Option<OutputFormat>,         // defaults to PNG
+    pub seek_mode: Option<SeekMode>,          // defaults to Accurate
+    pub resize_mode: Option<ResizeMode>,      // defaults to Fit
+    pub allow_upscale: Option<bool>,          // grow frames smaller than the box, defaults to false
+    pub encoder_options: Option<EncoderOptions>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BestThumbnailParams {
+    pub size_type: Option<ThumbnailSizeType>, // if None, use default size as per the videos aspect ratio
+    pub format: Option<OutputFormat>,         // defaults to PNG
+    pub candidates: Option<u32>,              // frames sampled across the video, defaults to 12 (max 100)
+    pub resize_mode: Option<ResizeMode>,      // defaults to Fit
+    pub allow_upscale: Option<bool>,          // defaults to false
+    pub encoder_options: Option<EncoderOptions>,
+}
+
+/// Encoded thumbnail returned by the in-memory thumbnail functions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThumbnailData {
+    pub data: Vec<u8>, // encoded image
+    pub width: u32,
+    pub height: u32,
+    pub time_ms: Option<u64>, // timestamp of the frame actually used; None for still images
+}
+
+/// Poster frame picked by `generate_best_thumbnail`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestThumbnail {
+    pub data: Vec<u8>, // encoded image
+    pub width: u32,
+    pub height: u32,
+    pub time_ms: u64, // timestamp of the chosen frame
+    pub score: f64,   // 0-1, higher is better; 0 when every candidate was near-black or near-uniform
+}
+
+/// Which frames `extract_frames` returns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FrameSelection {
+    Timestamps(Vec<u64>), // these positions in ms, in any order; duplicates are dropped
+    Interval(u64),        // every n ms from the start
+    Count(u32),           // this many, evenly spaced from the start, leaving out the last 1.5 s
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameExtractParams {
+    pub selection: FrameSelection,
+    pub size_type: Option<ThumbnailSizeType>, // defaults to Medium
+    pub format: Option<OutputFormat>,         // defaults to PNG
+    pub seek_mode: Option<SeekMode>,          // defaults to Accurate; Keyframe is much faster on long GOPs
+    pub resize_mode: Option<ResizeMode>,      // defaults to Fit
+    pub allow_upscale: Option<bool>,          // defaults to false
+    pub encoder_options: Option<EncoderOptions>,
+}
+
+/// One image written by `extract_frames`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedFrame {
+    pub path: String,
+    pub index: u32,        // position in time order
+    pub requested_ms: u64, // timestamp asked for
+    pub time_ms: u64,      // timestamp of the frame actually used
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Start of a new scene found by `detect_scenes`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SceneCut {
+    pub time_ms: u64, // first frame of the new scene
+    pub score: f64,   // 0-1, how abrupt the change is
+}
+
+/// Stream item of `detect_scenes`: progress while decoding, then every cut once at the end
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SceneDetectionEvent {
+    Progress(CompressProgress),
+    Cuts(Vec<SceneCut>),
+}
+
+/// Files written by `generate_storyboard`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Storyboard {
+    pub vtt_path: String,          // WebVTT cues pointing at `sheet.jpg#xywh=x,y,w,h`
+    pub sprite_paths: Vec<String>, // sprite sheets, in order
+    pub tile_count: u32,
+}
+
+/// One colour of a `Palette`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PaletteColor {
+    pub color: u32,      // 0xRRGGBB
+    pub population: f64, // share of the opaque pixels, 0-1
+}
+
+/// Dominant colours and Android-style swatches from `extract_palette`. A swatch is None
+/// when no colour falls in its saturation and lightness range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Palette {
+    pub colors: Vec<PaletteColor>, // at most `count`, most common first
+    pub average: PaletteColor,     // mean of all opaque pixels
+    pub vibrant: Option<PaletteColor>,
+    pub light_vibrant: Option<PaletteColor>,
+    pub dark_vibrant: Option<PaletteColor>,
+    pub muted: Option<PaletteColor>,
+    pub light_muted: Option<PaletteColor>,
+    pub dark_muted: Option<PaletteColor>,
+}
+
+/// Perceptual hash used for duplicate detection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HashAlgorithm {
+    DHash, // brightness gradients; fast
+    PHash, // low DCT frequencies; more robust to re-encoding and colour changes
+}
+
+/// 64-bit perceptual hashes of an image or of frames sampled across a video
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaHash {
+    pub algorithm: HashAlgorithm,
+    pub hashes: Vec<u64>,   // one for an image, one per sampled frame for a video
+    pub times_ms: Vec<u64>, // timestamp of each frame; empty for images
+}
+
+/// Result of `compare_media_hashes`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HashComparison {
+    pub distance: u32,    // Hamming distance, 0-64; averaged over frames for two videos
+    pub similarity: f64,  // 1 - distance / 64
+    pub is_similar: bool, // distance within the threshold
+}
+
+/// Small RGBA image rendered from a BlurHash or ThumbHash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceholderImage {
+    pub rgba: Vec<u8>, // 4 bytes per pixel, row by row
+    pub width: u32,
+    pub height: u32,
+}
+
+/// GPS position from EXIF, in signed decimal degrees (south and west negative)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GpsLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: Option<f64>, // metres, negative below sea level
+}
+
+/// Header and EXIF facts about an image, from `get_image_info`; no pixels are decoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageInfo {
+    pub width: u32,                       // as displayed, after EXIF orientation (HEIC: after irot/imir)
+    pub height: u32,
+    pub format: String,                   // e.g. "JPEG", "PNG", "HEIC"
+    pub bit_depth: u32,                   // bits per channel (HEIC: luma)
+    pub has_alpha: bool,
+    pub orientation: u32,                 // EXIF orientation 1-8, 1 when absent
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub capture_date: Option<String>,     // EXIF "YYYY:MM:DD HH:MM:SS", DateTimeOriginal first
+    pub gps: Option<GpsLocation>,
+    pub icc_profile_name: Option<String>, // e.g. "Display P3", "sRGB IEC61966-2.1"
+    pub image_count: u32,                 // top-level images; more than 1 for HEIC collections
+    pub auxiliary_image_count: u32,       // HEIC alpha, depth and other auxiliary images
+    pub has_depth: bool,                  // HEIC depth map present
+}
+
+/// Kind of data a stream carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamType {
+    Video, // includes still images and cover art
+    Audio,
+    Subtitle,
+    Data,
+    Attachment, // e.g. fonts in Matroska
+    Unknown,
+}
+
+/// Container or stream metadata entry, in file order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaTag {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaChapter {
+    pub id: i64,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub title: Option<String>,
+    pub tags: Vec<MediaTag>,
+}
+
+/// Stream disposition flags, as ffprobe reports them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamDisposition {
+    pub default: bool,
+    pub dub: bool,
+    pub original: bool,
+    pub comment: bool,
+    pub lyrics: bool,
+    pub karaoke: bool,
+    pub forced: bool,
+    pub hearing_impaired: bool,
+    pub visual_impaired: bool,
+    pub clean_effects: bool,
+    pub attached_pic: bool, // cover art in an audio file
+    pub captions: bool,
+    pub descriptions: bool,
+    pub metadata: bool,
+}
+
+/// Side data attached to a stream (display matrix, HDR metadata, Dolby Vision config, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamSideData {
+    pub kind: String, // FFmpeg's name, e.g. "Display Matrix"
+    pub size_bytes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoStreamProbe {
+    pub width: u32,                      // coded size
+    pub height: u32,
+    pub display_width: u32,              // after rotation
+    pub display_height: u32,
+    pub rotation: i32,                   // degrees clockwise
+    pub avg_frame_rate: Option<f64>,
+    pub real_frame_rate: Option<f64>,
+    pub is_vfr: bool,
+    pub pixel_format: Option<String>,
+    pub bit_depth: Option<u32>,
+    pub color_primaries: Option<String>,
+    pub color_transfer: Option<String>,
+    pub color_matrix: Option<String>,
+    pub color_range: Option<String>,
+    pub hdr_format: Option<HdrFormat>,
+    pub level: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioStreamProbe {
+    pub sample_rate: u32,               // Hz
+    pub channels: u32,
+    pub channel_layout: Option<String>, // e.g. "stereo", "5.1(side)"
+    pub sample_format: Option<String>,  // e.g. "fltp", "s16"
+    pub bits_per_sample: Option<u32>,   // when the codec records it (PCM, FLAC, ALAC)
+}
+
+/// One stream of `MediaProbe`. `video` or `audio` is set for streams of that type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamProbe {
+    pub index: u32,
+    pub stream_type: StreamType,
+    pub codec_name: Option<String>,      // e.g. "h264", "aac", "subrip"
+    pub codec_long_name: Option<String>, // e.g. "H.264 / AVC / MPEG-4 AVC / MPEG-4 part 10"
+    pub profile: Option<String>,
+    pub bitrate: Option<u64>,            // bits per second, when the container records it
+    pub start_time_ms: Option<i64>,
+    pub duration_ms: Option<u64>,
+    pub frame_count: Option<u64>,
+    pub language: Option<String>,        // ISO 639, None when missing or "und"
+    pub title: Option<String>,
+    pub disposition: StreamDisposition,
+    pub tags: Vec<MediaTag>,
+    pub side_data: Vec<StreamSideData>,
+    pub video: Option<VideoStreamProbe>,
+    pub audio: Option<AudioStreamProbe>,
+}
+
+/// Container and streams of a media file, from `probe_media`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaProbe {
+    pub format_name: String,              // demuxer, e.g. "mov,mp4,m4a,3gp,3g2,mj2"
+    pub format_long_name: Option<String>, // e.g. "QuickTime / MOV"
+    pub duration_ms: Option<u64>,
+    pub start_time_ms: Option<i64>,
+    pub bitrate: Option<u64>,
+    pub size_bytes: u64,
+    pub tags: Vec<MediaTag>,
+    pub chapters: Vec<MediaChapter>,
+    pub streams: Vec<StreamProbe>,        // in file order
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageThumbnailParams {
     pub size_type: Option<ThumbnailSizeType>, // if None, use default size as per the aspect ratio
     pub format: Option<OutputFormat>,         // defaults to PNG
+    pub seek_mode: Option<SeekMode>,          // timeline thumbnails only; defaults to Accurate
+    pub resize_mode: Option<ResizeMode>,      // defaults to Fit
+    pub allow_upscale: Option<bool>,          // defaults to false
+    pub encoder_options: Option<EncoderOptions>,
+}
+
+/// How the source is mapped onto an output of a different aspect ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AspectMode {
+    Fit,        // letterbox/pillarbox with `pad_color`
+    Fill,       // centre crop to the target aspect
+    BlurredFit, // fit over a blurred, scaled copy of the frame
+}
+
+/// Crop rectangle in display orientation (as the video is shown, after rotation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CropRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Corner (or centre) of the frame a watermark is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatermarkParams {
+    pub image_path: String,                  // PNG/WebP (alpha is kept) or any decodable image
+    pub position: Option<WatermarkPosition>, // defaults to BottomRight
+    pub margin: Option<u32>,                 // distance from the anchored edges in output pixels, defaults to 16
+    pub scale: Option<f32>,                  // watermark width relative to video width, defaults to 0.15
+    pub opacity: Option<f32>,                // 0.0-1.0, defaults to 1.0
+    pub start_ms: Option<u64>,               // if None, show from the start
+    pub end_ms: Option<u64>,                 // if None, show until the end
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubtitleParams {
+    pub subtitle_path: String,         // .srt or .vtt file
+    pub font_path: Option<String>,     // TTF/OTF; if None, use the bundled DejaVu Sans Bold
+    pub font_size: Option<u32>,        // pixels in the output frame, defaults to 5% of the frame height
+    pub color: Option<u32>,            // 0xRRGGBB, defaults to white
+    pub outline_color: Option<u32>,    // 0xRRGGBB, defaults to black
+    pub outline_width: Option<u32>,    // pixels, defaults to font_size / 12; 0 disables the outline
+    pub bottom_margin: Option<u32>,    // pixels from the bottom edge, defaults to 6% of the frame height
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpeedParams {
+    pub factor: f32,                   // 0.25-8.0; 2.0 plays twice as fast, 0.5 is slow motion
+    pub frame_rate: Option<u32>,       // constant output frame rate (frames dropped or duplicated); if None, retimed frames keep their spacing
+    pub mute_audio_above: Option<f32>, // drop the audio when factor exceeds this; otherwise audio is time-stretched at the same pitch
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CompressParams {
     pub target_bitrate_kbps: u32, // target bitrate in kbps
     pub preset: Option<String>,   // e.g. "veryfast"
@@ -88,6 +555,107 @@ pub struct CompressParams {
     pub width: Option<u32>,       // if None, use original width
     pub height: Option<u32>,
     pub sample_duration_ms: Option<u64>, // if None, use original height
+    pub crop: Option<CropRect>,          // applied before scaling, display coordinates
+    pub aspect_ratio: Option<(u32, u32)>, // e.g. (9, 16); if None, keep the source aspect
+    pub aspect_mode: Option<AspectMode>, // defaults to Fit
+    pub pad_color: Option<u32>,          // 0xRRGGBB for Fit padding, defaults to black
+    pub watermark: Option<WatermarkParams>, // image overlay composited on every frame
+    pub subtitles: Option<SubtitleParams>, // burned-in captions (always rendered in-process)
+    pub speed: Option<SpeedParams>,        // playback speed change; if None, keep the original timing
+    pub normalize_loudness: Option<LoudnessNormalization>, // re-encodes the audio at the target loudness (in-process)
+    pub video_filters: Option<String>, // FFmpeg filter chain run on upright decoded frames before crop/scale, e.g. "hqdn3d,eq=contrast=1.1" (in-process)
+    pub audio_filters: Option<String>, // FFmpeg filter chain run on decoded audio, e.g. "highpass=f=80"; forces an audio transcode (in-process)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConcatParams {
+    pub width: Option<u32>,               // output width when re-encoding; if None, use the first clip's
+    pub height: Option<u32>,              // output height when re-encoding; if None, use the first clip's
+    pub fps: Option<u32>,                 // output frame rate when re-encoding; if None, use the first clip's
+    pub target_bitrate_kbps: Option<u32>, // if None, use the highest input bitrate
+    pub preset: Option<String>,           // e.g. "veryfast"
+    pub crf: Option<u8>,                  // quality, 0-51, lower is better
+    pub force_reencode: Option<bool>,     // re-encode even when the clips could be joined losslessly
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioChannels {
+    Mono,
+    Stereo,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioExtractOptions {
+    pub start_ms: Option<u64>,             // if None, from the start
+    pub end_ms: Option<u64>,               // if None, until the end
+    pub channels: Option<AudioChannels>,   // if None, mono stays mono and everything else becomes stereo
+    pub sample_rate: Option<u32>,          // if None, keep the source rate (Opus is always 48 kHz)
+    pub bitrate_kbps: Option<u32>,         // lossy formats only; defaults to 192 (AAC) or 128 (Opus)
+    pub normalize_loudness: Option<LoudnessNormalization>, // measured over the whole track; forces a transcode
+}
+
+/// EBU R128 measurement of an audio track
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoudnessInfo {
+    pub integrated_lufs: f64,   // -inf for silence
+    pub loudness_range_lu: f64, // LRA
+    pub true_peak_dbtp: f64,    // -inf for silence
+}
+
+/// Single gain applied so the integrated loudness hits the target
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LoudnessNormalization {
+    pub target_lufs: f32,            // e.g. -23 (broadcast), -16 (podcasts) or -14 (streaming)
+    pub true_peak_dbtp: Option<f32>, // gain is capped so the true peak stays below this, defaults to -1
+}
+
+/// What `add_music` does with the video's own audio track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioTrackMode {
+    Replace, // only the music is kept
+    Mix,     // music is mixed under the original track
+}
+
+/// Lowers the music while the original track is loud (speech, effects)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DuckingParams {
+    pub threshold_db: Option<f32>,  // original level that triggers ducking, defaults to -30
+    pub reduction_db: Option<f32>,  // how much the music is lowered, defaults to 12
+    pub attack_ms: Option<u32>,     // time to duck, defaults to 50
+    pub release_ms: Option<u32>,    // time to recover, defaults to 400
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioMixParams {
+    pub mode: Option<AudioTrackMode>,     // defaults to Replace
+    pub music_gain_db: Option<f32>,       // if None, 0 dB
+    pub original_gain_db: Option<f32>,    // Mix only; if None, 0 dB
+    pub ducking: Option<DuckingParams>,   // Mix only; if None, no ducking
+    pub loop_music: bool,                 // repeat music shorter than the video; otherwise it ends in silence
+    pub music_start_ms: Option<u64>,      // where to start reading the music file
+    pub fade_in_ms: Option<u64>,          // music fade in at the start of the video
+    pub fade_out_ms: Option<u64>,         // music fade out at the end of the video
+}
+
+/// What happens to the audio track of a reversed clip
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReverseAudioMode {
+    Reverse,
+    Mute,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReverseParams {
+    pub start_ms: Option<u64>,             // if None, from the start
+    pub end_ms: Option<u64>,               // if None, until the end
+    pub boomerang_loops: Option<u32>,      // play forward then backward this many times; if None, only backward
+    pub audio: Option<ReverseAudioMode>,   // defaults to Reverse
+    pub width: Option<u32>,                // if None, use original width
+    pub height: Option<u32>,               // if None, use original height
+    pub target_bitrate_kbps: Option<u32>,  // if None, use the input bitrate
+    pub preset: Option<String>,            // e.g. "veryfast"
+    pub crf: Option<u8>,                   // quality, 0-51, lower is better
+    pub memory_budget_mb: Option<u32>,     // decoded frames held per chunk, defaults to 256
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +722,62 @@ pub async fn generate_video_thumbnail(
     }
 }
 
+/// Like `generate_video_thumbnail`, but returns the encoded image and the timestamp of the
+/// frame actually used instead of writing a file
+pub async fn generate_video_thumbnail_data(
+    path: String,
+    params: VideoThumbnailParams,
+) -> Result<ThumbnailData, Error> {
+    tracing::debug!("generate_video_thumbnail_data called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        frames::thumbnail_data(&path, &params)
+    }));
+
+    match result {
+        Ok(Ok(thumbnail)) => Ok(thumbnail),
+        Ok(Err(e)) => {
+            error!("generate_video_thumbnail_data returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "generate_video_thumbnail_data")),
+    }
+}
+
+/// Output format, encoder settings and fitting for the image and timeline thumbnail functions
+fn image_thumbnail_options(
+    params: Option<ImageThumbnailParams>,
+) -> (OutputFormat, EncoderOptions, Resize) {
+    match params {
+        Some(params) => (
+            params.format.unwrap_or(OutputFormat::PNG),
+            params.encoder_options.unwrap_or_default(),
+            Resize::new(
+                params
+                    .size_type
+                    .unwrap_or(ThumbnailSizeType::Medium)
+                    .dimensions(),
+                params.resize_mode,
+                params.allow_upscale,
+            ),
+        ),
+        None => (
+            OutputFormat::PNG,
+            EncoderOptions::default(),
+            Resize::new(ThumbnailSizeType::Medium.dimensions(), None, None),
+        ),
+    }
+}
+
+/// Writes `num_thumbnails` frames evenly spaced from the start of the video, one per
+/// equal slice of its duration, streaming each path through `sink` as it is written.
+/// With `empty_image_fallback`, a blank image stands in for any frame that can't be decoded.
 pub fn generate_video_timeline_thumbnails(
     path: String,
     output_path: String,
@@ -165,87 +789,96 @@ pub fn generate_video_timeline_thumbnails(
     let filename_without_extension = get_file_name_without_extension(&path);
     let base_output_dir = check_output_path(&output_path)?;
 
-    let (output_format, size) = match params {
-        Some(params) => (
-            params.format.unwrap_or(OutputFormat::PNG),
-            params.size_type.unwrap_or(ThumbnailSizeType::Medium),
-        ),
-        None => (OutputFormat::PNG, ThumbnailSizeType::Medium),
-    };
+    let seek_mode = params
+        .as_ref()
+        .and_then(|params| params.seek_mode)
+        .unwrap_or(SeekMode::Accurate);
+    let (output_format, encoder_options, resize) = image_thumbnail_options(params);
 
-    // Get video info (this acquires and releases the mutex)
-    let video_info = video::get_video_info(&path)
-        .map_err(|e| anyhow::anyhow!("Failed to get video info for timeline generation: {}", e))?;
-    let duration_ms = video_info.duration_ms;
-    
-    // Validate: num_thumbnails should not exceed duration_ms
-    if num_thumbnails > duration_ms as u32 {
-        let error = anyhow::anyhow!(
-            "Number of thumbnails ({}) cannot exceed video duration ({}ms)",
-            num_thumbnails,
-            duration_ms
-        );
+    if num_thumbnails == 0 {
+        let error = anyhow::anyhow!("Number of thumbnails must be greater than 0");
         let _ = sink.add_error(error).map_err(|_| anyhow::anyhow!("Sink closed"))?;
         return Ok(());
     }
-    
-    // Keep a buffer of 1.5 seconds from the end
-    const BUFFER_MS: u64 = 1500; // 1.5 seconds
-    let effective_duration_ms = if duration_ms > BUFFER_MS {
-        duration_ms - BUFFER_MS
-    } else {
-        // If video is shorter than buffer, use at least 1ms to avoid division by zero
-        duration_ms.max(1)
-    };
-    
-    let time_ms = effective_duration_ms / num_thumbnails as u64;
-
-    // Generate thumbnails sequentially
-    // Each generate_thumbnail call will acquire and release the mutex individually
-    // This prevents conflicts with other FFmpeg operations
-    for i in 0..num_thumbnails {
-        let mut time = time_ms * i as u64;
-        // Clamp to effective duration (which already has the buffer applied)
-        if time > effective_duration_ms {
-            time = effective_duration_ms;
-        }
-        let params = VideoThumbnailParams {
-            time_ms: time,
-            size_type: Some(size),
-            format: Some(output_format),
-        };
-        // generate_thumbnail will acquire the mutex internally
-        let thumbnail = video::generate_thumbnail(&path, &params);
-        let output_path = base_output_dir.join(format!(
-            "thumbnail_{}_{}.{}",
-            filename_without_extension.display(),
-            i,
-            output_format.extension()
-        ));
-        let output_path_str = output_path.to_string_lossy().to_string();
 
-        match thumbnail {
-            Ok(thumbnail) => {
-                std::fs::write(&output_path, thumbnail.0).unwrap();
-                sink.add(output_path_str)
-                    .map_err(|_| anyhow::anyhow!("Sink closed"))?;
-            }
-            Err((e, w, h)) => {
-                eprintln!("Error generating thumbnail: {:?}", e);
-                if empty_image_fallback.unwrap_or(false) {
-                    let size = if w > 0 && h > 0 {
-                        ThumbnailSizeType::Custom((w, h))
-                    } else {
-                        size
-                    };
-                    video::generate_empty_thumbnail(size, output_format, &output_path)?;
+    // One pass over the video: the input is opened once and frames are decoded in order
+    let mut last_size = ThumbnailSizeType::Custom((resize.width, resize.height));
+    frames::sample_frames(
+        &path,
+        &FrameSelection::Count(num_thumbnails),
+        resize,
+        seek_mode,
+        |index, _, frame| {
+            let output_path = base_output_dir.join(format!(
+                "thumbnail_{}_{}.{}",
+                filename_without_extension.display(),
+                index,
+                output_format.extension()
+            ));
+            let output_path_str = output_path.to_string_lossy().to_string();
+
+            match frame {
+                Some((image, _)) => {
+                    last_size = ThumbnailSizeType::Custom(image.dimensions());
+                    let data = encode::encode_rgb_image(&image, output_format, &encoder_options)?;
+                    std::fs::write(&output_path, data)
+                        .with_context(|| format!("Failed to write {}", output_path_str))?;
                     sink.add(output_path_str)
                         .map_err(|_| anyhow::anyhow!("Sink closed"))?;
                 }
+                None => {
+                    warn!("generate_video_timeline_thumbnails - no frame decoded for thumbnail {}", index);
+                    if empty_image_fallback.unwrap_or(false) {
+                        video::generate_empty_thumbnail(last_size, output_format, &output_path)?;
+                        sink.add(output_path_str)
+                            .map_err(|_| anyhow::anyhow!("Sink closed"))?;
+                    }
+                }
             }
-        }
+            Ok(())
+        },
+    )
+}
+
+/// Like `generate_video_timeline_thumbnails`, but streams each encoded image with its
+/// timestamp through `sink` instead of writing files
+pub fn generate_video_timeline_thumbnail_data(
+    path: String,
+    params: Option<ImageThumbnailParams>,
+    num_thumbnails: u32,
+    sink: StreamSink<ThumbnailData>,
+) -> anyhow::Result<()> {
+    let seek_mode = params
+        .as_ref()
+        .and_then(|params| params.seek_mode)
+        .unwrap_or(SeekMode::Accurate);
+    let (output_format, encoder_options, resize) = image_thumbnail_options(params);
+
+    if num_thumbnails == 0 {
+        let error = anyhow::anyhow!("Number of thumbnails must be greater than 0");
+        let _ = sink.add_error(error).map_err(|_| anyhow::anyhow!("Sink closed"))?;
+        return Ok(());
     }
-    Ok(())
+
+    frames::sample_frames(
+        &path,
+        &FrameSelection::Count(num_thumbnails),
+        resize,
+        seek_mode,
+        |index, _, frame| {
+            let Some((image, time_ms)) = frame else {
+                warn!("generate_video_timeline_thumbnail_data - no frame decoded for thumbnail {}", index);
+                return Ok(());
+            };
+            sink.add(ThumbnailData {
+                data: encode::encode_rgb_image(&image, output_format, &encoder_options)?,
+                width: image.width(),
+                height: image.height(),
+                time_ms: Some(time_ms),
+            })
+            .map_err(|_| anyhow::anyhow!("Sink closed"))
+        },
+    )
 }
 
 pub async fn generate_image_thumbnail(
@@ -257,16 +890,7 @@ pub async fn generate_image_thumbnail(
     let filename_without_extension = get_file_name_without_extension(&path);
     let base_output_dir = check_output_path(&output_path)?;
 
-    let (output_format, size) = match params {
-        Some(params) => (
-            params.format.unwrap_or(OutputFormat::PNG),
-            params
-                .size_type
-                .unwrap_or(ThumbnailSizeType::Medium)
-                .dimensions(),
-        ),
-        None => (OutputFormat::PNG, ThumbnailSizeType::Medium.dimensions()),
-    };
+    let (output_format, encoder_options, resize) = image_thumbnail_options(params);
     let mut suffix = suffix.unwrap_or_default();
     if suffix.is_empty() {
         suffix = "".to_string();
@@ -283,20 +907,58 @@ pub async fn generate_image_thumbnail(
     let output_path = base_output_dir.join(output_file_name);
     let output_path_str = output_path.to_string_lossy().to_string();
 
+    let img = decode_image(&path)?;
+
+    let thumbnail = resize.apply(&img);
+
+    let data = encode::encode_image(&thumbnail, output_format, &encoder_options)?;
+    std::fs::write(&output_path, data).with_context(|| {
+        format!(
+            "Failed to save {} thumbnail",
+            output_format.extension().to_uppercase()
+        )
+    })?;
+
+    Ok(output_path_str)
+}
+
+/// Like `generate_image_thumbnail`, but returns the encoded image instead of writing a file
+pub async fn generate_image_thumbnail_data(
+    path: String,
+    params: Option<ImageThumbnailParams>,
+) -> Result<ThumbnailData, Error> {
+    let (output_format, encoder_options, resize) = image_thumbnail_options(params);
+
+    let thumbnail = resize.apply(&decode_image(&path)?);
+
+    let data = encode::encode_image(&thumbnail, output_format, &encoder_options)
+        .with_context(|| format!("Failed to encode {} thumbnail", output_format.extension()))?;
+
+    Ok(ThumbnailData {
+        data,
+        width: thumbnail.width(),
+        height: thumbnail.height(),
+        time_ms: None,
+    })
+}
+
+/// Decode an image file with the best available decoder for its format
+/// (libheif for HEIC/HEIF, FFmpeg for everything else, image crate as fallback)
+pub(crate) fn decode_image(path: &str) -> Result<DynamicImage, Error> {
     // Try to decode image - use libheif for HEIC (via image crate integration), FFmpeg for other formats
     // The image crate integration in libheif-rs v2.5+ handles HEIC decoding automatically
     let img = if path.to_lowercase().ends_with(".heic") || path.to_lowercase().ends_with(".heif") {
         // For HEIC files, try image crate first (which will use libheif via integration)
         // If that fails, fall back to direct libheif decoding, then FFmpeg
-        match image::open(&path) {
+        match image::open(path) {
             Ok(img) => img,
             Err(image_err) => {
                 // Try direct libheif decoding
-                match decode_heic_with_libheif(&path) {
+                match decode_heic_with_libheif(path) {
                     Ok(img) => img,
                     Err(heif_err) => {
                         // Fall back to FFmpeg
-                        decode_image_with_ffmpeg(&path).with_context(|| {
+                        decode_image_with_ffmpeg(path).with_context(|| {
                             format!(
                                 "Failed to decode HEIC image. image crate error: {:?}, libheif error: {:?}",
                                 image_err, heif_err
@@ -308,11 +970,11 @@ pub async fn generate_image_thumbnail(
         }
     } else {
         // For non-HEIC files, try FFmpeg first, then image crate
-        match decode_image_with_ffmpeg(&path) {
+        match decode_image_with_ffmpeg(path) {
             Ok(img) => img,
             Err(ffmpeg_err) => {
                 // Fall back to image crate for common formats
-                image::open(&path).with_context(|| {
+                image::open(path).with_context(|| {
                     format!(
                         "Failed to open or decode image file. FFmpeg error: {:?}",
                         ffmpeg_err
@@ -322,27 +984,7 @@ pub async fn generate_image_thumbnail(
         }
     };
 
-    let thumbnail = img.thumbnail(size.0, size.1);
-
-    match output_format {
-        OutputFormat::JPEG => {
-            thumbnail
-                .save_with_format(&output_path, image::ImageFormat::Jpeg)
-                .context("Failed to save JPEG thumbnail")?;
-        }
-        OutputFormat::PNG => {
-            thumbnail
-                .save_with_format(&output_path, image::ImageFormat::Png)
-                .context("Failed to save PNG thumbnail")?;
-        }
-        OutputFormat::WEBP => {
-            thumbnail
-                .save_with_format(&output_path, image::ImageFormat::WebP)
-                .context("Failed to save WebP thumbnail")?;
-        }
-    }
-
-    Ok(output_path_str)
+    Ok(img)
 }
 
 /// Decode HEIC/HEIF images using libheif-rs (more reliable than FFmpeg for HEIC)
@@ -820,3 +1462,654 @@ pub fn compress_video(
         }
     }
 }
+
+/// Turn a caught panic into an error, so FFmpeg crashes don't take the app down
+fn panic_to_error(panic: Box<dyn std::any::Any + Send>, function: &str) -> Error {
+    let panic_msg = if let Some(s) = panic.downcast_ref::<&str>() {
+        format!("Panic in {}: {}", function, s)
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        format!("Panic in {}: {}", function, s)
+    } else {
+        format!("Panic in {}: unknown error", function)
+    };
+    error!("FATAL: {}", panic_msg);
+    anyhow::anyhow!(panic_msg)
+}
+
+/// Progress callback for the long-running wrappers: turns `(processed_ms, total_ms)` updates
+/// into `CompressProgress`, with the speed measured from when the reporter was created
+fn progress_reporter<'a>(send: impl Fn(CompressProgress) + 'a) -> impl FnMut(u64, u64) + 'a {
+    let started = std::time::Instant::now();
+    move |processed_ms, total_ms| {
+        let elapsed = started.elapsed().as_secs_f32();
+        let speed_x = if elapsed > 0.0 {
+            processed_ms as f32 / 1000.0 / elapsed
+        } else {
+            0.0
+        };
+        send(CompressProgress {
+            processed_ms,
+            total_ms,
+            speed_x,
+        });
+    }
+}
+
+/// Join several clips into one video at `output_path`.
+/// Clips with matching codecs and parameters are joined losslessly; otherwise they are
+/// normalised (resolution, frame rate, pixel format, rotation, stereo AAC audio with silence
+/// for clips that have none) and re-encoded. Progress is reported through `sink`.
+pub fn concat_videos(
+    inputs: Vec<String>,
+    output_path: String,
+    params: ConcatParams,
+    sink: StreamSink<CompressProgress>,
+) -> Result<(), Error> {
+    tracing::debug!("concat_videos called with {} inputs, output: {}", inputs.len(), output_path);
+
+    for path in &inputs {
+        if !std::path::Path::new(path).exists() {
+            let err = anyhow::anyhow!("Input file does not exist: {}", path);
+            error!("{}", err);
+            return Err(err);
+        }
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        concat::concat_videos(&inputs, &output_path, &params, progress_reporter(|progress| {
+            let _ = sink.add(progress);
+        }))
+    }));
+
+    match result {
+        Ok(Ok(stats)) => {
+            info!(
+                "concat_videos succeeded: {} ({} bytes)",
+                stats.output_file_path, stats.encoded_size_bytes
+            );
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            error!("concat_videos returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "concat_videos")),
+    }
+}
+
+/// Render a clip (or `start_ms..end_ms` of it) backwards, or as a boomerang that plays
+/// forward then backward `boomerang_loops` times. Frames are decoded one GOP-aligned chunk
+/// at a time within `memory_budget_mb`. Progress is reported through `sink`.
+pub fn reverse_video(
+    path: String,
+    output_path: String,
+    params: ReverseParams,
+    sink: StreamSink<CompressProgress>,
+) -> Result<(), Error> {
+    tracing::debug!("reverse_video called with path: {}, output: {}", path, output_path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        reverse::reverse_video(&path, &output_path, &params, progress_reporter(|progress| {
+            let _ = sink.add(progress);
+        }))
+    }));
+
+    match result {
+        Ok(Ok(stats)) => {
+            info!(
+                "reverse_video succeeded: {} ({} bytes)",
+                stats.output_file_path, stats.encoded_size_bytes
+            );
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            error!("reverse_video returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "reverse_video")),
+    }
+}
+
+/// Export the audio track of `path` as `format`. The track is copied without re-encoding when
+/// the codec already matches the container and no channel/rate/bitrate change is requested;
+/// otherwise it is decoded, resampled and encoded. Returns the output file path.
+pub fn extract_audio(
+    path: String,
+    output_path: String,
+    format: AudioFormat,
+    options: AudioExtractOptions,
+) -> Result<String, Error> {
+    tracing::debug!("extract_audio called with path: {}, output: {}, format: {:?}", path, output_path, format);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        extract::extract_audio(&path, &output_path, format, &options)
+    }));
+
+    match result {
+        Ok(Ok(output_file_path)) => {
+            info!("extract_audio succeeded: {}", output_file_path);
+            Ok(output_file_path)
+        }
+        Ok(Err(e)) => {
+            error!("extract_audio returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "extract_audio")),
+    }
+}
+
+/// Put the audio of `music_path` under the video `video_path`, replacing or mixing with the
+/// original track. The music is looped or cut to the video length. The video stream is copied
+/// when the output container supports its codec and re-encoded as H.264 otherwise.
+pub fn add_music(
+    video_path: String,
+    music_path: String,
+    output_path: String,
+    params: AudioMixParams,
+    sink: StreamSink<CompressProgress>,
+) -> Result<(), Error> {
+    tracing::debug!(
+        "add_music called with video: {}, music: {}, output: {}",
+        video_path, music_path, output_path
+    );
+
+    for path in [&video_path, &music_path] {
+        if !std::path::Path::new(path).exists() {
+            let err = anyhow::anyhow!("Input file does not exist: {}", path);
+            error!("{}", err);
+            return Err(err);
+        }
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        music::add_music(&video_path, &music_path, &output_path, &params, progress_reporter(|progress| {
+            let _ = sink.add(progress);
+        }))
+    }));
+
+    match result {
+        Ok(Ok(stats)) => {
+            info!(
+                "add_music succeeded: {} ({} bytes)",
+                stats.output_file_path, stats.encoded_size_bytes
+            );
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            error!("add_music returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "add_music")),
+    }
+}
+
+/// Measure the integrated loudness, loudness range and true peak (EBU R128) of the audio track
+pub fn analyze_loudness(path: String) -> Result<LoudnessInfo, Error> {
+    tracing::debug!("analyze_loudness called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| loudness::analyze(&path)));
+
+    match result {
+        Ok(Ok(info)) => Ok(info),
+        Ok(Err(e)) => {
+            error!("analyze_loudness returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "analyze_loudness")),
+    }
+}
+
+/// Export `[start_ms, start_ms + duration_ms)` of a video as an animated GIF, WebP or APNG
+/// at up to `fps` frames per second and `max_width` pixels wide. Returns the output file path.
+#[allow(clippy::too_many_arguments)]
+pub fn export_animation(
+    path: String,
+    output_path: String,
+    start_ms: u64,
+    duration_ms: u64,
+    fps: u32,
+    max_width: u32,
+    format: AnimationFormat,
+    options: AnimationOptions,
+) -> Result<String, Error> {
+    tracing::debug!(
+        "export_animation called with path: {}, output: {}, format: {:?}",
+        path, output_path, format
+    );
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        animation::export_animation(
+            &path,
+            &output_path,
+            start_ms,
+            duration_ms,
+            fps,
+            max_width,
+            format,
+            &options,
+        )
+    }));
+
+    match result {
+        Ok(Ok(output_file_path)) => {
+            info!("export_animation succeeded: {}", output_file_path);
+            Ok(output_file_path)
+        }
+        Ok(Err(e)) => {
+            error!("export_animation returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "export_animation")),
+    }
+}
+
+/// Pick a poster frame: samples frames across the video, scores them on brightness,
+/// contrast, sharpness and colour, and returns the best one encoded with its timestamp
+pub fn generate_best_thumbnail(path: String, params: BestThumbnailParams) -> Result<BestThumbnail, Error> {
+    tracing::debug!("generate_best_thumbnail called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        poster::generate_best_thumbnail(&path, &params)
+    }));
+
+    match result {
+        Ok(Ok(thumbnail)) => {
+            info!(
+                "generate_best_thumbnail succeeded: frame at {}ms (score {:.3})",
+                thumbnail.time_ms, thumbnail.score
+            );
+            Ok(thumbnail)
+        }
+        Ok(Err(e)) => {
+            error!("generate_best_thumbnail returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "generate_best_thumbnail")),
+    }
+}
+
+/// Storyboard for scrubbing previews: a frame every `interval_ms` tiled `columns` x `rows`
+/// per JPEG sprite sheet, plus a WebVTT file mapping each time range to its tile.
+/// Only the JPEG fields of `encoder_options` apply.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_storyboard(
+    path: String,
+    output_dir: String,
+    interval_ms: u64,
+    tile_width: u32,
+    tile_height: u32,
+    columns: u32,
+    rows: u32,
+    encoder_options: Option<EncoderOptions>,
+) -> Result<Storyboard, Error> {
+    tracing::debug!("generate_storyboard called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        storyboard::generate_storyboard(
+            &path,
+            &output_dir,
+            interval_ms,
+            tile_width,
+            tile_height,
+            columns,
+            rows,
+            &encoder_options.unwrap_or_default(),
+        )
+    }));
+
+    match result {
+        Ok(Ok(storyboard)) => {
+            info!(
+                "generate_storyboard succeeded: {} tiles in {} sheets, {}",
+                storyboard.tile_count,
+                storyboard.sprite_paths.len(),
+                storyboard.vtt_path
+            );
+            Ok(storyboard)
+        }
+        Ok(Err(e)) => {
+            error!("generate_storyboard returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "generate_storyboard")),
+    }
+}
+
+/// Extract many frames in a single pass: the input is opened once and decoded forward,
+/// seeking only when a timestamp is past the next keyframe. Each image is written to
+/// `output_path` and streamed through `sink` as soon as it is ready.
+pub fn extract_frames(
+    path: String,
+    output_path: String,
+    params: FrameExtractParams,
+    sink: StreamSink<ExtractedFrame>,
+) -> Result<(), Error> {
+    tracing::debug!("extract_frames called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        frames::extract_frames(&path, &output_path, &params, |frame| {
+            sink.add(frame).map_err(|_| anyhow::anyhow!("Sink closed"))
+        })
+    }));
+
+    match result {
+        Ok(Ok(count)) => {
+            info!("extract_frames succeeded: {} frames", count);
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            error!("extract_frames returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "extract_frames")),
+    }
+}
+
+/// Find scene cuts by comparing downscaled consecutive frames (colour histogram and pixel
+/// differences). `threshold` is 0-1, around 0.3 for hard cuts. Decodes the whole video;
+/// progress is reported through `sink`, followed by a single `Cuts` event with the result.
+pub fn detect_scenes(
+    path: String,
+    threshold: f64,
+    sink: StreamSink<SceneDetectionEvent>,
+) -> Result<(), Error> {
+    tracing::debug!("detect_scenes called with path: {}, threshold: {}", path, threshold);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        scenes::detect_scenes(&path, threshold, progress_reporter(|progress| {
+            let _ = sink.add(SceneDetectionEvent::Progress(progress));
+        }))
+    }));
+
+    match result {
+        Ok(Ok(cuts)) => {
+            info!("detect_scenes succeeded: {} scene cuts", cuts.len());
+            sink.add(SceneDetectionEvent::Cuts(cuts))
+                .map_err(|_| anyhow::anyhow!("Sink closed"))
+        }
+        Ok(Err(e)) => {
+            error!("detect_scenes returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "detect_scenes")),
+    }
+}
+
+/// BlurHash placeholder for an image, or for the frame at `time_ms` of a video.
+/// `components_x` and `components_y` (1-9) set how much detail the hash keeps; 4x3 is typical.
+pub async fn compute_blurhash(
+    path: String,
+    components_x: u32,
+    components_y: u32,
+    time_ms: Option<u64>,
+) -> Result<String, Error> {
+    tracing::debug!("compute_blurhash called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let image = placeholder::source_image(&path, time_ms)?;
+        placeholder::blurhash_encode(&image, components_x, components_y)
+    }));
+
+    match result {
+        Ok(Ok(hash)) => Ok(hash),
+        Ok(Err(e)) => {
+            error!("compute_blurhash returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "compute_blurhash")),
+    }
+}
+
+/// ThumbHash placeholder (about 25 bytes, keeps the aspect ratio and transparency) for an
+/// image, or for the frame at `time_ms` of a video
+pub async fn compute_thumbhash(path: String, time_ms: Option<u64>) -> Result<Vec<u8>, Error> {
+    tracing::debug!("compute_thumbhash called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        placeholder::thumbhash_encode(&placeholder::source_image(&path, time_ms)?)
+    }));
+
+    match result {
+        Ok(Ok(hash)) => Ok(hash),
+        Ok(Err(e)) => {
+            error!("compute_thumbhash returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "compute_thumbhash")),
+    }
+}
+
+/// Renders a BlurHash at `width` x `height` (at most 1024 each). `punch` scales the
+/// contrast, defaults to 1.
+pub fn decode_blurhash(
+    hash: String,
+    width: u32,
+    height: u32,
+    punch: Option<f32>,
+) -> Result<PlaceholderImage, Error> {
+    placeholder::blurhash_decode(&hash, width, height, punch.unwrap_or(1.0))
+}
+
+/// Renders a ThumbHash at its encoded aspect ratio, at most 32 pixels on the longest side
+pub fn decode_thumbhash(hash: Vec<u8>) -> Result<PlaceholderImage, Error> {
+    placeholder::thumbhash_decode(&hash)
+}
+
+/// Perceptual hash of an image (HEIC, JPEG, PNG, ...) for duplicate detection. Defaults to pHash.
+pub async fn compute_image_hash(
+    path: String,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<MediaHash, Error> {
+    tracing::debug!("compute_image_hash called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        perceptual::image_hash(&path, algorithm.unwrap_or(HashAlgorithm::PHash))
+    }));
+
+    match result {
+        Ok(Ok(hash)) => Ok(hash),
+        Ok(Err(e)) => {
+            error!("compute_image_hash returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "compute_image_hash")),
+    }
+}
+
+/// Perceptual hashes of `frame_count` frames (defaults to 16) spread evenly over a video.
+/// Defaults to pHash.
+pub fn compute_video_hash(
+    path: String,
+    algorithm: Option<HashAlgorithm>,
+    frame_count: Option<u32>,
+) -> Result<MediaHash, Error> {
+    tracing::debug!("compute_video_hash called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        perceptual::video_hash(
+            &path,
+            algorithm.unwrap_or(HashAlgorithm::PHash),
+            frame_count,
+        )
+    }));
+
+    match result {
+        Ok(Ok(hash)) => {
+            info!(
+                "compute_video_hash succeeded: {} frames hashed",
+                hash.hashes.len()
+            );
+            Ok(hash)
+        }
+        Ok(Err(e)) => {
+            error!("compute_video_hash returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "compute_video_hash")),
+    }
+}
+
+/// Compares two hashes made with the same algorithm. An image against a video matches its
+/// closest frame. `threshold` is the largest distance still reported as similar, defaults to 10.
+pub fn compare_media_hashes(
+    a: MediaHash,
+    b: MediaHash,
+    threshold: Option<u32>,
+) -> Result<HashComparison, Error> {
+    perceptual::compare(&a, &b, threshold)
+}
+
+/// The `count` (1-64) dominant colours of an image, or of the frame at `time_ms` of a video,
+/// found by median cut on a downscaled copy, plus vibrant/muted swatches for theming
+pub async fn extract_palette(
+    path: String,
+    count: u32,
+    time_ms: Option<u64>,
+) -> Result<Palette, Error> {
+    tracing::debug!("extract_palette called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        palette::extract(&placeholder::source_image(&path, time_ms)?, count)
+    }));
+
+    match result {
+        Ok(Ok(palette)) => Ok(palette),
+        Ok(Err(e)) => {
+            error!("extract_palette returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "extract_palette")),
+    }
+}
+
+/// Container (format, duration, bitrate, tags, chapters) and every stream (codec, parameters,
+/// language, disposition, side data) of a media file. Unlike `get_video_info` this works for
+/// audio-only, image and subtitle files.
+pub fn probe_media(path: String) -> Result<MediaProbe, Error> {
+    tracing::debug!("probe_media called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| probe::probe(&path)));
+
+    match result {
+        Ok(Ok(probe)) => Ok(probe),
+        Ok(Err(e)) => {
+            error!("probe_media returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "probe_media")),
+    }
+}
+
+/// `probe_media` as pretty-printed JSON, for logging or passing on to other tools
+pub fn probe_media_json(path: String) -> Result<String, Error> {
+    let probe = probe_media(path)?;
+    serde_json::to_string_pretty(&probe).context("Failed to serialize media probe")
+}
+
+/// Dimensions, format, bit depth, alpha, EXIF (orientation, camera, capture date, GPS) and ICC
+/// profile name of an image, plus image/auxiliary/depth counts for HEIC. Reads headers and
+/// metadata only, so it is cheap enough to call before choosing a thumbnail size.
+pub async fn get_image_info(path: String) -> Result<ImageInfo, Error> {
+    tracing::debug!("get_image_info called with path: {}", path);
+
+    if !std::path::Path::new(&path).exists() {
+        let err = anyhow::anyhow!("Input file does not exist: {}", path);
+        error!("{}", err);
+        return Err(err);
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| metadata::image_info(&path)));
+
+    match result {
+        Ok(Ok(info)) => Ok(info),
+        Ok(Err(e)) => {
+            error!("get_image_info returned error: {}", e);
+            Err(e)
+        }
+        Err(panic) => Err(panic_to_error(panic, "get_image_info")),
+    }
+}
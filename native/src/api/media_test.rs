@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
     use crate::api::media::{
-        decode_image_with_ffmpeg, OutputFormat, ThumbnailSizeType, VideoThumbnailParams,
+        decode_image_with_ffmpeg, AnimationFormat, AnimationOptions, AudioExtractOptions,
+        AudioFormat, AudioMixParams, BestThumbnailParams, ConcatParams, EncoderOptions,
+        FrameExtractParams, FrameSelection, HashAlgorithm, OutputFormat, ReverseParams, StreamType,
+        ThumbnailSizeType, VideoThumbnailParams,
     };
     use image::GenericImageView;
     use std::path::Path;
@@ -68,6 +71,10 @@ mod tests {
             time_ms: 1000, // 1 second
             size_type: Some(ThumbnailSizeType::Medium),
             format: Some(OutputFormat::PNG),
+            seek_mode: None,
+            resize_mode: None,
+            allow_upscale: None,
+            encoder_options: None,
         };
 
         match generate_thumbnail(video_path, &params) {
@@ -193,6 +200,10 @@ mod tests {
                 time_ms,
                 size_type: Some(ThumbnailSizeType::Medium),
                 format: Some(OutputFormat::PNG),
+                seek_mode: None,
+                resize_mode: None,
+                allow_upscale: None,
+                encoder_options: None,
             };
 
             match generate_thumbnail(video_path, &params) {
@@ -425,4 +436,381 @@ mod tests {
             }
         }
     }
+
+    const VIDEO_PATH: &str = "../native/video.MOV";
+    const TEMP_DIR: &str = "../native/temp";
+
+    /// Output directory for one test, emptied first so stale files can't pass the checks
+    fn temp_output_dir(name: &str) -> String {
+        let dir = format!("{}/{}", TEMP_DIR, name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).expect("Failed to create temp directory");
+        dir
+    }
+
+    fn sample_video() -> Option<&'static str> {
+        if !Path::new(VIDEO_PATH).exists() {
+            eprintln!("Skipping test: {} not found", VIDEO_PATH);
+            return None;
+        }
+        ffmpeg_next::init().expect("Failed to initialize FFmpeg");
+        Some(VIDEO_PATH)
+    }
+
+    fn has_audio(path: &str) -> bool {
+        crate::api::probe::probe(path)
+            .expect("Failed to probe video")
+            .streams
+            .iter()
+            .any(|stream| stream.stream_type == StreamType::Audio)
+    }
+
+    /// Probe and get_video_info must agree on the container they both read
+    #[test]
+    fn test_probe_media() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+
+        let probe = crate::api::probe::probe(video_path).expect("Failed to probe video");
+        let info = crate::api::video::get_video_info(video_path).expect("Failed to get video info");
+        println!(
+            "✓ Probed {}: {} streams, {:?} ms",
+            probe.format_name,
+            probe.streams.len(),
+            probe.duration_ms
+        );
+
+        assert!(probe.format_name.contains("mov"));
+        assert_eq!(probe.size_bytes, info.size_bytes);
+        // Both read the container duration, rounded differently
+        assert!(probe.duration_ms.unwrap().abs_diff(info.duration_ms) <= 1);
+        assert!(probe
+            .streams
+            .iter()
+            .any(|stream| stream.stream_type == StreamType::Video));
+        for (i, stream) in probe.streams.iter().enumerate() {
+            assert_eq!(stream.index as usize, i, "Streams should be in file order");
+        }
+    }
+
+    /// Frames come back in time order, with the files they were written to
+    #[test]
+    fn test_extract_frames() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        let output_dir = temp_output_dir("frames");
+
+        let params = FrameExtractParams {
+            selection: FrameSelection::Timestamps(vec![2_000, 0, 1_000]),
+            size_type: Some(ThumbnailSizeType::Small),
+            format: Some(OutputFormat::JPEG),
+            seek_mode: None,
+            resize_mode: None,
+            allow_upscale: None,
+            encoder_options: None,
+        };
+        let mut frames = Vec::new();
+        let count = crate::api::frames::extract_frames(video_path, &output_dir, &params, |frame| {
+            frames.push(frame);
+            Ok(())
+        })
+        .expect("Failed to extract frames");
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            frames.iter().map(|f| f.requested_ms).collect::<Vec<_>>(),
+            vec![0, 1_000, 2_000]
+        );
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.index as usize, i);
+            assert!(frame.time_ms + 1 >= frame.requested_ms);
+            let img = image::open(&frame.path).expect("Extracted frame should be readable");
+            assert_eq!(img.dimensions(), (frame.width, frame.height));
+        }
+        println!("✓ Extracted {} frames to {}", count, output_dir);
+    }
+
+    /// Every tile gets a cue pointing into a sprite sheet that exists
+    #[test]
+    fn test_generate_storyboard() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        let output_dir = temp_output_dir("storyboard");
+
+        let storyboard = crate::api::storyboard::generate_storyboard(
+            video_path,
+            &output_dir,
+            1_000,
+            160,
+            90,
+            4,
+            4,
+            &EncoderOptions::default(),
+        )
+        .expect("Failed to generate storyboard");
+
+        let duration_ms = crate::api::video::get_video_info(video_path)
+            .unwrap()
+            .duration_ms;
+        // One tile per started second; the durations may round differently
+        assert!((storyboard.tile_count as u64).abs_diff(duration_ms.div_ceil(1_000)) <= 1);
+        assert!(!storyboard.sprite_paths.is_empty());
+        for sprite in &storyboard.sprite_paths {
+            let (width, height) = image::image_dimensions(sprite).expect("Unreadable sprite");
+            assert!(width <= 160 * 4 && height <= 90 * 4);
+        }
+        let vtt = std::fs::read_to_string(&storyboard.vtt_path).expect("Missing WebVTT file");
+        assert!(vtt.starts_with("WEBVTT"));
+        assert_eq!(
+            vtt.matches("#xywh=").count() as u32,
+            storyboard.tile_count,
+            "One cue per tile"
+        );
+        println!("✓ Storyboard with {} tiles", storyboard.tile_count);
+    }
+
+    /// Best thumbnail, scene cuts and perceptual hashes on the same clip
+    #[test]
+    fn test_frame_analysis() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        let duration_ms = crate::api::video::get_video_info(video_path)
+            .unwrap()
+            .duration_ms;
+
+        let best = crate::api::poster::generate_best_thumbnail(
+            video_path,
+            &BestThumbnailParams {
+                format: Some(OutputFormat::PNG),
+                candidates: Some(6),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to pick a thumbnail");
+        assert_eq!(&best.data[0..4], &[0x89, 0x50, 0x4E, 0x47]);
+        assert!(best.time_ms <= duration_ms);
+        assert!((0.0..=1.0).contains(&best.score));
+        println!(
+            "✓ Best thumbnail at {}ms, score {:.2}",
+            best.time_ms, best.score
+        );
+
+        let mut last_progress = (0, 0);
+        let cuts = crate::api::scenes::detect_scenes(video_path, 0.3, |processed, total| {
+            last_progress = (processed, total)
+        })
+        .expect("Failed to detect scenes");
+        assert_eq!(last_progress.0, last_progress.1, "Progress should finish");
+        assert!(cuts.windows(2).all(|w| w[0].time_ms < w[1].time_ms));
+        assert!(cuts.iter().all(|cut| cut.score >= 0.3 && cut.score <= 1.0));
+        println!("✓ {} scene cuts", cuts.len());
+
+        let hash = crate::api::perceptual::video_hash(video_path, HashAlgorithm::PHash, Some(4))
+            .expect("Failed to hash video");
+        assert_eq!(hash.hashes.len(), hash.times_ms.len());
+        let comparison =
+            crate::api::perceptual::compare(&hash, &hash, None).expect("Failed to compare");
+        assert_eq!(comparison.distance, 0);
+        assert!(comparison.is_similar);
+    }
+
+    /// Placeholders and palette from a video frame round-trip through their decoders
+    #[test]
+    fn test_placeholders_and_palette() {
+        use crate::api::{palette, placeholder};
+
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+
+        let frame = placeholder::source_image(video_path, Some(1_000))
+            .expect("Failed to read a frame for the placeholder");
+
+        let blurhash = placeholder::blurhash_encode(&frame, 4, 3).expect("Failed to encode");
+        let decoded =
+            placeholder::blurhash_decode(&blurhash, 32, 32, 1.0).expect("Failed to decode");
+        assert_eq!(decoded.rgba.len(), 32 * 32 * 4);
+
+        let thumbhash = placeholder::thumbhash_encode(&frame).expect("Failed to encode");
+        let decoded = placeholder::thumbhash_decode(&thumbhash).expect("Failed to decode");
+        assert!(decoded.width.max(decoded.height) <= 32);
+        let frame_aspect = frame.width() as f32 / frame.height() as f32;
+        let hash_aspect = decoded.width as f32 / decoded.height as f32;
+        assert!((frame_aspect - hash_aspect).abs() < 0.2);
+
+        let palette = palette::extract(&frame, 5).expect("Failed to extract palette");
+        assert!(!palette.colors.is_empty() && palette.colors.len() <= 5);
+        let total: f64 = palette.colors.iter().map(|c| c.population).sum();
+        assert!(total <= 1.0 + 1e-6);
+        println!(
+            "✓ BlurHash {} and a {}-color palette",
+            blurhash,
+            palette.colors.len()
+        );
+    }
+
+    /// A short GIF export stays within its byte budget
+    #[test]
+    fn test_export_animation() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        let output_dir = temp_output_dir("animation");
+
+        let output = crate::api::animation::export_animation(
+            video_path,
+            &format!("{}/clip.gif", output_dir),
+            0,
+            1_000,
+            10,
+            240,
+            AnimationFormat::GIF,
+            &AnimationOptions {
+                max_bytes: Some(2 * 1024 * 1024),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to export animation");
+
+        let data = std::fs::read(&output).expect("Missing animation");
+        assert!(data.starts_with(b"GIF89a"));
+        assert!(data.len() as u64 <= 2 * 1024 * 1024);
+        let (width, _) = image::image_dimensions(&output).unwrap();
+        assert!(width <= 240);
+        println!("✓ Exported {} ({} bytes)", output, data.len());
+    }
+
+    /// Reverse a second of video, then join it with itself
+    #[test]
+    fn test_reverse_and_concat() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        let output_dir = temp_output_dir("edit");
+
+        let reversed = crate::api::reverse::reverse_video(
+            video_path,
+            &format!("{}/reversed.mp4", output_dir),
+            &ReverseParams {
+                end_ms: Some(1_000),
+                preset: Some("ultrafast".to_string()),
+                ..Default::default()
+            },
+            |_, _| {},
+        )
+        .expect("Failed to reverse video");
+        let reversed_info = crate::api::video::get_video_info(&reversed.output_file_path).unwrap();
+        assert!(reversed_info.duration_ms.abs_diff(1_000) < 200);
+        println!("✓ Reversed: {}ms", reversed_info.duration_ms);
+
+        let joined = crate::api::concat::concat_videos(
+            &[
+                reversed.output_file_path.clone(),
+                reversed.output_file_path.clone(),
+            ],
+            &format!("{}/joined.mp4", output_dir),
+            &ConcatParams::default(),
+            |_, _| {},
+        )
+        .expect("Failed to join videos");
+        let joined_info = crate::api::video::get_video_info(&joined.output_file_path).unwrap();
+        assert!(
+            joined_info
+                .duration_ms
+                .abs_diff(2 * reversed_info.duration_ms)
+                < 200
+        );
+        assert_eq!(
+            (joined_info.width, joined_info.height),
+            (reversed_info.width, reversed_info.height)
+        );
+        println!("✓ Joined: {}ms", joined_info.duration_ms);
+    }
+
+    /// Pull the soundtrack out, measure it, and lay it back over the video as music
+    #[test]
+    fn test_audio_round_trip() {
+        let Some(video_path) = sample_video() else {
+            return;
+        };
+        if !has_audio(video_path) {
+            eprintln!("Skipping test: {} has no audio", video_path);
+            return;
+        }
+        let output_dir = temp_output_dir("audio");
+
+        let audio_path = crate::api::extract::extract_audio(
+            video_path,
+            &format!("{}/track.wav", output_dir),
+            AudioFormat::WAV,
+            &AudioExtractOptions {
+                end_ms: Some(2_000),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to extract audio");
+        let audio_probe = crate::api::probe::probe(&audio_path).unwrap();
+        assert!(audio_probe.duration_ms.unwrap().abs_diff(2_000) < 100);
+
+        let loudness = crate::api::loudness::analyze(&audio_path).expect("Failed to measure");
+        assert!(loudness.integrated_lufs < 0.0);
+        assert!(loudness.loudness_range_lu >= 0.0);
+        println!("✓ Extracted audio at {:.1} LUFS", loudness.integrated_lufs);
+
+        let mut last_progress = (0, 0);
+        let stats = crate::api::music::add_music(
+            video_path,
+            &audio_path,
+            &format!("{}/with_music.mp4", output_dir),
+            &AudioMixParams {
+                loop_music: true,
+                fade_out_ms: Some(500),
+                ..Default::default()
+            },
+            |processed, total| last_progress = (processed, total),
+        )
+        .expect("Failed to add music");
+        assert_eq!(last_progress.0, last_progress.1, "Progress should finish");
+        let info = crate::api::video::get_video_info(video_path).unwrap();
+        let output_info = crate::api::video::get_video_info(&stats.output_file_path).unwrap();
+        assert!(output_info.duration_ms.abs_diff(info.duration_ms) < 200);
+        assert!(has_audio(&stats.output_file_path));
+        println!("✓ Added music: {}", stats.output_file_path);
+    }
+
+    /// Image metadata and hashing on the sample HEIC
+    #[test]
+    fn test_image_info_and_hash() {
+        let heic_path = "../native/image.HEIC";
+
+        if !Path::new(heic_path).exists() {
+            eprintln!("Skipping test: {} not found", heic_path);
+            return;
+        }
+
+        let info = crate::api::metadata::image_info(heic_path).expect("Failed to read image info");
+        let img = decode_image_with_ffmpeg(heic_path).expect("Failed to decode HEIC");
+        println!(
+            "✓ {} {}x{}, orientation {}",
+            info.format, info.width, info.height, info.orientation
+        );
+        assert_eq!(info.format, "HEIC");
+        assert!(info.image_count >= 1);
+        assert_eq!((info.width, info.height), img.dimensions());
+
+        let a = crate::api::perceptual::image_hash(heic_path, HashAlgorithm::DHash).unwrap();
+        let b = crate::api::perceptual::image_hash(heic_path, HashAlgorithm::DHash).unwrap();
+        assert_eq!(a.hashes.len(), 1);
+        assert!(a.times_ms.is_empty());
+        assert_eq!(
+            crate::api::perceptual::compare(&a, &b, None)
+                .unwrap()
+                .distance,
+            0
+        );
+    }
 }
@@ -0,0 +1,623 @@
+use anyhow::{Context, Result};
+use image::{ImageDecoder, ImageReader};
+use tracing::debug;
+
+use crate::api::media::{GpsLocation, ImageInfo};
+
+/// Prefix of an EXIF APP1 segment, present in some containers before the TIFF header
+const EXIF_PREFIX: &[u8] = b"Exif\0\0";
+
+/// ISO-BMFF brands libheif reads: HEIC/HEIF (HEVC) and AVIF (AV1)
+const HEIF_BRANDS: [&[u8; 4]; 8] = [
+    b"heic", b"heix", b"hevc", b"hevx", b"heim", b"heis", b"mif1", b"msf1",
+];
+const AVIF_BRANDS: [&[u8; 4]; 2] = [b"avif", b"avis"];
+
+// TIFF tags read from IFD0 and its Exif and GPS sub-IFDs
+const TAG_MAKE: u16 = 0x010F;
+const TAG_MODEL: u16 = 0x0110;
+const TAG_ORIENTATION: u16 = 0x0112;
+const TAG_DATE_TIME: u16 = 0x0132;
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_GPS_IFD: u16 = 0x8825;
+const TAG_DATE_TIME_ORIGINAL: u16 = 0x9003;
+const TAG_GPS_LATITUDE_REF: u16 = 1;
+const TAG_GPS_LATITUDE: u16 = 2;
+const TAG_GPS_LONGITUDE_REF: u16 = 3;
+const TAG_GPS_LONGITUDE: u16 = 4;
+const TAG_GPS_ALTITUDE_REF: u16 = 5;
+const TAG_GPS_ALTITUDE: u16 = 6;
+
+/// The EXIF fields `ImageInfo` reports
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Exif {
+    orientation: Option<u16>,
+    make: Option<String>,
+    model: Option<String>,
+    date_time_original: Option<String>,
+    date_time: Option<String>,
+    gps: Option<GpsLocation>,
+}
+
+/// One 12-byte IFD entry
+struct Entry {
+    kind: u16,
+    count: u32,
+    value_offset: usize, // where the value is: inline in the entry, or at its offset field
+}
+
+/// Minimal TIFF reader, enough for the few tags above
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let little_endian = match data.get(..4)? {
+            [b'I', b'I', 42, 0] => true,
+            [b'M', b'M', 0, 42] => false,
+            _ => return None,
+        };
+        Some(Self {
+            data,
+            little_endian,
+        })
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    /// Entries of the IFD at `offset`, by tag
+    fn ifd(&self, offset: usize) -> Vec<(u16, Entry)> {
+        let count = self.u16(offset).unwrap_or(0) as usize;
+        (0..count)
+            .filter_map(|i| {
+                let entry = offset + 2 + i * 12;
+                let kind = self.u16(entry + 2)?;
+                let count = self.u32(entry + 4)?;
+                let size = match kind {
+                    1 | 2 | 6 | 7 => 1,
+                    3 | 8 => 2,
+                    4 | 9 | 11 => 4,
+                    5 | 10 | 12 => 8,
+                    _ => return None,
+                } * count as usize;
+                let value_offset = if size <= 4 {
+                    entry + 8
+                } else {
+                    self.u32(entry + 8)? as usize
+                };
+                Some((
+                    self.u16(entry)?,
+                    Entry {
+                        kind,
+                        count,
+                        value_offset,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    fn unsigned(&self, entry: &Entry) -> Option<u32> {
+        match entry.kind {
+            1 | 7 => self.data.get(entry.value_offset).map(|&byte| byte as u32),
+            3 => self.u16(entry.value_offset).map(u32::from),
+            4 => self.u32(entry.value_offset),
+            _ => None,
+        }
+    }
+
+    /// ASCII value without the NUL terminator and padding; `None` when blank
+    fn text(&self, entry: &Entry) -> Option<String> {
+        if entry.kind != 2 {
+            return None;
+        }
+        let bytes = self
+            .data
+            .get(entry.value_offset..entry.value_offset + entry.count as usize)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        let text = String::from_utf8_lossy(&bytes[..end]).trim().to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    fn rationals(&self, entry: &Entry) -> Option<Vec<f64>> {
+        if entry.kind != 5 {
+            return None;
+        }
+        (0..entry.count as usize)
+            .map(|i| {
+                let offset = entry.value_offset + i * 8;
+                let (numerator, denominator) = (self.u32(offset)?, self.u32(offset + 4)?);
+                (denominator != 0).then(|| numerator as f64 / denominator as f64)
+            })
+            .collect()
+    }
+}
+
+/// Degrees, minutes and seconds as signed degrees; `negative` is "S" or "W"
+fn gps_coordinate(
+    tiff: &Tiff,
+    value: &Entry,
+    reference: Option<&Entry>,
+    negative: &str,
+) -> Option<f64> {
+    let parts = tiff.rationals(value)?;
+    let degrees = parts.first()?
+        + parts.get(1).unwrap_or(&0.0) / 60.0
+        + parts.get(2).unwrap_or(&0.0) / 3600.0;
+    let sign = match reference.and_then(|entry| tiff.text(entry)) {
+        Some(reference) if reference.eq_ignore_ascii_case(negative) => -1.0,
+        _ => 1.0,
+    };
+    Some(sign * degrees)
+}
+
+fn gps_location(tiff: &Tiff, offset: usize) -> Option<GpsLocation> {
+    let ifd = tiff.ifd(offset);
+    let find = |tag: u16| ifd.iter().find(|(t, _)| *t == tag).map(|(_, entry)| entry);
+
+    let latitude = gps_coordinate(
+        tiff,
+        find(TAG_GPS_LATITUDE)?,
+        find(TAG_GPS_LATITUDE_REF),
+        "S",
+    )?;
+    let longitude = gps_coordinate(
+        tiff,
+        find(TAG_GPS_LONGITUDE)?,
+        find(TAG_GPS_LONGITUDE_REF),
+        "W",
+    )?;
+    let altitude = find(TAG_GPS_ALTITUDE)
+        .and_then(|entry| tiff.rationals(entry)?.first().copied())
+        .map(|altitude| {
+            let below_sea_level =
+                find(TAG_GPS_ALTITUDE_REF).and_then(|entry| tiff.unsigned(entry)) == Some(1);
+            if below_sea_level {
+                -altitude
+            } else {
+                altitude
+            }
+        });
+    Some(GpsLocation {
+        latitude,
+        longitude,
+        altitude,
+    })
+}
+
+/// Reads the reported fields from an EXIF block (a TIFF header and IFDs, optionally
+/// preceded by "Exif\0\0"). Unreadable parts are skipped rather than failing the whole block.
+fn parse_exif(data: &[u8]) -> Option<Exif> {
+    let tiff = Tiff::new(data.strip_prefix(EXIF_PREFIX).unwrap_or(data))?;
+    let mut exif = Exif::default();
+    let mut sub_ifds = Vec::new();
+
+    for (tag, entry) in tiff.ifd(tiff.u32(4)? as usize) {
+        match tag {
+            TAG_MAKE => exif.make = tiff.text(&entry),
+            TAG_MODEL => exif.model = tiff.text(&entry),
+            TAG_ORIENTATION => exif.orientation = tiff.unsigned(&entry).map(|value| value as u16),
+            TAG_DATE_TIME => exif.date_time = tiff.text(&entry),
+            TAG_EXIF_IFD | TAG_GPS_IFD => {
+                sub_ifds.extend(tiff.unsigned(&entry).map(|offset| (tag, offset as usize)))
+            }
+            _ => {}
+        }
+    }
+    for (tag, offset) in sub_ifds {
+        if tag == TAG_GPS_IFD {
+            exif.gps = gps_location(&tiff, offset);
+        } else if let Some((_, entry)) = tiff
+            .ifd(offset)
+            .into_iter()
+            .find(|(tag, _)| *tag == TAG_DATE_TIME_ORIGINAL)
+        {
+            exif.date_time_original = tiff.text(&entry);
+        }
+    }
+    Some(exif)
+}
+
+/// Profile description ('desc' tag) of an ICC profile: v2 `textDescriptionType` or
+/// v4 `multiLocalizedUnicodeType`, preferring the English record
+fn icc_profile_name(icc: &[u8]) -> Option<String> {
+    // Counts and offsets come from the file, so every range is checked before it is read
+    fn bytes_at(data: &[u8], start: usize, len: usize) -> Option<&[u8]> {
+        data.get(start..start.checked_add(len)?)
+    }
+    fn u32_at(data: &[u8], offset: usize) -> Option<usize> {
+        Some(u32::from_be_bytes(bytes_at(data, offset, 4)?.try_into().ok()?) as usize)
+    }
+
+    // The tag table can't hold more 12-byte entries than fit after the header
+    let tag_count = u32_at(icc, 128)?.min(icc.len().saturating_sub(132) / 12);
+    let entry = (0..tag_count)
+        .map_while(|i| i.checked_mul(12)?.checked_add(132))
+        .find(|&entry| bytes_at(icc, entry, 4) == Some(&b"desc"[..]))?;
+    let (offset, size) = (u32_at(icc, entry + 4)?, u32_at(icc, entry + 8)?);
+    let tag = bytes_at(icc, offset, size)?;
+
+    let name = match tag.get(..4)? {
+        b"desc" => {
+            let length = u32_at(tag, 8)?;
+            let ascii = bytes_at(tag, 12, length)?;
+            let end = ascii.iter().position(|&b| b == 0).unwrap_or(ascii.len());
+            String::from_utf8_lossy(&ascii[..end]).into_owned()
+        }
+        b"mluc" => {
+            let record_size = u32_at(tag, 12)?;
+            let records = u32_at(tag, 8)?.min(tag.len().saturating_sub(16) / record_size.max(1));
+            let record = (0..records)
+                .map_while(|i| i.checked_mul(record_size)?.checked_add(16))
+                .find(|&record| bytes_at(tag, record, 2) == Some(&b"en"[..]))
+                .unwrap_or(16);
+            let length = u32_at(tag, record.checked_add(4)?)?;
+            let start = u32_at(tag, record.checked_add(8)?)?;
+            let units: Vec<u16> = bytes_at(tag, start, length)?
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => return None,
+    };
+    let name = name.trim_end_matches('\0').trim().to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+/// "HEIC" or "AVIF" when the file starts with an ISO-BMFF `ftyp` box libheif can read
+fn heif_format(header: &[u8]) -> Option<&'static str> {
+    if header.get(4..8)? != b"ftyp" {
+        return None;
+    }
+    let box_size = u32::from_be_bytes(header.get(..4)?.try_into().ok()?) as usize;
+    // Major brand, then compatible brands after the minor version
+    let brands = std::iter::once(header.get(8..12)?)
+        .chain(header.get(16..box_size.min(header.len()))?.chunks_exact(4));
+    let mut format = None;
+    for brand in brands {
+        if AVIF_BRANDS.iter().any(|avif| brand == *avif) {
+            return Some("AVIF");
+        }
+        if HEIF_BRANDS.iter().any(|heif| brand == *heif) {
+            format = Some("HEIC");
+        }
+    }
+    format
+}
+
+/// Orientations 5-8 turn the image a quarter, so the displayed size is swapped
+fn displayed_size(width: u32, height: u32, orientation: u32) -> (u32, u32) {
+    if (5..=8).contains(&orientation) {
+        (height, width)
+    } else {
+        (width, height)
+    }
+}
+
+fn with_exif(mut info: ImageInfo, exif: Option<Exif>) -> ImageInfo {
+    let Some(exif) = exif else {
+        return info;
+    };
+    info.orientation = exif.orientation.map_or(1, u32::from).clamp(1, 8);
+    info.camera_make = exif.make;
+    info.camera_model = exif.model;
+    info.capture_date = exif.date_time_original.or(exif.date_time);
+    info.gps = exif.gps;
+    info
+}
+
+/// HEIC/AVIF through libheif: box headers and metadata items only, no image is decoded
+fn heif_info(path: &str, format: &str) -> Result<ImageInfo> {
+    use libheif_rs::{AuxiliaryImagesFilter, HeifContext, ItemId};
+
+    let context = HeifContext::read_from_file(path)
+        .with_context(|| format!("Failed to parse {} file: {}", format, path))?;
+    let handle = context.primary_image_handle().with_context(|| {
+        format!(
+            "Failed to get primary image handle from {}: {}",
+            format, path
+        )
+    })?;
+
+    // The payload of an Exif item starts with the offset of the TIFF header after it
+    let mut exif_ids: Vec<ItemId> =
+        vec![0; handle.number_of_metadata_blocks(b"Exif").max(0) as usize];
+    let exif_count = handle.metadata_block_ids(&mut exif_ids, b"Exif");
+    let exif = exif_ids[..exif_count].first().and_then(|&id| {
+        let block = handle.metadata(id).ok()?;
+        let offset = u32::from_be_bytes(block.get(..4)?.try_into().ok()?) as usize;
+        parse_exif(block.get(4 + offset..)?)
+    });
+
+    // libheif reports the size with the container's rotation and mirroring applied, which
+    // viewers follow instead of the EXIF orientation
+    let info = ImageInfo {
+        width: handle.width(),
+        height: handle.height(),
+        format: format.to_string(),
+        bit_depth: handle.luma_bits_per_pixel() as u32,
+        has_alpha: handle.has_alpha_channel(),
+        orientation: 1,
+        camera_make: None,
+        camera_model: None,
+        capture_date: None,
+        gps: None,
+        icc_profile_name: handle
+            .color_profile_raw()
+            .and_then(|profile| icc_profile_name(&profile.data)),
+        image_count: context.number_of_top_level_images() as u32,
+        auxiliary_image_count: handle.number_of_auxiliary_images(AuxiliaryImagesFilter::empty())
+            as u32,
+        has_depth: handle.has_depth_image(),
+    };
+    Ok(with_exif(info, exif))
+}
+
+/// Everything else the image crate reads: only the headers (and for JPEG the metadata
+/// segments) are parsed
+fn decoder_info(path: &str) -> Result<ImageInfo> {
+    let reader = ImageReader::open(path)
+        .with_context(|| format!("Failed to open image: {}", path))?
+        .with_guessed_format()?;
+    let format = reader
+        .format()
+        .ok_or_else(|| anyhow::anyhow!("Unrecognised image format: {}", path))?;
+    let mut decoder = reader
+        .into_decoder()
+        .with_context(|| format!("Failed to read image header: {}", path))?;
+
+    let (width, height) = decoder.dimensions();
+    let color = decoder.original_color_type();
+    let icc_profile = decoder.icc_profile().unwrap_or_else(|e| {
+        debug!("get_image_info - unreadable ICC profile in {}: {}", path, e);
+        None
+    });
+    let exif = decoder.exif_metadata().unwrap_or_else(|e| {
+        debug!("get_image_info - unreadable EXIF in {}: {}", path, e);
+        None
+    });
+
+    let mut info = with_exif(
+        ImageInfo {
+            width,
+            height,
+            format: format!("{:?}", format).to_uppercase(),
+            bit_depth: (color.bits_per_pixel() / color.channel_count().max(1) as u16) as u32,
+            has_alpha: decoder.color_type().has_alpha(),
+            orientation: 1,
+            camera_make: None,
+            camera_model: None,
+            capture_date: None,
+            gps: None,
+            icc_profile_name: icc_profile.as_deref().and_then(icc_profile_name),
+            image_count: 1,
+            auxiliary_image_count: 0,
+            has_depth: false,
+        },
+        exif.as_deref().and_then(parse_exif),
+    );
+    (info.width, info.height) = displayed_size(width, height, info.orientation);
+    Ok(info)
+}
+
+/// Dimensions, format and metadata of an image without decoding its pixels
+pub fn image_info(path: &str) -> Result<ImageInfo> {
+    let mut header = [0u8; 64];
+    let read = {
+        use std::io::Read;
+        let mut file =
+            std::fs::File::open(path).with_context(|| format!("Failed to open image: {}", path))?;
+        file.read(&mut header)?
+    };
+    match heif_format(&header[..read]) {
+        Some(format) => heif_info(path, format),
+        None => decoder_info(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A little-endian TIFF value: type, count and bytes
+    type Value = (u16, u32, Vec<u8>);
+
+    fn ascii(text: &str) -> Value {
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.push(0);
+        (2, bytes.len() as u32, bytes)
+    }
+
+    fn rationals(values: &[(u32, u32)]) -> Value {
+        let bytes = values
+            .iter()
+            .flat_map(|(n, d)| n.to_le_bytes().into_iter().chain(d.to_le_bytes()))
+            .collect();
+        (5, values.len() as u32, bytes)
+    }
+
+    /// Little-endian TIFF with IFD0, an Exif IFD and a GPS IFD, in that order, and
+    /// out-of-line values after them
+    fn tiff(ifd0: Vec<(u16, Value)>, exif: Vec<(u16, Value)>, gps: Vec<(u16, Value)>) -> Vec<u8> {
+        let ifd_size = |entries: usize| 2 + entries * 12 + 4;
+        let exif_offset = 8 + ifd_size(ifd0.len() + 2);
+        let gps_offset = exif_offset + ifd_size(exif.len());
+        let mut data_offset = gps_offset + ifd_size(gps.len());
+
+        let mut ifd0 = ifd0;
+        ifd0.push((
+            TAG_EXIF_IFD,
+            (4, 1, (exif_offset as u32).to_le_bytes().to_vec()),
+        ));
+        ifd0.push((
+            TAG_GPS_IFD,
+            (4, 1, (gps_offset as u32).to_le_bytes().to_vec()),
+        ));
+
+        let mut out = b"II\x2A\x00\x08\x00\x00\x00".to_vec();
+        let mut data = Vec::new();
+        for ifd in [ifd0, exif, gps] {
+            out.extend((ifd.len() as u16).to_le_bytes());
+            for (tag, (kind, count, bytes)) in ifd {
+                out.extend(tag.to_le_bytes());
+                out.extend(kind.to_le_bytes());
+                out.extend(count.to_le_bytes());
+                if bytes.len() <= 4 {
+                    let mut inline = bytes.clone();
+                    inline.resize(4, 0);
+                    out.extend(inline);
+                } else {
+                    out.extend((data_offset as u32).to_le_bytes());
+                    data_offset += bytes.len();
+                    data.extend(bytes);
+                }
+            }
+            out.extend(0u32.to_le_bytes());
+        }
+        out.extend(data);
+        out
+    }
+
+    #[test]
+    fn test_parse_exif() {
+        let data = tiff(
+            vec![
+                (TAG_MAKE, ascii("Apple")),
+                (TAG_MODEL, ascii("iPhone 15 Pro")),
+                (TAG_ORIENTATION, (3, 1, 6u16.to_le_bytes().to_vec())),
+                (TAG_DATE_TIME, ascii("2024:06:02 10:00:00")),
+            ],
+            vec![(TAG_DATE_TIME_ORIGINAL, ascii("2024:06:01 18:30:05"))],
+            vec![
+                (TAG_GPS_LATITUDE_REF, ascii("N")),
+                (
+                    TAG_GPS_LATITUDE,
+                    rationals(&[(48, 1), (51, 1), (2406, 100)]),
+                ),
+                (TAG_GPS_LONGITUDE_REF, ascii("W")),
+                (
+                    TAG_GPS_LONGITUDE,
+                    rationals(&[(2, 1), (17, 1), (4000, 100)]),
+                ),
+                (TAG_GPS_ALTITUDE_REF, (1, 1, vec![1])),
+                (TAG_GPS_ALTITUDE, rationals(&[(125, 10)])),
+            ],
+        );
+
+        let mut app1 = EXIF_PREFIX.to_vec();
+        app1.extend(&data);
+        let exif = parse_exif(&app1).unwrap();
+        assert_eq!(exif.make.as_deref(), Some("Apple"));
+        assert_eq!(exif.model.as_deref(), Some("iPhone 15 Pro"));
+        assert_eq!(exif.orientation, Some(6));
+        assert_eq!(
+            exif.date_time_original.as_deref(),
+            Some("2024:06:01 18:30:05")
+        );
+
+        let gps = exif.gps.unwrap();
+        assert!((gps.latitude - (48.0 + 51.0 / 60.0 + 24.06 / 3600.0)).abs() < 1e-9);
+        assert!((gps.longitude + (2.0 + 17.0 / 60.0 + 40.0 / 3600.0)).abs() < 1e-9);
+        assert_eq!(gps.altitude, Some(-12.5));
+
+        assert!(parse_exif(b"not exif").is_none());
+        assert!(parse_exif(&data[..6]).is_none());
+    }
+
+    #[test]
+    fn test_icc_profile_name() {
+        /// A profile header and tag table with one 'desc' tag
+        fn profile(tag: Vec<u8>) -> Vec<u8> {
+            let mut icc = vec![0u8; 128];
+            icc.extend(1u32.to_be_bytes());
+            icc.extend(b"desc");
+            icc.extend(144u32.to_be_bytes());
+            icc.extend((tag.len() as u32).to_be_bytes());
+            icc.extend(tag);
+            icc
+        }
+
+        let mut v2 = b"desc\0\0\0\0".to_vec();
+        v2.extend(18u32.to_be_bytes());
+        v2.extend(b"sRGB IEC61966-2.1\0");
+        assert_eq!(
+            icc_profile_name(&profile(v2)).as_deref(),
+            Some("sRGB IEC61966-2.1")
+        );
+
+        // Two records, English second
+        let text = |s: &str| {
+            s.encode_utf16()
+                .flat_map(u16::to_be_bytes)
+                .collect::<Vec<u8>>()
+        };
+        let (de, en) = (text("Anzeige P3"), text("Display P3"));
+        let mut v4 = b"mluc\0\0\0\0".to_vec();
+        v4.extend(2u32.to_be_bytes());
+        v4.extend(12u32.to_be_bytes());
+        for (lang, name, start) in [(b"deDE", &de, 40), (b"enUS", &en, 40 + de.len())] {
+            v4.extend(lang);
+            v4.extend((name.len() as u32).to_be_bytes());
+            v4.extend((start as u32).to_be_bytes());
+        }
+        v4.extend(&de);
+        v4.extend(&en);
+        assert_eq!(
+            icc_profile_name(&profile(v4)).as_deref(),
+            Some("Display P3")
+        );
+
+        assert!(icc_profile_name(&[0; 64]).is_none());
+
+        // Untrusted counts and offsets: a huge tag count on a tiny table, and a
+        // tag whose offset + size overflows
+        let mut huge_count = profile(b"desc".to_vec());
+        huge_count[128..132].copy_from_slice(&u32::MAX.to_be_bytes());
+        huge_count[132..136].copy_from_slice(b"none");
+        assert!(icc_profile_name(&huge_count).is_none());
+        let mut bad_offset = profile(b"desc".to_vec());
+        bad_offset[136..140].copy_from_slice(&u32::MAX.to_be_bytes());
+        bad_offset[140..144].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(icc_profile_name(&bad_offset).is_none());
+    }
+
+    #[test]
+    fn test_header_only_info() {
+        let mut ftyp = 24u32.to_be_bytes().to_vec();
+        ftyp.extend(b"ftypmif1\0\0\0\0mif1heic");
+        assert_eq!(heif_format(&ftyp), Some("HEIC"));
+        ftyp[20..24].copy_from_slice(b"avif");
+        assert_eq!(heif_format(&ftyp), Some("AVIF"));
+
+        let path =
+            std::env::temp_dir().join(format!("media_image_info_{}.png", std::process::id()));
+        image::RgbaImage::new(40, 30).save(&path).unwrap();
+        let info = image_info(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!((info.width, info.height), (40, 30));
+        assert_eq!(info.format, "PNG");
+        assert_eq!(info.bit_depth, 8);
+        assert!(info.has_alpha);
+        assert_eq!(info.orientation, 1);
+        assert_eq!(displayed_size(40, 30, 6), (30, 40));
+    }
+}
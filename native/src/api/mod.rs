@@ -2,6 +2,31 @@ pub mod media;
 pub mod video;
 pub mod logger;
 pub mod ffmpeg_process;
+pub mod layout;
+pub mod overlay;
+pub mod subtitles;
+pub mod audio;
+pub mod pipeline;
+pub mod concat;
+pub mod speed;
+pub mod reverse;
+pub mod extract;
+pub mod music;
+pub mod loudness;
+pub mod animation;
+pub mod filters;
+pub mod frames;
+pub mod poster;
+pub mod sampler;
+pub mod storyboard;
+pub mod scenes;
+pub mod resize;
+pub mod encode;
+pub mod placeholder;
+pub mod perceptual;
+pub mod palette;
+pub mod probe;
+pub mod metadata;
 
 #[cfg(test)]
 mod media_test;
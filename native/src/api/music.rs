@@ -0,0 +1,745 @@
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use tracing::{debug, info};
+
+use crate::api::audio::{AudioResampler, AudioWriter, StereoBuffer, DEFAULT_SAMPLE_RATE};
+use crate::api::layout::rotate_yuv420p;
+use crate::api::media::{AudioMixParams, AudioTrackMode, DuckingParams};
+use crate::api::pipeline::{
+    rescale, ConstantRateEncoder, Progress, DEFAULT_BITRATE_KBPS, DEFAULT_FPS, MAX_FPS,
+    MICROSECONDS,
+};
+use crate::api::video::{self, CompressionStats};
+
+const DEFAULT_DUCK_THRESHOLD_DB: f32 = -30.0;
+const DEFAULT_DUCK_REDUCTION_DB: f32 = 12.0;
+const DEFAULT_DUCK_ATTACK_MS: u32 = 50;
+const DEFAULT_DUCK_RELEASE_MS: u32 = 400;
+
+/// Samples mixed per step
+const MIX_CHUNK: usize = 1024;
+
+fn db_to_gain(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+fn sample_rate() -> ffmpeg::Rational {
+    ffmpeg::Rational(1, DEFAULT_SAMPLE_RATE as i32)
+}
+
+/// Music gain for the fades at sample `position` of `total`
+fn fade_gain(position: u64, total: u64, fade_in: u64, fade_out: u64) -> f32 {
+    let mut gain = 1.0f32;
+    if fade_in > 0 && position < fade_in {
+        gain = gain.min(position as f32 / fade_in as f32);
+    }
+    let remaining = total.saturating_sub(position);
+    if fade_out > 0 && remaining < fade_out {
+        gain = gain.min(remaining as f32 / fade_out as f32);
+    }
+    gain
+}
+
+/// Follows the level of the original track and lowers the music while it is above the threshold
+struct Ducker {
+    threshold: f32, // linear
+    reduced: f32,   // linear music gain while ducked
+    attack: f32,    // per-sample smoothing coefficients
+    release: f32,
+    envelope: f32,
+    gain: f32,
+}
+
+impl Ducker {
+    fn new(params: &DuckingParams, rate: u32) -> Self {
+        let coefficient = |ms: u32| {
+            let samples = ms.max(1) as f32 * rate as f32 / 1000.0;
+            (-1.0 / samples).exp()
+        };
+        Ducker {
+            threshold: db_to_gain(params.threshold_db.unwrap_or(DEFAULT_DUCK_THRESHOLD_DB)),
+            reduced: db_to_gain(
+                -params
+                    .reduction_db
+                    .unwrap_or(DEFAULT_DUCK_REDUCTION_DB)
+                    .abs(),
+            ),
+            attack: coefficient(params.attack_ms.unwrap_or(DEFAULT_DUCK_ATTACK_MS)),
+            release: coefficient(params.release_ms.unwrap_or(DEFAULT_DUCK_RELEASE_MS)),
+            envelope: 0.0,
+            gain: 1.0,
+        }
+    }
+
+    /// Music gain for the sample played alongside an original sample of level `level`
+    fn next(&mut self, level: f32) -> f32 {
+        // Peak envelope: rises at once, decays at release speed so short pauses stay ducked
+        self.envelope = if level > self.envelope {
+            level
+        } else {
+            level + self.release * (self.envelope - level)
+        };
+        let target = if self.envelope > self.threshold {
+            self.reduced
+        } else {
+            1.0
+        };
+        let coefficient = if target < self.gain {
+            self.attack
+        } else {
+            self.release
+        };
+        self.gain = target + coefficient * (self.gain - target);
+        self.gain
+    }
+}
+
+/// Gains, fades and ducking applied while mixing, tracked by output sample position
+struct MixLevels {
+    music_gain: f32,
+    original_gain: f32,
+    ducker: Option<Ducker>,
+    fade_in: u64,
+    fade_out: u64,
+    total: u64,
+    position: u64,
+}
+
+impl MixLevels {
+    fn new(params: &AudioMixParams, total: u64) -> Self {
+        let samples = |ms: Option<u64>| ms.unwrap_or(0) * DEFAULT_SAMPLE_RATE as u64 / 1000;
+        MixLevels {
+            music_gain: db_to_gain(params.music_gain_db.unwrap_or(0.0)),
+            original_gain: db_to_gain(params.original_gain_db.unwrap_or(0.0)),
+            ducker: params
+                .ducking
+                .as_ref()
+                .map(|ducking| Ducker::new(ducking, DEFAULT_SAMPLE_RATE)),
+            fade_in: samples(params.fade_in_ms),
+            fade_out: samples(params.fade_out_ms),
+            total,
+            position: 0,
+        }
+    }
+
+    /// Scale `music` in place and add `original` on top of it
+    fn apply(&mut self, music: [&mut [f32]; 2], original: Option<[&[f32]; 2]>) {
+        let [left, right] = music;
+        for i in 0..left.len().min(right.len()) {
+            let mut gain =
+                self.music_gain * fade_gain(self.position, self.total, self.fade_in, self.fade_out);
+            if let Some([original_left, original_right]) = original {
+                let l = original_left[i] * self.original_gain;
+                let r = original_right[i] * self.original_gain;
+                if let Some(ref mut ducker) = self.ducker {
+                    gain *= ducker.next(l.abs().max(r.abs()));
+                }
+                left[i] = (left[i] * gain + l).clamp(-1.0, 1.0);
+                right[i] = (right[i] * gain + r).clamp(-1.0, 1.0);
+            } else {
+                left[i] = (left[i] * gain).clamp(-1.0, 1.0);
+                right[i] = (right[i] * gain).clamp(-1.0, 1.0);
+            }
+            self.position += 1;
+        }
+    }
+}
+
+/// Decodes the music file at the output rate from `start_sample`, rewinding when looped
+struct MusicSource {
+    ictx: ffmpeg::format::context::Input,
+    stream_index: usize,
+    time_base: ffmpeg::Rational,
+    stream_start: i64,
+    start_sample: i64,
+    looping: bool,
+    resampler: AudioResampler,
+    buffer: StereoBuffer,
+    next_sample: Option<i64>, // file position of the next decoded sample
+    produced_this_pass: bool,
+    ended: bool,
+}
+
+impl MusicSource {
+    fn open(path: &str, start_ms: u64, looping: bool) -> Result<Self> {
+        let ictx = video::open_input(path)?;
+        let (stream_index, time_base, stream_start, parameters) = {
+            let stream = ictx
+                .streams()
+                .best(ffmpeg::media::Type::Audio)
+                .ok_or_else(|| anyhow::anyhow!("No audio stream found in {}", path))?;
+            let start = match stream.start_time() {
+                ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+                start => start,
+            };
+            (
+                stream.index(),
+                stream.time_base(),
+                start,
+                stream.parameters(),
+            )
+        };
+        let mut source = MusicSource {
+            ictx,
+            stream_index,
+            time_base,
+            stream_start,
+            start_sample: (start_ms * DEFAULT_SAMPLE_RATE as u64 / 1000) as i64,
+            looping,
+            resampler: AudioResampler::new(parameters, DEFAULT_SAMPLE_RATE)?,
+            buffer: StereoBuffer::default(),
+            next_sample: None,
+            produced_this_pass: false,
+            ended: false,
+        };
+        if source.start_sample > 0 {
+            source.rewind()?;
+        }
+        Ok(source)
+    }
+
+    fn rewind(&mut self) -> Result<()> {
+        let target = rescale(self.stream_start, self.time_base, MICROSECONDS)
+            + rescale(self.start_sample, sample_rate(), MICROSECONDS);
+        if self.ictx.seek(target, ..target).is_err() {
+            self.ictx.seek(target, ..).context("Seek failed")?;
+        }
+        let parameters = self
+            .ictx
+            .stream(self.stream_index)
+            .ok_or_else(|| anyhow::anyhow!("Music stream not found"))?
+            .parameters();
+        self.resampler = AudioResampler::new(parameters, DEFAULT_SAMPLE_RATE)?;
+        self.next_sample = None;
+        self.produced_this_pass = false;
+        Ok(())
+    }
+
+    /// The next `samples` of music; silence once a non-looping track has ended
+    fn read(&mut self, samples: usize) -> Result<(Vec<f32>, Vec<f32>)> {
+        while self.buffer.len() < samples && !self.ended {
+            self.fill()?;
+        }
+        if self.buffer.len() < samples {
+            self.buffer.push_silence(samples - self.buffer.len());
+        }
+        Ok(self.buffer.drain_front(samples))
+    }
+
+    fn fill(&mut self) -> Result<()> {
+        let index = self.stream_index;
+        let packet = self
+            .ictx
+            .packets()
+            .find(|(stream, _)| stream.index() == index)
+            .map(|(_, packet)| packet);
+        let before = self.buffer.len();
+        match packet {
+            Some(packet) => {
+                if self.next_sample.is_none() {
+                    self.next_sample = Some(
+                        packet
+                            .pts()
+                            .map(|pts| {
+                                rescale(pts - self.stream_start, self.time_base, sample_rate())
+                            })
+                            .unwrap_or(self.start_sample),
+                    );
+                }
+                self.resampler.send_packet(&packet, &mut self.buffer)?;
+                self.skip_lead_in(before);
+            }
+            None => {
+                self.resampler.flush(&mut self.buffer)?;
+                self.skip_lead_in(before);
+                // A pass that produced nothing would loop forever
+                if self.looping && self.produced_this_pass {
+                    debug!("add_music - looping music");
+                    self.rewind()?;
+                } else {
+                    self.ended = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop newly decoded samples (from `before` on) that lie before `start_sample`
+    fn skip_lead_in(&mut self, before: usize) {
+        let added = self.buffer.len() - before;
+        let next = self.next_sample.unwrap_or(self.start_sample);
+        let skip = (self.start_sample - next).clamp(0, added as i64) as usize;
+        if skip > 0 {
+            self.buffer.left.drain(before..before + skip);
+            self.buffer.right.drain(before..before + skip);
+        }
+        self.next_sample = Some(next + added as i64);
+        self.produced_this_pass |= added > skip;
+    }
+}
+
+/// Fallback for videos whose codec the output container can't hold: decodes the stream and
+/// re-encodes it as upright H.264 at the source frame rate, like the concat re-encode.
+struct VideoReencoder {
+    decoder: ffmpeg::codec::decoder::Video,
+    scaler: Option<ffmpeg::software::scaling::Context>,
+    encoder: ConstantRateEncoder,
+    time_base: ffmpeg::Rational,
+    start: i64,
+    rotation: i32,
+    size: (u32, u32), // stored orientation, even
+    frame_duration_us: i64,
+    end_us: i64,
+    last: Option<ffmpeg::util::frame::video::Video>,
+}
+
+impl VideoReencoder {
+    /// Opens the decoder for `stream_index` and adds the H.264 stream to `octx`
+    fn new(
+        ictx: &ffmpeg::format::context::Input,
+        stream_index: usize,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<Self> {
+        let stream = ictx
+            .stream(stream_index)
+            .ok_or_else(|| anyhow::anyhow!("Input video stream not found"))?;
+        let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+            .decoder()
+            .video()?;
+        let (_, _, rotation) = video::get_display_dimensions_with_format(
+            ictx,
+            &stream,
+            decoder.width(),
+            decoder.height(),
+        );
+        let size = (
+            (decoder.width() & !1).max(2),
+            (decoder.height() & !1).max(2),
+        );
+        let (width, height) = match rotation {
+            90 | 270 => (size.1, size.0),
+            _ => size,
+        };
+        let rate = stream.avg_frame_rate();
+        let fps = if rate.numerator() > 0 && rate.denominator() > 0 {
+            (f64::from(rate).round() as u32).clamp(1, MAX_FPS)
+        } else {
+            DEFAULT_FPS
+        };
+        let bitrate_kbps = match decoder.bit_rate() / 1000 {
+            0 => DEFAULT_BITRATE_KBPS,
+            kbps => kbps as u32,
+        };
+
+        let global_header = octx
+            .format()
+            .flags()
+            .contains(ffmpeg::format::flag::Flags::GLOBAL_HEADER);
+        let codec = video::find_h264_encoder()?;
+        let encoder = video::open_h264_encoder(
+            codec,
+            width,
+            height,
+            fps,
+            bitrate_kbps,
+            None,
+            None,
+            global_header,
+        )?;
+        let video_out = {
+            let mut ost = octx.add_stream(codec)?;
+            ost.set_parameters(&encoder);
+            ost.index()
+        };
+
+        Ok(VideoReencoder {
+            decoder,
+            scaler: None,
+            encoder: ConstantRateEncoder::new(encoder, video_out, fps),
+            time_base: stream.time_base(),
+            start: match stream.start_time() {
+                ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+                start => start,
+            },
+            rotation,
+            size,
+            frame_duration_us: 1_000_000 / fps as i64,
+            end_us: 0,
+            last: None,
+        })
+    }
+
+    fn send_packet(
+        &mut self,
+        packet: &ffmpeg::Packet,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        self.decoder
+            .send_packet(packet)
+            .context("Failed to decode video packet")?;
+        self.receive_frames(octx)
+    }
+
+    fn receive_frames(&mut self, octx: &mut ffmpeg::format::context::Output) -> Result<()> {
+        let mut decoded = ffmpeg::util::frame::video::Video::empty();
+        while self.decoder.receive_frame(&mut decoded).is_ok() {
+            let pts = decoded.timestamp().or(decoded.pts()).unwrap_or(self.start);
+            let time_us = rescale(pts - self.start, self.time_base, MICROSECONDS);
+            if time_us < 0 {
+                continue;
+            }
+            if self.scaler.is_none() {
+                self.scaler = Some(
+                    ffmpeg::software::scaling::Context::get(
+                        decoded.format(),
+                        decoded.width(),
+                        decoded.height(),
+                        ffmpeg::format::Pixel::YUV420P,
+                        self.size.0,
+                        self.size.1,
+                        ffmpeg::software::scaling::Flags::BILINEAR,
+                    )
+                    .context("Failed to create video scaler")?,
+                );
+            }
+            let mut scaled = ffmpeg::util::frame::video::Video::empty();
+            if let Some(ref mut scaler) = self.scaler {
+                scaler
+                    .run(&decoded, &mut scaled)
+                    .context("Failed to scale video frame")?;
+            }
+            let mut frame = if self.rotation != 0 {
+                rotate_yuv420p(&scaled, self.rotation)
+            } else {
+                scaled
+            };
+            self.encoder.push(&mut frame, time_us, octx)?;
+            self.end_us = self.end_us.max(time_us + self.frame_duration_us);
+            self.last = Some(frame);
+        }
+        Ok(())
+    }
+
+    /// Drains the decoder, holds the last frame for its duration and flushes the encoder.
+    /// Returns the encoded video size.
+    fn finish(&mut self, octx: &mut ffmpeg::format::context::Output) -> Result<u64> {
+        self.decoder
+            .send_eof()
+            .context("Failed to flush video decoder")?;
+        self.receive_frames(octx)?;
+        if let Some(ref mut last) = self.last {
+            self.encoder.fill_until(last, self.end_us, octx)?;
+        }
+        self.encoder.finish(octx)?;
+        Ok(self.encoder.encoded_size_bytes())
+    }
+}
+
+/// Write `video_path` with the audio of `music_path` replacing or mixed under its own track.
+/// The video is copied when the output container can hold its codec, and re-encoded as
+/// H.264 otherwise. `on_progress` receives (processed_ms, total_ms).
+pub fn add_music(
+    video_path: &str,
+    music_path: &str,
+    output_path: &str,
+    params: &AudioMixParams,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<CompressionStats> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    if let Some(parent) = Path::new(output_path).parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create output directory: {}", parent.display())
+            })?;
+        }
+    }
+    let started = Instant::now();
+    let mode = params.mode.unwrap_or(AudioTrackMode::Replace);
+
+    let mut ictx = video::open_input(video_path)?;
+    let (video_index, video_time_base, video_start, video_parameters, duration_us, original) = {
+        let stream = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", video_path))?;
+        let start = match stream.start_time() {
+            ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+            start => start,
+        };
+        let duration_us = if stream.duration() > 0 {
+            rescale(stream.duration(), stream.time_base(), MICROSECONDS)
+        } else {
+            ictx.duration().max(0)
+        };
+        let original = match mode {
+            AudioTrackMode::Mix => ictx
+                .streams()
+                .best(ffmpeg::media::Type::Audio)
+                .map(|audio| (audio.index(), audio.time_base(), audio.parameters())),
+            AudioTrackMode::Replace => None,
+        };
+        (
+            stream.index(),
+            stream.time_base(),
+            start,
+            stream.parameters(),
+            duration_us,
+            original,
+        )
+    };
+    if duration_us <= 0 {
+        return Err(anyhow::anyhow!(
+            "Could not determine the duration of {}",
+            video_path
+        ));
+    }
+    let video_start_us = rescale(video_start, video_time_base, MICROSECONDS);
+    let total_samples = rescale(duration_us, MICROSECONDS, sample_rate()) as u64;
+    info!(
+        "add_music - {:?}, video {} ms, original audio: {}",
+        mode,
+        duration_us / 1000,
+        original.is_some()
+    );
+
+    let mut octx = ffmpeg::format::output(&output_path)
+        .with_context(|| format!("Failed to create output video file: {}", output_path))?;
+
+    // Copy the video when the container accepts its codec as-is, otherwise re-encode it
+    let video_codec = video_parameters.id();
+    let can_copy = unsafe {
+        ffmpeg::ffi::avformat_query_codec(
+            octx.format().as_ptr(),
+            video_codec.into(),
+            ffmpeg::codec::Compliance::Normal.into(),
+        )
+    } == 1;
+    let mut reencoder = if can_copy {
+        None
+    } else {
+        info!(
+            "add_music - {:?} video cannot be copied into {}; re-encoding as H.264",
+            video_codec, output_path
+        );
+        Some(VideoReencoder::new(&ictx, video_index, &mut octx)?)
+    };
+
+    let video_out = match reencoder {
+        Some(_) => None,
+        None => {
+            let mut ost = octx.add_stream(ffmpeg::encoder::find(video_codec))?;
+            ost.set_parameters(video_parameters);
+            ost.set_time_base(video_time_base);
+            unsafe {
+                // Let the muxer pick the tag for its container (e.g. MOV -> MP4)
+                let codecpar = (*ost.as_mut_ptr()).codecpar;
+                if !codecpar.is_null() {
+                    (*codecpar).codec_tag = 0;
+                }
+            }
+            Some(ost.index())
+        }
+    };
+    let mut writer = AudioWriter::aac(&mut octx, DEFAULT_SAMPLE_RATE)?;
+
+    octx.write_header()
+        .context("Failed to write output header")?;
+    let video_out = video_out.map(|index| (index, octx.stream(index).unwrap().time_base()));
+
+    let mut music = MusicSource::open(
+        music_path,
+        params.music_start_ms.unwrap_or(0),
+        params.loop_music,
+    )?;
+    let mut levels = MixLevels::new(params, total_samples);
+    let (original_index, original_time_base) = match original {
+        Some((index, time_base, _)) => (Some(index), time_base),
+        None => (None, MICROSECONDS),
+    };
+    let mut original_resampler = match original {
+        Some((_, _, parameters)) => Some(AudioResampler::new(parameters, DEFAULT_SAMPLE_RATE)?),
+        None => None,
+    };
+    let mut original_buffer = StereoBuffer::default();
+    let mut original_skip: Option<usize> = None; // original samples before the video start
+
+    let mut mixed = StereoBuffer::default();
+    let mut mix_until = |until: u64,
+                         original_buffer: &mut StereoBuffer,
+                         levels: &mut MixLevels,
+                         mixed: &mut StereoBuffer|
+     -> Result<()> {
+        let until = until.min(total_samples);
+        while levels.position < until {
+            let samples = ((until - levels.position) as usize).min(MIX_CHUNK);
+            let (mut left, mut right) = music.read(samples)?;
+            if original_index.is_some() {
+                if original_buffer.len() < samples {
+                    original_buffer.push_silence(samples - original_buffer.len());
+                }
+                let (original_left, original_right) = original_buffer.drain_front(samples);
+                levels.apply(
+                    [&mut left, &mut right],
+                    Some([&original_left, &original_right]),
+                );
+            } else {
+                levels.apply([&mut left, &mut right], None);
+            }
+            mixed.left.extend_from_slice(&left);
+            mixed.right.extend_from_slice(&right);
+        }
+        Ok(())
+    };
+
+    let mut progress = Progress::new((duration_us / 1000) as u64, &mut on_progress);
+    let mut video_size_bytes = 0u64;
+    for (stream, mut packet) in ictx.packets() {
+        if stream.index() == video_index {
+            let pts_us = packet
+                .pts()
+                .map(|pts| rescale(pts, video_time_base, MICROSECONDS) - video_start_us);
+            if let Some(ref mut reencoder) = reencoder {
+                reencoder.send_packet(&packet, &mut octx)?;
+            } else if let Some((video_out, video_out_time_base)) = video_out {
+                packet.set_pts(packet.pts().map(|pts| pts - video_start));
+                packet.set_dts(packet.dts().map(|dts| dts - video_start));
+                packet.set_stream(video_out);
+                packet.set_position(-1);
+                packet.rescale_ts(video_time_base, video_out_time_base);
+                video_size_bytes += packet.size() as u64;
+                packet
+                    .write_interleaved(&mut octx)
+                    .context("Failed to write video packet")?;
+            }
+
+            if let Some(pts_us) = pts_us.filter(|us| *us >= 0) {
+                progress.report(pts_us as u64 / 1000);
+                // Without an original track the music keeps pace with the video
+                if original_index.is_none() {
+                    let until = rescale(pts_us, MICROSECONDS, sample_rate()) as u64;
+                    mix_until(until, &mut original_buffer, &mut levels, &mut mixed)?;
+                }
+            }
+        } else if Some(stream.index()) == original_index {
+            let Some(ref mut resampler) = original_resampler else {
+                continue;
+            };
+            if original_skip.is_none() {
+                // Align the original track with the video start: pad a late start, cut an early one
+                let first = packet
+                    .pts()
+                    .map(|pts| {
+                        rescale(
+                            rescale(pts, original_time_base, MICROSECONDS) - video_start_us,
+                            MICROSECONDS,
+                            sample_rate(),
+                        )
+                    })
+                    .unwrap_or(0);
+                if first > 0 {
+                    original_buffer.push_silence(first as usize);
+                }
+                original_skip = Some((-first).max(0) as usize);
+            }
+            resampler.send_packet(&packet, &mut original_buffer)?;
+            if let Some(ref mut skip) = original_skip {
+                let dropped = (*skip).min(original_buffer.len());
+                original_buffer.drain_front(dropped);
+                *skip -= dropped;
+            }
+            let until = levels.position + original_buffer.len() as u64;
+            mix_until(until, &mut original_buffer, &mut levels, &mut mixed)?;
+        } else {
+            continue;
+        }
+        writer.write(&mut mixed, &mut octx)?;
+    }
+
+    if let Some(ref mut resampler) = original_resampler {
+        resampler.flush(&mut original_buffer)?;
+    }
+    mix_until(total_samples, &mut original_buffer, &mut levels, &mut mixed)?;
+    writer.finish(&mut mixed, &mut octx)?;
+    if let Some(ref mut reencoder) = reencoder {
+        video_size_bytes = reencoder.finish(&mut octx)?;
+    }
+
+    octx.write_trailer()
+        .context("Failed to write output trailer")?;
+    progress.finish();
+
+    Ok(CompressionStats {
+        processed_duration_ms: (duration_us / 1000) as u64,
+        elapsed_ms: started.elapsed().as_millis(),
+        encoded_size_bytes: video_size_bytes + writer.encoded_size_bytes,
+        output_file_path: output_path.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_gain() {
+        assert_eq!(fade_gain(0, 1000, 100, 100), 0.0);
+        assert_eq!(fade_gain(50, 1000, 100, 100), 0.5);
+        assert_eq!(fade_gain(500, 1000, 100, 100), 1.0);
+        assert_eq!(fade_gain(950, 1000, 100, 100), 0.5);
+        assert_eq!(fade_gain(1000, 1000, 100, 100), 0.0);
+        assert_eq!(fade_gain(0, 1000, 0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_ducking_lowers_music_under_loud_original() {
+        let params = AudioMixParams {
+            mode: Some(AudioTrackMode::Mix),
+            ducking: Some(DuckingParams::default()),
+            ..Default::default()
+        };
+        let rate = DEFAULT_SAMPLE_RATE as usize;
+        let mut levels = MixLevels::new(&params, 2 * rate as u64);
+
+        // One second of silence in the original, then one second of loud speech
+        let original: Vec<f32> = (0..2 * rate)
+            .map(|i| if i < rate { 0.0 } else { 0.5 })
+            .collect();
+        let mut left = vec![0.1f32; 2 * rate];
+        let mut right = left.clone();
+        levels.apply([&mut left, &mut right], Some([&original, &original]));
+
+        // Music alone stays untouched, then settles 12 dB lower (plus the original on top)
+        assert!((left[rate / 2] - 0.1).abs() < 1e-6);
+        let ducked = left[rate + rate / 2] - 0.5;
+        assert!(
+            (ducked - 0.1 * db_to_gain(-12.0)).abs() < 1e-3,
+            "{}",
+            ducked
+        );
+    }
+
+    #[test]
+    fn test_replace_applies_gain_and_clamps() {
+        let params = AudioMixParams {
+            music_gain_db: Some(6.0),
+            ..Default::default()
+        };
+        let mut levels = MixLevels::new(&params, 4);
+        let mut left = vec![0.25, 0.9, -0.9, 0.0];
+        let mut right = left.clone();
+        levels.apply([&mut left, &mut right], None);
+        assert!((left[0] - 0.25 * db_to_gain(6.0)).abs() < 1e-6);
+        assert_eq!(left[1], 1.0);
+        assert_eq!(right[2], -1.0);
+        assert_eq!(levels.position, 4);
+    }
+}
@@ -0,0 +1,395 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use image::{DynamicImage, RgbaImage};
+
+use crate::api::layout::{display_rect_to_stored, even, rgb_to_yuv, Rect};
+use crate::api::media::{SpeedParams, WatermarkParams, WatermarkPosition};
+
+/// Default watermark width relative to the video width
+const DEFAULT_WATERMARK_SCALE: f32 = 0.15;
+
+/// Default distance from the anchored edges, in output pixels
+const DEFAULT_WATERMARK_MARGIN: u32 = 16;
+
+/// RGBA image converted to YUV 4:2:0 with alpha, ready to be blended onto YUV420P frames.
+/// Position and size are in stored orientation and always even.
+pub struct YuvaOverlay {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    luma: Vec<u8>,
+    alpha: Vec<u8>,
+    cb: Vec<u8>,
+    cr: Vec<u8>,
+    alpha_chroma: Vec<u8>,
+}
+
+impl YuvaOverlay {
+    /// Convert an RGBA image placed at (x, y). `opacity` multiplies the image alpha.
+    pub fn from_rgba(img: &RgbaImage, x: u32, y: u32, opacity: f32, full_range: bool) -> Self {
+        let img = pad_to_even(img);
+        let (width, height) = img.dimensions();
+        let opacity = opacity.clamp(0.0, 1.0);
+
+        let mut luma = vec![0u8; (width * height) as usize];
+        let mut alpha = vec![0u8; (width * height) as usize];
+        let mut cb_full = vec![0u8; (width * height) as usize];
+        let mut cr_full = vec![0u8; (width * height) as usize];
+        for (px, py, pixel) in img.enumerate_pixels() {
+            let [r, g, b, a] = pixel.0;
+            let idx = (py * width + px) as usize;
+            let (yv, u, v) = rgb_to_yuv(
+                ((r as u32) << 16) | ((g as u32) << 8) | b as u32,
+                full_range,
+            );
+            luma[idx] = yv;
+            cb_full[idx] = u;
+            cr_full[idx] = v;
+            alpha[idx] = (a as f32 * opacity).round() as u8;
+        }
+
+        // 2x2 subsampling, chroma weighted by alpha so transparent pixels don't bleed colour
+        let (cw, ch) = (width / 2, height / 2);
+        let mut cb = vec![128u8; (cw * ch) as usize];
+        let mut cr = vec![128u8; (cw * ch) as usize];
+        let mut alpha_chroma = vec![0u8; (cw * ch) as usize];
+        for cy in 0..ch {
+            for cx in 0..cw {
+                let mut sum_a = 0u32;
+                let mut sum_u = 0u32;
+                let mut sum_v = 0u32;
+                for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    let idx = ((cy * 2 + dy) * width + cx * 2 + dx) as usize;
+                    let a = alpha[idx] as u32;
+                    sum_a += a;
+                    sum_u += cb_full[idx] as u32 * a;
+                    sum_v += cr_full[idx] as u32 * a;
+                }
+                let cidx = (cy * cw + cx) as usize;
+                if sum_a > 0 {
+                    cb[cidx] = (sum_u / sum_a) as u8;
+                    cr[cidx] = (sum_v / sum_a) as u8;
+                }
+                alpha_chroma[cidx] = (sum_a / 4) as u8;
+            }
+        }
+
+        YuvaOverlay {
+            x: x & !1,
+            y: y & !1,
+            width,
+            height,
+            luma,
+            alpha,
+            cb,
+            cr,
+            alpha_chroma,
+        }
+    }
+
+    /// Alpha-blend onto a YUV420P frame, clipping to the frame bounds
+    pub fn blend(&self, frame: &mut ffmpeg::util::frame::video::Video) {
+        blend_plane(
+            frame,
+            0,
+            (self.x, self.y, self.width, self.height),
+            &self.luma,
+            &self.alpha,
+        );
+        let chroma_rect = (self.x / 2, self.y / 2, self.width / 2, self.height / 2);
+        blend_plane(frame, 1, chroma_rect, &self.cb, &self.alpha_chroma);
+        blend_plane(frame, 2, chroma_rect, &self.cr, &self.alpha_chroma);
+    }
+}
+
+fn blend_plane(
+    frame: &mut ffmpeg::util::frame::video::Video,
+    plane: usize,
+    (x, y, width, height): (u32, u32, u32, u32),
+    src: &[u8],
+    alpha: &[u8],
+) {
+    let stride = frame.stride(plane);
+    let plane_w = frame.plane_width(plane);
+    let plane_h = frame.plane_height(plane);
+    if x >= plane_w || y >= plane_h {
+        return;
+    }
+    let cols = width.min(plane_w - x) as usize;
+    let rows = height.min(plane_h - y) as usize;
+    let data = frame.data_mut(plane);
+    for row in 0..rows {
+        let src_row = row * width as usize;
+        let dst_row = (y as usize + row) * stride + x as usize;
+        for col in 0..cols {
+            let a = alpha[src_row + col] as u32;
+            if a == 0 {
+                continue;
+            }
+            let d = &mut data[dst_row + col];
+            *d = ((src[src_row + col] as u32 * a + *d as u32 * (255 - a) + 127) / 255) as u8;
+        }
+    }
+}
+
+/// Add a transparent row/column so both dimensions are even (needed for 4:2:0 chroma)
+pub(crate) fn pad_to_even(img: &RgbaImage) -> RgbaImage {
+    let (w, h) = img.dimensions();
+    if w % 2 == 0 && h % 2 == 0 {
+        return img.clone();
+    }
+    let mut padded = RgbaImage::new(w + w % 2, h + h % 2);
+    image::imageops::replace(&mut padded, img, 0, 0);
+    padded
+}
+
+/// Turn an upright (display orientation) image into stored orientation, so it shows
+/// upright once the player applies the video's rotation.
+pub fn orient_for_stored(img: RgbaImage, rotation: i32) -> RgbaImage {
+    match rotation {
+        90 => image::imageops::rotate270(&img),
+        180 => image::imageops::rotate180(&img),
+        270 => image::imageops::rotate90(&img),
+        _ => img,
+    }
+}
+
+/// Top-left corner of a `width`x`height` box anchored inside the frame
+pub fn anchor_position(
+    position: WatermarkPosition,
+    margin: u32,
+    frame_w: u32,
+    frame_h: u32,
+    width: u32,
+    height: u32,
+) -> (u32, u32) {
+    let right = frame_w.saturating_sub(width + margin);
+    let bottom = frame_h.saturating_sub(height + margin);
+    let (x, y) = match position {
+        WatermarkPosition::TopLeft => (margin, margin),
+        WatermarkPosition::TopRight => (right, margin),
+        WatermarkPosition::BottomLeft => (margin, bottom),
+        WatermarkPosition::BottomRight => (right, bottom),
+        WatermarkPosition::Center => (
+            frame_w.saturating_sub(width) / 2,
+            frame_h.saturating_sub(height) / 2,
+        ),
+    };
+    (x & !1, y & !1)
+}
+
+/// Load a watermark image. The image crate keeps the alpha channel of PNG/WebP;
+/// anything else goes through the shared image decode pipeline.
+fn load_watermark_image(path: &str) -> Result<DynamicImage> {
+    #[cfg(target_os = "windows")]
+    let normalized_path = path.replace('\\', "/");
+    #[cfg(not(target_os = "windows"))]
+    let normalized_path = path.to_string();
+
+    image::open(&normalized_path)
+        .or_else(|_| crate::api::media::decode_image(path))
+        .with_context(|| format!("Failed to decode watermark image: {}", path))
+}
+
+/// Size of a watermark image, read from its header when the image crate knows the format
+fn watermark_dimensions(path: &str) -> Result<(u32, u32)> {
+    #[cfg(target_os = "windows")]
+    let normalized_path = path.replace('\\', "/");
+    #[cfg(not(target_os = "windows"))]
+    let normalized_path = path.to_string();
+
+    match image::image_dimensions(&normalized_path) {
+        Ok(dimensions) => Ok(dimensions),
+        Err(_) => load_watermark_image(path).map(|img| (img.width(), img.height())),
+    }
+}
+
+/// Watermark prepared for a specific output size and rotation
+pub struct Watermark {
+    overlay: YuvaOverlay,
+    start_ms: Option<u64>,
+    end_ms: Option<u64>,
+}
+
+impl Watermark {
+    /// `display_w`/`display_h` are the output dimensions as shown to the user;
+    /// `rotation` is the rotation the output keeps in its display matrix.
+    pub fn prepare(
+        params: &WatermarkParams,
+        display_w: u32,
+        display_h: u32,
+        rotation: i32,
+        full_range: bool,
+    ) -> Result<Self> {
+        let img = load_watermark_image(&params.image_path)?.to_rgba8();
+        let (img_w, img_h) = img.dimensions();
+        if img_w == 0 || img_h == 0 {
+            return Err(anyhow::anyhow!(
+                "Watermark image is empty: {}",
+                params.image_path
+            ));
+        }
+
+        let (width, height) = watermark_size(params, display_w, img_w, img_h);
+        let scaled = image::imageops::resize(
+            &img,
+            width,
+            height,
+            image::imageops::FilterType::Triangle,
+        );
+        let (x, y) = anchor_position(
+            params.position.unwrap_or(WatermarkPosition::BottomRight),
+            params.margin.unwrap_or(DEFAULT_WATERMARK_MARGIN),
+            display_w,
+            display_h,
+            width,
+            height,
+        );
+        let stored = display_rect_to_stored(
+            Rect {
+                x,
+                y,
+                width,
+                height,
+            },
+            rotation,
+            display_w,
+            display_h,
+        );
+
+        Ok(Watermark {
+            overlay: YuvaOverlay::from_rgba(
+                &orient_for_stored(scaled, rotation),
+                stored.x,
+                stored.y,
+                params.opacity.unwrap_or(1.0),
+                full_range,
+            ),
+            start_ms: params.start_ms,
+            end_ms: params.end_ms,
+        })
+    }
+
+    /// Whether the watermark is visible at `time_ms` (source timeline)
+    pub fn is_active(&self, time_ms: u64) -> bool {
+        self.start_ms.is_none_or(|start| time_ms >= start)
+            && self.end_ms.is_none_or(|end| time_ms <= end)
+    }
+
+    pub fn blend(&self, frame: &mut ffmpeg::util::frame::video::Video) {
+        self.overlay.blend(frame);
+    }
+}
+
+/// Watermark size in output pixels: `scale` of the video width, keeping the image aspect
+fn watermark_size(params: &WatermarkParams, display_w: u32, img_w: u32, img_h: u32) -> (u32, u32) {
+    let scale = params
+        .scale
+        .filter(|s| *s > 0.0)
+        .unwrap_or(DEFAULT_WATERMARK_SCALE)
+        .min(1.0);
+    let width = even((display_w as f32 * scale).round() as u32);
+    let height = even((width as f64 * img_h as f64 / img_w as f64).round() as u32);
+    (width, height)
+}
+
+/// Build the `-filter_complex` fragment overlaying input `[1:v]` on `[base]`, producing `[vout]`.
+/// `output_w` is the display width of the base video. `[base]` is already retimed by `speed`,
+/// so the time window, given on the source timeline like `Watermark::is_active`, is scaled
+/// onto the output timeline.
+pub fn watermark_filter(
+    params: &WatermarkParams,
+    output_w: u32,
+    speed: Option<&SpeedParams>,
+) -> Result<String> {
+    let (img_w, img_h) = watermark_dimensions(&params.image_path)?;
+    let (width, height) = watermark_size(params, output_w, img_w.max(1), img_h.max(1));
+    let margin = params.margin.unwrap_or(DEFAULT_WATERMARK_MARGIN);
+    let position = match params.position.unwrap_or(WatermarkPosition::BottomRight) {
+        WatermarkPosition::TopLeft => format!("{}:{}", margin, margin),
+        WatermarkPosition::TopRight => format!("W-w-{}:{}", margin, margin),
+        WatermarkPosition::BottomLeft => format!("{}:H-h-{}", margin, margin),
+        WatermarkPosition::BottomRight => format!("W-w-{}:H-h-{}", margin, margin),
+        WatermarkPosition::Center => "(W-w)/2:(H-h)/2".to_string(),
+    };
+    let factor = speed.map_or(1.0, |speed| speed.factor as f64);
+    let output_secs = |ms: u64| ms as f64 / 1000.0 / factor;
+    let enable = match (params.start_ms, params.end_ms) {
+        (Some(start), Some(end)) => format!(
+            ":enable='between(t,{:.3},{:.3})'",
+            output_secs(start),
+            output_secs(end)
+        ),
+        (Some(start), None) => format!(":enable='gte(t,{:.3})'", output_secs(start)),
+        (None, Some(end)) => format!(":enable='lte(t,{:.3})'", output_secs(end)),
+        (None, None) => String::new(),
+    };
+    let opacity = params.opacity.unwrap_or(1.0).clamp(0.0, 1.0);
+    Ok(format!(
+        "[1:v]scale={}:{},format=rgba,colorchannelmixer=aa={:.3}[wm];[base][wm]overlay={}{}[vout]",
+        width, height, opacity, position, enable
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_position() {
+        let pos = |p| anchor_position(p, 16, 1280, 720, 200, 100);
+        assert_eq!(pos(WatermarkPosition::TopLeft), (16, 16));
+        assert_eq!(pos(WatermarkPosition::TopRight), (1064, 16));
+        assert_eq!(pos(WatermarkPosition::BottomLeft), (16, 604));
+        assert_eq!(pos(WatermarkPosition::BottomRight), (1064, 604));
+        assert_eq!(pos(WatermarkPosition::Center), (540, 310));
+    }
+
+    #[test]
+    fn test_orient_for_stored_swaps_dimensions() {
+        let img = RgbaImage::new(40, 10);
+        assert_eq!(orient_for_stored(img.clone(), 90).dimensions(), (10, 40));
+        assert_eq!(orient_for_stored(img.clone(), 180).dimensions(), (40, 10));
+        assert_eq!(orient_for_stored(img, 270).dimensions(), (10, 40));
+    }
+
+    #[test]
+    fn test_overlay_pads_odd_sizes_and_applies_opacity() {
+        let img = RgbaImage::from_pixel(3, 3, image::Rgba([255, 255, 255, 255]));
+        let overlay = YuvaOverlay::from_rgba(&img, 5, 7, 0.5, true);
+        assert_eq!((overlay.x, overlay.y), (4, 6));
+        assert_eq!((overlay.width, overlay.height), (4, 4));
+        assert_eq!(overlay.luma[0], 255);
+        assert_eq!(overlay.alpha[0], 128);
+        // Padding column is transparent
+        assert_eq!(overlay.alpha[3], 0);
+    }
+
+    #[test]
+    fn test_watermark_filter_window_follows_speed() {
+        let path = std::env::temp_dir().join(format!("media_watermark_{}.png", std::process::id()));
+        RgbaImage::new(200, 100).save(&path).unwrap();
+        let params = WatermarkParams {
+            image_path: path.to_string_lossy().to_string(),
+            position: Some(WatermarkPosition::TopLeft),
+            margin: Some(16),
+            scale: Some(0.25),
+            opacity: None,
+            start_ms: Some(2000),
+            end_ms: Some(6000),
+        };
+        let speed = SpeedParams {
+            factor: 2.0,
+            frame_rate: None,
+            mute_audio_above: None,
+        };
+        let normal = watermark_filter(&params, 1280, None).unwrap();
+        let fast = watermark_filter(&params, 1280, Some(&speed)).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert!(normal.contains("scale=320:160"));
+        assert!(normal.contains("between(t,2.000,6.000)"));
+        // Source seconds 2-6 play at output seconds 1-3
+        assert!(fast.contains("between(t,1.000,3.000)"));
+    }
+}
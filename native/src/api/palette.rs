@@ -0,0 +1,276 @@
+use anyhow::Result;
+use image::RgbaImage;
+
+use crate::api::media::{Palette, PaletteColor};
+
+/// Most colours a palette can ask for
+const MAX_COLORS: u32 = 64;
+
+/// Swatches are picked from at least this many colours, as Android's Palette does
+const SWATCH_CANDIDATES: u32 = 16;
+
+/// Bits kept per channel when grouping pixels, so near-identical shades count together
+const QUANTIZE_BITS: u32 = 5;
+
+/// Pixels less opaque than this don't contribute
+const MIN_ALPHA: u8 = 128;
+
+/// Pixels sharing one quantised colour: their count and channel sums
+#[derive(Debug, Clone, Copy, Default)]
+struct Bin {
+    key: [u8; 3],
+    count: u64,
+    sum: [u64; 3],
+}
+
+impl Bin {
+    fn color(bins: &[Bin]) -> ([u8; 3], u64) {
+        let count: u64 = bins.iter().map(|bin| bin.count).sum();
+        let mut sum = [0u64; 3];
+        for bin in bins {
+            for (total, value) in sum.iter_mut().zip(bin.sum) {
+                *total += value;
+            }
+        }
+        (sum.map(|value| (value / count.max(1)) as u8), count)
+    }
+}
+
+fn histogram(image: &RgbaImage) -> Vec<Bin> {
+    let shift = 8 - QUANTIZE_BITS;
+    let mut bins = vec![Bin::default(); 1 << (3 * QUANTIZE_BITS)];
+    for pixel in image.pixels().filter(|pixel| pixel[3] >= MIN_ALPHA) {
+        let key = [pixel[0] >> shift, pixel[1] >> shift, pixel[2] >> shift];
+        let index = ((key[0] as usize) << (2 * QUANTIZE_BITS))
+            | ((key[1] as usize) << QUANTIZE_BITS)
+            | key[2] as usize;
+        let bin = &mut bins[index];
+        bin.key = key;
+        bin.count += 1;
+        for c in 0..3 {
+            bin.sum[c] += pixel[c] as u64;
+        }
+    }
+    bins.retain(|bin| bin.count > 0);
+    bins
+}
+
+/// Median cut: splits the most populated box along its widest channel at the pixel median
+/// until there are `count` boxes or none can be split. Returns each box's mean colour and
+/// pixel count, most common first.
+fn median_cut(bins: &[Bin], count: u32) -> Vec<([u8; 3], u64)> {
+    let mut boxes: Vec<Vec<Bin>> = vec![bins.to_vec()];
+    while boxes.len() < count as usize {
+        let Some(index) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, bins)| bins.len() > 1)
+            .max_by_key(|(_, bins)| bins.iter().map(|bin| bin.count).sum::<u64>())
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+
+        let mut bins = boxes.swap_remove(index);
+        let range = |c: usize| {
+            let (min, max) = bins.iter().fold((u8::MAX, 0), |(min, max), bin| {
+                (min.min(bin.key[c]), max.max(bin.key[c]))
+            });
+            max - min
+        };
+        let channel = (0..3).max_by_key(|&c| range(c)).unwrap_or(0);
+        bins.sort_by_key(|bin| bin.key[channel]);
+
+        // First bin past half the pixels, keeping at least one bin on each side
+        let total: u64 = bins.iter().map(|bin| bin.count).sum();
+        let mut seen = 0;
+        let split = bins
+            .iter()
+            .position(|bin| {
+                seen += bin.count;
+                seen * 2 >= total
+            })
+            .map_or(1, |position| position + 1)
+            .clamp(1, bins.len() - 1);
+        let upper = bins.split_off(split);
+        boxes.push(bins);
+        boxes.push(upper);
+    }
+
+    let mut colors: Vec<([u8; 3], u64)> = boxes.iter().map(|bins| Bin::color(bins)).collect();
+    colors.sort_by_key(|&(_, pixels)| std::cmp::Reverse(pixels));
+    colors
+}
+
+/// Hue-independent saturation and lightness, 0-1
+fn saturation_lightness([r, g, b]: [u8; 3]) -> (f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+    let lightness = (max + min) / 2.0;
+    let saturation = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+    (saturation, lightness)
+}
+
+/// An Android Palette target: acceptable saturation and lightness ranges and the ideal values
+struct Target {
+    saturation: (f64, f64, f64), // min, target, max
+    lightness: (f64, f64, f64),
+}
+
+const LIGHT: (f64, f64, f64) = (0.55, 0.74, 1.0);
+const NORMAL: (f64, f64, f64) = (0.3, 0.5, 0.7);
+const DARK: (f64, f64, f64) = (0.0, 0.26, 0.45);
+const VIBRANT: (f64, f64, f64) = (0.35, 1.0, 1.0);
+const MUTED: (f64, f64, f64) = (0.0, 0.3, 0.4);
+
+impl Target {
+    const fn new(saturation: (f64, f64, f64), lightness: (f64, f64, f64)) -> Self {
+        Self {
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Weighted closeness to the target, with population as a tie-breaker;
+    /// `None` outside the ranges
+    fn score(&self, color: [u8; 3], share: f64, max_share: f64) -> Option<f64> {
+        let (saturation, lightness) = saturation_lightness(color);
+        let within = |value: f64, (min, _, max): (f64, f64, f64)| value >= min && value <= max;
+        if !within(saturation, self.saturation) || !within(lightness, self.lightness) {
+            return None;
+        }
+        Some(
+            0.24 * (1.0 - (saturation - self.saturation.1).abs())
+                + 0.52 * (1.0 - (lightness - self.lightness.1).abs())
+                + 0.24 * share / max_share,
+        )
+    }
+}
+
+fn palette_color(color: [u8; 3], share: f64) -> PaletteColor {
+    PaletteColor {
+        color: (color[0] as u32) << 16 | (color[1] as u32) << 8 | color[2] as u32,
+        population: share,
+    }
+}
+
+/// The `count` dominant colours of `image` with their share of its opaque pixels, the
+/// average colour, and Android-style vibrant and muted swatches
+pub(crate) fn extract(image: &RgbaImage, count: u32) -> Result<Palette> {
+    if count == 0 || count > MAX_COLORS {
+        return Err(anyhow::anyhow!(
+            "Palette size must be between 1 and {}, got {}",
+            MAX_COLORS,
+            count
+        ));
+    }
+    let bins = histogram(image);
+    let (average, total) = Bin::color(&bins);
+    if total == 0 {
+        return Err(anyhow::anyhow!("Image has no opaque pixels"));
+    }
+    let share = |pixels: u64| pixels as f64 / total as f64;
+
+    let colors = median_cut(&bins, count);
+    let candidates = if count >= SWATCH_CANDIDATES {
+        colors.clone()
+    } else {
+        median_cut(&bins, SWATCH_CANDIDATES)
+    };
+    let max_share = candidates.first().map_or(1.0, |(_, pixels)| share(*pixels));
+
+    // Each candidate becomes at most one swatch, vibrant targets first like Android
+    let targets = [
+        Target::new(VIBRANT, NORMAL),
+        Target::new(VIBRANT, LIGHT),
+        Target::new(VIBRANT, DARK),
+        Target::new(MUTED, NORMAL),
+        Target::new(MUTED, LIGHT),
+        Target::new(MUTED, DARK),
+    ];
+    let mut used = vec![false; candidates.len()];
+    let mut swatches = targets.iter().map(|target| {
+        let best = candidates
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !used[*index])
+            .filter_map(|(index, &(color, pixels))| {
+                target
+                    .score(color, share(pixels), max_share)
+                    .map(|score| (index, score))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)?;
+        used[best] = true;
+        let (color, pixels) = candidates[best];
+        Some(palette_color(color, share(pixels)))
+    });
+
+    Ok(Palette {
+        colors: colors
+            .iter()
+            .map(|&(color, pixels)| palette_color(color, share(pixels)))
+            .collect(),
+        average: palette_color(average, 1.0),
+        vibrant: swatches.next().flatten(),
+        light_vibrant: swatches.next().flatten(),
+        dark_vibrant: swatches.next().flatten(),
+        muted: swatches.next().flatten(),
+        light_muted: swatches.next().flatten(),
+        dark_muted: swatches.next().flatten(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Left `split` columns `left`, the rest `right`
+    fn two_tone(split: u32, left: [u8; 4], right: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_fn(100, 10, |x, _| {
+            image::Rgba(if x < split { left } else { right })
+        })
+    }
+
+    #[test]
+    fn test_dominant_colors() {
+        let image = two_tone(75, [200, 30, 30, 255], [20, 40, 200, 255]);
+        let palette = extract(&image, 4).unwrap();
+        // Only two colours exist, so only two come back, most common first
+        assert_eq!(palette.colors.len(), 2);
+        assert_eq!(palette.colors[0].color, 0xC81E1E);
+        assert!((palette.colors[0].population - 0.75).abs() < 1e-9);
+        assert_eq!(palette.colors[1].color, 0x1428C8);
+        assert_eq!(palette.average.color, (155 << 16) | (32 << 8) | 72);
+
+        assert!(extract(&image, 0).is_err());
+        assert!(extract(&image, MAX_COLORS + 1).is_err());
+    }
+
+    #[test]
+    fn test_swatches() {
+        let image = two_tone(50, [220, 40, 40, 255], [128, 120, 120, 255]);
+        let palette = extract(&image, 2).unwrap();
+        assert_eq!(palette.vibrant.unwrap().color, 0xDC2828);
+        assert_eq!(palette.muted.unwrap().color, 0x807878);
+        // Nothing light or dark enough for the other targets
+        assert!(palette.light_vibrant.is_none());
+        assert!(palette.dark_muted.is_none());
+    }
+
+    #[test]
+    fn test_transparent_pixels_are_ignored() {
+        let image = two_tone(50, [0, 0, 0, 0], [10, 200, 10, 255]);
+        let palette = extract(&image, 3).unwrap();
+        assert_eq!(palette.colors.len(), 1);
+        assert_eq!(palette.colors[0].color, 0x0AC80A);
+        assert_eq!(palette.colors[0].population, 1.0);
+
+        let empty = RgbaImage::new(4, 4);
+        assert!(extract(&empty, 3).is_err());
+    }
+}
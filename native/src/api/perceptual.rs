@@ -0,0 +1,282 @@
+use std::f64::consts::PI;
+
+use anyhow::Result;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use tracing::debug;
+
+use crate::api::frames;
+use crate::api::media::{
+    decode_image, FrameSelection, HashAlgorithm, HashComparison, MediaHash, ResizeMode, SeekMode,
+};
+use crate::api::resize::Resize;
+
+/// pHash works on a 32x32 greyscale copy and keeps its 8x8 lowest frequencies
+const PHASH_SIZE: usize = 32;
+const HASH_SIZE: usize = 8;
+
+/// Bits in every hash
+const HASH_BITS: u32 = 64;
+
+/// Frames sampled from a video when the caller doesn't say
+const DEFAULT_VIDEO_FRAMES: u32 = 16;
+
+/// Distance at or below which two hashes are the same picture. Re-encoding and resizing
+/// typically stay under 6; unrelated images sit around 32.
+const DEFAULT_THRESHOLD: u32 = 10;
+
+/// Difference hash: one bit per horizontally adjacent pair in a 9x8 greyscale copy,
+/// set when brightness increases to the right
+fn dhash(image: &DynamicImage) -> u64 {
+    let small = image
+        .resize_exact(HASH_SIZE as u32 + 1, HASH_SIZE as u32, FilterType::Triangle)
+        .to_luma8();
+    let mut hash = 0u64;
+    for y in 0..HASH_SIZE as u32 {
+        for x in 0..HASH_SIZE as u32 {
+            let brighter = small.get_pixel(x + 1, y)[0] > small.get_pixel(x, y)[0];
+            hash = hash << 1 | brighter as u64;
+        }
+    }
+    hash
+}
+
+/// DCT hash: the 8x8 lowest frequencies of a 32x32 greyscale copy, one bit per
+/// coefficient above the median of the non-DC terms
+fn phash(image: &DynamicImage) -> u64 {
+    let small = image
+        .resize_exact(PHASH_SIZE as u32, PHASH_SIZE as u32, FilterType::Triangle)
+        .to_luma8();
+    let pixels: Vec<f64> = small.as_raw().iter().map(|&value| value as f64).collect();
+
+    // Separable DCT-II, computing only the frequencies that are kept
+    let basis: Vec<[f64; PHASH_SIZE]> = (0..HASH_SIZE)
+        .map(|u| {
+            std::array::from_fn(|x| {
+                ((2 * x + 1) as f64 * u as f64 * PI / (2 * PHASH_SIZE) as f64).cos()
+            })
+        })
+        .collect();
+    let rows: Vec<[f64; HASH_SIZE]> = pixels
+        .chunks_exact(PHASH_SIZE)
+        .map(|row| std::array::from_fn(|u| row.iter().zip(&basis[u]).map(|(p, c)| p * c).sum()))
+        .collect();
+    let coefficients: Vec<f64> = (0..HASH_SIZE)
+        .flat_map(|v| {
+            let (basis, rows) = (&basis, &rows);
+            (0..HASH_SIZE).map(move |u| rows.iter().zip(&basis[v]).map(|(row, c)| row[u] * c).sum())
+        })
+        .collect();
+
+    let mut ac = coefficients[1..].to_vec();
+    ac.sort_by(|a, b| a.total_cmp(b));
+    let median = ac[ac.len() / 2];
+    coefficients
+        .iter()
+        .fold(0u64, |hash, &value| hash << 1 | (value > median) as u64)
+}
+
+fn hash_image(image: &DynamicImage, algorithm: HashAlgorithm) -> u64 {
+    match algorithm {
+        HashAlgorithm::DHash => dhash(image),
+        HashAlgorithm::PHash => phash(image),
+    }
+}
+
+/// Hash of a still image, HEIC included
+pub fn image_hash(path: &str, algorithm: HashAlgorithm) -> Result<MediaHash> {
+    let image = decode_image(path)?;
+    Ok(MediaHash {
+        algorithm,
+        hashes: vec![hash_image(&image, algorithm)],
+        times_ms: Vec::new(),
+    })
+}
+
+/// Hashes of `frame_count` frames spread evenly over the video, in one decoding pass.
+/// Frames are spaced by duration, so the same video re-encoded lines up frame for frame.
+pub fn video_hash(
+    path: &str,
+    algorithm: HashAlgorithm,
+    frame_count: Option<u32>,
+) -> Result<MediaHash> {
+    // Both hashes shrink further themselves; this only saves converting full frames
+    let resize = Resize::new(
+        (PHASH_SIZE as u32, PHASH_SIZE as u32),
+        Some(ResizeMode::Exact),
+        None,
+    );
+    let mut hashes = Vec::new();
+    let mut times_ms = Vec::new();
+    frames::sample_frames(
+        path,
+        &FrameSelection::Count(frame_count.unwrap_or(DEFAULT_VIDEO_FRAMES)),
+        resize,
+        SeekMode::Accurate,
+        |_, requested_ms, frame| {
+            match frame {
+                Some((image, time_ms)) => {
+                    hashes.push(hash_image(&DynamicImage::ImageRgb8(image), algorithm));
+                    times_ms.push(time_ms);
+                }
+                None => debug!("video_hash - no frame for {}ms", requested_ms),
+            }
+            Ok(())
+        },
+    )?;
+
+    if hashes.is_empty() {
+        return Err(anyhow::anyhow!("Could not decode any frame from {}", path));
+    }
+    Ok(MediaHash {
+        algorithm,
+        hashes,
+        times_ms,
+    })
+}
+
+/// Hamming distance between two hashes from `image_hash` or `video_hash`. A single hash
+/// (an image) is matched against its closest frame of the other side; two videos are compared
+/// frame by frame at the same relative position and the distances averaged.
+pub fn compare(a: &MediaHash, b: &MediaHash, threshold: Option<u32>) -> Result<HashComparison> {
+    if a.algorithm != b.algorithm {
+        return Err(anyhow::anyhow!(
+            "Cannot compare a {:?} hash with a {:?} hash",
+            a.algorithm,
+            b.algorithm
+        ));
+    }
+    if a.hashes.is_empty() || b.hashes.is_empty() {
+        return Err(anyhow::anyhow!("Cannot compare an empty hash"));
+    }
+
+    let hamming = |x: u64, y: u64| (x ^ y).count_ones();
+    let distance = if a.hashes.len() == 1 || b.hashes.len() == 1 {
+        a.hashes
+            .iter()
+            .flat_map(|&x| b.hashes.iter().map(move |&y| hamming(x, y)))
+            .min()
+            .unwrap_or(HASH_BITS)
+    } else {
+        // Pair each frame of the longer sequence with the one at the same relative position
+        let (long, short) = if a.hashes.len() >= b.hashes.len() {
+            (&a.hashes, &b.hashes)
+        } else {
+            (&b.hashes, &a.hashes)
+        };
+        let total: u32 = long
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let j = (i * (short.len() - 1) + (long.len() - 1) / 2) / (long.len() - 1);
+                hamming(x, short[j])
+            })
+            .sum();
+        (total as f64 / long.len() as f64).round() as u32
+    };
+
+    Ok(HashComparison {
+        distance,
+        similarity: 1.0 - distance as f64 / HASH_BITS as f64,
+        is_similar: distance <= threshold.unwrap_or(DEFAULT_THRESHOLD),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shapes over a gradient, something like a photo for the hashes to work on
+    fn scene(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(image::RgbImage::from_fn(width, height, |x, y| {
+            let (u, v) = (x as f64 / width as f64, y as f64 / height as f64);
+            let circle = (u - 0.3).powi(2) + (v - 0.4).powi(2) < 0.04;
+            let bar = (0.6..0.8).contains(&u) && v > 0.2;
+            let base = (u * 120.0 + v * 80.0) as u8;
+            if circle {
+                image::Rgb([230, 200, 40])
+            } else if bar {
+                image::Rgb([30, 60, 160])
+            } else {
+                image::Rgb([base, base / 2 + 40, 90])
+            }
+        }))
+    }
+
+    fn hash(image: &DynamicImage, algorithm: HashAlgorithm) -> MediaHash {
+        MediaHash {
+            algorithm,
+            hashes: vec![hash_image(image, algorithm)],
+            times_ms: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_dhash_of_gradient() {
+        let gradient = DynamicImage::ImageLuma8(image::GrayImage::from_fn(90, 80, |x, _| {
+            image::Luma([(x * 2) as u8])
+        }));
+        assert_eq!(dhash(&gradient), u64::MAX);
+        let flipped = gradient.fliph();
+        assert_eq!(dhash(&flipped), 0);
+    }
+
+    #[test]
+    fn test_hashes_survive_resize_reencode_and_crop() {
+        let original = scene(640, 480);
+
+        let mut jpeg = Vec::new();
+        original
+            .resize_exact(320, 240, FilterType::Triangle)
+            .write_to(
+                &mut std::io::Cursor::new(&mut jpeg),
+                image::ImageFormat::Jpeg,
+            )
+            .unwrap();
+        let reencoded = image::load_from_memory(&jpeg).unwrap();
+        let cropped = original.crop_imm(8, 6, 624, 468);
+        let other = scene(640, 480).fliph().flipv();
+
+        for algorithm in [HashAlgorithm::DHash, HashAlgorithm::PHash] {
+            let reference = hash(&original, algorithm);
+            for copy in [&reencoded, &cropped] {
+                let result = compare(&reference, &hash(copy, algorithm), None).unwrap();
+                assert!(result.is_similar, "{:?}: {}", algorithm, result.distance);
+            }
+            let result = compare(&reference, &hash(&other, algorithm), None).unwrap();
+            assert!(!result.is_similar, "{:?}: {}", algorithm, result.distance);
+        }
+    }
+
+    #[test]
+    fn test_compare_sequences() {
+        let video = |hashes: Vec<u64>| MediaHash {
+            algorithm: HashAlgorithm::PHash,
+            times_ms: (0..hashes.len() as u64).map(|i| i * 1000).collect(),
+            hashes,
+        };
+        let a = video(vec![0, u64::MAX, 0xFF, 0xFF00]);
+        let b = video(vec![0, u64::MAX, 0xFF, 0xFF01]);
+        let result = compare(&a, &b, None).unwrap();
+        assert_eq!(result.distance, 0); // 1 bit over 4 frames rounds to 0
+        assert!(result.is_similar);
+
+        // Half as many frames: the first two of `a` pair with 0, the last two with 0xFF00
+        let short = video(vec![0, 0xFF00]);
+        assert_eq!(compare(&a, &short, None).unwrap().distance, (64 + 16) / 4);
+
+        // An image matches its closest frame
+        let still = MediaHash {
+            algorithm: HashAlgorithm::PHash,
+            hashes: vec![0xFF],
+            times_ms: Vec::new(),
+        };
+        assert_eq!(compare(&still, &a, None).unwrap().distance, 0);
+
+        let dhash = MediaHash {
+            algorithm: HashAlgorithm::DHash,
+            ..still
+        };
+        assert!(compare(&dhash, &a, None).is_err());
+    }
+}
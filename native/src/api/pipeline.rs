@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+
+/// Output frame rate when neither the params nor the source provide one
+pub(crate) const DEFAULT_FPS: u32 = 30;
+pub(crate) const MAX_FPS: u32 = 60;
+
+/// Output bitrate when neither the params nor the inputs provide one
+pub(crate) const DEFAULT_BITRATE_KBPS: u32 = 4000;
+
+/// Minimum media time between two progress reports
+const PROGRESS_INTERVAL_MS: u64 = 250;
+
+pub(crate) const MICROSECONDS: ffmpeg::Rational = ffmpeg::Rational(1, 1_000_000);
+
+pub(crate) fn rescale(value: i64, from: ffmpeg::Rational, to: ffmpeg::Rational) -> i64 {
+    unsafe { ffmpeg::ffi::av_rescale_q(value, from.into(), to.into()) }
+}
+
+/// Throttled progress reporting in media time
+pub(crate) struct Progress<'a> {
+    total_ms: u64,
+    last_ms: Option<u64>,
+    callback: &'a mut dyn FnMut(u64, u64),
+}
+
+impl<'a> Progress<'a> {
+    pub(crate) fn new(total_ms: u64, callback: &'a mut dyn FnMut(u64, u64)) -> Self {
+        Progress {
+            total_ms,
+            last_ms: None,
+            callback,
+        }
+    }
+
+    pub(crate) fn report(&mut self, processed_ms: u64) {
+        let processed_ms = processed_ms.min(self.total_ms);
+        if self
+            .last_ms
+            .is_some_and(|last| processed_ms < last + PROGRESS_INTERVAL_MS)
+        {
+            return;
+        }
+        self.last_ms = Some(processed_ms);
+        (self.callback)(processed_ms, self.total_ms);
+    }
+
+    pub(crate) fn finish(&mut self) {
+        (self.callback)(self.total_ms, self.total_ms);
+    }
+}
+
+/// Encodes upright YUV420P frames at a constant frame rate, duplicating or dropping
+/// frames so each output slot of 1/fps gets the frame shown at that time.
+pub(crate) struct ConstantRateEncoder {
+    encoder: ffmpeg::codec::encoder::Video,
+    stream_index: usize,
+    fps: u32,
+    next_index: i64,
+    encoded_size_bytes: u64,
+}
+
+impl ConstantRateEncoder {
+    /// `encoder` must use a 1/`fps` time base; its packets go to `stream_index`
+    pub(crate) fn new(
+        encoder: ffmpeg::codec::encoder::Video,
+        stream_index: usize,
+        fps: u32,
+    ) -> Self {
+        ConstantRateEncoder {
+            encoder,
+            stream_index,
+            fps,
+            next_index: 0,
+            encoded_size_bytes: 0,
+        }
+    }
+
+    pub(crate) fn encoded_size_bytes(&self) -> u64 {
+        self.encoded_size_bytes
+    }
+
+    fn slot_for(&self, time_us: i64) -> i64 {
+        (time_us as f64 * self.fps as f64 / 1_000_000.0).round() as i64
+    }
+
+    /// Submit a frame shown from `time_us` on the output timeline
+    pub(crate) fn push(
+        &mut self,
+        frame: &mut ffmpeg::util::frame::video::Video,
+        time_us: i64,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        let slot = self.slot_for(time_us);
+        while self.next_index <= slot {
+            self.encode(frame, octx)?;
+        }
+        Ok(())
+    }
+
+    /// Repeat `frame` until the output reaches `end_us`
+    pub(crate) fn fill_until(
+        &mut self,
+        frame: &mut ffmpeg::util::frame::video::Video,
+        end_us: i64,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        let end_slot = self.slot_for(end_us);
+        while self.next_index < end_slot {
+            self.encode(frame, octx)?;
+        }
+        Ok(())
+    }
+
+    fn encode(
+        &mut self,
+        frame: &mut ffmpeg::util::frame::video::Video,
+        octx: &mut ffmpeg::format::context::Output,
+    ) -> Result<()> {
+        frame.set_pts(Some(self.next_index));
+        self.next_index += 1;
+        self.encoder
+            .send_frame(frame)
+            .context("Encoder send_frame failed")?;
+        self.drain_packets(octx)
+    }
+
+    pub(crate) fn finish(&mut self, octx: &mut ffmpeg::format::context::Output) -> Result<()> {
+        self.encoder.send_eof().context("Encoder send_eof failed")?;
+        self.drain_packets(octx)
+    }
+
+    fn drain_packets(&mut self, octx: &mut ffmpeg::format::context::Output) -> Result<()> {
+        let out_tb = octx
+            .stream(self.stream_index)
+            .ok_or_else(|| anyhow::anyhow!("Video output stream not found"))?
+            .time_base();
+        let mut packet = ffmpeg::Packet::empty();
+        while self.encoder.receive_packet(&mut packet).is_ok() {
+            packet.set_stream(self.stream_index);
+            packet.rescale_ts(self.encoder.time_base(), out_tb);
+            self.encoded_size_bytes += packet.size() as u64;
+            packet
+                .write_interleaved(octx)
+                .context("Failed to write video packet")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rescale_between_time_bases() {
+        let ninety_khz = ffmpeg::Rational(1, 90_000);
+        assert_eq!(rescale(1_000_000, MICROSECONDS, ninety_khz), 90_000);
+        assert_eq!(rescale(90_000, ninety_khz, MICROSECONDS), 1_000_000);
+        assert_eq!(rescale(-45_000, ninety_khz, MICROSECONDS), -500_000);
+        // Rounds to the nearest tick: 1/30 s is 33_333.3 µs
+        assert_eq!(rescale(1, ffmpeg::Rational(1, 30), MICROSECONDS), 33_333);
+        // Long durations don't overflow the intermediate product
+        let ten_hours_us = 10 * 3_600 * 1_000_000i64;
+        assert_eq!(
+            rescale(ten_hours_us, MICROSECONDS, ninety_khz),
+            10 * 3_600 * 90_000
+        );
+    }
+
+    #[test]
+    fn test_progress_is_throttled_and_clamped() {
+        let mut reports = Vec::new();
+        let mut callback = |processed, total| reports.push((processed, total));
+        let mut progress = Progress::new(1_000, &mut callback);
+        progress.report(0);
+        progress.report(100); // within the interval
+        progress.report(300);
+        progress.report(5_000); // past the end
+        progress.finish();
+        assert_eq!(
+            reports,
+            vec![(0, 1_000), (300, 1_000), (1_000, 1_000), (1_000, 1_000)]
+        );
+    }
+}
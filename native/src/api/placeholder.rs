@@ -0,0 +1,550 @@
+use std::f32::consts::PI;
+
+use anyhow::Result;
+use image::{DynamicImage, RgbaImage};
+
+use crate::api::media::{decode_image, PlaceholderImage, SeekMode};
+use crate::api::resize::Resize;
+use crate::api::sampler::FrameSampler;
+use crate::api::video;
+
+/// Sources are shrunk to this box before hashing; ThumbHash requires it and BlurHash
+/// gains nothing from more pixels
+const SOURCE_SIZE: u32 = 100;
+
+/// Longest side of a decoded ThumbHash, as in the reference implementation
+const THUMBHASH_SIZE: f32 = 32.0;
+
+const BASE83: &[u8; 83] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// The image to hash: the frame at `time_ms` for a video, otherwise the decoded image,
+/// upright and scaled to fit `SOURCE_SIZE`
+pub(crate) fn source_image(path: &str, time_ms: Option<u64>) -> Result<RgbaImage> {
+    let resize = Resize::new((SOURCE_SIZE, SOURCE_SIZE), None, None);
+    let Some(time_ms) = time_ms else {
+        return Ok(resize.apply(&decode_image(path)?).to_rgba8());
+    };
+
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let mut sampler = FrameSampler::open(path)?;
+    sampler
+        .frame_at(time_ms, SeekMode::Accurate)?
+        .ok_or_else(|| anyhow::anyhow!("Could not decode any frame from {}", path))?;
+    Ok(DynamicImage::ImageRgb8(sampler.image(&resize)?).to_rgba8())
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let v = value as f32 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0 + 0.5) as u8
+}
+
+fn sign_pow(value: f32, exponent: f32) -> f32 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+fn encode83(value: u32, length: u32, out: &mut String) {
+    for i in 1..=length {
+        let digit = value / 83u32.pow(length - i) % 83;
+        out.push(BASE83[digit as usize] as char);
+    }
+}
+
+fn decode83(digits: &str) -> Result<u32> {
+    digits.bytes().try_fold(0u32, |value, c| {
+        let digit = BASE83
+            .iter()
+            .position(|&b| b == c)
+            .ok_or_else(|| anyhow::anyhow!("Invalid BlurHash character '{}'", c as char))?;
+        Ok(value * 83 + digit as u32)
+    })
+}
+
+/// BlurHash of `image` with `components_x` x `components_y` cosine components (1-9 each)
+pub(crate) fn blurhash_encode(
+    image: &RgbaImage,
+    components_x: u32,
+    components_y: u32,
+) -> Result<String> {
+    if !(1..=9).contains(&components_x) || !(1..=9).contains(&components_y) {
+        return Err(anyhow::anyhow!(
+            "BlurHash components must be between 1 and 9, got {}x{}",
+            components_x,
+            components_y
+        ));
+    }
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return Err(anyhow::anyhow!("Cannot hash an empty image"));
+    }
+
+    let linear: Vec<[f32; 3]> = image
+        .pixels()
+        .map(|p| {
+            [
+                srgb_to_linear(p[0]),
+                srgb_to_linear(p[1]),
+                srgb_to_linear(p[2]),
+            ]
+        })
+        .collect();
+    let mut factors = Vec::with_capacity((components_x * components_y) as usize);
+    for j in 0..components_y {
+        for i in 0..components_x {
+            let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            let mut factor = [0.0f32; 3];
+            for y in 0..height {
+                let basis_y = (PI * j as f32 * y as f32 / height as f32).cos();
+                for x in 0..width {
+                    let basis =
+                        normalisation * (PI * i as f32 * x as f32 / width as f32).cos() * basis_y;
+                    let pixel = linear[(y * width + x) as usize];
+                    for c in 0..3 {
+                        factor[c] += basis * pixel[c];
+                    }
+                }
+            }
+            let scale = 1.0 / (width * height) as f32;
+            factors.push(factor.map(|value| value * scale));
+        }
+    }
+
+    let mut hash = String::with_capacity(4 + 2 * factors.len());
+    encode83((components_x - 1) + (components_y - 1) * 9, 1, &mut hash);
+
+    let ac = &factors[1..];
+    let max_value = if ac.is_empty() {
+        encode83(0, 1, &mut hash);
+        1.0
+    } else {
+        let actual_max = ac
+            .iter()
+            .flat_map(|factor| factor.iter())
+            .fold(0.0f32, |max, value| max.max(value.abs()));
+        let quantised_max = ((actual_max * 166.0 - 0.5).floor() as i32).clamp(0, 82) as u32;
+        encode83(quantised_max, 1, &mut hash);
+        (quantised_max + 1) as f32 / 166.0
+    };
+
+    let [r, g, b] = factors[0].map(linear_to_srgb);
+    encode83(
+        ((r as u32) << 16) | ((g as u32) << 8) | b as u32,
+        4,
+        &mut hash,
+    );
+    for factor in ac {
+        let [r, g, b] = factor.map(|value| {
+            ((sign_pow(value / max_value, 0.5) * 9.0 + 9.5).floor() as i32).clamp(0, 18) as u32
+        });
+        encode83(r * 19 * 19 + g * 19 + b, 2, &mut hash);
+    }
+    Ok(hash)
+}
+
+/// Renders a BlurHash at `width` x `height`. `punch` above 1 exaggerates the contrast.
+pub(crate) fn blurhash_decode(
+    hash: &str,
+    width: u32,
+    height: u32,
+    punch: f32,
+) -> Result<PlaceholderImage> {
+    if width == 0 || height == 0 || width > 1024 || height > 1024 {
+        return Err(anyhow::anyhow!(
+            "Placeholder size must be between 1 and 1024, got {}x{}",
+            width,
+            height
+        ));
+    }
+    if hash.len() < 6 || !hash.is_ascii() {
+        return Err(anyhow::anyhow!("Invalid BlurHash '{}'", hash));
+    }
+    let size_flag = decode83(&hash[0..1])?;
+    let (components_x, components_y) = (size_flag % 9 + 1, size_flag / 9 + 1);
+    if hash.len() != (4 + 2 * components_x * components_y) as usize {
+        return Err(anyhow::anyhow!(
+            "Invalid BlurHash length {} for {}x{} components",
+            hash.len(),
+            components_x,
+            components_y
+        ));
+    }
+
+    let max_value = (decode83(&hash[1..2])? + 1) as f32 / 166.0 * punch;
+    let dc = decode83(&hash[2..6])?;
+    let mut colors = vec![[
+        srgb_to_linear((dc >> 16) as u8),
+        srgb_to_linear((dc >> 8) as u8),
+        srgb_to_linear(dc as u8),
+    ]];
+    for i in 1..(components_x * components_y) as usize {
+        let value = decode83(&hash[4 + i * 2..6 + i * 2])?;
+        let component = |quantised: u32| sign_pow((quantised as f32 - 9.0) / 9.0, 2.0) * max_value;
+        colors.push([
+            component(value / (19 * 19)),
+            component(value / 19 % 19),
+            component(value % 19),
+        ]);
+    }
+
+    let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let mut pixel = [0.0f32; 3];
+            for j in 0..components_y {
+                let basis_y = (PI * y as f32 * j as f32 / height as f32).cos();
+                for i in 0..components_x {
+                    let basis = (PI * x as f32 * i as f32 / width as f32).cos() * basis_y;
+                    let color = colors[(i + j * components_x) as usize];
+                    for c in 0..3 {
+                        pixel[c] += color[c] * basis;
+                    }
+                }
+            }
+            rgba.extend(pixel.map(linear_to_srgb));
+            rgba.push(255);
+        }
+    }
+    Ok(PlaceholderImage {
+        rgba,
+        width,
+        height,
+    })
+}
+
+/// (cx, cy) of the AC coefficients ThumbHash stores for an `nx` x `ny` channel: the
+/// triangle below the diagonal, row by row, without the DC term at (0, 0)
+fn thumbhash_ac_positions(nx: usize, ny: usize) -> Vec<(usize, usize)> {
+    (0..ny)
+        .flat_map(|cy| {
+            (0..)
+                .take_while(move |cx| cx * ny < nx * (ny - cy))
+                .map(move |cx| (cx, cy))
+        })
+        .skip(1)
+        .collect()
+}
+
+/// DCT of one LPQA channel. Returns the DC term, the AC terms normalised to 0-1 and their scale.
+fn thumbhash_channel(
+    channel: &[f32],
+    width: usize,
+    height: usize,
+    nx: usize,
+    ny: usize,
+) -> (f32, Vec<f32>, f32) {
+    let coefficient = |cx: usize, cy: usize| {
+        let fx: Vec<f32> = (0..width)
+            .map(|x| (PI / width as f32 * cx as f32 * (x as f32 + 0.5)).cos())
+            .collect();
+        let mut f = 0.0;
+        for (y, row) in channel.chunks_exact(width).enumerate() {
+            let fy = (PI / height as f32 * cy as f32 * (y as f32 + 0.5)).cos();
+            f += row
+                .iter()
+                .zip(&fx)
+                .map(|(value, fx)| value * fx * fy)
+                .sum::<f32>();
+        }
+        f / (width * height) as f32
+    };
+
+    let dc = coefficient(0, 0);
+    let mut ac: Vec<f32> = thumbhash_ac_positions(nx, ny)
+        .into_iter()
+        .map(|(cx, cy)| coefficient(cx, cy))
+        .collect();
+    let scale = ac.iter().fold(0.0f32, |max, f| max.max(f.abs()));
+    if scale > 0.0 {
+        for f in &mut ac {
+            *f = 0.5 + 0.5 / scale * *f;
+        }
+    }
+    (dc, ac, scale)
+}
+
+/// ThumbHash of an image no larger than 100x100
+pub(crate) fn thumbhash_encode(image: &RgbaImage) -> Result<Vec<u8>> {
+    let (w, h) = image.dimensions();
+    if w == 0 || h == 0 || w > 100 || h > 100 {
+        return Err(anyhow::anyhow!("{}x{} doesn't fit in 100x100", w, h));
+    }
+    let (width, height) = (w as usize, h as usize);
+
+    // Average colour, weighted by alpha
+    let (mut avg_r, mut avg_g, mut avg_b, mut avg_a) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
+    for pixel in image.pixels() {
+        let alpha = pixel[3] as f32 / 255.0;
+        avg_r += alpha / 255.0 * pixel[0] as f32;
+        avg_g += alpha / 255.0 * pixel[1] as f32;
+        avg_b += alpha / 255.0 * pixel[2] as f32;
+        avg_a += alpha;
+    }
+    if avg_a > 0.0 {
+        avg_r /= avg_a;
+        avg_g /= avg_a;
+        avg_b /= avg_a;
+    }
+
+    let has_alpha = avg_a < (width * height) as f32;
+    let l_limit = if has_alpha { 5.0 } else { 7.0 }; // fewer luminance bits with alpha
+    let longest = w.max(h) as f32;
+    let lx = ((l_limit * w as f32 / longest).round() as usize).max(1);
+    let ly = ((l_limit * h as f32 / longest).round() as usize).max(1);
+
+    // Luminance, yellow-blue, red-green and alpha, composited over the average colour
+    let count = width * height;
+    let (mut l, mut p, mut q, mut a) = (
+        Vec::with_capacity(count),
+        Vec::with_capacity(count),
+        Vec::with_capacity(count),
+        Vec::with_capacity(count),
+    );
+    for pixel in image.pixels() {
+        let alpha = pixel[3] as f32 / 255.0;
+        let r = avg_r * (1.0 - alpha) + alpha / 255.0 * pixel[0] as f32;
+        let g = avg_g * (1.0 - alpha) + alpha / 255.0 * pixel[1] as f32;
+        let b = avg_b * (1.0 - alpha) + alpha / 255.0 * pixel[2] as f32;
+        l.push((r + g + b) / 3.0);
+        p.push((r + g) / 2.0 - b);
+        q.push(r - g);
+        a.push(alpha);
+    }
+
+    let (l_dc, l_ac, l_scale) = thumbhash_channel(&l, width, height, lx.max(3), ly.max(3));
+    let (p_dc, p_ac, p_scale) = thumbhash_channel(&p, width, height, 3, 3);
+    let (q_dc, q_ac, q_scale) = thumbhash_channel(&q, width, height, 3, 3);
+    let alpha_channel = has_alpha.then(|| thumbhash_channel(&a, width, height, 5, 5));
+
+    let is_landscape = w > h;
+    let header24 = (63.0 * l_dc).round() as u32
+        | ((31.5 + 31.5 * p_dc).round() as u32) << 6
+        | ((31.5 + 31.5 * q_dc).round() as u32) << 12
+        | ((31.0 * l_scale).round() as u32) << 18
+        | (has_alpha as u32) << 23;
+    let header16 = (if is_landscape { ly } else { lx }) as u32
+        | ((63.0 * p_scale).round() as u32) << 3
+        | ((63.0 * q_scale).round() as u32) << 9
+        | (is_landscape as u32) << 15;
+    let mut hash = vec![
+        header24 as u8,
+        (header24 >> 8) as u8,
+        (header24 >> 16) as u8,
+        header16 as u8,
+        (header16 >> 8) as u8,
+    ];
+    if let Some((a_dc, _, a_scale)) = &alpha_channel {
+        hash.push((15.0 * a_dc).round() as u8 | ((15.0 * a_scale).round() as u8) << 4);
+    }
+
+    // AC terms, two 4-bit values per byte
+    let ac_start = hash.len();
+    let mut channels = vec![l_ac, p_ac, q_ac];
+    if let Some((_, a_ac, _)) = alpha_channel {
+        channels.push(a_ac);
+    }
+    for (index, f) in channels.iter().flatten().enumerate() {
+        if index % 2 == 0 {
+            hash.push(0);
+        }
+        hash[ac_start + index / 2] |= ((15.0 * f).round() as u8) << ((index & 1) << 2);
+    }
+    Ok(hash)
+}
+
+/// Renders a ThumbHash at its own size, at most 32 pixels on the longest side
+pub(crate) fn thumbhash_decode(hash: &[u8]) -> Result<PlaceholderImage> {
+    if hash.len() < 5 {
+        return Err(anyhow::anyhow!(
+            "ThumbHash is too short ({} bytes)",
+            hash.len()
+        ));
+    }
+    let header24 = hash[0] as u32 | (hash[1] as u32) << 8 | (hash[2] as u32) << 16;
+    let header16 = hash[3] as u32 | (hash[4] as u32) << 8;
+    let l_dc = (header24 & 63) as f32 / 63.0;
+    let p_dc = ((header24 >> 6) & 63) as f32 / 31.5 - 1.0;
+    let q_dc = ((header24 >> 12) & 63) as f32 / 31.5 - 1.0;
+    let l_scale = ((header24 >> 18) & 31) as f32 / 31.0;
+    let has_alpha = (header24 >> 23) != 0;
+    let p_scale = ((header16 >> 3) & 63) as f32 / 63.0;
+    let q_scale = ((header16 >> 9) & 63) as f32 / 63.0;
+    let is_landscape = (header16 >> 15) != 0;
+    let l_max = if has_alpha { 5 } else { 7 };
+    let stored = (header16 & 7) as usize;
+    let (lx, ly) = if is_landscape {
+        (l_max, stored)
+    } else {
+        (stored, l_max)
+    };
+    // Aspect ratio from the unclamped counts, then at least 3x3 luminance terms
+    let ratio = lx as f32 / ly.max(1) as f32;
+    let (lx, ly) = (lx.max(3), ly.max(3));
+    if has_alpha && hash.len() < 6 {
+        return Err(anyhow::anyhow!(
+            "ThumbHash is too short ({} bytes)",
+            hash.len()
+        ));
+    }
+    let (a_dc, a_scale) = if has_alpha {
+        ((hash[5] & 15) as f32 / 15.0, (hash[5] >> 4) as f32 / 15.0)
+    } else {
+        (1.0, 0.0)
+    };
+
+    // Saturation is boosted 1.25x to make up for quantisation
+    let ac_start = if has_alpha { 6 } else { 5 };
+    let mut ac_index = 0;
+    let mut decode_channel = |nx: usize, ny: usize, scale: f32| -> Result<Vec<_>> {
+        thumbhash_ac_positions(nx, ny)
+            .into_iter()
+            .map(|position| {
+                let byte = hash
+                    .get(ac_start + ac_index / 2)
+                    .ok_or_else(|| anyhow::anyhow!("ThumbHash is truncated"))?;
+                let nibble = (byte >> ((ac_index & 1) << 2)) & 15;
+                ac_index += 1;
+                Ok((position, (nibble as f32 / 7.5 - 1.0) * scale))
+            })
+            .collect()
+    };
+    let l_ac = decode_channel(lx, ly, l_scale)?;
+    let p_ac = decode_channel(3, 3, p_scale * 1.25)?;
+    let q_ac = decode_channel(3, 3, q_scale * 1.25)?;
+    let a_ac = if has_alpha {
+        decode_channel(5, 5, a_scale)?
+    } else {
+        Vec::new()
+    };
+    let sum = |ac: &[((usize, usize), f32)], fx: &[f32], fy: &[f32]| -> f32 {
+        ac.iter()
+            .map(|&((cx, cy), value)| value * fx[cx] * fy[cy] * 2.0)
+            .sum()
+    };
+
+    let (width, height) = if ratio > 1.0 {
+        (THUMBHASH_SIZE, (THUMBHASH_SIZE / ratio).round())
+    } else {
+        ((THUMBHASH_SIZE * ratio).round(), THUMBHASH_SIZE)
+    };
+    let (width, height) = (width as usize, height as usize);
+    let n = if has_alpha { 5 } else { 3 };
+    let mut fx = vec![0.0f32; lx.max(n)];
+    let mut fy = vec![0.0f32; ly.max(n)];
+    let mut rgba = Vec::with_capacity(width * height * 4);
+    for y in 0..height {
+        for x in 0..width {
+            let (mut l, mut p, mut q, mut a) = (l_dc, p_dc, q_dc, a_dc);
+            for (cx, f) in fx.iter_mut().enumerate() {
+                *f = (PI / width as f32 * (x as f32 + 0.5) * cx as f32).cos();
+            }
+            for (cy, f) in fy.iter_mut().enumerate() {
+                *f = (PI / height as f32 * (y as f32 + 0.5) * cy as f32).cos();
+            }
+
+            l += sum(&l_ac, &fx, &fy);
+            p += sum(&p_ac, &fx, &fy);
+            q += sum(&q_ac, &fx, &fy);
+            a += sum(&a_ac, &fx, &fy);
+
+            let b = l - 2.0 / 3.0 * p;
+            let r = (3.0 * l - b + q) / 2.0;
+            let g = r - q;
+            rgba.extend([r, g, b, a].map(|value| (255.0 * value.clamp(0.0, 1.0)) as u8));
+        }
+    }
+    Ok(PlaceholderImage {
+        rgba,
+        width: width as u32,
+        height: height as u32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([(x * 255 / width) as u8, (y * 255 / height) as u8, 96, 255])
+        })
+    }
+
+    #[test]
+    fn test_blurhash_average_colour() {
+        // A single component only carries the average colour
+        let image = RgbaImage::from_pixel(4, 4, image::Rgba([255, 255, 255, 255]));
+        assert_eq!(blurhash_encode(&image, 1, 1).unwrap(), "00TSUA");
+
+        let image = RgbaImage::from_pixel(16, 16, image::Rgba([200, 100, 50, 255]));
+        let decoded = blurhash_decode(&blurhash_encode(&image, 1, 1).unwrap(), 4, 4, 1.0).unwrap();
+        assert_eq!(&decoded.rgba[..4], &[200, 100, 50, 255]);
+    }
+
+    #[test]
+    fn test_blurhash_round_trip() {
+        let hash = blurhash_encode(&gradient(40, 30), 4, 3).unwrap();
+        assert_eq!(hash.len(), 4 + 2 * 4 * 3);
+
+        let decoded = blurhash_decode(&hash, 32, 24, 1.0).unwrap();
+        assert_eq!(decoded.rgba.len(), 32 * 24 * 4);
+        let pixel = |x: u32, y: u32| decoded.rgba[((y * 32 + x) * 4) as usize..][..4].to_vec();
+        // Red grows to the right and green downwards, like the source
+        assert!(pixel(30, 12)[0] > pixel(1, 12)[0] + 100);
+        assert!(pixel(16, 22)[1] > pixel(16, 1)[1] + 100);
+
+        assert!(blurhash_encode(&gradient(40, 30), 0, 3).is_err());
+        assert!(blurhash_decode(&hash[..hash.len() - 2], 8, 8, 1.0).is_err());
+        assert!(blurhash_decode("00TS!A", 8, 8, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_thumbhash_round_trip() {
+        let hash = thumbhash_encode(&gradient(80, 40)).unwrap();
+        assert!(hash.len() <= 25);
+        let decoded = thumbhash_decode(&hash).unwrap();
+        // The aspect ratio survives, roughly
+        assert_eq!(decoded.width, 32);
+        assert!((12..=20).contains(&decoded.height));
+        assert_eq!(
+            decoded.rgba.len(),
+            (decoded.width * decoded.height * 4) as usize
+        );
+
+        // Left side dark red, right side bright red, top to bottom dark to bright green
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * decoded.width + x) * 4) as usize;
+            &decoded.rgba[i..i + 4]
+        };
+        assert!(pixel(decoded.width - 2, 1)[0] > pixel(1, 1)[0]);
+        assert!(pixel(1, decoded.height - 2)[1] > pixel(1, 1)[1]);
+        assert_eq!(pixel(1, 1)[3], 255);
+
+        let transparent =
+            RgbaImage::from_fn(20, 20, |x, _| image::Rgba([0, 0, 255, (x * 12) as u8]));
+        let decoded = thumbhash_decode(&thumbhash_encode(&transparent).unwrap()).unwrap();
+        assert!(decoded.rgba[3] < decoded.rgba[decoded.rgba.len() - 1]);
+
+        assert!(thumbhash_encode(&gradient(200, 50)).is_err());
+        assert!(thumbhash_decode(&hash[..4]).is_err());
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::Result;
+use image::RgbImage;
+use tracing::debug;
+
+use crate::api::encode::encode_rgb_image;
+use crate::api::media::{
+    BestThumbnail, BestThumbnailParams, OutputFormat, SeekMode, ThumbnailSizeType,
+};
+use crate::api::resize::Resize;
+use crate::api::sampler::FrameSampler;
+use crate::api::video;
+
+/// Candidate frames sampled when the caller doesn't say
+const DEFAULT_CANDIDATES: u32 = 12;
+const MAX_CANDIDATES: u32 = 100;
+
+/// Frames are scored on a small copy; detail finer than this doesn't change the ranking
+const ANALYSIS_WIDTH: u32 = 160;
+
+/// Mean luma (0-1) below which a frame counts as black
+const MIN_BRIGHTNESS: f64 = 0.08;
+
+/// Luma standard deviation (0-1) below which a frame counts as uniform
+const MIN_CONTRAST: f64 = 0.03;
+
+/// Laplacian variance giving half the sharpness score
+const SHARPNESS_HALF: f64 = 300.0;
+
+/// Hasler-Süsstrunk colourfulness counted as fully colourful
+const FULL_COLORFULNESS: f64 = 100.0;
+
+/// Image statistics a poster frame is ranked on
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FrameScore {
+    brightness: f64,   // mean luma, 0-1
+    contrast: f64,     // luma standard deviation, 0-1
+    sharpness: f64,    // variance of the luma Laplacian
+    colorfulness: f64, // Hasler-Süsstrunk metric
+}
+
+impl FrameScore {
+    fn measure(image: &RgbImage) -> Self {
+        let (width, height) = image.dimensions();
+        let pixel_count = (width as usize * height as usize).max(1) as f64;
+        let luma: Vec<f64> = image
+            .pixels()
+            .map(|p| 0.299 * p[0] as f64 + 0.587 * p[1] as f64 + 0.114 * p[2] as f64)
+            .collect();
+
+        let mean = luma.iter().sum::<f64>() / pixel_count;
+        let variance = luma.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / pixel_count;
+
+        // 4-neighbour Laplacian over the interior
+        let mut laplacian = Vec::new();
+        let (w, h) = (width as usize, height as usize);
+        for y in 1..h.saturating_sub(1) {
+            for x in 1..w.saturating_sub(1) {
+                let i = y * w + x;
+                laplacian
+                    .push(luma[i - 1] + luma[i + 1] + luma[i - w] + luma[i + w] - 4.0 * luma[i]);
+            }
+        }
+        let sharpness = if laplacian.is_empty() {
+            0.0
+        } else {
+            let n = laplacian.len() as f64;
+            let mean = laplacian.iter().sum::<f64>() / n;
+            laplacian.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n
+        };
+
+        // Opponent colour channels
+        let (mut rg_sum, mut yb_sum, mut rg_sq, mut yb_sq) = (0.0, 0.0, 0.0, 0.0);
+        for p in image.pixels() {
+            let (r, g, b) = (p[0] as f64, p[1] as f64, p[2] as f64);
+            let rg = r - g;
+            let yb = 0.5 * (r + g) - b;
+            rg_sum += rg;
+            yb_sum += yb;
+            rg_sq += rg * rg;
+            yb_sq += yb * yb;
+        }
+        let (rg_mean, yb_mean) = (rg_sum / pixel_count, yb_sum / pixel_count);
+        let rg_var = (rg_sq / pixel_count - rg_mean * rg_mean).max(0.0);
+        let yb_var = (yb_sq / pixel_count - yb_mean * yb_mean).max(0.0);
+        let colorfulness =
+            (rg_var + yb_var).sqrt() + 0.3 * (rg_mean.powi(2) + yb_mean.powi(2)).sqrt();
+
+        Self {
+            brightness: mean / 255.0,
+            contrast: variance.sqrt() / 255.0,
+            sharpness,
+            colorfulness,
+        }
+    }
+
+    /// Near-black and near-uniform frames (fades, title cards on black) never win
+    fn rejected(&self) -> bool {
+        self.brightness < MIN_BRIGHTNESS || self.contrast < MIN_CONTRAST
+    }
+
+    /// Weighted 0-1 score: well exposed, contrasty, sharp and colourful frames rank highest
+    fn total(&self) -> f64 {
+        let exposure = 1.0 - (self.brightness - 0.5).abs() * 2.0;
+        let contrast = (self.contrast / 0.25).min(1.0);
+        let sharpness = self.sharpness / (self.sharpness + SHARPNESS_HALF);
+        let colorfulness = (self.colorfulness / FULL_COLORFULNESS).min(1.0);
+        0.2 * exposure + 0.25 * contrast + 0.35 * sharpness + 0.2 * colorfulness
+    }
+}
+
+/// Evenly spaced sample times, leaving out the very start and end of the video
+fn candidate_times(duration_ms: u64, count: u32) -> Vec<u64> {
+    let count = count.clamp(1, MAX_CANDIDATES) as u64;
+    let mut times: Vec<u64> = (1..=count).map(|i| duration_ms * i / (count + 1)).collect();
+    times.dedup();
+    times
+}
+
+/// Samples frames across the video and returns the best-scoring one as an encoded image.
+/// Candidates are the keyframes nearest each sample time, which are also the cleanest
+/// frames to show; sample times sharing a keyframe use their exact frame instead.
+/// If every candidate is near-black or near-uniform the best of them is returned with
+/// a score of 0.
+pub fn generate_best_thumbnail(path: &str, params: &BestThumbnailParams) -> Result<BestThumbnail> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let mut sampler = FrameSampler::open(path)?;
+    let (display_width, display_height) = sampler.display_size();
+    let times = candidate_times(
+        sampler.duration_ms(),
+        params.candidates.unwrap_or(DEFAULT_CANDIDATES),
+    );
+    let analysis = Resize::new((ANALYSIS_WIDTH, 0), None, Some(false));
+    let resize = Resize::new(
+        params
+            .size_type
+            .unwrap_or(ThumbnailSizeType::Custom((display_width, display_height)))
+            .dimensions(),
+        params.resize_mode,
+        params.allow_upscale,
+    );
+
+    let mut best: Option<(bool, f64, u64, RgbImage)> = None; // (rejected, score, time, image)
+    let mut seen = Vec::new();
+
+    for time_ms in times {
+        // Keyframes first; when a long GOP maps this time onto a keyframe that was already
+        // scored, the exact frame at the time is decoded instead
+        let mut frame_ms = None;
+        for mode in [SeekMode::Keyframe, SeekMode::Accurate] {
+            if let Ok(Some(ms)) = sampler.frame_at(time_ms, mode) {
+                if !seen.contains(&ms) {
+                    frame_ms = Some(ms);
+                    break;
+                }
+            }
+        }
+        let Some(frame_ms) = frame_ms else {
+            continue;
+        };
+        seen.push(frame_ms);
+
+        let score = FrameScore::measure(&sampler.image(&analysis)?);
+        let (rejected, total) = (score.rejected(), score.total());
+        debug!(
+            "generate_best_thumbnail - candidate at {}ms: {:?} -> {:.3}",
+            frame_ms, score, total
+        );
+
+        // Accepted frames beat rejected ones, then the higher score wins
+        let better = match best {
+            None => true,
+            Some((best_rejected, best_total, _, _)) => {
+                (best_rejected && !rejected) || (best_rejected == rejected && total > best_total)
+            }
+        };
+        if better {
+            best = Some((rejected, total, frame_ms, sampler.image(&resize)?));
+        }
+    }
+
+    let (rejected, score, time_ms, image) =
+        best.ok_or_else(|| anyhow::anyhow!("Could not decode any frame from {}", path))?;
+    let (width, height) = image.dimensions();
+    let data = encode_rgb_image(
+        &image,
+        params.format.unwrap_or(OutputFormat::PNG),
+        &params.encoder_options.unwrap_or_default(),
+    )?;
+
+    Ok(BestThumbnail {
+        data,
+        width,
+        height,
+        time_ms,
+        score: if rejected { 0.0 } else { score },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard(cell: u32, colors: [[u8; 3]; 2]) -> RgbImage {
+        RgbImage::from_fn(64, 64, |x, y| {
+            image::Rgb(colors[((x / cell + y / cell) % 2) as usize])
+        })
+    }
+
+    #[test]
+    fn test_rejects_black_and_uniform_frames() {
+        let black = FrameScore::measure(&RgbImage::from_pixel(32, 32, image::Rgb([4, 4, 4])));
+        assert!(black.rejected());
+        let grey = FrameScore::measure(&RgbImage::from_pixel(32, 32, image::Rgb([128, 128, 128])));
+        assert!(grey.rejected());
+        assert!(grey.sharpness < 1e-9 && grey.colorfulness < 1e-9);
+
+        let pattern = FrameScore::measure(&checkerboard(4, [[40, 40, 40], [200, 200, 200]]));
+        assert!(!pattern.rejected());
+        assert!((pattern.brightness - 120.0 / 255.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sharp_and_colourful_frames_score_higher() {
+        let fine = FrameScore::measure(&checkerboard(2, [[40, 40, 40], [200, 200, 200]]));
+        let coarse = FrameScore::measure(&checkerboard(16, [[40, 40, 40], [200, 200, 200]]));
+        assert!(fine.sharpness > coarse.sharpness);
+        assert!(fine.total() > coarse.total());
+
+        let colourful = FrameScore::measure(&checkerboard(2, [[200, 40, 40], [40, 40, 200]]));
+        assert!(colourful.colorfulness > fine.colorfulness);
+        assert!((0.0..=1.0).contains(&colourful.total()));
+    }
+
+    #[test]
+    fn test_candidate_times() {
+        assert_eq!(candidate_times(10_000, 4), vec![2_000, 4_000, 6_000, 8_000]);
+        assert_eq!(candidate_times(0, 5), vec![0]);
+        assert_eq!(candidate_times(1_000, 0), vec![500]);
+        assert_eq!(
+            candidate_times(100_000, 1_000).len(),
+            MAX_CANDIDATES as usize
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use std::ffi::{c_char, CStr};
+
+use anyhow::Result;
+use ffmpeg_next::{self as ffmpeg};
+use tracing::debug;
+
+use crate::api::media::{
+    AudioStreamInfo, AudioStreamProbe, HdrFormat, MediaChapter, MediaProbe, MediaTag,
+    StreamDisposition, StreamProbe, StreamSideData, StreamType, VideoStreamProbe,
+};
+use crate::api::video;
+
+/// Average and real frame rates further apart than this mark a variable frame rate
+const VFR_TOLERANCE: f64 = 0.01;
+
+/// Video stream properties beyond size and duration, read from the codec parameters
+/// and side data without decoding
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VideoDetails {
+    pub avg_frame_rate: Option<f64>,
+    pub real_frame_rate: Option<f64>,
+    pub is_vfr: bool,
+    pub pixel_format: Option<String>,
+    pub bit_depth: Option<u32>,
+    pub color_primaries: Option<String>,
+    pub color_transfer: Option<String>,
+    pub color_matrix: Option<String>,
+    pub color_range: Option<String>,
+    pub hdr_format: Option<HdrFormat>,
+    pub profile: Option<String>,
+    pub level: Option<i32>,
+    pub frame_count: Option<u64>,
+}
+
+/// A rational as a number, `None` when unset (0/0 or x/0)
+pub(crate) fn rational_value(rational: ffmpeg::Rational) -> Option<f64> {
+    (rational.numerator() > 0 && rational.denominator() > 0).then(|| f64::from(rational))
+}
+
+/// Like ffprobe, a stream whose average rate differs from the rate every timestamp fits
+/// (`r_frame_rate`) has frames of varying duration
+fn is_variable_frame_rate(average: Option<f64>, real: Option<f64>) -> bool {
+    match (average, real) {
+        (Some(average), Some(real)) => (average - real).abs() / real > VFR_TOLERANCE,
+        _ => false,
+    }
+}
+
+/// HDR signalling: Dolby Vision configuration record first, then the PQ/HLG transfer
+fn hdr_format(
+    transfer: ffmpeg::color::TransferCharacteristic,
+    has_dovi_config: bool,
+) -> Option<HdrFormat> {
+    use ffmpeg::color::TransferCharacteristic;
+
+    if has_dovi_config {
+        return Some(HdrFormat::DolbyVision);
+    }
+    match transfer {
+        TransferCharacteristic::SMPTE2084 => Some(HdrFormat::Hdr10),
+        TransferCharacteristic::ARIB_STD_B67 => Some(HdrFormat::Hlg),
+        _ => None,
+    }
+}
+
+/// Codec profile name (e.g. "High", "Main 10"), `None` when the codec doesn't say
+pub(crate) fn profile_name(parameters: &ffmpeg::codec::Parameters) -> Option<String> {
+    unsafe {
+        let raw = &*parameters.as_ptr();
+        c_string(ffmpeg::ffi::avcodec_profile_name(raw.codec_id, raw.profile))
+    }
+}
+
+/// ISO 639 language tag, `None` when missing or undetermined
+pub(crate) fn language(stream: &ffmpeg::format::stream::Stream) -> Option<String> {
+    stream
+        .metadata()
+        .get("language")
+        .filter(|language| !language.is_empty() && *language != "und")
+        .map(str::to_string)
+}
+
+/// Details of a video stream, read from its parameters, side data and a codec `context`
+/// filled from them (which holds the pixel format and colour properties as typed values).
+/// The context doesn't need to be opened, so this works without a decoder for the codec.
+pub(crate) fn video_details(
+    stream: &ffmpeg::format::stream::Stream,
+    context: &ffmpeg::codec::context::Context,
+) -> VideoDetails {
+    use ffmpeg::color;
+
+    let parameters = stream.parameters();
+    let raw = unsafe { &*parameters.as_ptr() };
+    let codec = unsafe { &*context.as_ptr() };
+
+    let avg_frame_rate = rational_value(stream.avg_frame_rate());
+    let real_frame_rate = rational_value(stream.rate());
+    let descriptor = ffmpeg::format::Pixel::from(codec.pix_fmt).descriptor();
+    let transfer = color::TransferCharacteristic::from(codec.color_trc);
+    let has_dovi_config = stream
+        .side_data()
+        .any(|side_data| side_data.kind() == ffmpeg::codec::packet::side_data::Type::DOVI_CONF);
+
+    VideoDetails {
+        avg_frame_rate,
+        real_frame_rate,
+        is_vfr: is_variable_frame_rate(avg_frame_rate, real_frame_rate),
+        pixel_format: descriptor.map(|descriptor| descriptor.name().to_string()),
+        bit_depth: descriptor
+            .map(|descriptor| unsafe { (*descriptor.as_ptr()).comp[0].depth.max(0) as u32 })
+            .filter(|&depth| depth > 0),
+        color_primaries: color::Primaries::from(codec.color_primaries)
+            .name()
+            .map(str::to_string),
+        color_transfer: transfer.name().map(str::to_string),
+        color_matrix: color::Space::from(codec.colorspace)
+            .name()
+            .map(str::to_string),
+        color_range: color::Range::from(codec.color_range)
+            .name()
+            .map(str::to_string),
+        hdr_format: hdr_format(transfer, has_dovi_config),
+        profile: profile_name(&parameters),
+        level: (raw.level > 0).then_some(raw.level),
+        frame_count: (stream.frames() > 0).then(|| stream.frames() as u64),
+    }
+}
+
+/// Codec, layout and language of the best audio stream, if there is one
+pub(crate) fn audio_summary(ictx: &ffmpeg::format::context::Input) -> Option<AudioStreamInfo> {
+    let stream = ictx.streams().best(ffmpeg::media::Type::Audio)?;
+    let parameters = stream.parameters();
+    let raw = unsafe { &*parameters.as_ptr() };
+    Some(AudioStreamInfo {
+        codec_name: Some(parameters.id().name().to_string()),
+        channels: raw.ch_layout.nb_channels.max(0) as u32,
+        sample_rate: raw.sample_rate.max(0) as u32,
+        bitrate: (raw.bit_rate > 0).then_some(raw.bit_rate as u64),
+        language: language(&stream),
+    })
+}
+
+/// Metadata entries in file order
+fn tags(metadata: ffmpeg::DictionaryRef) -> Vec<MediaTag> {
+    metadata
+        .iter()
+        .map(|(key, value)| MediaTag {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+        .collect()
+}
+
+/// `value` in `time_base` units as milliseconds, `None` for `AV_NOPTS_VALUE` or a bad time base
+fn to_ms(value: i64, time_base: ffmpeg::Rational) -> Option<i64> {
+    if value == ffmpeg::ffi::AV_NOPTS_VALUE || time_base.denominator() <= 0 {
+        return None;
+    }
+    let ms = value as i128 * time_base.numerator() as i128 * 1000 / time_base.denominator() as i128;
+    i64::try_from(ms).ok()
+}
+
+/// Durations are never negative; a negative one means unknown
+fn duration_ms(value: i64, time_base: ffmpeg::Rational) -> Option<u64> {
+    to_ms(value, time_base).and_then(|ms| u64::try_from(ms).ok())
+}
+
+fn stream_type(medium: ffmpeg::media::Type) -> StreamType {
+    use ffmpeg::media::Type;
+
+    match medium {
+        Type::Video => StreamType::Video,
+        Type::Audio => StreamType::Audio,
+        Type::Subtitle => StreamType::Subtitle,
+        Type::Data => StreamType::Data,
+        Type::Attachment => StreamType::Attachment,
+        Type::Unknown => StreamType::Unknown,
+    }
+}
+
+fn disposition(flags: ffmpeg::format::stream::Disposition) -> StreamDisposition {
+    use ffmpeg::format::stream::Disposition;
+
+    StreamDisposition {
+        default: flags.contains(Disposition::DEFAULT),
+        dub: flags.contains(Disposition::DUB),
+        original: flags.contains(Disposition::ORIGINAL),
+        comment: flags.contains(Disposition::COMMENT),
+        lyrics: flags.contains(Disposition::LYRICS),
+        karaoke: flags.contains(Disposition::KARAOKE),
+        forced: flags.contains(Disposition::FORCED),
+        hearing_impaired: flags.contains(Disposition::HEARING_IMPAIRED),
+        visual_impaired: flags.contains(Disposition::VISUAL_IMPAIRED),
+        clean_effects: flags.contains(Disposition::CLEAN_EFFECTS),
+        attached_pic: flags.contains(Disposition::ATTACHED_PIC),
+        captions: flags.contains(Disposition::CAPTIONS),
+        descriptions: flags.contains(Disposition::DESCRIPTIONS),
+        metadata: flags.contains(Disposition::METADATA),
+    }
+}
+
+unsafe fn c_string(ptr: *const c_char) -> Option<String> {
+    ptr.as_ref()
+        .map(|ptr| CStr::from_ptr(ptr).to_string_lossy().into_owned())
+}
+
+fn side_data(stream: &ffmpeg::format::stream::Stream) -> Vec<StreamSideData> {
+    stream
+        .side_data()
+        .map(|side_data| StreamSideData {
+            kind: unsafe {
+                c_string(ffmpeg::ffi::av_packet_side_data_name(
+                    side_data.kind().into(),
+                ))
+            }
+            .unwrap_or_else(|| format!("{:?}", side_data.kind())),
+            size_bytes: side_data.data().len() as u32,
+        })
+        .collect()
+}
+
+/// Channel layout as FFmpeg describes it, e.g. "stereo" or "5.1(side)"
+fn channel_layout(layout: &ffmpeg::ffi::AVChannelLayout) -> Option<String> {
+    let mut buffer = [0 as c_char; 64];
+    let written = unsafe {
+        ffmpeg::ffi::av_channel_layout_describe(layout, buffer.as_mut_ptr(), buffer.len())
+    };
+    if written <= 0 {
+        return None;
+    }
+    unsafe { c_string(buffer.as_ptr()) }
+}
+
+fn audio_details(
+    parameters: &ffmpeg::ffi::AVCodecParameters,
+    context: &ffmpeg::ffi::AVCodecContext,
+) -> AudioStreamProbe {
+    let sample_format = ffmpeg::format::Sample::from(context.sample_fmt);
+    AudioStreamProbe {
+        sample_rate: parameters.sample_rate.max(0) as u32,
+        channels: parameters.ch_layout.nb_channels.max(0) as u32,
+        channel_layout: channel_layout(&parameters.ch_layout),
+        // `Sample::name` has no name for None
+        sample_format: (sample_format != ffmpeg::format::Sample::None)
+            .then(|| sample_format.name().to_string()),
+        bits_per_sample: (parameters.bits_per_raw_sample > 0)
+            .then_some(parameters.bits_per_raw_sample as u32),
+    }
+}
+
+fn stream_probe(
+    ictx: &ffmpeg::format::context::Input,
+    stream: &ffmpeg::format::stream::Stream,
+) -> Result<StreamProbe> {
+    let parameters = stream.parameters();
+    let raw = unsafe { &*parameters.as_ptr() };
+    let stream_type = stream_type(parameters.medium());
+    // Copies the parameters into typed fields; no codec is opened
+    let context = ffmpeg::codec::context::Context::from_parameters(parameters.clone())?;
+    let codec = unsafe { ffmpeg::ffi::avcodec_descriptor_get(raw.codec_id).as_ref() };
+
+    let video = (stream_type == StreamType::Video).then(|| {
+        let (width, height) = (raw.width.max(0) as u32, raw.height.max(0) as u32);
+        let (display_width, display_height, rotation) =
+            video::get_display_dimensions_with_format(ictx, stream, width, height);
+        let details = video_details(stream, &context);
+        VideoStreamProbe {
+            width,
+            height,
+            display_width,
+            display_height,
+            rotation,
+            avg_frame_rate: details.avg_frame_rate,
+            real_frame_rate: details.real_frame_rate,
+            is_vfr: details.is_vfr,
+            pixel_format: details.pixel_format,
+            bit_depth: details.bit_depth,
+            color_primaries: details.color_primaries,
+            color_transfer: details.color_transfer,
+            color_matrix: details.color_matrix,
+            color_range: details.color_range,
+            hdr_format: details.hdr_format,
+            level: details.level,
+        }
+    });
+    let audio = (stream_type == StreamType::Audio)
+        .then(|| audio_details(raw, unsafe { &*context.as_ptr() }));
+
+    Ok(StreamProbe {
+        index: stream.index() as u32,
+        stream_type,
+        codec_name: codec.and_then(|codec| unsafe { c_string(codec.name) }),
+        codec_long_name: codec.and_then(|codec| unsafe { c_string(codec.long_name) }),
+        profile: profile_name(&parameters),
+        bitrate: (raw.bit_rate > 0).then_some(raw.bit_rate as u64),
+        start_time_ms: to_ms(stream.start_time(), stream.time_base()),
+        duration_ms: duration_ms(stream.duration(), stream.time_base()),
+        frame_count: (stream.frames() > 0).then(|| stream.frames() as u64),
+        language: language(stream),
+        title: stream.metadata().get("title").map(str::to_string),
+        disposition: disposition(stream.disposition()),
+        tags: tags(stream.metadata()),
+        side_data: side_data(stream),
+        video,
+        audio,
+    })
+}
+
+/// Container, chapters and every stream of `path`, like `ffprobe -show_format -show_streams
+/// -show_chapters`. Reads headers only, so audio-only, image and subtitle files work too.
+pub fn probe(path: &str) -> Result<MediaProbe> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let ictx = video::open_input(path)?;
+    let time_base = ffmpeg::Rational::new(1, ffmpeg::ffi::AV_TIME_BASE);
+    let start_time = unsafe { (*ictx.as_ptr()).start_time };
+
+    let streams = ictx
+        .streams()
+        .map(|stream| stream_probe(&ictx, &stream))
+        .collect::<Result<Vec<_>>>()?;
+    debug!("probe - {} streams in {}", streams.len(), path);
+
+    let chapters = ictx
+        .chapters()
+        .map(|chapter| MediaChapter {
+            id: chapter.id(),
+            start_ms: duration_ms(chapter.start(), chapter.time_base()).unwrap_or(0),
+            end_ms: duration_ms(chapter.end(), chapter.time_base()).unwrap_or(0),
+            title: chapter.metadata().get("title").map(str::to_string),
+            tags: tags(chapter.metadata()),
+        })
+        .collect();
+
+    Ok(MediaProbe {
+        format_name: ictx.format().name().to_string(),
+        format_long_name: Some(ictx.format().description().to_string())
+            .filter(|name| !name.is_empty()),
+        duration_ms: duration_ms(ictx.duration(), time_base),
+        start_time_ms: to_ms(start_time, time_base),
+        bitrate: (ictx.bit_rate() > 0).then_some(ictx.bit_rate() as u64),
+        size_bytes: std::fs::metadata(path)?.len(),
+        tags: tags(ictx.metadata()),
+        chapters,
+        streams,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ffmpeg::color::TransferCharacteristic;
+
+    #[test]
+    fn test_rational_value() {
+        assert_eq!(
+            rational_value(ffmpeg::Rational::new(30000, 1001)).map(|fps| (fps * 1000.0).round()),
+            Some(29970.0)
+        );
+        assert_eq!(rational_value(ffmpeg::Rational::new(0, 1)), None);
+        assert_eq!(rational_value(ffmpeg::Rational::new(25, 0)), None);
+    }
+
+    #[test]
+    fn test_variable_frame_rate() {
+        assert!(!is_variable_frame_rate(Some(29.97), Some(29.97)));
+        // Phone recordings: 30 fps nominal, frames dropped under load
+        assert!(is_variable_frame_rate(Some(28.4), Some(30.0)));
+        assert!(!is_variable_frame_rate(None, Some(30.0)));
+    }
+
+    #[test]
+    fn test_to_ms() {
+        let mpeg_ts = ffmpeg::Rational::new(1, 90_000);
+        assert_eq!(to_ms(900_000, mpeg_ts), Some(10_000));
+        assert_eq!(to_ms(-45_000, mpeg_ts), Some(-500));
+        assert_eq!(to_ms(ffmpeg::ffi::AV_NOPTS_VALUE, mpeg_ts), None);
+        assert_eq!(duration_ms(-45_000, mpeg_ts), None);
+        // Large timestamps in fine time bases don't overflow
+        assert_eq!(
+            duration_ms(i64::MAX / 2, ffmpeg::Rational::new(1, 1_000_000_000)),
+            Some((i64::MAX / 2 / 1_000_000) as u64)
+        );
+    }
+
+    #[test]
+    fn test_hdr_format() {
+        assert_eq!(
+            hdr_format(TransferCharacteristic::SMPTE2084, false),
+            Some(HdrFormat::Hdr10)
+        );
+        assert_eq!(
+            hdr_format(TransferCharacteristic::ARIB_STD_B67, false),
+            Some(HdrFormat::Hlg)
+        );
+        // Dolby Vision profile 8 streams also carry a PQ or HLG base layer
+        assert_eq!(
+            hdr_format(TransferCharacteristic::SMPTE2084, true),
+            Some(HdrFormat::DolbyVision)
+        );
+        assert_eq!(hdr_format(TransferCharacteristic::BT709, false), None);
+    }
+}
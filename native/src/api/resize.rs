@@ -0,0 +1,260 @@
+use image::{DynamicImage, RgbImage};
+
+use crate::api::media::ResizeMode;
+
+/// How a thumbnail is fitted into a `width` x `height` box given in display orientation.
+/// A 0 bound leaves that side free, so (W, 0) behaves like `FitWidth` in every mode.
+/// Shared by the FFmpeg process, in-process and still image paths so they agree on geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Resize {
+    pub width: u32,
+    pub height: u32,
+    pub mode: ResizeMode,
+    pub allow_upscale: bool,
+}
+
+/// Size to scale the source to, and for `Fill` the centred region of the scaled image to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ResizePlan {
+    pub width: u32,
+    pub height: u32,
+    pub crop: Option<(u32, u32, u32, u32)>, // x, y, width, height
+}
+
+impl ResizePlan {
+    /// Dimensions of the finished thumbnail
+    pub fn output_size(&self) -> (u32, u32) {
+        match self.crop {
+            Some((_, _, width, height)) => (width, height),
+            None => (self.width, self.height),
+        }
+    }
+
+    /// Cuts the kept region out of an image already scaled to `width` x `height`
+    pub fn crop_rgb(&self, image: RgbImage) -> RgbImage {
+        match self.crop {
+            Some((x, y, width, height)) => {
+                image::imageops::crop_imm(&image, x, y, width, height).to_image()
+            }
+            None => image,
+        }
+    }
+}
+
+impl Resize {
+    pub fn new(size: (u32, u32), mode: Option<ResizeMode>, allow_upscale: Option<bool>) -> Self {
+        Self {
+            width: size.0,
+            height: size.1,
+            mode: mode.unwrap_or(ResizeMode::Fit),
+            allow_upscale: allow_upscale.unwrap_or(false),
+        }
+    }
+
+    /// Scale factors to the box sides that constrain this mode
+    fn bounds(&self, src_width: u32, src_height: u32) -> (Option<f64>, Option<f64>) {
+        let scale_x = (self.width > 0).then(|| self.width as f64 / src_width.max(1) as f64);
+        let scale_y = (self.height > 0).then(|| self.height as f64 / src_height.max(1) as f64);
+        match self.mode {
+            ResizeMode::FitWidth => (scale_x, None),
+            ResizeMode::FitHeight => (None, scale_y),
+            _ => (scale_x, scale_y),
+        }
+    }
+
+    /// Geometry for a `src_width` x `src_height` source in display orientation
+    pub fn plan(&self, src_width: u32, src_height: u32) -> ResizePlan {
+        let cap = |scale: f64| {
+            if self.allow_upscale {
+                scale
+            } else {
+                scale.min(1.0)
+            }
+        };
+        let scaled = |scale: f64| {
+            (
+                ((src_width as f64 * scale).round() as u32).max(1),
+                ((src_height as f64 * scale).round() as u32).max(1),
+            )
+        };
+
+        let (width, height, crop) = match (self.mode, self.bounds(src_width, src_height)) {
+            (_, (None, None)) => (src_width, src_height, None),
+            (ResizeMode::Exact, (Some(_), Some(_))) => (self.width, self.height, None),
+            (ResizeMode::Fill, (Some(scale_x), Some(scale_y))) => {
+                let (width, height) = scaled(cap(scale_x.max(scale_y)));
+                let (keep_width, keep_height) = (self.width.min(width), self.height.min(height));
+                let crop = ((keep_width, keep_height) != (width, height)).then(|| {
+                    (
+                        (width - keep_width) / 2,
+                        (height - keep_height) / 2,
+                        keep_width,
+                        keep_height,
+                    )
+                });
+                (width, height, crop)
+            }
+            (_, (scale_x, scale_y)) => {
+                let scale = scale_x
+                    .into_iter()
+                    .chain(scale_y)
+                    .fold(f64::INFINITY, f64::min);
+                let (width, height) = scaled(cap(scale));
+                (width, height, None)
+            }
+        };
+        ResizePlan {
+            width,
+            height,
+            crop,
+        }
+    }
+
+    /// The same geometry as an FFmpeg filter for the process path, which works in display
+    /// orientation after autorotation. `None` when the frame keeps its size.
+    pub fn ffmpeg_filter(&self) -> Option<String> {
+        // Without upscaling the box shrinks to the source, which is what `plan` does
+        let bound = |value: u32, side: &str| {
+            if self.allow_upscale {
+                value.to_string()
+            } else {
+                format!("'min({},{})'", value, side)
+            }
+        };
+        let (width, height) = (bound(self.width, "iw"), bound(self.height, "ih"));
+
+        let (has_width, has_height) = match self.mode {
+            ResizeMode::FitWidth => (self.width > 0, false),
+            ResizeMode::FitHeight => (false, self.height > 0),
+            _ => (self.width > 0, self.height > 0),
+        };
+        match (self.mode, has_width, has_height) {
+            (_, false, false) => None,
+            (_, true, false) => Some(format!("scale={}:-1", width)),
+            (_, false, true) => Some(format!("scale=-1:{}", height)),
+            (ResizeMode::Exact, true, true) => {
+                Some(format!("scale={}:{}", self.width, self.height))
+            }
+            (ResizeMode::Fill, true, true) => Some(format!(
+                "scale={0}:{1}:force_original_aspect_ratio=increase,crop={0}:{1}",
+                width, height
+            )),
+            (_, true, true) => Some(format!(
+                "scale={}:{}:force_original_aspect_ratio=decrease",
+                width, height
+            )),
+        }
+    }
+
+    /// Resizes a still image
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        let plan = self.plan(image.width(), image.height());
+        let resized = if (plan.width, plan.height) == (image.width(), image.height()) {
+            image.clone()
+        } else {
+            image.thumbnail_exact(plan.width, plan.height)
+        };
+        match plan.crop {
+            Some((x, y, width, height)) => resized.crop_imm(x, y, width, height),
+            None => resized,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resize(width: u32, height: u32, mode: ResizeMode, allow_upscale: bool) -> Resize {
+        Resize::new((width, height), Some(mode), Some(allow_upscale))
+    }
+
+    #[test]
+    fn test_plan_modes() {
+        let plan = |mode| resize(256, 256, mode, false).plan(1920, 1080);
+        assert_eq!(plan(ResizeMode::Fit).output_size(), (256, 144));
+        assert_eq!(plan(ResizeMode::Exact).output_size(), (256, 256));
+        assert_eq!(plan(ResizeMode::FitHeight).output_size(), (455, 256));
+        assert_eq!(plan(ResizeMode::FitWidth).output_size(), (256, 144));
+
+        let fill = plan(ResizeMode::Fill);
+        assert_eq!((fill.width, fill.height), (455, 256));
+        assert_eq!(fill.crop, Some((99, 0, 256, 256)));
+
+        // A free side follows the aspect ratio whatever the mode
+        let free = resize(0, 90, ResizeMode::Fill, false).plan(1920, 1080);
+        assert_eq!(free.output_size(), (160, 90));
+        assert_eq!(
+            resize(0, 0, ResizeMode::Exact, false)
+                .plan(640, 480)
+                .output_size(),
+            (640, 480)
+        );
+    }
+
+    #[test]
+    fn test_plan_upscale() {
+        // A small source stays small unless upscaling is allowed
+        assert_eq!(
+            resize(512, 512, ResizeMode::Fit, false)
+                .plan(320, 240)
+                .output_size(),
+            (320, 240)
+        );
+        assert_eq!(
+            resize(512, 512, ResizeMode::Fit, true)
+                .plan(320, 240)
+                .output_size(),
+            (512, 384)
+        );
+        // Fill without upscaling crops to the part of the box the source covers
+        let fill = resize(512, 200, ResizeMode::Fill, false).plan(320, 240);
+        assert_eq!((fill.width, fill.height), (320, 240));
+        assert_eq!(fill.crop, Some((0, 20, 320, 200)));
+        assert_eq!(
+            resize(512, 200, ResizeMode::Fill, true)
+                .plan(320, 240)
+                .output_size(),
+            (512, 200)
+        );
+
+        let image = DynamicImage::new_rgb8(320, 240);
+        let filled = resize(100, 100, ResizeMode::Fill, false).apply(&image);
+        assert_eq!((filled.width(), filled.height()), (100, 100));
+    }
+
+    #[test]
+    fn test_ffmpeg_filter() {
+        assert_eq!(
+            resize(256, 256, ResizeMode::Fit, false)
+                .ffmpeg_filter()
+                .unwrap(),
+            "scale='min(256,iw)':'min(256,ih)':force_original_aspect_ratio=decrease"
+        );
+        assert_eq!(
+            resize(256, 144, ResizeMode::Fill, true)
+                .ffmpeg_filter()
+                .unwrap(),
+            "scale=256:144:force_original_aspect_ratio=increase,crop=256:144"
+        );
+        assert_eq!(
+            resize(256, 144, ResizeMode::Exact, false)
+                .ffmpeg_filter()
+                .unwrap(),
+            "scale=256:144"
+        );
+        assert_eq!(
+            resize(256, 144, ResizeMode::FitHeight, true)
+                .ffmpeg_filter()
+                .unwrap(),
+            "scale=-1:144"
+        );
+        assert_eq!(
+            resize(256, 0, ResizeMode::Fill, true)
+                .ffmpeg_filter()
+                .unwrap(),
+            "scale=256:-1"
+        );
+        assert_eq!(resize(0, 0, ResizeMode::Fit, false).ffmpeg_filter(), None);
+    }
+}
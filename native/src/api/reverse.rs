@@ -0,0 +1,587 @@
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use tracing::{debug, info};
+
+use crate::api::audio::{AudioResampler, AudioWriter, StereoBuffer, DEFAULT_SAMPLE_RATE};
+use crate::api::layout::{even, rotate_yuv420p};
+use crate::api::media::{ReverseAudioMode, ReverseParams};
+use crate::api::pipeline::{
+    rescale, ConstantRateEncoder, Progress, DEFAULT_BITRATE_KBPS, DEFAULT_FPS, MAX_FPS,
+    MICROSECONDS,
+};
+use crate::api::video::{self, CompressionStats};
+
+/// Decoded frames held per chunk when `memory_budget_mb` is not set
+const DEFAULT_MEMORY_BUDGET_MB: u32 = 256;
+
+/// Index of the sample playing at `time_us`; adjacent chunks share their boundary index
+fn sample_at(time_us: i64, rate: u32) -> i64 {
+    (time_us as i128 * rate as i128).div_euclid(1_000_000) as i64
+}
+
+/// Split `start_us..end_us` into chunks of at most `max_chunk_us`, last chunk first.
+/// Chunks start on a keyframe where one is in reach, so no frame is decoded twice;
+/// GOPs longer than `max_chunk_us` are split and their head is decoded again per piece.
+fn plan_chunks(
+    keyframes_us: &[i64],
+    start_us: i64,
+    end_us: i64,
+    max_chunk_us: i64,
+) -> Vec<(i64, i64)> {
+    let max_chunk_us = max_chunk_us.max(1);
+    let mut chunks = Vec::new();
+    let mut chunk_end = end_us;
+    while chunk_end > start_us {
+        let earliest = (chunk_end - max_chunk_us).max(start_us);
+        let chunk_start = keyframes_us
+            .iter()
+            .copied()
+            .filter(|&keyframe| keyframe >= earliest && keyframe < chunk_end)
+            .min()
+            .unwrap_or(earliest);
+        chunks.push((chunk_start, chunk_end));
+        chunk_end = chunk_start;
+    }
+    chunks
+}
+
+/// Input opened for decoding arbitrary time ranges, with frames scaled to the output size
+struct RangeDecoder {
+    ictx: ffmpeg::format::context::Input,
+    video_index: usize,
+    video_time_base: ffmpeg::Rational,
+    decoder: ffmpeg::codec::decoder::Video,
+    scaler: Option<ffmpeg::software::scaling::Context>,
+    audio_index: Option<usize>,
+    audio_time_base: ffmpeg::Rational,
+    out_width: u32,  // stored orientation
+    out_height: u32, // stored orientation
+}
+
+impl RangeDecoder {
+    fn open(path: &str, out_width: u32, out_height: u32) -> Result<Self> {
+        let ictx = video::open_input(path)?;
+        let (video_index, video_time_base, decoder) = {
+            let stream = ictx
+                .streams()
+                .best(ffmpeg::media::Type::Video)
+                .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", path))?;
+            let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+                .decoder()
+                .video()?;
+            (stream.index(), stream.time_base(), decoder)
+        };
+        let (audio_index, audio_time_base) = match ictx.streams().best(ffmpeg::media::Type::Audio) {
+            Some(stream) => (Some(stream.index()), stream.time_base()),
+            None => (None, MICROSECONDS),
+        };
+        Ok(RangeDecoder {
+            ictx,
+            video_index,
+            video_time_base,
+            decoder,
+            scaler: None,
+            audio_index,
+            audio_time_base,
+            out_width,
+            out_height,
+        })
+    }
+
+    /// Keyframe times of the video stream (demux only, nothing is decoded)
+    fn keyframes_us(&mut self) -> Result<Vec<i64>> {
+        let mut keyframes = Vec::new();
+        for (stream, packet) in self.ictx.packets() {
+            if stream.index() == self.video_index && packet.is_key() {
+                if let Some(ts) = packet.pts().or(packet.dts()) {
+                    keyframes.push(rescale(ts, self.video_time_base, MICROSECONDS));
+                }
+            }
+        }
+        keyframes.sort_unstable();
+        keyframes.dedup();
+        Ok(keyframes)
+    }
+
+    /// Decode the frames shown in `start_us..end_us`, starting from the keyframe at or before
+    /// `start_us`. Each scaled frame is handed to `on_frame` with its time. When `audio` is
+    /// given it receives exactly the range's samples (stereo, `DEFAULT_SAMPLE_RATE`).
+    fn decode_range(
+        &mut self,
+        start_us: i64,
+        end_us: i64,
+        audio: Option<&mut StereoBuffer>,
+        mut on_frame: impl FnMut(&mut ffmpeg::util::frame::video::Video, i64) -> Result<()>,
+    ) -> Result<()> {
+        let target = start_us.max(0);
+        if self.ictx.seek(target, ..target).is_err() {
+            self.ictx.seek(target, ..).context("Seek failed")?;
+        }
+        self.decoder.flush();
+
+        let mut resampler = match (audio.is_some(), self.audio_index) {
+            (true, Some(index)) => {
+                let parameters = self
+                    .ictx
+                    .stream(index)
+                    .ok_or_else(|| anyhow::anyhow!("Audio stream not found"))?
+                    .parameters();
+                Some(AudioResampler::new(parameters, DEFAULT_SAMPLE_RATE)?)
+            }
+            _ => None,
+        };
+        let mut pending = StereoBuffer::default();
+        let mut pending_start: Option<i64> = None; // sample index of pending[0]
+
+        let mut decoded = ffmpeg::util::frame::video::Video::empty();
+        let mut scaled = ffmpeg::util::frame::video::Video::new(
+            ffmpeg::format::Pixel::YUV420P,
+            self.out_width,
+            self.out_height,
+        );
+        let mut video_done = false;
+        let mut audio_done = resampler.is_none();
+
+        for (stream, packet) in self.ictx.packets() {
+            if stream.index() == self.video_index && !video_done {
+                self.decoder
+                    .send_packet(&packet)
+                    .context("Decoder send_packet failed")?;
+                video_done = receive_frames(
+                    &mut self.decoder,
+                    &mut self.scaler,
+                    self.video_time_base,
+                    start_us,
+                    end_us,
+                    &mut decoded,
+                    &mut scaled,
+                    &mut on_frame,
+                )?;
+            } else if Some(stream.index()) == self.audio_index && !audio_done {
+                if let Some(ref mut resampler) = resampler {
+                    let pts_us = packet
+                        .pts()
+                        .map(|pts| rescale(pts, self.audio_time_base, MICROSECONDS));
+                    if pts_us.is_some_and(|pts_us| pts_us >= end_us) {
+                        audio_done = true;
+                    } else {
+                        if pending_start.is_none() {
+                            pending_start =
+                                pts_us.map(|pts_us| sample_at(pts_us, DEFAULT_SAMPLE_RATE));
+                        }
+                        resampler.send_packet(&packet, &mut pending)?;
+                    }
+                }
+            }
+            if video_done && audio_done {
+                break;
+            }
+        }
+
+        if !video_done {
+            self.decoder.send_eof().ok();
+            receive_frames(
+                &mut self.decoder,
+                &mut self.scaler,
+                self.video_time_base,
+                start_us,
+                end_us,
+                &mut decoded,
+                &mut scaled,
+                &mut on_frame,
+            )?;
+        }
+
+        if let Some(out) = audio {
+            if let Some(ref mut resampler) = resampler {
+                if !audio_done {
+                    resampler.flush(&mut pending)?;
+                }
+            }
+            // Trim (or pad) the decoded audio to exactly the range's samples
+            let first = sample_at(start_us, DEFAULT_SAMPLE_RATE);
+            let wanted = (sample_at(end_us, DEFAULT_SAMPLE_RATE) - first).max(0) as usize;
+            let skip = first - pending_start.unwrap_or(first);
+            let lead = if skip >= 0 {
+                pending.drain_front(skip as usize);
+                0
+            } else {
+                ((-skip) as usize).min(wanted)
+            };
+            out.push_silence(lead);
+            let (left, right) = pending.drain_front(wanted - lead);
+            out.left.extend_from_slice(&left);
+            out.right.extend_from_slice(&right);
+            out.push_silence(wanted - lead - left.len());
+        }
+        Ok(())
+    }
+}
+
+/// Drain decoded frames, scaling those inside the range; returns true once past `end_us`
+#[allow(clippy::too_many_arguments)]
+fn receive_frames(
+    decoder: &mut ffmpeg::codec::decoder::Video,
+    scaler: &mut Option<ffmpeg::software::scaling::Context>,
+    time_base: ffmpeg::Rational,
+    start_us: i64,
+    end_us: i64,
+    decoded: &mut ffmpeg::util::frame::video::Video,
+    scaled: &mut ffmpeg::util::frame::video::Video,
+    on_frame: &mut impl FnMut(&mut ffmpeg::util::frame::video::Video, i64) -> Result<()>,
+) -> Result<bool> {
+    while decoder.receive_frame(decoded).is_ok() {
+        let Some(pts) = decoded.timestamp().or(decoded.pts()) else {
+            continue;
+        };
+        let pts_us = rescale(pts, time_base, MICROSECONDS);
+        if pts_us >= end_us {
+            return Ok(true);
+        }
+        if pts_us < start_us {
+            continue;
+        }
+
+        if scaler.is_none() {
+            *scaler = Some(ffmpeg::software::scaling::Context::get(
+                decoded.format(),
+                decoded.width(),
+                decoded.height(),
+                ffmpeg::format::Pixel::YUV420P,
+                scaled.width(),
+                scaled.height(),
+                ffmpeg::software::scaling::flag::Flags::BILINEAR,
+            )?);
+        }
+        // The encoder may still reference the previous buffer
+        unsafe {
+            ffmpeg::ffi::av_frame_make_writable(scaled.as_mut_ptr());
+        }
+        if let Some(ref mut scaler) = scaler {
+            scaler.run(decoded, scaled).context("Scaler run failed")?;
+        }
+        on_frame(scaled, pts_us)?;
+    }
+    Ok(false)
+}
+
+/// Encode one frame (stored orientation) upright at `time_us` on the output timeline
+fn emit(
+    encoder: &mut ConstantRateEncoder,
+    octx: &mut ffmpeg::format::context::Output,
+    frame: &mut ffmpeg::util::frame::video::Video,
+    rotation: i32,
+    time_us: i64,
+    progress: &mut Progress,
+) -> Result<()> {
+    if rotation != 0 {
+        let mut upright = rotate_yuv420p(frame, rotation);
+        encoder.push(&mut upright, time_us, octx)?;
+    } else {
+        encoder.push(frame, time_us, octx)?;
+    }
+    progress.report((time_us.max(0) / 1000) as u64);
+    Ok(())
+}
+
+/// Render `path` backwards (or as a boomerang) into `output_path`.
+/// `on_progress` receives (processed_ms, total_ms) in output time.
+pub fn reverse_video(
+    path: &str,
+    output_path: &str,
+    params: &ReverseParams,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<CompressionStats> {
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    if let Some(parent) = Path::new(output_path).parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create output directory: {}", parent.display())
+            })?;
+        }
+    }
+    let started = Instant::now();
+
+    // Probe geometry, timing and bitrate
+    let (
+        display_width,
+        display_height,
+        rotation,
+        source_fps,
+        stream_start_us,
+        duration_us,
+        input_bitrate,
+    ) = {
+        let ictx = video::open_input(path)?;
+        let stream = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", path))?;
+        let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+            .decoder()
+            .video()?;
+        let (display_width, display_height, rotation) = video::get_display_dimensions_with_format(
+            &ictx,
+            &stream,
+            decoder.width(),
+            decoder.height(),
+        );
+        let rate = stream.avg_frame_rate();
+        let fps = if rate.numerator() > 0 && rate.denominator() > 0 {
+            f64::from(rate)
+        } else {
+            DEFAULT_FPS as f64
+        };
+        let start_us = match stream.start_time() {
+            ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+            start => rescale(start, stream.time_base(), MICROSECONDS),
+        };
+        let duration_us = if stream.duration() > 0 {
+            rescale(stream.duration(), stream.time_base(), MICROSECONDS)
+        } else {
+            ictx.duration().max(0)
+        };
+        (
+            display_width,
+            display_height,
+            rotation,
+            fps,
+            start_us,
+            duration_us,
+            ictx.bit_rate().max(0) as u32 / 1000,
+        )
+    };
+
+    let start_us = stream_start_us + params.start_ms.unwrap_or(0) as i64 * 1000;
+    let end_us = match params.end_ms {
+        Some(end_ms) => (stream_start_us + end_ms as i64 * 1000).min(stream_start_us + duration_us),
+        None => stream_start_us + duration_us,
+    };
+    if end_us <= start_us {
+        return Err(anyhow::anyhow!(
+            "Empty range to reverse: start {:?} ms, end {:?} ms, duration {} ms",
+            params.start_ms,
+            params.end_ms,
+            duration_us / 1000
+        ));
+    }
+    let range_us = end_us - start_us;
+
+    let (target_width, target_height) =
+        video::calculate_dimensions(display_width, display_height, params.width, params.height);
+    let (out_width, out_height) = (even(target_width), even(target_height));
+    let (stored_width, stored_height) = match rotation {
+        90 | 270 => (out_height, out_width),
+        _ => (out_width, out_height),
+    };
+    let fps = (source_fps.round() as u32).clamp(1, MAX_FPS);
+    let bitrate_kbps =
+        params
+            .target_bitrate_kbps
+            .filter(|b| *b > 0)
+            .unwrap_or(if input_bitrate > 0 {
+                input_bitrate
+            } else {
+                DEFAULT_BITRATE_KBPS
+            });
+
+    // Chunk length: as many frames as fit in the memory budget
+    let frame_bytes = stored_width as u64 * stored_height as u64 * 3 / 2;
+    let budget_bytes = params
+        .memory_budget_mb
+        .unwrap_or(DEFAULT_MEMORY_BUDGET_MB)
+        .max(1) as u64
+        * 1024
+        * 1024;
+    let max_frames = (budget_bytes / frame_bytes.max(1)).max(2);
+    let max_chunk_us = (max_frames as f64 * 1_000_000.0 / source_fps) as i64;
+
+    let mut decoder = RangeDecoder::open(path, stored_width, stored_height)?;
+    let keyframes = decoder.keyframes_us()?;
+    let chunks = plan_chunks(&keyframes, start_us, end_us, max_chunk_us);
+    info!(
+        "reverse_video - {}x{} @ {} fps, {} ms in {} chunks of up to {} frames",
+        out_width,
+        out_height,
+        fps,
+        range_us / 1000,
+        chunks.len(),
+        max_frames
+    );
+
+    let with_audio = params.audio.unwrap_or(ReverseAudioMode::Reverse) == ReverseAudioMode::Reverse
+        && decoder.audio_index.is_some();
+    let boomerang = params.boomerang_loops.is_some();
+    let loops = params.boomerang_loops.unwrap_or(1).max(1);
+    let passes = if boomerang { loops as i64 * 2 } else { 1 };
+    let mut progress = Progress::new((range_us * passes / 1000) as u64, &mut on_progress);
+
+    let mut octx = ffmpeg::format::output(&output_path)
+        .with_context(|| format!("Failed to create output video file: {}", output_path))?;
+    let global_header = octx
+        .format()
+        .flags()
+        .contains(ffmpeg::format::flag::Flags::GLOBAL_HEADER);
+    let codec = video::find_h264_encoder()?;
+    let encoder = video::open_h264_encoder(
+        codec,
+        out_width,
+        out_height,
+        fps,
+        bitrate_kbps,
+        params.crf,
+        params.preset.as_deref(),
+        global_header,
+    )?;
+    let video_out = {
+        let mut ost = octx.add_stream(codec)?;
+        ost.set_parameters(&encoder);
+        ost.index()
+    };
+    let mut audio_writer = if with_audio {
+        Some(AudioWriter::aac(&mut octx, DEFAULT_SAMPLE_RATE)?)
+    } else {
+        None
+    };
+    octx.write_header()
+        .context("Failed to write output header")?;
+
+    let mut encoder = ConstantRateEncoder::new(encoder, video_out, fps);
+    let mut audio_buffer = StereoBuffer::default();
+    let mut offset_us: i64 = 0;
+
+    for _ in 0..loops {
+        if boomerang {
+            debug!("reverse_video - forward pass at {} us", offset_us);
+            for &(chunk_start, chunk_end) in chunks.iter().rev() {
+                let mut chunk_audio = StereoBuffer::default();
+                decoder.decode_range(
+                    chunk_start,
+                    chunk_end,
+                    with_audio.then_some(&mut chunk_audio),
+                    |frame, pts_us| {
+                        emit(
+                            &mut encoder,
+                            &mut octx,
+                            frame,
+                            rotation,
+                            offset_us + pts_us - start_us,
+                            &mut progress,
+                        )
+                    },
+                )?;
+                if let Some(ref mut writer) = audio_writer {
+                    audio_buffer.left.extend_from_slice(&chunk_audio.left);
+                    audio_buffer.right.extend_from_slice(&chunk_audio.right);
+                    writer.write(&mut audio_buffer, &mut octx)?;
+                }
+            }
+            offset_us += range_us;
+        }
+
+        debug!("reverse_video - reverse pass at {} us", offset_us);
+        for &(chunk_start, chunk_end) in &chunks {
+            let mut frames: Vec<(i64, ffmpeg::util::frame::video::Video)> = Vec::new();
+            let mut chunk_audio = StereoBuffer::default();
+            decoder.decode_range(
+                chunk_start,
+                chunk_end,
+                with_audio.then_some(&mut chunk_audio),
+                |frame, pts_us| {
+                    frames.push((pts_us, frame.clone()));
+                    Ok(())
+                },
+            )?;
+
+            // A frame shown over [pts, next) in the source is shown over [end - next, end - pts)
+            let mut next_us = chunk_end;
+            for (pts_us, mut frame) in frames.into_iter().rev() {
+                emit(
+                    &mut encoder,
+                    &mut octx,
+                    &mut frame,
+                    rotation,
+                    offset_us + end_us - next_us,
+                    &mut progress,
+                )?;
+                next_us = pts_us;
+            }
+
+            if let Some(ref mut writer) = audio_writer {
+                audio_buffer.left.extend(chunk_audio.left.iter().rev());
+                audio_buffer.right.extend(chunk_audio.right.iter().rev());
+                writer.write(&mut audio_buffer, &mut octx)?;
+            }
+        }
+        offset_us += range_us;
+    }
+
+    encoder.finish(&mut octx)?;
+    let mut encoded_size_bytes = encoder.encoded_size_bytes();
+    if let Some(ref mut writer) = audio_writer {
+        writer.finish(&mut audio_buffer, &mut octx)?;
+        encoded_size_bytes += writer.encoded_size_bytes;
+    }
+    octx.write_trailer().context("Write trailer failed")?;
+    progress.finish();
+
+    Ok(CompressionStats {
+        processed_duration_ms: (offset_us / 1000) as u64,
+        elapsed_ms: started.elapsed().as_millis(),
+        encoded_size_bytes,
+        output_file_path: output_path.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_snap_to_keyframes() {
+        // Keyframe every second, room for 2.5 s per chunk
+        let keyframes: Vec<i64> = (0..10).map(|s| s * 1_000_000).collect();
+        let chunks = plan_chunks(&keyframes, 0, 5_000_000, 2_500_000);
+        assert_eq!(
+            chunks,
+            vec![
+                (3_000_000, 5_000_000),
+                (1_000_000, 3_000_000),
+                (0, 1_000_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long_gop_is_split() {
+        // One keyframe only: chunks fall back to the budget length
+        let chunks = plan_chunks(&[0], 500_000, 4_000_000, 1_500_000);
+        assert_eq!(
+            chunks,
+            vec![
+                (2_500_000, 4_000_000),
+                (1_000_000, 2_500_000),
+                (500_000, 1_000_000)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chunk_samples_tile() {
+        let chunks = plan_chunks(&[0, 1_234_567], 0, 3_333_333, 1_000_000);
+        let total: i64 = chunks
+            .iter()
+            .map(|&(start, end)| sample_at(end, 48_000) - sample_at(start, 48_000))
+            .sum();
+        assert_eq!(total, sample_at(3_333_333, 48_000));
+    }
+}
@@ -0,0 +1,325 @@
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use image::RgbImage;
+
+use crate::api::media::SeekMode;
+use crate::api::pipeline::{rescale, MICROSECONDS};
+use crate::api::resize::Resize;
+use crate::api::video;
+
+/// Without a seek index, targets further ahead than this are reached by seeking
+const SEEK_AHEAD_MS: i64 = 3000;
+
+/// Walks forward through one video, returning the frames for a series of
+/// non-decreasing timestamps. The input, decoder and scaler are opened once;
+/// it seeks only when the target is past the next keyframe, and otherwise
+/// keeps decoding from where the previous timestamp left off.
+pub(crate) struct FrameSampler {
+    ictx: ffmpeg::format::context::Input,
+    stream_index: usize,
+    decoder: ffmpeg::codec::decoder::Video,
+    time_base: ffmpeg::Rational,
+    start_pts: i64,
+    duration_ms: u64,
+    display_size: (u32, u32),
+    rotation: i32,
+    current: ffmpeg::util::frame::video::Video,
+    current_pts: Option<i64>,
+    current_dts: Option<i64>, // decode timestamp, the key of the seek index
+    scratch: ffmpeg::util::frame::video::Video,
+    eof: bool,
+    scaler: Option<ffmpeg::software::scaling::Context>,
+}
+
+impl FrameSampler {
+    pub fn open(path: &str) -> Result<Self> {
+        let ictx = video::open_input(path)?;
+        let stream = ictx
+            .streams()
+            .best(ffmpeg::media::Type::Video)
+            .ok_or_else(|| anyhow::anyhow!("No video stream found in {}", path))?;
+        let stream_index = stream.index();
+        let time_base = stream.time_base();
+        let start_pts = match stream.start_time() {
+            ffmpeg::ffi::AV_NOPTS_VALUE => 0,
+            start => start,
+        };
+        let decoder = ffmpeg::codec::context::Context::from_parameters(stream.parameters())?
+            .decoder()
+            .video()
+            .context("Failed to open video decoder")?;
+        if decoder.width() == 0 || decoder.height() == 0 {
+            return Err(anyhow::anyhow!("Invalid video dimensions (0x0)"));
+        }
+        let (display_width, display_height, rotation) = video::get_display_dimensions_with_format(
+            &ictx,
+            &stream,
+            decoder.width(),
+            decoder.height(),
+        );
+        let duration_ms = (ictx.duration().max(0) / 1000) as u64; // AV_TIME_BASE is microseconds
+
+        Ok(Self {
+            ictx,
+            stream_index,
+            decoder,
+            time_base,
+            start_pts,
+            duration_ms,
+            display_size: (display_width, display_height),
+            rotation,
+            current: ffmpeg::util::frame::video::Video::empty(),
+            current_pts: None,
+            current_dts: None,
+            scratch: ffmpeg::util::frame::video::Video::empty(),
+            eof: false,
+            scaler: None,
+        })
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.duration_ms
+    }
+
+    /// Frame size in display orientation
+    pub fn display_size(&self) -> (u32, u32) {
+        self.display_size
+    }
+
+    fn pts_for(&self, time_ms: u64) -> i64 {
+        self.start_pts + rescale(time_ms as i64 * 1000, MICROSECONDS, self.time_base)
+    }
+
+    fn ms_for(&self, pts: i64) -> u64 {
+        (rescale(pts - self.start_pts, self.time_base, MICROSECONDS).max(0) / 1000) as u64
+    }
+
+    /// Decode timestamp of the last keyframe at or before `pts`, from the demuxer's seek
+    /// index. Index entries are keyed by DTS, so compare the result with `current_dts`.
+    fn keyframe_before(&self, pts: i64) -> Option<i64> {
+        let stream = self.ictx.stream(self.stream_index)?;
+        unsafe {
+            let entry = ffmpeg::ffi::avformat_index_get_entry_from_timestamp(
+                stream.as_ptr() as *mut _,
+                pts,
+                ffmpeg::ffi::AVSEEK_FLAG_BACKWARD,
+            );
+            entry.as_ref().map(|entry| entry.timestamp)
+        }
+    }
+
+    fn seek(&mut self, target_pts: i64) -> Result<()> {
+        let target_us = rescale(target_pts, self.time_base, MICROSECONDS);
+        if self.ictx.seek(target_us, ..target_us).is_err() {
+            self.ictx
+                .seek(target_us, ..)
+                .with_context(|| format!("Failed to seek to {}ms", self.ms_for(target_pts)))?;
+        }
+        self.decoder.flush();
+        self.current_pts = None;
+        self.current_dts = None;
+        self.eof = false;
+        Ok(())
+    }
+
+    /// Decodes the next frame into `current`; false at the end of the stream
+    fn next_frame(&mut self) -> Result<bool> {
+        loop {
+            if self.decoder.receive_frame(&mut self.scratch).is_ok() {
+                std::mem::swap(&mut self.current, &mut self.scratch);
+                self.current_pts = Some(
+                    self.current
+                        .timestamp()
+                        .or(self.current.pts())
+                        .unwrap_or(self.start_pts),
+                );
+                self.current_dts = match self.current.packet().dts {
+                    ffmpeg::ffi::AV_NOPTS_VALUE => None,
+                    dts => Some(dts),
+                };
+                return Ok(true);
+            }
+            if self.eof {
+                return Ok(false);
+            }
+            match self.ictx.packets().next() {
+                Some((stream, packet)) => {
+                    if stream.index() == self.stream_index {
+                        // Corrupt packets are skipped like in the other decode loops
+                        let _ = self.decoder.send_packet(&packet);
+                    }
+                }
+                None => {
+                    // Drain the frames still held for reordering
+                    self.decoder.send_eof().ok();
+                    self.eof = true;
+                }
+            }
+        }
+    }
+
+    /// Moves on to the next frame in decode order and returns its timestamp in ms;
+    /// `None` at the end of the stream
+    pub fn advance(&mut self) -> Result<Option<u64>> {
+        if !self.next_frame()? {
+            return Ok(None);
+        }
+        Ok(self.current_pts.map(|pts| self.ms_for(pts)))
+    }
+
+    /// Positions on the frame for `time_ms` and returns its actual timestamp in ms.
+    /// Accurate mode gives the first frame at or after the timestamp; keyframe mode the
+    /// keyframe at or before it. Past the end the last frame is returned, and `None`
+    /// only when nothing could be decoded. Timestamps must not decrease between calls.
+    pub fn frame_at(&mut self, time_ms: u64, mode: SeekMode) -> Result<Option<u64>> {
+        let target_pts = self.pts_for(time_ms);
+
+        match mode {
+            SeekMode::Keyframe => {
+                let keyframe = self.keyframe_before(target_pts);
+                let reuse = keyframe.is_some() && self.current_dts == keyframe;
+                if !reuse {
+                    self.seek(target_pts)?;
+                    if !self.next_frame()? {
+                        return Ok(None);
+                    }
+                }
+            }
+            SeekMode::Accurate => {
+                if self.current_pts.is_some_and(|pts| pts >= target_pts) {
+                    return Ok(self.current_pts.map(|pts| self.ms_for(pts)));
+                }
+                let should_seek = match self.current_pts {
+                    None => true,
+                    Some(current) => match (self.keyframe_before(target_pts), self.current_dts) {
+                        (Some(keyframe), Some(current_dts)) => keyframe > current_dts,
+                        _ => {
+                            target_pts - current
+                                > rescale(SEEK_AHEAD_MS * 1000, MICROSECONDS, self.time_base)
+                        }
+                    },
+                };
+                if should_seek && !self.eof {
+                    self.seek(target_pts)?;
+                }
+                while self.current_pts.is_none_or(|pts| pts < target_pts) {
+                    if !self.next_frame()? {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(self.current_pts.map(|pts| self.ms_for(pts)))
+    }
+
+    /// The current frame scaled into the `resize` box (display orientation) and turned
+    /// upright. The scaler is kept for the next frame of the same size.
+    pub fn image(&mut self, resize: &Resize) -> Result<RgbImage> {
+        if self.current_pts.is_none() {
+            return Err(anyhow::anyhow!("No decoded frame"));
+        }
+        video::scale_frame(&self.current, resize, self.rotation, &mut self.scaler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const VIDEO_PATH: &str = "../native/video.MOV";
+
+    fn open_sample() -> Option<FrameSampler> {
+        if !Path::new(VIDEO_PATH).exists() {
+            eprintln!("Skipping test: {} not found", VIDEO_PATH);
+            return None;
+        }
+        ffmpeg::init().expect("Failed to initialize FFmpeg");
+        Some(FrameSampler::open(VIDEO_PATH).expect("Failed to open sample video"))
+    }
+
+    fn sample_times(duration_ms: u64) -> Vec<u64> {
+        (0..8).map(|i| duration_ms * i / 8).collect()
+    }
+
+    /// Walking forward must land on the same frames as a fresh seek to each timestamp
+    fn check_walk_matches_fresh_seeks(mode: SeekMode) {
+        let Some(mut sampler) = open_sample() else {
+            return;
+        };
+        let times = sample_times(sampler.duration_ms());
+        let mut previous = 0;
+        for &time_ms in &times {
+            let walked = sampler
+                .frame_at(time_ms, mode)
+                .unwrap()
+                .expect("No frame decoded");
+            let fresh = FrameSampler::open(VIDEO_PATH)
+                .unwrap()
+                .frame_at(time_ms, mode)
+                .unwrap()
+                .expect("No frame decoded");
+            assert_eq!(walked, fresh, "frame for {}ms", time_ms);
+            assert!(
+                walked >= previous,
+                "{}ms came before {}ms",
+                walked,
+                previous
+            );
+            previous = walked;
+        }
+    }
+
+    #[test]
+    fn test_accurate_walk_returns_first_frame_at_or_after_target() {
+        check_walk_matches_fresh_seeks(SeekMode::Accurate);
+
+        let Some(mut sampler) = open_sample() else {
+            return;
+        };
+        for time_ms in sample_times(sampler.duration_ms()) {
+            let actual = sampler
+                .frame_at(time_ms, SeekMode::Accurate)
+                .unwrap()
+                .unwrap();
+            // Timestamps are truncated to ms, so allow for rounding
+            assert!(actual + 1 >= time_ms, "{}ms for {}ms", actual, time_ms);
+        }
+    }
+
+    #[test]
+    fn test_keyframe_walk_returns_keyframe_at_or_before_target() {
+        check_walk_matches_fresh_seeks(SeekMode::Keyframe);
+
+        let Some(mut sampler) = open_sample() else {
+            return;
+        };
+        for time_ms in sample_times(sampler.duration_ms()) {
+            let actual = sampler
+                .frame_at(time_ms, SeekMode::Keyframe)
+                .unwrap()
+                .unwrap();
+            assert!(actual <= time_ms + 1, "{}ms for {}ms", actual, time_ms);
+        }
+    }
+
+    #[test]
+    fn test_repeated_and_past_end_timestamps() {
+        let Some(mut sampler) = open_sample() else {
+            return;
+        };
+        let duration_ms = sampler.duration_ms();
+        let first = sampler.frame_at(1_000, SeekMode::Accurate).unwrap();
+        assert_eq!(sampler.frame_at(1_000, SeekMode::Accurate).unwrap(), first);
+
+        // Past the end the last frame is kept rather than nothing
+        let last = sampler
+            .frame_at(duration_ms + 10_000, SeekMode::Accurate)
+            .unwrap()
+            .expect("Past the end should return the last frame");
+        assert!(last <= duration_ms);
+        let image = sampler.image(&Resize::new((64, 64), None, None)).unwrap();
+        assert!(image.width() <= 64 && image.height() <= 64);
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::Result;
+use image::RgbImage;
+use tracing::debug;
+
+use crate::api::media::SceneCut;
+use crate::api::pipeline::Progress;
+use crate::api::resize::Resize;
+use crate::api::sampler::FrameSampler;
+use crate::api::video;
+
+/// Frames are compared on a copy this small; it's enough to see a cut and hides noise
+const ANALYSIS_SIZE: u32 = 64;
+
+/// Histogram bins per RGB channel
+const BINS: usize = 16;
+
+/// Cuts closer together than this are one transition; the stronger one is kept
+const MIN_SCENE_MS: u64 = 500;
+
+/// Difference between two consecutive frames, 0 (identical) to 1. Averages the colour
+/// histogram distance, which ignores motion within a shot, with the mean absolute pixel
+/// difference, which catches cuts between shots of similar colour.
+fn frame_difference(previous: &RgbImage, current: &RgbImage) -> f64 {
+    if previous.dimensions() != current.dimensions() {
+        return 1.0;
+    }
+
+    let histogram = |image: &RgbImage| {
+        let mut bins = [[0u32; BINS]; 3];
+        for pixel in image.pixels() {
+            for (channel, value) in pixel.0.iter().enumerate() {
+                bins[channel][*value as usize * BINS / 256] += 1;
+            }
+        }
+        bins
+    };
+    let (a, b) = (histogram(previous), histogram(current));
+    let pixel_count = (previous.width() * previous.height()).max(1) as f64;
+    let histogram_distance = (0..3)
+        .map(|channel| {
+            (0..BINS)
+                .map(|bin| (a[channel][bin] as f64 - b[channel][bin] as f64).abs())
+                .sum::<f64>()
+                / (2.0 * pixel_count)
+        })
+        .sum::<f64>()
+        / 3.0;
+
+    let sad = previous
+        .as_raw()
+        .iter()
+        .zip(current.as_raw())
+        .map(|(a, b)| a.abs_diff(*b) as u64)
+        .sum::<u64>() as f64;
+    let mean_difference = sad / (pixel_count * 3.0 * 255.0);
+
+    (histogram_distance + mean_difference) / 2.0
+}
+
+/// Turns per-frame differences into cut scores. Like FFmpeg's `scdet`, a frame scores the
+/// smaller of its difference and the change from the previous difference, so steady motion
+/// (a high difference on every frame) doesn't read as a string of cuts.
+struct CutDetector {
+    threshold: f64,
+    previous_difference: f64,
+    cuts: Vec<SceneCut>,
+}
+
+impl CutDetector {
+    fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            previous_difference: 0.0,
+            cuts: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, time_ms: u64, difference: f64) {
+        let score = difference.min((difference - self.previous_difference).abs());
+        self.previous_difference = difference;
+        if score < self.threshold {
+            return;
+        }
+
+        match self.cuts.last_mut() {
+            Some(last) if time_ms < last.time_ms + MIN_SCENE_MS => {
+                if score > last.score {
+                    *last = SceneCut { time_ms, score };
+                }
+            }
+            _ => self.cuts.push(SceneCut { time_ms, score }),
+        }
+    }
+}
+
+/// Decodes every frame and returns the timestamps where a new scene starts, with a 0-1
+/// score for how abrupt the change is. `threshold` is the minimum score for a cut; around
+/// 0.3 finds hard cuts, lower values also catch fades and dissolves. The start of the video
+/// isn't reported. `on_progress` receives (processed_ms, total_ms).
+pub fn detect_scenes(
+    path: &str,
+    threshold: f64,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<Vec<SceneCut>> {
+    if threshold.is_nan() || threshold <= 0.0 || threshold > 1.0 {
+        return Err(anyhow::anyhow!(
+            "Scene threshold must be between 0 and 1, got {}",
+            threshold
+        ));
+    }
+
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let mut sampler = FrameSampler::open(path)?;
+    let resize = Resize::new((ANALYSIS_SIZE, ANALYSIS_SIZE), None, None);
+    let mut progress = Progress::new(sampler.duration_ms(), &mut on_progress);
+    let mut detector = CutDetector::new(threshold);
+    let mut previous: Option<RgbImage> = None;
+    let mut frame_count = 0u64;
+
+    while let Some(time_ms) = sampler.advance()? {
+        let current = sampler.image(&resize)?;
+        if let Some(ref previous) = previous {
+            detector.push(time_ms, frame_difference(previous, &current));
+        }
+        previous = Some(current);
+        frame_count += 1;
+        progress.report(time_ms);
+    }
+    progress.finish();
+
+    if frame_count == 0 {
+        return Err(anyhow::anyhow!("Could not decode any frame from {}", path));
+    }
+    debug!(
+        "detect_scenes - {} cuts in {} frames",
+        detector.cuts.len(),
+        frame_count
+    );
+    Ok(detector.cuts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_difference() {
+        let dark = RgbImage::from_pixel(16, 16, image::Rgb([20, 20, 20]));
+        let bright = RgbImage::from_pixel(16, 16, image::Rgb([230, 230, 230]));
+        assert_eq!(frame_difference(&dark, &dark), 0.0);
+        assert!(frame_difference(&dark, &bright) > 0.9);
+
+        // The same content shifted by a few pixels keeps its histogram
+        let stripes = |offset: u32| {
+            RgbImage::from_fn(16, 16, |x, _| {
+                if (x + offset) % 8 < 4 {
+                    image::Rgb([200, 40, 40])
+                } else {
+                    image::Rgb([40, 40, 200])
+                }
+            })
+        };
+        let moved = frame_difference(&stripes(0), &stripes(2));
+        assert!(moved > 0.0 && moved < 0.4);
+    }
+
+    #[test]
+    fn test_steady_motion_is_not_a_cut() {
+        let mut detector = CutDetector::new(0.3);
+        for i in 0..10 {
+            detector.push(i * 40, 0.5);
+        }
+        // Only the jump from a still start registers
+        assert_eq!(detector.cuts.len(), 1);
+        assert_eq!(detector.cuts[0].time_ms, 0);
+    }
+
+    #[test]
+    fn test_close_cuts_keep_the_strongest() {
+        let mut detector = CutDetector::new(0.3);
+        detector.push(1_000, 0.4);
+        detector.push(1_040, 0.0);
+        detector.push(1_200, 0.9);
+        detector.push(1_240, 0.0);
+        detector.push(3_000, 0.5);
+        let times: Vec<u64> = detector.cuts.iter().map(|cut| cut.time_ms).collect();
+        assert_eq!(times, vec![1_200, 3_000]);
+        assert_eq!(detector.cuts[0].score, 0.9);
+    }
+}
@@ -0,0 +1,336 @@
+use anyhow::Result;
+
+use crate::api::media::SpeedParams;
+
+pub const MIN_SPEED: f32 = 0.25;
+pub const MAX_SPEED: f32 = 8.0;
+
+/// Length of each audio segment copied from the input by the time stretcher
+const SEQUENCE_MS: u32 = 40;
+
+/// Cross-fade between consecutive segments
+const OVERLAP_MS: u32 = 8;
+
+/// How far around the nominal position the best-matching segment is searched for
+const SEEK_MS: u32 = 15;
+
+/// Coarse step of the segment search, refined around the best coarse match
+const SEEK_COARSE_STEP: usize = 4;
+
+pub fn validate(params: &SpeedParams) -> Result<()> {
+    if !params.factor.is_finite() || !(MIN_SPEED..=MAX_SPEED).contains(&params.factor) {
+        return Err(anyhow::anyhow!(
+            "Speed factor {} is out of range ({}-{})",
+            params.factor,
+            MIN_SPEED,
+            MAX_SPEED
+        ));
+    }
+    if params.frame_rate == Some(0) {
+        return Err(anyhow::anyhow!("Speed frame_rate must be greater than 0"));
+    }
+    Ok(())
+}
+
+/// Whether the audio track is dropped instead of time-stretched
+pub fn mutes_audio(params: &SpeedParams) -> bool {
+    params
+        .mute_audio_above
+        .is_some_and(|threshold| params.factor > threshold)
+}
+
+/// FFmpeg CLI video filter doing the same retiming as `VideoRetimer`
+pub fn video_filter(params: &SpeedParams) -> String {
+    let setpts = format!("setpts=PTS/{:.6}", params.factor);
+    match params.frame_rate {
+        Some(rate) => format!("{},fps={}", setpts, rate),
+        None => setpts,
+    }
+}
+
+/// Maps source frame times to output PTS for a speed change.
+/// With a frame rate the output is constant-rate: frames are dropped when several land in
+/// one slot and duplicated to fill gaps. Without one, frames keep their (scaled) spacing and
+/// only frames that would collide on the encoder time base are dropped.
+pub struct VideoRetimer {
+    speed: f64,
+    frame_rate: Option<u32>,
+    next_pts: i64,
+}
+
+impl VideoRetimer {
+    pub fn new(params: &SpeedParams) -> Self {
+        VideoRetimer {
+            speed: params.factor as f64,
+            frame_rate: params.frame_rate,
+            next_pts: 0,
+        }
+    }
+
+    /// Output time of a frame shown `source_us` into the source
+    pub fn output_us(&self, source_us: i64) -> i64 {
+        (source_us as f64 / self.speed).round() as i64
+    }
+
+    /// Encoder time base denominator: 1/frame_rate for constant-rate output, else `default_rate`
+    pub fn time_base_rate(&self, default_rate: u32) -> u32 {
+        self.frame_rate.unwrap_or(default_rate)
+    }
+
+    /// PTS values (in 1/`time_base_rate` units) the frame at `source_us` is encoded with:
+    /// empty when the frame is dropped, more than one when it is duplicated
+    pub fn output_pts(&mut self, source_us: i64, time_base_rate: u32) -> Vec<i64> {
+        let output_us = self.output_us(source_us.max(0));
+        let slot = (output_us as f64 * time_base_rate as f64 / 1_000_000.0).round() as i64;
+        if slot < self.next_pts {
+            return Vec::new();
+        }
+        let pts: Vec<i64> = if self.frame_rate.is_some() {
+            (self.next_pts..=slot).collect()
+        } else {
+            vec![slot]
+        };
+        self.next_pts = slot + 1;
+        pts
+    }
+}
+
+/// Pitch-preserving time stretch for planar stereo audio (WSOLA, like FFmpeg's `atempo`).
+/// The input is cut into overlapping segments; each next segment is taken near its nominal
+/// position (advanced by `tempo` times the output hop) where it best matches the tail of
+/// the previous one, then cross-faded in.
+pub struct TimeStretch {
+    tempo: f64,
+    sequence: usize,
+    overlap: usize,
+    seek: usize,
+    input: [Vec<f32>; 2],
+    position: f64, // analysis position within `input`
+    tail: Option<[Vec<f32>; 2]>,
+    samples_in: u64,
+    samples_out: u64,
+}
+
+impl TimeStretch {
+    pub fn new(tempo: f64, sample_rate: u32) -> Self {
+        let ms = |value: u32| (sample_rate as usize * value as usize / 1000).max(1);
+        TimeStretch {
+            tempo,
+            sequence: ms(SEQUENCE_MS),
+            overlap: ms(OVERLAP_MS),
+            seek: ms(SEEK_MS),
+            input: [Vec::new(), Vec::new()],
+            position: 0.0,
+            tail: None,
+            samples_in: 0,
+            samples_out: 0,
+        }
+    }
+
+    /// Stretch `left`/`right` and append whatever output is ready
+    pub fn process(
+        &mut self,
+        left: &[f32],
+        right: &[f32],
+        out_left: &mut Vec<f32>,
+        out_right: &mut Vec<f32>,
+    ) {
+        let samples = left.len().min(right.len());
+        self.input[0].extend_from_slice(&left[..samples]);
+        self.input[1].extend_from_slice(&right[..samples]);
+        self.samples_in += samples as u64;
+        self.run(out_left, out_right);
+    }
+
+    /// Flush the remaining input; the total output is trimmed or padded to input / tempo
+    pub fn finish(&mut self, out_left: &mut Vec<f32>, out_right: &mut Vec<f32>) {
+        let padding = self.sequence + 2 * self.seek;
+        for channel in self.input.iter_mut() {
+            channel.extend(std::iter::repeat_n(0.0, padding));
+        }
+        self.run(out_left, out_right);
+        if let Some(tail) = self.tail.take() {
+            out_left.extend_from_slice(&tail[0]);
+            out_right.extend_from_slice(&tail[1]);
+            self.samples_out += tail[0].len() as u64;
+        }
+
+        let target = (self.samples_in as f64 / self.tempo).round() as u64;
+        if self.samples_out > target {
+            let excess = (self.samples_out - target) as usize;
+            out_left.truncate(out_left.len().saturating_sub(excess));
+            out_right.truncate(out_right.len().saturating_sub(excess));
+        } else {
+            let missing = (target - self.samples_out) as usize;
+            out_left.extend(std::iter::repeat_n(0.0, missing));
+            out_right.extend(std::iter::repeat_n(0.0, missing));
+        }
+        self.samples_out = target;
+        self.input = [Vec::new(), Vec::new()];
+        self.position = 0.0;
+    }
+
+    fn run(&mut self, out_left: &mut Vec<f32>, out_right: &mut Vec<f32>) {
+        let hop = self.sequence - self.overlap;
+        loop {
+            let start = self.position as usize;
+            if start + 2 * self.seek + self.sequence > self.input[0].len() {
+                break;
+            }
+            let segment = start
+                + match self.tail {
+                    Some(ref tail) => self.best_offset(start, tail),
+                    None => self.seek,
+                };
+
+            for (channel, out) in [&mut *out_left, &mut *out_right].into_iter().enumerate() {
+                let input = &self.input[channel][segment..segment + self.sequence];
+                match self.tail {
+                    Some(ref tail) => {
+                        for (i, (&previous, &next)) in
+                            tail[channel].iter().zip(&input[..self.overlap]).enumerate()
+                        {
+                            let t = i as f32 / self.overlap as f32;
+                            out.push(previous * (1.0 - t) + next * t);
+                        }
+                    }
+                    None => out.extend_from_slice(&input[..self.overlap]),
+                }
+                out.extend_from_slice(&input[self.overlap..hop]);
+            }
+            self.tail = Some([
+                self.input[0][segment + hop..segment + self.sequence].to_vec(),
+                self.input[1][segment + hop..segment + self.sequence].to_vec(),
+            ]);
+            self.samples_out += hop as u64;
+            self.position += hop as f64 * self.tempo;
+        }
+
+        // Drop input that no future segment can reach
+        let consumed = (self.position as usize).min(self.input[0].len());
+        if consumed > 0 {
+            for channel in self.input.iter_mut() {
+                channel.drain(..consumed);
+            }
+            self.position -= consumed as f64;
+        }
+    }
+
+    /// Offset in [0, 2 * seek] whose segment start correlates best with `tail`
+    fn best_offset(&self, start: usize, tail: &[Vec<f32>; 2]) -> usize {
+        let reference: Vec<f32> = tail[0]
+            .iter()
+            .zip(&tail[1])
+            .map(|(l, r)| (l + r) * 0.5)
+            .collect();
+        let score = |offset: usize| -> f32 {
+            let base = start + offset;
+            let mut dot = 0.0f32;
+            let mut energy = 0.0f32;
+            for (i, &r) in reference.iter().enumerate() {
+                let sample = (self.input[0][base + i] + self.input[1][base + i]) * 0.5;
+                dot += r * sample;
+                energy += sample * sample;
+            }
+            dot / (energy + 1e-9).sqrt()
+        };
+
+        let max_offset = 2 * self.seek;
+        let best_in = |offsets: &mut dyn Iterator<Item = usize>| {
+            offsets
+                .map(|offset| (offset, score(offset)))
+                .fold((self.seek, f32::MIN), |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                })
+                .0
+        };
+        let coarse = best_in(&mut (0..=max_offset).step_by(SEEK_COARSE_STEP));
+        let from = coarse.saturating_sub(SEEK_COARSE_STEP - 1);
+        let to = (coarse + SEEK_COARSE_STEP - 1).min(max_offset);
+        best_in(&mut (from..=to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(factor: f32, frame_rate: Option<u32>) -> SpeedParams {
+        SpeedParams {
+            factor,
+            frame_rate,
+            mute_audio_above: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_and_mute() {
+        assert!(validate(&params(2.0, None)).is_ok());
+        assert!(validate(&params(0.1, None)).is_err());
+        assert!(validate(&params(9.0, None)).is_err());
+        assert!(validate(&params(f32::NAN, None)).is_err());
+        assert!(validate(&params(1.0, Some(0))).is_err());
+
+        let mut fast = params(4.0, None);
+        assert!(!mutes_audio(&fast));
+        fast.mute_audio_above = Some(2.0);
+        assert!(mutes_audio(&fast));
+        assert_eq!(video_filter(&params(2.0, Some(30))), "setpts=PTS/2.000000,fps=30");
+    }
+
+    #[test]
+    fn test_retimer_drops_and_duplicates() {
+        // 30 fps source at 2x into 30 fps output: about every other frame is dropped
+        let mut fast = VideoRetimer::new(&params(2.0, Some(30)));
+        let mut pts = Vec::new();
+        for i in 0..30 {
+            pts.extend(fast.output_pts(i * 1_000_000 / 30, 30));
+        }
+        assert_eq!(pts, (0..15).collect::<Vec<_>>());
+
+        // 30 fps source at 0.5x into 30 fps output: every frame is shown twice
+        let mut slow = VideoRetimer::new(&params(0.5, Some(30)));
+        let mut pts = Vec::new();
+        for i in 0..10 {
+            pts.extend(slow.output_pts(i * 1_000_000 / 30, 30));
+        }
+        assert_eq!(pts, (0..19).collect::<Vec<_>>());
+
+        // Without a frame rate, slow motion keeps one output frame per input frame
+        let mut vfr = VideoRetimer::new(&params(0.5, None));
+        assert_eq!(vfr.output_pts(0, 30), vec![0]);
+        assert_eq!(vfr.output_pts(33_333, 30), vec![2]);
+    }
+
+    #[test]
+    fn test_time_stretch_length_and_pitch() {
+        let rate = 48_000;
+        let input: Vec<f32> = (0..rate)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / rate as f32).sin())
+            .collect();
+
+        for tempo in [0.5, 1.5, 2.0] {
+            let mut stretch = TimeStretch::new(tempo, rate as u32);
+            let (mut left, mut right) = (Vec::new(), Vec::new());
+            for chunk in input.chunks(1024) {
+                stretch.process(chunk, chunk, &mut left, &mut right);
+            }
+            stretch.finish(&mut left, &mut right);
+            assert_eq!(left.len(), (rate as f64 / tempo).round() as usize);
+            assert_eq!(left.len(), right.len());
+
+            // Pitch is kept: zero crossings per second stay around 2 * 440
+            let body = &left[2_000..left.len() - 4_000];
+            let crossings = body
+                .windows(2)
+                .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+                .count();
+            let per_second = crossings as f64 * rate as f64 / body.len() as f64;
+            assert!((per_second - 880.0).abs() < 60.0, "tempo {}: {}", tempo, per_second);
+        }
+    }
+}
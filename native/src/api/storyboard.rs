@@ -0,0 +1,278 @@
+use anyhow::{Context, Result};
+use image::RgbImage;
+use std::fmt::Write as _;
+use tracing::debug;
+
+use crate::api::encode::encode_rgb_image;
+use crate::api::media::{EncoderOptions, OutputFormat, SeekMode, Storyboard};
+use crate::api::resize::Resize;
+use crate::api::sampler::FrameSampler;
+use crate::api::video::{self, check_output_path, get_file_name_without_extension};
+
+/// Largest sprite sheet edge; bigger sheets are slow to load and some decoders refuse them
+const MAX_SHEET_SIZE: u32 = 16_384;
+
+/// Upper bound on tiles, so a tiny interval on a long video can't run for hours
+const MAX_TILES: usize = 10_000;
+
+/// Start times of the tiles: every `interval_ms` from 0, and always at least one
+fn tile_times(duration_ms: u64, interval_ms: u64) -> Vec<u64> {
+    let mut times: Vec<u64> = (0u64..)
+        .map_while(|i| i.checked_mul(interval_ms))
+        .take_while(|time| *time < duration_ms)
+        .take(MAX_TILES + 1)
+        .collect();
+    if times.is_empty() {
+        times.push(0);
+    }
+    times
+}
+
+/// Tile size and how many tiles go across and down one sheet
+#[derive(Debug, Clone, Copy)]
+struct Grid {
+    columns: u32,
+    rows: u32,
+    tile_width: u32,
+    tile_height: u32,
+}
+
+impl Grid {
+    fn per_sheet(&self) -> usize {
+        (self.columns * self.rows) as usize
+    }
+
+    /// Position of tile `index` as (sheet, x, y)
+    fn position(&self, index: usize) -> (usize, u32, u32) {
+        let slot = (index % self.per_sheet()) as u32;
+        (
+            index / self.per_sheet(),
+            (slot % self.columns) * self.tile_width,
+            (slot / self.columns) * self.tile_height,
+        )
+    }
+}
+
+/// WebVTT timestamp, `HH:MM:SS.mmm`
+fn format_vtt_time(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+/// One cue per tile, each pointing at its region of a sheet with a media fragment.
+/// The last cue runs to the end of the video.
+fn build_vtt(
+    times: &[u64],
+    duration_ms: u64,
+    interval_ms: u64,
+    sheet_names: &[String],
+    grid: Grid,
+) -> String {
+    let mut vtt = String::from("WEBVTT\n");
+    for (index, &start) in times.iter().enumerate() {
+        let end = match times.get(index + 1) {
+            Some(&next) => next,
+            None if duration_ms > start => duration_ms,
+            None => start + interval_ms,
+        };
+        let (sheet, x, y) = grid.position(index);
+        let _ = write!(
+            vtt,
+            "\n{} --> {}\n{}#xywh={},{},{},{}\n",
+            format_vtt_time(start),
+            format_vtt_time(end),
+            sheet_names[sheet],
+            x,
+            y,
+            grid.tile_width,
+            grid.tile_height
+        );
+    }
+    vtt
+}
+
+/// Writes the frames every `interval_ms` as `columns` x `rows` grids of tiles into JPEG
+/// sprite sheets, plus a WebVTT file mapping each time range to its tile. Frames are
+/// letterboxed into the tile size; the last sheet is cut down to the rows it uses.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_storyboard(
+    path: &str,
+    output_dir: &str,
+    interval_ms: u64,
+    tile_width: u32,
+    tile_height: u32,
+    columns: u32,
+    rows: u32,
+    encoder_options: &EncoderOptions,
+) -> Result<Storyboard> {
+    if interval_ms == 0 {
+        return Err(anyhow::anyhow!(
+            "Storyboard interval must be greater than 0"
+        ));
+    }
+    if tile_width == 0 || tile_height == 0 || columns == 0 || rows == 0 {
+        return Err(anyhow::anyhow!(
+            "Storyboard tiles and grid must be at least 1x1 (got {}x{} tiles, {}x{} grid)",
+            tile_width,
+            tile_height,
+            columns,
+            rows
+        ));
+    }
+    let (sheet_width, sheet_height) = (
+        tile_width.saturating_mul(columns),
+        tile_height.saturating_mul(rows),
+    );
+    if sheet_width > MAX_SHEET_SIZE || sheet_height > MAX_SHEET_SIZE {
+        return Err(anyhow::anyhow!(
+            "Sprite sheet of {}x{} exceeds the {}px limit; use smaller tiles or fewer columns/rows",
+            sheet_width,
+            sheet_height,
+            MAX_SHEET_SIZE
+        ));
+    }
+
+    video::init_ffmpeg()?;
+
+    #[cfg(target_os = "windows")]
+    let _serialization_guard = video::FFMPEG_SERIALIZATION_MUTEX
+        .lock()
+        .expect("Failed to acquire serialization mutex");
+
+    let mut sampler = FrameSampler::open(path)?;
+    let duration_ms = sampler.duration_ms();
+    let times = tile_times(duration_ms, interval_ms);
+    if times.len() > MAX_TILES {
+        return Err(anyhow::anyhow!(
+            "Storyboard would have {} tiles (max {}); use a longer interval",
+            times.len(),
+            MAX_TILES
+        ));
+    }
+
+    let base_output_dir = check_output_path(output_dir)?;
+    let name = get_file_name_without_extension(path);
+    let grid = Grid {
+        columns,
+        rows,
+        tile_width,
+        tile_height,
+    };
+    let per_sheet = grid.per_sheet();
+    let sheet_count = times.len().div_ceil(per_sheet);
+    let sheet_names: Vec<String> = (0..sheet_count)
+        .map(|i| format!("storyboard_{}_{}.jpg", name.display(), i))
+        .collect();
+
+    let resize = Resize::new((tile_width, tile_height), None, None);
+    let mut sprite_paths = Vec::with_capacity(sheet_count);
+    let mut sheet = RgbImage::new(sheet_width, sheet_height);
+    let mut decoded_any = false;
+
+    for (index, &time_ms) in times.iter().enumerate() {
+        let (sheet_index, x, y) = grid.position(index);
+
+        // A tile whose frame can't be decoded stays black
+        if sampler.frame_at(time_ms, SeekMode::Accurate)?.is_some() {
+            let tile = sampler.image(&resize)?;
+            let offset_x = x + (tile_width - tile.width().min(tile_width)) / 2;
+            let offset_y = y + (tile_height - tile.height().min(tile_height)) / 2;
+            image::imageops::replace(&mut sheet, &tile, offset_x as i64, offset_y as i64);
+            decoded_any = true;
+        }
+
+        let last_in_sheet = index + 1 == times.len() || (index + 1) % per_sheet == 0;
+        if last_in_sheet {
+            let used_rows = ((index % per_sheet) as u32 / columns) + 1;
+            let used =
+                image::imageops::crop_imm(&sheet, 0, 0, sheet_width, used_rows * tile_height)
+                    .to_image();
+            let data = encode_rgb_image(&used, OutputFormat::JPEG, encoder_options)?;
+            let sheet_path = base_output_dir.join(&sheet_names[sheet_index]);
+            std::fs::write(&sheet_path, data).with_context(|| {
+                format!("Failed to write sprite sheet {}", sheet_path.display())
+            })?;
+            debug!(
+                "generate_storyboard - wrote sheet {} with {} rows",
+                sheet_path.display(),
+                used_rows
+            );
+            sprite_paths.push(sheet_path.to_string_lossy().to_string());
+            sheet = RgbImage::new(sheet_width, sheet_height);
+        }
+    }
+
+    if !decoded_any {
+        return Err(anyhow::anyhow!("Could not decode any frame from {}", path));
+    }
+
+    let vtt = build_vtt(&times, duration_ms, interval_ms, &sheet_names, grid);
+    let vtt_path = base_output_dir.join(format!("storyboard_{}.vtt", name.display()));
+    std::fs::write(&vtt_path, vtt)
+        .with_context(|| format!("Failed to write storyboard VTT {}", vtt_path.display()))?;
+
+    Ok(Storyboard {
+        vtt_path: vtt_path.to_string_lossy().to_string(),
+        sprite_paths,
+        tile_count: times.len() as u32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_vtt_time() {
+        assert_eq!(format_vtt_time(0), "00:00:00.000");
+        assert_eq!(format_vtt_time(61_005), "00:01:01.005");
+        assert_eq!(format_vtt_time(3_723_450), "01:02:03.450");
+    }
+
+    #[test]
+    fn test_tile_layout() {
+        assert_eq!(tile_times(10_000, 4_000), vec![0, 4_000, 8_000]);
+        assert_eq!(tile_times(0, 1_000), vec![0]);
+        // Intervals past u32 don't wrap on 32-bit targets, nor overflow near u64::MAX
+        assert_eq!(tile_times(10_000, 1 << 32), vec![0]);
+        assert_eq!(
+            tile_times(u64::MAX, u64::MAX / 2 + 1),
+            vec![0, u64::MAX / 2 + 1]
+        );
+
+        // 3x2 grid of 160x90 tiles: the 7th tile starts the second sheet
+        let grid = Grid {
+            columns: 3,
+            rows: 2,
+            tile_width: 160,
+            tile_height: 90,
+        };
+        assert_eq!(grid.position(0), (0, 0, 0));
+        assert_eq!(grid.position(4), (0, 160, 90));
+        assert_eq!(grid.position(6), (1, 0, 0));
+    }
+
+    #[test]
+    fn test_build_vtt() {
+        let sheets = vec!["a_0.jpg".to_string(), "a_1.jpg".to_string()];
+        let grid = Grid {
+            columns: 2,
+            rows: 1,
+            tile_width: 100,
+            tile_height: 50,
+        };
+        let vtt = build_vtt(&[0, 5_000, 10_000], 12_000, 5_000, &sheets, grid);
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\
+             \n00:00:00.000 --> 00:00:05.000\na_0.jpg#xywh=0,0,100,50\n\
+             \n00:00:05.000 --> 00:00:10.000\na_0.jpg#xywh=100,0,100,50\n\
+             \n00:00:10.000 --> 00:00:12.000\na_1.jpg#xywh=0,0,100,50\n"
+        );
+    }
+}
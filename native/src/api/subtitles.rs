@@ -0,0 +1,485 @@
+use ab_glyph::{point, Font, FontArc, PxScale, ScaleFont};
+use anyhow::{Context, Result};
+use ffmpeg_next::{self as ffmpeg};
+use image::{Rgba, RgbaImage};
+
+use crate::api::layout::{display_rect_to_stored, Rect};
+use crate::api::media::SubtitleParams;
+use crate::api::overlay::{orient_for_stored, pad_to_even, YuvaOverlay};
+
+/// Font used when `SubtitleParams::font_path` is not set (see assets/fonts/DejaVu-LICENSE.txt)
+static DEFAULT_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+/// A single caption with its display window on the source timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+/// Parse SRT or WebVTT content. Both formats are handled the same way: every block with a
+/// `start --> end` line is a cue, and the following non-empty lines are its text.
+pub fn parse_subtitles(content: &str) -> Result<Vec<Cue>> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut cues = Vec::new();
+    let mut lines = content.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((start, end)) = line.split_once("-->") else {
+            continue;
+        };
+        // WebVTT allows cue settings after the end time ("00:01.000 --> 00:02.000 line:0")
+        let end = end.split_whitespace().next().unwrap_or("");
+        let (Some(start_ms), Some(end_ms)) = (parse_timestamp(start.trim()), parse_timestamp(end))
+        else {
+            return Err(anyhow::anyhow!("Invalid subtitle timing line: {}", line));
+        };
+
+        let mut text_lines = Vec::new();
+        while let Some(next) = lines.peek() {
+            if next.trim().is_empty() {
+                break;
+            }
+            text_lines.push(clean_cue_text(next));
+            lines.next();
+        }
+
+        let text = text_lines.join("\n").trim().to_string();
+        if !text.is_empty() && end_ms > start_ms {
+            cues.push(Cue {
+                start_ms,
+                end_ms,
+                text,
+            });
+        }
+    }
+
+    cues.sort_by_key(|cue| cue.start_ms);
+    Ok(cues)
+}
+
+/// Parse `HH:MM:SS,mmm` (SRT) or `[HH:]MM:SS.mmm` (WebVTT) into milliseconds
+fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.trim().replace(',', ".");
+    let mut parts: Vec<&str> = value.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+    let seconds_part = parts.pop()?;
+    let (seconds, fraction) = seconds_part.split_once('.').unwrap_or((seconds_part, "0"));
+    let seconds: u64 = seconds.parse().ok()?;
+    let fraction_digits = &fraction[..fraction.len().min(3)];
+    let millis: u64 = format!("{:0<3}", fraction_digits).parse().ok()?;
+
+    let mut total_minutes = 0u64;
+    for part in parts {
+        total_minutes = total_minutes * 60 + part.parse::<u64>().ok()?;
+    }
+    Some((total_minutes * 60 + seconds) * 1000 + millis)
+}
+
+/// Strip markup (HTML-style tags, ASS override blocks) and decode the common entities
+fn clean_cue_text(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_tag = false;
+    let mut in_override = false;
+    for c in line.chars() {
+        match c {
+            '<' if !in_override => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            '{' if !in_tag => in_override = true,
+            '}' if in_override => in_override = false,
+            _ if !in_tag && !in_override => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/// Width of a single line of text in pixels
+fn measure_line<F: Font>(font: &ab_glyph::PxScaleFont<F>, text: &str) -> f32 {
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = font.glyph_id(c);
+        if let Some(prev) = previous {
+            width += font.kern(prev, id);
+        }
+        width += font.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+/// Greedy word wrap to `max_width`, keeping explicit line breaks.
+/// Words wider than a whole line are split by character.
+fn wrap_text<F: Font>(font: &ab_glyph::PxScaleFont<F>, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+            if measure_line(font, &candidate) <= max_width {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            if measure_line(font, word) <= max_width {
+                current = word.to_string();
+                continue;
+            }
+            for c in word.chars() {
+                current.push(c);
+                if measure_line(font, &current) > max_width && current.chars().count() > 1 {
+                    current.pop();
+                    lines.push(std::mem::take(&mut current));
+                    current.push(c);
+                }
+            }
+        }
+        if !current.is_empty() {
+            lines.push(current);
+        }
+    }
+    lines
+}
+
+/// Resolved caption style in output pixels
+#[derive(Debug, Clone, Copy)]
+struct SubtitleStyle {
+    font_size: f32,
+    color: u32,
+    outline_color: u32,
+    outline_width: u32,
+    bottom_margin: u32,
+}
+
+impl SubtitleStyle {
+    fn resolve(params: &SubtitleParams, display_h: u32) -> Self {
+        let font_size = params
+            .font_size
+            .filter(|s| *s > 0)
+            .unwrap_or(((display_h as f32 * 0.05).round() as u32).max(12));
+        SubtitleStyle {
+            font_size: font_size as f32,
+            color: params.color.unwrap_or(0xFFFFFF),
+            outline_color: params.outline_color.unwrap_or(0x000000),
+            outline_width: params
+                .outline_width
+                .unwrap_or((font_size / 12).max(1)),
+            bottom_margin: params
+                .bottom_margin
+                .unwrap_or((display_h as f32 * 0.06).round() as u32),
+        }
+    }
+}
+
+/// Rasterize centred lines of text with an outline into an RGBA image (even dimensions)
+fn render_lines(font: &FontArc, lines: &[String], style: &SubtitleStyle) -> RgbaImage {
+    let scaled = font.as_scaled(PxScale::from(style.font_size));
+    let pad = style.outline_width + 1;
+    let line_height = (scaled.height() + scaled.line_gap()).ceil();
+    let text_width = lines
+        .iter()
+        .map(|line| measure_line(&scaled, line))
+        .fold(0.0f32, f32::max)
+        .ceil() as u32;
+
+    let width = ((text_width + pad * 2) + 1) & !1;
+    let height = ((line_height as u32 * lines.len() as u32 + pad * 2) + 1) & !1;
+
+    // Glyph coverage
+    let mut coverage = vec![0f32; (width * height) as usize];
+    for (i, line) in lines.iter().enumerate() {
+        let line_width = measure_line(&scaled, line);
+        let mut x = pad as f32 + (text_width as f32 - line_width) / 2.0;
+        let baseline = pad as f32 + i as f32 * line_height + scaled.ascent();
+        let mut previous = None;
+        for c in line.chars() {
+            let id = scaled.glyph_id(c);
+            if let Some(prev) = previous {
+                x += scaled.kern(prev, id);
+            }
+            let glyph = id.with_scale_and_position(style.font_size, point(x, baseline));
+            x += scaled.h_advance(id);
+            previous = Some(id);
+
+            let Some(outlined) = font.outline_glyph(glyph) else {
+                continue;
+            };
+            let bounds = outlined.px_bounds();
+            outlined.draw(|gx, gy, c| {
+                let px = bounds.min.x as i32 + gx as i32;
+                let py = bounds.min.y as i32 + gy as i32;
+                if px >= 0 && py >= 0 && (px as u32) < width && (py as u32) < height {
+                    let idx = (py as u32 * width + px as u32) as usize;
+                    coverage[idx] = coverage[idx].max(c.min(1.0));
+                }
+            });
+        }
+    }
+
+    let outline = dilate(&coverage, width, height, style.outline_width);
+
+    let split = |color: u32| {
+        [
+            ((color >> 16) & 0xFF) as f32,
+            ((color >> 8) & 0xFF) as f32,
+            (color & 0xFF) as f32,
+        ]
+    };
+    let text_rgb = split(style.color);
+    let outline_rgb = split(style.outline_color);
+
+    let mut img = RgbaImage::new(width, height);
+    for (idx, pixel) in img.pixels_mut().enumerate() {
+        let a_text = coverage[idx];
+        let a_outline = outline[idx] * (1.0 - a_text);
+        let alpha = a_text + a_outline;
+        if alpha <= 0.0 {
+            continue;
+        }
+        let mix = |k: usize| ((text_rgb[k] * a_text + outline_rgb[k] * a_outline) / alpha).round() as u8;
+        *pixel = Rgba([mix(0), mix(1), mix(2), (alpha * 255.0).round() as u8]);
+    }
+    img
+}
+
+/// Max filter over a disc of `radius` pixels (the outline mask)
+fn dilate(mask: &[f32], width: u32, height: u32, radius: u32) -> Vec<f32> {
+    if radius == 0 {
+        return vec![0.0; mask.len()];
+    }
+    let r = radius as i32;
+    let offsets: Vec<(i32, i32)> = (-r..=r)
+        .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+        .filter(|(dx, dy)| dx * dx + dy * dy <= r * r)
+        .collect();
+    let mut out = vec![0f32; mask.len()];
+    for y in 0..height as i32 {
+        for x in 0..width as i32 {
+            let mut max = 0f32;
+            for (dx, dy) in &offsets {
+                let (sx, sy) = (x + dx, y + dy);
+                if sx >= 0 && sy >= 0 && sx < width as i32 && sy < height as i32 {
+                    max = max.max(mask[(sy * width as i32 + sx) as usize]);
+                    if max >= 1.0 {
+                        break;
+                    }
+                }
+            }
+            out[(y * width as i32 + x) as usize] = max;
+        }
+    }
+    out
+}
+
+/// Renders the active cues onto YUV420P frames. The rendered overlay is cached until the set
+/// of active cues changes, so text is only rasterized once per cue.
+pub struct SubtitleRenderer {
+    font: FontArc,
+    cues: Vec<Cue>,
+    style: SubtitleStyle,
+    display_w: u32,
+    display_h: u32,
+    rotation: i32,
+    full_range: bool,
+    current: Option<(Vec<usize>, Option<YuvaOverlay>)>,
+}
+
+impl SubtitleRenderer {
+    /// `display_w`/`display_h` are the output dimensions as shown to the user;
+    /// `rotation` is the rotation the output keeps in its display matrix.
+    pub fn new(
+        params: &SubtitleParams,
+        display_w: u32,
+        display_h: u32,
+        rotation: i32,
+        full_range: bool,
+    ) -> Result<Self> {
+        let bytes = std::fs::read(&params.subtitle_path)
+            .with_context(|| format!("Failed to read subtitle file: {}", params.subtitle_path))?;
+        let content = String::from_utf8_lossy(&bytes);
+        let cues = parse_subtitles(&content)
+            .with_context(|| format!("Failed to parse subtitle file: {}", params.subtitle_path))?;
+
+        let font = match params.font_path {
+            Some(ref font_path) => {
+                let data = std::fs::read(font_path)
+                    .with_context(|| format!("Failed to read font file: {}", font_path))?;
+                FontArc::try_from_vec(data)
+                    .map_err(|e| anyhow::anyhow!("Invalid font file {}: {}", font_path, e))?
+            }
+            None => FontArc::try_from_slice(DEFAULT_FONT)
+                .map_err(|e| anyhow::anyhow!("Invalid bundled font: {}", e))?,
+        };
+
+        Ok(SubtitleRenderer {
+            font,
+            cues,
+            style: SubtitleStyle::resolve(params, display_h),
+            display_w,
+            display_h,
+            rotation,
+            full_range,
+            current: None,
+        })
+    }
+
+    pub fn cue_count(&self) -> usize {
+        self.cues.len()
+    }
+
+    /// Blend the captions active at `time_ms` (source timeline) onto `frame`
+    pub fn blend(&mut self, frame: &mut ffmpeg::util::frame::video::Video, time_ms: u64) {
+        let active: Vec<usize> = self
+            .cues
+            .iter()
+            .enumerate()
+            .filter(|(_, cue)| cue.start_ms <= time_ms && time_ms < cue.end_ms)
+            .map(|(i, _)| i)
+            .collect();
+
+        let stale = match self.current {
+            Some((ref indices, _)) => *indices != active,
+            None => true,
+        };
+        if stale {
+            let overlay = if active.is_empty() {
+                None
+            } else {
+                Some(self.render_overlay(&active))
+            };
+            self.current = Some((active, overlay));
+        }
+
+        if let Some((_, Some(ref overlay))) = self.current {
+            overlay.blend(frame);
+        }
+    }
+
+    fn render_overlay(&self, active: &[usize]) -> YuvaOverlay {
+        let text = active
+            .iter()
+            .map(|&i| self.cues[i].text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let scaled = self.font.as_scaled(PxScale::from(self.style.font_size));
+        // Keep 5% of the frame width free on either side
+        let max_width = self.display_w as f32 * 0.9 - (self.style.outline_width * 2) as f32;
+        let lines = wrap_text(&scaled, &text, max_width.max(self.style.font_size));
+        // Even size in display orientation, so the rotated box keeps an even origin too
+        let img = pad_to_even(&render_lines(&self.font, &lines, &self.style));
+
+        let (width, height) = img.dimensions();
+        let x = self.display_w.saturating_sub(width) / 2;
+        let y = self
+            .display_h
+            .saturating_sub(height + self.style.bottom_margin);
+        let stored = display_rect_to_stored(
+            Rect {
+                x: x & !1,
+                y: y & !1,
+                width,
+                height,
+            },
+            self.rotation,
+            self.display_w,
+            self.display_h,
+        );
+        // Chroma is subsampled 2x2, so the stored origin has to be even as well
+        YuvaOverlay::from_rgba(
+            &orient_for_stored(img, self.rotation),
+            stored.x & !1,
+            stored.y & !1,
+            1.0,
+            self.full_range,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_srt() {
+        let srt = "1\n00:00:01,000 --> 00:00:02,500\nHello <i>world</i>\n\n2\n00:00:03,000 --> 00:00:04,000\nLine one\nLine two\n";
+        let cues = parse_subtitles(srt).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(
+            cues[0],
+            Cue {
+                start_ms: 1000,
+                end_ms: 2500,
+                text: "Hello world".to_string()
+            }
+        );
+        assert_eq!(cues[1].text, "Line one\nLine two");
+    }
+
+    #[test]
+    fn test_parse_webvtt_with_settings() {
+        let vtt = "\u{feff}WEBVTT\n\nNOTE a comment\n\n00:01.200 --> 00:03.000 line:0 align:start\n{\\an8}Tom &amp; Jerry\n\n01:00:00.000 --> 01:00:01.5\nLate\n";
+        let cues = parse_subtitles(vtt).unwrap();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start_ms, 1200);
+        assert_eq!(cues[0].end_ms, 3000);
+        assert_eq!(cues[0].text, "Tom & Jerry");
+        assert_eq!(cues[1].start_ms, 3_600_000);
+        assert_eq!(cues[1].end_ms, 3_601_500);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_timing() {
+        assert!(parse_subtitles("1\n00:00:xx,000 --> 00:00:02,000\nText\n").is_err());
+    }
+
+    #[test]
+    fn test_wrap_text_fits_width() {
+        let font = FontArc::try_from_slice(DEFAULT_FONT).unwrap();
+        let scaled = font.as_scaled(PxScale::from(32.0));
+        let lines = wrap_text(
+            &scaled,
+            "The quick brown fox jumps over the lazy dog",
+            300.0,
+        );
+        assert!(lines.len() > 1);
+        for line in &lines {
+            assert!(measure_line(&scaled, line) <= 300.0, "line too wide: {}", line);
+        }
+        assert_eq!(
+            lines.join(" "),
+            "The quick brown fox jumps over the lazy dog"
+        );
+    }
+
+    #[test]
+    fn test_render_lines_draws_text_and_outline() {
+        let font = FontArc::try_from_slice(DEFAULT_FONT).unwrap();
+        let style = SubtitleStyle {
+            font_size: 24.0,
+            color: 0xFFFFFF,
+            outline_color: 0x000000,
+            outline_width: 2,
+            bottom_margin: 0,
+        };
+        let img = render_lines(&font, &["Hi".to_string()], &style);
+        assert_eq!(img.width() % 2, 0);
+        assert_eq!(img.height() % 2, 0);
+        assert!(img.pixels().any(|p| p.0 == [255, 255, 255, 255]));
+        assert!(img.pixels().any(|p| p.0 == [0, 0, 0, 255]));
+    }
+}
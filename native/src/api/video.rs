@@ -1,13 +1,15 @@
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::api::media::{CompressParams, CompressionEstimate, OutputFormat, ThumbnailSizeType};
+use crate::api::encode::encode_rgb_image;
+use crate::api::media::{CompressParams, CompressionEstimate, EncoderOptions, OutputFormat, SeekMode, ThumbnailSizeType};
 use anyhow::{Context, Error, Result};
 use ffmpeg_next::packet::Mut;
 use ffmpeg_next::{self as ffmpeg};
 use tracing::{debug, error, info, warn};
 
 use crate::api::media::VideoThumbnailParams;
+use crate::api::resize::Resize;
 #[cfg(target_os = "windows")]
 use std::sync::Mutex; // Still needed for cache on Windows
 
@@ -17,7 +19,7 @@ static FFMPEG_INIT: std::sync::Once = std::sync::Once::new();
 static mut FFMPEG_INIT_ERROR: Option<anyhow::Error> = None;
 
 #[cfg(target_os = "windows")]
-static FFMPEG_SERIALIZATION_MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
+pub(crate) static FFMPEG_SERIALIZATION_MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
 
 
@@ -37,7 +39,7 @@ fn get_video_info_cache() -> &'static Mutex<HashMap<String, (crate::api::media::
 #[cfg(target_os = "windows")]
 const CACHE_TTL_SECONDS: u64 = 60; // Cache for 60 seconds
 
-fn init_ffmpeg() -> Result<()> {
+pub(crate) fn init_ffmpeg() -> Result<()> {
     // Use Once to ensure thread-safe single initialization
     FFMPEG_INIT.call_once(|| {
         debug!("First call to init_ffmpeg() - initializing FFmpeg");
@@ -131,9 +133,22 @@ fn init_ffmpeg() -> Result<()> {
     Ok(())
 }
 
+/// Open an input file, trying the normalized path first and the original path as fallback.
+/// FFmpeg (built with MinGW) may have issues with Windows path separators.
+pub(crate) fn open_input(path: &str) -> Result<ffmpeg::format::context::Input> {
+    #[cfg(target_os = "windows")]
+    let normalized_path = path.replace('\\', "/");
+    #[cfg(not(target_os = "windows"))]
+    let normalized_path = path.to_string();
+
+    ffmpeg::format::input(&normalized_path)
+        .or_else(|_| ffmpeg::format::input(&path))
+        .with_context(|| format!("Failed to open input file: {}", path))
+}
+
 /// Get video rotation from display matrix side data
 /// Returns rotation in degrees (0, 90, 180, 270) or None if not found
-fn get_video_rotation(stream: &ffmpeg::format::stream::Stream) -> Option<i32> {
+pub(crate) fn get_video_rotation(stream: &ffmpeg::format::stream::Stream) -> Option<i32> {
     use ffmpeg::codec::packet::side_data::Type as SideDataType;
 
     // Check stream side data for display matrix
@@ -210,7 +225,7 @@ fn get_video_rotation_from_format(ictx: &ffmpeg::format::context::Input) -> Opti
 
 /// Get display dimensions accounting for rotation (with format context for MOV files)
 /// Returns (display_width, display_height, rotation_degrees)
-fn get_display_dimensions_with_format(
+pub(crate) fn get_display_dimensions_with_format(
     ictx: &ffmpeg::format::context::Input,
     stream: &ffmpeg::format::stream::Stream,
     stored_width: u32,
@@ -231,7 +246,7 @@ fn get_display_dimensions_with_format(
 /// Priority: VideoToolbox (macOS/iOS) > OpenH264 > software encoder
 /// Note: MediaCodec is disabled to avoid NDK linking issues
 /// On Android, hardware encoders (v4l2m2m, omx, mediacodec) are excluded due to permission issues
-fn find_h264_encoder() -> Result<ffmpeg::Codec> {
+pub(crate) fn find_h264_encoder() -> Result<ffmpeg::Codec> {
     #[cfg(any(target_os = "macos", target_os = "ios"))]
     {
         // VideoToolbox is LGPL-compliant (Apple's framework, not GPL code)
@@ -359,6 +374,70 @@ fn find_h264_encoder() -> Result<ffmpeg::Codec> {
     }
 }
 
+/// Open an H.264 encoder for `width`x`height` YUV420P frames with a 1/`fps` time base.
+/// Mirrors the option handling of `perform_compression`: full options first, then a
+/// bitrate-only configuration for encoders that reject preset/crf/profile.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn open_h264_encoder(
+    codec: ffmpeg::Codec,
+    width: u32,
+    height: u32,
+    fps: u32,
+    bitrate_kbps: u32,
+    crf: Option<u8>,
+    preset: Option<&str>,
+    global_header: bool,
+) -> Result<ffmpeg::codec::encoder::Video> {
+    let setup = || -> Result<ffmpeg::codec::encoder::video::Video> {
+        let encoder_ctx = ffmpeg::codec::context::Context::new_with_codec(codec);
+        let mut encoder_setup = encoder_ctx.encoder().video()?;
+        encoder_setup.set_width(width);
+        encoder_setup.set_height(height);
+        encoder_setup.set_bit_rate((bitrate_kbps * 1000) as usize);
+        encoder_setup.set_time_base(ffmpeg::util::rational::Rational(1, fps as i32));
+        encoder_setup.set_frame_rate(Some(ffmpeg::util::rational::Rational(fps as i32, 1)));
+        encoder_setup.set_format(ffmpeg::format::Pixel::YUV420P);
+        if global_header {
+            encoder_setup.set_flags(ffmpeg::codec::flag::Flags::GLOBAL_HEADER);
+        }
+        Ok(encoder_setup)
+    };
+
+    debug!(
+        "open_h264_encoder - opening {} with dimensions {}x{} @ {} fps, bitrate {} kbps",
+        codec.name(),
+        width,
+        height,
+        fps,
+        bitrate_kbps
+    );
+    let mut opts = ffmpeg::Dictionary::new();
+    if let Some(p) = preset {
+        opts.set("preset", p);
+    }
+    if let Some(crf) = crf {
+        opts.set("crf", &crf.to_string());
+    }
+    opts.set("b", &format!("{}", bitrate_kbps * 1000));
+    opts.set("profile", "high");
+
+    match setup()?.open_as_with(codec, opts) {
+        Ok(encoder) => Ok(encoder),
+        Err(e) => {
+            warn!("open_h264_encoder - failed to open with full options: {:?}. Trying minimal configuration...", e);
+            let mut minimal_opts = ffmpeg::Dictionary::new();
+            minimal_opts.set("b", &format!("{}", bitrate_kbps * 1000));
+            setup()?.open_as_with(codec, minimal_opts).map_err(|e2| {
+                anyhow::anyhow!(
+                    "Failed to open H.264 encoder even with minimal options. Error: {:?}. Codec: {:?}",
+                    e2,
+                    codec.name()
+                )
+            })
+        }
+    }
+}
+
 /// Internal version that doesn't acquire the mutex (assumes caller already holds it)
 fn get_video_info_internal(path: &str) -> Result<crate::api::media::VideoInfo> {
     debug!("get_video_info_internal called with path: {}", path);
@@ -491,7 +570,7 @@ fn get_video_info_internal(path: &str) -> Result<crate::api::media::VideoInfo> {
 
     debug!("get_video_info_internal - getting display dimensions with rotation");
     // Get display dimensions accounting for rotation (check both stream and format metadata for MOV files)
-    let (display_width, display_height, _rotation) =
+    let (display_width, display_height, rotation) =
         get_display_dimensions_with_format(&ictx, &stream, stored_width, stored_height);
     debug!("get_video_info_internal - display dimensions: {}x{}", display_width, display_height);
 
@@ -513,6 +592,8 @@ fn get_video_info_internal(path: &str) -> Result<crate::api::media::VideoInfo> {
 
     let codec_name = decoder.codec().map(|c| c.name().to_string());
     let format_name = ictx.format().name().to_string();
+    let details = crate::api::probe::video_details(&stream, &decoder);
+    let audio = crate::api::probe::audio_summary(&ictx);
 
     // Generate Suggestions using display dimensions (corrected for rotation)
     let suggestions =
@@ -526,6 +607,23 @@ fn get_video_info_internal(path: &str) -> Result<crate::api::media::VideoInfo> {
         bitrate,
         codec_name: Some(codec_name.unwrap_or_default()),
         format_name: Some(format_name),
+        stored_width,
+        stored_height,
+        rotation,
+        avg_frame_rate: details.avg_frame_rate,
+        real_frame_rate: details.real_frame_rate,
+        is_vfr: details.is_vfr,
+        pixel_format: details.pixel_format,
+        bit_depth: details.bit_depth,
+        color_primaries: details.color_primaries,
+        color_transfer: details.color_transfer,
+        color_matrix: details.color_matrix,
+        color_range: details.color_range,
+        hdr_format: details.hdr_format,
+        profile: details.profile,
+        level: details.level,
+        frame_count: details.frame_count,
+        audio,
         suggestions,
     };
     
@@ -679,11 +777,9 @@ pub fn generate_empty_thumbnail(
 ) -> Result<()> {
     let (w, h) = size.dimensions();
     let img = image::ImageBuffer::<image::Rgb<u8>, Vec<u8>>::new(w, h);
-    let _ = match format {
-        OutputFormat::JPEG => img.save_with_format(&output_path, image::ImageFormat::Jpeg),
-        OutputFormat::PNG => img.save_with_format(&output_path, image::ImageFormat::Png),
-        OutputFormat::WEBP => img.save_with_format(&output_path, image::ImageFormat::WebP),
-    };
+    let data = encode_rgb_image(&img, format, &EncoderOptions::default())?;
+    std::fs::write(output_path, data)
+        .with_context(|| format!("Failed to write {}", output_path.display()))?;
 
     Ok(())
 }
@@ -701,7 +797,9 @@ pub fn generate_thumbnail(
     let normalized_path = path.to_string();
 
     // Try process-based thumbnail generation first
-    if let Ok(ffmpeg) = crate::api::ffmpeg_process::FFmpegProcess::new() {
+    if !crate::api::ffmpeg_process::FFmpegProcess::can_encode(params) {
+        debug!("generate_thumbnail - output needs the in-process encoder");
+    } else if let Ok(ffmpeg) = crate::api::ffmpeg_process::FFmpegProcess::new() {
         // Use normalized path for FFmpeg CLI
         match ffmpeg.generate_thumbnail(&normalized_path, params.time_ms.into(), params) {
             Ok(result) => {
@@ -730,15 +828,14 @@ pub fn generate_thumbnail(
     init_ffmpeg().map_err(|e| (e, 0, 0))?;
     
     // Use catch_unwind to prevent crashes
-    let ictx_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        ffmpeg::format::input(&normalized_path)
-            .or_else(|_| ffmpeg::format::input(path)) // Fallback to original
+    let sampler_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        crate::api::sampler::FrameSampler::open(path)
     }));
     
-    let mut ictx = match ictx_result {
-        Ok(Ok(ctx)) => ctx,
+    let mut sampler = match sampler_result {
+        Ok(Ok(sampler)) => sampler,
         Ok(Err(e)) => {
-            return Err((anyhow::anyhow!("Failed to open input: {}. Error: {}", path, e), 0, 0));
+            return Err((e, 0, 0));
         }
         Err(panic) => {
              let panic_msg = if let Some(s) = panic.downcast_ref::<&str>() {
@@ -751,114 +848,93 @@ pub fn generate_thumbnail(
             return Err((anyhow::anyhow!("FFmpeg panic: {}", panic_msg), 0, 0));
         }
     };
-    
-    // ... rest of in-process implementation (simplified somewhat or kept as is but without semaphore) ...
-    // Note: Since I'm replacing the whole function block, I need to include the rest of the logic or impl it.
-    // The previous implementation was huge. I should try to keep it but remove semaphore.
-    // Actually, I can just copy the critical logic back but stripped of semaphore.
-    
-    // Re-implementing the key parts of in-process fallback (simplified to avoid huge code block)
-    // Or I can just trust the process based approach and return error if it fails?
-    // If I remove the semaphore, the fallback is unsafe on Windows for concurrency.
-    // But maybe that's acceptable given the user prompt was about compression estimation.
-    // Let's implement full fallback but without semaphore.
-    
-    let stream = ictx
-        .streams()
-        .best(ffmpeg::media::Type::Video)
-        .ok_or_else(|| (anyhow::anyhow!("No video stream found"), 0, 0))?;
-        
-    let stream_index = stream.index();
-    let video_params = stream.parameters(); // Clone? No, it returns parameters struct
-    
-    // We need to recreate context creation logic
-    let context = ffmpeg::codec::context::Context::from_parameters(video_params)
-        .map_err(|e| (e.into(), 0, 0))?;
-        
-    let mut decoder = context.decoder().video().map_err(|e| (e.into(), 0, 0))?;
-    
-    let stored_width = decoder.width();
-    let stored_height = decoder.height();
-    
-    // Safety check for invalid dimensions
-    if stored_width == 0 || stored_height == 0 {
-         return Err((anyhow::anyhow!("Invalid video dimensions (0x0)"), 0, 0));
-    }
-    
-    // Get rotation
-    let (display_width, display_height, rotation) =
-        get_display_dimensions_with_format(&ictx, &stream, stored_width, stored_height);
-        
-    // Seek
-    let ts = (params.time_ms as i64 * 1000).max(0);
-    // Minimal seek logic for fallback
-    let _ = ictx.seek(ts, (ts - 1000000).max(0)..ts); // Best effort seek
-    
-    let mut scaler = None::<ffmpeg::software::scaling::Context>;
-    let mut decoded = ffmpeg::util::frame::video::Video::empty();
-    let mut rgb_frame = ffmpeg::util::frame::video::Video::empty();
-    
-    for (stream, packet) in ictx.packets() {
-        if stream.index() != stream_index { continue; }
-        
-        if decoder.send_packet(&packet).is_err() { continue; }
-        
-        while decoder.receive_frame(&mut decoded).is_ok() {
-            // Lazy init scaler
-             if scaler.is_none() {
-                let src_w = decoded.width();
-                let src_h = decoded.height();
-                
-                let size = params
-                    .size_type
-                    .unwrap_or(ThumbnailSizeType::Custom((display_width, display_height)))
-                    .dimensions();
-                    
-               let (dst_w, dst_h) = if rotation == 90 || rotation == 270 {
-                    scale_to_fit(src_w, src_h, size.1, size.0)
-                } else {
-                    scale_to_fit(src_w, src_h, size.0, size.1)
-                };
-                
-                 if dst_w == 0 || dst_h == 0 { continue; }
 
-                rgb_frame = ffmpeg::util::frame::video::Video::new(
-                    ffmpeg::format::Pixel::RGB24,
-                    dst_w,
-                    dst_h,
-                );
-                
-                scaler = Some(
-                    ffmpeg::software::scaling::Context::get(
-                        decoded.format(),
-                        src_w,
-                        src_h,
-                        ffmpeg::format::Pixel::RGB24,
-                        dst_w,
-                        dst_h,
-                        ffmpeg::software::scaling::flag::Flags::FAST_BILINEAR,
-                    ).map_err(|e| (e.into(), display_width, display_height))?
-                );
-            }
-            
-            if let Some(ref mut scaler_ctx) = scaler {
-                if scaler_ctx.run(&decoded, &mut rgb_frame).is_ok() {
-                    let output_format = params.format.unwrap_or(crate::api::media::OutputFormat::PNG);
-                    return encode_png_from_rgb_frame_with_rotation(&rgb_frame, output_format, rotation)
-                        .map_err(|e| (e, display_width, display_height));
-                }
-            }
+    let (display_width, display_height) = sampler.display_size();
+    let seek_mode = params.seek_mode.unwrap_or(SeekMode::Accurate);
+    match sampler.frame_at(params.time_ms, seek_mode) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Err((
+                anyhow::anyhow!("Could not decode a frame at {}ms", params.time_ms),
+                display_width,
+                display_height,
+            ));
         }
+        Err(e) => return Err((e, display_width, display_height)),
     }
-    
-    Err((
-        anyhow::anyhow!("Could not decode frame for thumbnail (fallback)"),
-        display_width,
-        display_height,
-    ))
+
+    let resize = Resize::new(
+        params
+            .size_type
+            .unwrap_or(ThumbnailSizeType::Custom((display_width, display_height)))
+            .dimensions(),
+        params.resize_mode,
+        params.allow_upscale,
+    );
+    let output_format = params.format.unwrap_or(crate::api::media::OutputFormat::PNG);
+    let encoder_options = params.encoder_options.unwrap_or_default();
+    sampler
+        .image(&resize)
+        .and_then(|image| {
+            let (width, height) = image.dimensions();
+            Ok((encode_rgb_image(&image, output_format, &encoder_options)?, width, height))
+        })
+        .map_err(|e| (e, display_width, display_height))
+}
+
+/// Scales a decoded frame into the `resize` box and returns it upright, cropped as the
+/// resize mode asks. `scaler` caches the scaling context between frames of the same size.
+pub(crate) fn scale_frame(
+    decoded: &ffmpeg::util::frame::video::Video,
+    resize: &Resize,
+    rotation: i32,
+    scaler: &mut Option<ffmpeg::software::scaling::Context>,
+) -> Result<image::RgbImage> {
+    let src_w = decoded.width();
+    let src_h = decoded.height();
+    if src_w == 0 || src_h == 0 {
+        return Err(anyhow::anyhow!("Invalid thumbnail dimensions"));
+    }
+    let rotated = rotation == 90 || rotation == 270;
+
+    // Plan in display orientation, scale in stored orientation
+    let plan = if rotated {
+        resize.plan(src_h, src_w)
+    } else {
+        resize.plan(src_w, src_h)
+    };
+    let (dst_w, dst_h) = if rotated {
+        (plan.height, plan.width)
+    } else {
+        (plan.width, plan.height)
+    };
+
+    let reusable = scaler.as_ref().is_some_and(|scaler| {
+        let (input, output) = (scaler.input(), scaler.output());
+        (input.format, input.width, input.height) == (decoded.format(), src_w, src_h)
+            && (output.width, output.height) == (dst_w, dst_h)
+    });
+    if !reusable {
+        *scaler = Some(ffmpeg::software::scaling::Context::get(
+            decoded.format(),
+            src_w,
+            src_h,
+            ffmpeg::format::Pixel::RGB24,
+            dst_w,
+            dst_h,
+            ffmpeg::software::scaling::flag::Flags::BILINEAR,
+        )?);
+    }
+
+    let mut rgb_frame =
+        ffmpeg::util::frame::video::Video::new(ffmpeg::format::Pixel::RGB24, dst_w, dst_h);
+    if let Some(scaler) = scaler.as_mut() {
+        scaler.run(decoded, &mut rgb_frame)?;
+    }
+    Ok(plan.crop_rgb(rgb_frame_to_image(&rgb_frame, rotation)?))
 }
 
-fn scale_to_fit(src_w: u32, src_h: u32, max_w: u32, max_h: u32) -> (u32, u32) {
+pub(crate) fn scale_to_fit(src_w: u32, src_h: u32, max_w: u32, max_h: u32) -> (u32, u32) {
     if max_w == 0 || max_h == 0 {
         return (src_w, src_h);
     }
@@ -875,16 +951,12 @@ fn scale_to_fit(src_w: u32, src_h: u32, max_w: u32, max_h: u32) -> (u32, u32) {
     )
 }
 
-fn encode_png_from_rgb_frame_with_rotation(
+/// Copy an RGB24 frame into an image turned upright for its display rotation
+pub(crate) fn rgb_frame_to_image(
     frame: &ffmpeg::util::frame::video::Video,
-    format: OutputFormat,
     rotation: i32,
-) -> Result<(Vec<u8>, u32, u32)> {
-    use image::codecs::jpeg::JpegEncoder;
-    use image::codecs::png::PngEncoder;
-    use image::codecs::webp::WebPEncoder;
-    use image::ExtendedColorType;
-    use image::{ImageBuffer, ImageEncoder, Rgb};
+) -> Result<image::RgbImage> {
+    use image::{ImageBuffer, Rgb};
 
     let width = frame.width();
     let height = frame.height();
@@ -915,60 +987,25 @@ fn encode_png_from_rgb_frame_with_rotation(
     // - rotate270() = 270° CCW = 90° CW
     // So if display matrix says "90° CCW needed", video is stored at 270° CCW, rotate 90° CCW = rotate90()
     // If display matrix says "270° CCW needed", video is stored at 90° CCW, rotate 270° CCW = rotate270()
-    let (final_width, final_height) = match rotation {
+    match rotation {
         90 => {
             // Display matrix says "rotate 90° CCW to display", so video is stored at 270° CCW
             // Rotate 90° CCW (rotate90) to correct it
             img = image::DynamicImage::ImageRgb8(img).rotate90().into_rgb8();
-            (height, width)
         }
         180 => {
             // Display matrix says "rotate 180° CCW to display", so video is stored at 180° CCW
             // Rotate 180° CCW (rotate180) to correct it
             img = image::DynamicImage::ImageRgb8(img).rotate180().into_rgb8();
-            (width, height)
         }
         270 => {
             // Display matrix says "rotate 270° CCW to display", so video is stored at 90° CCW
             // Rotate 270° CCW (rotate270) to correct it
             img = image::DynamicImage::ImageRgb8(img).rotate270().into_rgb8();
-            (height, width)
-        }
-        _ => (width, height),
-    };
-
-    let mut out = Vec::new();
-    match format {
-        OutputFormat::PNG => {
-            let encoder = PngEncoder::new(&mut out);
-            encoder.write_image(
-                img.as_raw(),
-                final_width,
-                final_height,
-                ExtendedColorType::Rgb8,
-            )?;
-        }
-        OutputFormat::JPEG => {
-            let encoder = JpegEncoder::new(&mut out);
-            encoder.write_image(
-                img.as_raw(),
-                final_width,
-                final_height,
-                ExtendedColorType::Rgb8,
-            )?;
-        }
-        OutputFormat::WEBP => {
-            let encoder = WebPEncoder::new_lossless(&mut out);
-            encoder.write_image(
-                img.as_raw(),
-                final_width,
-                final_height,
-                ExtendedColorType::Rgb8,
-            )?;
         }
+        _ => {}
     }
-
-    Ok((out, final_width, final_height))
+    Ok(img)
 }
 
 pub fn get_file_name_without_extension(path: &str) -> PathBuf {
@@ -1374,7 +1411,7 @@ pub fn estimate_compression_with_info(
     })
 }
 
-fn calculate_dimensions(
+pub(crate) fn calculate_dimensions(
     src_w: u32,
     src_h: u32,
     target_w: Option<u32>,
@@ -1393,19 +1430,12 @@ fn calculate_dimensions(
         (None, None) => (src_w, src_h),
     };
 
-    // Anti-Upscaling: Clamp to source dimensions
-    if w > src_w {
-        // If width requested is larger, reset to source width and recalc height to maintain aspect
-        // Actually, simplest is to just cap both? But we want to maintain aspect ratio of the request?
-        // Usually assuming request *intends* to keep source aspect ratio.
-        // Let's just limit to source bounds.
-        w = src_w;
-        h = src_h; // Reset to original if we are trying to blow it up.
-                   // Note: this assumes we are scaling the WHOLE image.
-    }
-    if h > src_h {
-        h = src_h;
-        w = src_w;
+    // Anti-Upscaling: if either side would exceed the source, shrink the requested size
+    // proportionally so it fits inside the source while keeping the requested aspect ratio.
+    if w > src_w || h > src_h {
+        let factor = (src_w as f64 / w as f64).min(src_h as f64 / h as f64);
+        w = ((w as f64 * factor).round() as u32).min(src_w);
+        h = ((h as f64 * factor).round() as u32).min(src_h);
     }
 
     // enforce even
@@ -1436,6 +1466,11 @@ fn perform_compression(
     duration_limit_ms: Option<u64>,
 ) -> Result<CompressionStats> {
     debug!("perform_compression called with path: {}, output: {}", path, output_path);
+
+    if let Some(ref speed) = params.speed {
+        crate::api::speed::validate(speed)?;
+    }
+    crate::api::filters::validate(params)?;
     
     // On Windows, verify MinGW DLLs are accessible only if we fall back to in-process
     // The FFmpeg process approach doesn't need this check in the Rust process
@@ -1463,8 +1498,17 @@ fn perform_compression(
     
     let output_path_str = output_path_resolved.to_string_lossy().to_string();
 
-    debug!("perform_compression - attempting process-based compression");
-    if let Ok(ffmpeg) = crate::api::ffmpeg_process::FFmpegProcess::new() {
+    // Subtitle burn-in is rendered in-process so it doesn't depend on the CLI being built with libass
+    if params.subtitles.is_some() {
+        debug!("perform_compression - subtitles requested, skipping process-based compression");
+    } else if params.normalize_loudness.is_some() {
+        // The process path writes video only, so there is no audio to normalise there
+        debug!("perform_compression - loudness normalisation requested, skipping process-based compression");
+    } else if params.video_filters.is_some() || params.audio_filters.is_some() {
+        // Filter chains run on the linked libavfilter, which was validated above
+        debug!("perform_compression - filters requested, skipping process-based compression");
+    } else if let Ok(ffmpeg) = crate::api::ffmpeg_process::FFmpegProcess::new() {
+        debug!("perform_compression - attempting process-based compression");
         match ffmpeg.compress_segment(
             path,
             &output_path_str,
@@ -1636,6 +1680,29 @@ fn perform_compression(
         (index, decoder)
     };
 
+    // Caller-supplied filters run between the decoder and crop/scale. They get upright
+    // frames, so a transpose or rotate in the chain acts on the video as displayed.
+    let mut video_filter = match params.video_filters {
+        Some(ref spec) => {
+            let stream = ictx
+                .stream(video_stream_index)
+                .ok_or(anyhow::anyhow!("Input video stream not found"))?;
+            let (_, _, rotation) = get_display_dimensions_with_format(
+                &ictx,
+                &stream,
+                decoder.width(),
+                decoder.height(),
+            );
+            Some(crate::api::filters::FilterChain::video(
+                spec,
+                &decoder,
+                stream.time_base(),
+                rotation,
+            )?)
+        }
+        None => None,
+    };
+
     // Find best audio stream (optional)
     let audio_stream_index = ictx
         .streams()
@@ -1657,26 +1724,31 @@ fn perform_compression(
         )
     })?;
 
-    eprintln!("Using H.264 encoder: {}", codec.name());
+    debug!("perform_compression - using H.264 encoder: {}", codec.name());
 
-    // Get stored dimensions and rotation information
-    let stored_width = decoder.width();
-    let stored_height = decoder.height();
+    // Get stored dimensions and rotation information (after any filter that resizes the frames)
+    let (stored_width, stored_height) = video_filter
+        .as_ref()
+        .map_or((decoder.width(), decoder.height()), |filter| filter.output_size());
 
     // Get display dimensions accounting for rotation (needed for correct dimension calculation)
     let input_video_stream = ictx
         .stream(video_stream_index)
         .ok_or(anyhow::anyhow!("Input video stream not found"))?;
 
-    let (display_width, display_height, rotation) =
-        get_display_dimensions_with_format(&ictx, &input_video_stream, stored_width, stored_height);
+    // Filtered frames are already upright
+    let (display_width, display_height, rotation) = if video_filter.is_some() {
+        (stored_width, stored_height, 0)
+    } else {
+        get_display_dimensions_with_format(&ictx, &input_video_stream, stored_width, stored_height)
+    };
 
-    eprintln!(
-        "DEBUG: Stored dimensions: {}x{}, Display dimensions: {}x{}, Rotation: {}°",
+    debug!(
+        "perform_compression - stored {}x{}, display {}x{}, rotation {}°",
         stored_width, stored_height, display_width, display_height, rotation
     );
-    eprintln!(
-        "DEBUG: Target params: width={:?}, height={:?}",
+    debug!(
+        "perform_compression - target params: width={:?}, height={:?}",
         params.width, params.height
     );
 
@@ -1685,8 +1757,8 @@ fn perform_compression(
     let (target_display_width, target_display_height) =
         calculate_dimensions(display_width, display_height, params.width, params.height);
 
-    eprintln!(
-        "DEBUG: Target display dimensions: {}x{}",
+    debug!(
+        "perform_compression - target display dimensions: {}x{}",
         target_display_width, target_display_height
     );
 
@@ -1726,8 +1798,23 @@ fn perform_compression(
             }
         };
 
-    eprintln!(
-        "DEBUG: Final encoder dimensions: {}x{} (rotation={}°, preserve_rotation={}, needs_frame_rotation={})",
+    // Crop / aspect-ratio conversion replaces the plain scaled size with the layout canvas.
+    // Frames are rendered in stored orientation, so the source display matrix stays valid.
+    let mut layout_renderer = crate::api::layout::compute_layout(display_width, display_height, params)
+        .map(|layout| {
+            debug!("perform_compression - output layout: {:?}", layout);
+            crate::api::layout::LayoutRenderer::new(&layout, rotation, display_width, display_height)
+        });
+    let (target_width, target_height, should_preserve_rotation) = match layout_renderer {
+        Some(ref renderer) => {
+            let (w, h) = renderer.output_size();
+            (w, h, true)
+        }
+        None => (target_width, target_height, should_preserve_rotation),
+    };
+
+    debug!(
+        "perform_compression - encoder dimensions: {}x{} (rotation={}°, preserve_rotation={}, needs_frame_rotation={})",
         target_width, target_height, rotation, should_preserve_rotation, needs_frame_rotation
     );
 
@@ -1742,13 +1829,19 @@ fn perform_compression(
     let mut final_bitrate_kbps = params.target_bitrate_kbps;
 
     if input_bitrate_kbps > 0 && final_bitrate_kbps > input_bitrate_kbps {
-        println!(
-            "INFO: Clamping target bitrate {} kbps to input {} kbps",
+        info!(
+            "perform_compression - clamping target bitrate {} kbps to input {} kbps",
             final_bitrate_kbps, input_bitrate_kbps
         );
         final_bitrate_kbps = input_bitrate_kbps;
     }
 
+    // Speed changes retime frames onto the encoder time base (1/frame_rate for constant-rate output)
+    let mut video_retimer = params.speed.as_ref().map(crate::api::speed::VideoRetimer::new);
+    let encoder_rate = video_retimer
+        .as_ref()
+        .map_or(30, |retimer| retimer.time_base_rate(30));
+
     // Create new context for encoder
     let encoder_ctx = ffmpeg::codec::context::Context::new_with_codec(codec);
     let mut encoder_setup = encoder_ctx.encoder().video()?;
@@ -1756,7 +1849,7 @@ fn perform_compression(
     encoder_setup.set_width(target_width);
     encoder_setup.set_height(target_height);
     encoder_setup.set_bit_rate((final_bitrate_kbps * 1000) as usize);
-    encoder_setup.set_time_base(ffmpeg::util::rational::Rational(1, 30));
+    encoder_setup.set_time_base(ffmpeg::util::rational::Rational(1, encoder_rate as i32));
     encoder_setup.set_format(ffmpeg::format::Pixel::YUV420P);
 
     // Preserve color metadata from input (critical for HDR videos)
@@ -1793,8 +1886,13 @@ fn perform_compression(
     // 2. Open encoder
     // Note: FFmpeg's built-in H.264 encoder (without libx264) has limited options
     // We try with options first, then fall back to minimal configuration if needed
-    eprintln!("DEBUG: Opening H.264 encoder: {} with dimensions {}x{}, bitrate {} kbps", 
-        codec.name(), target_width, target_height, final_bitrate_kbps);
+    debug!(
+        "perform_compression - opening H.264 encoder: {} with dimensions {}x{}, bitrate {} kbps",
+        codec.name(),
+        target_width,
+        target_height,
+        final_bitrate_kbps
+    );
     let mut opts = ffmpeg::Dictionary::new();
 
     // Built-in encoder might not support preset, so we only set it if available
@@ -1832,13 +1930,16 @@ fn perform_compression(
         Ok(enc) => enc,
         Err(e) => {
             // If opening with options fails, recreate encoder_setup and try with minimal options
-            eprintln!("Warning: Failed to open H.264 encoder with full options: {:?}. Trying minimal configuration...", e);
+            warn!(
+                "perform_compression - failed to open H.264 encoder with full options: {:?}. Trying minimal configuration...",
+                e
+            );
             let encoder_ctx_minimal = ffmpeg::codec::context::Context::new_with_codec(codec);
             let mut encoder_setup_minimal = encoder_ctx_minimal.encoder().video()?;
             encoder_setup_minimal.set_width(target_width);
             encoder_setup_minimal.set_height(target_height);
             encoder_setup_minimal.set_bit_rate((final_bitrate_kbps * 1000) as usize);
-            encoder_setup_minimal.set_time_base(ffmpeg::util::rational::Rational(1, 30));
+            encoder_setup_minimal.set_time_base(ffmpeg::util::rational::Rational(1, encoder_rate as i32));
             encoder_setup_minimal.set_format(ffmpeg::format::Pixel::YUV420P);
             if global_header {
                 encoder_setup_minimal.set_flags(ffmpeg::codec::flag::Flags::GLOBAL_HEADER);
@@ -1877,6 +1978,8 @@ fn perform_compression(
     use ffmpeg::codec::packet::side_data::Type as SideDataType;
     for side_data in input_video_stream.side_data() {
         match side_data.kind() {
+            // Filtered frames were turned upright, so the source rotation no longer applies
+            SideDataType::DisplayMatrix if video_filter.is_some() => {}
             SideDataType::DisplayMatrix => {
                 let data = side_data.data();
                 if data.len() >= 36 {
@@ -2013,28 +2116,30 @@ fn perform_compression(
 
     // 3. Setup Audio: Copy, Transcode, or Skip (if estimating)
     let mut audio_ost_index = None;
-    let mut audio_decoder: Option<ffmpeg::codec::decoder::Audio> = None;
-    let mut audio_encoder: Option<ffmpeg::codec::encoder::Audio> = None;
-    let mut audio_resampler: Option<ffmpeg::software::resampling::Context> = None;
-    // let mut audio_fifo: Option<ffmpeg::util::fifo::Fifo> = None;
+    let mut audio_transcode: Option<(
+        crate::api::audio::AudioResampler,
+        crate::api::audio::AudioWriter,
+    )> = None;
+    let mut audio_buffer = crate::api::audio::StereoBuffer::default();
 
-    // Manual buffering
-    let mut left_buffer: Option<Vec<f32>> = None;
-    let mut right_buffer: Option<Vec<f32>> = None;
-    let mut audio_pts_counter: Option<i64> = None;
+    // Speed changes stretch the audio (same pitch), or drop it above the mute threshold
+    let mute_audio = params.speed.as_ref().is_some_and(crate::api::speed::mutes_audio);
 
     // Only process audio if NOT estimating
-    if duration_limit_ms.is_none() {
+    if duration_limit_ms.is_none() && !mute_audio {
         if let Some(idx) = audio_stream_index {
             let input_stream = ictx.stream(idx)
                 .ok_or_else(|| anyhow::anyhow!("Audio stream at index {} not found", idx))?;
             let input_codec_id = input_stream.parameters().id();
 
-            // Allow copy for common safe codecs: AAC, MP3
-            let can_copy = matches!(
-                input_codec_id,
-                ffmpeg::codec::Id::AAC | ffmpeg::codec::Id::MP3
-            );
+            // Allow copy for common safe codecs: AAC, MP3 (not when the audio is retimed)
+            let can_copy = params.speed.is_none()
+                && params.normalize_loudness.is_none()
+                && params.audio_filters.is_none()
+                && matches!(
+                    input_codec_id,
+                    ffmpeg::codec::Id::AAC | ffmpeg::codec::Id::MP3
+                );
 
             if can_copy {
                 // COPY PATH
@@ -2042,84 +2147,58 @@ fn perform_compression(
                     ost.set_parameters(input_stream.parameters());
                     audio_ost_index = Some(ost.index());
                 } else {
-                    println!(
-                        "WARN: Could not add audio stream for copy (ID: {:?})",
+                    warn!(
+                        "perform_compression - could not add audio stream for copy (ID: {:?})",
                         input_codec_id
                     );
                 }
             } else {
                 // TRANSCODE PATH (e.g. WMA -> AAC)
-                println!("INFO: Transcoding audio from {:?} to AAC", input_codec_id);
+                info!("perform_compression - transcoding audio from {:?} to AAC", input_codec_id);
 
-                // 3a. Initialize Decoder
-                let mut decoder_ctx =
+                // Use input sample rate if reasonable, else 44.1kHz
+                let source_rate =
                     ffmpeg::codec::context::Context::from_parameters(input_stream.parameters())?
                         .decoder()
-                        .audio()?;
-                // Set channel layout if missing (common in some containers)
-                if decoder_ctx.channel_layout().is_empty() {
-                    decoder_ctx
-                        .set_channel_layout(ffmpeg::util::channel_layout::ChannelLayout::STEREO);
-                }
-
-                // 3b. Initialize Encoder (AAC)
-                let output_codec = ffmpeg::encoder::find(ffmpeg::codec::Id::AAC)
-                    .ok_or(anyhow::anyhow!("AAC codec not found"))?;
-                let encoder_ctx = ffmpeg::codec::context::Context::new_with_codec(output_codec);
-                let mut encoder = encoder_ctx.encoder().audio()?;
-
-                // Configure AAC: Stereo, 44.1kHz or 48kHz, FLTP
-                // Use input sample rate if reasonable, else clamp
-                let target_sample_rate = if decoder_ctx.rate() >= 44100 {
-                    decoder_ctx.rate()
-                } else {
-                    44100
+                        .audio()?
+                        .rate();
+                let target_sample_rate = source_rate.max(44100);
+                // Decoding, audio_filters and speed, shared by the loudness pass and the encode
+                let processed_audio = || -> Result<crate::api::audio::AudioResampler> {
+                    let mut resampler = crate::api::audio::AudioResampler::new(
+                        input_stream.parameters(),
+                        target_sample_rate,
+                    )?;
+                    if let Some(ref spec) = params.audio_filters {
+                        resampler = resampler.filtered(spec, input_stream.time_base())?;
+                    }
+                    if let Some(speed) = params.speed.filter(|speed| speed.factor != 1.0) {
+                        resampler = resampler.stretched(speed.factor as f64);
+                    }
+                    Ok(resampler)
                 };
-                encoder.set_rate(target_sample_rate as i32);
-                encoder.set_channel_layout(ffmpeg::util::channel_layout::ChannelLayout::STEREO);
-                // encoder.set_channels(2); // Removed: implied by layout or not available on wrapper
-                // FLTP: Float Planar
-                encoder.set_format(ffmpeg::format::Sample::F32(
-                    ffmpeg::format::sample::Type::Planar,
-                ));
-                encoder.set_bit_rate(192_000); // 192kbps
-                encoder.set_time_base(ffmpeg::util::rational::Rational(
-                    1,
-                    target_sample_rate as i32,
-                ));
 
-                // Global headers likely needed for MP4 container
-                if global_header {
-                    encoder.set_flags(ffmpeg::codec::flag::Flags::GLOBAL_HEADER);
+                let mut writer = crate::api::audio::AudioWriter::aac(&mut octx, target_sample_rate)?;
+                audio_ost_index = Some(writer.stream_index());
+
+                if let Some(ref target) = params.normalize_loudness {
+                    // First pass over the same processed samples that get encoded
+                    let info = crate::api::loudness::measure_processed(
+                        &mut open_input(path)?,
+                        idx,
+                        processed_audio()?,
+                        target_sample_rate,
+                        2,
+                    )?;
+                    let gain = crate::api::loudness::normalization_gain(&info, target);
+                    debug!(
+                        "perform_compression - audio at {:.1} LUFS, normalising to {:.1} LUFS (gain {:.2})",
+                        info.integrated_lufs, target.target_lufs, gain
+                    );
+                    writer.set_gain(gain);
                 }
 
-                let encoder_opened = encoder
-                    .open_as(output_codec)
-                    .map_err(|e| anyhow::anyhow!("Failed to open AAC encoder: {:?}", e))?;
-
-                // 3c. Add Output Stream
-                let mut ost = octx.add_stream(output_codec)?;
-                ost.set_parameters(&encoder_opened);
-                audio_ost_index = Some(ost.index());
-
-                // 3d. Initialize Resampler
-                let resampler = ffmpeg::software::resampling::Context::get(
-                    decoder_ctx.format(),
-                    decoder_ctx.channel_layout(),
-                    decoder_ctx.rate(),
-                    encoder_opened.format(),
-                    encoder_opened.channel_layout(),
-                    encoder_opened.rate(),
-                )?;
-
-                // 3e. Initialize Buffers (done lazily or here)
-                left_buffer = Some(Vec::with_capacity(4096));
-                right_buffer = Some(Vec::with_capacity(4096));
-                audio_pts_counter = Some(0);
-
-                audio_decoder = Some(decoder_ctx);
-                audio_encoder = Some(encoder_opened);
-                audio_resampler = Some(resampler);
+                audio_transcode = Some((processed_audio()?, writer));
             }
         }
     }
@@ -2139,15 +2218,52 @@ fn perform_compression(
     // The scaler will respect the color_range set on the input and output frames
     // When explicit dimensions are provided and rotation is present, we scale from stored dimensions
     // directly to target dimensions (rotation will be handled by not preserving rotation metadata)
-    let mut scaler = ffmpeg::software::scaling::Context::get(
-        decoder.format(),
-        decoder.width(),
-        decoder.height(),
-        ffmpeg::format::Pixel::YUV420P,
-        target_width,
-        target_height,
-        ffmpeg::software::scaling::flag::Flags::BILINEAR,
-    )?;
+    // With a layout the renderer owns its scalers instead
+    let mut scaler = if layout_renderer.is_none() {
+        Some(ffmpeg::software::scaling::Context::get(
+            decoder.format(),
+            stored_width,
+            stored_height,
+            ffmpeg::format::Pixel::YUV420P,
+            target_width,
+            target_height,
+            ffmpeg::software::scaling::flag::Flags::BILINEAR,
+        )?)
+    } else {
+        None
+    };
+
+    // Overlays are prepared once for the output geometry, in stored orientation like the frames
+    let output_rotation = if should_preserve_rotation { rotation } else { 0 };
+    let (output_display_width, output_display_height) = match output_rotation {
+        90 | 270 => (target_height, target_width),
+        _ => (target_width, target_height),
+    };
+    let output_full_range = input_color_range == ffmpeg::ffi::AVColorRange::AVCOL_RANGE_JPEG;
+    let watermark = match params.watermark {
+        Some(ref watermark_params) => Some(crate::api::overlay::Watermark::prepare(
+            watermark_params,
+            output_display_width,
+            output_display_height,
+            output_rotation,
+            output_full_range,
+        )?),
+        None => None,
+    };
+    let mut subtitle_renderer = match params.subtitles {
+        Some(ref subtitle_params) => {
+            let renderer = crate::api::subtitles::SubtitleRenderer::new(
+                subtitle_params,
+                output_display_width,
+                output_display_height,
+                output_rotation,
+                output_full_range,
+            )?;
+            debug!("perform_compression - burning in {} subtitle cues", renderer.cue_count());
+            Some(renderer)
+        }
+        None => None,
+    };
 
     let mut decoded = ffmpeg::util::frame::video::Video::empty();
     let mut converted = ffmpeg::util::frame::video::Video::new(
@@ -2172,9 +2288,6 @@ fn perform_compression(
     // use ffmpeg::util::color::Range;
     // converted.set_color_range(Range::Limited);
 
-    // Audio frames reusable
-    let mut decoded_audio = ffmpeg::util::frame::audio::Audio::empty();
-
     // Handle seek if start_ms is provided
     if let Some(start) = start_ms {
         let position = (start as i64) * ffmpeg::ffi::AV_TIME_BASE as i64 / 1000;
@@ -2214,13 +2327,38 @@ fn perform_compression(
     // Track if we've added rotation side data to first packet
     let mut rotation_side_data_added = false;
 
-    for (stream, mut packet) in ictx.packets() {
-        if stream.index() == video_stream_index {
-            decoder
-                .send_packet(&packet)
-                .context("Decoder send_packet failed")?;
+    // A trailing `None` drains the decoder and the video filters at end of stream
+    let mut video_filter_flushed = false;
+    let packets = ictx
+        .packets()
+        .map(|(stream, packet)| (stream.index(), Some(packet)))
+        .chain(std::iter::once((video_stream_index, None)));
+    for (stream_index, packet) in packets {
+        if stream_index == video_stream_index {
+            match packet {
+                Some(ref packet) => decoder
+                    .send_packet(packet)
+                    .context("Decoder send_packet failed")?,
+                None => decoder.send_eof().context("Decoder send_eof failed")?,
+            }
+
+            loop {
+                let received = match video_filter {
+                    Some(ref mut filter) => filter.receive(&mut decoder, &mut decoded)?,
+                    None => decoder.receive_frame(&mut decoded).is_ok(),
+                };
+                if !received {
+                    // Filters such as reverse, tpad or fps hold frames back until end of stream
+                    match video_filter {
+                        Some(ref mut filter) if packet.is_none() && !video_filter_flushed => {
+                            filter.flush()?;
+                            video_filter_flushed = true;
+                            continue;
+                        }
+                        _ => break,
+                    }
+                }
 
-            while decoder.receive_frame(&mut decoded).is_ok() {
                 // Start timer on first decoded frame to exclude pre-roll decoding
                 if processing_start_time.is_none() {
                     processing_start_time = Some(std::time::Instant::now());
@@ -2257,9 +2395,13 @@ fn perform_compression(
                     stats_start_size = encoded_size_bytes;
                 }
 
-                scaler
-                    .run(&decoded, &mut converted)
-                    .context("Scaler run failed")?;
+                if let Some(ref mut renderer) = layout_renderer {
+                    renderer.render(&mut decoded, &mut converted)?;
+                } else if let Some(ref mut scaler) = scaler {
+                    scaler
+                        .run(&decoded, &mut converted)
+                        .context("Scaler run failed")?;
+                }
 
                 // Preserve color metadata from decoded frame to converted frame
                 // This is critical for HDR videos to maintain proper tone mapping
@@ -2277,8 +2419,25 @@ fn perform_compression(
                     }
                 }
 
-                // Recalculate PTS for the new stream
-                if let Some(pts) = decoded.pts() {
+                let source_time_ms = (pts_us.max(0) / 1000) as u64;
+                if let Some(ref watermark) = watermark {
+                    if watermark.is_active(source_time_ms) {
+                        watermark.blend(&mut converted);
+                    }
+                }
+                if let Some(ref mut renderer) = subtitle_renderer {
+                    renderer.blend(&mut converted, source_time_ms);
+                }
+
+                // Recalculate PTS for the new stream. A speed change can drop the frame
+                // (no PTS) or duplicate it (several PTS) to keep the output frame rate.
+                let output_pts: Vec<Option<i64>> = if let Some(ref mut retimer) = video_retimer {
+                    retimer
+                        .output_pts(relative_us, encoder_rate)
+                        .into_iter()
+                        .map(Some)
+                        .collect()
+                } else if let Some(pts) = decoded.pts() {
                     // Normalize to start at 0
                     let normalized_pts = pts - first_frame_pts.unwrap_or(0);
                     let rescaled_pts = unsafe {
@@ -2288,119 +2447,123 @@ fn perform_compression(
                             encoder.time_base().into(),
                         )
                     };
-                    converted.set_pts(Some(rescaled_pts));
+                    vec![Some(rescaled_pts)]
                 } else {
-                    converted.set_pts(None);
-                }
+                    vec![None]
+                };
 
-                encoder
-                    .send_frame(&converted)
-                    .context("Encoder send_frame failed")?;
-
-                let mut encoded = ffmpeg::Packet::empty();
-                while encoder.receive_packet(&mut encoded).is_ok() {
-                    encoded.set_stream(video_ost_index);
-                    encoded.rescale_ts(encoder.time_base(), ost_time_base);
-
-                    // Copy side data to keyframes (preserves rotation and HDR metadata)
-                    // Also add to all packets as fallback since some muxers read from packets
-                    if encoded.is_key() || !rotation_side_data_added {
-                        unsafe {
-                            use ffmpeg::codec::packet::side_data::Type as SideDataType;
-                            use ffmpeg::ffi;
-                            let pkt = encoded.as_mut_ptr();
-
-                            if !pkt.is_null() {
-                                // Add display matrix side data (rotation) - only if we should preserve rotation
-                                if should_preserve_rotation {
-                                    if let Some(ref matrix_data) = display_matrix_data {
-                                        if matrix_data.len() >= 36 {
-                                            let side_data_type: ffi::AVPacketSideDataType =
-                                                SideDataType::DisplayMatrix.into();
-                                            let side_data_ptr = ffi::av_packet_new_side_data(
-                                                pkt,
-                                                side_data_type,
-                                                matrix_data.len(),
-                                            );
-                                            if !side_data_ptr.is_null() {
-                                                std::ptr::copy_nonoverlapping(
-                                                    matrix_data.as_ptr(),
-                                                    side_data_ptr,
+                for pts in output_pts {
+                    converted.set_pts(pts);
+
+                    encoder
+                        .send_frame(&converted)
+                        .context("Encoder send_frame failed")?;
+
+                    let mut encoded = ffmpeg::Packet::empty();
+                    while encoder.receive_packet(&mut encoded).is_ok() {
+                        encoded.set_stream(video_ost_index);
+                        encoded.rescale_ts(encoder.time_base(), ost_time_base);
+
+                        // Copy side data to keyframes (preserves rotation and HDR metadata)
+                        // Also add to all packets as fallback since some muxers read from packets
+                        if encoded.is_key() || !rotation_side_data_added {
+                            unsafe {
+                                use ffmpeg::codec::packet::side_data::Type as SideDataType;
+                                use ffmpeg::ffi;
+                                let pkt = encoded.as_mut_ptr();
+
+                                if !pkt.is_null() {
+                                    // Add display matrix side data (rotation) - only if we should preserve rotation
+                                    if should_preserve_rotation {
+                                        if let Some(ref matrix_data) = display_matrix_data {
+                                            if matrix_data.len() >= 36 {
+                                                let side_data_type: ffi::AVPacketSideDataType =
+                                                    SideDataType::DisplayMatrix.into();
+                                                let side_data_ptr = ffi::av_packet_new_side_data(
+                                                    pkt,
+                                                    side_data_type,
                                                     matrix_data.len(),
                                                 );
-                                                if encoded.is_key() {
-                                                    rotation_side_data_added = true;
+                                                if !side_data_ptr.is_null() {
+                                                    std::ptr::copy_nonoverlapping(
+                                                        matrix_data.as_ptr(),
+                                                        side_data_ptr,
+                                                        matrix_data.len(),
+                                                    );
+                                                    if encoded.is_key() {
+                                                        rotation_side_data_added = true;
+                                                    }
                                                 }
                                             }
                                         }
                                     }
-                                }
 
-                                // Add mastering display metadata (HDR10 color volume)
-                                if let Some(ref md_data) = mastering_display_data {
-                                    let side_data_type: ffi::AVPacketSideDataType =
-                                        SideDataType::MasteringDisplayMetadata.into();
-                                    let side_data_ptr = ffi::av_packet_new_side_data(
-                                        pkt,
-                                        side_data_type,
-                                        md_data.len(),
-                                    );
-                                    if !side_data_ptr.is_null() {
-                                        std::ptr::copy_nonoverlapping(
-                                            md_data.as_ptr(),
-                                            side_data_ptr,
+                                    // Add mastering display metadata (HDR10 color volume)
+                                    if let Some(ref md_data) = mastering_display_data {
+                                        let side_data_type: ffi::AVPacketSideDataType =
+                                            SideDataType::MasteringDisplayMetadata.into();
+                                        let side_data_ptr = ffi::av_packet_new_side_data(
+                                            pkt,
+                                            side_data_type,
                                             md_data.len(),
                                         );
+                                        if !side_data_ptr.is_null() {
+                                            std::ptr::copy_nonoverlapping(
+                                                md_data.as_ptr(),
+                                                side_data_ptr,
+                                                md_data.len(),
+                                            );
+                                        }
                                     }
-                                }
 
-                                // Add content light level (HDR10 peak brightness)
-                                if let Some(ref cll_data) = content_light_level_data {
-                                    let side_data_type: ffi::AVPacketSideDataType =
-                                        SideDataType::ContentLightLevel.into();
-                                    let side_data_ptr = ffi::av_packet_new_side_data(
-                                        pkt,
-                                        side_data_type,
-                                        cll_data.len(),
-                                    );
-                                    if !side_data_ptr.is_null() {
-                                        std::ptr::copy_nonoverlapping(
-                                            cll_data.as_ptr(),
-                                            side_data_ptr,
+                                    // Add content light level (HDR10 peak brightness)
+                                    if let Some(ref cll_data) = content_light_level_data {
+                                        let side_data_type: ffi::AVPacketSideDataType =
+                                            SideDataType::ContentLightLevel.into();
+                                        let side_data_ptr = ffi::av_packet_new_side_data(
+                                            pkt,
+                                            side_data_type,
                                             cll_data.len(),
                                         );
+                                        if !side_data_ptr.is_null() {
+                                            std::ptr::copy_nonoverlapping(
+                                                cll_data.as_ptr(),
+                                                side_data_ptr,
+                                                cll_data.len(),
+                                            );
+                                        }
                                     }
                                 }
                             }
                         }
-                    }
 
-                    // Ensure DTS is monotonically increasing and PTS >= DTS
-                    if let Some(dts) = encoded.dts() {
-                        if let Some(last_dts) = last_video_dts {
-                            if dts <= last_dts {
-                                // Force DTS to be greater than last DTS
-                                encoded.set_dts(Some(last_dts + 1));
+                        // Ensure DTS is monotonically increasing and PTS >= DTS
+                        if let Some(dts) = encoded.dts() {
+                            if let Some(last_dts) = last_video_dts {
+                                if dts <= last_dts {
+                                    // Force DTS to be greater than last DTS
+                                    encoded.set_dts(Some(last_dts + 1));
+                                }
                             }
-                        }
-                        // Ensure PTS >= DTS (required for valid MP4)
-                        if let Some(pts) = encoded.pts() {
-                            if pts < encoded.dts().unwrap_or(dts) {
-                                encoded.set_pts(Some(encoded.dts().unwrap_or(dts)));
+                            // Ensure PTS >= DTS (required for valid MP4)
+                            if let Some(pts) = encoded.pts() {
+                                if pts < encoded.dts().unwrap_or(dts) {
+                                    encoded.set_pts(Some(encoded.dts().unwrap_or(dts)));
+                                }
                             }
+                            last_video_dts = encoded.dts();
+                        } else if let Some(last_dts) = last_video_dts {
+                            // If no DTS, set it to last_dts + 1
+                            encoded.set_dts(Some(last_dts + 1));
+                            last_video_dts = Some(last_dts + 1);
                         }
-                        last_video_dts = encoded.dts();
-                    } else if let Some(last_dts) = last_video_dts {
-                        // If no DTS, set it to last_dts + 1
-                        encoded.set_dts(Some(last_dts + 1));
-                        last_video_dts = Some(last_dts + 1);
-                    }
 
-                    encoded_size_bytes += encoded.size() as u64;
+                        encoded_size_bytes += encoded.size() as u64;
 
-                    encoded
-                        .write_interleaved(&mut octx)
-                        .context("Write interleaved failed")?;
+                        encoded
+                            .write_interleaved(&mut octx)
+                            .context("Write interleaved failed")?;
+                    }
                 }
             }
 
@@ -2410,125 +2573,17 @@ fn perform_compression(
                     break;
                 }
             }
-        } else if Some(stream.index()) == audio_stream_index {
+        } else if Some(stream_index) == audio_stream_index {
+            let Some(mut packet) = packet else {
+                continue;
+            };
             // Processing Audio Packet
             if let Some(out_idx) = audio_ost_index {
                 // Determine if we are Copying or Transcoding
-                if audio_encoder.is_some() {
+                if let Some((ref mut resampler, ref mut writer)) = audio_transcode {
                     // --- TRANSCODE PATH ---
-                    if let (Some(decoder), Some(resampler), Some(encoder)) = (
-                        audio_decoder.as_mut(),
-                        audio_resampler.as_mut(),
-                        audio_encoder.as_mut(),
-                    ) {
-                        decoder.send_packet(&packet)?;
-                        while decoder.receive_frame(&mut decoded_audio).is_ok() {
-                            // Skip empty or invalid audio frames
-                            if decoded_audio.samples() == 0 {
-                                continue;
-                            }
-                            // Resample
-                            // Calculate output samples: use max to ensure we have enough space
-                            // FFmpeg resampler formula: ceil(in_samples * out_rate / in_rate)
-                            let in_rate = decoder.rate() as u64;
-                            let out_rate = encoder.rate() as u64;
-                            let in_samples = decoded_audio.samples() as u64;
-
-                            // Calculate exact output samples needed (with ceiling for rounding)
-                            let out_samples_est =
-                                (in_samples * out_rate).div_ceil(in_rate) as usize;
-                            // Add some padding for resampler delay/compensation (typically 32-64 samples)
-                            let out_samples_with_padding = out_samples_est + 64;
-
-                            let mut resampled = ffmpeg::util::frame::audio::Audio::new(
-                                encoder.format(),
-                                out_samples_with_padding,
-                                encoder.channel_layout(),
-                            );
-                            resampled.set_rate(encoder.rate());
-
-                            resampler.run(&decoded_audio, &mut resampled)?;
-
-                            // Get actual number of samples produced by resampler
-                            let actual_samples = resampled.samples();
-
-                            // Manual Buffering
-                            // Append planar data to buffers
-                            if left_buffer.is_none() {
-                                left_buffer = Some(Vec::new());
-                            }
-                            if right_buffer.is_none() {
-                                right_buffer = Some(Vec::new());
-                            }
-
-                            // Buffers are initialized above (lines 1909-1918), so unwrap is safe
-                            // But add defensive check for better error messages
-                            let lb = left_buffer.as_mut().expect("Left audio buffer should be initialized");
-                            let rb = right_buffer.as_mut().expect("Right audio buffer should be initialized");
-
-                            if resampled.planes() >= 2 {
-                                let p0 = resampled.plane::<f32>(0);
-                                let p1 = resampled.plane::<f32>(1);
-                                // Only copy the actual samples produced, not the padding
-                                lb.extend_from_slice(&p0[0..actual_samples]);
-                                rb.extend_from_slice(&p1[0..actual_samples]);
-                            } else {
-                                // Mono to Stereo: duplicate the single plane
-                                let p0 = resampled.plane::<f32>(0);
-                                lb.extend_from_slice(&p0[0..actual_samples]);
-                                rb.extend_from_slice(&p0[0..actual_samples]);
-                            }
-
-                            // Encode chunks
-                            let frame_size = encoder.frame_size() as usize;
-                            // frame_size is usually 1024 for AAC.
-
-                            while lb.len() >= frame_size && rb.len() >= frame_size {
-                                let mut frame_to_encode = ffmpeg::util::frame::audio::Audio::new(
-                                    encoder.format(),
-                                    frame_size,
-                                    encoder.channel_layout(),
-                                );
-                                frame_to_encode.set_rate(encoder.rate());
-
-                                // Copy data sequentially to satisfy borrow checker
-                                {
-                                    let p0 = frame_to_encode.plane_mut::<f32>(0);
-                                    p0.copy_from_slice(&lb[0..frame_size]);
-                                }
-                                {
-                                    let p1 = frame_to_encode.plane_mut::<f32>(1);
-                                    p1.copy_from_slice(&rb[0..frame_size]);
-                                }
-
-                                // Drain buffer
-                                lb.drain(0..frame_size);
-                                rb.drain(0..frame_size);
-
-                                frame_to_encode.set_pts(audio_pts_counter);
-                                if let Some(pts) = audio_pts_counter {
-                                    audio_pts_counter = Some(pts + frame_size as i64);
-                                } else {
-                                    audio_pts_counter = Some(0);
-                                }
-
-                                encoder.send_frame(&frame_to_encode)?;
-
-                                let mut encoded_pkt = ffmpeg::Packet::empty();
-                                while encoder.receive_packet(&mut encoded_pkt).is_ok() {
-                                    encoded_pkt.set_stream(out_idx);
-                                    if let Some(audio_tb) = audio_ost_time_base {
-                                        encoded_pkt.rescale_ts(
-                                            encoder.time_base(),
-                                            audio_tb,
-                                        );
-                                    }
-                                    encoded_size_bytes += encoded_pkt.size() as u64;
-                                    encoded_pkt.write_interleaved(&mut octx).ok();
-                                }
-                            }
-                        }
-                    }
+                    resampler.send_packet(&packet, &mut audio_buffer)?;
+                    writer.write(&mut audio_buffer, &mut octx)?;
                 } else if let (Some(out_tb), Some(in_tb)) =
                     (audio_ost_time_base, audio_ist_time_base)
                 {
@@ -2590,128 +2645,10 @@ fn perform_compression(
     }
 
     // Flush Audio Encoder (Transcode path only)
-    if let (Some(encoder), Some(out_idx), Some(out_tb)) =
-        (audio_encoder.as_mut(), audio_ost_index, audio_ost_time_base)
-    {
-        // First, flush the resampler to get any remaining samples
-        if let (Some(_decoder), Some(resampler), Some(lb), Some(rb)) = (
-            audio_decoder.as_mut(),
-            audio_resampler.as_mut(),
-            left_buffer.as_mut(),
-            right_buffer.as_mut(),
-        ) {
-            // Flush resampler with empty frame
-            let empty_frame = ffmpeg::util::frame::audio::Audio::empty();
-            let mut flushed_resampled = ffmpeg::util::frame::audio::Audio::new(
-                encoder.format(),
-                1024, // Allocate space for flushed samples
-                encoder.channel_layout(),
-            );
-            flushed_resampled.set_rate(encoder.rate());
-
-            // Try to flush resampler (may not produce output, but worth trying)
-            if resampler.run(&empty_frame, &mut flushed_resampled).is_ok() {
-                let flushed_samples = flushed_resampled.samples();
-                if flushed_samples > 0 {
-                    if flushed_resampled.planes() >= 2 {
-                        let p0 = flushed_resampled.plane::<f32>(0);
-                        let p1 = flushed_resampled.plane::<f32>(1);
-                        lb.extend_from_slice(&p0[0..flushed_samples]);
-                        rb.extend_from_slice(&p1[0..flushed_samples]);
-                    } else {
-                        let p0 = flushed_resampled.plane::<f32>(0);
-                        lb.extend_from_slice(&p0[0..flushed_samples]);
-                        rb.extend_from_slice(&p0[0..flushed_samples]);
-                    }
-                }
-            }
-        }
-
-        // Flush remaining buffer
-        if let (Some(lb), Some(rb)) = (left_buffer.as_mut(), right_buffer.as_mut()) {
-            let frame_size = encoder.frame_size() as usize;
-
-            // Encode any remaining complete frames
-            while lb.len() >= frame_size && rb.len() >= frame_size {
-                let mut frame_to_encode = ffmpeg::util::frame::audio::Audio::new(
-                    encoder.format(),
-                    frame_size,
-                    encoder.channel_layout(),
-                );
-                frame_to_encode.set_rate(encoder.rate());
-                {
-                    let p0 = frame_to_encode.plane_mut::<f32>(0);
-                    p0.copy_from_slice(&lb[0..frame_size]);
-                }
-                {
-                    let p1 = frame_to_encode.plane_mut::<f32>(1);
-                    p1.copy_from_slice(&rb[0..frame_size]);
-                }
-                frame_to_encode.set_pts(audio_pts_counter);
-                if let Some(pts) = audio_pts_counter {
-                    audio_pts_counter = Some(pts + frame_size as i64);
-                }
-                encoder.send_frame(&frame_to_encode).ok();
-
-                let mut encoded_pkt = ffmpeg::Packet::empty();
-                while encoder.receive_packet(&mut encoded_pkt).is_ok() {
-                    encoded_pkt.set_stream(out_idx);
-                    encoded_pkt.rescale_ts(encoder.time_base(), out_tb);
-                    encoded_size_bytes += encoded_pkt.size() as u64;
-                    encoded_pkt.write_interleaved(&mut octx).ok();
-                }
-
-                lb.drain(0..frame_size);
-                rb.drain(0..frame_size);
-            }
-
-            // Pad and encode final incomplete frame if any samples remain
-            if !lb.is_empty() {
-                let pad_len = frame_size - lb.len();
-                if pad_len > 0 && pad_len < frame_size {
-                    // Pad with silence
-                    lb.extend(std::iter::repeat_n(0.0, pad_len));
-                    rb.extend(std::iter::repeat_n(0.0, pad_len));
-                }
-
-                if lb.len() >= frame_size {
-                    let mut frame_to_encode = ffmpeg::util::frame::audio::Audio::new(
-                        encoder.format(),
-                        frame_size,
-                        encoder.channel_layout(),
-                    );
-                    frame_to_encode.set_rate(encoder.rate());
-                    {
-                        let p0 = frame_to_encode.plane_mut::<f32>(0);
-                        p0.copy_from_slice(&lb[0..frame_size]);
-                    }
-                    {
-                        let p1 = frame_to_encode.plane_mut::<f32>(1);
-                        p1.copy_from_slice(&rb[0..frame_size]);
-                    }
-                    frame_to_encode.set_pts(audio_pts_counter);
-                    encoder.send_frame(&frame_to_encode).ok();
-
-                    let mut encoded_pkt = ffmpeg::Packet::empty();
-                    while encoder.receive_packet(&mut encoded_pkt).is_ok() {
-                        encoded_pkt.set_stream(out_idx);
-                        encoded_pkt.rescale_ts(encoder.time_base(), out_tb);
-                        encoded_size_bytes += encoded_pkt.size() as u64;
-                        encoded_pkt.write_interleaved(&mut octx).ok();
-                    }
-                }
-            }
-        }
-
-        // Flush encoder
-        encoder.send_eof().ok();
-        let mut encoded_pkt = ffmpeg::Packet::empty();
-        while encoder.receive_packet(&mut encoded_pkt).is_ok() {
-            encoded_pkt.set_stream(out_idx);
-            encoded_pkt.rescale_ts(encoder.time_base(), out_tb);
-            encoded_size_bytes += encoded_pkt.size() as u64;
-            encoded_pkt.write_interleaved(&mut octx).ok();
-        }
+    if let Some((ref mut resampler, ref mut writer)) = audio_transcode {
+        resampler.flush(&mut audio_buffer)?;
+        writer.finish(&mut audio_buffer, &mut octx)?;
+        encoded_size_bytes += writer.encoded_size_bytes;
     }
 
     octx.write_trailer().context("Write trailer failed")?;
@@ -2735,8 +2672,8 @@ fn perform_compression(
         encoded_size_bytes
     };
 
-    println!(
-        "PERF: Res: {}x{}, Video+Audio Bytes: {}, Duration: {}ms",
+    debug!(
+        "perform_compression - {}x{}, video+audio bytes: {}, duration: {}ms",
         target_width, target_height, final_encoded_size, final_processed_ms
     );
 
@@ -2860,6 +2797,7 @@ mod tests {
             width: None,
             height: None,
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         // Create temp directory if it doesn't exist
@@ -2991,6 +2929,7 @@ mod tests {
             width: Some(640),
             height: Some(360),
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         // Run compression (without sink)
@@ -3076,6 +3015,7 @@ mod tests {
             crf: Some(23),
             target_bitrate_kbps: 0, // ignored when CRF is set
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         // Clean up any previous test output
@@ -3174,6 +3114,7 @@ mod tests {
             crf: Some(23),
             target_bitrate_kbps: 0, // ignored
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         let result_crf = estimate_compression(path, temp_output_path, &params_crf).unwrap();
@@ -3197,6 +3138,7 @@ mod tests {
             crf: None,
             target_bitrate_kbps: 1000,
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         let result_br = estimate_compression(path, temp_output_path, &params_br).unwrap();
@@ -3244,6 +3186,7 @@ mod tests {
             crf: Some(23),
             target_bitrate_kbps: 0,
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         // Clean up any previous test output
@@ -3321,6 +3264,7 @@ mod tests {
             crf: Some(23),
             target_bitrate_kbps: 0,
             sample_duration_ms: None,
+            ..Default::default()
         };
 
         // Clean up any previous test output
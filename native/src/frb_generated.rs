@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1535488819;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -593138137;
 
 // Section: executor
 
@@ -45,29 +45,40 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__media__compress_video_impl(
+fn wire__crate__api__media__add_music_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
+    video_path: impl CstDecode<String>,
+    music_path: impl CstDecode<String>,
     output_path: impl CstDecode<String>,
-    params: impl CstDecode<crate::api::media::CompressParams>,
+    params: impl CstDecode<crate::api::media::AudioMixParams>,
+    sink: impl CstDecode<
+        StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        >,
+    >,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "compress_video",
+            debug_name: "add_music",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.cst_decode();
+            let api_video_path = video_path.cst_decode();
+            let api_music_path = music_path.cst_decode();
             let api_output_path = output_path.cst_decode();
             let api_params = params.cst_decode();
+            let api_sink = sink.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::compress_video(
-                            api_path,
+                        let output_ok = crate::api::media::add_music(
+                            api_video_path,
+                            api_music_path,
                             api_output_path,
                             api_params,
+                            api_sink,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -76,20 +87,22 @@ fn wire__crate__api__media__compress_video_impl(
         },
     )
 }
-fn wire__crate__api__logger__debug_threads_impl(
+fn wire__crate__api__media__analyze_loudness_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "debug_threads",
+            debug_name: "analyze_loudness",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
+            let api_path = path.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::logger::debug_threads()?;
+                        let output_ok = crate::api::media::analyze_loudness(api_path)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -97,28 +110,102 @@ fn wire__crate__api__logger__debug_threads_impl(
         },
     )
 }
-fn wire__crate__api__media__estimate_compression_impl(
+fn wire__crate__api__media__animation_format_extension_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::api::media::AnimationFormat>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "animation_format_extension",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::media::AnimationFormat::extension(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__audio_format_extension_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::api::media::AudioFormat>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_format_extension",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::media::AudioFormat::extension(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__compare_media_hashes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    a: impl CstDecode<crate::api::media::MediaHash>,
+    b: impl CstDecode<crate::api::media::MediaHash>,
+    threshold: impl CstDecode<Option<u32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compare_media_hashes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_a = a.cst_decode();
+            let api_b = b.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::compare_media_hashes(api_a, api_b, api_threshold)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__compress_video_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     path: impl CstDecode<String>,
-    temp_output_path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
     params: impl CstDecode<crate::api::media::CompressParams>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "estimate_compression",
+            debug_name: "compress_video",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_path = path.cst_decode();
-            let api_temp_output_path = temp_output_path.cst_decode();
+            let api_output_path = output_path.cst_decode();
             let api_params = params.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::estimate_compression(
+                        let output_ok = crate::api::media::compress_video(
                             api_path,
-                            api_temp_output_path,
+                            api_output_path,
                             api_params,
                         )?;
                         Ok(output_ok)
@@ -128,32 +215,32 @@ fn wire__crate__api__media__estimate_compression_impl(
         },
     )
 }
-fn wire__crate__api__media__generate_image_thumbnail_impl(
+fn wire__crate__api__media__compute_blurhash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     path: impl CstDecode<String>,
-    output_path: impl CstDecode<String>,
-    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
-    suffix: impl CstDecode<Option<String>>,
+    components_x: impl CstDecode<u32>,
+    components_y: impl CstDecode<u32>,
+    time_ms: impl CstDecode<Option<u64>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_image_thumbnail",
+            debug_name: "compute_blurhash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_path = path.cst_decode();
-            let api_output_path = output_path.cst_decode();
-            let api_params = params.cst_decode();
-            let api_suffix = suffix.cst_decode();
+            let api_components_x = components_x.cst_decode();
+            let api_components_y = components_y.cst_decode();
+            let api_time_ms = time_ms.cst_decode();
             move |context| async move {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::media::generate_image_thumbnail(
+                        let output_ok = crate::api::media::compute_blurhash(
                             api_path,
-                            api_output_path,
-                            api_params,
-                            api_suffix,
+                            api_components_x,
+                            api_components_y,
+                            api_time_ms,
                         )
                         .await?;
                         Ok(output_ok)
@@ -164,34 +251,25 @@ fn wire__crate__api__media__generate_image_thumbnail_impl(
         },
     )
 }
-fn wire__crate__api__media__generate_video_thumbnail_impl(
+fn wire__crate__api__media__compute_image_hash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     path: impl CstDecode<String>,
-    output_path: impl CstDecode<String>,
-    params: impl CstDecode<crate::api::media::VideoThumbnailParams>,
-    empty_image_fallback: impl CstDecode<Option<bool>>,
+    algorithm: impl CstDecode<Option<crate::api::media::HashAlgorithm>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_video_thumbnail",
+            debug_name: "compute_image_hash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_path = path.cst_decode();
-            let api_output_path = output_path.cst_decode();
-            let api_params = params.cst_decode();
-            let api_empty_image_fallback = empty_image_fallback.cst_decode();
+            let api_algorithm = algorithm.cst_decode();
             move |context| async move {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::media::generate_video_thumbnail(
-                            api_path,
-                            api_output_path,
-                            api_params,
-                            api_empty_image_fallback,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::media::compute_image_hash(api_path, api_algorithm).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -200,62 +278,57 @@ fn wire__crate__api__media__generate_video_thumbnail_impl(
         },
     )
 }
-fn wire__crate__api__media__generate_video_timeline_thumbnails_impl(
+fn wire__crate__api__media__compute_thumbhash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     path: impl CstDecode<String>,
-    output_path: impl CstDecode<String>,
-    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
-    num_thumbnails: impl CstDecode<u32>,
-    empty_image_fallback: impl CstDecode<Option<bool>>,
-    sink: impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>,
+    time_ms: impl CstDecode<Option<u64>>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_video_timeline_thumbnails",
+            debug_name: "compute_thumbhash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_path = path.cst_decode();
-            let api_output_path = output_path.cst_decode();
-            let api_params = params.cst_decode();
-            let api_num_thumbnails = num_thumbnails.cst_decode();
-            let api_empty_image_fallback = empty_image_fallback.cst_decode();
-            let api_sink = sink.cst_decode();
-            move |context| {
+            let api_time_ms = time_ms.cst_decode();
+            move |context| async move {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::media::generate_video_timeline_thumbnails(
-                            api_path,
-                            api_output_path,
-                            api_params,
-                            api_num_thumbnails,
-                            api_empty_image_fallback,
-                            api_sink,
-                        )?;
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::compute_thumbhash(api_path, api_time_ms).await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__media__get_video_info_impl(
+fn wire__crate__api__media__compute_video_hash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     path: impl CstDecode<String>,
+    algorithm: impl CstDecode<Option<crate::api::media::HashAlgorithm>>,
+    frame_count: impl CstDecode<Option<u32>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_info",
+            debug_name: "compute_video_hash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_path = path.cst_decode();
+            let api_algorithm = algorithm.cst_decode();
+            let api_frame_count = frame_count.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_video_info(api_path)?;
+                        let output_ok = crate::api::media::compute_video_hash(
+                            api_path,
+                            api_algorithm,
+                            api_frame_count,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -263,1174 +336,9458 @@ fn wire__crate__api__media__get_video_info_impl(
         },
     )
 }
-fn wire__crate__api__logger__init_logger_impl(
+fn wire__crate__api__media__concat_videos_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    log_level: impl CstDecode<crate::api::logger::LogLevel>,
-    write_to_stdout_or_system: impl CstDecode<bool>,
-    write_to_files: impl CstDecode<Option<crate::api::logger::WriteToFiles>>,
-    use_lightweight_tokio_runtime: impl CstDecode<bool>,
+    inputs: impl CstDecode<Vec<String>>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::ConcatParams>,
+    sink: impl CstDecode<
+        StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        >,
+    >,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logger",
+            debug_name: "concat_videos",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_log_level = log_level.cst_decode();
-            let api_write_to_stdout_or_system = write_to_stdout_or_system.cst_decode();
-            let api_write_to_files = write_to_files.cst_decode();
-            let api_use_lightweight_tokio_runtime = use_lightweight_tokio_runtime.cst_decode();
-            move |context| async move {
+            let api_inputs = inputs.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::logger::init_logger(
-                            api_log_level,
-                            api_write_to_stdout_or_system,
-                            api_write_to_files,
-                            api_use_lightweight_tokio_runtime,
-                        )
-                        .await?;
+                    (move || {
+                        let output_ok = crate::api::media::concat_videos(
+                            api_inputs,
+                            api_output_path,
+                            api_params,
+                            api_sink,
+                        )?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__logger__log_impl(
+fn wire__crate__api__logger__debug_threads_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    file: impl CstDecode<String>,
-    line: impl CstDecode<Option<u32>>,
-    level: impl CstDecode<crate::api::logger::LogLevel>,
-    target: impl CstDecode<String>,
-    message: impl CstDecode<String>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log",
+            debug_name: "debug_threads",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_file = file.cst_decode();
-            let api_line = line.cst_decode();
-            let api_level = level.cst_decode();
-            let api_target = target.cst_decode();
-            let api_message = message.cst_decode();
-            move |context| async move {
-                transform_result_dco::<_, _, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::logger::log(
-                                api_file,
-                                api_line,
-                                api_level,
-                                api_target,
-                                api_message,
-                            )
-                            .await;
-                        })?;
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::logger::debug_threads()?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__media__output_format_extension_impl(
+fn wire__crate__api__media__decode_blurhash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    that: impl CstDecode<crate::api::media::OutputFormat>,
+    hash: impl CstDecode<String>,
+    width: impl CstDecode<u32>,
+    height: impl CstDecode<u32>,
+    punch: impl CstDecode<Option<f32>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "output_format_extension",
+            debug_name: "decode_blurhash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_that = that.cst_decode();
+            let api_hash = hash.cst_decode();
+            let api_width = width.cst_decode();
+            let api_height = height.cst_decode();
+            let api_punch = punch.cst_decode();
             move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::media::OutputFormat::extension(&api_that);
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::decode_blurhash(
+                            api_hash, api_width, api_height, api_punch,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__logger__reload_tracing_file_writer_impl(
+fn wire__crate__api__media__decode_thumbhash_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    write_to_files: impl CstDecode<crate::api::logger::WriteToFiles>,
+    hash: impl CstDecode<Vec<u8>>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reload_tracing_file_writer",
+            debug_name: "decode_thumbhash",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_write_to_files = write_to_files.cst_decode();
-            move |context| async move {
+            let api_hash = hash.cst_decode();
+            move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::logger::reload_tracing_file_writer(api_write_to_files)
-                                .await?;
+                    (move || {
+                        let output_ok = crate::api::media::decode_thumbhash(api_hash)?;
                         Ok(output_ok)
-                    })()
-                    .await,
+                    })(),
                 )
             }
         },
     )
 }
-fn wire__crate__api__media__thumbnail_size_type_dimensions_impl(
+fn wire__crate__api__media__detect_scenes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    that: impl CstDecode<crate::api::media::ThumbnailSizeType>,
+    path: impl CstDecode<String>,
+    threshold: impl CstDecode<f64>,
+    sink: impl CstDecode<
+        StreamSink<
+            crate::api::media::SceneDetectionEvent,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        >,
+    >,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "thumbnail_size_type_dimensions",
+            debug_name: "detect_scenes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_that = that.cst_decode();
+            let api_path = path.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            let api_sink = sink.cst_decode();
             move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::media::ThumbnailSizeType::dimensions(&api_that),
-                    )?;
-                    Ok(output_ok)
-                })())
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::detect_scenes(api_path, api_threshold, api_sink)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__estimate_compression_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    temp_output_path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::CompressParams>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "estimate_compression",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_temp_output_path = temp_output_path.cst_decode();
+            let api_params = params.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::estimate_compression(
+                            api_path,
+                            api_temp_output_path,
+                            api_params,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__export_animation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    start_ms: impl CstDecode<u64>,
+    duration_ms: impl CstDecode<u64>,
+    fps: impl CstDecode<u32>,
+    max_width: impl CstDecode<u32>,
+    format: impl CstDecode<crate::api::media::AnimationFormat>,
+    options: impl CstDecode<crate::api::media::AnimationOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_animation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_start_ms = start_ms.cst_decode();
+            let api_duration_ms = duration_ms.cst_decode();
+            let api_fps = fps.cst_decode();
+            let api_max_width = max_width.cst_decode();
+            let api_format = format.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::export_animation(
+                            api_path,
+                            api_output_path,
+                            api_start_ms,
+                            api_duration_ms,
+                            api_fps,
+                            api_max_width,
+                            api_format,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__extract_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    format: impl CstDecode<crate::api::media::AudioFormat>,
+    options: impl CstDecode<crate::api::media::AudioExtractOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_format = format.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::extract_audio(
+                            api_path,
+                            api_output_path,
+                            api_format,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__extract_frames_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::FrameExtractParams>,
+    sink: impl CstDecode<
+        StreamSink<crate::api::media::ExtractedFrame, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_frames",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::extract_frames(
+                            api_path,
+                            api_output_path,
+                            api_params,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__extract_palette_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    count: impl CstDecode<u32>,
+    time_ms: impl CstDecode<Option<u64>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_palette",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_count = count.cst_decode();
+            let api_time_ms = time_ms.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::extract_palette(api_path, api_count, api_time_ms)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_best_thumbnail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::BestThumbnailParams>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_best_thumbnail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_params = params.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::generate_best_thumbnail(api_path, api_params)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_image_thumbnail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
+    suffix: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_image_thumbnail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_suffix = suffix.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::generate_image_thumbnail(
+                            api_path,
+                            api_output_path,
+                            api_params,
+                            api_suffix,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_image_thumbnail_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_image_thumbnail_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_params = params.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::generate_image_thumbnail_data(api_path, api_params)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_storyboard_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_dir: impl CstDecode<String>,
+    interval_ms: impl CstDecode<u64>,
+    tile_width: impl CstDecode<u32>,
+    tile_height: impl CstDecode<u32>,
+    columns: impl CstDecode<u32>,
+    rows: impl CstDecode<u32>,
+    encoder_options: impl CstDecode<Option<crate::api::media::EncoderOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_storyboard",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_dir = output_dir.cst_decode();
+            let api_interval_ms = interval_ms.cst_decode();
+            let api_tile_width = tile_width.cst_decode();
+            let api_tile_height = tile_height.cst_decode();
+            let api_columns = columns.cst_decode();
+            let api_rows = rows.cst_decode();
+            let api_encoder_options = encoder_options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::generate_storyboard(
+                            api_path,
+                            api_output_dir,
+                            api_interval_ms,
+                            api_tile_width,
+                            api_tile_height,
+                            api_columns,
+                            api_rows,
+                            api_encoder_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
+fn wire__crate__api__media__generate_video_thumbnail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::VideoThumbnailParams>,
+    empty_image_fallback: impl CstDecode<Option<bool>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_video_thumbnail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_empty_image_fallback = empty_image_fallback.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::generate_video_thumbnail(
+                            api_path,
+                            api_output_path,
+                            api_params,
+                            api_empty_image_fallback,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_video_thumbnail_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::VideoThumbnailParams>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_video_thumbnail_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_params = params.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::generate_video_thumbnail_data(api_path, api_params)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_video_timeline_thumbnail_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
+    num_thumbnails: impl CstDecode<u32>,
+    sink: impl CstDecode<
+        StreamSink<crate::api::media::ThumbnailData, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_video_timeline_thumbnail_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_num_thumbnails = num_thumbnails.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::generate_video_timeline_thumbnail_data(
+                            api_path,
+                            api_params,
+                            api_num_thumbnails,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_video_timeline_thumbnails_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<Option<crate::api::media::ImageThumbnailParams>>,
+    num_thumbnails: impl CstDecode<u32>,
+    empty_image_fallback: impl CstDecode<Option<bool>>,
+    sink: impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_video_timeline_thumbnails",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_num_thumbnails = num_thumbnails.cst_decode();
+            let api_empty_image_fallback = empty_image_fallback.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::generate_video_timeline_thumbnails(
+                            api_path,
+                            api_output_path,
+                            api_params,
+                            api_num_thumbnails,
+                            api_empty_image_fallback,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_image_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_image_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::get_image_info(api_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_video_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_video_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_video_info(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__init_logger_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    log_level: impl CstDecode<crate::api::logger::LogLevel>,
+    write_to_stdout_or_system: impl CstDecode<bool>,
+    write_to_files: impl CstDecode<Option<crate::api::logger::WriteToFiles>>,
+    use_lightweight_tokio_runtime: impl CstDecode<bool>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logger",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_log_level = log_level.cst_decode();
+            let api_write_to_stdout_or_system = write_to_stdout_or_system.cst_decode();
+            let api_write_to_files = write_to_files.cst_decode();
+            let api_use_lightweight_tokio_runtime = use_lightweight_tokio_runtime.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::logger::init_logger(
+                            api_log_level,
+                            api_write_to_stdout_or_system,
+                            api_write_to_files,
+                            api_use_lightweight_tokio_runtime,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__log_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    file: impl CstDecode<String>,
+    line: impl CstDecode<Option<u32>>,
+    level: impl CstDecode<crate::api::logger::LogLevel>,
+    target: impl CstDecode<String>,
+    message: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_file = file.cst_decode();
+            let api_line = line.cst_decode();
+            let api_level = level.cst_decode();
+            let api_target = target.cst_decode();
+            let api_message = message.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::logger::log(
+                                api_file,
+                                api_line,
+                                api_level,
+                                api_target,
+                                api_message,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__output_format_extension_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::api::media::OutputFormat>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "output_format_extension",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::media::OutputFormat::extension(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__probe_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::probe_media(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__probe_media_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_media_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::probe_media_json(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__logger__reload_tracing_file_writer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    write_to_files: impl CstDecode<crate::api::logger::WriteToFiles>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_tracing_file_writer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_write_to_files = write_to_files.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::logger::reload_tracing_file_writer(api_write_to_files)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__reverse_video_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    output_path: impl CstDecode<String>,
+    params: impl CstDecode<crate::api::media::ReverseParams>,
+    sink: impl CstDecode<
+        StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        >,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reverse_video",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_output_path = output_path.cst_decode();
+            let api_params = params.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::reverse_video(
+                            api_path,
+                            api_output_path,
+                            api_params,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__thumbnail_size_type_dimensions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::api::media::ThumbnailSizeType>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "thumbnail_size_type_dimensions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::media::ThumbnailSizeType::dimensions(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl CstDecode<crate::api::media::AnimationFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::AnimationFormat {
+        match self {
+            0 => crate::api::media::AnimationFormat::GIF,
+            1 => crate::api::media::AnimationFormat::WEBP,
+            2 => crate::api::media::AnimationFormat::APNG,
+            _ => unreachable!("Invalid variant for AnimationFormat: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::AspectMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::AspectMode {
+        match self {
+            0 => crate::api::media::AspectMode::Fit,
+            1 => crate::api::media::AspectMode::Fill,
+            2 => crate::api::media::AspectMode::BlurredFit,
+            _ => unreachable!("Invalid variant for AspectMode: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::AudioChannels> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::AudioChannels {
+        match self {
+            0 => crate::api::media::AudioChannels::Mono,
+            1 => crate::api::media::AudioChannels::Stereo,
+            _ => unreachable!("Invalid variant for AudioChannels: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::AudioFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::AudioFormat {
+        match self {
+            0 => crate::api::media::AudioFormat::M4A,
+            1 => crate::api::media::AudioFormat::OPUS,
+            2 => crate::api::media::AudioFormat::WAV,
+            _ => unreachable!("Invalid variant for AudioFormat: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::AudioTrackMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::AudioTrackMode {
+        match self {
+            0 => crate::api::media::AudioTrackMode::Replace,
+            1 => crate::api::media::AudioTrackMode::Mix,
+            _ => unreachable!("Invalid variant for AudioTrackMode: {}", self),
+        }
+    }
+}
+impl CstDecode<bool> for bool {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> bool {
+        self
+    }
+}
+impl CstDecode<f32> for f32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f32 {
+        self
+    }
+}
+impl CstDecode<f64> for f64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f64 {
+        self
+    }
+}
+impl CstDecode<crate::api::media::HashAlgorithm> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::HashAlgorithm {
+        match self {
+            0 => crate::api::media::HashAlgorithm::DHash,
+            1 => crate::api::media::HashAlgorithm::PHash,
+            _ => unreachable!("Invalid variant for HashAlgorithm: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::HdrFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::HdrFormat {
+        match self {
+            0 => crate::api::media::HdrFormat::Hdr10,
+            1 => crate::api::media::HdrFormat::Hlg,
+            2 => crate::api::media::HdrFormat::DolbyVision,
+            _ => unreachable!("Invalid variant for HdrFormat: {}", self),
+        }
+    }
+}
+impl CstDecode<i32> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i32 {
+        self
+    }
+}
+impl CstDecode<i64> for i64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i64 {
+        self
+    }
+}
+impl CstDecode<crate::api::logger::LogLevel> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::logger::LogLevel {
+        match self {
+            0 => crate::api::logger::LogLevel::Error,
+            1 => crate::api::logger::LogLevel::Warn,
+            2 => crate::api::logger::LogLevel::Info,
+            3 => crate::api::logger::LogLevel::Debug,
+            4 => crate::api::logger::LogLevel::Trace,
+            _ => unreachable!("Invalid variant for LogLevel: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::OutputFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::OutputFormat {
+        match self {
+            0 => crate::api::media::OutputFormat::WEBP,
+            1 => crate::api::media::OutputFormat::JPEG,
+            2 => crate::api::media::OutputFormat::PNG,
+            3 => crate::api::media::OutputFormat::AVIF,
+            4 => crate::api::media::OutputFormat::HEIC,
+            _ => unreachable!("Invalid variant for OutputFormat: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::ResizeMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::ResizeMode {
+        match self {
+            0 => crate::api::media::ResizeMode::Fit,
+            1 => crate::api::media::ResizeMode::Fill,
+            2 => crate::api::media::ResizeMode::Exact,
+            3 => crate::api::media::ResizeMode::FitWidth,
+            4 => crate::api::media::ResizeMode::FitHeight,
+            _ => unreachable!("Invalid variant for ResizeMode: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::ReverseAudioMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::ReverseAudioMode {
+        match self {
+            0 => crate::api::media::ReverseAudioMode::Reverse,
+            1 => crate::api::media::ReverseAudioMode::Mute,
+            _ => unreachable!("Invalid variant for ReverseAudioMode: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::SeekMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::SeekMode {
+        match self {
+            0 => crate::api::media::SeekMode::Accurate,
+            1 => crate::api::media::SeekMode::Keyframe,
+            _ => unreachable!("Invalid variant for SeekMode: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::api::media::StreamType> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::StreamType {
+        match self {
+            0 => crate::api::media::StreamType::Video,
+            1 => crate::api::media::StreamType::Audio,
+            2 => crate::api::media::StreamType::Subtitle,
+            3 => crate::api::media::StreamType::Data,
+            4 => crate::api::media::StreamType::Attachment,
+            5 => crate::api::media::StreamType::Unknown,
+            _ => unreachable!("Invalid variant for StreamType: {}", self),
+        }
+    }
+}
+impl CstDecode<u16> for u16 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u16 {
+        self
+    }
+}
+impl CstDecode<u32> for u32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u32 {
+        self
+    }
+}
+impl CstDecode<u64> for u64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u64 {
+        self
+    }
+}
+impl CstDecode<u8> for u8 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u8 {
+        self
+    }
+}
+impl CstDecode<crate::api::media::WatermarkPosition> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::api::media::WatermarkPosition {
+        match self {
+            0 => crate::api::media::WatermarkPosition::TopLeft,
+            1 => crate::api::media::WatermarkPosition::TopRight,
+            2 => crate::api::media::WatermarkPosition::BottomLeft,
+            3 => crate::api::media::WatermarkPosition::BottomRight,
+            4 => crate::api::media::WatermarkPosition::Center,
+            _ => unreachable!("Invalid variant for WatermarkPosition: {}", self),
+        }
+    }
+}
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::media::CompressProgress,
+        flutter_rust_bridge::for_generated::DcoCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::media::ExtractedFrame, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::media::SceneDetectionEvent,
+        flutter_rust_bridge::for_generated::DcoCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::media::ThumbnailData, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::media::AnimationFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::AnimationFormat::GIF,
+            1 => crate::api::media::AnimationFormat::WEBP,
+            2 => crate::api::media::AnimationFormat::APNG,
+            _ => unreachable!("Invalid variant for AnimationFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AnimationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_loopCount = <Option<u16>>::sse_decode(deserializer);
+        let mut var_dither = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::media::AnimationOptions {
+            max_bytes: var_maxBytes,
+            loop_count: var_loopCount,
+            dither: var_dither,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AspectMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::AspectMode::Fit,
+            1 => crate::api::media::AspectMode::Fill,
+            2 => crate::api::media::AspectMode::BlurredFit,
+            _ => unreachable!("Invalid variant for AspectMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioChannels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::AudioChannels::Mono,
+            1 => crate::api::media::AudioChannels::Stereo,
+            _ => unreachable!("Invalid variant for AudioChannels: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioExtractOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_channels = <Option<crate::api::media::AudioChannels>>::sse_decode(deserializer);
+        let mut var_sampleRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_normalizeLoudness =
+            <Option<crate::api::media::LoudnessNormalization>>::sse_decode(deserializer);
+        return crate::api::media::AudioExtractOptions {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            channels: var_channels,
+            sample_rate: var_sampleRate,
+            bitrate_kbps: var_bitrateKbps,
+            normalize_loudness: var_normalizeLoudness,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::AudioFormat::M4A,
+            1 => crate::api::media::AudioFormat::OPUS,
+            2 => crate::api::media::AudioFormat::WAV,
+            _ => unreachable!("Invalid variant for AudioFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioMixParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mode = <Option<crate::api::media::AudioTrackMode>>::sse_decode(deserializer);
+        let mut var_musicGainDb = <Option<f32>>::sse_decode(deserializer);
+        let mut var_originalGainDb = <Option<f32>>::sse_decode(deserializer);
+        let mut var_ducking = <Option<crate::api::media::DuckingParams>>::sse_decode(deserializer);
+        let mut var_loopMusic = <bool>::sse_decode(deserializer);
+        let mut var_musicStartMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_fadeInMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_fadeOutMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::media::AudioMixParams {
+            mode: var_mode,
+            music_gain_db: var_musicGainDb,
+            original_gain_db: var_originalGainDb,
+            ducking: var_ducking,
+            loop_music: var_loopMusic,
+            music_start_ms: var_musicStartMs,
+            fade_in_ms: var_fadeInMs,
+            fade_out_ms: var_fadeOutMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioStreamInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_codecName = <Option<String>>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        return crate::api::media::AudioStreamInfo {
+            codec_name: var_codecName,
+            channels: var_channels,
+            sample_rate: var_sampleRate,
+            bitrate: var_bitrate,
+            language: var_language,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioStreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sampleRate = <u32>::sse_decode(deserializer);
+        let mut var_channels = <u32>::sse_decode(deserializer);
+        let mut var_channelLayout = <Option<String>>::sse_decode(deserializer);
+        let mut var_sampleFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitsPerSample = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::media::AudioStreamProbe {
+            sample_rate: var_sampleRate,
+            channels: var_channels,
+            channel_layout: var_channelLayout,
+            sample_format: var_sampleFormat,
+            bits_per_sample: var_bitsPerSample,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::AudioTrackMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::AudioTrackMode::Replace,
+            1 => crate::api::media::AudioTrackMode::Mix,
+            _ => unreachable!("Invalid variant for AudioTrackMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::BestThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::media::BestThumbnail {
+            data: var_data,
+            width: var_width,
+            height: var_height,
+            time_ms: var_timeMs,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::BestThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sizeType =
+            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
+        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
+        let mut var_candidates = <Option<u32>>::sse_decode(deserializer);
+        let mut var_resizeMode = <Option<crate::api::media::ResizeMode>>::sse_decode(deserializer);
+        let mut var_allowUpscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_encoderOptions =
+            <Option<crate::api::media::EncoderOptions>>::sse_decode(deserializer);
+        return crate::api::media::BestThumbnailParams {
+            size_type: var_sizeType,
+            format: var_format,
+            candidates: var_candidates,
+            resize_mode: var_resizeMode,
+            allow_upscale: var_allowUpscale,
+            encoder_options: var_encoderOptions,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::media::CompressParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_targetBitrateKbps = <u32>::sse_decode(deserializer);
+        let mut var_preset = <Option<String>>::sse_decode(deserializer);
+        let mut var_crf = <Option<u8>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sampleDurationMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_crop = <Option<crate::api::media::CropRect>>::sse_decode(deserializer);
+        let mut var_aspectRatio = <Option<(u32, u32)>>::sse_decode(deserializer);
+        let mut var_aspectMode = <Option<crate::api::media::AspectMode>>::sse_decode(deserializer);
+        let mut var_padColor = <Option<u32>>::sse_decode(deserializer);
+        let mut var_watermark =
+            <Option<crate::api::media::WatermarkParams>>::sse_decode(deserializer);
+        let mut var_subtitles =
+            <Option<crate::api::media::SubtitleParams>>::sse_decode(deserializer);
+        let mut var_speed = <Option<crate::api::media::SpeedParams>>::sse_decode(deserializer);
+        let mut var_normalizeLoudness =
+            <Option<crate::api::media::LoudnessNormalization>>::sse_decode(deserializer);
+        let mut var_videoFilters = <Option<String>>::sse_decode(deserializer);
+        let mut var_audioFilters = <Option<String>>::sse_decode(deserializer);
+        return crate::api::media::CompressParams {
+            target_bitrate_kbps: var_targetBitrateKbps,
+            preset: var_preset,
+            crf: var_crf,
+            width: var_width,
+            height: var_height,
+            sample_duration_ms: var_sampleDurationMs,
+            crop: var_crop,
+            aspect_ratio: var_aspectRatio,
+            aspect_mode: var_aspectMode,
+            pad_color: var_padColor,
+            watermark: var_watermark,
+            subtitles: var_subtitles,
+            speed: var_speed,
+            normalize_loudness: var_normalizeLoudness,
+            video_filters: var_videoFilters,
+            audio_filters: var_audioFilters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::CompressProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_processedMs = <u64>::sse_decode(deserializer);
+        let mut var_totalMs = <u64>::sse_decode(deserializer);
+        let mut var_speedX = <f32>::sse_decode(deserializer);
+        return crate::api::media::CompressProgress {
+            processed_ms: var_processedMs,
+            total_ms: var_totalMs,
+            speed_x: var_speedX,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::CompressionEstimate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_estimatedSizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_estimatedDurationMs = <u64>::sse_decode(deserializer);
+        return crate::api::media::CompressionEstimate {
+            estimated_size_bytes: var_estimatedSizeBytes,
+            estimated_duration_ms: var_estimatedDurationMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ConcatParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_fps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_targetBitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_preset = <Option<String>>::sse_decode(deserializer);
+        let mut var_crf = <Option<u8>>::sse_decode(deserializer);
+        let mut var_forceReencode = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::media::ConcatParams {
+            width: var_width,
+            height: var_height,
+            fps: var_fps,
+            target_bitrate_kbps: var_targetBitrateKbps,
+            preset: var_preset,
+            crf: var_crf,
+            force_reencode: var_forceReencode,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::CropRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <u32>::sse_decode(deserializer);
+        let mut var_y = <u32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::media::CropRect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::DuckingParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_thresholdDb = <Option<f32>>::sse_decode(deserializer);
+        let mut var_reductionDb = <Option<f32>>::sse_decode(deserializer);
+        let mut var_attackMs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_releaseMs = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::media::DuckingParams {
+            threshold_db: var_thresholdDb,
+            reduction_db: var_reductionDb,
+            attack_ms: var_attackMs,
+            release_ms: var_releaseMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::EncoderOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jpegQuality = <Option<u8>>::sse_decode(deserializer);
+        let mut var_jpegProgressive = <Option<bool>>::sse_decode(deserializer);
+        let mut var_webpQuality = <Option<f32>>::sse_decode(deserializer);
+        let mut var_pngCompression = <Option<u8>>::sse_decode(deserializer);
+        let mut var_avifQuality = <Option<u8>>::sse_decode(deserializer);
+        let mut var_heicQuality = <Option<u8>>::sse_decode(deserializer);
+        return crate::api::media::EncoderOptions {
+            jpeg_quality: var_jpegQuality,
+            jpeg_progressive: var_jpegProgressive,
+            webp_quality: var_webpQuality,
+            png_compression: var_pngCompression,
+            avif_quality: var_avifQuality,
+            heic_quality: var_heicQuality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ExtractedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_requestedMs = <u64>::sse_decode(deserializer);
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::media::ExtractedFrame {
+            path: var_path,
+            index: var_index,
+            requested_ms: var_requestedMs,
+            time_ms: var_timeMs,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::media::FrameExtractParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_selection = <crate::api::media::FrameSelection>::sse_decode(deserializer);
+        let mut var_sizeType =
+            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
+        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
+        let mut var_seekMode = <Option<crate::api::media::SeekMode>>::sse_decode(deserializer);
+        let mut var_resizeMode = <Option<crate::api::media::ResizeMode>>::sse_decode(deserializer);
+        let mut var_allowUpscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_encoderOptions =
+            <Option<crate::api::media::EncoderOptions>>::sse_decode(deserializer);
+        return crate::api::media::FrameExtractParams {
+            selection: var_selection,
+            size_type: var_sizeType,
+            format: var_format,
+            seek_mode: var_seekMode,
+            resize_mode: var_resizeMode,
+            allow_upscale: var_allowUpscale,
+            encoder_options: var_encoderOptions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::FrameSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Vec<u64>>::sse_decode(deserializer);
+                return crate::api::media::FrameSelection::Timestamps(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::media::FrameSelection::Interval(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::media::FrameSelection::Count(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::media::GpsLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_latitude = <f64>::sse_decode(deserializer);
+        let mut var_longitude = <f64>::sse_decode(deserializer);
+        let mut var_altitude = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::media::GpsLocation {
+            latitude: var_latitude,
+            longitude: var_longitude,
+            altitude: var_altitude,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::HashAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::HashAlgorithm::DHash,
+            1 => crate::api::media::HashAlgorithm::PHash,
+            _ => unreachable!("Invalid variant for HashAlgorithm: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::HashComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_distance = <u32>::sse_decode(deserializer);
+        let mut var_similarity = <f64>::sse_decode(deserializer);
+        let mut var_isSimilar = <bool>::sse_decode(deserializer);
+        return crate::api::media::HashComparison {
+            distance: var_distance,
+            similarity: var_similarity,
+            is_similar: var_isSimilar,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::HdrFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::HdrFormat::Hdr10,
+            1 => crate::api::media::HdrFormat::Hlg,
+            2 => crate::api::media::HdrFormat::DolbyVision,
+            _ => unreachable!("Invalid variant for HdrFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::media::ImageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_bitDepth = <u32>::sse_decode(deserializer);
+        let mut var_hasAlpha = <bool>::sse_decode(deserializer);
+        let mut var_orientation = <u32>::sse_decode(deserializer);
+        let mut var_cameraMake = <Option<String>>::sse_decode(deserializer);
+        let mut var_cameraModel = <Option<String>>::sse_decode(deserializer);
+        let mut var_captureDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_gps = <Option<crate::api::media::GpsLocation>>::sse_decode(deserializer);
+        let mut var_iccProfileName = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageCount = <u32>::sse_decode(deserializer);
+        let mut var_auxiliaryImageCount = <u32>::sse_decode(deserializer);
+        let mut var_hasDepth = <bool>::sse_decode(deserializer);
+        return crate::api::media::ImageInfo {
+            width: var_width,
+            height: var_height,
+            format: var_format,
+            bit_depth: var_bitDepth,
+            has_alpha: var_hasAlpha,
+            orientation: var_orientation,
+            camera_make: var_cameraMake,
+            camera_model: var_cameraModel,
+            capture_date: var_captureDate,
+            gps: var_gps,
+            icc_profile_name: var_iccProfileName,
+            image_count: var_imageCount,
+            auxiliary_image_count: var_auxiliaryImageCount,
+            has_depth: var_hasDepth,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ImageThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sizeType =
+            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
+        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
+        let mut var_seekMode = <Option<crate::api::media::SeekMode>>::sse_decode(deserializer);
+        let mut var_resizeMode = <Option<crate::api::media::ResizeMode>>::sse_decode(deserializer);
+        let mut var_allowUpscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_encoderOptions =
+            <Option<crate::api::media::EncoderOptions>>::sse_decode(deserializer);
+        return crate::api::media::ImageThumbnailParams {
+            size_type: var_sizeType,
+            format: var_format,
+            seek_mode: var_seekMode,
+            resize_mode: var_resizeMode,
+            allow_upscale: var_allowUpscale,
+            encoder_options: var_encoderOptions,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::MediaChapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::MediaChapter>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::MediaTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::MediaTag>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::PaletteColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::PaletteColor>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::ResolutionPreset> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::ResolutionPreset>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::SceneCut> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::SceneCut>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::StreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::StreamProbe>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::StreamSideData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::StreamSideData>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::logger::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::logger::LogLevel::Error,
+            1 => crate::api::logger::LogLevel::Warn,
+            2 => crate::api::logger::LogLevel::Info,
+            3 => crate::api::logger::LogLevel::Debug,
+            4 => crate::api::logger::LogLevel::Trace,
+            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::LoudnessInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_integratedLufs = <f64>::sse_decode(deserializer);
+        let mut var_loudnessRangeLu = <f64>::sse_decode(deserializer);
+        let mut var_truePeakDbtp = <f64>::sse_decode(deserializer);
+        return crate::api::media::LoudnessInfo {
+            integrated_lufs: var_integratedLufs,
+            loudness_range_lu: var_loudnessRangeLu,
+            true_peak_dbtp: var_truePeakDbtp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::LoudnessNormalization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_targetLufs = <f32>::sse_decode(deserializer);
+        let mut var_truePeakDbtp = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::media::LoudnessNormalization {
+            target_lufs: var_targetLufs,
+            true_peak_dbtp: var_truePeakDbtp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::MediaChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_startMs = <u64>::sse_decode(deserializer);
+        let mut var_endMs = <u64>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::media::MediaTag>>::sse_decode(deserializer);
+        return crate::api::media::MediaChapter {
+            id: var_id,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            title: var_title,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::MediaHash {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_algorithm = <crate::api::media::HashAlgorithm>::sse_decode(deserializer);
+        let mut var_hashes = <Vec<u64>>::sse_decode(deserializer);
+        let mut var_timesMs = <Vec<u64>>::sse_decode(deserializer);
+        return crate::api::media::MediaHash {
+            algorithm: var_algorithm,
+            hashes: var_hashes,
+            times_ms: var_timesMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::MediaProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_formatName = <String>::sse_decode(deserializer);
+        let mut var_formatLongName = <Option<String>>::sse_decode(deserializer);
+        let mut var_durationMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_startTimeMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::media::MediaTag>>::sse_decode(deserializer);
+        let mut var_chapters = <Vec<crate::api::media::MediaChapter>>::sse_decode(deserializer);
+        let mut var_streams = <Vec<crate::api::media::StreamProbe>>::sse_decode(deserializer);
+        return crate::api::media::MediaProbe {
+            format_name: var_formatName,
+            format_long_name: var_formatLongName,
+            duration_ms: var_durationMs,
+            start_time_ms: var_startTimeMs,
+            bitrate: var_bitrate,
+            size_bytes: var_sizeBytes,
+            tags: var_tags,
+            chapters: var_chapters,
+            streams: var_streams,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::MediaTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::media::MediaTag {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::AspectMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::AspectMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::AudioChannels> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::AudioChannels>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::AudioStreamInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::AudioStreamInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::AudioStreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::AudioStreamProbe>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::AudioTrackMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::AudioTrackMode>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::CropRect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::CropRect>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::DuckingParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::DuckingParams>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::EncoderOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::EncoderOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::GpsLocation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::GpsLocation>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::HashAlgorithm> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::HashAlgorithm>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::HdrFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::HdrFormat>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::ImageThumbnailParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::ImageThumbnailParams>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::LoudnessNormalization> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::LoudnessNormalization>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::OutputFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::OutputFormat>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::PaletteColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::PaletteColor>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<(u32, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<(u32, u32)>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::ResizeMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::ResizeMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::ReverseAudioMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::ReverseAudioMode>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::SeekMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::SeekMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::SpeedParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::SpeedParams>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::SubtitleParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::SubtitleParams>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::ThumbnailSizeType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::ThumbnailSizeType>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::VideoStreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::VideoStreamProbe>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::WatermarkParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::WatermarkParams>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::media::WatermarkPosition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::WatermarkPosition>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::logger::WriteToFiles> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::logger::WriteToFiles>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::media::OutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::OutputFormat::WEBP,
+            1 => crate::api::media::OutputFormat::JPEG,
+            2 => crate::api::media::OutputFormat::PNG,
+            3 => crate::api::media::OutputFormat::AVIF,
+            4 => crate::api::media::OutputFormat::HEIC,
+            _ => unreachable!("Invalid variant for OutputFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::Palette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_colors = <Vec<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_average = <crate::api::media::PaletteColor>::sse_decode(deserializer);
+        let mut var_vibrant = <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_lightVibrant =
+            <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_darkVibrant =
+            <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_muted = <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_lightMuted =
+            <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        let mut var_darkMuted = <Option<crate::api::media::PaletteColor>>::sse_decode(deserializer);
+        return crate::api::media::Palette {
+            colors: var_colors,
+            average: var_average,
+            vibrant: var_vibrant,
+            light_vibrant: var_lightVibrant,
+            dark_vibrant: var_darkVibrant,
+            muted: var_muted,
+            light_muted: var_lightMuted,
+            dark_muted: var_darkMuted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::PaletteColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_color = <u32>::sse_decode(deserializer);
+        let mut var_population = <f64>::sse_decode(deserializer);
+        return crate::api::media::PaletteColor {
+            color: var_color,
+            population: var_population,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::PlaceholderImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rgba = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::media::PlaceholderImage {
+            rgba: var_rgba,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for (u32, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <u32>::sse_decode(deserializer);
+        let mut var_field1 = <u32>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::media::ResizeMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::ResizeMode::Fit,
+            1 => crate::api::media::ResizeMode::Fill,
+            2 => crate::api::media::ResizeMode::Exact,
+            3 => crate::api::media::ResizeMode::FitWidth,
+            4 => crate::api::media::ResizeMode::FitHeight,
+            _ => unreachable!("Invalid variant for ResizeMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ResolutionPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_bitrate = <u64>::sse_decode(deserializer);
+        let mut var_crf = <u8>::sse_decode(deserializer);
+        return crate::api::media::ResolutionPreset {
+            name: var_name,
+            width: var_width,
+            height: var_height,
+            bitrate: var_bitrate,
+            crf: var_crf,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ReverseAudioMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::ReverseAudioMode::Reverse,
+            1 => crate::api::media::ReverseAudioMode::Mute,
+            _ => unreachable!("Invalid variant for ReverseAudioMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ReverseParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_boomerangLoops = <Option<u32>>::sse_decode(deserializer);
+        let mut var_audio = <Option<crate::api::media::ReverseAudioMode>>::sse_decode(deserializer);
+        let mut var_width = <Option<u32>>::sse_decode(deserializer);
+        let mut var_height = <Option<u32>>::sse_decode(deserializer);
+        let mut var_targetBitrateKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_preset = <Option<String>>::sse_decode(deserializer);
+        let mut var_crf = <Option<u8>>::sse_decode(deserializer);
+        let mut var_memoryBudgetMb = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::media::ReverseParams {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            boomerang_loops: var_boomerangLoops,
+            audio: var_audio,
+            width: var_width,
+            height: var_height,
+            target_bitrate_kbps: var_targetBitrateKbps,
+            preset: var_preset,
+            crf: var_crf,
+            memory_budget_mb: var_memoryBudgetMb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::SceneCut {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::media::SceneCut {
+            time_ms: var_timeMs,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::SceneDetectionEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <crate::api::media::CompressProgress>::sse_decode(deserializer);
+                return crate::api::media::SceneDetectionEvent::Progress(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<crate::api::media::SceneCut>>::sse_decode(deserializer);
+                return crate::api::media::SceneDetectionEvent::Cuts(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::media::SeekMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::SeekMode::Accurate,
+            1 => crate::api::media::SeekMode::Keyframe,
+            _ => unreachable!("Invalid variant for SeekMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::SpeedParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_factor = <f32>::sse_decode(deserializer);
+        let mut var_frameRate = <Option<u32>>::sse_decode(deserializer);
+        let mut var_muteAudioAbove = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::media::SpeedParams {
+            factor: var_factor,
+            frame_rate: var_frameRate,
+            mute_audio_above: var_muteAudioAbove,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::Storyboard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_vttPath = <String>::sse_decode(deserializer);
+        let mut var_spritePaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_tileCount = <u32>::sse_decode(deserializer);
+        return crate::api::media::Storyboard {
+            vtt_path: var_vttPath,
+            sprite_paths: var_spritePaths,
+            tile_count: var_tileCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::StreamDisposition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_default = <bool>::sse_decode(deserializer);
+        let mut var_dub = <bool>::sse_decode(deserializer);
+        let mut var_original = <bool>::sse_decode(deserializer);
+        let mut var_comment = <bool>::sse_decode(deserializer);
+        let mut var_lyrics = <bool>::sse_decode(deserializer);
+        let mut var_karaoke = <bool>::sse_decode(deserializer);
+        let mut var_forced = <bool>::sse_decode(deserializer);
+        let mut var_hearingImpaired = <bool>::sse_decode(deserializer);
+        let mut var_visualImpaired = <bool>::sse_decode(deserializer);
+        let mut var_cleanEffects = <bool>::sse_decode(deserializer);
+        let mut var_attachedPic = <bool>::sse_decode(deserializer);
+        let mut var_captions = <bool>::sse_decode(deserializer);
+        let mut var_descriptions = <bool>::sse_decode(deserializer);
+        let mut var_metadata = <bool>::sse_decode(deserializer);
+        return crate::api::media::StreamDisposition {
+            default: var_default,
+            dub: var_dub,
+            original: var_original,
+            comment: var_comment,
+            lyrics: var_lyrics,
+            karaoke: var_karaoke,
+            forced: var_forced,
+            hearing_impaired: var_hearingImpaired,
+            visual_impaired: var_visualImpaired,
+            clean_effects: var_cleanEffects,
+            attached_pic: var_attachedPic,
+            captions: var_captions,
+            descriptions: var_descriptions,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::StreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_streamType = <crate::api::media::StreamType>::sse_decode(deserializer);
+        let mut var_codecName = <Option<String>>::sse_decode(deserializer);
+        let mut var_codecLongName = <Option<String>>::sse_decode(deserializer);
+        let mut var_profile = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_startTimeMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_durationMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_frameCount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_disposition = <crate::api::media::StreamDisposition>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::media::MediaTag>>::sse_decode(deserializer);
+        let mut var_sideData = <Vec<crate::api::media::StreamSideData>>::sse_decode(deserializer);
+        let mut var_video = <Option<crate::api::media::VideoStreamProbe>>::sse_decode(deserializer);
+        let mut var_audio = <Option<crate::api::media::AudioStreamProbe>>::sse_decode(deserializer);
+        return crate::api::media::StreamProbe {
+            index: var_index,
+            stream_type: var_streamType,
+            codec_name: var_codecName,
+            codec_long_name: var_codecLongName,
+            profile: var_profile,
+            bitrate: var_bitrate,
+            start_time_ms: var_startTimeMs,
+            duration_ms: var_durationMs,
+            frame_count: var_frameCount,
+            language: var_language,
+            title: var_title,
+            disposition: var_disposition,
+            tags: var_tags,
+            side_data: var_sideData,
+            video: var_video,
+            audio: var_audio,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::StreamSideData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u32>::sse_decode(deserializer);
+        return crate::api::media::StreamSideData {
+            kind: var_kind,
+            size_bytes: var_sizeBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::StreamType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::StreamType::Video,
+            1 => crate::api::media::StreamType::Audio,
+            2 => crate::api::media::StreamType::Subtitle,
+            3 => crate::api::media::StreamType::Data,
+            4 => crate::api::media::StreamType::Attachment,
+            5 => crate::api::media::StreamType::Unknown,
+            _ => unreachable!("Invalid variant for StreamType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::SubtitleParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subtitlePath = <String>::sse_decode(deserializer);
+        let mut var_fontPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_fontSize = <Option<u32>>::sse_decode(deserializer);
+        let mut var_color = <Option<u32>>::sse_decode(deserializer);
+        let mut var_outlineColor = <Option<u32>>::sse_decode(deserializer);
+        let mut var_outlineWidth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_bottomMargin = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::media::SubtitleParams {
+            subtitle_path: var_subtitlePath,
+            font_path: var_fontPath,
+            font_size: var_fontSize,
+            color: var_color,
+            outline_color: var_outlineColor,
+            outline_width: var_outlineWidth,
+            bottom_margin: var_bottomMargin,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ThumbnailData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_timeMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::media::ThumbnailData {
+            data: var_data,
+            width: var_width,
+            height: var_height,
+            time_ms: var_timeMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::ThumbnailSizeType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::media::ThumbnailSizeType::Icon;
+            }
+            1 => {
+                return crate::api::media::ThumbnailSizeType::Small;
+            }
+            2 => {
+                return crate::api::media::ThumbnailSizeType::Medium;
+            }
+            3 => {
+                return crate::api::media::ThumbnailSizeType::Large;
+            }
+            4 => {
+                return crate::api::media::ThumbnailSizeType::Larger;
+            }
+            5 => {
+                let mut var_field0 = <(u32, u32)>::sse_decode(deserializer);
+                return crate::api::media::ThumbnailSizeType::Custom(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::media::VideoInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<u64>>::sse_decode(deserializer);
+        let mut var_codecName = <Option<String>>::sse_decode(deserializer);
+        let mut var_formatName = <Option<String>>::sse_decode(deserializer);
+        let mut var_storedWidth = <u32>::sse_decode(deserializer);
+        let mut var_storedHeight = <u32>::sse_decode(deserializer);
+        let mut var_rotation = <i32>::sse_decode(deserializer);
+        let mut var_avgFrameRate = <Option<f64>>::sse_decode(deserializer);
+        let mut var_realFrameRate = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isVfr = <bool>::sse_decode(deserializer);
+        let mut var_pixelFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_colorPrimaries = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorTransfer = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorMatrix = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorRange = <Option<String>>::sse_decode(deserializer);
+        let mut var_hdrFormat = <Option<crate::api::media::HdrFormat>>::sse_decode(deserializer);
+        let mut var_profile = <Option<String>>::sse_decode(deserializer);
+        let mut var_level = <Option<i32>>::sse_decode(deserializer);
+        let mut var_frameCount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_audio = <Option<crate::api::media::AudioStreamInfo>>::sse_decode(deserializer);
+        let mut var_suggestions =
+            <Vec<crate::api::media::ResolutionPreset>>::sse_decode(deserializer);
+        return crate::api::media::VideoInfo {
+            duration_ms: var_durationMs,
+            width: var_width,
+            height: var_height,
+            size_bytes: var_sizeBytes,
+            bitrate: var_bitrate,
+            codec_name: var_codecName,
+            format_name: var_formatName,
+            stored_width: var_storedWidth,
+            stored_height: var_storedHeight,
+            rotation: var_rotation,
+            avg_frame_rate: var_avgFrameRate,
+            real_frame_rate: var_realFrameRate,
+            is_vfr: var_isVfr,
+            pixel_format: var_pixelFormat,
+            bit_depth: var_bitDepth,
+            color_primaries: var_colorPrimaries,
+            color_transfer: var_colorTransfer,
+            color_matrix: var_colorMatrix,
+            color_range: var_colorRange,
+            hdr_format: var_hdrFormat,
+            profile: var_profile,
+            level: var_level,
+            frame_count: var_frameCount,
+            audio: var_audio,
+            suggestions: var_suggestions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::VideoStreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_displayWidth = <u32>::sse_decode(deserializer);
+        let mut var_displayHeight = <u32>::sse_decode(deserializer);
+        let mut var_rotation = <i32>::sse_decode(deserializer);
+        let mut var_avgFrameRate = <Option<f64>>::sse_decode(deserializer);
+        let mut var_realFrameRate = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isVfr = <bool>::sse_decode(deserializer);
+        let mut var_pixelFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitDepth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_colorPrimaries = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorTransfer = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorMatrix = <Option<String>>::sse_decode(deserializer);
+        let mut var_colorRange = <Option<String>>::sse_decode(deserializer);
+        let mut var_hdrFormat = <Option<crate::api::media::HdrFormat>>::sse_decode(deserializer);
+        let mut var_level = <Option<i32>>::sse_decode(deserializer);
+        return crate::api::media::VideoStreamProbe {
+            width: var_width,
+            height: var_height,
+            display_width: var_displayWidth,
+            display_height: var_displayHeight,
+            rotation: var_rotation,
+            avg_frame_rate: var_avgFrameRate,
+            real_frame_rate: var_realFrameRate,
+            is_vfr: var_isVfr,
+            pixel_format: var_pixelFormat,
+            bit_depth: var_bitDepth,
+            color_primaries: var_colorPrimaries,
+            color_transfer: var_colorTransfer,
+            color_matrix: var_colorMatrix,
+            color_range: var_colorRange,
+            hdr_format: var_hdrFormat,
+            level: var_level,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::VideoThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_sizeType =
+            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
+        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
+        let mut var_seekMode = <Option<crate::api::media::SeekMode>>::sse_decode(deserializer);
+        let mut var_resizeMode = <Option<crate::api::media::ResizeMode>>::sse_decode(deserializer);
+        let mut var_allowUpscale = <Option<bool>>::sse_decode(deserializer);
+        let mut var_encoderOptions =
+            <Option<crate::api::media::EncoderOptions>>::sse_decode(deserializer);
+        return crate::api::media::VideoThumbnailParams {
+            time_ms: var_timeMs,
+            size_type: var_sizeType,
+            format: var_format,
+            seek_mode: var_seekMode,
+            resize_mode: var_resizeMode,
+            allow_upscale: var_allowUpscale,
+            encoder_options: var_encoderOptions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::WatermarkParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imagePath = <String>::sse_decode(deserializer);
+        let mut var_position =
+            <Option<crate::api::media::WatermarkPosition>>::sse_decode(deserializer);
+        let mut var_margin = <Option<u32>>::sse_decode(deserializer);
+        let mut var_scale = <Option<f32>>::sse_decode(deserializer);
+        let mut var_opacity = <Option<f32>>::sse_decode(deserializer);
+        let mut var_startMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_endMs = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::media::WatermarkParams {
+            image_path: var_imagePath,
+            position: var_position,
+            margin: var_margin,
+            scale: var_scale,
+            opacity: var_opacity,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::media::WatermarkPosition::TopLeft,
+            1 => crate::api::media::WatermarkPosition::TopRight,
+            2 => crate::api::media::WatermarkPosition::BottomLeft,
+            3 => crate::api::media::WatermarkPosition::BottomRight,
+            4 => crate::api::media::WatermarkPosition::Center,
+            _ => unreachable!("Invalid variant for WatermarkPosition: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::logger::WriteToFiles {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_filePrefix = <String>::sse_decode(deserializer);
+        let mut var_fileSuffix = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxFiles = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::logger::WriteToFiles {
+            path: var_path,
+            file_prefix: var_filePrefix,
+            file_suffix: var_fileSuffix,
+            max_files: var_maxFiles,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AnimationFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::GIF => 0.into_dart(),
+            Self::WEBP => 1.into_dart(),
+            Self::APNG => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AnimationFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AnimationFormat>
+    for crate::api::media::AnimationFormat
+{
+    fn into_into_dart(self) -> crate::api::media::AnimationFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AnimationOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_bytes.into_into_dart().into_dart(),
+            self.loop_count.into_into_dart().into_dart(),
+            self.dither.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AnimationOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AnimationOptions>
+    for crate::api::media::AnimationOptions
+{
+    fn into_into_dart(self) -> crate::api::media::AnimationOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AspectMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fit => 0.into_dart(),
+            Self::Fill => 1.into_dart(),
+            Self::BlurredFit => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::AspectMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AspectMode>
+    for crate::api::media::AspectMode
+{
+    fn into_into_dart(self) -> crate::api::media::AspectMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioChannels {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mono => 0.into_dart(),
+            Self::Stereo => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioChannels
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioChannels>
+    for crate::api::media::AudioChannels
+{
+    fn into_into_dart(self) -> crate::api::media::AudioChannels {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioExtractOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bitrate_kbps.into_into_dart().into_dart(),
+            self.normalize_loudness.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioExtractOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioExtractOptions>
+    for crate::api::media::AudioExtractOptions
+{
+    fn into_into_dart(self) -> crate::api::media::AudioExtractOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::M4A => 0.into_dart(),
+            Self::OPUS => 1.into_dart(),
+            Self::WAV => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioFormat>
+    for crate::api::media::AudioFormat
+{
+    fn into_into_dart(self) -> crate::api::media::AudioFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioMixParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mode.into_into_dart().into_dart(),
+            self.music_gain_db.into_into_dart().into_dart(),
+            self.original_gain_db.into_into_dart().into_dart(),
+            self.ducking.into_into_dart().into_dart(),
+            self.loop_music.into_into_dart().into_dart(),
+            self.music_start_ms.into_into_dart().into_dart(),
+            self.fade_in_ms.into_into_dart().into_dart(),
+            self.fade_out_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioMixParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioMixParams>
+    for crate::api::media::AudioMixParams
+{
+    fn into_into_dart(self) -> crate::api::media::AudioMixParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioStreamInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.codec_name.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.sample_rate.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioStreamInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioStreamInfo>
+    for crate::api::media::AudioStreamInfo
+{
+    fn into_into_dart(self) -> crate::api::media::AudioStreamInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioStreamProbe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sample_rate.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.channel_layout.into_into_dart().into_dart(),
+            self.sample_format.into_into_dart().into_dart(),
+            self.bits_per_sample.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioStreamProbe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioStreamProbe>
+    for crate::api::media::AudioStreamProbe
+{
+    fn into_into_dart(self) -> crate::api::media::AudioStreamProbe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::AudioTrackMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Replace => 0.into_dart(),
+            Self::Mix => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::AudioTrackMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::AudioTrackMode>
+    for crate::api::media::AudioTrackMode
+{
+    fn into_into_dart(self) -> crate::api::media::AudioTrackMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::BestThumbnail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.time_ms.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::BestThumbnail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::BestThumbnail>
+    for crate::api::media::BestThumbnail
+{
+    fn into_into_dart(self) -> crate::api::media::BestThumbnail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::BestThumbnailParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.size_type.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.candidates.into_into_dart().into_dart(),
+            self.resize_mode.into_into_dart().into_dart(),
+            self.allow_upscale.into_into_dart().into_dart(),
+            self.encoder_options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::BestThumbnailParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::BestThumbnailParams>
+    for crate::api::media::BestThumbnailParams
+{
+    fn into_into_dart(self) -> crate::api::media::BestThumbnailParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::CompressParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.target_bitrate_kbps.into_into_dart().into_dart(),
+            self.preset.into_into_dart().into_dart(),
+            self.crf.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.sample_duration_ms.into_into_dart().into_dart(),
+            self.crop.into_into_dart().into_dart(),
+            self.aspect_ratio.into_into_dart().into_dart(),
+            self.aspect_mode.into_into_dart().into_dart(),
+            self.pad_color.into_into_dart().into_dart(),
+            self.watermark.into_into_dart().into_dart(),
+            self.subtitles.into_into_dart().into_dart(),
+            self.speed.into_into_dart().into_dart(),
+            self.normalize_loudness.into_into_dart().into_dart(),
+            self.video_filters.into_into_dart().into_dart(),
+            self.audio_filters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::CompressParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CompressParams>
+    for crate::api::media::CompressParams
+{
+    fn into_into_dart(self) -> crate::api::media::CompressParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::CompressProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.processed_ms.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+            self.speed_x.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::CompressProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CompressProgress>
+    for crate::api::media::CompressProgress
+{
+    fn into_into_dart(self) -> crate::api::media::CompressProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::CompressionEstimate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.estimated_size_bytes.into_into_dart().into_dart(),
+            self.estimated_duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::CompressionEstimate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CompressionEstimate>
+    for crate::api::media::CompressionEstimate
+{
+    fn into_into_dart(self) -> crate::api::media::CompressionEstimate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ConcatParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.fps.into_into_dart().into_dart(),
+            self.target_bitrate_kbps.into_into_dart().into_dart(),
+            self.preset.into_into_dart().into_dart(),
+            self.crf.into_into_dart().into_dart(),
+            self.force_reencode.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ConcatParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ConcatParams>
+    for crate::api::media::ConcatParams
+{
+    fn into_into_dart(self) -> crate::api::media::ConcatParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::CropRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::CropRect {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CropRect>
+    for crate::api::media::CropRect
+{
+    fn into_into_dart(self) -> crate::api::media::CropRect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::DuckingParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.threshold_db.into_into_dart().into_dart(),
+            self.reduction_db.into_into_dart().into_dart(),
+            self.attack_ms.into_into_dart().into_dart(),
+            self.release_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::DuckingParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::DuckingParams>
+    for crate::api::media::DuckingParams
+{
+    fn into_into_dart(self) -> crate::api::media::DuckingParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::EncoderOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.jpeg_quality.into_into_dart().into_dart(),
+            self.jpeg_progressive.into_into_dart().into_dart(),
+            self.webp_quality.into_into_dart().into_dart(),
+            self.png_compression.into_into_dart().into_dart(),
+            self.avif_quality.into_into_dart().into_dart(),
+            self.heic_quality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::EncoderOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::EncoderOptions>
+    for crate::api::media::EncoderOptions
+{
+    fn into_into_dart(self) -> crate::api::media::EncoderOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ExtractedFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+            self.requested_ms.into_into_dart().into_dart(),
+            self.time_ms.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ExtractedFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ExtractedFrame>
+    for crate::api::media::ExtractedFrame
+{
+    fn into_into_dart(self) -> crate::api::media::ExtractedFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::FrameExtractParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.selection.into_into_dart().into_dart(),
+            self.size_type.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.seek_mode.into_into_dart().into_dart(),
+            self.resize_mode.into_into_dart().into_dart(),
+            self.allow_upscale.into_into_dart().into_dart(),
+            self.encoder_options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::FrameExtractParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::FrameExtractParams>
+    for crate::api::media::FrameExtractParams
+{
+    fn into_into_dart(self) -> crate::api::media::FrameExtractParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::FrameSelection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::media::FrameSelection::Timestamps(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::media::FrameSelection::Interval(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::media::FrameSelection::Count(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::FrameSelection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::FrameSelection>
+    for crate::api::media::FrameSelection
+{
+    fn into_into_dart(self) -> crate::api::media::FrameSelection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::GpsLocation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.latitude.into_into_dart().into_dart(),
+            self.longitude.into_into_dart().into_dart(),
+            self.altitude.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::GpsLocation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::GpsLocation>
+    for crate::api::media::GpsLocation
+{
+    fn into_into_dart(self) -> crate::api::media::GpsLocation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::HashAlgorithm {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::DHash => 0.into_dart(),
+            Self::PHash => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::HashAlgorithm
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::HashAlgorithm>
+    for crate::api::media::HashAlgorithm
+{
+    fn into_into_dart(self) -> crate::api::media::HashAlgorithm {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::HashComparison {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.distance.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+            self.is_similar.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::HashComparison
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::HashComparison>
+    for crate::api::media::HashComparison
+{
+    fn into_into_dart(self) -> crate::api::media::HashComparison {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::HdrFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Hdr10 => 0.into_dart(),
+            Self::Hlg => 1.into_dart(),
+            Self::DolbyVision => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::HdrFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::HdrFormat>
+    for crate::api::media::HdrFormat
+{
+    fn into_into_dart(self) -> crate::api::media::HdrFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ImageInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.has_alpha.into_into_dart().into_dart(),
+            self.orientation.into_into_dart().into_dart(),
+            self.camera_make.into_into_dart().into_dart(),
+            self.camera_model.into_into_dart().into_dart(),
+            self.capture_date.into_into_dart().into_dart(),
+            self.gps.into_into_dart().into_dart(),
+            self.icc_profile_name.into_into_dart().into_dart(),
+            self.image_count.into_into_dart().into_dart(),
+            self.auxiliary_image_count.into_into_dart().into_dart(),
+            self.has_depth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::ImageInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ImageInfo>
+    for crate::api::media::ImageInfo
+{
+    fn into_into_dart(self) -> crate::api::media::ImageInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ImageThumbnailParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.size_type.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.seek_mode.into_into_dart().into_dart(),
+            self.resize_mode.into_into_dart().into_dart(),
+            self.allow_upscale.into_into_dart().into_dart(),
+            self.encoder_options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ImageThumbnailParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ImageThumbnailParams>
+    for crate::api::media::ImageThumbnailParams
+{
+    fn into_into_dart(self) -> crate::api::media::ImageThumbnailParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::logger::LogLevel> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::logger::LogLevel::Error => 0.into_dart(),
+            crate::api::logger::LogLevel::Warn => 1.into_dart(),
+            crate::api::logger::LogLevel::Info => 2.into_dart(),
+            crate::api::logger::LogLevel::Debug => 3.into_dart(),
+            crate::api::logger::LogLevel::Trace => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::logger::LogLevel>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::logger::LogLevel>>
+    for crate::api::logger::LogLevel
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::logger::LogLevel> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::LoudnessInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.integrated_lufs.into_into_dart().into_dart(),
+            self.loudness_range_lu.into_into_dart().into_dart(),
+            self.true_peak_dbtp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::LoudnessInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::LoudnessInfo>
+    for crate::api::media::LoudnessInfo
+{
+    fn into_into_dart(self) -> crate::api::media::LoudnessInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::LoudnessNormalization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.target_lufs.into_into_dart().into_dart(),
+            self.true_peak_dbtp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::LoudnessNormalization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::LoudnessNormalization>
+    for crate::api::media::LoudnessNormalization
+{
+    fn into_into_dart(self) -> crate::api::media::LoudnessNormalization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaChapter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::MediaChapter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaChapter>
+    for crate::api::media::MediaChapter
+{
+    fn into_into_dart(self) -> crate::api::media::MediaChapter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaHash {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.algorithm.into_into_dart().into_dart(),
+            self.hashes.into_into_dart().into_dart(),
+            self.times_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::MediaHash {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaHash>
+    for crate::api::media::MediaHash
+{
+    fn into_into_dart(self) -> crate::api::media::MediaHash {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaProbe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format_name.into_into_dart().into_dart(),
+            self.format_long_name.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.start_time_ms.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.chapters.into_into_dart().into_dart(),
+            self.streams.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::MediaProbe {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaProbe>
+    for crate::api::media::MediaProbe
+{
+    fn into_into_dart(self) -> crate::api::media::MediaProbe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::MediaTag {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::MediaTag {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaTag>
+    for crate::api::media::MediaTag
+{
+    fn into_into_dart(self) -> crate::api::media::MediaTag {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::OutputFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::WEBP => 0.into_dart(),
+            Self::JPEG => 1.into_dart(),
+            Self::PNG => 2.into_dart(),
+            Self::AVIF => 3.into_dart(),
+            Self::HEIC => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::OutputFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::OutputFormat>
+    for crate::api::media::OutputFormat
+{
+    fn into_into_dart(self) -> crate::api::media::OutputFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::Palette {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.colors.into_into_dart().into_dart(),
+            self.average.into_into_dart().into_dart(),
+            self.vibrant.into_into_dart().into_dart(),
+            self.light_vibrant.into_into_dart().into_dart(),
+            self.dark_vibrant.into_into_dart().into_dart(),
+            self.muted.into_into_dart().into_dart(),
+            self.light_muted.into_into_dart().into_dart(),
+            self.dark_muted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::Palette {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::Palette> for crate::api::media::Palette {
+    fn into_into_dart(self) -> crate::api::media::Palette {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::PaletteColor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.color.into_into_dart().into_dart(),
+            self.population.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::PaletteColor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::PaletteColor>
+    for crate::api::media::PaletteColor
+{
+    fn into_into_dart(self) -> crate::api::media::PaletteColor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::PlaceholderImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rgba.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::PlaceholderImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::PlaceholderImage>
+    for crate::api::media::PlaceholderImage
+{
+    fn into_into_dart(self) -> crate::api::media::PlaceholderImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ResizeMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fit => 0.into_dart(),
+            Self::Fill => 1.into_dart(),
+            Self::Exact => 2.into_dart(),
+            Self::FitWidth => 3.into_dart(),
+            Self::FitHeight => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::ResizeMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ResizeMode>
+    for crate::api::media::ResizeMode
+{
+    fn into_into_dart(self) -> crate::api::media::ResizeMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ResolutionPreset {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.crf.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ResolutionPreset
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ResolutionPreset>
+    for crate::api::media::ResolutionPreset
+{
+    fn into_into_dart(self) -> crate::api::media::ResolutionPreset {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ReverseAudioMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reverse => 0.into_dart(),
+            Self::Mute => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ReverseAudioMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ReverseAudioMode>
+    for crate::api::media::ReverseAudioMode
+{
+    fn into_into_dart(self) -> crate::api::media::ReverseAudioMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ReverseParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.boomerang_loops.into_into_dart().into_dart(),
+            self.audio.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.target_bitrate_kbps.into_into_dart().into_dart(),
+            self.preset.into_into_dart().into_dart(),
+            self.crf.into_into_dart().into_dart(),
+            self.memory_budget_mb.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ReverseParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ReverseParams>
+    for crate::api::media::ReverseParams
+{
+    fn into_into_dart(self) -> crate::api::media::ReverseParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::SceneCut {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_ms.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::SceneCut {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::SceneCut>
+    for crate::api::media::SceneCut
+{
+    fn into_into_dart(self) -> crate::api::media::SceneCut {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::SceneDetectionEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::media::SceneDetectionEvent::Progress(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::media::SceneDetectionEvent::Cuts(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::SceneDetectionEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::SceneDetectionEvent>
+    for crate::api::media::SceneDetectionEvent
+{
+    fn into_into_dart(self) -> crate::api::media::SceneDetectionEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::SeekMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Accurate => 0.into_dart(),
+            Self::Keyframe => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::SeekMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::SeekMode>
+    for crate::api::media::SeekMode
+{
+    fn into_into_dart(self) -> crate::api::media::SeekMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::SpeedParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.factor.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+            self.mute_audio_above.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::SpeedParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::SpeedParams>
+    for crate::api::media::SpeedParams
+{
+    fn into_into_dart(self) -> crate::api::media::SpeedParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::Storyboard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.vtt_path.into_into_dart().into_dart(),
+            self.sprite_paths.into_into_dart().into_dart(),
+            self.tile_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::Storyboard {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::Storyboard>
+    for crate::api::media::Storyboard
+{
+    fn into_into_dart(self) -> crate::api::media::Storyboard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::StreamDisposition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default.into_into_dart().into_dart(),
+            self.dub.into_into_dart().into_dart(),
+            self.original.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+            self.lyrics.into_into_dart().into_dart(),
+            self.karaoke.into_into_dart().into_dart(),
+            self.forced.into_into_dart().into_dart(),
+            self.hearing_impaired.into_into_dart().into_dart(),
+            self.visual_impaired.into_into_dart().into_dart(),
+            self.clean_effects.into_into_dart().into_dart(),
+            self.attached_pic.into_into_dart().into_dart(),
+            self.captions.into_into_dart().into_dart(),
+            self.descriptions.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::StreamDisposition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::StreamDisposition>
+    for crate::api::media::StreamDisposition
+{
+    fn into_into_dart(self) -> crate::api::media::StreamDisposition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::StreamProbe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.stream_type.into_into_dart().into_dart(),
+            self.codec_name.into_into_dart().into_dart(),
+            self.codec_long_name.into_into_dart().into_dart(),
+            self.profile.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.start_time_ms.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.frame_count.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.disposition.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.side_data.into_into_dart().into_dart(),
+            self.video.into_into_dart().into_dart(),
+            self.audio.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::StreamProbe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::StreamProbe>
+    for crate::api::media::StreamProbe
+{
+    fn into_into_dart(self) -> crate::api::media::StreamProbe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::StreamSideData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::StreamSideData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::StreamSideData>
+    for crate::api::media::StreamSideData
+{
+    fn into_into_dart(self) -> crate::api::media::StreamSideData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::StreamType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Video => 0.into_dart(),
+            Self::Audio => 1.into_dart(),
+            Self::Subtitle => 2.into_dart(),
+            Self::Data => 3.into_dart(),
+            Self::Attachment => 4.into_dart(),
+            Self::Unknown => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::StreamType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::StreamType>
+    for crate::api::media::StreamType
+{
+    fn into_into_dart(self) -> crate::api::media::StreamType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::SubtitleParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.subtitle_path.into_into_dart().into_dart(),
+            self.font_path.into_into_dart().into_dart(),
+            self.font_size.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.outline_color.into_into_dart().into_dart(),
+            self.outline_width.into_into_dart().into_dart(),
+            self.bottom_margin.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::SubtitleParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::SubtitleParams>
+    for crate::api::media::SubtitleParams
+{
+    fn into_into_dart(self) -> crate::api::media::SubtitleParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ThumbnailData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.time_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ThumbnailData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ThumbnailData>
+    for crate::api::media::ThumbnailData
+{
+    fn into_into_dart(self) -> crate::api::media::ThumbnailData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ThumbnailSizeType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::media::ThumbnailSizeType::Icon => [0.into_dart()].into_dart(),
+            crate::api::media::ThumbnailSizeType::Small => [1.into_dart()].into_dart(),
+            crate::api::media::ThumbnailSizeType::Medium => [2.into_dart()].into_dart(),
+            crate::api::media::ThumbnailSizeType::Large => [3.into_dart()].into_dart(),
+            crate::api::media::ThumbnailSizeType::Larger => [4.into_dart()].into_dart(),
+            crate::api::media::ThumbnailSizeType::Custom(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ThumbnailSizeType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ThumbnailSizeType>
+    for crate::api::media::ThumbnailSizeType
+{
+    fn into_into_dart(self) -> crate::api::media::ThumbnailSizeType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::VideoInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration_ms.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.codec_name.into_into_dart().into_dart(),
+            self.format_name.into_into_dart().into_dart(),
+            self.stored_width.into_into_dart().into_dart(),
+            self.stored_height.into_into_dart().into_dart(),
+            self.rotation.into_into_dart().into_dart(),
+            self.avg_frame_rate.into_into_dart().into_dart(),
+            self.real_frame_rate.into_into_dart().into_dart(),
+            self.is_vfr.into_into_dart().into_dart(),
+            self.pixel_format.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.color_primaries.into_into_dart().into_dart(),
+            self.color_transfer.into_into_dart().into_dart(),
+            self.color_matrix.into_into_dart().into_dart(),
+            self.color_range.into_into_dart().into_dart(),
+            self.hdr_format.into_into_dart().into_dart(),
+            self.profile.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+            self.frame_count.into_into_dart().into_dart(),
+            self.audio.into_into_dart().into_dart(),
+            self.suggestions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::VideoInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::VideoInfo>
+    for crate::api::media::VideoInfo
+{
+    fn into_into_dart(self) -> crate::api::media::VideoInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::VideoStreamProbe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.display_width.into_into_dart().into_dart(),
+            self.display_height.into_into_dart().into_dart(),
+            self.rotation.into_into_dart().into_dart(),
+            self.avg_frame_rate.into_into_dart().into_dart(),
+            self.real_frame_rate.into_into_dart().into_dart(),
+            self.is_vfr.into_into_dart().into_dart(),
+            self.pixel_format.into_into_dart().into_dart(),
+            self.bit_depth.into_into_dart().into_dart(),
+            self.color_primaries.into_into_dart().into_dart(),
+            self.color_transfer.into_into_dart().into_dart(),
+            self.color_matrix.into_into_dart().into_dart(),
+            self.color_range.into_into_dart().into_dart(),
+            self.hdr_format.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::VideoStreamProbe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::VideoStreamProbe>
+    for crate::api::media::VideoStreamProbe
+{
+    fn into_into_dart(self) -> crate::api::media::VideoStreamProbe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::VideoThumbnailParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_ms.into_into_dart().into_dart(),
+            self.size_type.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.seek_mode.into_into_dart().into_dart(),
+            self.resize_mode.into_into_dart().into_dart(),
+            self.allow_upscale.into_into_dart().into_dart(),
+            self.encoder_options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::VideoThumbnailParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::VideoThumbnailParams>
+    for crate::api::media::VideoThumbnailParams
+{
+    fn into_into_dart(self) -> crate::api::media::VideoThumbnailParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::WatermarkParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.image_path.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.margin.into_into_dart().into_dart(),
+            self.scale.into_into_dart().into_dart(),
+            self.opacity.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::WatermarkParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::WatermarkParams>
+    for crate::api::media::WatermarkParams
+{
+    fn into_into_dart(self) -> crate::api::media::WatermarkParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::WatermarkPosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TopLeft => 0.into_dart(),
+            Self::TopRight => 1.into_dart(),
+            Self::BottomLeft => 2.into_dart(),
+            Self::BottomRight => 3.into_dart(),
+            Self::Center => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::WatermarkPosition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::WatermarkPosition>
+    for crate::api::media::WatermarkPosition
+{
+    fn into_into_dart(self) -> crate::api::media::WatermarkPosition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logger::WriteToFiles {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.file_prefix.into_into_dart().into_dart(),
+            self.file_suffix.into_into_dart().into_dart(),
+            self.max_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::logger::WriteToFiles
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::WriteToFiles>
+    for crate::api::logger::WriteToFiles
+{
+    fn into_into_dart(self) -> crate::api::logger::WriteToFiles {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::media::CompressProgress,
+        flutter_rust_bridge::for_generated::DcoCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::media::ExtractedFrame, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::media::SceneDetectionEvent,
+        flutter_rust_bridge::for_generated::DcoCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::media::ThumbnailData, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::AnimationFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::AnimationFormat::GIF => 0,
+                crate::api::media::AnimationFormat::WEBP => 1,
+                crate::api::media::AnimationFormat::APNG => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::AnimationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.max_bytes, serializer);
+        <Option<u16>>::sse_encode(self.loop_count, serializer);
+        <Option<bool>>::sse_encode(self.dither, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::AspectMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::AspectMode::Fit => 0,
+                crate::api::media::AspectMode::Fill => 1,
+                crate::api::media::AspectMode::BlurredFit => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::AudioChannels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::AudioChannels::Mono => 0,
+                crate::api::media::AudioChannels::Stereo => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::AudioExtractOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+        <Option<crate::api::media::AudioChannels>>::sse_encode(self.channels, serializer);
+        <Option<u32>>::sse_encode(self.sample_rate, serializer);
+        <Option<u32>>::sse_encode(self.bitrate_kbps, serializer);
+        <Option<crate::api::media::LoudnessNormalization>>::sse_encode(
+            self.normalize_loudness,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::AudioFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::AudioFormat::M4A => 0,
+                crate::api::media::AudioFormat::OPUS => 1,
+                crate::api::media::AudioFormat::WAV => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::AudioMixParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::media::AudioTrackMode>>::sse_encode(self.mode, serializer);
+        <Option<f32>>::sse_encode(self.music_gain_db, serializer);
+        <Option<f32>>::sse_encode(self.original_gain_db, serializer);
+        <Option<crate::api::media::DuckingParams>>::sse_encode(self.ducking, serializer);
+        <bool>::sse_encode(self.loop_music, serializer);
+        <Option<u64>>::sse_encode(self.music_start_ms, serializer);
+        <Option<u64>>::sse_encode(self.fade_in_ms, serializer);
+        <Option<u64>>::sse_encode(self.fade_out_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::AudioStreamInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.codec_name, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <Option<u64>>::sse_encode(self.bitrate, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::AudioStreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.sample_rate, serializer);
+        <u32>::sse_encode(self.channels, serializer);
+        <Option<String>>::sse_encode(self.channel_layout, serializer);
+        <Option<String>>::sse_encode(self.sample_format, serializer);
+        <Option<u32>>::sse_encode(self.bits_per_sample, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::AudioTrackMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::AudioTrackMode::Replace => 0,
+                crate::api::media::AudioTrackMode::Mix => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::BestThumbnail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.time_ms, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::BestThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
+        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+        <Option<u32>>::sse_encode(self.candidates, serializer);
+        <Option<crate::api::media::ResizeMode>>::sse_encode(self.resize_mode, serializer);
+        <Option<bool>>::sse_encode(self.allow_upscale, serializer);
+        <Option<crate::api::media::EncoderOptions>>::sse_encode(self.encoder_options, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::media::CompressParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.target_bitrate_kbps, serializer);
+        <Option<String>>::sse_encode(self.preset, serializer);
+        <Option<u8>>::sse_encode(self.crf, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<u64>>::sse_encode(self.sample_duration_ms, serializer);
+        <Option<crate::api::media::CropRect>>::sse_encode(self.crop, serializer);
+        <Option<(u32, u32)>>::sse_encode(self.aspect_ratio, serializer);
+        <Option<crate::api::media::AspectMode>>::sse_encode(self.aspect_mode, serializer);
+        <Option<u32>>::sse_encode(self.pad_color, serializer);
+        <Option<crate::api::media::WatermarkParams>>::sse_encode(self.watermark, serializer);
+        <Option<crate::api::media::SubtitleParams>>::sse_encode(self.subtitles, serializer);
+        <Option<crate::api::media::SpeedParams>>::sse_encode(self.speed, serializer);
+        <Option<crate::api::media::LoudnessNormalization>>::sse_encode(
+            self.normalize_loudness,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.video_filters, serializer);
+        <Option<String>>::sse_encode(self.audio_filters, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::CompressProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.processed_ms, serializer);
+        <u64>::sse_encode(self.total_ms, serializer);
+        <f32>::sse_encode(self.speed_x, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::CompressionEstimate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.estimated_size_bytes, serializer);
+        <u64>::sse_encode(self.estimated_duration_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ConcatParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<u32>>::sse_encode(self.fps, serializer);
+        <Option<u32>>::sse_encode(self.target_bitrate_kbps, serializer);
+        <Option<String>>::sse_encode(self.preset, serializer);
+        <Option<u8>>::sse_encode(self.crf, serializer);
+        <Option<bool>>::sse_encode(self.force_reencode, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::CropRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.x, serializer);
+        <u32>::sse_encode(self.y, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::DuckingParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f32>>::sse_encode(self.threshold_db, serializer);
+        <Option<f32>>::sse_encode(self.reduction_db, serializer);
+        <Option<u32>>::sse_encode(self.attack_ms, serializer);
+        <Option<u32>>::sse_encode(self.release_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::EncoderOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u8>>::sse_encode(self.jpeg_quality, serializer);
+        <Option<bool>>::sse_encode(self.jpeg_progressive, serializer);
+        <Option<f32>>::sse_encode(self.webp_quality, serializer);
+        <Option<u8>>::sse_encode(self.png_compression, serializer);
+        <Option<u8>>::sse_encode(self.avif_quality, serializer);
+        <Option<u8>>::sse_encode(self.heic_quality, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ExtractedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u32>::sse_encode(self.index, serializer);
+        <u64>::sse_encode(self.requested_ms, serializer);
+        <u64>::sse_encode(self.time_ms, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::media::FrameExtractParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::media::FrameSelection>::sse_encode(self.selection, serializer);
+        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
+        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+        <Option<crate::api::media::SeekMode>>::sse_encode(self.seek_mode, serializer);
+        <Option<crate::api::media::ResizeMode>>::sse_encode(self.resize_mode, serializer);
+        <Option<bool>>::sse_encode(self.allow_upscale, serializer);
+        <Option<crate::api::media::EncoderOptions>>::sse_encode(self.encoder_options, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::FrameSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::media::FrameSelection::Timestamps(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<u64>>::sse_encode(field0, serializer);
+            }
+            crate::api::media::FrameSelection::Interval(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::media::FrameSelection::Count(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::media::GpsLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.latitude, serializer);
+        <f64>::sse_encode(self.longitude, serializer);
+        <Option<f64>>::sse_encode(self.altitude, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::HashAlgorithm {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::HashAlgorithm::DHash => 0,
+                crate::api::media::HashAlgorithm::PHash => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::HashComparison {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.distance, serializer);
+        <f64>::sse_encode(self.similarity, serializer);
+        <bool>::sse_encode(self.is_similar, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::HdrFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::HdrFormat::Hdr10 => 0,
+                crate::api::media::HdrFormat::Hlg => 1,
+                crate::api::media::HdrFormat::DolbyVision => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::media::ImageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.bit_depth, serializer);
+        <bool>::sse_encode(self.has_alpha, serializer);
+        <u32>::sse_encode(self.orientation, serializer);
+        <Option<String>>::sse_encode(self.camera_make, serializer);
+        <Option<String>>::sse_encode(self.camera_model, serializer);
+        <Option<String>>::sse_encode(self.capture_date, serializer);
+        <Option<crate::api::media::GpsLocation>>::sse_encode(self.gps, serializer);
+        <Option<String>>::sse_encode(self.icc_profile_name, serializer);
+        <u32>::sse_encode(self.image_count, serializer);
+        <u32>::sse_encode(self.auxiliary_image_count, serializer);
+        <bool>::sse_encode(self.has_depth, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ImageThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
+        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+        <Option<crate::api::media::SeekMode>>::sse_encode(self.seek_mode, serializer);
+        <Option<crate::api::media::ResizeMode>>::sse_encode(self.resize_mode, serializer);
+        <Option<bool>>::sse_encode(self.allow_upscale, serializer);
+        <Option<crate::api::media::EncoderOptions>>::sse_encode(self.encoder_options, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::MediaChapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::MediaChapter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::MediaTag> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::MediaTag>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::PaletteColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::PaletteColor>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::ResolutionPreset> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::ResolutionPreset>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::SceneCut> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::SceneCut>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::StreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::StreamProbe>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::StreamSideData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::StreamSideData>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::logger::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::logger::LogLevel::Error => 0,
+                crate::api::logger::LogLevel::Warn => 1,
+                crate::api::logger::LogLevel::Info => 2,
+                crate::api::logger::LogLevel::Debug => 3,
+                crate::api::logger::LogLevel::Trace => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::LoudnessInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.integrated_lufs, serializer);
+        <f64>::sse_encode(self.loudness_range_lu, serializer);
+        <f64>::sse_encode(self.true_peak_dbtp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::LoudnessNormalization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.target_lufs, serializer);
+        <Option<f32>>::sse_encode(self.true_peak_dbtp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::MediaChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <u64>::sse_encode(self.start_ms, serializer);
+        <u64>::sse_encode(self.end_ms, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Vec<crate::api::media::MediaTag>>::sse_encode(self.tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::MediaHash {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::media::HashAlgorithm>::sse_encode(self.algorithm, serializer);
+        <Vec<u64>>::sse_encode(self.hashes, serializer);
+        <Vec<u64>>::sse_encode(self.times_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::MediaProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.format_name, serializer);
+        <Option<String>>::sse_encode(self.format_long_name, serializer);
+        <Option<u64>>::sse_encode(self.duration_ms, serializer);
+        <Option<i64>>::sse_encode(self.start_time_ms, serializer);
+        <Option<u64>>::sse_encode(self.bitrate, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <Vec<crate::api::media::MediaTag>>::sse_encode(self.tags, serializer);
+        <Vec<crate::api::media::MediaChapter>>::sse_encode(self.chapters, serializer);
+        <Vec<crate::api::media::StreamProbe>>::sse_encode(self.streams, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::MediaTag {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::AspectMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::AspectMode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::AudioChannels> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::AudioChannels>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::AudioStreamInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::AudioStreamInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::AudioStreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::AudioStreamProbe>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::AudioTrackMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::AudioTrackMode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::CropRect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::CropRect>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::DuckingParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::DuckingParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::EncoderOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::EncoderOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::GpsLocation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::GpsLocation>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::HashAlgorithm> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::HashAlgorithm>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::HdrFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::HdrFormat>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::ImageThumbnailParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::ImageThumbnailParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::LoudnessNormalization> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::LoudnessNormalization>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::OutputFormat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::OutputFormat>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::PaletteColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::PaletteColor>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<(u32, u32)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <(u32, u32)>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::ResizeMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::ResizeMode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::ReverseAudioMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::ReverseAudioMode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::SeekMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::SeekMode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::SpeedParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::SpeedParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::SubtitleParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::SubtitleParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::ThumbnailSizeType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::ThumbnailSizeType>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::VideoStreamProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::VideoStreamProbe>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::WatermarkParams> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::WatermarkParams>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::media::WatermarkPosition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::WatermarkPosition>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::logger::WriteToFiles> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::logger::WriteToFiles>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::media::OutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::OutputFormat::WEBP => 0,
+                crate::api::media::OutputFormat::JPEG => 1,
+                crate::api::media::OutputFormat::PNG => 2,
+                crate::api::media::OutputFormat::AVIF => 3,
+                crate::api::media::OutputFormat::HEIC => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::Palette {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::media::PaletteColor>>::sse_encode(self.colors, serializer);
+        <crate::api::media::PaletteColor>::sse_encode(self.average, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.vibrant, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.light_vibrant, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.dark_vibrant, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.muted, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.light_muted, serializer);
+        <Option<crate::api::media::PaletteColor>>::sse_encode(self.dark_muted, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::PaletteColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.color, serializer);
+        <f64>::sse_encode(self.population, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::PlaceholderImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.rgba, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for (u32, u32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.0, serializer);
+        <u32>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ResizeMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::ResizeMode::Fit => 0,
+                crate::api::media::ResizeMode::Fill => 1,
+                crate::api::media::ResizeMode::Exact => 2,
+                crate::api::media::ResizeMode::FitWidth => 3,
+                crate::api::media::ResizeMode::FitHeight => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::ResolutionPreset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.bitrate, serializer);
+        <u8>::sse_encode(self.crf, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ReverseAudioMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::ReverseAudioMode::Reverse => 0,
+                crate::api::media::ReverseAudioMode::Mute => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::ReverseParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+        <Option<u32>>::sse_encode(self.boomerang_loops, serializer);
+        <Option<crate::api::media::ReverseAudioMode>>::sse_encode(self.audio, serializer);
+        <Option<u32>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.height, serializer);
+        <Option<u32>>::sse_encode(self.target_bitrate_kbps, serializer);
+        <Option<String>>::sse_encode(self.preset, serializer);
+        <Option<u8>>::sse_encode(self.crf, serializer);
+        <Option<u32>>::sse_encode(self.memory_budget_mb, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::SceneCut {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.time_ms, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::SceneDetectionEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::media::SceneDetectionEvent::Progress(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::media::CompressProgress>::sse_encode(field0, serializer);
+            }
+            crate::api::media::SceneDetectionEvent::Cuts(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<crate::api::media::SceneCut>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::media::SeekMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::SeekMode::Accurate => 0,
+                crate::api::media::SeekMode::Keyframe => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::SpeedParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.factor, serializer);
+        <Option<u32>>::sse_encode(self.frame_rate, serializer);
+        <Option<f32>>::sse_encode(self.mute_audio_above, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::Storyboard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.vtt_path, serializer);
+        <Vec<String>>::sse_encode(self.sprite_paths, serializer);
+        <u32>::sse_encode(self.tile_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::StreamDisposition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.default, serializer);
+        <bool>::sse_encode(self.dub, serializer);
+        <bool>::sse_encode(self.original, serializer);
+        <bool>::sse_encode(self.comment, serializer);
+        <bool>::sse_encode(self.lyrics, serializer);
+        <bool>::sse_encode(self.karaoke, serializer);
+        <bool>::sse_encode(self.forced, serializer);
+        <bool>::sse_encode(self.hearing_impaired, serializer);
+        <bool>::sse_encode(self.visual_impaired, serializer);
+        <bool>::sse_encode(self.clean_effects, serializer);
+        <bool>::sse_encode(self.attached_pic, serializer);
+        <bool>::sse_encode(self.captions, serializer);
+        <bool>::sse_encode(self.descriptions, serializer);
+        <bool>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::StreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <crate::api::media::StreamType>::sse_encode(self.stream_type, serializer);
+        <Option<String>>::sse_encode(self.codec_name, serializer);
+        <Option<String>>::sse_encode(self.codec_long_name, serializer);
+        <Option<String>>::sse_encode(self.profile, serializer);
+        <Option<u64>>::sse_encode(self.bitrate, serializer);
+        <Option<i64>>::sse_encode(self.start_time_ms, serializer);
+        <Option<u64>>::sse_encode(self.duration_ms, serializer);
+        <Option<u64>>::sse_encode(self.frame_count, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <crate::api::media::StreamDisposition>::sse_encode(self.disposition, serializer);
+        <Vec<crate::api::media::MediaTag>>::sse_encode(self.tags, serializer);
+        <Vec<crate::api::media::StreamSideData>>::sse_encode(self.side_data, serializer);
+        <Option<crate::api::media::VideoStreamProbe>>::sse_encode(self.video, serializer);
+        <Option<crate::api::media::AudioStreamProbe>>::sse_encode(self.audio, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::StreamSideData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.size_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::StreamType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::StreamType::Video => 0,
+                crate::api::media::StreamType::Audio => 1,
+                crate::api::media::StreamType::Subtitle => 2,
+                crate::api::media::StreamType::Data => 3,
+                crate::api::media::StreamType::Attachment => 4,
+                crate::api::media::StreamType::Unknown => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::media::SubtitleParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.subtitle_path, serializer);
+        <Option<String>>::sse_encode(self.font_path, serializer);
+        <Option<u32>>::sse_encode(self.font_size, serializer);
+        <Option<u32>>::sse_encode(self.color, serializer);
+        <Option<u32>>::sse_encode(self.outline_color, serializer);
+        <Option<u32>>::sse_encode(self.outline_width, serializer);
+        <Option<u32>>::sse_encode(self.bottom_margin, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ThumbnailData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Option<u64>>::sse_encode(self.time_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::ThumbnailSizeType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::media::ThumbnailSizeType::Icon => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::media::ThumbnailSizeType::Small => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::media::ThumbnailSizeType::Medium => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::media::ThumbnailSizeType::Large => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::media::ThumbnailSizeType::Larger => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::media::ThumbnailSizeType::Custom(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <(u32, u32)>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::api::media::VideoInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <Option<u64>>::sse_encode(self.bitrate, serializer);
+        <Option<String>>::sse_encode(self.codec_name, serializer);
+        <Option<String>>::sse_encode(self.format_name, serializer);
+        <u32>::sse_encode(self.stored_width, serializer);
+        <u32>::sse_encode(self.stored_height, serializer);
+        <i32>::sse_encode(self.rotation, serializer);
+        <Option<f64>>::sse_encode(self.avg_frame_rate, serializer);
+        <Option<f64>>::sse_encode(self.real_frame_rate, serializer);
+        <bool>::sse_encode(self.is_vfr, serializer);
+        <Option<String>>::sse_encode(self.pixel_format, serializer);
+        <Option<u32>>::sse_encode(self.bit_depth, serializer);
+        <Option<String>>::sse_encode(self.color_primaries, serializer);
+        <Option<String>>::sse_encode(self.color_transfer, serializer);
+        <Option<String>>::sse_encode(self.color_matrix, serializer);
+        <Option<String>>::sse_encode(self.color_range, serializer);
+        <Option<crate::api::media::HdrFormat>>::sse_encode(self.hdr_format, serializer);
+        <Option<String>>::sse_encode(self.profile, serializer);
+        <Option<i32>>::sse_encode(self.level, serializer);
+        <Option<u64>>::sse_encode(self.frame_count, serializer);
+        <Option<crate::api::media::AudioStreamInfo>>::sse_encode(self.audio, serializer);
+        <Vec<crate::api::media::ResolutionPreset>>::sse_encode(self.suggestions, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::VideoStreamProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.display_width, serializer);
+        <u32>::sse_encode(self.display_height, serializer);
+        <i32>::sse_encode(self.rotation, serializer);
+        <Option<f64>>::sse_encode(self.avg_frame_rate, serializer);
+        <Option<f64>>::sse_encode(self.real_frame_rate, serializer);
+        <bool>::sse_encode(self.is_vfr, serializer);
+        <Option<String>>::sse_encode(self.pixel_format, serializer);
+        <Option<u32>>::sse_encode(self.bit_depth, serializer);
+        <Option<String>>::sse_encode(self.color_primaries, serializer);
+        <Option<String>>::sse_encode(self.color_transfer, serializer);
+        <Option<String>>::sse_encode(self.color_matrix, serializer);
+        <Option<String>>::sse_encode(self.color_range, serializer);
+        <Option<crate::api::media::HdrFormat>>::sse_encode(self.hdr_format, serializer);
+        <Option<i32>>::sse_encode(self.level, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::VideoThumbnailParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.time_ms, serializer);
+        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
+        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+        <Option<crate::api::media::SeekMode>>::sse_encode(self.seek_mode, serializer);
+        <Option<crate::api::media::ResizeMode>>::sse_encode(self.resize_mode, serializer);
+        <Option<bool>>::sse_encode(self.allow_upscale, serializer);
+        <Option<crate::api::media::EncoderOptions>>::sse_encode(self.encoder_options, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::WatermarkParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.image_path, serializer);
+        <Option<crate::api::media::WatermarkPosition>>::sse_encode(self.position, serializer);
+        <Option<u32>>::sse_encode(self.margin, serializer);
+        <Option<f32>>::sse_encode(self.scale, serializer);
+        <Option<f32>>::sse_encode(self.opacity, serializer);
+        <Option<u64>>::sse_encode(self.start_ms, serializer);
+        <Option<u64>>::sse_encode(self.end_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::media::WatermarkPosition::TopLeft => 0,
+                crate::api::media::WatermarkPosition::TopRight => 1,
+                crate::api::media::WatermarkPosition::BottomLeft => 2,
+                crate::api::media::WatermarkPosition::BottomRight => 3,
+                crate::api::media::WatermarkPosition::Center => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::logger::WriteToFiles {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.file_prefix, serializer);
+        <Option<String>>::sse_encode(self.file_suffix, serializer);
+        <Option<u64>>::sse_encode(self.max_files, serializer);
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    // This file is automatically generated, so please do not edit it.
+    // @generated by `flutter_rust_bridge`@ 2.11.1.
+
+    // Section: imports
+
+    use super::*;
+    use flutter_rust_bridge::for_generated::byteorder::{
+        NativeEndian, ReadBytesExt, WriteBytesExt,
+    };
+    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
+    use flutter_rust_bridge::{Handler, IntoIntoDart};
+
+    // Section: boilerplate
+
+    flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    // Section: dart2rust
+
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
+        for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
+        }
+    }
+    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>
+        for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::CompressProgress,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ExtractedFrame,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ExtractedFrame,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::SceneDetectionEvent,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::SceneDetectionEvent,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ThumbnailData,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ThumbnailData,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> String {
+            let vec: Vec<u8> = self.cst_decode();
+            String::from_utf8(vec).unwrap()
+        }
+    }
+    impl CstDecode<crate::api::media::AnimationOptions> for wire_cst_animation_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AnimationOptions {
+            crate::api::media::AnimationOptions {
+                max_bytes: self.max_bytes.cst_decode(),
+                loop_count: self.loop_count.cst_decode(),
+                dither: self.dither.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::AudioExtractOptions> for wire_cst_audio_extract_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioExtractOptions {
+            crate::api::media::AudioExtractOptions {
+                start_ms: self.start_ms.cst_decode(),
+                end_ms: self.end_ms.cst_decode(),
+                channels: self.channels.cst_decode(),
+                sample_rate: self.sample_rate.cst_decode(),
+                bitrate_kbps: self.bitrate_kbps.cst_decode(),
+                normalize_loudness: self.normalize_loudness.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::AudioMixParams> for wire_cst_audio_mix_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioMixParams {
+            crate::api::media::AudioMixParams {
+                mode: self.mode.cst_decode(),
+                music_gain_db: self.music_gain_db.cst_decode(),
+                original_gain_db: self.original_gain_db.cst_decode(),
+                ducking: self.ducking.cst_decode(),
+                loop_music: self.loop_music.cst_decode(),
+                music_start_ms: self.music_start_ms.cst_decode(),
+                fade_in_ms: self.fade_in_ms.cst_decode(),
+                fade_out_ms: self.fade_out_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::AudioStreamInfo> for wire_cst_audio_stream_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamInfo {
+            crate::api::media::AudioStreamInfo {
+                codec_name: self.codec_name.cst_decode(),
+                channels: self.channels.cst_decode(),
+                sample_rate: self.sample_rate.cst_decode(),
+                bitrate: self.bitrate.cst_decode(),
+                language: self.language.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::AudioStreamProbe> for wire_cst_audio_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamProbe {
+            crate::api::media::AudioStreamProbe {
+                sample_rate: self.sample_rate.cst_decode(),
+                channels: self.channels.cst_decode(),
+                channel_layout: self.channel_layout.cst_decode(),
+                sample_format: self.sample_format.cst_decode(),
+                bits_per_sample: self.bits_per_sample.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::BestThumbnail> for wire_cst_best_thumbnail {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::BestThumbnail {
+            crate::api::media::BestThumbnail {
+                data: self.data.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                time_ms: self.time_ms.cst_decode(),
+                score: self.score.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::BestThumbnailParams> for wire_cst_best_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::BestThumbnailParams {
+            crate::api::media::BestThumbnailParams {
+                size_type: self.size_type.cst_decode(),
+                format: self.format.cst_decode(),
+                candidates: self.candidates.cst_decode(),
+                resize_mode: self.resize_mode.cst_decode(),
+                allow_upscale: self.allow_upscale.cst_decode(),
+                encoder_options: self.encoder_options.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::AnimationOptions> for *mut wire_cst_animation_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AnimationOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AnimationOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AspectMode> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AspectMode {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AspectMode>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioChannels> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioChannels {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioChannels>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioExtractOptions> for *mut wire_cst_audio_extract_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioExtractOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioExtractOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioMixParams> for *mut wire_cst_audio_mix_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioMixParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioMixParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioStreamInfo> for *mut wire_cst_audio_stream_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamInfo {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioStreamInfo>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioStreamProbe> for *mut wire_cst_audio_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamProbe {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioStreamProbe>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioTrackMode> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioTrackMode {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::AudioTrackMode>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::BestThumbnailParams> for *mut wire_cst_best_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::BestThumbnailParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::BestThumbnailParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<bool> for *mut bool {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> bool {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::api::media::CompressParams> for *mut wire_cst_compress_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::CompressParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::CompressProgress> for *mut wire_cst_compress_progress {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressProgress {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::CompressProgress>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::ConcatParams> for *mut wire_cst_concat_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ConcatParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ConcatParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::CropRect> for *mut wire_cst_crop_rect {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CropRect {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::CropRect>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::DuckingParams> for *mut wire_cst_ducking_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::DuckingParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::DuckingParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::EncoderOptions> for *mut wire_cst_encoder_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::EncoderOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::EncoderOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<f32> for *mut f32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<f64> for *mut f64 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f64 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::api::media::FrameExtractParams> for *mut wire_cst_frame_extract_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::FrameExtractParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::FrameExtractParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::GpsLocation> for *mut wire_cst_gps_location {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::GpsLocation {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::GpsLocation>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::HashAlgorithm> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::HashAlgorithm {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::HashAlgorithm>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::HdrFormat> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::HdrFormat {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::HdrFormat>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<i32> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<i64> for *mut i64 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i64 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::api::media::ImageThumbnailParams> for *mut wire_cst_image_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ImageThumbnailParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::LoudnessNormalization> for *mut wire_cst_loudness_normalization {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::LoudnessNormalization {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::LoudnessNormalization>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::MediaHash> for *mut wire_cst_media_hash {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaHash {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::MediaHash>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::OutputFormat> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::OutputFormat {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::OutputFormat>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::PaletteColor> for *mut wire_cst_palette_color {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::PaletteColor {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::PaletteColor>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<(u32, u32)> for *mut wire_cst_record_u_32_u_32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> (u32, u32) {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<(u32, u32)>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::ResizeMode> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ResizeMode {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ResizeMode>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::ReverseAudioMode> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ReverseAudioMode {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ReverseAudioMode>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::ReverseParams> for *mut wire_cst_reverse_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ReverseParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ReverseParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::SeekMode> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SeekMode {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::SeekMode>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::SpeedParams> for *mut wire_cst_speed_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SpeedParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::SpeedParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::SubtitleParams> for *mut wire_cst_subtitle_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SubtitleParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::SubtitleParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::ThumbnailSizeType> for *mut wire_cst_thumbnail_size_type {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ThumbnailSizeType {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::ThumbnailSizeType>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<u16> for *mut u16 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u16 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<u32> for *mut u32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<u64> for *mut u64 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u64 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<u8> for *mut u8 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u8 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::api::media::VideoStreamProbe> for *mut wire_cst_video_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoStreamProbe {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::VideoStreamProbe>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::VideoThumbnailParams> for *mut wire_cst_video_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoThumbnailParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::VideoThumbnailParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::WatermarkParams> for *mut wire_cst_watermark_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::WatermarkParams {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::WatermarkParams>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::WatermarkPosition> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::WatermarkPosition {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::media::WatermarkPosition>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::logger::WriteToFiles> for *mut wire_cst_write_to_files {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::logger::WriteToFiles {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::api::logger::WriteToFiles>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::api::media::CompressParams> for wire_cst_compress_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressParams {
+            crate::api::media::CompressParams {
+                target_bitrate_kbps: self.target_bitrate_kbps.cst_decode(),
+                preset: self.preset.cst_decode(),
+                crf: self.crf.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                sample_duration_ms: self.sample_duration_ms.cst_decode(),
+                crop: self.crop.cst_decode(),
+                aspect_ratio: self.aspect_ratio.cst_decode(),
+                aspect_mode: self.aspect_mode.cst_decode(),
+                pad_color: self.pad_color.cst_decode(),
+                watermark: self.watermark.cst_decode(),
+                subtitles: self.subtitles.cst_decode(),
+                speed: self.speed.cst_decode(),
+                normalize_loudness: self.normalize_loudness.cst_decode(),
+                video_filters: self.video_filters.cst_decode(),
+                audio_filters: self.audio_filters.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::CompressProgress> for wire_cst_compress_progress {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressProgress {
+            crate::api::media::CompressProgress {
+                processed_ms: self.processed_ms.cst_decode(),
+                total_ms: self.total_ms.cst_decode(),
+                speed_x: self.speed_x.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::CompressionEstimate> for wire_cst_compression_estimate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressionEstimate {
+            crate::api::media::CompressionEstimate {
+                estimated_size_bytes: self.estimated_size_bytes.cst_decode(),
+                estimated_duration_ms: self.estimated_duration_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ConcatParams> for wire_cst_concat_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ConcatParams {
+            crate::api::media::ConcatParams {
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                fps: self.fps.cst_decode(),
+                target_bitrate_kbps: self.target_bitrate_kbps.cst_decode(),
+                preset: self.preset.cst_decode(),
+                crf: self.crf.cst_decode(),
+                force_reencode: self.force_reencode.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::CropRect> for wire_cst_crop_rect {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CropRect {
+            crate::api::media::CropRect {
+                x: self.x.cst_decode(),
+                y: self.y.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::DuckingParams> for wire_cst_ducking_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::DuckingParams {
+            crate::api::media::DuckingParams {
+                threshold_db: self.threshold_db.cst_decode(),
+                reduction_db: self.reduction_db.cst_decode(),
+                attack_ms: self.attack_ms.cst_decode(),
+                release_ms: self.release_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::EncoderOptions> for wire_cst_encoder_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::EncoderOptions {
+            crate::api::media::EncoderOptions {
+                jpeg_quality: self.jpeg_quality.cst_decode(),
+                jpeg_progressive: self.jpeg_progressive.cst_decode(),
+                webp_quality: self.webp_quality.cst_decode(),
+                png_compression: self.png_compression.cst_decode(),
+                avif_quality: self.avif_quality.cst_decode(),
+                heic_quality: self.heic_quality.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ExtractedFrame> for wire_cst_extracted_frame {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ExtractedFrame {
+            crate::api::media::ExtractedFrame {
+                path: self.path.cst_decode(),
+                index: self.index.cst_decode(),
+                requested_ms: self.requested_ms.cst_decode(),
+                time_ms: self.time_ms.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::FrameExtractParams> for wire_cst_frame_extract_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::FrameExtractParams {
+            crate::api::media::FrameExtractParams {
+                selection: self.selection.cst_decode(),
+                size_type: self.size_type.cst_decode(),
+                format: self.format.cst_decode(),
+                seek_mode: self.seek_mode.cst_decode(),
+                resize_mode: self.resize_mode.cst_decode(),
+                allow_upscale: self.allow_upscale.cst_decode(),
+                encoder_options: self.encoder_options.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::FrameSelection> for wire_cst_frame_selection {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::FrameSelection {
+            match self.tag {
+                0 => {
+                    let ans = unsafe { self.kind.Timestamps };
+                    crate::api::media::FrameSelection::Timestamps(ans.field0.cst_decode())
+                }
+                1 => {
+                    let ans = unsafe { self.kind.Interval };
+                    crate::api::media::FrameSelection::Interval(ans.field0.cst_decode())
+                }
+                2 => {
+                    let ans = unsafe { self.kind.Count };
+                    crate::api::media::FrameSelection::Count(ans.field0.cst_decode())
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::GpsLocation> for wire_cst_gps_location {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::GpsLocation {
+            crate::api::media::GpsLocation {
+                latitude: self.latitude.cst_decode(),
+                longitude: self.longitude.cst_decode(),
+                altitude: self.altitude.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::HashComparison> for wire_cst_hash_comparison {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::HashComparison {
+            crate::api::media::HashComparison {
+                distance: self.distance.cst_decode(),
+                similarity: self.similarity.cst_decode(),
+                is_similar: self.is_similar.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ImageInfo> for wire_cst_image_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ImageInfo {
+            crate::api::media::ImageInfo {
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                format: self.format.cst_decode(),
+                bit_depth: self.bit_depth.cst_decode(),
+                has_alpha: self.has_alpha.cst_decode(),
+                orientation: self.orientation.cst_decode(),
+                camera_make: self.camera_make.cst_decode(),
+                camera_model: self.camera_model.cst_decode(),
+                capture_date: self.capture_date.cst_decode(),
+                gps: self.gps.cst_decode(),
+                icc_profile_name: self.icc_profile_name.cst_decode(),
+                image_count: self.image_count.cst_decode(),
+                auxiliary_image_count: self.auxiliary_image_count.cst_decode(),
+                has_depth: self.has_depth.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ImageThumbnailParams> for wire_cst_image_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
+            crate::api::media::ImageThumbnailParams {
+                size_type: self.size_type.cst_decode(),
+                format: self.format.cst_decode(),
+                seek_mode: self.seek_mode.cst_decode(),
+                resize_mode: self.resize_mode.cst_decode(),
+                allow_upscale: self.allow_upscale.cst_decode(),
+                encoder_options: self.encoder_options.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<Vec<String>> for *mut wire_cst_list_String {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<String> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::MediaChapter>> for *mut wire_cst_list_media_chapter {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::MediaChapter> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::MediaTag>> for *mut wire_cst_list_media_tag {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::MediaTag> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::PaletteColor>> for *mut wire_cst_list_palette_color {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::PaletteColor> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<u64>> for *mut wire_cst_list_prim_u_64_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u64> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_loose {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::ResolutionPreset>> for *mut wire_cst_list_resolution_preset {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::ResolutionPreset> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::SceneCut>> for *mut wire_cst_list_scene_cut {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::SceneCut> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::StreamProbe>> for *mut wire_cst_list_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::StreamProbe> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::media::StreamSideData>> for *mut wire_cst_list_stream_side_data {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::StreamSideData> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<crate::api::media::LoudnessInfo> for wire_cst_loudness_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::LoudnessInfo {
+            crate::api::media::LoudnessInfo {
+                integrated_lufs: self.integrated_lufs.cst_decode(),
+                loudness_range_lu: self.loudness_range_lu.cst_decode(),
+                true_peak_dbtp: self.true_peak_dbtp.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::LoudnessNormalization> for wire_cst_loudness_normalization {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::LoudnessNormalization {
+            crate::api::media::LoudnessNormalization {
+                target_lufs: self.target_lufs.cst_decode(),
+                true_peak_dbtp: self.true_peak_dbtp.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::MediaChapter> for wire_cst_media_chapter {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaChapter {
+            crate::api::media::MediaChapter {
+                id: self.id.cst_decode(),
+                start_ms: self.start_ms.cst_decode(),
+                end_ms: self.end_ms.cst_decode(),
+                title: self.title.cst_decode(),
+                tags: self.tags.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::MediaHash> for wire_cst_media_hash {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaHash {
+            crate::api::media::MediaHash {
+                algorithm: self.algorithm.cst_decode(),
+                hashes: self.hashes.cst_decode(),
+                times_ms: self.times_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::MediaProbe> for wire_cst_media_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaProbe {
+            crate::api::media::MediaProbe {
+                format_name: self.format_name.cst_decode(),
+                format_long_name: self.format_long_name.cst_decode(),
+                duration_ms: self.duration_ms.cst_decode(),
+                start_time_ms: self.start_time_ms.cst_decode(),
+                bitrate: self.bitrate.cst_decode(),
+                size_bytes: self.size_bytes.cst_decode(),
+                tags: self.tags.cst_decode(),
+                chapters: self.chapters.cst_decode(),
+                streams: self.streams.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::MediaTag> for wire_cst_media_tag {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaTag {
+            crate::api::media::MediaTag {
+                key: self.key.cst_decode(),
+                value: self.value.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::Palette> for wire_cst_palette {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::Palette {
+            crate::api::media::Palette {
+                colors: self.colors.cst_decode(),
+                average: self.average.cst_decode(),
+                vibrant: self.vibrant.cst_decode(),
+                light_vibrant: self.light_vibrant.cst_decode(),
+                dark_vibrant: self.dark_vibrant.cst_decode(),
+                muted: self.muted.cst_decode(),
+                light_muted: self.light_muted.cst_decode(),
+                dark_muted: self.dark_muted.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::PaletteColor> for wire_cst_palette_color {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::PaletteColor {
+            crate::api::media::PaletteColor {
+                color: self.color.cst_decode(),
+                population: self.population.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::PlaceholderImage> for wire_cst_placeholder_image {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::PlaceholderImage {
+            crate::api::media::PlaceholderImage {
+                rgba: self.rgba.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<(u32, u32)> for wire_cst_record_u_32_u_32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> (u32, u32) {
+            (self.field0.cst_decode(), self.field1.cst_decode())
+        }
+    }
+    impl CstDecode<crate::api::media::ResolutionPreset> for wire_cst_resolution_preset {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ResolutionPreset {
+            crate::api::media::ResolutionPreset {
+                name: self.name.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                bitrate: self.bitrate.cst_decode(),
+                crf: self.crf.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ReverseParams> for wire_cst_reverse_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ReverseParams {
+            crate::api::media::ReverseParams {
+                start_ms: self.start_ms.cst_decode(),
+                end_ms: self.end_ms.cst_decode(),
+                boomerang_loops: self.boomerang_loops.cst_decode(),
+                audio: self.audio.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                target_bitrate_kbps: self.target_bitrate_kbps.cst_decode(),
+                preset: self.preset.cst_decode(),
+                crf: self.crf.cst_decode(),
+                memory_budget_mb: self.memory_budget_mb.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::SceneCut> for wire_cst_scene_cut {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SceneCut {
+            crate::api::media::SceneCut {
+                time_ms: self.time_ms.cst_decode(),
+                score: self.score.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::SceneDetectionEvent> for wire_cst_scene_detection_event {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SceneDetectionEvent {
+            match self.tag {
+                0 => {
+                    let ans = unsafe { self.kind.Progress };
+                    crate::api::media::SceneDetectionEvent::Progress(ans.field0.cst_decode())
+                }
+                1 => {
+                    let ans = unsafe { self.kind.Cuts };
+                    crate::api::media::SceneDetectionEvent::Cuts(ans.field0.cst_decode())
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::SpeedParams> for wire_cst_speed_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SpeedParams {
+            crate::api::media::SpeedParams {
+                factor: self.factor.cst_decode(),
+                frame_rate: self.frame_rate.cst_decode(),
+                mute_audio_above: self.mute_audio_above.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::Storyboard> for wire_cst_storyboard {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::Storyboard {
+            crate::api::media::Storyboard {
+                vtt_path: self.vtt_path.cst_decode(),
+                sprite_paths: self.sprite_paths.cst_decode(),
+                tile_count: self.tile_count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::StreamDisposition> for wire_cst_stream_disposition {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::StreamDisposition {
+            crate::api::media::StreamDisposition {
+                default: self.default.cst_decode(),
+                dub: self.dub.cst_decode(),
+                original: self.original.cst_decode(),
+                comment: self.comment.cst_decode(),
+                lyrics: self.lyrics.cst_decode(),
+                karaoke: self.karaoke.cst_decode(),
+                forced: self.forced.cst_decode(),
+                hearing_impaired: self.hearing_impaired.cst_decode(),
+                visual_impaired: self.visual_impaired.cst_decode(),
+                clean_effects: self.clean_effects.cst_decode(),
+                attached_pic: self.attached_pic.cst_decode(),
+                captions: self.captions.cst_decode(),
+                descriptions: self.descriptions.cst_decode(),
+                metadata: self.metadata.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::StreamProbe> for wire_cst_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::StreamProbe {
+            crate::api::media::StreamProbe {
+                index: self.index.cst_decode(),
+                stream_type: self.stream_type.cst_decode(),
+                codec_name: self.codec_name.cst_decode(),
+                codec_long_name: self.codec_long_name.cst_decode(),
+                profile: self.profile.cst_decode(),
+                bitrate: self.bitrate.cst_decode(),
+                start_time_ms: self.start_time_ms.cst_decode(),
+                duration_ms: self.duration_ms.cst_decode(),
+                frame_count: self.frame_count.cst_decode(),
+                language: self.language.cst_decode(),
+                title: self.title.cst_decode(),
+                disposition: self.disposition.cst_decode(),
+                tags: self.tags.cst_decode(),
+                side_data: self.side_data.cst_decode(),
+                video: self.video.cst_decode(),
+                audio: self.audio.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::StreamSideData> for wire_cst_stream_side_data {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::StreamSideData {
+            crate::api::media::StreamSideData {
+                kind: self.kind.cst_decode(),
+                size_bytes: self.size_bytes.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::SubtitleParams> for wire_cst_subtitle_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SubtitleParams {
+            crate::api::media::SubtitleParams {
+                subtitle_path: self.subtitle_path.cst_decode(),
+                font_path: self.font_path.cst_decode(),
+                font_size: self.font_size.cst_decode(),
+                color: self.color.cst_decode(),
+                outline_color: self.outline_color.cst_decode(),
+                outline_width: self.outline_width.cst_decode(),
+                bottom_margin: self.bottom_margin.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ThumbnailData> for wire_cst_thumbnail_data {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ThumbnailData {
+            crate::api::media::ThumbnailData {
+                data: self.data.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                time_ms: self.time_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ThumbnailSizeType> for wire_cst_thumbnail_size_type {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ThumbnailSizeType {
+            match self.tag {
+                0 => crate::api::media::ThumbnailSizeType::Icon,
+                1 => crate::api::media::ThumbnailSizeType::Small,
+                2 => crate::api::media::ThumbnailSizeType::Medium,
+                3 => crate::api::media::ThumbnailSizeType::Large,
+                4 => crate::api::media::ThumbnailSizeType::Larger,
+                5 => {
+                    let ans = unsafe { self.kind.Custom };
+                    crate::api::media::ThumbnailSizeType::Custom(ans.field0.cst_decode())
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::VideoInfo> for wire_cst_video_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoInfo {
+            crate::api::media::VideoInfo {
+                duration_ms: self.duration_ms.cst_decode(),
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                size_bytes: self.size_bytes.cst_decode(),
+                bitrate: self.bitrate.cst_decode(),
+                codec_name: self.codec_name.cst_decode(),
+                format_name: self.format_name.cst_decode(),
+                stored_width: self.stored_width.cst_decode(),
+                stored_height: self.stored_height.cst_decode(),
+                rotation: self.rotation.cst_decode(),
+                avg_frame_rate: self.avg_frame_rate.cst_decode(),
+                real_frame_rate: self.real_frame_rate.cst_decode(),
+                is_vfr: self.is_vfr.cst_decode(),
+                pixel_format: self.pixel_format.cst_decode(),
+                bit_depth: self.bit_depth.cst_decode(),
+                color_primaries: self.color_primaries.cst_decode(),
+                color_transfer: self.color_transfer.cst_decode(),
+                color_matrix: self.color_matrix.cst_decode(),
+                color_range: self.color_range.cst_decode(),
+                hdr_format: self.hdr_format.cst_decode(),
+                profile: self.profile.cst_decode(),
+                level: self.level.cst_decode(),
+                frame_count: self.frame_count.cst_decode(),
+                audio: self.audio.cst_decode(),
+                suggestions: self.suggestions.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::VideoStreamProbe> for wire_cst_video_stream_probe {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoStreamProbe {
+            crate::api::media::VideoStreamProbe {
+                width: self.width.cst_decode(),
+                height: self.height.cst_decode(),
+                display_width: self.display_width.cst_decode(),
+                display_height: self.display_height.cst_decode(),
+                rotation: self.rotation.cst_decode(),
+                avg_frame_rate: self.avg_frame_rate.cst_decode(),
+                real_frame_rate: self.real_frame_rate.cst_decode(),
+                is_vfr: self.is_vfr.cst_decode(),
+                pixel_format: self.pixel_format.cst_decode(),
+                bit_depth: self.bit_depth.cst_decode(),
+                color_primaries: self.color_primaries.cst_decode(),
+                color_transfer: self.color_transfer.cst_decode(),
+                color_matrix: self.color_matrix.cst_decode(),
+                color_range: self.color_range.cst_decode(),
+                hdr_format: self.hdr_format.cst_decode(),
+                level: self.level.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::VideoThumbnailParams> for wire_cst_video_thumbnail_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoThumbnailParams {
+            crate::api::media::VideoThumbnailParams {
+                time_ms: self.time_ms.cst_decode(),
+                size_type: self.size_type.cst_decode(),
+                format: self.format.cst_decode(),
+                seek_mode: self.seek_mode.cst_decode(),
+                resize_mode: self.resize_mode.cst_decode(),
+                allow_upscale: self.allow_upscale.cst_decode(),
+                encoder_options: self.encoder_options.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::WatermarkParams> for wire_cst_watermark_params {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::WatermarkParams {
+            crate::api::media::WatermarkParams {
+                image_path: self.image_path.cst_decode(),
+                position: self.position.cst_decode(),
+                margin: self.margin.cst_decode(),
+                scale: self.scale.cst_decode(),
+                opacity: self.opacity.cst_decode(),
+                start_ms: self.start_ms.cst_decode(),
+                end_ms: self.end_ms.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::logger::WriteToFiles> for wire_cst_write_to_files {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::logger::WriteToFiles {
+            crate::api::logger::WriteToFiles {
+                path: self.path.cst_decode(),
+                file_prefix: self.file_prefix.cst_decode(),
+                file_suffix: self.file_suffix.cst_decode(),
+                max_files: self.max_files.cst_decode(),
+            }
+        }
+    }
+    impl NewWithNullPtr for wire_cst_animation_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                max_bytes: core::ptr::null_mut(),
+                loop_count: core::ptr::null_mut(),
+                dither: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_animation_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_audio_extract_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                start_ms: core::ptr::null_mut(),
+                end_ms: core::ptr::null_mut(),
+                channels: core::ptr::null_mut(),
+                sample_rate: core::ptr::null_mut(),
+                bitrate_kbps: core::ptr::null_mut(),
+                normalize_loudness: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_audio_extract_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_audio_mix_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                mode: core::ptr::null_mut(),
+                music_gain_db: core::ptr::null_mut(),
+                original_gain_db: core::ptr::null_mut(),
+                ducking: core::ptr::null_mut(),
+                loop_music: Default::default(),
+                music_start_ms: core::ptr::null_mut(),
+                fade_in_ms: core::ptr::null_mut(),
+                fade_out_ms: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_audio_mix_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_audio_stream_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                codec_name: core::ptr::null_mut(),
+                channels: Default::default(),
+                sample_rate: Default::default(),
+                bitrate: core::ptr::null_mut(),
+                language: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_audio_stream_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_audio_stream_probe {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                sample_rate: Default::default(),
+                channels: Default::default(),
+                channel_layout: core::ptr::null_mut(),
+                sample_format: core::ptr::null_mut(),
+                bits_per_sample: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_audio_stream_probe {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_best_thumbnail {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                data: core::ptr::null_mut(),
+                width: Default::default(),
+                height: Default::default(),
+                time_ms: Default::default(),
+                score: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_best_thumbnail {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_best_thumbnail_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                size_type: core::ptr::null_mut(),
+                format: core::ptr::null_mut(),
+                candidates: core::ptr::null_mut(),
+                resize_mode: core::ptr::null_mut(),
+                allow_upscale: core::ptr::null_mut(),
+                encoder_options: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_best_thumbnail_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_compress_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                target_bitrate_kbps: Default::default(),
+                preset: core::ptr::null_mut(),
+                crf: core::ptr::null_mut(),
+                width: core::ptr::null_mut(),
+                height: core::ptr::null_mut(),
+                sample_duration_ms: core::ptr::null_mut(),
+                crop: core::ptr::null_mut(),
+                aspect_ratio: core::ptr::null_mut(),
+                aspect_mode: core::ptr::null_mut(),
+                pad_color: core::ptr::null_mut(),
+                watermark: core::ptr::null_mut(),
+                subtitles: core::ptr::null_mut(),
+                speed: core::ptr::null_mut(),
+                normalize_loudness: core::ptr::null_mut(),
+                video_filters: core::ptr::null_mut(),
+                audio_filters: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_compress_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_compress_progress {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                processed_ms: Default::default(),
+                total_ms: Default::default(),
+                speed_x: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_compress_progress {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_compression_estimate {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                estimated_size_bytes: Default::default(),
+                estimated_duration_ms: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_compression_estimate {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_concat_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                width: core::ptr::null_mut(),
+                height: core::ptr::null_mut(),
+                fps: core::ptr::null_mut(),
+                target_bitrate_kbps: core::ptr::null_mut(),
+                preset: core::ptr::null_mut(),
+                crf: core::ptr::null_mut(),
+                force_reencode: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_concat_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_crop_rect {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                x: Default::default(),
+                y: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_crop_rect {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_ducking_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                threshold_db: core::ptr::null_mut(),
+                reduction_db: core::ptr::null_mut(),
+                attack_ms: core::ptr::null_mut(),
+                release_ms: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_ducking_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_encoder_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                jpeg_quality: core::ptr::null_mut(),
+                jpeg_progressive: core::ptr::null_mut(),
+                webp_quality: core::ptr::null_mut(),
+                png_compression: core::ptr::null_mut(),
+                avif_quality: core::ptr::null_mut(),
+                heic_quality: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_encoder_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_extracted_frame {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                path: core::ptr::null_mut(),
+                index: Default::default(),
+                requested_ms: Default::default(),
+                time_ms: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_extracted_frame {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_frame_extract_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                selection: Default::default(),
+                size_type: core::ptr::null_mut(),
+                format: core::ptr::null_mut(),
+                seek_mode: core::ptr::null_mut(),
+                resize_mode: core::ptr::null_mut(),
+                allow_upscale: core::ptr::null_mut(),
+                encoder_options: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_frame_extract_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_frame_selection {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: FrameSelectionKind { nil__: () },
+            }
+        }
+    }
+    impl Default for wire_cst_frame_selection {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_gps_location {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                latitude: Default::default(),
+                longitude: Default::default(),
+                altitude: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_gps_location {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_hash_comparison {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                distance: Default::default(),
+                similarity: Default::default(),
+                is_similar: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_hash_comparison {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_image_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                width: Default::default(),
+                height: Default::default(),
+                format: core::ptr::null_mut(),
+                bit_depth: Default::default(),
+                has_alpha: Default::default(),
+                orientation: Default::default(),
+                camera_make: core::ptr::null_mut(),
+                camera_model: core::ptr::null_mut(),
+                capture_date: core::ptr::null_mut(),
+                gps: core::ptr::null_mut(),
+                icc_profile_name: core::ptr::null_mut(),
+                image_count: Default::default(),
+                auxiliary_image_count: Default::default(),
+                has_depth: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_image_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_image_thumbnail_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                size_type: core::ptr::null_mut(),
+                format: core::ptr::null_mut(),
+                seek_mode: core::ptr::null_mut(),
+                resize_mode: core::ptr::null_mut(),
+                allow_upscale: core::ptr::null_mut(),
+                encoder_options: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_image_thumbnail_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_loudness_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                integrated_lufs: Default::default(),
+                loudness_range_lu: Default::default(),
+                true_peak_dbtp: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_loudness_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_loudness_normalization {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                target_lufs: Default::default(),
+                true_peak_dbtp: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_loudness_normalization {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_media_chapter {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: Default::default(),
+                start_ms: Default::default(),
+                end_ms: Default::default(),
+                title: core::ptr::null_mut(),
+                tags: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_media_chapter {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_media_hash {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                algorithm: Default::default(),
+                hashes: core::ptr::null_mut(),
+                times_ms: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_media_hash {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_media_probe {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                format_name: core::ptr::null_mut(),
+                format_long_name: core::ptr::null_mut(),
+                duration_ms: core::ptr::null_mut(),
+                start_time_ms: core::ptr::null_mut(),
+                bitrate: core::ptr::null_mut(),
+                size_bytes: Default::default(),
+                tags: core::ptr::null_mut(),
+                chapters: core::ptr::null_mut(),
+                streams: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_media_probe {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_media_tag {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                key: core::ptr::null_mut(),
+                value: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_media_tag {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_palette {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                colors: core::ptr::null_mut(),
+                average: Default::default(),
+                vibrant: core::ptr::null_mut(),
+                light_vibrant: core::ptr::null_mut(),
+                dark_vibrant: core::ptr::null_mut(),
+                muted: core::ptr::null_mut(),
+                light_muted: core::ptr::null_mut(),
+                dark_muted: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_palette {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_palette_color {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                color: Default::default(),
+                population: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_palette_color {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_placeholder_image {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                rgba: core::ptr::null_mut(),
+                width: Default::default(),
+                height: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_placeholder_image {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_record_u_32_u_32 {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: Default::default(),
+                field1: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_record_u_32_u_32 {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_resolution_preset {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                width: Default::default(),
+                height: Default::default(),
+                bitrate: Default::default(),
+                crf: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_resolution_preset {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_reverse_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                start_ms: core::ptr::null_mut(),
+                end_ms: core::ptr::null_mut(),
+                boomerang_loops: core::ptr::null_mut(),
+                audio: core::ptr::null_mut(),
+                width: core::ptr::null_mut(),
+                height: core::ptr::null_mut(),
+                target_bitrate_kbps: core::ptr::null_mut(),
+                preset: core::ptr::null_mut(),
+                crf: core::ptr::null_mut(),
+                memory_budget_mb: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_reverse_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_scene_cut {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                time_ms: Default::default(),
+                score: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_scene_cut {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_scene_detection_event {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: SceneDetectionEventKind { nil__: () },
+            }
+        }
+    }
+    impl Default for wire_cst_scene_detection_event {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_speed_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                factor: Default::default(),
+                frame_rate: core::ptr::null_mut(),
+                mute_audio_above: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_speed_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_storyboard {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                vtt_path: core::ptr::null_mut(),
+                sprite_paths: core::ptr::null_mut(),
+                tile_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_storyboard {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_stream_disposition {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                default: Default::default(),
+                dub: Default::default(),
+                original: Default::default(),
+                comment: Default::default(),
+                lyrics: Default::default(),
+                karaoke: Default::default(),
+                forced: Default::default(),
+                hearing_impaired: Default::default(),
+                visual_impaired: Default::default(),
+                clean_effects: Default::default(),
+                attached_pic: Default::default(),
+                captions: Default::default(),
+                descriptions: Default::default(),
+                metadata: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_stream_disposition {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_stream_probe {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                index: Default::default(),
+                stream_type: Default::default(),
+                codec_name: core::ptr::null_mut(),
+                codec_long_name: core::ptr::null_mut(),
+                profile: core::ptr::null_mut(),
+                bitrate: core::ptr::null_mut(),
+                start_time_ms: core::ptr::null_mut(),
+                duration_ms: core::ptr::null_mut(),
+                frame_count: core::ptr::null_mut(),
+                language: core::ptr::null_mut(),
+                title: core::ptr::null_mut(),
+                disposition: Default::default(),
+                tags: core::ptr::null_mut(),
+                side_data: core::ptr::null_mut(),
+                video: core::ptr::null_mut(),
+                audio: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_stream_probe {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_stream_side_data {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                kind: core::ptr::null_mut(),
+                size_bytes: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_stream_side_data {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_subtitle_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                subtitle_path: core::ptr::null_mut(),
+                font_path: core::ptr::null_mut(),
+                font_size: core::ptr::null_mut(),
+                color: core::ptr::null_mut(),
+                outline_color: core::ptr::null_mut(),
+                outline_width: core::ptr::null_mut(),
+                bottom_margin: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_subtitle_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_thumbnail_data {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                data: core::ptr::null_mut(),
+                width: Default::default(),
+                height: Default::default(),
+                time_ms: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_thumbnail_data {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_thumbnail_size_type {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: ThumbnailSizeTypeKind { nil__: () },
+            }
+        }
+    }
+    impl Default for wire_cst_thumbnail_size_type {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_video_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                duration_ms: Default::default(),
+                width: Default::default(),
+                height: Default::default(),
+                size_bytes: Default::default(),
+                bitrate: core::ptr::null_mut(),
+                codec_name: core::ptr::null_mut(),
+                format_name: core::ptr::null_mut(),
+                stored_width: Default::default(),
+                stored_height: Default::default(),
+                rotation: Default::default(),
+                avg_frame_rate: core::ptr::null_mut(),
+                real_frame_rate: core::ptr::null_mut(),
+                is_vfr: Default::default(),
+                pixel_format: core::ptr::null_mut(),
+                bit_depth: core::ptr::null_mut(),
+                color_primaries: core::ptr::null_mut(),
+                color_transfer: core::ptr::null_mut(),
+                color_matrix: core::ptr::null_mut(),
+                color_range: core::ptr::null_mut(),
+                hdr_format: core::ptr::null_mut(),
+                profile: core::ptr::null_mut(),
+                level: core::ptr::null_mut(),
+                frame_count: core::ptr::null_mut(),
+                audio: core::ptr::null_mut(),
+                suggestions: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_video_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_video_stream_probe {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                width: Default::default(),
+                height: Default::default(),
+                display_width: Default::default(),
+                display_height: Default::default(),
+                rotation: Default::default(),
+                avg_frame_rate: core::ptr::null_mut(),
+                real_frame_rate: core::ptr::null_mut(),
+                is_vfr: Default::default(),
+                pixel_format: core::ptr::null_mut(),
+                bit_depth: core::ptr::null_mut(),
+                color_primaries: core::ptr::null_mut(),
+                color_transfer: core::ptr::null_mut(),
+                color_matrix: core::ptr::null_mut(),
+                color_range: core::ptr::null_mut(),
+                hdr_format: core::ptr::null_mut(),
+                level: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_video_stream_probe {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_video_thumbnail_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                time_ms: Default::default(),
+                size_type: core::ptr::null_mut(),
+                format: core::ptr::null_mut(),
+                seek_mode: core::ptr::null_mut(),
+                resize_mode: core::ptr::null_mut(),
+                allow_upscale: core::ptr::null_mut(),
+                encoder_options: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_video_thumbnail_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_watermark_params {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                image_path: core::ptr::null_mut(),
+                position: core::ptr::null_mut(),
+                margin: core::ptr::null_mut(),
+                scale: core::ptr::null_mut(),
+                opacity: core::ptr::null_mut(),
+                start_ms: core::ptr::null_mut(),
+                end_ms: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_watermark_params {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_write_to_files {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                path: core::ptr::null_mut(),
+                file_prefix: core::ptr::null_mut(),
+                file_suffix: core::ptr::null_mut(),
+                max_files: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_write_to_files {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__add_music(
+        port_: i64,
+        video_path: *mut wire_cst_list_prim_u_8_strict,
+        music_path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_audio_mix_params,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__add_music_impl(
+            port_,
+            video_path,
+            music_path,
+            output_path,
+            params,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__analyze_loudness(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__analyze_loudness_impl(port_, path)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__animation_format_extension(
+        port_: i64,
+        that: i32,
+    ) {
+        wire__crate__api__media__animation_format_extension_impl(port_, that)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__audio_format_extension(
+        port_: i64,
+        that: i32,
+    ) {
+        wire__crate__api__media__audio_format_extension_impl(port_, that)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compare_media_hashes(
+        port_: i64,
+        a: *mut wire_cst_media_hash,
+        b: *mut wire_cst_media_hash,
+        threshold: *mut u32,
+    ) {
+        wire__crate__api__media__compare_media_hashes_impl(port_, a, b, threshold)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compress_video(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_compress_params,
+    ) {
+        wire__crate__api__media__compress_video_impl(port_, path, output_path, params)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compute_blurhash(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        components_x: u32,
+        components_y: u32,
+        time_ms: *mut u64,
+    ) {
+        wire__crate__api__media__compute_blurhash_impl(
+            port_,
+            path,
+            components_x,
+            components_y,
+            time_ms,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compute_image_hash(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        algorithm: *mut i32,
+    ) {
+        wire__crate__api__media__compute_image_hash_impl(port_, path, algorithm)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compute_thumbhash(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        time_ms: *mut u64,
+    ) {
+        wire__crate__api__media__compute_thumbhash_impl(port_, path, time_ms)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__compute_video_hash(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        algorithm: *mut i32,
+        frame_count: *mut u32,
+    ) {
+        wire__crate__api__media__compute_video_hash_impl(port_, path, algorithm, frame_count)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__concat_videos(
+        port_: i64,
+        inputs: *mut wire_cst_list_String,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_concat_params,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__concat_videos_impl(port_, inputs, output_path, params, sink)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__logger__debug_threads(port_: i64) {
+        wire__crate__api__logger__debug_threads_impl(port_)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__decode_blurhash(
+        port_: i64,
+        hash: *mut wire_cst_list_prim_u_8_strict,
+        width: u32,
+        height: u32,
+        punch: *mut f32,
+    ) {
+        wire__crate__api__media__decode_blurhash_impl(port_, hash, width, height, punch)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__decode_thumbhash(
+        port_: i64,
+        hash: *mut wire_cst_list_prim_u_8_loose,
+    ) {
+        wire__crate__api__media__decode_thumbhash_impl(port_, hash)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__detect_scenes(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        threshold: f64,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__detect_scenes_impl(port_, path, threshold, sink)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__estimate_compression(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        temp_output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_compress_params,
+    ) {
+        wire__crate__api__media__estimate_compression_impl(port_, path, temp_output_path, params)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__export_animation(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        start_ms: u64,
+        duration_ms: u64,
+        fps: u32,
+        max_width: u32,
+        format: i32,
+        options: *mut wire_cst_animation_options,
+    ) {
+        wire__crate__api__media__export_animation_impl(
+            port_,
+            path,
+            output_path,
+            start_ms,
+            duration_ms,
+            fps,
+            max_width,
+            format,
+            options,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__extract_audio(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        format: i32,
+        options: *mut wire_cst_audio_extract_options,
+    ) {
+        wire__crate__api__media__extract_audio_impl(port_, path, output_path, format, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__extract_frames(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_frame_extract_params,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__extract_frames_impl(port_, path, output_path, params, sink)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__extract_palette(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        count: u32,
+        time_ms: *mut u64,
+    ) {
+        wire__crate__api__media__extract_palette_impl(port_, path, count, time_ms)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_best_thumbnail(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_best_thumbnail_params,
+    ) {
+        wire__crate__api__media__generate_best_thumbnail_impl(port_, path, params)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_image_thumbnail(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_image_thumbnail_params,
+        suffix: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__generate_image_thumbnail_impl(
+            port_,
+            path,
+            output_path,
+            params,
+            suffix,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_image_thumbnail_data(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_image_thumbnail_params,
+    ) {
+        wire__crate__api__media__generate_image_thumbnail_data_impl(port_, path, params)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_storyboard(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_dir: *mut wire_cst_list_prim_u_8_strict,
+        interval_ms: u64,
+        tile_width: u32,
+        tile_height: u32,
+        columns: u32,
+        rows: u32,
+        encoder_options: *mut wire_cst_encoder_options,
+    ) {
+        wire__crate__api__media__generate_storyboard_impl(
+            port_,
+            path,
+            output_dir,
+            interval_ms,
+            tile_width,
+            tile_height,
+            columns,
+            rows,
+            encoder_options,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_thumbnail(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_video_thumbnail_params,
+        empty_image_fallback: *mut bool,
+    ) {
+        wire__crate__api__media__generate_video_thumbnail_impl(
+            port_,
+            path,
+            output_path,
+            params,
+            empty_image_fallback,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_thumbnail_data(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_video_thumbnail_params,
+    ) {
+        wire__crate__api__media__generate_video_thumbnail_data_impl(port_, path, params)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnail_data(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_image_thumbnail_params,
+        num_thumbnails: u32,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__generate_video_timeline_thumbnail_data_impl(
+            port_,
+            path,
+            params,
+            num_thumbnails,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_image_thumbnail_params,
+        num_thumbnails: u32,
+        empty_image_fallback: *mut bool,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__generate_video_timeline_thumbnails_impl(
+            port_,
+            path,
+            output_path,
+            params,
+            num_thumbnails,
+            empty_image_fallback,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__get_image_info(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__get_image_info_impl(port_, path)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__get_video_info(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__get_video_info_impl(port_, path)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__logger__init_logger(
+        port_: i64,
+        log_level: i32,
+        write_to_stdout_or_system: bool,
+        write_to_files: *mut wire_cst_write_to_files,
+        use_lightweight_tokio_runtime: bool,
+    ) {
+        wire__crate__api__logger__init_logger_impl(
+            port_,
+            log_level,
+            write_to_stdout_or_system,
+            write_to_files,
+            use_lightweight_tokio_runtime,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__logger__log(
+        port_: i64,
+        file: *mut wire_cst_list_prim_u_8_strict,
+        line: *mut u32,
+        level: i32,
+        target: *mut wire_cst_list_prim_u_8_strict,
+        message: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__logger__log_impl(port_, file, line, level, target, message)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__output_format_extension(
+        port_: i64,
+        that: i32,
+    ) {
+        wire__crate__api__media__output_format_extension_impl(port_, that)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__probe_media(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__probe_media_impl(port_, path)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__probe_media_json(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__probe_media_json_impl(port_, path)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__logger__reload_tracing_file_writer(
+        port_: i64,
+        write_to_files: *mut wire_cst_write_to_files,
+    ) {
+        wire__crate__api__logger__reload_tracing_file_writer_impl(port_, write_to_files)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__reverse_video(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        output_path: *mut wire_cst_list_prim_u_8_strict,
+        params: *mut wire_cst_reverse_params,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__media__reverse_video_impl(port_, path, output_path, params, sink)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions(
+        port_: i64,
+        that: *mut wire_cst_thumbnail_size_type,
+    ) {
+        wire__crate__api__media__thumbnail_size_type_dimensions_impl(port_, that)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_animation_options(
+    ) -> *mut wire_cst_animation_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_animation_options::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_aspect_mode(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_channels(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
 
-// Section: dart2rust
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_extract_options(
+    ) -> *mut wire_cst_audio_extract_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_audio_extract_options::new_with_null_ptr(),
+        )
+    }
 
-impl CstDecode<bool> for bool {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> bool {
-        self
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_mix_params(
+    ) -> *mut wire_cst_audio_mix_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_audio_mix_params::new_with_null_ptr(),
+        )
     }
-}
-impl CstDecode<i32> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> i32 {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_stream_info(
+    ) -> *mut wire_cst_audio_stream_info {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_audio_stream_info::new_with_null_ptr(),
+        )
     }
-}
-impl CstDecode<crate::api::logger::LogLevel> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::api::logger::LogLevel {
-        match self {
-            0 => crate::api::logger::LogLevel::Error,
-            1 => crate::api::logger::LogLevel::Warn,
-            2 => crate::api::logger::LogLevel::Info,
-            3 => crate::api::logger::LogLevel::Debug,
-            4 => crate::api::logger::LogLevel::Trace,
-            _ => unreachable!("Invalid variant for LogLevel: {}", self),
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_stream_probe(
+    ) -> *mut wire_cst_audio_stream_probe {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_audio_stream_probe::new_with_null_ptr(),
+        )
     }
-}
-impl CstDecode<crate::api::media::OutputFormat> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::api::media::OutputFormat {
-        match self {
-            0 => crate::api::media::OutputFormat::WEBP,
-            1 => crate::api::media::OutputFormat::JPEG,
-            2 => crate::api::media::OutputFormat::PNG,
-            _ => unreachable!("Invalid variant for OutputFormat: {}", self),
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_audio_track_mode(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
-impl CstDecode<u32> for u32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u32 {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_best_thumbnail_params(
+    ) -> *mut wire_cst_best_thumbnail_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_best_thumbnail_params::new_with_null_ptr(),
+        )
     }
-}
-impl CstDecode<u64> for u64 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u64 {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_bool(value: bool) -> *mut bool {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
-impl CstDecode<u8> for u8 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u8 {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_compress_params(
+    ) -> *mut wire_cst_compress_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_compress_params::new_with_null_ptr(),
+        )
     }
-}
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_compress_progress(
+    ) -> *mut wire_cst_compress_progress {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_compress_progress::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_concat_params() -> *mut wire_cst_concat_params
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_concat_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_crop_rect() -> *mut wire_cst_crop_rect {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_crop_rect::new_with_null_ptr())
     }
-}
 
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_ducking_params(
+    ) -> *mut wire_cst_ducking_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_ducking_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for crate::api::media::CompressParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_targetBitrateKbps = <u32>::sse_decode(deserializer);
-        let mut var_preset = <Option<String>>::sse_decode(deserializer);
-        let mut var_crf = <Option<u8>>::sse_decode(deserializer);
-        let mut var_width = <Option<u32>>::sse_decode(deserializer);
-        let mut var_height = <Option<u32>>::sse_decode(deserializer);
-        let mut var_sampleDurationMs = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::media::CompressParams {
-            target_bitrate_kbps: var_targetBitrateKbps,
-            preset: var_preset,
-            crf: var_crf,
-            width: var_width,
-            height: var_height,
-            sample_duration_ms: var_sampleDurationMs,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_encoder_options(
+    ) -> *mut wire_cst_encoder_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_encoder_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for crate::api::media::CompressionEstimate {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_estimatedSizeBytes = <u64>::sse_decode(deserializer);
-        let mut var_estimatedDurationMs = <u64>::sse_decode(deserializer);
-        return crate::api::media::CompressionEstimate {
-            estimated_size_bytes: var_estimatedSizeBytes,
-            estimated_duration_ms: var_estimatedDurationMs,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_f_32(value: f32) -> *mut f32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_f_64(value: f64) -> *mut f64 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for crate::api::media::ImageThumbnailParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sizeType =
-            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
-        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
-        return crate::api::media::ImageThumbnailParams {
-            size_type: var_sizeType,
-            format: var_format,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_frame_extract_params(
+    ) -> *mut wire_cst_frame_extract_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_frame_extract_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_gps_location() -> *mut wire_cst_gps_location
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_gps_location::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Vec<crate::api::media::ResolutionPreset> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::media::ResolutionPreset>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_hash_algorithm(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_hdr_format(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_i_32(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_i_64(value: i64) -> *mut i64 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_image_thumbnail_params(
+    ) -> *mut wire_cst_image_thumbnail_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_image_thumbnail_params::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_loudness_normalization(
+    ) -> *mut wire_cst_loudness_normalization {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_loudness_normalization::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_media_hash() -> *mut wire_cst_media_hash {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_media_hash::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_output_format(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_palette_color() -> *mut wire_cst_palette_color
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_palette_color::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_record_u_32_u_32(
+    ) -> *mut wire_cst_record_u_32_u_32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_record_u_32_u_32::new_with_null_ptr(),
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_resize_mode(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_reverse_audio_mode(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_reverse_params(
+    ) -> *mut wire_cst_reverse_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_reverse_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for crate::api::logger::LogLevel {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::logger::LogLevel::Error,
-            1 => crate::api::logger::LogLevel::Warn,
-            2 => crate::api::logger::LogLevel::Info,
-            3 => crate::api::logger::LogLevel::Debug,
-            4 => crate::api::logger::LogLevel::Trace,
-            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_seek_mode(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_speed_params() -> *mut wire_cst_speed_params
+    {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_speed_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Option<bool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_subtitle_params(
+    ) -> *mut wire_cst_subtitle_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_subtitle_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Option<crate::api::media::ImageThumbnailParams> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::media::ImageThumbnailParams>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_thumbnail_size_type(
+    ) -> *mut wire_cst_thumbnail_size_type {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_thumbnail_size_type::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Option<crate::api::media::OutputFormat> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::media::OutputFormat>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_16(value: u16) -> *mut u16 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for Option<crate::api::media::ThumbnailSizeType> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::media::ThumbnailSizeType>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_64(value: u64) -> *mut u64 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_8(value: u8) -> *mut u8 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for Option<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_video_stream_probe(
+    ) -> *mut wire_cst_video_stream_probe {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_video_stream_probe::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for Option<crate::api::logger::WriteToFiles> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::logger::WriteToFiles>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_video_thumbnail_params(
+    ) -> *mut wire_cst_video_thumbnail_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_video_thumbnail_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for crate::api::media::OutputFormat {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::media::OutputFormat::WEBP,
-            1 => crate::api::media::OutputFormat::JPEG,
-            2 => crate::api::media::OutputFormat::PNG,
-            _ => unreachable!("Invalid variant for OutputFormat: {}", inner),
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_watermark_params(
+    ) -> *mut wire_cst_watermark_params {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_watermark_params::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for (u32, u32) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <u32>::sse_decode(deserializer);
-        let mut var_field1 = <u32>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_watermark_position(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseDecode for crate::api::media::ResolutionPreset {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_width = <u32>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
-        let mut var_bitrate = <u64>::sse_decode(deserializer);
-        let mut var_crf = <u8>::sse_decode(deserializer);
-        return crate::api::media::ResolutionPreset {
-            name: var_name,
-            width: var_width,
-            height: var_height,
-            bitrate: var_bitrate,
-            crf: var_crf,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_box_autoadd_write_to_files(
+    ) -> *mut wire_cst_write_to_files {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_write_to_files::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseDecode for crate::api::media::ThumbnailSizeType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::media::ThumbnailSizeType::Icon;
-            }
-            1 => {
-                return crate::api::media::ThumbnailSizeType::Small;
-            }
-            2 => {
-                return crate::api::media::ThumbnailSizeType::Medium;
-            }
-            3 => {
-                return crate::api::media::ThumbnailSizeType::Large;
-            }
-            4 => {
-                return crate::api::media::ThumbnailSizeType::Larger;
-            }
-            5 => {
-                let mut var_field0 = <(u32, u32)>::sse_decode(deserializer);
-                return crate::api::media::ThumbnailSizeType::Custom(var_field0);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_String(len: i32) -> *mut wire_cst_list_String {
+        let wrap = wire_cst_list_String {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <*mut wire_cst_list_prim_u_8_strict>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_media_chapter(
+        len: i32,
+    ) -> *mut wire_cst_list_media_chapter {
+        let wrap = wire_cst_list_media_chapter {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_media_chapter>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_media_tag(
+        len: i32,
+    ) -> *mut wire_cst_list_media_tag {
+        let wrap = wire_cst_list_media_tag {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_media_tag>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_palette_color(
+        len: i32,
+    ) -> *mut wire_cst_list_palette_color {
+        let wrap = wire_cst_list_palette_color {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_palette_color>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_prim_u_64_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_u_64_strict {
+        let ans = wire_cst_list_prim_u_64_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+    }
 
-impl SseDecode for crate::api::media::VideoInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_durationMs = <u64>::sse_decode(deserializer);
-        let mut var_width = <u32>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
-        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
-        let mut var_bitrate = <Option<u64>>::sse_decode(deserializer);
-        let mut var_codecName = <Option<String>>::sse_decode(deserializer);
-        let mut var_formatName = <Option<String>>::sse_decode(deserializer);
-        let mut var_suggestions =
-            <Vec<crate::api::media::ResolutionPreset>>::sse_decode(deserializer);
-        return crate::api::media::VideoInfo {
-            duration_ms: var_durationMs,
-            width: var_width,
-            height: var_height,
-            size_bytes: var_sizeBytes,
-            bitrate: var_bitrate,
-            codec_name: var_codecName,
-            format_name: var_formatName,
-            suggestions: var_suggestions,
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_prim_u_8_loose(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_u_8_loose {
+        let ans = wire_cst_list_prim_u_8_loose {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
         };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-}
 
-impl SseDecode for crate::api::media::VideoThumbnailParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timeMs = <u64>::sse_decode(deserializer);
-        let mut var_sizeType =
-            <Option<crate::api::media::ThumbnailSizeType>>::sse_decode(deserializer);
-        let mut var_format = <Option<crate::api::media::OutputFormat>>::sse_decode(deserializer);
-        return crate::api::media::VideoThumbnailParams {
-            time_ms: var_timeMs,
-            size_type: var_sizeType,
-            format: var_format,
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_prim_u_8_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_u_8_strict {
+        let ans = wire_cst_list_prim_u_8_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
         };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-}
 
-impl SseDecode for crate::api::logger::WriteToFiles {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_path = <String>::sse_decode(deserializer);
-        let mut var_filePrefix = <String>::sse_decode(deserializer);
-        let mut var_fileSuffix = <Option<String>>::sse_decode(deserializer);
-        let mut var_maxFiles = <Option<u64>>::sse_decode(deserializer);
-        return crate::api::logger::WriteToFiles {
-            path: var_path,
-            file_prefix: var_filePrefix,
-            file_suffix: var_fileSuffix,
-            max_files: var_maxFiles,
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_resolution_preset(
+        len: i32,
+    ) -> *mut wire_cst_list_resolution_preset {
+        let wrap = wire_cst_list_resolution_preset {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_resolution_preset>::new_with_null_ptr(),
+                len,
+            ),
+            len,
         };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_scene_cut(
+        len: i32,
+    ) -> *mut wire_cst_list_scene_cut {
+        let wrap = wire_cst_list_scene_cut {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_scene_cut>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_stream_probe(
+        len: i32,
+    ) -> *mut wire_cst_list_stream_probe {
+        let wrap = wire_cst_list_stream_probe {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_stream_probe>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-// Section: rust2dart
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_media_cst_new_list_stream_side_data(
+        len: i32,
+    ) -> *mut wire_cst_list_stream_side_data {
+        let wrap = wire_cst_list_stream_side_data {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_stream_side_data>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::CompressParams {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.target_bitrate_kbps.into_into_dart().into_dart(),
-            self.preset.into_into_dart().into_dart(),
-            self.crf.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.sample_duration_ms.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_animation_options {
+        max_bytes: *mut u64,
+        loop_count: *mut u16,
+        dither: *mut bool,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::CompressParams
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CompressParams>
-    for crate::api::media::CompressParams
-{
-    fn into_into_dart(self) -> crate::api::media::CompressParams {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_audio_extract_options {
+        start_ms: *mut u64,
+        end_ms: *mut u64,
+        channels: *mut i32,
+        sample_rate: *mut u32,
+        bitrate_kbps: *mut u32,
+        normalize_loudness: *mut wire_cst_loudness_normalization,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::CompressionEstimate {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.estimated_size_bytes.into_into_dart().into_dart(),
-            self.estimated_duration_ms.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_audio_mix_params {
+        mode: *mut i32,
+        music_gain_db: *mut f32,
+        original_gain_db: *mut f32,
+        ducking: *mut wire_cst_ducking_params,
+        loop_music: bool,
+        music_start_ms: *mut u64,
+        fade_in_ms: *mut u64,
+        fade_out_ms: *mut u64,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::CompressionEstimate
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::CompressionEstimate>
-    for crate::api::media::CompressionEstimate
-{
-    fn into_into_dart(self) -> crate::api::media::CompressionEstimate {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_audio_stream_info {
+        codec_name: *mut wire_cst_list_prim_u_8_strict,
+        channels: u32,
+        sample_rate: u32,
+        bitrate: *mut u64,
+        language: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_audio_stream_probe {
+        sample_rate: u32,
+        channels: u32,
+        channel_layout: *mut wire_cst_list_prim_u_8_strict,
+        sample_format: *mut wire_cst_list_prim_u_8_strict,
+        bits_per_sample: *mut u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_best_thumbnail {
+        data: *mut wire_cst_list_prim_u_8_strict,
+        width: u32,
+        height: u32,
+        time_ms: u64,
+        score: f64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_best_thumbnail_params {
+        size_type: *mut wire_cst_thumbnail_size_type,
+        format: *mut i32,
+        candidates: *mut u32,
+        resize_mode: *mut i32,
+        allow_upscale: *mut bool,
+        encoder_options: *mut wire_cst_encoder_options,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_compress_params {
+        target_bitrate_kbps: u32,
+        preset: *mut wire_cst_list_prim_u_8_strict,
+        crf: *mut u8,
+        width: *mut u32,
+        height: *mut u32,
+        sample_duration_ms: *mut u64,
+        crop: *mut wire_cst_crop_rect,
+        aspect_ratio: *mut wire_cst_record_u_32_u_32,
+        aspect_mode: *mut i32,
+        pad_color: *mut u32,
+        watermark: *mut wire_cst_watermark_params,
+        subtitles: *mut wire_cst_subtitle_params,
+        speed: *mut wire_cst_speed_params,
+        normalize_loudness: *mut wire_cst_loudness_normalization,
+        video_filters: *mut wire_cst_list_prim_u_8_strict,
+        audio_filters: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_compress_progress {
+        processed_ms: u64,
+        total_ms: u64,
+        speed_x: f32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_compression_estimate {
+        estimated_size_bytes: u64,
+        estimated_duration_ms: u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_concat_params {
+        width: *mut u32,
+        height: *mut u32,
+        fps: *mut u32,
+        target_bitrate_kbps: *mut u32,
+        preset: *mut wire_cst_list_prim_u_8_strict,
+        crf: *mut u8,
+        force_reencode: *mut bool,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_crop_rect {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ducking_params {
+        threshold_db: *mut f32,
+        reduction_db: *mut f32,
+        attack_ms: *mut u32,
+        release_ms: *mut u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_encoder_options {
+        jpeg_quality: *mut u8,
+        jpeg_progressive: *mut bool,
+        webp_quality: *mut f32,
+        png_compression: *mut u8,
+        avif_quality: *mut u8,
+        heic_quality: *mut u8,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_extracted_frame {
+        path: *mut wire_cst_list_prim_u_8_strict,
+        index: u32,
+        requested_ms: u64,
+        time_ms: u64,
+        width: u32,
+        height: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_frame_extract_params {
+        selection: wire_cst_frame_selection,
+        size_type: *mut wire_cst_thumbnail_size_type,
+        format: *mut i32,
+        seek_mode: *mut i32,
+        resize_mode: *mut i32,
+        allow_upscale: *mut bool,
+        encoder_options: *mut wire_cst_encoder_options,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_frame_selection {
+        tag: i32,
+        kind: FrameSelectionKind,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union FrameSelectionKind {
+        Timestamps: wire_cst_FrameSelection_Timestamps,
+        Interval: wire_cst_FrameSelection_Interval,
+        Count: wire_cst_FrameSelection_Count,
+        nil__: (),
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_FrameSelection_Timestamps {
+        field0: *mut wire_cst_list_prim_u_64_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_FrameSelection_Interval {
+        field0: u64,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::ImageThumbnailParams {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.size_type.into_into_dart().into_dart(),
-            self.format.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_FrameSelection_Count {
+        field0: u32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::ImageThumbnailParams
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ImageThumbnailParams>
-    for crate::api::media::ImageThumbnailParams
-{
-    fn into_into_dart(self) -> crate::api::media::ImageThumbnailParams {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_gps_location {
+        latitude: f64,
+        longitude: f64,
+        altitude: *mut f64,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::logger::LogLevel> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self.0 {
-            crate::api::logger::LogLevel::Error => 0.into_dart(),
-            crate::api::logger::LogLevel::Warn => 1.into_dart(),
-            crate::api::logger::LogLevel::Info => 2.into_dart(),
-            crate::api::logger::LogLevel::Debug => 3.into_dart(),
-            crate::api::logger::LogLevel::Trace => 4.into_dart(),
-            _ => unreachable!(),
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_hash_comparison {
+        distance: u32,
+        similarity: f64,
+        is_similar: bool,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::api::logger::LogLevel>
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::logger::LogLevel>>
-    for crate::api::logger::LogLevel
-{
-    fn into_into_dart(self) -> FrbWrapper<crate::api::logger::LogLevel> {
-        self.into()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_image_info {
+        width: u32,
+        height: u32,
+        format: *mut wire_cst_list_prim_u_8_strict,
+        bit_depth: u32,
+        has_alpha: bool,
+        orientation: u32,
+        camera_make: *mut wire_cst_list_prim_u_8_strict,
+        camera_model: *mut wire_cst_list_prim_u_8_strict,
+        capture_date: *mut wire_cst_list_prim_u_8_strict,
+        gps: *mut wire_cst_gps_location,
+        icc_profile_name: *mut wire_cst_list_prim_u_8_strict,
+        image_count: u32,
+        auxiliary_image_count: u32,
+        has_depth: bool,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::OutputFormat {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::WEBP => 0.into_dart(),
-            Self::JPEG => 1.into_dart(),
-            Self::PNG => 2.into_dart(),
-            _ => unreachable!(),
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_image_thumbnail_params {
+        size_type: *mut wire_cst_thumbnail_size_type,
+        format: *mut i32,
+        seek_mode: *mut i32,
+        resize_mode: *mut i32,
+        allow_upscale: *mut bool,
+        encoder_options: *mut wire_cst_encoder_options,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::OutputFormat
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::OutputFormat>
-    for crate::api::media::OutputFormat
-{
-    fn into_into_dart(self) -> crate::api::media::OutputFormat {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_String {
+        ptr: *mut *mut wire_cst_list_prim_u_8_strict,
+        len: i32,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::ResolutionPreset {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.bitrate.into_into_dart().into_dart(),
-            self.crf.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_media_chapter {
+        ptr: *mut wire_cst_media_chapter,
+        len: i32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::ResolutionPreset
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ResolutionPreset>
-    for crate::api::media::ResolutionPreset
-{
-    fn into_into_dart(self) -> crate::api::media::ResolutionPreset {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_media_tag {
+        ptr: *mut wire_cst_media_tag,
+        len: i32,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::ThumbnailSizeType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::media::ThumbnailSizeType::Icon => [0.into_dart()].into_dart(),
-            crate::api::media::ThumbnailSizeType::Small => [1.into_dart()].into_dart(),
-            crate::api::media::ThumbnailSizeType::Medium => [2.into_dart()].into_dart(),
-            crate::api::media::ThumbnailSizeType::Large => [3.into_dart()].into_dart(),
-            crate::api::media::ThumbnailSizeType::Larger => [4.into_dart()].into_dart(),
-            crate::api::media::ThumbnailSizeType::Custom(field0) => {
-                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_palette_color {
+        ptr: *mut wire_cst_palette_color,
+        len: i32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::ThumbnailSizeType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ThumbnailSizeType>
-    for crate::api::media::ThumbnailSizeType
-{
-    fn into_into_dart(self) -> crate::api::media::ThumbnailSizeType {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_u_64_strict {
+        ptr: *mut u64,
+        len: i32,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::VideoInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.duration_ms.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.size_bytes.into_into_dart().into_dart(),
-            self.bitrate.into_into_dart().into_dart(),
-            self.codec_name.into_into_dart().into_dart(),
-            self.format_name.into_into_dart().into_dart(),
-            self.suggestions.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_u_8_loose {
+        ptr: *mut u8,
+        len: i32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::VideoInfo {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::VideoInfo>
-    for crate::api::media::VideoInfo
-{
-    fn into_into_dart(self) -> crate::api::media::VideoInfo {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_u_8_strict {
+        ptr: *mut u8,
+        len: i32,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::VideoThumbnailParams {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.time_ms.into_into_dart().into_dart(),
-            self.size_type.into_into_dart().into_dart(),
-            self.format.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_resolution_preset {
+        ptr: *mut wire_cst_resolution_preset,
+        len: i32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::VideoThumbnailParams
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::VideoThumbnailParams>
-    for crate::api::media::VideoThumbnailParams
-{
-    fn into_into_dart(self) -> crate::api::media::VideoThumbnailParams {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_scene_cut {
+        ptr: *mut wire_cst_scene_cut,
+        len: i32,
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::logger::WriteToFiles {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.path.into_into_dart().into_dart(),
-            self.file_prefix.into_into_dart().into_dart(),
-            self.file_suffix.into_into_dart().into_dart(),
-            self.max_files.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_stream_probe {
+        ptr: *mut wire_cst_stream_probe,
+        len: i32,
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::logger::WriteToFiles
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::logger::WriteToFiles>
-    for crate::api::logger::WriteToFiles
-{
-    fn into_into_dart(self) -> crate::api::logger::WriteToFiles {
-        self
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_stream_side_data {
+        ptr: *mut wire_cst_stream_side_data,
+        len: i32,
     }
-}
-
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_loudness_info {
+        integrated_lufs: f64,
+        loudness_range_lu: f64,
+        true_peak_dbtp: f64,
     }
-}
-
-impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_loudness_normalization {
+        target_lufs: f32,
+        true_peak_dbtp: *mut f32,
     }
-}
-
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_media_chapter {
+        id: i64,
+        start_ms: u64,
+        end_ms: u64,
+        title: *mut wire_cst_list_prim_u_8_strict,
+        tags: *mut wire_cst_list_media_tag,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_media_hash {
+        algorithm: i32,
+        hashes: *mut wire_cst_list_prim_u_64_strict,
+        times_ms: *mut wire_cst_list_prim_u_64_strict,
     }
-}
-
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_media_probe {
+        format_name: *mut wire_cst_list_prim_u_8_strict,
+        format_long_name: *mut wire_cst_list_prim_u_8_strict,
+        duration_ms: *mut u64,
+        start_time_ms: *mut i64,
+        bitrate: *mut u64,
+        size_bytes: u64,
+        tags: *mut wire_cst_list_media_tag,
+        chapters: *mut wire_cst_list_media_chapter,
+        streams: *mut wire_cst_list_stream_probe,
     }
-}
-
-impl SseEncode for crate::api::media::CompressParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.target_bitrate_kbps, serializer);
-        <Option<String>>::sse_encode(self.preset, serializer);
-        <Option<u8>>::sse_encode(self.crf, serializer);
-        <Option<u32>>::sse_encode(self.width, serializer);
-        <Option<u32>>::sse_encode(self.height, serializer);
-        <Option<u64>>::sse_encode(self.sample_duration_ms, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_media_tag {
+        key: *mut wire_cst_list_prim_u_8_strict,
+        value: *mut wire_cst_list_prim_u_8_strict,
     }
-}
-
-impl SseEncode for crate::api::media::CompressionEstimate {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.estimated_size_bytes, serializer);
-        <u64>::sse_encode(self.estimated_duration_ms, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_palette {
+        colors: *mut wire_cst_list_palette_color,
+        average: wire_cst_palette_color,
+        vibrant: *mut wire_cst_palette_color,
+        light_vibrant: *mut wire_cst_palette_color,
+        dark_vibrant: *mut wire_cst_palette_color,
+        muted: *mut wire_cst_palette_color,
+        light_muted: *mut wire_cst_palette_color,
+        dark_muted: *mut wire_cst_palette_color,
     }
-}
-
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_palette_color {
+        color: u32,
+        population: f64,
     }
-}
-
-impl SseEncode for crate::api::media::ImageThumbnailParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
-        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_placeholder_image {
+        rgba: *mut wire_cst_list_prim_u_8_strict,
+        width: u32,
+        height: u32,
     }
-}
-
-impl SseEncode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_record_u_32_u_32 {
+        field0: u32,
+        field1: u32,
     }
-}
-
-impl SseEncode for Vec<crate::api::media::ResolutionPreset> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::media::ResolutionPreset>::sse_encode(item, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_resolution_preset {
+        name: *mut wire_cst_list_prim_u_8_strict,
+        width: u32,
+        height: u32,
+        bitrate: u64,
+        crf: u8,
     }
-}
-
-impl SseEncode for crate::api::logger::LogLevel {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::logger::LogLevel::Error => 0,
-                crate::api::logger::LogLevel::Warn => 1,
-                crate::api::logger::LogLevel::Info => 2,
-                crate::api::logger::LogLevel::Debug => 3,
-                crate::api::logger::LogLevel::Trace => 4,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_reverse_params {
+        start_ms: *mut u64,
+        end_ms: *mut u64,
+        boomerang_loops: *mut u32,
+        audio: *mut i32,
+        width: *mut u32,
+        height: *mut u32,
+        target_bitrate_kbps: *mut u32,
+        preset: *mut wire_cst_list_prim_u_8_strict,
+        crf: *mut u8,
+        memory_budget_mb: *mut u32,
     }
-}
-
-impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_scene_cut {
+        time_ms: u64,
+        score: f64,
     }
-}
-
-impl SseEncode for Option<bool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_scene_detection_event {
+        tag: i32,
+        kind: SceneDetectionEventKind,
     }
-}
-
-impl SseEncode for Option<crate::api::media::ImageThumbnailParams> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::media::ImageThumbnailParams>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union SceneDetectionEventKind {
+        Progress: wire_cst_SceneDetectionEvent_Progress,
+        Cuts: wire_cst_SceneDetectionEvent_Cuts,
+        nil__: (),
     }
-}
-
-impl SseEncode for Option<crate::api::media::OutputFormat> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::media::OutputFormat>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SceneDetectionEvent_Progress {
+        field0: *mut wire_cst_compress_progress,
     }
-}
-
-impl SseEncode for Option<crate::api::media::ThumbnailSizeType> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::media::ThumbnailSizeType>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_SceneDetectionEvent_Cuts {
+        field0: *mut wire_cst_list_scene_cut,
     }
-}
-
-impl SseEncode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_speed_params {
+        factor: f32,
+        frame_rate: *mut u32,
+        mute_audio_above: *mut f32,
     }
-}
-
-impl SseEncode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_storyboard {
+        vtt_path: *mut wire_cst_list_prim_u_8_strict,
+        sprite_paths: *mut wire_cst_list_String,
+        tile_count: u32,
     }
-}
-
-impl SseEncode for Option<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u8>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_stream_disposition {
+        default: bool,
+        dub: bool,
+        original: bool,
+        comment: bool,
+        lyrics: bool,
+        karaoke: bool,
+        forced: bool,
+        hearing_impaired: bool,
+        visual_impaired: bool,
+        clean_effects: bool,
+        attached_pic: bool,
+        captions: bool,
+        descriptions: bool,
+        metadata: bool,
     }
-}
-
-impl SseEncode for Option<crate::api::logger::WriteToFiles> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::logger::WriteToFiles>::sse_encode(value, serializer);
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_stream_probe {
+        index: u32,
+        stream_type: i32,
+        codec_name: *mut wire_cst_list_prim_u_8_strict,
+        codec_long_name: *mut wire_cst_list_prim_u_8_strict,
+        profile: *mut wire_cst_list_prim_u_8_strict,
+        bitrate: *mut u64,
+        start_time_ms: *mut i64,
+        duration_ms: *mut u64,
+        frame_count: *mut u64,
+        language: *mut wire_cst_list_prim_u_8_strict,
+        title: *mut wire_cst_list_prim_u_8_strict,
+        disposition: wire_cst_stream_disposition,
+        tags: *mut wire_cst_list_media_tag,
+        side_data: *mut wire_cst_list_stream_side_data,
+        video: *mut wire_cst_video_stream_probe,
+        audio: *mut wire_cst_audio_stream_probe,
     }
-}
-
-impl SseEncode for crate::api::media::OutputFormat {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::media::OutputFormat::WEBP => 0,
-                crate::api::media::OutputFormat::JPEG => 1,
-                crate::api::media::OutputFormat::PNG => 2,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_stream_side_data {
+        kind: *mut wire_cst_list_prim_u_8_strict,
+        size_bytes: u32,
     }
-}
-
-impl SseEncode for (u32, u32) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.0, serializer);
-        <u32>::sse_encode(self.1, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_subtitle_params {
+        subtitle_path: *mut wire_cst_list_prim_u_8_strict,
+        font_path: *mut wire_cst_list_prim_u_8_strict,
+        font_size: *mut u32,
+        color: *mut u32,
+        outline_color: *mut u32,
+        outline_width: *mut u32,
+        bottom_margin: *mut u32,
     }
-}
-
-impl SseEncode for crate::api::media::ResolutionPreset {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.name, serializer);
-        <u32>::sse_encode(self.width, serializer);
-        <u32>::sse_encode(self.height, serializer);
-        <u64>::sse_encode(self.bitrate, serializer);
-        <u8>::sse_encode(self.crf, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_thumbnail_data {
+        data: *mut wire_cst_list_prim_u_8_strict,
+        width: u32,
+        height: u32,
+        time_ms: *mut u64,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_thumbnail_size_type {
+        tag: i32,
+        kind: ThumbnailSizeTypeKind,
     }
-}
-
-impl SseEncode for crate::api::media::ThumbnailSizeType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::media::ThumbnailSizeType::Icon => {
-                <i32>::sse_encode(0, serializer);
-            }
-            crate::api::media::ThumbnailSizeType::Small => {
-                <i32>::sse_encode(1, serializer);
-            }
-            crate::api::media::ThumbnailSizeType::Medium => {
-                <i32>::sse_encode(2, serializer);
-            }
-            crate::api::media::ThumbnailSizeType::Large => {
-                <i32>::sse_encode(3, serializer);
-            }
-            crate::api::media::ThumbnailSizeType::Larger => {
-                <i32>::sse_encode(4, serializer);
-            }
-            crate::api::media::ThumbnailSizeType::Custom(field0) => {
-                <i32>::sse_encode(5, serializer);
-                <(u32, u32)>::sse_encode(field0, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union ThumbnailSizeTypeKind {
+        Custom: wire_cst_ThumbnailSizeType_Custom,
+        nil__: (),
     }
-}
-
-impl SseEncode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_ThumbnailSizeType_Custom {
+        field0: *mut wire_cst_record_u_32_u_32,
     }
-}
-
-impl SseEncode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_video_info {
+        duration_ms: u64,
+        width: u32,
+        height: u32,
+        size_bytes: u64,
+        bitrate: *mut u64,
+        codec_name: *mut wire_cst_list_prim_u_8_strict,
+        format_name: *mut wire_cst_list_prim_u_8_strict,
+        stored_width: u32,
+        stored_height: u32,
+        rotation: i32,
+        avg_frame_rate: *mut f64,
+        real_frame_rate: *mut f64,
+        is_vfr: bool,
+        pixel_format: *mut wire_cst_list_prim_u_8_strict,
+        bit_depth: *mut u32,
+        color_primaries: *mut wire_cst_list_prim_u_8_strict,
+        color_transfer: *mut wire_cst_list_prim_u_8_strict,
+        color_matrix: *mut wire_cst_list_prim_u_8_strict,
+        color_range: *mut wire_cst_list_prim_u_8_strict,
+        hdr_format: *mut i32,
+        profile: *mut wire_cst_list_prim_u_8_strict,
+        level: *mut i32,
+        frame_count: *mut u64,
+        audio: *mut wire_cst_audio_stream_info,
+        suggestions: *mut wire_cst_list_resolution_preset,
     }
-}
-
-impl SseEncode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_video_stream_probe {
+        width: u32,
+        height: u32,
+        display_width: u32,
+        display_height: u32,
+        rotation: i32,
+        avg_frame_rate: *mut f64,
+        real_frame_rate: *mut f64,
+        is_vfr: bool,
+        pixel_format: *mut wire_cst_list_prim_u_8_strict,
+        bit_depth: *mut u32,
+        color_primaries: *mut wire_cst_list_prim_u_8_strict,
+        color_transfer: *mut wire_cst_list_prim_u_8_strict,
+        color_matrix: *mut wire_cst_list_prim_u_8_strict,
+        color_range: *mut wire_cst_list_prim_u_8_strict,
+        hdr_format: *mut i32,
+        level: *mut i32,
     }
-}
-
-impl SseEncode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
-
-impl SseEncode for crate::api::media::VideoInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.duration_ms, serializer);
-        <u32>::sse_encode(self.width, serializer);
-        <u32>::sse_encode(self.height, serializer);
-        <u64>::sse_encode(self.size_bytes, serializer);
-        <Option<u64>>::sse_encode(self.bitrate, serializer);
-        <Option<String>>::sse_encode(self.codec_name, serializer);
-        <Option<String>>::sse_encode(self.format_name, serializer);
-        <Vec<crate::api::media::ResolutionPreset>>::sse_encode(self.suggestions, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_video_thumbnail_params {
+        time_ms: u64,
+        size_type: *mut wire_cst_thumbnail_size_type,
+        format: *mut i32,
+        seek_mode: *mut i32,
+        resize_mode: *mut i32,
+        allow_upscale: *mut bool,
+        encoder_options: *mut wire_cst_encoder_options,
     }
-}
-
-impl SseEncode for crate::api::media::VideoThumbnailParams {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.time_ms, serializer);
-        <Option<crate::api::media::ThumbnailSizeType>>::sse_encode(self.size_type, serializer);
-        <Option<crate::api::media::OutputFormat>>::sse_encode(self.format, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_watermark_params {
+        image_path: *mut wire_cst_list_prim_u_8_strict,
+        position: *mut i32,
+        margin: *mut u32,
+        scale: *mut f32,
+        opacity: *mut f32,
+        start_ms: *mut u64,
+        end_ms: *mut u64,
     }
-}
-
-impl SseEncode for crate::api::logger::WriteToFiles {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.path, serializer);
-        <String>::sse_encode(self.file_prefix, serializer);
-        <Option<String>>::sse_encode(self.file_suffix, serializer);
-        <Option<u64>>::sse_encode(self.max_files, serializer);
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_write_to_files {
+        path: *mut wire_cst_list_prim_u_8_strict,
+        file_prefix: *mut wire_cst_list_prim_u_8_strict,
+        file_suffix: *mut wire_cst_list_prim_u_8_strict,
+        max_files: *mut u64,
     }
 }
-
 #[cfg(not(target_family = "wasm"))]
-mod io {
+pub use io::*;
+
+/// cbindgen:ignore
+#[cfg(target_family = "wasm")]
+mod web {
     // This file is automatically generated, so please do not edit it.
     // @generated by `flutter_rust_bridge`@ 2.11.1.
 
@@ -1440,773 +9797,988 @@ mod io {
     use flutter_rust_bridge::for_generated::byteorder::{
         NativeEndian, ReadBytesExt, WriteBytesExt,
     };
+    use flutter_rust_bridge::for_generated::wasm_bindgen;
+    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
     use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
     use flutter_rust_bridge::{Handler, IntoIntoDart};
 
     // Section: boilerplate
 
-    flutter_rust_bridge::frb_generated_boilerplate_io!();
-
-    // Section: dart2rust
+    flutter_rust_bridge::frb_generated_boilerplate_web!();
 
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
-        for *mut wire_cst_list_prim_u_8_strict
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
-        }
-    }
-    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>
-        for *mut wire_cst_list_prim_u_8_strict
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
-            let raw: String = self.cst_decode();
-            StreamSink::deserialize(raw)
-        }
-    }
-    impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            let vec: Vec<u8> = self.cst_decode();
-            String::from_utf8(vec).unwrap()
-        }
-    }
-    impl CstDecode<bool> for *mut bool {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> bool {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<crate::api::media::CompressParams> for *mut wire_cst_compress_params {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::CompressParams {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::media::CompressParams>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<crate::api::media::ImageThumbnailParams> for *mut wire_cst_image_thumbnail_params {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::media::ImageThumbnailParams>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<crate::api::media::OutputFormat> for *mut i32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::OutputFormat {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::media::OutputFormat>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<(u32, u32)> for *mut wire_cst_record_u_32_u_32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> (u32, u32) {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<(u32, u32)>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<crate::api::media::ThumbnailSizeType> for *mut wire_cst_thumbnail_size_type {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ThumbnailSizeType {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::media::ThumbnailSizeType>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<u32> for *mut u32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u32 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<u64> for *mut u64 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u64 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<u8> for *mut u8 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u8 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<crate::api::media::VideoThumbnailParams> for *mut wire_cst_video_thumbnail_params {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::VideoThumbnailParams {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::media::VideoThumbnailParams>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<crate::api::logger::WriteToFiles> for *mut wire_cst_write_to_files {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::logger::WriteToFiles {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::api::logger::WriteToFiles>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<crate::api::media::CompressParams> for wire_cst_compress_params {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::CompressParams {
-            crate::api::media::CompressParams {
-                target_bitrate_kbps: self.target_bitrate_kbps.cst_decode(),
-                preset: self.preset.cst_decode(),
-                crf: self.crf.cst_decode(),
-                width: self.width.cst_decode(),
-                height: self.height.cst_decode(),
-                sample_duration_ms: self.sample_duration_ms.cst_decode(),
-            }
-        }
-    }
-    impl CstDecode<crate::api::media::CompressionEstimate> for wire_cst_compression_estimate {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::CompressionEstimate {
-            crate::api::media::CompressionEstimate {
-                estimated_size_bytes: self.estimated_size_bytes.cst_decode(),
-                estimated_duration_ms: self.estimated_duration_ms.cst_decode(),
-            }
-        }
-    }
-    impl CstDecode<crate::api::media::ImageThumbnailParams> for wire_cst_image_thumbnail_params {
+    // Section: dart2rust
+
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error> for String {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
-            crate::api::media::ImageThumbnailParams {
-                size_type: self.size_type.cst_decode(),
-                format: self.format.cst_decode(),
-            }
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
         }
     }
-    impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
+    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>> for String {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<u8> {
-            unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            }
+        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
+            StreamSink::deserialize(self)
         }
     }
-    impl CstDecode<Vec<crate::api::media::ResolutionPreset>> for *mut wire_cst_list_resolution_preset {
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::CompressProgress,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for String
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::api::media::ResolutionPreset> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self)
         }
     }
-    impl CstDecode<(u32, u32)> for wire_cst_record_u_32_u_32 {
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ExtractedFrame,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for String
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> (u32, u32) {
-            (self.field0.cst_decode(), self.field1.cst_decode())
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ExtractedFrame,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self)
         }
     }
-    impl CstDecode<crate::api::media::ResolutionPreset> for wire_cst_resolution_preset {
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::SceneDetectionEvent,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for String
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ResolutionPreset {
-            crate::api::media::ResolutionPreset {
-                name: self.name.cst_decode(),
-                width: self.width.cst_decode(),
-                height: self.height.cst_decode(),
-                bitrate: self.bitrate.cst_decode(),
-                crf: self.crf.cst_decode(),
-            }
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::SceneDetectionEvent,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self)
         }
     }
-    impl CstDecode<crate::api::media::ThumbnailSizeType> for wire_cst_thumbnail_size_type {
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ThumbnailData,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for String
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ThumbnailSizeType {
-            match self.tag {
-                0 => crate::api::media::ThumbnailSizeType::Icon,
-                1 => crate::api::media::ThumbnailSizeType::Small,
-                2 => crate::api::media::ThumbnailSizeType::Medium,
-                3 => crate::api::media::ThumbnailSizeType::Large,
-                4 => crate::api::media::ThumbnailSizeType::Larger,
-                5 => {
-                    let ans = unsafe { self.kind.Custom };
-                    crate::api::media::ThumbnailSizeType::Custom(ans.field0.cst_decode())
-                }
-                _ => unreachable!(),
-            }
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ThumbnailData,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self)
         }
     }
-    impl CstDecode<crate::api::media::VideoInfo> for wire_cst_video_info {
+    impl CstDecode<String> for String {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::VideoInfo {
-            crate::api::media::VideoInfo {
-                duration_ms: self.duration_ms.cst_decode(),
-                width: self.width.cst_decode(),
-                height: self.height.cst_decode(),
-                size_bytes: self.size_bytes.cst_decode(),
-                bitrate: self.bitrate.cst_decode(),
-                codec_name: self.codec_name.cst_decode(),
-                format_name: self.format_name.cst_decode(),
-                suggestions: self.suggestions.cst_decode(),
-            }
+        fn cst_decode(self) -> String {
+            self
         }
     }
-    impl CstDecode<crate::api::media::VideoThumbnailParams> for wire_cst_video_thumbnail_params {
+    impl CstDecode<crate::api::media::AnimationOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::VideoThumbnailParams {
-            crate::api::media::VideoThumbnailParams {
-                time_ms: self.time_ms.cst_decode(),
-                size_type: self.size_type.cst_decode(),
-                format: self.format.cst_decode(),
+        fn cst_decode(self) -> crate::api::media::AnimationOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::AnimationOptions {
+                max_bytes: self_.get(0).cst_decode(),
+                loop_count: self_.get(1).cst_decode(),
+                dither: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::api::logger::WriteToFiles> for wire_cst_write_to_files {
+    impl CstDecode<crate::api::media::AudioExtractOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::logger::WriteToFiles {
-            crate::api::logger::WriteToFiles {
-                path: self.path.cst_decode(),
-                file_prefix: self.file_prefix.cst_decode(),
-                file_suffix: self.file_suffix.cst_decode(),
-                max_files: self.max_files.cst_decode(),
+        fn cst_decode(self) -> crate::api::media::AudioExtractOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::AudioExtractOptions {
+                start_ms: self_.get(0).cst_decode(),
+                end_ms: self_.get(1).cst_decode(),
+                channels: self_.get(2).cst_decode(),
+                sample_rate: self_.get(3).cst_decode(),
+                bitrate_kbps: self_.get(4).cst_decode(),
+                normalize_loudness: self_.get(5).cst_decode(),
             }
         }
     }
-    impl NewWithNullPtr for wire_cst_compress_params {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                target_bitrate_kbps: Default::default(),
-                preset: core::ptr::null_mut(),
-                crf: core::ptr::null_mut(),
-                width: core::ptr::null_mut(),
-                height: core::ptr::null_mut(),
-                sample_duration_ms: core::ptr::null_mut(),
+    impl CstDecode<crate::api::media::AudioMixParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioMixParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                8,
+                "Expected 8 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::AudioMixParams {
+                mode: self_.get(0).cst_decode(),
+                music_gain_db: self_.get(1).cst_decode(),
+                original_gain_db: self_.get(2).cst_decode(),
+                ducking: self_.get(3).cst_decode(),
+                loop_music: self_.get(4).cst_decode(),
+                music_start_ms: self_.get(5).cst_decode(),
+                fade_in_ms: self_.get(6).cst_decode(),
+                fade_out_ms: self_.get(7).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_compress_params {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_compression_estimate {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                estimated_size_bytes: Default::default(),
-                estimated_duration_ms: Default::default(),
+    impl CstDecode<crate::api::media::AudioStreamInfo>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamInfo {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::AudioStreamInfo {
+                codec_name: self_.get(0).cst_decode(),
+                channels: self_.get(1).cst_decode(),
+                sample_rate: self_.get(2).cst_decode(),
+                bitrate: self_.get(3).cst_decode(),
+                language: self_.get(4).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_compression_estimate {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_image_thumbnail_params {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                size_type: core::ptr::null_mut(),
-                format: core::ptr::null_mut(),
+    impl CstDecode<crate::api::media::AudioStreamProbe>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioStreamProbe {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::AudioStreamProbe {
+                sample_rate: self_.get(0).cst_decode(),
+                channels: self_.get(1).cst_decode(),
+                channel_layout: self_.get(2).cst_decode(),
+                sample_format: self_.get(3).cst_decode(),
+                bits_per_sample: self_.get(4).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_image_thumbnail_params {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_record_u_32_u_32 {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                field0: Default::default(),
-                field1: Default::default(),
+    impl CstDecode<crate::api::media::BestThumbnail>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::BestThumbnail {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::BestThumbnail {
+                data: self_.get(0).cst_decode(),
+                width: self_.get(1).cst_decode(),
+                height: self_.get(2).cst_decode(),
+                time_ms: self_.get(3).cst_decode(),
+                score: self_.get(4).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_record_u_32_u_32 {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_resolution_preset {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                name: core::ptr::null_mut(),
-                width: Default::default(),
-                height: Default::default(),
-                bitrate: Default::default(),
-                crf: Default::default(),
+    impl CstDecode<crate::api::media::BestThumbnailParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::BestThumbnailParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::BestThumbnailParams {
+                size_type: self_.get(0).cst_decode(),
+                format: self_.get(1).cst_decode(),
+                candidates: self_.get(2).cst_decode(),
+                resize_mode: self_.get(3).cst_decode(),
+                allow_upscale: self_.get(4).cst_decode(),
+                encoder_options: self_.get(5).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_resolution_preset {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_thumbnail_size_type {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                tag: -1,
-                kind: ThumbnailSizeTypeKind { nil__: () },
+    impl CstDecode<crate::api::media::CompressParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                16,
+                "Expected 16 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::CompressParams {
+                target_bitrate_kbps: self_.get(0).cst_decode(),
+                preset: self_.get(1).cst_decode(),
+                crf: self_.get(2).cst_decode(),
+                width: self_.get(3).cst_decode(),
+                height: self_.get(4).cst_decode(),
+                sample_duration_ms: self_.get(5).cst_decode(),
+                crop: self_.get(6).cst_decode(),
+                aspect_ratio: self_.get(7).cst_decode(),
+                aspect_mode: self_.get(8).cst_decode(),
+                pad_color: self_.get(9).cst_decode(),
+                watermark: self_.get(10).cst_decode(),
+                subtitles: self_.get(11).cst_decode(),
+                speed: self_.get(12).cst_decode(),
+                normalize_loudness: self_.get(13).cst_decode(),
+                video_filters: self_.get(14).cst_decode(),
+                audio_filters: self_.get(15).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_thumbnail_size_type {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_video_info {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                duration_ms: Default::default(),
-                width: Default::default(),
-                height: Default::default(),
-                size_bytes: Default::default(),
-                bitrate: core::ptr::null_mut(),
-                codec_name: core::ptr::null_mut(),
-                format_name: core::ptr::null_mut(),
-                suggestions: core::ptr::null_mut(),
+    impl CstDecode<crate::api::media::CompressProgress>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressProgress {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::CompressProgress {
+                processed_ms: self_.get(0).cst_decode(),
+                total_ms: self_.get(1).cst_decode(),
+                speed_x: self_.get(2).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_video_info {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
-    }
-    impl NewWithNullPtr for wire_cst_video_thumbnail_params {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                time_ms: Default::default(),
-                size_type: core::ptr::null_mut(),
-                format: core::ptr::null_mut(),
+    impl CstDecode<crate::api::media::CompressionEstimate>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CompressionEstimate {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::CompressionEstimate {
+                estimated_size_bytes: self_.get(0).cst_decode(),
+                estimated_duration_ms: self_.get(1).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_video_thumbnail_params {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
+    impl CstDecode<crate::api::media::ConcatParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ConcatParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ConcatParams {
+                width: self_.get(0).cst_decode(),
+                height: self_.get(1).cst_decode(),
+                fps: self_.get(2).cst_decode(),
+                target_bitrate_kbps: self_.get(3).cst_decode(),
+                preset: self_.get(4).cst_decode(),
+                crf: self_.get(5).cst_decode(),
+                force_reencode: self_.get(6).cst_decode(),
+            }
         }
     }
-    impl NewWithNullPtr for wire_cst_write_to_files {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                path: core::ptr::null_mut(),
-                file_prefix: core::ptr::null_mut(),
-                file_suffix: core::ptr::null_mut(),
-                max_files: core::ptr::null_mut(),
+    impl CstDecode<crate::api::media::CropRect>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::CropRect {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::CropRect {
+                x: self_.get(0).cst_decode(),
+                y: self_.get(1).cst_decode(),
+                width: self_.get(2).cst_decode(),
+                height: self_.get(3).cst_decode(),
             }
         }
     }
-    impl Default for wire_cst_write_to_files {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
+    impl CstDecode<crate::api::media::DuckingParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::DuckingParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::DuckingParams {
+                threshold_db: self_.get(0).cst_decode(),
+                reduction_db: self_.get(1).cst_decode(),
+                attack_ms: self_.get(2).cst_decode(),
+                release_ms: self_.get(3).cst_decode(),
+            }
         }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__compress_video(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        output_path: *mut wire_cst_list_prim_u_8_strict,
-        params: *mut wire_cst_compress_params,
-    ) {
-        wire__crate__api__media__compress_video_impl(port_, path, output_path, params)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__logger__debug_threads(port_: i64) {
-        wire__crate__api__logger__debug_threads_impl(port_)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__estimate_compression(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        temp_output_path: *mut wire_cst_list_prim_u_8_strict,
-        params: *mut wire_cst_compress_params,
-    ) {
-        wire__crate__api__media__estimate_compression_impl(port_, path, temp_output_path, params)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_image_thumbnail(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        output_path: *mut wire_cst_list_prim_u_8_strict,
-        params: *mut wire_cst_image_thumbnail_params,
-        suffix: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__media__generate_image_thumbnail_impl(
-            port_,
-            path,
-            output_path,
-            params,
-            suffix,
-        )
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_thumbnail(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        output_path: *mut wire_cst_list_prim_u_8_strict,
-        params: *mut wire_cst_video_thumbnail_params,
-        empty_image_fallback: *mut bool,
-    ) {
-        wire__crate__api__media__generate_video_thumbnail_impl(
-            port_,
-            path,
-            output_path,
-            params,
-            empty_image_fallback,
-        )
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__generate_video_timeline_thumbnails(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        output_path: *mut wire_cst_list_prim_u_8_strict,
-        params: *mut wire_cst_image_thumbnail_params,
-        num_thumbnails: u32,
-        empty_image_fallback: *mut bool,
-        sink: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__media__generate_video_timeline_thumbnails_impl(
-            port_,
-            path,
-            output_path,
-            params,
-            num_thumbnails,
-            empty_image_fallback,
-            sink,
-        )
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__get_video_info(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__media__get_video_info_impl(port_, path)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__logger__init_logger(
-        port_: i64,
-        log_level: i32,
-        write_to_stdout_or_system: bool,
-        write_to_files: *mut wire_cst_write_to_files,
-        use_lightweight_tokio_runtime: bool,
-    ) {
-        wire__crate__api__logger__init_logger_impl(
-            port_,
-            log_level,
-            write_to_stdout_or_system,
-            write_to_files,
-            use_lightweight_tokio_runtime,
-        )
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__logger__log(
-        port_: i64,
-        file: *mut wire_cst_list_prim_u_8_strict,
-        line: *mut u32,
-        level: i32,
-        target: *mut wire_cst_list_prim_u_8_strict,
-        message: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__logger__log_impl(port_, file, line, level, target, message)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__output_format_extension(
-        port_: i64,
-        that: i32,
-    ) {
-        wire__crate__api__media__output_format_extension_impl(port_, that)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__logger__reload_tracing_file_writer(
-        port_: i64,
-        write_to_files: *mut wire_cst_write_to_files,
-    ) {
-        wire__crate__api__logger__reload_tracing_file_writer_impl(port_, write_to_files)
-    }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_wire__crate__api__media__thumbnail_size_type_dimensions(
-        port_: i64,
-        that: *mut wire_cst_thumbnail_size_type,
-    ) {
-        wire__crate__api__media__thumbnail_size_type_dimensions_impl(port_, that)
+    impl CstDecode<crate::api::media::EncoderOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::EncoderOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::EncoderOptions {
+                jpeg_quality: self_.get(0).cst_decode(),
+                jpeg_progressive: self_.get(1).cst_decode(),
+                webp_quality: self_.get(2).cst_decode(),
+                png_compression: self_.get(3).cst_decode(),
+                avif_quality: self_.get(4).cst_decode(),
+                heic_quality: self_.get(5).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_bool(value: bool) -> *mut bool {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::api::media::ExtractedFrame>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ExtractedFrame {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ExtractedFrame {
+                path: self_.get(0).cst_decode(),
+                index: self_.get(1).cst_decode(),
+                requested_ms: self_.get(2).cst_decode(),
+                time_ms: self_.get(3).cst_decode(),
+                width: self_.get(4).cst_decode(),
+                height: self_.get(5).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_compress_params(
-    ) -> *mut wire_cst_compress_params {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_compress_params::new_with_null_ptr(),
-        )
+    impl CstDecode<crate::api::media::FrameExtractParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::FrameExtractParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::FrameExtractParams {
+                selection: self_.get(0).cst_decode(),
+                size_type: self_.get(1).cst_decode(),
+                format: self_.get(2).cst_decode(),
+                seek_mode: self_.get(3).cst_decode(),
+                resize_mode: self_.get(4).cst_decode(),
+                allow_upscale: self_.get(5).cst_decode(),
+                encoder_options: self_.get(6).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_image_thumbnail_params(
-    ) -> *mut wire_cst_image_thumbnail_params {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_image_thumbnail_params::new_with_null_ptr(),
-        )
+    impl CstDecode<crate::api::media::FrameSelection>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::FrameSelection {
+            let self_ = self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Array>();
+            match self_.get(0).unchecked_into_f64() as _ {
+                0 => crate::api::media::FrameSelection::Timestamps(self_.get(1).cst_decode()),
+                1 => crate::api::media::FrameSelection::Interval(self_.get(1).cst_decode()),
+                2 => crate::api::media::FrameSelection::Count(self_.get(1).cst_decode()),
+                _ => unreachable!(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_output_format(value: i32) -> *mut i32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::api::media::GpsLocation>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::GpsLocation {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::GpsLocation {
+                latitude: self_.get(0).cst_decode(),
+                longitude: self_.get(1).cst_decode(),
+                altitude: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_record_u_32_u_32(
-    ) -> *mut wire_cst_record_u_32_u_32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_record_u_32_u_32::new_with_null_ptr(),
-        )
+    impl CstDecode<crate::api::media::HashComparison>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::HashComparison {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::HashComparison {
+                distance: self_.get(0).cst_decode(),
+                similarity: self_.get(1).cst_decode(),
+                is_similar: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_thumbnail_size_type(
-    ) -> *mut wire_cst_thumbnail_size_type {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_thumbnail_size_type::new_with_null_ptr(),
-        )
+    impl CstDecode<crate::api::media::ImageInfo>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ImageInfo {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                14,
+                "Expected 14 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ImageInfo {
+                width: self_.get(0).cst_decode(),
+                height: self_.get(1).cst_decode(),
+                format: self_.get(2).cst_decode(),
+                bit_depth: self_.get(3).cst_decode(),
+                has_alpha: self_.get(4).cst_decode(),
+                orientation: self_.get(5).cst_decode(),
+                camera_make: self_.get(6).cst_decode(),
+                camera_model: self_.get(7).cst_decode(),
+                capture_date: self_.get(8).cst_decode(),
+                gps: self_.get(9).cst_decode(),
+                icc_profile_name: self_.get(10).cst_decode(),
+                image_count: self_.get(11).cst_decode(),
+                auxiliary_image_count: self_.get(12).cst_decode(),
+                has_depth: self_.get(13).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::api::media::ImageThumbnailParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ImageThumbnailParams {
+                size_type: self_.get(0).cst_decode(),
+                format: self_.get(1).cst_decode(),
+                seek_mode: self_.get(2).cst_decode(),
+                resize_mode: self_.get(3).cst_decode(),
+                allow_upscale: self_.get(4).cst_decode(),
+                encoder_options: self_.get(5).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_64(value: u64) -> *mut u64 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<Vec<String>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<String> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_u_8(value: u8) -> *mut u8 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<Vec<crate::api::media::MediaChapter>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::MediaChapter> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_video_thumbnail_params(
-    ) -> *mut wire_cst_video_thumbnail_params {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_video_thumbnail_params::new_with_null_ptr(),
-        )
+    impl CstDecode<Vec<crate::api::media::MediaTag>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::MediaTag> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_box_autoadd_write_to_files(
-    ) -> *mut wire_cst_write_to_files {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_write_to_files::new_with_null_ptr(),
-        )
+    impl CstDecode<Vec<crate::api::media::PaletteColor>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::PaletteColor> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_list_prim_u_8_strict(
-        len: i32,
-    ) -> *mut wire_cst_list_prim_u_8_strict {
-        let ans = wire_cst_list_prim_u_8_strict {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+    impl CstDecode<Vec<u64>> for Box<[u64]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u64> {
+            self.into_vec()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_media_cst_new_list_resolution_preset(
-        len: i32,
-    ) -> *mut wire_cst_list_resolution_preset {
-        let wrap = wire_cst_list_resolution_preset {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_resolution_preset>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<u8>> for Box<[u8]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            self.into_vec()
+        }
     }
-
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_compress_params {
-        target_bitrate_kbps: u32,
-        preset: *mut wire_cst_list_prim_u_8_strict,
-        crf: *mut u8,
-        width: *mut u32,
-        height: *mut u32,
-        sample_duration_ms: *mut u64,
+    impl CstDecode<Vec<u8>> for Box<[u8]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            self.into_vec()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_compression_estimate {
-        estimated_size_bytes: u64,
-        estimated_duration_ms: u64,
+    impl CstDecode<Vec<crate::api::media::ResolutionPreset>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::ResolutionPreset> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_image_thumbnail_params {
-        size_type: *mut wire_cst_thumbnail_size_type,
-        format: *mut i32,
+    impl CstDecode<Vec<crate::api::media::SceneCut>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::SceneCut> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_prim_u_8_strict {
-        ptr: *mut u8,
-        len: i32,
+    impl CstDecode<Vec<crate::api::media::StreamProbe>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::StreamProbe> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_resolution_preset {
-        ptr: *mut wire_cst_resolution_preset,
-        len: i32,
+    impl CstDecode<Vec<crate::api::media::StreamSideData>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::media::StreamSideData> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_record_u_32_u_32 {
-        field0: u32,
-        field1: u32,
+    impl CstDecode<crate::api::media::LoudnessInfo>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::LoudnessInfo {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::LoudnessInfo {
+                integrated_lufs: self_.get(0).cst_decode(),
+                loudness_range_lu: self_.get(1).cst_decode(),
+                true_peak_dbtp: self_.get(2).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_resolution_preset {
-        name: *mut wire_cst_list_prim_u_8_strict,
-        width: u32,
-        height: u32,
-        bitrate: u64,
-        crf: u8,
+    impl CstDecode<crate::api::media::LoudnessNormalization>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::LoudnessNormalization {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::LoudnessNormalization {
+                target_lufs: self_.get(0).cst_decode(),
+                true_peak_dbtp: self_.get(1).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_thumbnail_size_type {
-        tag: i32,
-        kind: ThumbnailSizeTypeKind,
+    impl CstDecode<crate::api::media::MediaChapter>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaChapter {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::MediaChapter {
+                id: self_.get(0).cst_decode(),
+                start_ms: self_.get(1).cst_decode(),
+                end_ms: self_.get(2).cst_decode(),
+                title: self_.get(3).cst_decode(),
+                tags: self_.get(4).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub union ThumbnailSizeTypeKind {
-        Custom: wire_cst_ThumbnailSizeType_Custom,
-        nil__: (),
+    impl CstDecode<crate::api::media::MediaHash>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaHash {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::MediaHash {
+                algorithm: self_.get(0).cst_decode(),
+                hashes: self_.get(1).cst_decode(),
+                times_ms: self_.get(2).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_ThumbnailSizeType_Custom {
-        field0: *mut wire_cst_record_u_32_u_32,
+    impl CstDecode<crate::api::media::MediaProbe>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaProbe {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                9,
+                "Expected 9 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::MediaProbe {
+                format_name: self_.get(0).cst_decode(),
+                format_long_name: self_.get(1).cst_decode(),
+                duration_ms: self_.get(2).cst_decode(),
+                start_time_ms: self_.get(3).cst_decode(),
+                bitrate: self_.get(4).cst_decode(),
+                size_bytes: self_.get(5).cst_decode(),
+                tags: self_.get(6).cst_decode(),
+                chapters: self_.get(7).cst_decode(),
+                streams: self_.get(8).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_video_info {
-        duration_ms: u64,
-        width: u32,
-        height: u32,
-        size_bytes: u64,
-        bitrate: *mut u64,
-        codec_name: *mut wire_cst_list_prim_u_8_strict,
-        format_name: *mut wire_cst_list_prim_u_8_strict,
-        suggestions: *mut wire_cst_list_resolution_preset,
+    impl CstDecode<crate::api::media::MediaTag>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::MediaTag {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::MediaTag {
+                key: self_.get(0).cst_decode(),
+                value: self_.get(1).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_video_thumbnail_params {
-        time_ms: u64,
-        size_type: *mut wire_cst_thumbnail_size_type,
-        format: *mut i32,
+    impl CstDecode<Option<String>> for Option<String> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Option<String> {
+            self.map(CstDecode::cst_decode)
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_write_to_files {
-        path: *mut wire_cst_list_prim_u_8_strict,
-        file_prefix: *mut wire_cst_list_prim_u_8_strict,
-        file_suffix: *mut wire_cst_list_prim_u_8_strict,
-        max_files: *mut u64,
+    impl CstDecode<crate::api::media::Palette>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::Palette {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                8,
+                "Expected 8 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::Palette {
+                colors: self_.get(0).cst_decode(),
+                average: self_.get(1).cst_decode(),
+                vibrant: self_.get(2).cst_decode(),
+                light_vibrant: self_.get(3).cst_decode(),
+                dark_vibrant: self_.get(4).cst_decode(),
+                muted: self_.get(5).cst_decode(),
+                light_muted: self_.get(6).cst_decode(),
+                dark_muted: self_.get(7).cst_decode(),
+            }
+        }
     }
-}
-#[cfg(not(target_family = "wasm"))]
-pub use io::*;
-
-/// cbindgen:ignore
-#[cfg(target_family = "wasm")]
-mod web {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.11.1.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::wasm_bindgen;
-    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_web!();
-
-    // Section: dart2rust
-
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error> for String {
+    impl CstDecode<crate::api::media::PaletteColor>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
+        fn cst_decode(self) -> crate::api::media::PaletteColor {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::PaletteColor {
+                color: self_.get(0).cst_decode(),
+                population: self_.get(1).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::PlaceholderImage>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::PlaceholderImage {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::PlaceholderImage {
+                rgba: self_.get(0).cst_decode(),
+                width: self_.get(1).cst_decode(),
+                height: self_.get(2).cst_decode(),
+            }
         }
     }
-    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>> for String {
+    impl CstDecode<(u32, u32)> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
-            StreamSink::deserialize(self)
+        fn cst_decode(self) -> (u32, u32) {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            (self_.get(0).cst_decode(), self_.get(1).cst_decode())
         }
     }
-    impl CstDecode<String> for String {
+    impl CstDecode<crate::api::media::ResolutionPreset>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            self
+        fn cst_decode(self) -> crate::api::media::ResolutionPreset {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ResolutionPreset {
+                name: self_.get(0).cst_decode(),
+                width: self_.get(1).cst_decode(),
+                height: self_.get(2).cst_decode(),
+                bitrate: self_.get(3).cst_decode(),
+                crf: self_.get(4).cst_decode(),
+            }
         }
     }
-    impl CstDecode<crate::api::media::CompressParams>
+    impl CstDecode<crate::api::media::ReverseParams>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::CompressParams {
+        fn cst_decode(self) -> crate::api::media::ReverseParams {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                6,
-                "Expected 6 elements, got {}",
+                10,
+                "Expected 10 elements, got {}",
                 self_.length()
             );
-            crate::api::media::CompressParams {
-                target_bitrate_kbps: self_.get(0).cst_decode(),
-                preset: self_.get(1).cst_decode(),
-                crf: self_.get(2).cst_decode(),
-                width: self_.get(3).cst_decode(),
-                height: self_.get(4).cst_decode(),
-                sample_duration_ms: self_.get(5).cst_decode(),
+            crate::api::media::ReverseParams {
+                start_ms: self_.get(0).cst_decode(),
+                end_ms: self_.get(1).cst_decode(),
+                boomerang_loops: self_.get(2).cst_decode(),
+                audio: self_.get(3).cst_decode(),
+                width: self_.get(4).cst_decode(),
+                height: self_.get(5).cst_decode(),
+                target_bitrate_kbps: self_.get(6).cst_decode(),
+                preset: self_.get(7).cst_decode(),
+                crf: self_.get(8).cst_decode(),
+                memory_budget_mb: self_.get(9).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::api::media::CompressionEstimate>
+    impl CstDecode<crate::api::media::SceneCut>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::CompressionEstimate {
+        fn cst_decode(self) -> crate::api::media::SceneCut {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -2216,59 +10788,138 @@ mod web {
                 "Expected 2 elements, got {}",
                 self_.length()
             );
-            crate::api::media::CompressionEstimate {
-                estimated_size_bytes: self_.get(0).cst_decode(),
-                estimated_duration_ms: self_.get(1).cst_decode(),
+            crate::api::media::SceneCut {
+                time_ms: self_.get(0).cst_decode(),
+                score: self_.get(1).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::api::media::ImageThumbnailParams>
+    impl CstDecode<crate::api::media::SceneDetectionEvent>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ImageThumbnailParams {
+        fn cst_decode(self) -> crate::api::media::SceneDetectionEvent {
+            let self_ = self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Array>();
+            match self_.get(0).unchecked_into_f64() as _ {
+                0 => crate::api::media::SceneDetectionEvent::Progress(self_.get(1).cst_decode()),
+                1 => crate::api::media::SceneDetectionEvent::Cuts(self_.get(1).cst_decode()),
+                _ => unreachable!(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::SpeedParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SpeedParams {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                2,
-                "Expected 2 elements, got {}",
+                3,
+                "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::api::media::ImageThumbnailParams {
-                size_type: self_.get(0).cst_decode(),
-                format: self_.get(1).cst_decode(),
+            crate::api::media::SpeedParams {
+                factor: self_.get(0).cst_decode(),
+                frame_rate: self_.get(1).cst_decode(),
+                mute_audio_above: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<Vec<u8>> for Box<[u8]> {
+    impl CstDecode<crate::api::media::Storyboard>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<u8> {
-            self.into_vec()
+        fn cst_decode(self) -> crate::api::media::Storyboard {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::Storyboard {
+                vtt_path: self_.get(0).cst_decode(),
+                sprite_paths: self_.get(1).cst_decode(),
+                tile_count: self_.get(2).cst_decode(),
+            }
         }
     }
-    impl CstDecode<Vec<crate::api::media::ResolutionPreset>>
+    impl CstDecode<crate::api::media::StreamDisposition>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::api::media::ResolutionPreset> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::api::media::StreamDisposition {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                14,
+                "Expected 14 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::StreamDisposition {
+                default: self_.get(0).cst_decode(),
+                dub: self_.get(1).cst_decode(),
+                original: self_.get(2).cst_decode(),
+                comment: self_.get(3).cst_decode(),
+                lyrics: self_.get(4).cst_decode(),
+                karaoke: self_.get(5).cst_decode(),
+                forced: self_.get(6).cst_decode(),
+                hearing_impaired: self_.get(7).cst_decode(),
+                visual_impaired: self_.get(8).cst_decode(),
+                clean_effects: self_.get(9).cst_decode(),
+                attached_pic: self_.get(10).cst_decode(),
+                captions: self_.get(11).cst_decode(),
+                descriptions: self_.get(12).cst_decode(),
+                metadata: self_.get(13).cst_decode(),
+            }
         }
     }
-    impl CstDecode<Option<String>> for Option<String> {
+    impl CstDecode<crate::api::media::StreamProbe>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Option<String> {
-            self.map(CstDecode::cst_decode)
+        fn cst_decode(self) -> crate::api::media::StreamProbe {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                16,
+                "Expected 16 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::StreamProbe {
+                index: self_.get(0).cst_decode(),
+                stream_type: self_.get(1).cst_decode(),
+                codec_name: self_.get(2).cst_decode(),
+                codec_long_name: self_.get(3).cst_decode(),
+                profile: self_.get(4).cst_decode(),
+                bitrate: self_.get(5).cst_decode(),
+                start_time_ms: self_.get(6).cst_decode(),
+                duration_ms: self_.get(7).cst_decode(),
+                frame_count: self_.get(8).cst_decode(),
+                language: self_.get(9).cst_decode(),
+                title: self_.get(10).cst_decode(),
+                disposition: self_.get(11).cst_decode(),
+                tags: self_.get(12).cst_decode(),
+                side_data: self_.get(13).cst_decode(),
+                video: self_.get(14).cst_decode(),
+                audio: self_.get(15).cst_decode(),
+            }
         }
     }
-    impl CstDecode<(u32, u32)> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<crate::api::media::StreamSideData>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> (u32, u32) {
+        fn cst_decode(self) -> crate::api::media::StreamSideData {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -2278,29 +10929,56 @@ mod web {
                 "Expected 2 elements, got {}",
                 self_.length()
             );
-            (self_.get(0).cst_decode(), self_.get(1).cst_decode())
+            crate::api::media::StreamSideData {
+                kind: self_.get(0).cst_decode(),
+                size_bytes: self_.get(1).cst_decode(),
+            }
         }
     }
-    impl CstDecode<crate::api::media::ResolutionPreset>
+    impl CstDecode<crate::api::media::SubtitleParams>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::media::ResolutionPreset {
+        fn cst_decode(self) -> crate::api::media::SubtitleParams {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                5,
-                "Expected 5 elements, got {}",
+                7,
+                "Expected 7 elements, got {}",
                 self_.length()
             );
-            crate::api::media::ResolutionPreset {
-                name: self_.get(0).cst_decode(),
+            crate::api::media::SubtitleParams {
+                subtitle_path: self_.get(0).cst_decode(),
+                font_path: self_.get(1).cst_decode(),
+                font_size: self_.get(2).cst_decode(),
+                color: self_.get(3).cst_decode(),
+                outline_color: self_.get(4).cst_decode(),
+                outline_width: self_.get(5).cst_decode(),
+                bottom_margin: self_.get(6).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::ThumbnailData>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ThumbnailData {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::ThumbnailData {
+                data: self_.get(0).cst_decode(),
                 width: self_.get(1).cst_decode(),
                 height: self_.get(2).cst_decode(),
-                bitrate: self_.get(3).cst_decode(),
-                crf: self_.get(4).cst_decode(),
+                time_ms: self_.get(3).cst_decode(),
             }
         }
     }
@@ -2331,8 +11009,8 @@ mod web {
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                8,
-                "Expected 8 elements, got {}",
+                25,
+                "Expected 25 elements, got {}",
                 self_.length()
             );
             crate::api::media::VideoInfo {
@@ -2343,7 +11021,58 @@ mod web {
                 bitrate: self_.get(4).cst_decode(),
                 codec_name: self_.get(5).cst_decode(),
                 format_name: self_.get(6).cst_decode(),
-                suggestions: self_.get(7).cst_decode(),
+                stored_width: self_.get(7).cst_decode(),
+                stored_height: self_.get(8).cst_decode(),
+                rotation: self_.get(9).cst_decode(),
+                avg_frame_rate: self_.get(10).cst_decode(),
+                real_frame_rate: self_.get(11).cst_decode(),
+                is_vfr: self_.get(12).cst_decode(),
+                pixel_format: self_.get(13).cst_decode(),
+                bit_depth: self_.get(14).cst_decode(),
+                color_primaries: self_.get(15).cst_decode(),
+                color_transfer: self_.get(16).cst_decode(),
+                color_matrix: self_.get(17).cst_decode(),
+                color_range: self_.get(18).cst_decode(),
+                hdr_format: self_.get(19).cst_decode(),
+                profile: self_.get(20).cst_decode(),
+                level: self_.get(21).cst_decode(),
+                frame_count: self_.get(22).cst_decode(),
+                audio: self_.get(23).cst_decode(),
+                suggestions: self_.get(24).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::VideoStreamProbe>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::VideoStreamProbe {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                16,
+                "Expected 16 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::VideoStreamProbe {
+                width: self_.get(0).cst_decode(),
+                height: self_.get(1).cst_decode(),
+                display_width: self_.get(2).cst_decode(),
+                display_height: self_.get(3).cst_decode(),
+                rotation: self_.get(4).cst_decode(),
+                avg_frame_rate: self_.get(5).cst_decode(),
+                real_frame_rate: self_.get(6).cst_decode(),
+                is_vfr: self_.get(7).cst_decode(),
+                pixel_format: self_.get(8).cst_decode(),
+                bit_depth: self_.get(9).cst_decode(),
+                color_primaries: self_.get(10).cst_decode(),
+                color_transfer: self_.get(11).cst_decode(),
+                color_matrix: self_.get(12).cst_decode(),
+                color_range: self_.get(13).cst_decode(),
+                hdr_format: self_.get(14).cst_decode(),
+                level: self_.get(15).cst_decode(),
             }
         }
     }
@@ -2357,14 +11086,43 @@ mod web {
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                3,
-                "Expected 3 elements, got {}",
+                7,
+                "Expected 7 elements, got {}",
                 self_.length()
             );
             crate::api::media::VideoThumbnailParams {
                 time_ms: self_.get(0).cst_decode(),
                 size_type: self_.get(1).cst_decode(),
                 format: self_.get(2).cst_decode(),
+                seek_mode: self_.get(3).cst_decode(),
+                resize_mode: self_.get(4).cst_decode(),
+                allow_upscale: self_.get(5).cst_decode(),
+                encoder_options: self_.get(6).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::media::WatermarkParams>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::WatermarkParams {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::api::media::WatermarkParams {
+                image_path: self_.get(0).cst_decode(),
+                position: self_.get(1).cst_decode(),
+                margin: self_.get(2).cst_decode(),
+                scale: self_.get(3).cst_decode(),
+                opacity: self_.get(4).cst_decode(),
+                start_ms: self_.get(5).cst_decode(),
+                end_ms: self_.get(6).cst_decode(),
             }
         }
     }
@@ -2390,38 +11148,192 @@ mod web {
             }
         }
     }
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
+        }
+    }
+    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::CompressProgress,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::CompressProgress,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ExtractedFrame,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ExtractedFrame,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::SceneDetectionEvent,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::SceneDetectionEvent,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::api::media::ThumbnailData,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::api::media::ThumbnailData,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        }
+    }
+    impl CstDecode<String> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> String {
+            self.as_string().expect("non-UTF-8 string, or not a string")
+        }
+    }
+    impl CstDecode<crate::api::media::AnimationFormat>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AnimationFormat {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::AspectMode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AspectMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioChannels>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioChannels {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioFormat>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioFormat {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::AudioTrackMode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::AudioTrackMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<bool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> bool {
+            self.is_truthy()
+        }
+    }
+    impl CstDecode<f32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f32 {
+            self.unchecked_into_f64() as _
+        }
+    }
+    impl CstDecode<f64> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f64 {
+            self.unchecked_into_f64() as _
+        }
+    }
+    impl CstDecode<crate::api::media::HashAlgorithm>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
+        fn cst_decode(self) -> crate::api::media::HashAlgorithm {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec>>
+    impl CstDecode<crate::api::media::HdrFormat>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> StreamSink<String, flutter_rust_bridge::for_generated::DcoCodec> {
-            StreamSink::deserialize(self.as_string().expect("should be a string"))
+        fn cst_decode(self) -> crate::api::media::HdrFormat {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<String> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<i32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            self.as_string().expect("non-UTF-8 string, or not a string")
+        fn cst_decode(self) -> i32 {
+            self.unchecked_into_f64() as _
         }
     }
-    impl CstDecode<bool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<i64> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> bool {
-            self.is_truthy()
+        fn cst_decode(self) -> i64 {
+            ::std::convert::TryInto::<i64>::try_into(self).unwrap() as _
         }
     }
-    impl CstDecode<i32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<Vec<u64>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> i32 {
-            self.unchecked_into_f64() as _
+        fn cst_decode(self) -> Vec<u64> {
+            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::BigUint64Array>()
+                .to_vec()
+                .into()
         }
     }
     impl CstDecode<Vec<u8>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
@@ -2448,6 +11360,44 @@ mod web {
             (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
+    impl CstDecode<crate::api::media::ResizeMode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ResizeMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::ReverseAudioMode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::ReverseAudioMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::SeekMode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::SeekMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::api::media::StreamType>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::StreamType {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<u16> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u16 {
+            self.unchecked_into_f64() as _
+        }
+    }
     impl CstDecode<u32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> u32 {
@@ -2466,6 +11416,67 @@ mod web {
             self.unchecked_into_f64() as _
         }
     }
+    impl CstDecode<crate::api::media::WatermarkPosition>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::media::WatermarkPosition {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__add_music(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        video_path: String,
+        music_path: String,
+        output_path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        sink: String,
+    ) {
+        wire__crate__api__media__add_music_impl(
+            port_,
+            video_path,
+            music_path,
+            output_path,
+            params,
+            sink,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__analyze_loudness(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__media__analyze_loudness_impl(port_, path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__animation_format_extension(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        that: i32,
+    ) {
+        wire__crate__api__media__animation_format_extension_impl(port_, that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__audio_format_extension(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        that: i32,
+    ) {
+        wire__crate__api__media__audio_format_extension_impl(port_, that)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__compare_media_hashes(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        a: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        b: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__compare_media_hashes_impl(port_, a, b, threshold)
+    }
 
     #[wasm_bindgen]
     pub fn wire__crate__api__media__compress_video(
@@ -2477,6 +11488,62 @@ mod web {
         wire__crate__api__media__compress_video_impl(port_, path, output_path, params)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__compute_blurhash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        components_x: u32,
+        components_y: u32,
+        time_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__compute_blurhash_impl(
+            port_,
+            path,
+            components_x,
+            components_y,
+            time_ms,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__compute_image_hash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        algorithm: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__compute_image_hash_impl(port_, path, algorithm)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__compute_thumbhash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        time_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__compute_thumbhash_impl(port_, path, time_ms)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__compute_video_hash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        algorithm: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        frame_count: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__compute_video_hash_impl(port_, path, algorithm, frame_count)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__concat_videos(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        inputs: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        output_path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        sink: String,
+    ) {
+        wire__crate__api__media__concat_videos_impl(port_, inputs, output_path, params, sink)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__logger__debug_threads(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2484,6 +11551,35 @@ mod web {
         wire__crate__api__logger__debug_threads_impl(port_)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__decode_blurhash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        hash: String,
+        width: u32,
+        height: u32,
+        punch: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__decode_blurhash_impl(port_, hash, width, height, punch)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__decode_thumbhash(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        hash: Box<[u8]>,
+    ) {
+        wire__crate__api__media__decode_thumbhash_impl(port_, hash)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__detect_scenes(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        threshold: f64,
+        sink: String,
+    ) {
+        wire__crate__api__media__detect_scenes_impl(port_, path, threshold, sink)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__estimate_compression(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2494,6 +11590,72 @@ mod web {
         wire__crate__api__media__estimate_compression_impl(port_, path, temp_output_path, params)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__export_animation(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        output_path: String,
+        start_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        duration_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        fps: u32,
+        max_width: u32,
+        format: i32,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__export_animation_impl(
+            port_,
+            path,
+            output_path,
+            start_ms,
+            duration_ms,
+            fps,
+            max_width,
+            format,
+            options,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__extract_audio(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        output_path: String,
+        format: i32,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__extract_audio_impl(port_, path, output_path, format, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__extract_frames(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        output_path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        sink: String,
+    ) {
+        wire__crate__api__media__extract_frames_impl(port_, path, output_path, params, sink)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__extract_palette(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        count: u32,
+        time_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__extract_palette_impl(port_, path, count, time_ms)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__generate_best_thumbnail(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__generate_best_thumbnail_impl(port_, path, params)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__generate_image_thumbnail(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2511,6 +11673,40 @@ mod web {
         )
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__generate_image_thumbnail_data(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__generate_image_thumbnail_data_impl(port_, path, params)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__generate_storyboard(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        output_dir: String,
+        interval_ms: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        tile_width: u32,
+        tile_height: u32,
+        columns: u32,
+        rows: u32,
+        encoder_options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__generate_storyboard_impl(
+            port_,
+            path,
+            output_dir,
+            interval_ms,
+            tile_width,
+            tile_height,
+            columns,
+            rows,
+            encoder_options,
+        )
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__generate_video_thumbnail(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2528,6 +11724,32 @@ mod web {
         )
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__generate_video_thumbnail_data(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__media__generate_video_thumbnail_data_impl(port_, path, params)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__generate_video_timeline_thumbnail_data(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        num_thumbnails: u32,
+        sink: String,
+    ) {
+        wire__crate__api__media__generate_video_timeline_thumbnail_data_impl(
+            port_,
+            path,
+            params,
+            num_thumbnails,
+            sink,
+        )
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__generate_video_timeline_thumbnails(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2549,6 +11771,14 @@ mod web {
         )
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__get_image_info(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__media__get_image_info_impl(port_, path)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__get_video_info(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2594,6 +11824,22 @@ mod web {
         wire__crate__api__media__output_format_extension_impl(port_, that)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__probe_media(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__media__probe_media_impl(port_, path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__probe_media_json(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__media__probe_media_json_impl(port_, path)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__logger__reload_tracing_file_writer(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -2602,6 +11848,17 @@ mod web {
         wire__crate__api__logger__reload_tracing_file_writer_impl(port_, write_to_files)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__media__reverse_video(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        output_path: String,
+        params: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        sink: String,
+    ) {
+        wire__crate__api__media__reverse_video_impl(port_, path, output_path, params, sink)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__media__thumbnail_size_type_dimensions(
         port_: flutter_rust_bridge::for_generated::MessagePort,
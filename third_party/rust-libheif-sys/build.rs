@@ -240,6 +240,9 @@ fn install_libheif_by_vcpkg() -> Vec<String> {
         println!("cargo:rustc-link-search=native={}", lib_dir.display());
         println!("cargo:rustc-link-lib=static=heif");
         println!("cargo:rustc-link-lib=static=de265");
+        if lib_dir.join("libaom.a").exists() || lib_dir.join("aom.lib").exists() {
+            println!("cargo:rustc-link-lib=static=aom");
+        }
         println!("cargo:include={}", include_dir.display());
         return vec![include_dir.to_string_lossy().to_string()];
     } else {
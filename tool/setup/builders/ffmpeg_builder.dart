@@ -75,7 +75,7 @@ class FFmpegBuilder extends BaseBuilder {
   Future<void> _buildMacOS(PlatformInfo platform, {bool skipOpenH264 = false}) async {
     final archs = [Architecture.arm64, Architecture.x86_64];
     final installDirs = <String>[];
-    final libNames = ['libavcodec', 'libavformat', 'libavutil', 'libswresample', 'libswscale'];
+    final libNames = ['libavcodec', 'libavfilter', 'libavformat', 'libavutil', 'libswresample', 'libswscale'];
 
     for (final arch in archs) {
       print('Building FFmpeg for macOS $arch...');
@@ -107,7 +107,7 @@ class FFmpegBuilder extends BaseBuilder {
       await buildSystem.install(buildDir: sourceDir, installDir: archInstallDir);
 
       // Validate build outputs
-      final expectedLibs = ['libavcodec.a', 'libavformat.a', 'libavutil.a', 'libswresample.a', 'libswscale.a'];
+      final expectedLibs = ['libavcodec.a', 'libavfilter.a', 'libavformat.a', 'libavutil.a', 'libswresample.a', 'libswscale.a'];
       for (final libName in expectedLibs) {
         final libPath = path.join(archInstallDir, 'lib', libName);
         if (!await FileOps.exists(libPath)) {
@@ -192,7 +192,7 @@ class FFmpegBuilder extends BaseBuilder {
       '--enable-videotoolbox',
       '--enable-zlib',
       '--disable-avdevice',
-      '--disable-avfilter',
+      '--enable-avfilter',
       '--disable-debug',
       '--disable-ffplay',
       '--disable-ffprobe',
@@ -283,7 +283,7 @@ class FFmpegBuilder extends BaseBuilder {
         '--enable-videotoolbox',
         '--enable-zlib',
         '--disable-avdevice',
-        '--disable-avfilter',
+        '--enable-avfilter',
         '--disable-debug',
         '--disable-ffplay',
         '--disable-ffprobe',
@@ -518,7 +518,7 @@ class FFmpegBuilder extends BaseBuilder {
         '--enable-avutil',
         '--enable-zlib',
         '--disable-avdevice',
-        '--disable-avfilter',
+        '--enable-avfilter',
         '--disable-debug',
         '--disable-ffplay',
         '--disable-ffprobe',
@@ -616,7 +616,7 @@ class FFmpegBuilder extends BaseBuilder {
       await buildSystem.install(buildDir: sourceDir, installDir: buildDir);
 
       // Validate build outputs
-      final expectedLibs = ['libavcodec.a', 'libavformat.a', 'libavutil.a', 'libswresample.a', 'libswscale.a'];
+      final expectedLibs = ['libavcodec.a', 'libavfilter.a', 'libavformat.a', 'libavutil.a', 'libswresample.a', 'libswscale.a'];
       for (final libName in expectedLibs) {
         final libPath = path.join(buildDir, 'lib', libName);
         if (!await FileOps.exists(libPath)) {
@@ -871,7 +871,7 @@ exec /usr/bin/pkg-config "${args[@]}"
       '--enable-swresample',
       '--enable-zlib',
       '--disable-avdevice',
-      '--disable-avfilter',
+      '--enable-avfilter',
       '--disable-debug',
       '--disable-ffplay',
       '--disable-ffprobe',
@@ -1065,7 +1065,7 @@ exec /usr/bin/pkg-config "${args[@]}"
       '--enable-swresample',
       '--enable-zlib',
       '--disable-avdevice',
-      '--disable-avfilter',
+      '--enable-avfilter',
       '--disable-debug',
       '--disable-ffplay',
       '--disable-ffprobe',
@@ -1,4 +1,4 @@
-// libheif builder (includes libde265 dependency)
+// libheif builder (includes libde265 and libaom dependencies)
 import 'dart:io';
 import 'package:path/path.dart' as path;
 import '../platforms/platform.dart';
@@ -18,6 +18,10 @@ class LibHeifBuilder extends BaseBuilder {
   static const String de265Version = 'v1.0.15';
   static const String de265Repo = 'https://github.com/strukturag/libde265.git';
 
+  // AV1 encoder for AVIF output (BSD-licensed); the decoder is left out
+  static const String aomVersion = 'v3.12.1';
+  static const String aomRepo = 'https://aomedia.googlesource.com/aom';
+
   LibHeifBuilder(super.projectRoot);
 
   @override
@@ -78,6 +82,19 @@ class LibHeifBuilder extends BaseBuilder {
         }
       }
     }
+
+    // Download libaom
+    final aomDir = getSourceDir('libaom');
+    final aomCheckFile = path.join(aomDir, 'CMakeLists.txt');
+
+    if (await FileOps.exists(aomCheckFile)) {
+      print('libaom source already downloaded');
+    } else {
+      if (await Directory(aomDir).exists()) {
+        await FileOps.removeIfExists(aomDir);
+      }
+      await Git.clone(aomRepo, aomDir, branch: aomVersion, depth: 1);
+    }
   }
 
   @override
@@ -103,7 +120,7 @@ class LibHeifBuilder extends BaseBuilder {
 
   Future<void> _buildMacOS(PlatformInfo platform) async {
     final archs = [Architecture.arm64, Architecture.x86_64];
-    final libNames = ['libheif', 'libde265'];
+    final libNames = ['libheif', 'libde265', 'libaom'];
     final archInstalls = <String, String>{};
 
     for (final arch in archs) {
@@ -125,6 +142,19 @@ class LibHeifBuilder extends BaseBuilder {
         ],
       );
 
+      // Build libaom (AVIF encoder)
+      final aomInstall = path.join(buildDir, 'libaom_install');
+      await _buildLibAom(
+        arch: arch,
+        platform: 'macOS',
+        buildPlatform: BuildPlatform.macos,
+        installDir: aomInstall,
+        cmakeFlags: [
+          if (arch == Architecture.arm64) '-DCMAKE_OSX_ARCHITECTURES=arm64' else '-DCMAKE_OSX_ARCHITECTURES=x86_64',
+          '-DCMAKE_OSX_DEPLOYMENT_TARGET=11.0',
+        ],
+      );
+
       // Build libheif
       await _buildLibHeif(
         arch: arch,
@@ -132,6 +162,7 @@ class LibHeifBuilder extends BaseBuilder {
         buildPlatform: BuildPlatform.macos,
         buildDir: buildDir,
         de265Install: de265Install,
+        aomInstall: aomInstall,
         cmakeFlags: [
           if (arch == Architecture.arm64) '-DCMAKE_OSX_ARCHITECTURES=arm64' else '-DCMAKE_OSX_ARCHITECTURES=x86_64',
           '-DCMAKE_OSX_DEPLOYMENT_TARGET=11.0',
@@ -155,6 +186,8 @@ class LibHeifBuilder extends BaseBuilder {
       }
       await FileOps.copyRecursive(path.join(de265Install, 'lib'), path.join(archInstall, 'lib'));
       await FileOps.copyRecursive(path.join(de265Install, 'include'), path.join(archInstall, 'include'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'lib'), path.join(archInstall, 'lib'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'include'), path.join(archInstall, 'include'));
 
       archInstalls[arch.name] = archInstall;
       print('✓ libheif built for macOS $arch');
@@ -201,7 +234,7 @@ includedir=\${prefix}/include
 Name: libheif
 Description: HEIF image codec library
 Version: $version
-Libs: -L\${libdir} -lheif -lde265
+Libs: -L\${libdir} -lheif -lde265 -laom
 Cflags: -I\${includedir}
 Requires:
 ''';
@@ -267,6 +300,27 @@ Requires:
         sanitizeEnv: true,
       );
 
+      // Build libaom (AVIF encoder)
+      final aomInstall = path.join(buildDir, 'libaom_install');
+      await _buildLibAom(
+        arch: arch,
+        platform: 'iOS-$iosPlatform',
+        buildPlatform: BuildPlatform.ios,
+        installDir: aomInstall,
+        cmakeFlags: [
+          '-DCMAKE_SYSTEM_NAME=iOS',
+          '-DCMAKE_OSX_SYSROOT=$sdkPath',
+          '-DCMAKE_OSX_ARCHITECTURES=${arch.name}',
+          '-DCMAKE_C_COMPILER=$cc',
+          '-DCMAKE_CXX_COMPILER=$cxx',
+          '-DCMAKE_C_FLAGS=$minVersion -fPIC',
+          '-DCMAKE_CXX_FLAGS=$minVersion -fPIC',
+          // For cross-compiling to iOS, avoid running test executables.
+          '-DCMAKE_TRY_COMPILE_TARGET_TYPE=STATIC_LIBRARY',
+        ],
+        sanitizeEnv: true,
+      );
+
       // Build libheif
       await _buildLibHeif(
         arch: arch,
@@ -274,6 +328,7 @@ Requires:
         buildPlatform: BuildPlatform.ios,
         buildDir: buildDir,
         de265Install: de265Install,
+        aomInstall: aomInstall,
         cmakeFlags: [
           '-DCMAKE_SYSTEM_NAME=iOS',
           '-DCMAKE_OSX_SYSROOT=$sdkPath',
@@ -309,6 +364,8 @@ Requires:
       }
       await FileOps.copyRecursive(path.join(de265Install, 'lib'), path.join(platformInstall, 'lib'));
       await FileOps.copyRecursive(path.join(de265Install, 'include'), path.join(platformInstall, 'include'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'lib'), path.join(platformInstall, 'lib'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'include'), path.join(platformInstall, 'include'));
 
       // Create pkg-config file
       await FileOps.ensureDirectory(path.join(platformInstall, 'lib', 'pkgconfig'));
@@ -322,7 +379,7 @@ includedir=\${prefix}/include
 Name: libheif
 Description: HEIF image codec library
 Version: $version
-Libs: -L\${libdir} -lheif -lde265
+Libs: -L\${libdir} -lheif -lde265 -laom
 Cflags: -I\${includedir}
 Requires:
 ''';
@@ -380,12 +437,37 @@ Requires:
         sysroot: sysroot,
       );
 
+      // Build libaom (AVIF encoder)
+      final aomInstall = path.join(buildDir, 'libaom_install');
+      final aomEnv = Map<String, String>.from(Platform.environment);
+      for (final name in ['LDFLAGS', 'CFLAGS', 'CPPFLAGS', 'CXXFLAGS', 'LIBRARY_PATH', 'CPATH', 'C_INCLUDE_PATH', 'CPLUS_INCLUDE_PATH']) {
+        aomEnv.remove(name);
+      }
+      await _buildLibAom(
+        arch: null,
+        platform: 'android_$abi',
+        buildPlatform: BuildPlatform.android,
+        installDir: aomInstall,
+        cmakeFlags: [
+          '-DCMAKE_SYSTEM_NAME=Android',
+          '-DCMAKE_SYSTEM_PROCESSOR=$arch',
+          '-DCMAKE_ANDROID_ARCH_ABI=$abi',
+          '-DCMAKE_ANDROID_NDK=$ndkHome',
+          '-DCMAKE_C_COMPILER=$cc',
+          '-DCMAKE_CXX_COMPILER=$cxx',
+          '-DCMAKE_C_FLAGS=--sysroot=$sysroot -fPIC',
+          '-DCMAKE_CXX_FLAGS=--sysroot=$sysroot -fPIC',
+        ],
+        environment: aomEnv,
+      );
+
       // Build libheif
       await _buildLibHeifAndroid(
         arch: arch,
         abi: abi,
         buildDir: buildDir,
         de265Install: de265Install,
+        aomInstall: aomInstall,
         ndkHome: ndkHome,
         toolchain: toolchain,
         cc: cc,
@@ -401,6 +483,8 @@ Requires:
       await FileOps.copyRecursive(path.join(buildDir, 'include'), path.join(abiInstallDir, 'include'));
       await FileOps.copyRecursive(path.join(de265Install, 'lib'), path.join(abiInstallDir, 'lib'));
       await FileOps.copyRecursive(path.join(de265Install, 'include'), path.join(abiInstallDir, 'include'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'lib'), path.join(abiInstallDir, 'lib'));
+      await FileOps.copyRecursive(path.join(aomInstall, 'include'), path.join(abiInstallDir, 'include'));
 
       // Create pkg-config files
       await FileOps.ensureDirectory(path.join(abiInstallDir, 'lib', 'pkgconfig'));
@@ -435,7 +519,7 @@ includedir=\${prefix}/include
 Name: libheif
 Description: HEIF image codec library
 Version: $version
-Libs: -L\${libdir} -lheif -lde265
+Libs: -L\${libdir} -lheif -lde265 -laom
 Cflags: -I\${includedir}
 Requires:
 ''';
@@ -469,6 +553,16 @@ Requires:
       cmakeFlags: [],
     );
 
+    // Build libaom (AVIF encoder)
+    final aomInstall = path.join(buildDir, 'libaom_install');
+    await _buildLibAom(
+      arch: arch,
+      platform: 'Linux',
+      buildPlatform: BuildPlatform.linux,
+      installDir: aomInstall,
+      cmakeFlags: [],
+    );
+
     // Build libheif
     await _buildLibHeif(
       arch: arch,
@@ -476,6 +570,7 @@ Requires:
       buildPlatform: BuildPlatform.linux,
       buildDir: buildDir,
       de265Install: de265Install,
+      aomInstall: aomInstall,
       cmakeFlags: [],
     );
 
@@ -487,6 +582,8 @@ Requires:
     await FileOps.copyRecursive(path.join(buildDir, 'include'), path.join(installDir, 'include'));
     await FileOps.copyRecursive(path.join(de265Install, 'lib'), path.join(installDir, 'lib'));
     await FileOps.copyRecursive(path.join(de265Install, 'include'), path.join(installDir, 'include'));
+    await FileOps.copyRecursive(path.join(aomInstall, 'lib'), path.join(installDir, 'lib'));
+    await FileOps.copyRecursive(path.join(aomInstall, 'include'), path.join(installDir, 'include'));
 
     // Create pkg-config file
     await FileOps.ensureDirectory(path.join(installDir, 'lib', 'pkgconfig'));
@@ -500,7 +597,7 @@ includedir=\${prefix}/include
 Name: libheif
 Description: HEIF image codec library
 Version: $version
-Libs: -L\${libdir} -lheif -lde265
+Libs: -L\${libdir} -lheif -lde265 -laom
 Cflags: -I\${includedir}
 Requires:
 ''';
@@ -642,6 +739,22 @@ Requires:
       }
     }
 
+    // Build libaom (AVIF encoder)
+    final aomInstall = path.join(buildDir, 'libaom_install');
+    await _buildLibAom(
+      arch: Architecture.x86_64,
+      platform: 'windows',
+      buildPlatform: BuildPlatform.windows,
+      installDir: aomInstall,
+      cmakeFlags: [
+        '-DCMAKE_SYSTEM_NAME=Windows',
+        '-DCMAKE_C_COMPILER=$cc',
+        '-DCMAKE_CXX_COMPILER=$cxx',
+        '-DCMAKE_RC_COMPILER=$rc',
+      ],
+      environment: env,
+    );
+
     // Build libheif
     final heifDir = getSourceDir(sourceName);
     final heifBuildDir = path.join(heifDir, 'build_windows');
@@ -660,7 +773,10 @@ Requires:
       '-DCMAKE_POSITION_INDEPENDENT_CODE=ON',
       '-DBUILD_SHARED_LIBS=OFF',
       '-DENABLE_PLUGIN_LOADING=OFF',
-      '-DWITH_AOM=OFF',
+      '-DWITH_AOM_ENCODER=ON',
+      '-DWITH_AOM_DECODER=OFF',
+      '-DAOM_INCLUDE_DIR=${path.join(aomInstall, 'include')}',
+      '-DAOM_LIBRARY=${path.join(aomInstall, 'lib', 'libaom.a')}',
       '-DWITH_DAV1D=OFF',
       '-DWITH_RAV1E=OFF',
       '-DWITH_X265=OFF',
@@ -670,7 +786,6 @@ Requires:
       '-DWITH_EXAMPLES=OFF',
       '-DWITH_TESTS=OFF',
       '-DWITH_UNCOMPRESSED_CODEC=OFF',
-      '-DCMAKE_DISABLE_FIND_PACKAGE_AOM=ON',
       '-DCMAKE_DISABLE_FIND_PACKAGE_libsharpyuv=ON',
       '-DCMAKE_C_FLAGS=-fPIC',
       '-DCMAKE_CXX_FLAGS=-fPIC',
@@ -739,6 +854,8 @@ Requires:
     if (await FileOps.exists(path.join(de265Install, 'lib', 'libde265.a'))) {
       await File(path.join(de265Install, 'lib', 'libde265.a')).copy(path.join(installDir, 'lib', 'libde265.a'));
     }
+    await File(path.join(aomInstall, 'lib', 'libaom.a')).copy(path.join(installDir, 'lib', 'libaom.a'));
+    await FileOps.copyRecursive(path.join(aomInstall, 'include', 'aom'), path.join(installDir, 'include', 'aom'));
 
     // Copy headers
     if (await Directory(path.join(buildDir, 'include', 'libheif')).exists()) {
@@ -771,7 +888,7 @@ includedir=\${prefix}/include
 Name: libheif
 Description: HEIF image codec library
 Version: $version
-Libs: -L\${libdir} -lheif -lde265
+Libs: -L\${libdir} -lheif -lde265 -laom
 Cflags: -I\${includedir}
 Requires:
 ''';
@@ -903,6 +1020,65 @@ Requires:
       print('WARNING: Installed de265.h not found at ${de265InstalledHeader.path}');
     }
 
+    // Build libaom with MSVC (AVIF encoder)
+    final aomDir = getSourceDir('libaom');
+    final aomBuildDir = path.join(buildDir, 'libaom');
+    await FileOps.ensureDirectory(aomBuildDir);
+    await FileOps.removeIfExists(aomBuildDir);
+    await FileOps.ensureDirectory(aomBuildDir);
+
+    final aomInstall = path.join(buildDir, 'libaom_install');
+
+    print('Configuring libaom with MSVC...');
+    final aomConfigureResult = await runProcessStreaming(
+      cmakeExe,
+      [
+        '-G', selectedGenerator,
+        '-A', 'x64',
+        '-DCMAKE_BUILD_TYPE=Release',
+        '-DBUILD_SHARED_LIBS=OFF',
+        '-DAOM_TARGET_CPU=generic',
+        '-DCONFIG_AV1_DECODER=0',
+        '-DENABLE_DOCS=0',
+        '-DENABLE_EXAMPLES=0',
+        '-DENABLE_TESTDATA=0',
+        '-DENABLE_TESTS=0',
+        '-DENABLE_TOOLS=0',
+        '-DCMAKE_CXX_FLAGS_RELEASE=/MD',
+        '-DCMAKE_C_FLAGS_RELEASE=/MD',
+        aomDir,
+      ],
+      workingDirectory: aomBuildDir,
+      environment: env,
+    );
+
+    if (aomConfigureResult.exitCode != 0) {
+      throw Exception('Failed to configure libaom with MSVC: ${aomConfigureResult.stderr}');
+    }
+
+    print('Building libaom with MSVC...');
+    final aomBuildResult = await runProcessStreaming(
+      cmakeExe,
+      [
+        '--build',
+        '.',
+        '--config',
+        'Release',
+        '--target',
+        'aom',
+        '--parallel',
+        PlatformDetector.getCpuCores().toString(),
+      ],
+      workingDirectory: aomBuildDir,
+      environment: env,
+    );
+
+    if (aomBuildResult.exitCode != 0) {
+      throw Exception('Failed to build libaom with MSVC: ${aomBuildResult.stderr}');
+    }
+
+    await _installLibAom(aomDir: aomDir, buildDir: aomBuildDir, installDir: aomInstall, libFile: 'aom.lib');
+
     // Build libheif with MSVC
     final heifBuildDir = path.join(buildDir, 'libheif');
     await FileOps.ensureDirectory(heifBuildDir);
@@ -918,7 +1094,10 @@ Requires:
       '-DCMAKE_POLICY_VERSION_MINIMUM=3.5', // Required for newer CMake versions
       '-DBUILD_SHARED_LIBS=OFF',
       '-DENABLE_PLUGIN_LOADING=OFF',
-      '-DWITH_AOM=OFF',
+      '-DWITH_AOM_ENCODER=ON',
+      '-DWITH_AOM_DECODER=OFF',
+      '-DAOM_INCLUDE_DIR=${path.join(aomInstall, 'include')}',
+      '-DAOM_LIBRARY=${path.join(aomInstall, 'lib', 'aom.lib')}',
       '-DWITH_DAV1D=OFF',
       '-DWITH_RAV1E=OFF',
       '-DWITH_X265=OFF',
@@ -928,7 +1107,6 @@ Requires:
       '-DWITH_EXAMPLES=OFF',
       '-DWITH_TESTS=OFF',
       '-DWITH_UNCOMPRESSED_CODEC=OFF',
-      '-DCMAKE_DISABLE_FIND_PACKAGE_AOM=ON',
       '-DCMAKE_DISABLE_FIND_PACKAGE_libsharpyuv=ON',
       '-DCMAKE_CXX_FLAGS=-DLIBDE265_STATIC_BUILD',
       '-DCMAKE_C_FLAGS=-DLIBDE265_STATIC_BUILD',
@@ -998,7 +1176,11 @@ Requires:
       print('WARNING: heif.lib not found at $heifLibSrc');
     }
 
-    print('✓ MSVC-built libheif/libde265 installed with .lib files');
+    // Copy aom.lib from MSVC build
+    print('Copying aom.lib from MSVC build...');
+    await File(path.join(aomInstall, 'lib', 'aom.lib')).copy(path.join(installDir, 'lib', 'aom.lib'));
+
+    print('✓ MSVC-built libheif/libde265/libaom installed with .lib files');
   }
 
   // Helper methods for building libde265 and libheif
@@ -1214,12 +1396,105 @@ Cflags: -I\${includedir}
     }
   }
 
+  /// Builds the libaom AV1 encoder that libheif uses for AVIF output. The generic
+  /// target CPU needs no assembler, so the same flags work for every cross build.
+  Future<void> _buildLibAom({
+    required Architecture? arch,
+    required String platform,
+    required BuildPlatform buildPlatform,
+    required String installDir,
+    required List<String> cmakeFlags,
+    bool sanitizeEnv = false,
+    Map<String, String>? environment,
+  }) async {
+    final aomDir = getSourceDir('libaom');
+    final buildDir = path.join(aomDir, 'build_${platform}_${arch?.name ?? 'default'}');
+
+    await FileOps.ensureDirectory(buildDir);
+    await FileOps.removeIfExists(buildDir);
+    await FileOps.ensureDirectory(buildDir);
+
+    final cmakeArgs = <String>[
+      '-DCMAKE_BUILD_TYPE=Release',
+      '-DCMAKE_INSTALL_PREFIX=$installDir',
+      '-DCMAKE_POSITION_INDEPENDENT_CODE=ON',
+      '-DBUILD_SHARED_LIBS=OFF',
+      '-DAOM_TARGET_CPU=generic',
+      '-DCONFIG_AV1_DECODER=0',
+      '-DENABLE_DOCS=0',
+      '-DENABLE_EXAMPLES=0',
+      '-DENABLE_TESTDATA=0',
+      '-DENABLE_TESTS=0',
+      '-DENABLE_TOOLS=0',
+      ...cmakeFlags,
+    ];
+
+    var env = environment;
+    if (env == null && sanitizeEnv) {
+      env = Map<String, String>.from(Platform.environment);
+      env.remove('LDFLAGS');
+      env.remove('LIBRARY_PATH');
+      env.remove('DYLD_LIBRARY_PATH');
+      env.remove('DYLD_FALLBACK_LIBRARY_PATH');
+      env.remove('CPATH');
+      env.remove('C_INCLUDE_PATH');
+      env.remove('CPLUS_INCLUDE_PATH');
+    }
+
+    final buildSystem = CMakeBuildSystem(cmakeArgs: cmakeArgs);
+    await buildSystem.configure(
+      sourceDir: aomDir,
+      buildDir: buildDir,
+      platform: PlatformInfo(platform: buildPlatform, architecture: arch),
+      environment: env,
+    );
+
+    // Build only the aom library target
+    final cmakeExe = Platform.isWindows ? await PlatformDetector.findCmake() : 'cmake';
+    final result = await runProcessStreaming(
+      cmakeExe,
+      ['--build', '.', '--target', 'aom', '--parallel', PlatformDetector.getCpuCores().toString()],
+      workingDirectory: buildDir,
+      environment: env,
+    );
+
+    if (result.exitCode != 0) {
+      throw Exception('libaom build failed: ${result.stderr}');
+    }
+
+    await _installLibAom(aomDir: aomDir, buildDir: buildDir, installDir: installDir, libFile: 'libaom.a');
+  }
+
+  /// Manual install of the library and public headers (`cmake --install` also wants the tools)
+  Future<void> _installLibAom({
+    required String aomDir,
+    required String buildDir,
+    required String installDir,
+    required String libFile,
+  }) async {
+    await FileOps.ensureDirectory(path.join(installDir, 'lib'));
+    await FileOps.ensureDirectory(path.join(installDir, 'include', 'aom'));
+
+    final lib = await _findFile(buildDir, libFile);
+    if (lib == null) {
+      throw Exception('$libFile not found after build');
+    }
+    await File(lib).copy(path.join(installDir, 'lib', libFile));
+
+    await for (final entity in Directory(path.join(aomDir, 'aom')).list()) {
+      if (entity is File && entity.path.endsWith('.h')) {
+        await entity.copy(path.join(installDir, 'include', 'aom', path.basename(entity.path)));
+      }
+    }
+  }
+
   Future<void> _buildLibHeif({
     required Architecture? arch,
     required String platform,
     required BuildPlatform buildPlatform,
     required String buildDir,
     required String de265Install,
+    required String aomInstall,
     required List<String> cmakeFlags,
     bool sanitizeEnv = false,
   }) async {
@@ -1237,7 +1512,10 @@ Cflags: -I\${includedir}
       '-DCMAKE_POSITION_INDEPENDENT_CODE=ON',
       '-DBUILD_SHARED_LIBS=OFF',
       '-DENABLE_PLUGIN_LOADING=OFF',
-      '-DWITH_AOM=OFF',
+      '-DWITH_AOM_ENCODER=ON',
+      '-DWITH_AOM_DECODER=OFF',
+      '-DAOM_INCLUDE_DIR=${path.join(aomInstall, 'include')}',
+      '-DAOM_LIBRARY=${path.join(aomInstall, 'lib', 'libaom.a')}',
       '-DWITH_DAV1D=OFF',
       '-DWITH_RAV1E=OFF',
       '-DWITH_X265=OFF',
@@ -1247,7 +1525,6 @@ Cflags: -I\${includedir}
       '-DWITH_EXAMPLES=OFF',
       '-DWITH_TESTS=OFF',
       '-DWITH_UNCOMPRESSED_CODEC=OFF',
-      '-DCMAKE_DISABLE_FIND_PACKAGE_AOM=ON',
       '-DCMAKE_DISABLE_FIND_PACKAGE_libsharpyuv=ON',
       ...cmakeFlags,
     ];
@@ -1337,6 +1614,7 @@ Cflags: -I\${includedir}
     required String abi,
     required String buildDir,
     required String de265Install,
+    required String aomInstall,
     required String ndkHome,
     required String toolchain,
     required String cc,
@@ -1367,7 +1645,10 @@ Cflags: -I\${includedir}
       '-DCMAKE_POSITION_INDEPENDENT_CODE=ON',
       '-DBUILD_SHARED_LIBS=OFF',
       '-DENABLE_PLUGIN_LOADING=OFF',
-      '-DWITH_AOM=OFF',
+      '-DWITH_AOM_ENCODER=ON',
+      '-DWITH_AOM_DECODER=OFF',
+      '-DAOM_INCLUDE_DIR=${path.join(aomInstall, 'include')}',
+      '-DAOM_LIBRARY=${path.join(aomInstall, 'lib', 'libaom.a')}',
       '-DWITH_DAV1D=OFF',
       '-DWITH_RAV1E=OFF',
       '-DWITH_X265=OFF',
@@ -1377,7 +1658,6 @@ Cflags: -I\${includedir}
       '-DWITH_EXAMPLES=OFF',
       '-DWITH_TESTS=OFF',
       '-DWITH_UNCOMPRESSED_CODEC=OFF',
-      '-DCMAKE_DISABLE_FIND_PACKAGE_AOM=ON',
       '-DCMAKE_DISABLE_FIND_PACKAGE_libsharpyuv=ON',
       '-DCMAKE_C_FLAGS=--sysroot=$sysroot -fPIC',
       '-DCMAKE_CXX_FLAGS=--sysroot=$sysroot -fPIC',